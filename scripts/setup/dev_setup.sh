@@ -459,7 +459,7 @@ EOF
 Development tools (since -d was provided):
   * mysql client
   * python3 (boto3, black, yamllint, ...)
-  * python database drivers (mysql-connector-python, pymysql, sqlalchemy, clickhouse_driver)
+  * python database drivers (mysql-connector-python, pymysql, sqlalchemy, clickhouse_driver, pyarrow)
 EOF
 	fi
 
@@ -664,7 +664,7 @@ if [[ "$INSTALL_DEV_TOOLS" == "true" ]]; then
 	fi
 	python3 -m pip install --quiet boto3 "moto[all]" black shfmt-py toml yamllint
 	# drivers
-	python3 -m pip install --quiet pymysql sqlalchemy clickhouse_driver
+	python3 -m pip install --quiet pymysql sqlalchemy clickhouse_driver pyarrow
 	# sqllogic dependencies
 	python3 -m pip install --quiet mysql-connector-python==8.0.30
 fi
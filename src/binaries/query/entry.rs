@@ -16,6 +16,7 @@ use std::env;
 use std::time::Duration;
 
 use databend_common_base::mem_allocator::GlobalAllocator;
+use databend_common_base::runtime::metrics::GLOBAL_METRICS_REGISTRY;
 use databend_common_base::runtime::set_alloc_error_hook;
 use databend_common_base::runtime::GLOBAL_MEM_STAT;
 use databend_common_config::Commands;
@@ -165,6 +166,10 @@ pub async fn start_services(conf: &InnerConfig) -> Result<()> {
     // Metric API service.
     {
         set_system_version("query", QUERY_GIT_SEMVER.as_str(), QUERY_GIT_SHA.as_str());
+        if conf.query.metric_label_retention_hours > 0 {
+            let hours = conf.query.metric_label_retention_hours;
+            GLOBAL_METRICS_REGISTRY.set_label_retention(Some(Duration::from_secs(hours * 3600)));
+        }
         let address = conf.query.metric_api_address.clone();
         let mut srv = MetricService::create();
         let listening = srv.start(address.parse()?).await?;
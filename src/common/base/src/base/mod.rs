@@ -17,6 +17,7 @@ mod profiling;
 mod progress;
 mod select;
 mod semaphore;
+mod server_warnings;
 mod shutdown_signal;
 mod singleton_instance;
 mod stop_handle;
@@ -34,6 +35,9 @@ pub use progress::ProgressValues;
 pub use select::select3;
 pub use select::Select3Output;
 pub use semaphore::Semaphore;
+pub use server_warnings::ServerWarning;
+pub use server_warnings::ServerWarnings;
+pub use server_warnings::WarningSeverity;
 pub use shutdown_signal::signal_stream;
 pub use shutdown_signal::DummySignalStream;
 pub use shutdown_signal::SignalStream;
@@ -0,0 +1,129 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::LazyLock;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use parking_lot::Mutex;
+
+static SERVER_WARNINGS: LazyLock<ServerWarnings> =
+    LazyLock::new(|| ServerWarnings::create(1000, Duration::from_secs(600)));
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WarningSeverity {
+    Warning,
+    Error,
+}
+
+impl fmt::Display for WarningSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WarningSeverity::Warning => write!(f, "WARN"),
+            WarningSeverity::Error => write!(f, "ERROR"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ServerWarning {
+    /// Unix microseconds of the first occurrence.
+    pub first_seen: i64,
+    /// Unix microseconds of the latest occurrence.
+    pub last_seen: i64,
+    pub severity: WarningSeverity,
+    /// The subsystem that reported it, e.g. `background_compaction`.
+    pub component: String,
+    pub message: String,
+    /// The occurrences collapsed into this warning.
+    pub count: u64,
+}
+
+/// The non-fatal issues of this node, for the operators to query besides the logs.
+///
+/// A warning repeated within the dedup window bumps the count of the recorded one instead of
+/// taking a new slot, beyond the capacity the oldest warnings are dropped.
+pub struct ServerWarnings {
+    capacity: usize,
+    dedup_window: Duration,
+    warnings: Mutex<VecDeque<ServerWarning>>,
+}
+
+impl ServerWarnings {
+    pub fn instance() -> &'static ServerWarnings {
+        &SERVER_WARNINGS
+    }
+
+    pub fn create(capacity: usize, dedup_window: Duration) -> ServerWarnings {
+        ServerWarnings {
+            capacity: capacity.max(1),
+            dedup_window,
+            warnings: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    pub fn push(&self, severity: WarningSeverity, component: &str, message: impl ToString) {
+        let message = message.to_string();
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_micros() as i64);
+        let window_start = now - self.dedup_window.as_micros() as i64;
+
+        let mut warnings = self.warnings.lock();
+        let repeated = warnings.iter_mut().rev().find(|w| {
+            w.last_seen >= window_start
+                && w.severity == severity
+                && w.component == component
+                && w.message == message
+        });
+        if let Some(warning) = repeated {
+            warning.last_seen = now;
+            warning.count += 1;
+            return;
+        }
+
+        if warnings.len() == self.capacity {
+            warnings.pop_front();
+        }
+        warnings.push_back(ServerWarning {
+            first_seen: now,
+            last_seen: now,
+            severity,
+            component: component.to_string(),
+            message,
+            count: 1,
+        });
+    }
+
+    pub fn warn(&self, component: &str, message: impl ToString) {
+        self.push(WarningSeverity::Warning, component, message)
+    }
+
+    pub fn error(&self, component: &str, message: impl ToString) {
+        self.push(WarningSeverity::Error, component, message)
+    }
+
+    /// The warnings, oldest first.
+    pub fn list(&self) -> Vec<ServerWarning> {
+        self.warnings.lock().iter().cloned().collect()
+    }
+
+    /// Acknowledges all the warnings.
+    pub fn clear(&self) {
+        self.warnings.lock().clear();
+    }
+}
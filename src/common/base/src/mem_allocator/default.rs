@@ -17,3 +17,8 @@
 pub type DefaultAllocator = crate::mem_allocator::JEAllocator;
 #[cfg(not(feature = "jemalloc"))]
 pub type DefaultAllocator = crate::mem_allocator::StdAllocator;
+
+#[cfg(feature = "jemalloc")]
+pub const DEFAULT_ALLOCATOR_NAME: &str = "jemalloc";
+#[cfg(not(feature = "jemalloc"))]
+pub const DEFAULT_ALLOCATOR_NAME: &str = "std";
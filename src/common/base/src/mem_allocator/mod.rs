@@ -18,6 +18,7 @@ mod mmap;
 mod std_;
 
 pub use default::DefaultAllocator;
+pub use default::DEFAULT_ALLOCATOR_NAME;
 pub use global::GlobalAllocator;
 pub use jemalloc::JEAllocator;
 pub use mmap::MmapAllocator;
@@ -14,10 +14,13 @@
 
 mod alloc_error_hook;
 mod mem_stat;
+mod pipeline_memory;
 mod stat_buffer;
 
 pub use alloc_error_hook::set_alloc_error_hook;
 pub use mem_stat::MemStat;
 pub use mem_stat::OutOfLimit;
 pub use mem_stat::GLOBAL_MEM_STAT;
+pub use pipeline_memory::MemoryReservation;
+pub use pipeline_memory::PipelineMemory;
 pub use stat_buffer::StatBuffer;
@@ -0,0 +1,185 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use bytesize::ByteSize;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+use crate::runtime::ThreadTracker;
+
+/// The memory of the blocks buffered by the processors of a pipeline.
+///
+/// Unlike [`MemStat`](crate::runtime::MemStat) which is fed by the allocator, it is fed by the
+/// processors through [`MemoryReservation`], so the processor exceeding the limit is known.
+pub struct PipelineMemory {
+    used: AtomicUsize,
+
+    /// Set to 0 to disable the limit.
+    limit: usize,
+}
+
+impl PipelineMemory {
+    pub fn create(limit: usize) -> Arc<PipelineMemory> {
+        Arc::new(PipelineMemory {
+            used: AtomicUsize::new(0),
+            limit,
+        })
+    }
+
+    pub fn get_memory_usage(&self) -> usize {
+        self.used.load(Ordering::Relaxed)
+    }
+
+    fn try_grow(&self, size: usize) -> std::result::Result<(), usize> {
+        let used = self.used.fetch_add(size, Ordering::Relaxed);
+
+        if self.limit != 0 && used + size > self.limit {
+            self.used.fetch_sub(size, Ordering::Relaxed);
+            return Err(used);
+        }
+
+        Ok(())
+    }
+
+    fn shrink(&self, size: usize) {
+        self.used.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+/// The memory a processor reserves from the [`PipelineMemory`] of the pipeline it runs in.
+///
+/// The reservation is bound to the pipeline of the first thread growing it, so it can be
+/// created when building the pipeline. Out of a pipeline it is not limited. The reserved
+/// memory is released on drop.
+pub struct MemoryReservation {
+    name: String,
+    size: usize,
+    memory: Option<Arc<PipelineMemory>>,
+}
+
+impl MemoryReservation {
+    pub fn create(name: impl Into<String>) -> MemoryReservation {
+        MemoryReservation {
+            name: name.into(),
+            size: 0,
+            memory: None,
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Reserves `size` bytes more, the error names the processor exceeding the limit.
+    pub fn grow(&mut self, size: usize) -> Result<()> {
+        match self.grow_memory(size) {
+            Ok(_) => Ok(()),
+            Err((used, limit)) => Err(ErrorCode::PipelineMemoryExceeded(format!(
+                "{} exceeds the memory limit of the pipeline, reserving {} with {} used, limit {}",
+                self.name,
+                ByteSize::b(size as u64),
+                ByteSize::b(used as u64),
+                ByteSize::b(limit as u64),
+            ))),
+        }
+    }
+
+    /// Same as [`MemoryReservation::grow`], but returns false instead of an error. The
+    /// processors which can spill call it, and spill their buffers when it fails.
+    pub fn try_grow(&mut self, size: usize) -> bool {
+        self.grow_memory(size).is_ok()
+    }
+
+    pub fn shrink(&mut self, size: usize) {
+        let size = size.min(self.size);
+        if let Some(memory) = &self.memory {
+            memory.shrink(size);
+        }
+
+        self.size -= size;
+    }
+
+    pub fn free(&mut self) {
+        self.shrink(self.size);
+    }
+
+    fn grow_memory(&mut self, size: usize) -> std::result::Result<(), (usize, usize)> {
+        if self.memory.is_none() {
+            self.memory = ThreadTracker::pipeline_memory();
+        }
+
+        if let Some(memory) = &self.memory {
+            memory.try_grow(size).map_err(|used| (used, memory.limit))?;
+        }
+
+        self.size += size;
+        Ok(())
+    }
+}
+
+impl Drop for MemoryReservation {
+    fn drop(&mut self) {
+        self.free();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use databend_common_exception::ErrorCode;
+    use databend_common_exception::Result;
+
+    use crate::runtime::memory::MemoryReservation;
+    use crate::runtime::memory::PipelineMemory;
+    use crate::runtime::ThreadTracker;
+
+    #[test]
+    fn test_memory_reservation() -> Result<()> {
+        let memory = PipelineMemory::create(100);
+        let mut payload = ThreadTracker::new_tracking_payload();
+        payload.pipeline_memory = Some(memory.clone());
+        let _guard = ThreadTracker::tracking(payload);
+
+        let mut source = MemoryReservation::create("source");
+        let mut sink = MemoryReservation::create("sink");
+        source.grow(60)?;
+        assert!(!sink.try_grow(50));
+        sink.grow(40)?;
+        assert_eq!(memory.get_memory_usage(), 100);
+
+        let error = source.grow(1).unwrap_err();
+        assert_eq!(error.code(), ErrorCode::PIPELINE_MEMORY_EXCEEDED);
+        assert!(error.message().starts_with("source exceeds"));
+
+        source.shrink(10);
+        assert!(sink.try_grow(10));
+        drop(sink);
+        assert_eq!(memory.get_memory_usage(), 50);
+        source.free();
+        assert_eq!(memory.get_memory_usage(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_memory_reservation_out_of_pipeline() -> Result<()> {
+        let mut reservation = MemoryReservation::create("source");
+        reservation.grow(usize::MAX / 2)?;
+        assert_eq!(reservation.size(), usize::MAX / 2);
+        Ok(())
+    }
+}
@@ -18,7 +18,12 @@ use std::fmt::Debug;
 use std::fmt::Error;
 use std::fmt::Formatter;
 use std::hash::Hash;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
 use parking_lot::RwLock;
 use prometheus_client::collector::Collector;
@@ -50,10 +55,38 @@ pub trait FamilyMetricCreator<Labels: FamilyLabels, M: FamilyMetric>:
     fn create(&self, index: usize, labels: Labels) -> M;
 }
 
+#[derive(Debug)]
+struct FamilyEntry<M> {
+    metric: Arc<M>,
+    /// Unix timestamp in milliseconds of the last lookup of the label set.
+    last_updated: AtomicU64,
+}
+
+impl<M> FamilyEntry<M> {
+    fn create(metric: Arc<M>) -> Self {
+        FamilyEntry {
+            metric,
+            last_updated: AtomicU64::new(unix_millis()),
+        }
+    }
+
+    fn touch(&self) -> Arc<M> {
+        self.last_updated.store(unix_millis(), Ordering::Relaxed);
+        self.metric.clone()
+    }
+}
+
+fn unix_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
+
 pub struct Family<S: FamilyLabels, M: FamilyMetric> {
     index: usize,
 
-    metrics: Arc<RwLock<HashMap<S, Arc<M>>>>,
+    metrics: Arc<RwLock<HashMap<S, FamilyEntry<M>>>>,
 
     family_metric_creator: Arc<Box<dyn FamilyMetricCreator<S, M>>>,
 }
@@ -88,25 +121,50 @@ impl<S: FamilyLabels, M: FamilyMetric> Family<S, M> {
 
 impl<S: FamilyLabels, M: FamilyMetric> Family<S, M> {
     pub fn get_or_create(&self, label_set: &S) -> Arc<M> {
-        if let Some(metric) = self.metrics.read().get(label_set) {
-            return metric.clone();
+        if let Some(entry) = self.metrics.read().get(label_set) {
+            return entry.touch();
         }
 
         let mut write_guard = self.metrics.write();
 
         match write_guard.entry(label_set.clone()) {
-            Entry::Occupied(v) => v.get().clone(),
+            Entry::Occupied(v) => v.get().touch(),
             Entry::Vacant(v) => {
                 let metric = Arc::new(
                     self.family_metric_creator
                         .create(self.index, label_set.clone()),
                 );
-                v.insert(metric.clone());
+                v.insert(FamilyEntry::create(metric.clone()));
                 metric
             }
         }
     }
 
+    pub fn len(&self) -> usize {
+        self.metrics.read().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.metrics.read().is_empty()
+    }
+
+    /// Drop the label sets that have not been looked up within `idle`,
+    /// returns the number of dropped label sets.
+    ///
+    /// A label set whose metric is still held by a caller is kept, the caller may update it
+    /// without looking it up again.
+    pub fn evict_idle(&self, idle: Duration) -> usize {
+        let deadline = unix_millis().saturating_sub(idle.as_millis() as u64);
+
+        let mut metrics = self.metrics.write();
+        let before = metrics.len();
+        metrics.retain(|_, entry| {
+            entry.last_updated.load(Ordering::Relaxed) > deadline
+                || Arc::strong_count(&entry.metric) > 1
+        });
+        before - metrics.len()
+    }
+
     pub fn remove(&self, label_set: &S) -> bool {
         ScopedRegistry::op(self.index, |m: &Self| {
             m.metrics.write().remove(label_set);
@@ -132,9 +190,9 @@ impl<S: FamilyLabels, M: FamilyMetric> TypedMetric for Family<S, M> {
 impl<S: FamilyLabels, M: FamilyMetric> EncodeMetric for Family<S, M> {
     fn encode(&self, mut encoder: MetricEncoder) -> Result<(), std::fmt::Error> {
         let guard = self.metrics.read();
-        for (label_set, m) in guard.iter() {
+        for (label_set, entry) in guard.iter() {
             let encoder = encoder.encode_family(label_set)?;
-            m.encode(encoder)?;
+            entry.metric.encode(encoder)?;
         }
 
         Ok(())
@@ -150,6 +208,10 @@ impl<S: FamilyLabels, M: FamilyMetric> DatabendMetric for Family<S, M> {
         self.clear()
     }
 
+    fn evict_idle_labels(&self, idle: Duration) {
+        self.evict_idle(idle);
+    }
+
     fn sample(&self, name: &str, samples: &mut Vec<MetricSample>) {
         // TODO: hack, using prometheus parser for get metric labels
         let mut registry = Registry::with_labels(vec![].into_iter());
@@ -160,7 +222,10 @@ impl<S: FamilyLabels, M: FamilyMetric> DatabendMetric for Family<S, M> {
 
         let mut text = String::new();
         if prometheus_client::encoding::text::encode(&mut text, &registry).is_ok() {
-            samples.extend(prometheus_parse::parse_at(text.lines()));
+            // The label sets are kept in a hash map, sort them for a stable output.
+            let mut family_samples = prometheus_parse::parse_at(text.lines());
+            family_samples.sort_by(|a, b| (&a.name, &a.labels).cmp(&(&b.name, &b.labels)));
+            samples.extend(family_samples);
         }
     }
 }
@@ -168,16 +233,16 @@ impl<S: FamilyLabels, M: FamilyMetric> DatabendMetric for Family<S, M> {
 #[derive(Debug)]
 struct DummyCollector<S: FamilyLabels, M: FamilyMetric> {
     name: String,
-    metrics: Arc<RwLock<HashMap<S, Arc<M>>>>,
+    metrics: Arc<RwLock<HashMap<S, FamilyEntry<M>>>>,
 }
 
 impl<S: FamilyLabels, M: FamilyMetric> Collector for DummyCollector<S, M> {
     fn encode(&self, mut encoder: DescriptorEncoder) -> Result<(), Error> {
         let mut encoder = encoder.encode_descriptor(&self.name, "", None, M::TYPE)?;
 
-        for (label_set, m) in self.metrics.read().iter() {
+        for (label_set, entry) in self.metrics.read().iter() {
             let encoder = encoder.encode_family(label_set)?;
-            m.encode(encoder)?;
+            entry.metric.encode(encoder)?;
         }
 
         Ok(())
@@ -192,10 +257,10 @@ mod prometheus_parse {
     use prometheus_parse::LineInfo;
     use prometheus_parse::SampleType;
 
-    use crate::runtime::metrics::sample::SummaryCount;
     use crate::runtime::metrics::HistogramCount;
     use crate::runtime::metrics::MetricSample;
     use crate::runtime::metrics::MetricValue;
+    use crate::runtime::metrics::sample::SummaryCount;
 
     fn parse_golang_float(s: &str) -> Result<f64, <f64 as std::str::FromStr>::Err> {
         match s.to_lowercase().as_str() {
@@ -231,7 +296,8 @@ mod prometheus_parse {
 
     pub fn parse_at<'a>(lines: impl Iterator<Item = &'a str>) -> Vec<MetricSample> {
         let mut types: HashMap<String, SampleType> = HashMap::new();
-        let mut buckets: HashMap<(String, BTreeMap<String, String>), MetricSample> = HashMap::new();
+        let mut buckets: BTreeMap<(String, BTreeMap<String, String>), MetricSample> =
+            BTreeMap::new();
         let mut samples: Vec<MetricSample> = vec![];
 
         for read_line in lines {
@@ -268,7 +334,7 @@ mod prometheus_parse {
                                     .entry((metric_name.to_string(), labels.clone()))
                                     .or_insert(MetricSample {
                                         name: metric_name.to_string(),
-                                        labels: labels.into_iter().collect(),
+                                        labels,
                                         value: MetricValue::Histogram(vec![]),
                                     });
 
@@ -287,7 +353,7 @@ mod prometheus_parse {
                                     .entry((metric_name.to_string(), labels.clone()))
                                     .or_insert(MetricSample {
                                         name: metric_name.to_string(),
-                                        labels: labels.into_iter().collect(),
+                                        labels,
                                         value: MetricValue::Summary(vec![]),
                                     });
 
@@ -300,7 +366,7 @@ mod prometheus_parse {
                             }
                         }
                         (ty, labels) => {
-                            let mut labels_map = HashMap::new();
+                            let mut labels_map = BTreeMap::new();
                             if let Some(labels) = labels {
                                 for kv in labels.split(',') {
                                     let kvpair = kv.split('=').collect::<Vec<_>>();
@@ -337,7 +403,7 @@ mod prometheus_parse {
                 _ => {}
             }
         }
-        samples.extend(buckets.drain().map(|(_k, v)| v).collect::<Vec<_>>());
+        samples.extend(buckets.into_values());
         samples
     }
 }
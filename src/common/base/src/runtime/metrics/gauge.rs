@@ -108,7 +108,7 @@ impl EncodeMetric for Gauge {
 
 impl DatabendMetric for Gauge {
     fn reset_metric(&self) {
-        self.inner.inc_by(-self.inner.get());
+        self.inner.set(0);
     }
 
     fn sample(&self, name: &str, samples: &mut Vec<MetricSample>) {
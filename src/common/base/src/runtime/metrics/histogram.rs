@@ -146,7 +146,7 @@ impl DatabendMetric for Histogram {
     }
 
     fn sample(&self, name: &str, samples: &mut Vec<MetricSample>) {
-        let (_sum, _count, buckets) = self.get();
+        let (sum, count, buckets) = self.get();
 
         let mut histogram_count = vec![];
         let mut cumulative = 0;
@@ -164,5 +164,17 @@ impl DatabendMetric for Histogram {
             labels: Default::default(),
             value: MetricValue::Histogram(histogram_count),
         });
+
+        // Same as the `_sum` and `_count` lines of the prometheus exposition.
+        samples.push(MetricSample {
+            name: format!("{}_sum", name),
+            labels: Default::default(),
+            value: MetricValue::Untyped(sum),
+        });
+        samples.push(MetricSample {
+            name: format!("{}_count", name),
+            labels: Default::default(),
+            value: MetricValue::Untyped(count as f64),
+        });
     }
 }
@@ -16,6 +16,7 @@ use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::LazyLock;
+use std::time::Duration;
 
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -52,6 +53,9 @@ pub static GLOBAL_METRICS_REGISTRY: LazyLock<GlobalRegistry> =
 pub trait DatabendMetric {
     fn reset_metric(&self);
 
+    /// Drop the label sets not updated within `idle`, only meaningful for metric families.
+    fn evict_idle_labels(&self, _idle: Duration) {}
+
     fn sample(&self, name: &str, samples: &mut Vec<MetricSample>);
 }
 
@@ -74,6 +78,17 @@ struct GlobalMetric {
 struct GlobalRegistryInner {
     registry: Registry,
     metrics: Vec<GlobalMetric>,
+    label_retention: Option<Duration>,
+}
+
+impl GlobalRegistryInner {
+    fn evict_idle_labels(&self) {
+        if let Some(retention) = self.label_retention {
+            for metric in &self.metrics {
+                metric.metric.evict_idle_labels(retention);
+            }
+        }
+    }
 }
 
 pub struct GlobalRegistry {
@@ -88,6 +103,7 @@ impl GlobalRegistry {
             inner: Mutex::new(GlobalRegistryInner {
                 metrics: vec![],
                 registry,
+                label_retention: None,
             }),
         }
     }
@@ -129,6 +145,14 @@ impl GlobalRegistry {
         MutexGuard::map(guard, |f| &mut f.registry)
     }
 
+    /// Label sets of the metric families not updated within the retention are
+    /// dropped before each scrape, `None` keeps them forever.
+    pub fn set_label_retention(&self, retention: Option<Duration>) {
+        self.inner.lock().label_retention = retention;
+    }
+
+    /// Reset all the registered metrics, the registry lock is held so that a
+    /// concurrent scrape sees either all or none of the metrics reset.
     pub fn reset(&self) {
         let global_registry = self.inner.lock();
         for metric in &global_registry.metrics {
@@ -138,6 +162,7 @@ impl GlobalRegistry {
 
     pub fn dump_sample(&self) -> Result<Vec<MetricSample>> {
         let global_registry = self.inner.lock();
+        global_registry.evict_idle_labels();
 
         let mut samples = Vec::with_capacity(global_registry.metrics.len());
 
@@ -152,8 +177,11 @@ impl GlobalRegistry {
     }
 
     pub fn render_metrics(&self) -> Result<String> {
+        let global_registry = self.inner.lock();
+        global_registry.evict_idle_labels();
+
         let mut text = String::new();
-        match prometheus_client::encoding::text::encode(&mut text, &self.inner.lock().registry) {
+        match prometheus_client::encoding::text::encode(&mut text, &global_registry.registry) {
             Ok(_) => Ok(text),
             Err(err) => Err(ErrorCode::Internal(format!(
                 "Failed to encode metrics: {}",
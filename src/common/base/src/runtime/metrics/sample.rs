@@ -12,14 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct MetricSample {
     pub name: String,
-    #[serde(skip_serializing_if = "std::collections::HashMap::is_empty")]
+    /// Ordered by the label name, so that the same label set always renders the same.
+    #[serde(skip_serializing_if = "std::collections::BTreeMap::is_empty")]
     #[serde(default)]
-    pub labels: HashMap<String, String>,
+    pub labels: BTreeMap<String, String>,
     pub value: MetricValue,
 }
 
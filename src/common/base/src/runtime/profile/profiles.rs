@@ -45,6 +45,12 @@ pub enum ProfileStatisticsName {
     SpillReadTime,
     RuntimeFilterPruneParts,
     MemoryUsage,
+    InputRows,
+    InputBytes,
+    /// The number of calls to `process`, the sync work of the processors
+    ProcessCount,
+    /// The number of calls to `async_process` that completed
+    AsyncProcessCount,
 }
 
 #[derive(Clone, Hash, Eq, PartialEq, serde::Serialize, serde::Deserialize, Debug)]
@@ -242,6 +248,34 @@ pub fn get_statistics_desc() -> Arc<BTreeMap<ProfileStatisticsName, ProfileDesc>
                 index: ProfileStatisticsName::MemoryUsage as usize,
                 unit: StatisticsUnit::Bytes,
                 plain_statistics: false,
+            }),
+            (ProfileStatisticsName::InputRows, ProfileDesc {
+                display_name: "input rows",
+                desc: "The number of rows from the previous physical plan input to the physical plan",
+                index: ProfileStatisticsName::InputRows as usize,
+                unit: StatisticsUnit::Rows,
+                plain_statistics: true,
+            }),
+            (ProfileStatisticsName::InputBytes, ProfileDesc {
+                display_name: "input bytes",
+                desc: "The number of bytes from the previous physical plan input to the physical plan",
+                index: ProfileStatisticsName::InputBytes as usize,
+                unit: StatisticsUnit::Bytes,
+                plain_statistics: true,
+            }),
+            (ProfileStatisticsName::ProcessCount, ProfileDesc {
+                display_name: "process calls",
+                desc: "The number of calls to process of the processors",
+                index: ProfileStatisticsName::ProcessCount as usize,
+                unit: StatisticsUnit::Count,
+                plain_statistics: true,
+            }),
+            (ProfileStatisticsName::AsyncProcessCount, ProfileDesc {
+                display_name: "async process calls",
+                desc: "The number of completed calls to async_process of the processors",
+                index: ProfileStatisticsName::AsyncProcessCount as usize,
+                unit: StatisticsUnit::Count,
+                plain_statistics: true,
             })
         ]))
     }).clone()
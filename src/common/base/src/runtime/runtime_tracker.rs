@@ -54,6 +54,7 @@ use pin_project_lite::pin_project;
 
 use crate::runtime::memory::MemStat;
 use crate::runtime::memory::OutOfLimit;
+use crate::runtime::memory::PipelineMemory;
 use crate::runtime::memory::StatBuffer;
 use crate::runtime::metrics::ScopedRegistry;
 use crate::runtime::profile::Profile;
@@ -104,6 +105,7 @@ pub struct TrackingPayload {
     pub profile: Option<Arc<Profile>>,
     pub mem_stat: Option<Arc<MemStat>>,
     pub metrics: Option<Arc<ScopedRegistry>>,
+    pub pipeline_memory: Option<Arc<PipelineMemory>>,
 }
 
 pub struct TrackingGuard {
@@ -164,6 +166,7 @@ impl ThreadTracker {
                 metrics: None,
                 mem_stat: None,
                 query_id: None,
+                pipeline_memory: None,
             },
         }
     }
@@ -283,6 +286,10 @@ impl ThreadTracker {
         }
     }
 
+    pub fn pipeline_memory() -> Option<Arc<PipelineMemory>> {
+        TRACKER.with(|tracker| tracker.borrow().payload.pipeline_memory.clone())
+    }
+
     pub fn query_id() -> Option<&'static String> {
         TRACKER.with(|tracker| {
             tracker
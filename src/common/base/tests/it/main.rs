@@ -24,6 +24,7 @@ mod pool_retry;
 mod progress;
 mod range_merger;
 mod runtime;
+mod server_warnings;
 mod stoppable;
 mod string;
 
@@ -1 +1,72 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
 
+use std::time::Duration;
+
+use databend_common_base::base::GlobalUniqName;
+use databend_common_base::runtime::metrics::register_counter_family;
+use databend_common_base::runtime::metrics::GLOBAL_METRICS_REGISTRY;
+use databend_common_exception::Result;
+
+#[test]
+fn test_family_evict_idle_labels() -> Result<()> {
+    let uniq_metric_name = GlobalUniqName::unique();
+    let family = register_counter_family::<Vec<(&'static str, &'static str)>>(&uniq_metric_name);
+    family.get_or_create(&vec![("table", "t1")]).inc();
+    family.get_or_create(&vec![("table", "t2")]).inc();
+
+    // Both label sets were just updated.
+    assert_eq!(family.evict_idle(Duration::from_secs(3600)), 0);
+    assert_eq!(family.len(), 2);
+
+    let exposition = GLOBAL_METRICS_REGISTRY.render_metrics()?;
+    assert!(exposition.contains(&format!("{}_total{{table=\"t1\"}}", uniq_metric_name)));
+
+    assert_eq!(family.evict_idle(Duration::ZERO), 2);
+    assert!(family.is_empty());
+
+    let samples = GLOBAL_METRICS_REGISTRY.dump_sample()?;
+    assert!(
+        samples
+            .iter()
+            .all(|s| !s.name.starts_with(&uniq_metric_name))
+    );
+    let exposition = GLOBAL_METRICS_REGISTRY.render_metrics()?;
+    assert!(!exposition.contains(&format!("{}_total{{", uniq_metric_name)));
+
+    // An evicted label set starts over once updated again.
+    family.get_or_create(&vec![("table", "t1")]).inc();
+    assert_eq!(family.get_or_create(&vec![("table", "t1")]).get(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn test_family_keep_held_labels() -> Result<()> {
+    let uniq_metric_name = GlobalUniqName::unique();
+    let family = register_counter_family::<Vec<(&'static str, &'static str)>>(&uniq_metric_name);
+
+    // The counter is updated through the held handle, without looking it up again.
+    let counter = family.get_or_create(&vec![("table", "t1")]);
+    counter.inc();
+    assert_eq!(family.evict_idle(Duration::ZERO), 0);
+    counter.inc();
+    assert_eq!(family.get_or_create(&vec![("table", "t1")]).get(), 2);
+
+    drop(counter);
+    assert_eq!(family.evict_idle(Duration::ZERO), 1);
+    assert!(family.is_empty());
+
+    Ok(())
+}
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 use databend_common_base::base::GlobalUniqName;
 use databend_common_base::runtime::metrics::register_counter;
@@ -287,32 +287,32 @@ fn test_tracking_scoped_family_counter_metrics() -> Result<()> {
         assert_contain_metrics(GLOBAL_METRICS_REGISTRY.dump_sample()?, vec![
             MetricSample {
                 name: uniq_metric_name.clone(),
-                labels: HashMap::from([("TEST_LABEL1".to_string(), "1".to_string())]),
+                labels: BTreeMap::from([("TEST_LABEL1".to_string(), "1".to_string())]),
                 value: MetricValue::Counter(2_f64),
             },
             MetricSample {
                 name: uniq_metric_name.clone(),
-                labels: HashMap::from([("TEST_LABEL1".to_string(), "2".to_string())]),
+                labels: BTreeMap::from([("TEST_LABEL1".to_string(), "2".to_string())]),
                 value: MetricValue::Counter(2_f64),
             },
             MetricSample {
                 name: uniq_metric_name.clone(),
-                labels: HashMap::from([("TEST_LABEL1".to_string(), "3".to_string())]),
+                labels: BTreeMap::from([("TEST_LABEL1".to_string(), "3".to_string())]),
                 value: MetricValue::Counter(1_f64),
             },
             MetricSample {
                 name: uniq_metric_name.clone(),
-                labels: HashMap::from([("TEST_LABEL2".to_string(), "1".to_string())]),
+                labels: BTreeMap::from([("TEST_LABEL2".to_string(), "1".to_string())]),
                 value: MetricValue::Counter(2_f64),
             },
             MetricSample {
                 name: uniq_metric_name.clone(),
-                labels: HashMap::from([("TEST_LABEL3".to_string(), "1".to_string())]),
+                labels: BTreeMap::from([("TEST_LABEL3".to_string(), "1".to_string())]),
                 value: MetricValue::Counter(1_f64),
             },
             MetricSample {
                 name: uniq_metric_name.clone(),
-                labels: HashMap::from([("TEST_LABEL4".to_string(), "1".to_string())]),
+                labels: BTreeMap::from([("TEST_LABEL4".to_string(), "1".to_string())]),
                 value: MetricValue::Counter(1_f64),
             },
         ]);
@@ -320,32 +320,32 @@ fn test_tracking_scoped_family_counter_metrics() -> Result<()> {
         assert_contain_metrics(scoped_registry.dump_sample()?, vec![
             MetricSample {
                 name: uniq_metric_name.clone(),
-                labels: HashMap::from([("TEST_LABEL1".to_string(), "1".to_string())]),
+                labels: BTreeMap::from([("TEST_LABEL1".to_string(), "1".to_string())]),
                 value: MetricValue::Counter(1_f64),
             },
             MetricSample {
                 name: uniq_metric_name.clone(),
-                labels: HashMap::from([("TEST_LABEL1".to_string(), "2".to_string())]),
+                labels: BTreeMap::from([("TEST_LABEL1".to_string(), "2".to_string())]),
                 value: MetricValue::Counter(1_f64),
             },
             MetricSample {
                 name: uniq_metric_name.clone(),
-                labels: HashMap::from([("TEST_LABEL1".to_string(), "3".to_string())]),
+                labels: BTreeMap::from([("TEST_LABEL1".to_string(), "3".to_string())]),
                 value: MetricValue::Counter(1_f64),
             },
             MetricSample {
                 name: uniq_metric_name.clone(),
-                labels: HashMap::from([("TEST_LABEL1".to_string(), "2".to_string())]),
+                labels: BTreeMap::from([("TEST_LABEL1".to_string(), "2".to_string())]),
                 value: MetricValue::Counter(1_f64),
             },
             MetricSample {
                 name: uniq_metric_name.clone(),
-                labels: HashMap::from([("TEST_LABEL2".to_string(), "1".to_string())]),
+                labels: BTreeMap::from([("TEST_LABEL2".to_string(), "1".to_string())]),
                 value: MetricValue::Counter(1_f64),
             },
             MetricSample {
                 name: uniq_metric_name.clone(),
-                labels: HashMap::from([("TEST_LABEL4".to_string(), "1".to_string())]),
+                labels: BTreeMap::from([("TEST_LABEL4".to_string(), "1".to_string())]),
                 value: MetricValue::Counter(1_f64),
             },
         ]);
@@ -360,32 +360,32 @@ fn test_tracking_scoped_family_counter_metrics() -> Result<()> {
     assert_contain_metrics(GLOBAL_METRICS_REGISTRY.dump_sample()?, vec![
         MetricSample {
             name: uniq_metric_name.clone(),
-            labels: HashMap::from([("TEST_LABEL1".to_string(), "1".to_string())]),
+            labels: BTreeMap::from([("TEST_LABEL1".to_string(), "1".to_string())]),
             value: MetricValue::Counter(3_f64),
         },
         MetricSample {
             name: uniq_metric_name.clone(),
-            labels: HashMap::from([("TEST_LABEL1".to_string(), "2".to_string())]),
+            labels: BTreeMap::from([("TEST_LABEL1".to_string(), "2".to_string())]),
             value: MetricValue::Counter(3_f64),
         },
         MetricSample {
             name: uniq_metric_name.clone(),
-            labels: HashMap::from([("TEST_LABEL1".to_string(), "3".to_string())]),
+            labels: BTreeMap::from([("TEST_LABEL1".to_string(), "3".to_string())]),
             value: MetricValue::Counter(1_f64),
         },
         MetricSample {
             name: uniq_metric_name.clone(),
-            labels: HashMap::from([("TEST_LABEL2".to_string(), "1".to_string())]),
+            labels: BTreeMap::from([("TEST_LABEL2".to_string(), "1".to_string())]),
             value: MetricValue::Counter(3_f64),
         },
         MetricSample {
             name: uniq_metric_name.clone(),
-            labels: HashMap::from([("TEST_LABEL3".to_string(), "1".to_string())]),
+            labels: BTreeMap::from([("TEST_LABEL3".to_string(), "1".to_string())]),
             value: MetricValue::Counter(2_f64),
         },
         MetricSample {
             name: uniq_metric_name.clone(),
-            labels: HashMap::from([("TEST_LABEL4".to_string(), "1".to_string())]),
+            labels: BTreeMap::from([("TEST_LABEL4".to_string(), "1".to_string())]),
             value: MetricValue::Counter(1_f64),
         },
     ]);
@@ -393,32 +393,32 @@ fn test_tracking_scoped_family_counter_metrics() -> Result<()> {
     assert_contain_metrics(scoped_registry.dump_sample()?, vec![
         MetricSample {
             name: uniq_metric_name.clone(),
-            labels: HashMap::from([("TEST_LABEL1".to_string(), "1".to_string())]),
+            labels: BTreeMap::from([("TEST_LABEL1".to_string(), "1".to_string())]),
             value: MetricValue::Counter(1_f64),
         },
         MetricSample {
             name: uniq_metric_name.clone(),
-            labels: HashMap::from([("TEST_LABEL1".to_string(), "2".to_string())]),
+            labels: BTreeMap::from([("TEST_LABEL1".to_string(), "2".to_string())]),
             value: MetricValue::Counter(1_f64),
         },
         MetricSample {
             name: uniq_metric_name.clone(),
-            labels: HashMap::from([("TEST_LABEL1".to_string(), "3".to_string())]),
+            labels: BTreeMap::from([("TEST_LABEL1".to_string(), "3".to_string())]),
             value: MetricValue::Counter(1_f64),
         },
         MetricSample {
             name: uniq_metric_name.clone(),
-            labels: HashMap::from([("TEST_LABEL1".to_string(), "2".to_string())]),
+            labels: BTreeMap::from([("TEST_LABEL1".to_string(), "2".to_string())]),
             value: MetricValue::Counter(1_f64),
         },
         MetricSample {
             name: uniq_metric_name.clone(),
-            labels: HashMap::from([("TEST_LABEL2".to_string(), "1".to_string())]),
+            labels: BTreeMap::from([("TEST_LABEL2".to_string(), "1".to_string())]),
             value: MetricValue::Counter(1_f64),
         },
         MetricSample {
             name: uniq_metric_name.clone(),
-            labels: HashMap::from([("TEST_LABEL4".to_string(), "1".to_string())]),
+            labels: BTreeMap::from([("TEST_LABEL4".to_string(), "1".to_string())]),
             value: MetricValue::Counter(1_f64),
         },
     ]);
@@ -0,0 +1,60 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use databend_common_base::base::ServerWarnings;
+use databend_common_base::base::WarningSeverity;
+
+#[test]
+fn test_server_warnings_dedup() {
+    let warnings = ServerWarnings::create(10, Duration::from_secs(600));
+
+    warnings.warn("fake_component", "cache init fallback");
+    warnings.warn("fake_component", "cache init fallback");
+    warnings.error("fake_component", "cache init fallback");
+    warnings.warn("other_component", "cache init fallback");
+    warnings.warn("fake_component", "cache init fallback");
+
+    let list = warnings.list();
+    let rows = list
+        .iter()
+        .map(|w| (w.severity, w.component.as_str(), w.count))
+        .collect::<Vec<_>>();
+    assert_eq!(rows, vec![
+        (WarningSeverity::Warning, "fake_component", 3),
+        (WarningSeverity::Error, "fake_component", 1),
+        (WarningSeverity::Warning, "other_component", 1),
+    ]);
+    assert!(list[0].first_seen <= list[0].last_seen);
+
+    warnings.clear();
+    assert!(warnings.list().is_empty());
+}
+
+#[test]
+fn test_server_warnings_capacity() {
+    let warnings = ServerWarnings::create(2, Duration::from_secs(600));
+
+    warnings.warn("fake_component", "first");
+    warnings.warn("fake_component", "second");
+    warnings.warn("fake_component", "third");
+
+    let messages = warnings
+        .list()
+        .into_iter()
+        .map(|w| w.message)
+        .collect::<Vec<_>>();
+    assert_eq!(messages, vec!["second", "third"]);
+}
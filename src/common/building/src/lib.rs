@@ -63,7 +63,10 @@ pub fn set_env_config() {
     EmitBuilder::builder()
         .fail_on_error()
         .build_timestamp()
+        .cargo_debug()
         .cargo_features()
+        .cargo_opt_level()
+        .cargo_target_triple()
         .rustc_semver()
         .git_sha(true)
         .emit()
@@ -89,6 +89,9 @@ where
     /// by the cache.
     fn size(&self) -> u64;
 
+    /// Returns the number of key-value pairs removed by the policy since the cache is created.
+    fn evictions(&self) -> u64;
+
     /// Removes all key-value pairs from the cache.
     fn clear(&mut self);
 }
@@ -78,6 +78,7 @@ pub struct LruCache<
     current_measure: M::Measure,
     max_capacity: u64,
     meter: M,
+    evictions: u64,
 }
 
 impl<K: Eq + Hash, V> LruCache<K, V> {
@@ -95,6 +96,7 @@ impl<K: Eq + Hash, V> LruCache<K, V> {
             current_measure: (),
             max_capacity: capacity,
             meter: Count,
+            evictions: 0,
         }
     }
 }
@@ -139,6 +141,7 @@ impl<K: Eq + Hash, V, M: CountableMeter<K, V>> LruCache<K, V, DefaultHashBuilder
             current_measure: Default::default(),
             max_capacity: capacity,
             meter,
+            evictions: 0,
         }
     }
 }
@@ -151,6 +154,7 @@ impl<K: Eq + Hash, V, S: BuildHasher> LruCache<K, V, S, Count> {
             current_measure: (),
             max_capacity: capacity,
             meter: Count,
+            evictions: 0,
         }
     }
 }
@@ -166,6 +170,7 @@ impl<K: Eq + Hash, V, S: BuildHasher, M: CountableMeter<K, V>> Cache<K, V, S, M>
             current_measure: Default::default(),
             max_capacity: capacity,
             meter,
+            evictions: 0,
         }
     }
 
@@ -347,6 +352,7 @@ impl<K: Eq + Hash, V, S: BuildHasher, M: CountableMeter<K, V>> Cache<K, V, S, M>
             self.current_measure = self
                 .meter
                 .sub(self.current_measure, self.meter.measure(&k, &v));
+            self.evictions += 1;
             (k, v)
         })
     }
@@ -424,6 +430,26 @@ impl<K: Eq + Hash, V, S: BuildHasher, M: CountableMeter<K, V>> Cache<K, V, S, M>
         self.map.is_empty()
     }
 
+    /// Returns the number of key-value pairs removed by `pop_by_policy`, including the ones
+    /// removed to make room for new pairs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use databend_common_cache::{Cache, LruCache};
+    ///
+    /// let mut cache = LruCache::new(2);
+    ///
+    /// cache.put(1, "a");
+    /// cache.put(2, "b");
+    /// cache.put(3, "c");
+    ///
+    /// assert_eq!(cache.evictions(), 1);
+    /// ```
+    fn evictions(&self) -> u64 {
+        self.evictions
+    }
+
     /// Removes all key-value pairs from the cache.
     fn clear(&mut self) {
         self.map.clear();
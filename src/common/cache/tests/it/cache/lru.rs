@@ -81,6 +81,22 @@ fn test_change_capacity() {
     assert_eq!(cache.capacity(), 1);
 }
 
+#[test]
+fn test_evictions() {
+    let mut cache = LruCache::new(2);
+    cache.put(1, 10);
+    cache.put(2, 20);
+    cache.put(2, 21);
+    assert_eq!(cache.evictions(), 0);
+    cache.put(3, 30);
+    assert_eq!(cache.evictions(), 1);
+    cache.pop(&2);
+    assert_eq!(cache.evictions(), 1);
+    cache.put(4, 40);
+    cache.set_capacity(1);
+    assert_eq!(cache.evictions(), 2);
+}
+
 #[test]
 fn test_debug() {
     let mut cache = LruCache::new(3);
@@ -104,6 +104,7 @@ build_exceptions! {
     CannotListenerPort(1045),
     BadBytes(1046),
     InitPrometheusFailure(1047),
+    TooManyQueuedQueries(1048),
     Overflow(1049),
     AuthenticateFailure(1051),
     TLSConfigurationFailure(1052),
@@ -158,6 +159,7 @@ build_exceptions! {
     WrongSequenceCount(1125),
     UnknownSequence(1126),
     UnknownQuery(1127),
+    PipelineMemoryExceeded(1128),
 
     // Data Related Errors
 
@@ -366,12 +368,16 @@ build_exceptions! {
     UnknownVariable(2801),
     OnlySupportAsciiChars(2802),
     WrongValueForVariable(2803),
+    ImmutableVariable(2804),
 
     // Tenant quota error codes.
     IllegalTenantQuotaFormat(2901),
     TenantQuotaUnknown(2902),
     TenantQuotaExceeded(2903),
 
+    // User quota error codes.
+    UserQuotaExceeded(2911),
+
     // Script error codes.
     ScriptSemanticError(3001),
     ScriptExecutionError(3002),
@@ -399,6 +405,8 @@ build_exceptions! {
     CurrentTransactionIsAborted(4002),
     TransactionTimeout(4003),
     InvalidSessionState(4004),
+    ImplicitCommitInTransaction(4005),
+    NonTransactionalWriteInTransaction(4006),
 }
 
 // Service errors [5001,6000].
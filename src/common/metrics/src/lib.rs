@@ -24,6 +24,7 @@ pub type VecLabels = Vec<(&'static str, String)>;
 
 pub use crate::metrics::cache;
 pub use crate::metrics::cluster;
+pub use crate::metrics::flight_sql;
 /// Metrics.
 pub use crate::metrics::http;
 pub use crate::metrics::interpreter;
@@ -0,0 +1,43 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::LazyLock;
+
+use databend_common_base::runtime::metrics::register_counter;
+use databend_common_base::runtime::metrics::register_gauge;
+use databend_common_base::runtime::metrics::Counter;
+use databend_common_base::runtime::metrics::Gauge;
+
+pub static FLIGHT_SQL_OPEN_CURSORS: LazyLock<Gauge> =
+    LazyLock::new(|| register_gauge("flight_sql_open_cursors"));
+pub static FLIGHT_SQL_THROTTLED_STATEMENTS: LazyLock<Counter> =
+    LazyLock::new(|| register_counter("flight_sql_throttled_statements"));
+pub static FLIGHT_SQL_THROTTLED_METADATA_REQUESTS: LazyLock<Counter> =
+    LazyLock::new(|| register_counter("flight_sql_throttled_metadata_requests"));
+
+pub fn incr_flight_sql_open_cursors() {
+    FLIGHT_SQL_OPEN_CURSORS.inc();
+}
+
+pub fn decr_flight_sql_open_cursors() {
+    FLIGHT_SQL_OPEN_CURSORS.dec();
+}
+
+pub fn incr_flight_sql_throttled_statements() {
+    FLIGHT_SQL_THROTTLED_STATEMENTS.inc();
+}
+
+pub fn incr_flight_sql_throttled_metadata_requests() {
+    FLIGHT_SQL_THROTTLED_METADATA_REQUESTS.inc();
+}
@@ -15,13 +15,25 @@
 use std::sync::LazyLock;
 use std::time::Duration;
 
+use databend_common_base::runtime::metrics::register_counter;
+use databend_common_base::runtime::metrics::register_counter_family;
 use databend_common_base::runtime::metrics::register_histogram_in_milliseconds;
+use databend_common_base::runtime::metrics::Counter;
+use databend_common_base::runtime::metrics::FamilyCounter;
 use databend_common_base::runtime::metrics::Histogram;
 
+use crate::VecLabels;
+
 pub static MYSQL_PROCESSOR_REQUEST_DURATION: LazyLock<Histogram> =
     LazyLock::new(|| register_histogram_in_milliseconds("mysql_process_request_duration_ms"));
 pub static MYSQL_INTERPRETER_USEDTIME: LazyLock<Histogram> =
     LazyLock::new(|| register_histogram_in_milliseconds("mysql_interpreter_usedtime_ms"));
+pub static MYSQL_FEDERATED_RULE_MATCHED: LazyLock<FamilyCounter<VecLabels>> =
+    LazyLock::new(|| register_counter_family("federated_rule_matched"));
+pub static MYSQL_FEDERATED_MISS_CANDIDATE: LazyLock<Counter> =
+    LazyLock::new(|| register_counter("federated_miss_candidate"));
+
+const LABEL_RULE: &str = "rule";
 
 pub fn observe_mysql_process_request_duration(duration: Duration) {
     MYSQL_PROCESSOR_REQUEST_DURATION.observe(duration.as_millis() as f64);
@@ -30,3 +42,12 @@ pub fn observe_mysql_process_request_duration(duration: Duration) {
 pub fn observe_mysql_interpreter_used_time(duration: Duration) {
     MYSQL_INTERPRETER_USEDTIME.observe(duration.as_millis() as f64);
 }
+
+pub fn metrics_inc_federated_rule_matched(rule: &str) {
+    let labels = &vec![(LABEL_RULE, rule.to_string())];
+    MYSQL_FEDERATED_RULE_MATCHED.get_or_create(labels).inc();
+}
+
+pub fn metrics_inc_federated_miss_candidate() {
+    MYSQL_FEDERATED_MISS_CANDIDATE.inc();
+}
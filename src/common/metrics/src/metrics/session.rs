@@ -36,6 +36,8 @@ pub static SESSION_QUEUE_ACQUIRE_ERROR_COUNT: LazyLock<Counter> =
     LazyLock::new(|| register_counter("session_queue_acquire_error_count"));
 pub static SESSION_QUEUE_ACQUIRE_TIMEOUT_COUNT: LazyLock<Counter> =
     LazyLock::new(|| register_counter("session_queue_acquire_timeout_count"));
+pub static SESSION_QUEUE_REJECT_COUNT: LazyLock<Counter> =
+    LazyLock::new(|| register_counter("session_queue_reject_count"));
 pub static SESSION_QUEUE_ACQUIRE_DURATION_MS: LazyLock<Histogram> =
     LazyLock::new(|| register_histogram_in_milliseconds("session_queue_acquire_duration_ms"));
 
@@ -70,6 +72,10 @@ pub fn incr_session_queue_acquire_timeout_count() {
     SESSION_QUEUE_ACQUIRE_TIMEOUT_COUNT.inc();
 }
 
+pub fn incr_session_queue_reject_count() {
+    SESSION_QUEUE_REJECT_COUNT.inc();
+}
+
 pub fn record_session_queue_acquire_duration_ms(duration: Duration) {
     SESSION_QUEUE_ACQUIRE_DURATION_MS.observe(duration.as_millis() as f64);
 }
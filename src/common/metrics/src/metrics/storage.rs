@@ -288,6 +288,9 @@ static AGG_INDEX_WRITE_BYTES: LazyLock<Counter> =
 static AGG_INDEX_WRITE_MILLISECONDS: LazyLock<Histogram> =
     LazyLock::new(|| register_histogram_in_milliseconds("fuse_aggregate_index_write_milliseconds"));
 
+// Spill metrics.
+static SPILL_FILES_BYTES: LazyLock<Gauge> = LazyLock::new(|| register_gauge("spill_files_bytes"));
+
 /// Common metrics.
 pub fn metrics_inc_omit_filter_rowgroups(c: u64) {
     OMIT_FILTER_ROWGROUPS.inc_by(c);
@@ -790,3 +793,8 @@ pub fn metrics_inc_agg_index_write_bytes(c: u64) {
 pub fn metrics_inc_agg_index_write_milliseconds(c: u64) {
     AGG_INDEX_WRITE_MILLISECONDS.observe(c as f64);
 }
+
+/// Spill metrics.
+pub fn metrics_set_spill_files_bytes(c: u64) {
+    SPILL_FILES_BYTES.set(c as i64);
+}
@@ -69,6 +69,7 @@ mod copy;
 mod merge;
 mod metrics_layer;
 mod multi_table_insert;
+mod spill_files;
 mod statistics;
 
 pub use copy::CopyStatus;
@@ -76,5 +77,7 @@ pub use copy::FileParseError;
 pub use copy::FileStatus;
 pub use merge::MergeStatus;
 pub use multi_table_insert::MultiTableInsertStatus;
+pub use spill_files::SpillFileInfo;
+pub use spill_files::SpillFiles;
 pub use statistics::Datum;
 pub use statistics::F64;
@@ -0,0 +1,131 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::LazyLock;
+
+use chrono::DateTime;
+use chrono::Utc;
+use dashmap::DashMap;
+use databend_common_metrics::storage::metrics_set_spill_files_bytes;
+
+static SPILL_FILES: LazyLock<SpillFiles> = LazyLock::new(SpillFiles::default);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpillFileInfo {
+    pub query_id: String,
+    /// The operator that spilled, e.g. `OrderBy` or `Aggregate`.
+    pub operator: String,
+    pub location: String,
+    pub bytes: u64,
+    pub created_at: DateTime<Utc>,
+}
+
+/// The spill files written by the queries of this node, keyed by query id.
+///
+/// Files are recorded once written, and forgotten once removed from the storage or once their
+/// query finishes. The files a finished query left behind are removed by the vacuum of the
+/// temporary files.
+#[derive(Default)]
+pub struct SpillFiles {
+    queries: DashMap<String, HashMap<String, SpillFileInfo>>,
+    total_bytes: AtomicU64,
+}
+
+impl SpillFiles {
+    pub fn instance() -> &'static SpillFiles {
+        &SPILL_FILES
+    }
+
+    pub fn record(&self, query_id: &str, operator: &str, location: &str, bytes: u64) {
+        let info = SpillFileInfo {
+            query_id: query_id.to_string(),
+            operator: operator.to_string(),
+            location: location.to_string(),
+            bytes,
+            created_at: Utc::now(),
+        };
+        let prev = self
+            .queries
+            .entry(info.query_id.clone())
+            .or_default()
+            .insert(info.location.clone(), info);
+
+        if let Some(prev) = prev {
+            self.total_bytes.fetch_sub(prev.bytes, Ordering::Relaxed);
+        }
+        let total = self.total_bytes.fetch_add(bytes, Ordering::Relaxed) + bytes;
+        metrics_set_spill_files_bytes(total);
+    }
+
+    /// Forget the removed files.
+    pub fn forget<S: AsRef<str>>(&self, locations: &[S]) {
+        self.forget_if(|file| locations.iter().any(|l| l.as_ref() == file.location));
+    }
+
+    /// Forget the files of a finished query.
+    pub fn forget_query(&self, query_id: &str) {
+        if let Some((_, files)) = self.queries.remove(query_id) {
+            let removed = files.values().map(|file| file.bytes).sum::<u64>();
+            if removed > 0 {
+                let total = self.total_bytes.fetch_sub(removed, Ordering::Relaxed) - removed;
+                metrics_set_spill_files_bytes(total);
+            }
+        }
+    }
+
+    /// Forget the files under a removed directory.
+    pub fn forget_prefix(&self, prefix: &str) {
+        self.forget_if(|file| file.location.starts_with(prefix));
+    }
+
+    pub fn list(&self) -> Vec<SpillFileInfo> {
+        self.queries
+            .iter()
+            .flat_map(|files| files.values().cloned().collect::<Vec<_>>())
+            .collect()
+    }
+
+    pub fn list_query(&self, query_id: &str) -> Vec<SpillFileInfo> {
+        self.queries
+            .get(query_id)
+            .map(|files| files.values().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    pub fn total_bytes(&self) -> u64 {
+        self.total_bytes.load(Ordering::Relaxed)
+    }
+
+    fn forget_if(&self, f: impl Fn(&SpillFileInfo) -> bool) {
+        let mut removed = 0;
+        self.queries.retain(|_, files| {
+            files.retain(|_, file| {
+                let remove = f(file);
+                if remove {
+                    removed += file.bytes;
+                }
+                !remove
+            });
+            !files.is_empty()
+        });
+
+        if removed > 0 {
+            let total = self.total_bytes.fetch_sub(removed, Ordering::Relaxed) - removed;
+            metrics_set_spill_files_bytes(total);
+        }
+    }
+}
@@ -67,6 +67,8 @@ use databend_common_meta_app::schema::ListIndexesReq;
 use databend_common_meta_app::schema::ListLockRevReq;
 use databend_common_meta_app::schema::ListLocksReq;
 use databend_common_meta_app::schema::ListTableReq;
+use databend_common_meta_app::schema::ListTableVersionsReply;
+use databend_common_meta_app::schema::ListTableVersionsReq;
 use databend_common_meta_app::schema::ListVirtualColumnsReq;
 use databend_common_meta_app::schema::LockInfo;
 use databend_common_meta_app::schema::LockMeta;
@@ -208,6 +210,15 @@ pub trait SchemaApi: Send + Sync {
     async fn get_table_history(&self, req: ListTableReq)
     -> Result<Vec<Arc<TableInfo>>, KVAppError>;
 
+    /// List the table ids ever bound to a table name with their current `TableMeta`, oldest first.
+    ///
+    /// Ids removed from the name's id list by vacuum are not returned,
+    /// a listed id whose meta has been purged is returned with `meta: None`.
+    async fn list_table_versions(
+        &self,
+        req: ListTableVersionsReq,
+    ) -> Result<ListTableVersionsReply, KVAppError>;
+
     async fn list_tables(&self, req: ListTableReq) -> Result<Vec<Arc<TableInfo>>, KVAppError>;
 
     /// Return TableMeta by table_id.
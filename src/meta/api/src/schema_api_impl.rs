@@ -138,6 +138,8 @@ use databend_common_meta_app::schema::ListIndexesReq;
 use databend_common_meta_app::schema::ListLockRevReq;
 use databend_common_meta_app::schema::ListLocksReq;
 use databend_common_meta_app::schema::ListTableReq;
+use databend_common_meta_app::schema::ListTableVersionsReply;
+use databend_common_meta_app::schema::ListTableVersionsReq;
 use databend_common_meta_app::schema::ListVirtualColumnsReq;
 use databend_common_meta_app::schema::LockInfo;
 use databend_common_meta_app::schema::LockMeta;
@@ -161,7 +163,10 @@ use databend_common_meta_app::schema::TableIndex;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableInfoFilter;
 use databend_common_meta_app::schema::TableMeta;
+use databend_common_meta_app::schema::TableMetaChange;
+use databend_common_meta_app::schema::TableMetaOperation;
 use databend_common_meta_app::schema::TableNameIdent;
+use databend_common_meta_app::schema::TableVersion;
 use databend_common_meta_app::schema::TruncateTableReply;
 use databend_common_meta_app::schema::TruncateTableReq;
 use databend_common_meta_app::schema::UndropDatabaseReply;
@@ -2409,6 +2414,72 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         return Ok(tb_info_list);
     }
 
+    #[logcall::logcall]
+    #[minitrace::trace]
+    async fn list_table_versions(
+        &self,
+        req: ListTableVersionsReq,
+    ) -> Result<ListTableVersionsReply, KVAppError> {
+        debug!(req :? =(&req); "SchemaApi: {}", func_name!());
+
+        let tenant_dbname_tbname = &req.inner;
+        let tenant_dbname = tenant_dbname_tbname.db_name_ident();
+
+        // Get db by name to ensure presence
+        let (_db_id_seq, db_id, _db_meta_seq, _db_meta) = get_db_or_err(
+            self,
+            &tenant_dbname,
+            format!("list_table_versions: {}", tenant_dbname.display()),
+        )
+        .await?;
+
+        let dbid_tbname_idlist = TableIdHistoryIdent {
+            database_id: db_id,
+            table_name: tenant_dbname_tbname.table_name.clone(),
+        };
+
+        let (tb_id_list_seq, tb_id_list_opt): (_, Option<TableIdList>) =
+            get_pb_value(self, &dbid_tbname_idlist).await?;
+
+        let tb_id_list = match tb_id_list_opt {
+            Some(list) if tb_id_list_seq != 0 => list,
+            _ => {
+                return Err(KVAppError::AppError(AppError::UnknownTable(
+                    UnknownTable::new(
+                        &tenant_dbname_tbname.table_name,
+                        format!("list_table_versions: {}", tenant_dbname_tbname),
+                    ),
+                )));
+            }
+        };
+
+        let mut versions = Vec::with_capacity(tb_id_list.len());
+
+        // Read the metas page by page, a name that is re-created often may have a long id list.
+        for ids in tb_id_list.id_list().chunks(DEFAULT_MGET_SIZE) {
+            let keys: Vec<String> = ids
+                .iter()
+                .map(|table_id| {
+                    TableId {
+                        table_id: *table_id,
+                    }
+                    .to_string_key()
+                })
+                .collect();
+
+            let tb_meta_vec: Vec<(u64, Option<TableMeta>)> = mget_pb_values(self, &keys).await?;
+            for (table_id, (tb_meta_seq, tb_meta)) in ids.iter().zip(tb_meta_vec) {
+                versions.push(TableVersion {
+                    table_id: *table_id,
+                    seq: tb_meta_seq,
+                    meta: if tb_meta_seq == 0 { None } else { tb_meta },
+                });
+            }
+        }
+
+        Ok(ListTableVersionsReply { versions })
+    }
+
     #[logcall::logcall]
     #[minitrace::trace]
     async fn list_tables(&self, req: ListTableReq) -> Result<Vec<Arc<TableInfo>>, KVAppError> {
@@ -3127,6 +3198,17 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
             let mut new_table_meta = req.0.new_table_meta.clone();
             new_table_meta.shared_by = table_meta.shared_by.clone();
 
+            // The changes are only recorded here, a schema change is an in place ALTER.
+            new_table_meta.changes = table_meta.changes.clone();
+            new_table_meta.changes_truncated = table_meta.changes_truncated;
+            if new_table_meta.schema != table_meta.schema {
+                new_table_meta.push_change(TableMetaChange {
+                    operation: TableMetaOperation::Alter,
+                    changed_on: Utc::now(),
+                    schema: Some(table_meta.schema.clone()),
+                });
+            }
+
             tbl_seqs.insert(req.0.table_id, *tb_meta_seq);
             txn_req
                 .condition
@@ -3757,7 +3839,8 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
 
         let lock_key = &req.lock_key;
         let lock_type = lock_key.lock_type();
-        let extra_info = lock_key.get_extra_info();
+        let mut extra_info = lock_key.get_extra_info();
+        extra_info.extend(req.extra_info.clone());
 
         let table_id = lock_key.get_table_id();
         let tbid = TableId { table_id };
@@ -5766,7 +5849,17 @@ async fn handle_undrop_table(
                     UndropTableWithNoDropTime::new(&tenant_dbname_tbname.table_name),
                 )));
             }
-            tb_meta.drop_on = None;
+            // The drop time is lost once reset, keep it with the undrop in the changes.
+            tb_meta.push_change(TableMetaChange {
+                operation: TableMetaOperation::Drop,
+                changed_on: tb_meta.drop_on.take().unwrap(),
+                schema: None,
+            });
+            tb_meta.push_change(TableMetaChange {
+                operation: TableMetaOperation::Undrop,
+                changed_on: Utc::now(),
+                schema: None,
+            });
 
             let txn_req = TxnRequest {
                 condition: vec![
@@ -96,6 +96,7 @@ use databend_common_meta_app::schema::ListIndexesByIdReq;
 use databend_common_meta_app::schema::ListIndexesReq;
 use databend_common_meta_app::schema::ListLockRevReq;
 use databend_common_meta_app::schema::ListTableReq;
+use databend_common_meta_app::schema::ListTableVersionsReq;
 use databend_common_meta_app::schema::ListVirtualColumnsReq;
 use databend_common_meta_app::schema::LockKey;
 use databend_common_meta_app::schema::RenameDatabaseReq;
@@ -114,6 +115,7 @@ use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableInfoFilter;
 use databend_common_meta_app::schema::TableMeta;
+use databend_common_meta_app::schema::TableMetaOperation;
 use databend_common_meta_app::schema::TableNameIdent;
 use databend_common_meta_app::schema::TableStatistics;
 use databend_common_meta_app::schema::TruncateTableReq;
@@ -325,6 +327,7 @@ impl SchemaApiTestSuite {
         suite
             .table_drop_undrop_list_history(&b.build().await)
             .await?;
+        suite.table_list_versions(&b.build().await).await?;
         suite
             .database_gc_out_of_retention_time(&b.build().await)
             .await?;
@@ -4956,6 +4959,186 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[minitrace::trace]
+    async fn table_list_versions<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant_name = "tenant_table_list_versions";
+        let tenant = Tenant::new_or_err(tenant_name, func_name!())?;
+
+        let db_name = "db1";
+        let tbl_name = "tb1";
+        let tbl_name_ident = TableNameIdent::new(tenant.clone(), db_name, tbl_name);
+
+        let table_meta = |number_type| TableMeta {
+            schema: Arc::new(TableSchema::new(vec![TableField::new(
+                "number",
+                TableDataType::Number(number_type),
+            )])),
+            engine: "JSON".to_string(),
+            created_on: Utc::now(),
+            ..TableMeta::default()
+        };
+
+        info!("--- prepare db");
+        let db_id = {
+            let plan = CreateDatabaseReq {
+                create_option: CreateOption::Create,
+                name_ident: DatabaseNameIdent::new(&tenant, db_name),
+                meta: DatabaseMeta::default(),
+            };
+            mt.create_database(plan).await?.db_id
+        };
+
+        info!("--- unknown table has no versions");
+        {
+            let res = mt
+                .list_table_versions(ListTableVersionsReq::new(&tenant, db_name, tbl_name))
+                .await;
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::UNKNOWN_TABLE,
+                ErrorCode::from(err).code(),
+                "list versions of an unknown table"
+            );
+        }
+
+        info!("--- create, drop and re-create the table");
+        let (first_id, second_id) = {
+            let first_id = mt
+                .create_table(CreateTableReq {
+                    create_option: CreateOption::Create,
+                    name_ident: tbl_name_ident.clone(),
+                    table_meta: table_meta(NumberDataType::UInt64),
+                    as_dropped: false,
+                })
+                .await?
+                .table_id;
+
+            mt.drop_table_by_id(DropTableByIdReq {
+                if_exists: false,
+                tenant: tenant.clone(),
+                db_id,
+                table_name: tbl_name.to_string(),
+                tb_id: first_id,
+            })
+            .await?;
+
+            let second_id = mt
+                .create_table(CreateTableReq {
+                    create_option: CreateOption::Create,
+                    name_ident: tbl_name_ident.clone(),
+                    table_meta: table_meta(NumberDataType::Int32),
+                    as_dropped: false,
+                })
+                .await?
+                .table_id;
+
+            (first_id, second_id)
+        };
+
+        info!("--- list the versions, oldest first");
+        {
+            let reply = mt
+                .list_table_versions(ListTableVersionsReq::new(&tenant, db_name, tbl_name))
+                .await?;
+
+            let ids = reply
+                .versions
+                .iter()
+                .map(|v| v.table_id)
+                .collect::<Vec<_>>();
+            assert_eq!(vec![first_id, second_id], ids);
+
+            let first = reply.versions[0].meta.as_ref().unwrap();
+            assert!(first.drop_on.is_some());
+            assert_eq!(
+                TableDataType::Number(NumberDataType::UInt64),
+                first.schema.fields()[0].data_type().clone()
+            );
+
+            let second = reply.versions[1].meta.as_ref().unwrap();
+            assert!(second.drop_on.is_none());
+            assert_eq!(
+                TableDataType::Number(NumberDataType::Int32),
+                second.schema.fields()[0].data_type().clone()
+            );
+            assert!(reply.versions.iter().all(|v| v.seq > 0));
+            assert!(
+                reply
+                    .versions
+                    .iter()
+                    .all(|v| v.meta.as_ref().unwrap().changes.is_empty())
+            );
+        }
+
+        info!("--- alter, drop and undrop the table in place");
+        {
+            let table = mt
+                .get_table((tenant_name, db_name, tbl_name).into())
+                .await?;
+            let mut new_table_meta = table.meta.clone();
+            new_table_meta.schema = table_meta(NumberDataType::Int64).schema;
+            let req = UpdateTableMetaReq {
+                table_id: second_id,
+                seq: MatchSeq::Exact(table.ident.seq),
+                new_table_meta,
+            };
+            mt.update_multi_table_meta(UpdateMultiTableMetaReq {
+                update_table_metas: vec![(req, table.as_ref().clone())],
+                ..Default::default()
+            })
+            .await?
+            .unwrap();
+
+            mt.drop_table_by_id(DropTableByIdReq {
+                if_exists: false,
+                tenant: tenant.clone(),
+                db_id,
+                table_name: tbl_name.to_string(),
+                tb_id: second_id,
+            })
+            .await?;
+            mt.undrop_table(UndropTableReq {
+                name_ident: tbl_name_ident.clone(),
+            })
+            .await?;
+
+            let reply = mt
+                .list_table_versions(ListTableVersionsReq::new(&tenant, db_name, tbl_name))
+                .await?;
+            let second = reply.versions[1].meta.as_ref().unwrap();
+            assert!(second.drop_on.is_none());
+            assert!(!second.changes_truncated);
+
+            let operations = second
+                .changes
+                .iter()
+                .map(|c| c.operation)
+                .collect::<Vec<_>>();
+            assert_eq!(
+                vec![
+                    TableMetaOperation::Alter,
+                    TableMetaOperation::Drop,
+                    TableMetaOperation::Undrop
+                ],
+                operations
+            );
+
+            // The ALTER keeps the schema it replaced.
+            assert_eq!(
+                TableDataType::Number(NumberDataType::Int32),
+                second.changes[0].schema.as_ref().unwrap().fields()[0]
+                    .data_type()
+                    .clone()
+            );
+            assert_eq!(
+                TableDataType::Number(NumberDataType::Int64),
+                second.schema.fields()[0].data_type().clone()
+            );
+        }
+
+        Ok(())
+    }
+
     #[minitrace::trace]
     async fn table_commit_table_meta<MT: SchemaApi + kvapi::AsKVApi<Error = MetaError>>(
         &self,
@@ -6620,6 +6803,7 @@ impl SchemaApiTestSuite {
                 user: "root".to_string(),
                 node: "node1".to_string(),
                 query_id: "query1".to_string(),
+                extra_info: BTreeMap::new(),
             };
             let res1 = mt.create_lock_revision(req1).await?;
 
@@ -6633,6 +6817,7 @@ impl SchemaApiTestSuite {
                 user: "root".to_string(),
                 node: "node1".to_string(),
                 query_id: "query2".to_string(),
+                extra_info: BTreeMap::new(),
             };
             let res2 = mt.create_lock_revision(req2).await?;
             assert!(res2.revision > res1.revision);
@@ -295,6 +295,8 @@ impl UserOption {
             UserOptionItem::SetPasswordPolicy(v) => self.password_policy = Some(v.clone()),
             UserOptionItem::UnsetPasswordPolicy => self.password_policy = None,
             UserOptionItem::Disabled(v) => self.disabled = Some(*v),
+            // Quotas are kept in `UserQuota`, see `UserQuota::apply`.
+            UserOptionItem::SetQuota(_) => {}
         }
     }
 }
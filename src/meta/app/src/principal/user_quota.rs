@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_ast::ast::UserQuotaItem;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -26,6 +27,15 @@ pub struct UserQuota {
 
     // The max storage(bytes) can be used(0 is no limited).
     pub max_storage_in_bytes: u64,
+
+    // The max queries can run at the same time(0 is no limited).
+    pub max_concurrent_queries: u64,
+
+    // The max memory(bytes) a single query can use(0 is no limited).
+    pub max_memory_per_query: u64,
+
+    // The max rows a query can return(0 is no limited).
+    pub max_result_rows: u64,
 }
 
 impl UserQuota {
@@ -34,6 +44,17 @@ impl UserQuota {
             max_cpu: 0,
             max_memory_in_bytes: 0,
             max_storage_in_bytes: 0,
+            max_concurrent_queries: 0,
+            max_memory_per_query: 0,
+            max_result_rows: 0,
+        }
+    }
+
+    pub fn apply(&mut self, item: &UserQuotaItem) {
+        match item {
+            UserQuotaItem::MaxConcurrentQueries(v) => self.max_concurrent_queries = *v,
+            UserQuotaItem::MaxMemoryPerQuery(v) => self.max_memory_per_query = *v,
+            UserQuotaItem::MaxResultRows(v) => self.max_result_rows = *v,
         }
     }
 }
@@ -42,8 +63,13 @@ impl std::fmt::Debug for UserQuota {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(
             f,
-            "UserQuota<cpu:{},mem:{},store:{}>",
-            self.max_cpu, self.max_memory_in_bytes, self.max_storage_in_bytes
+            "UserQuota<cpu:{},mem:{},store:{},queries:{},query_mem:{},result_rows:{}>",
+            self.max_cpu,
+            self.max_memory_in_bytes,
+            self.max_storage_in_bytes,
+            self.max_concurrent_queries,
+            self.max_memory_per_query,
+            self.max_result_rows
         )
     }
 }
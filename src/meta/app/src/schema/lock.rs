@@ -168,6 +168,8 @@ pub struct CreateLockRevReq {
     pub user: String,
     pub node: String,
     pub query_id: String,
+    /// Merged into the extra info of the lock key when the revision is created.
+    pub extra_info: BTreeMap<String, String>,
 }
 
 impl CreateLockRevReq {
@@ -177,6 +179,7 @@ impl CreateLockRevReq {
         node: String,
         query_id: String,
         expire_secs: u64,
+        extra_info: BTreeMap<String, String>,
     ) -> Self {
         Self {
             lock_key,
@@ -184,6 +187,7 @@ impl CreateLockRevReq {
             node,
             query_id,
             expire_secs,
+            extra_info,
         }
     }
 }
@@ -102,6 +102,8 @@ pub use table::GetTableReq;
 pub use table::ListDroppedTableReq;
 pub use table::ListDroppedTableResp;
 pub use table::ListTableReq;
+pub use table::ListTableVersionsReply;
+pub use table::ListTableVersionsReq;
 pub use table::RenameTableReply;
 pub use table::RenameTableReq;
 pub use table::SetTableColumnMaskPolicyAction;
@@ -118,8 +120,11 @@ pub use table::TableIndex;
 pub use table::TableInfo;
 pub use table::TableInfoFilter;
 pub use table::TableMeta;
+pub use table::TableMetaChange;
+pub use table::TableMetaOperation;
 pub use table::TableNameIdent;
 pub use table::TableStatistics;
+pub use table::TableVersion;
 pub use table::TruncateTableReply;
 pub use table::TruncateTableReq;
 pub use table::UndropTableByIdReq;
@@ -260,6 +260,13 @@ pub struct TableMeta {
     pub shared_by: BTreeSet<u64>,
     pub column_mask_policy: Option<BTreeMap<String, String>>,
     pub indexes: BTreeMap<String, TableIndex>,
+    // The changes applied in place to this table id, oldest first, see `push_change`.
+    // Defaulted so that a `TableMeta` serialized by an older version still loads.
+    #[serde(default)]
+    pub changes: Vec<TableMetaChange>,
+    // Whether older changes have been discarded to bound the size of the meta.
+    #[serde(default)]
+    pub changes_truncated: bool,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
@@ -277,6 +284,37 @@ pub struct TableIndex {
     pub options: BTreeMap<String, String>,
 }
 
+/// At most this many changes are kept in a `TableMeta`, the oldest ones are discarded first.
+pub const MAX_TABLE_META_CHANGES: usize = 32;
+
+/// A change applied in place to the meta of a table id, which does not bind a new id to the name.
+///
+/// These changes leave no trace in the id list of the name, they are kept for `table_history`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TableMetaChange {
+    pub operation: TableMetaOperation,
+    pub changed_on: DateTime<Utc>,
+    // The schema before the change, only kept for an `Alter`.
+    pub schema: Option<Arc<TableSchema>>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TableMetaOperation {
+    Alter,
+    Drop,
+    Undrop,
+}
+
+impl Display for TableMetaOperation {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            TableMetaOperation::Alter => write!(f, "alter"),
+            TableMetaOperation::Drop => write!(f, "drop"),
+            TableMetaOperation::Undrop => write!(f, "undrop"),
+        }
+    }
+}
+
 impl TableMeta {
     pub fn add_column(
         &mut self,
@@ -389,11 +427,25 @@ impl Default for TableMeta {
             shared_by: BTreeSet::new(),
             column_mask_policy: None,
             indexes: BTreeMap::new(),
+            changes: vec![],
+            changes_truncated: false,
         }
     }
 }
 
 impl TableMeta {
+    /// Record a change applied to this meta in place, discarding the oldest ones beyond
+    /// `MAX_TABLE_META_CHANGES`.
+    pub fn push_change(&mut self, change: TableMetaChange) {
+        self.changes.push(change);
+
+        if self.changes.len() > MAX_TABLE_META_CHANGES {
+            let discarded = self.changes.len() - MAX_TABLE_META_CHANGES;
+            self.changes.drain(..discarded);
+            self.changes_truncated = true;
+        }
+    }
+
     pub fn push_cluster_key(mut self, cluster_key: String) -> Self {
         self.cluster_keys.push(cluster_key.clone());
         self.default_cluster_key = Some(cluster_key);
@@ -900,6 +952,46 @@ impl ListTableReq {
     }
 }
 
+/// List every table id that has been bound to a table name, oldest first.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ListTableVersionsReq {
+    pub inner: TableNameIdent,
+}
+
+impl Deref for ListTableVersionsReq {
+    type Target = TableNameIdent;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl ListTableVersionsReq {
+    pub fn new(
+        tenant: &Tenant,
+        db_name: impl ToString,
+        table_name: impl ToString,
+    ) -> ListTableVersionsReq {
+        ListTableVersionsReq {
+            inner: TableNameIdent::new(tenant.clone(), db_name, table_name),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TableVersion {
+    pub table_id: u64,
+    /// The seq of the `TableMeta` record, 0 if it has been purged.
+    pub seq: u64,
+    /// `None` if the meta has been purged by vacuum.
+    pub meta: Option<TableMeta>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ListTableVersionsReply {
+    pub versions: Vec<TableVersion>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum TableInfoFilter {
     // if datatime is some, filter only dropped tables which drop time before that,
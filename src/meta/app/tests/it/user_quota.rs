@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_ast::ast::UserQuotaItem;
 use databend_common_exception::exception::Result;
 use databend_common_meta_app::principal::UserQuota;
 
@@ -21,6 +22,27 @@ fn test_user_quota() -> Result<()> {
     assert_eq!(quota.max_cpu, 0);
     assert_eq!(quota.max_memory_in_bytes, 0);
     assert_eq!(quota.max_storage_in_bytes, 0);
+    assert_eq!(quota.max_concurrent_queries, 0);
+    assert_eq!(quota.max_memory_per_query, 0);
+    assert_eq!(quota.max_result_rows, 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_user_quota_apply() -> Result<()> {
+    let mut quota = UserQuota::no_limit();
+    quota.apply(&UserQuotaItem::MaxConcurrentQueries(2));
+    quota.apply(&UserQuotaItem::MaxMemoryPerQuery(1024));
+    quota.apply(&UserQuotaItem::MaxResultRows(100));
+    assert_eq!(quota.max_concurrent_queries, 2);
+    assert_eq!(quota.max_memory_per_query, 1024);
+    assert_eq!(quota.max_result_rows, 100);
+
+    // Zero lifts the limit again.
+    quota.apply(&UserQuotaItem::MaxConcurrentQueries(0));
+    assert_eq!(quota.max_concurrent_queries, 0);
+    assert_eq!(quota.max_cpu, 0);
 
     Ok(())
 }
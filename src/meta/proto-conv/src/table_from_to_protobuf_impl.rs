@@ -192,6 +192,11 @@ impl FromToProto for mt::TableMeta {
             indexes.insert(name, mt::TableIndex::from_pb(index)?);
         }
 
+        let mut changes = Vec::with_capacity(p.changes.len());
+        for change in p.changes {
+            changes.push(mt::TableMetaChange::from_pb(change)?);
+        }
+
         let v = Self {
             schema: Arc::new(ex::TableSchema::from_pb(schema)?),
             engine: p.engine,
@@ -225,6 +230,8 @@ impl FromToProto for mt::TableMeta {
                 Some(p.column_mask_policy)
             },
             indexes,
+            changes,
+            changes_truncated: p.changes_truncated,
         };
         Ok(v)
     }
@@ -234,6 +241,10 @@ impl FromToProto for mt::TableMeta {
         for (name, index) in &self.indexes {
             indexes.insert(name.clone(), index.to_pb()?);
         }
+        let mut changes = Vec::with_capacity(self.changes.len());
+        for change in &self.changes {
+            changes.push(change.to_pb()?);
+        }
         let p = pb::TableMeta {
             ver: VER,
             min_reader_ver: MIN_READER_VER,
@@ -265,6 +276,53 @@ impl FromToProto for mt::TableMeta {
             shared_by: Vec::from_iter(self.shared_by.clone()),
             column_mask_policy: self.column_mask_policy.clone().unwrap_or_default(),
             indexes,
+            changes,
+            changes_truncated: self.changes_truncated,
+        };
+        Ok(p)
+    }
+}
+
+impl FromToProto for mt::TableMetaChange {
+    type PB = pb::TableMetaChange;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::TableMetaChange) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let operation = match p.operation.as_str() {
+            "alter" => mt::TableMetaOperation::Alter,
+            "drop" => mt::TableMetaOperation::Drop,
+            "undrop" => mt::TableMetaOperation::Undrop,
+            _ => {
+                return Err(Incompatible {
+                    reason: format!("invalid TableMetaOperation: {}", p.operation),
+                });
+            }
+        };
+
+        let v = Self {
+            operation,
+            changed_on: DateTime::<Utc>::from_pb(p.changed_on)?,
+            schema: match p.schema {
+                Some(schema) => Some(Arc::new(ex::TableSchema::from_pb(schema)?)),
+                None => None,
+            },
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::TableMetaChange, Incompatible> {
+        let p = pb::TableMetaChange {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            operation: self.operation.to_string(),
+            changed_on: self.changed_on.to_pb()?,
+            schema: match &self.schema {
+                Some(schema) => Some(schema.to_pb()?),
+                None => None,
+            },
         };
         Ok(p)
     }
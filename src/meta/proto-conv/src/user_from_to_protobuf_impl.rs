@@ -130,6 +130,9 @@ impl FromToProto for mt::principal::UserQuota {
             max_cpu: p.max_cpu,
             max_memory_in_bytes: p.max_memory_in_bytes,
             max_storage_in_bytes: p.max_storage_in_bytes,
+            max_concurrent_queries: p.max_concurrent_queries,
+            max_memory_per_query: p.max_memory_per_query,
+            max_result_rows: p.max_result_rows,
         })
     }
 
@@ -140,6 +143,9 @@ impl FromToProto for mt::principal::UserQuota {
             max_cpu: self.max_cpu,
             max_memory_in_bytes: self.max_memory_in_bytes,
             max_storage_in_bytes: self.max_storage_in_bytes,
+            max_concurrent_queries: self.max_concurrent_queries,
+            max_memory_per_query: self.max_memory_per_query,
+            max_result_rows: self.max_result_rows,
         })
     }
 }
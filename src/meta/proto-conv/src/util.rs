@@ -131,6 +131,8 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (99, "2024-07-08: Add: missing_field_as in user.proto/ParquetFileFormatParams"),
     (100, "2024-06-21: Add: tenant.proto/TenantQuota"),
     (101, "2024-07-06: Add: add from_share_db_id field into DatabaseMeta"),
+    (102, "2024-07-10: Add: user.proto/UserQuota add max_concurrent_queries, max_memory_per_query and max_result_rows"),
+    (103, "2024-07-12: Add: table.proto/TableMeta add changes and changes_truncated"),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
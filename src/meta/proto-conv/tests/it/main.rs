@@ -105,3 +105,5 @@ mod v098_catalog_option;
 mod v099_parquet_format_params;
 mod v100_tenant_quota;
 mod v101_database_meta;
+mod v102_user_quota;
+mod v103_table_meta_change;
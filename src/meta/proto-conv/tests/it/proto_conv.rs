@@ -245,6 +245,8 @@ fn new_table_meta() -> mt::TableMeta {
         shared_by: btreeset! {1},
         column_mask_policy: Some(btreemap! {s("a") => s("b")}),
         indexes: btreemap! {},
+        changes: vec![],
+        changes_truncated: false,
     }
 }
 
@@ -67,6 +67,9 @@ fn test_user_info() -> mt::principal::UserInfo {
             max_cpu: 10,
             max_memory_in_bytes: 10240,
             max_storage_in_bytes: 20480,
+            max_concurrent_queries: 0,
+            max_memory_per_query: 0,
+            max_result_rows: 0,
         },
         option,
         history_auth_infos: vec![],
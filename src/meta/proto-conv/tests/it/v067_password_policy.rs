@@ -98,6 +98,9 @@ fn test_decode_v67_password_policy() -> anyhow::Result<()> {
             max_cpu: 10,
             max_memory_in_bytes: 10240,
             max_storage_in_bytes: 20480,
+            max_concurrent_queries: 0,
+            max_memory_per_query: 0,
+            max_result_rows: 0,
         },
         option: databend_common_meta_app::principal::UserOption::default()
             .with_set_flag(databend_common_meta_app::principal::UserOptionFlag::TenantSetting)
@@ -0,0 +1,47 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_app::principal::UserQuota;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+#[test]
+fn test_decode_v102_user_quota() -> anyhow::Result<()> {
+    let user_quota_v102 = vec![
+        8, 10, 16, 128, 80, 24, 128, 160, 1, 32, 2, 40, 128, 128, 64, 48, 232, 7, 160, 6, 102, 168,
+        6, 24,
+    ];
+    let want = || UserQuota {
+        max_cpu: 10,
+        max_memory_in_bytes: 10240,
+        max_storage_in_bytes: 20480,
+        max_concurrent_queries: 2,
+        max_memory_per_query: 1048576,
+        max_result_rows: 1000,
+    };
+    common::test_load_old(func_name!(), user_quota_v102.as_slice(), 102, want())?;
+    common::test_pb_from_to(func_name!(), want())?;
+    Ok(())
+}
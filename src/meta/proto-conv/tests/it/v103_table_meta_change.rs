@@ -0,0 +1,77 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_expression as ce;
+use databend_common_meta_app::schema as mt;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+#[test]
+fn test_decode_v103_table_meta_change() -> anyhow::Result<()> {
+    let table_meta_v103 = vec![
+        10, 30, 10, 20, 10, 1, 97, 26, 9, 146, 2, 0, 160, 6, 103, 168, 6, 24, 160, 6, 103, 168, 6,
+        24, 24, 1, 160, 6, 103, 168, 6, 24, 50, 4, 70, 85, 83, 69, 162, 1, 23, 50, 48, 49, 52, 45,
+        49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 170, 1, 23, 50, 48,
+        49, 52, 45, 49, 49, 45, 50, 57, 32, 49, 50, 58, 48, 48, 58, 49, 48, 32, 85, 84, 67, 186, 1,
+        6, 160, 6, 103, 168, 6, 24, 130, 2, 46, 10, 5, 97, 108, 116, 101, 114, 18, 23, 50, 48, 49,
+        52, 45, 49, 49, 45, 50, 57, 32, 49, 50, 58, 48, 48, 58, 49, 48, 32, 85, 84, 67, 26, 6, 160,
+        6, 103, 168, 6, 24, 160, 6, 103, 168, 6, 24, 130, 2, 39, 10, 6, 117, 110, 100, 114, 111,
+        112, 18, 23, 50, 48, 49, 52, 45, 49, 49, 45, 51, 48, 32, 49, 50, 58, 48, 48, 58, 49, 49,
+        32, 85, 84, 67, 160, 6, 103, 168, 6, 24, 136, 2, 1, 160, 6, 103, 168, 6, 24,
+    ];
+
+    let want = || mt::TableMeta {
+        schema: Arc::new(ce::TableSchema::new(vec![ce::TableField::new(
+            "a",
+            ce::TableDataType::String,
+        )])),
+        engine: "FUSE".to_string(),
+        created_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+        updated_on: Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 10).unwrap(),
+        changes: vec![
+            mt::TableMetaChange {
+                operation: mt::TableMetaOperation::Alter,
+                changed_on: Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 10).unwrap(),
+                schema: Some(Arc::new(ce::TableSchema::empty())),
+            },
+            mt::TableMetaChange {
+                operation: mt::TableMetaOperation::Undrop,
+                changed_on: Utc.with_ymd_and_hms(2014, 11, 30, 12, 0, 11).unwrap(),
+                schema: None,
+            },
+        ],
+        changes_truncated: true,
+        ..Default::default()
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), table_meta_v103.as_slice(), 103, want())?;
+
+    Ok(())
+}
@@ -736,6 +736,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                                 ExplainOption::Verbose => "Verbose",
                                 ExplainOption::Logical => "Logical",
                                 ExplainOption::Optimized => "Optimized",
+                                ExplainOption::Graphviz => "Graphviz",
                             }
                         })
                         .join(", ")
@@ -1336,6 +1337,19 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_show_keys(&mut self, stmt: &'ast ShowKeysStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let mut children = vec![self.children.pop().unwrap()];
+        if let Some(limit) = &stmt.limit {
+            self.visit_show_limit(limit);
+            children.push(self.children.pop().unwrap());
+        }
+        let name = "ShowKeys".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
     fn visit_show_create_table(&mut self, stmt: &'ast ShowCreateTableStmt) {
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
         let child = self.children.pop().unwrap();
@@ -1801,6 +1815,15 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_show_create_view(&mut self, stmt: &'ast ShowCreateViewStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.view);
+        let child = self.children.pop().unwrap();
+        let name = "ShowCreateView".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_describe_view(&mut self, stmt: &'ast DescribeViewStmt) {
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.view);
         let child = self.children.pop().unwrap();
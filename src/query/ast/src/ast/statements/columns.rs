@@ -53,3 +53,30 @@ impl Display for ShowColumnsStmt {
         Ok(())
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct ShowKeysStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+    pub limit: Option<ShowLimit>,
+}
+
+impl Display for ShowKeysStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "SHOW INDEX FROM ")?;
+        if let Some(database) = &self.database {
+            if let Some(catalog) = &self.catalog {
+                write!(f, "{catalog}.",)?;
+            }
+            write!(f, "{database}.")?;
+        }
+        write!(f, "{}", self.table)?;
+
+        if let Some(limit) = &self.limit {
+            write!(f, " {limit}")?;
+        }
+
+        Ok(())
+    }
+}
@@ -54,11 +54,15 @@ impl Display for ShowDatabasesStmt {
 pub struct ShowCreateDatabaseStmt {
     pub catalog: Option<Identifier>,
     pub database: Identifier,
+    pub if_not_exists: bool,
 }
 
 impl Display for ShowCreateDatabaseStmt {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         write!(f, "SHOW CREATE DATABASE ")?;
+        if self.if_not_exists {
+            write!(f, "IF NOT EXISTS ")?;
+        }
         write_dot_separated_list(f, self.catalog.iter().chain(Some(&self.database)))?;
 
         Ok(())
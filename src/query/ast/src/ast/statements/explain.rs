@@ -44,4 +44,5 @@ pub enum ExplainOption {
     Verbose,
     Logical,
     Optimized,
+    Graphviz,
 }
@@ -53,6 +53,7 @@ pub enum Statement {
         show_options: Option<ShowOptions>,
     },
     ShowProcessList {
+        full: bool,
         show_options: Option<ShowOptions>,
     },
     ShowMetrics {
@@ -144,12 +145,14 @@ pub enum Statement {
 
     // Columns
     ShowColumns(ShowColumnsStmt),
+    ShowKeys(ShowKeysStmt),
 
     // Views
     CreateView(CreateViewStmt),
     AlterView(AlterViewStmt),
     DropView(DropViewStmt),
     ShowViews(ShowViewsStmt),
+    ShowCreateView(ShowCreateViewStmt),
     DescribeView(DescribeViewStmt),
 
     // Streams
@@ -380,6 +383,7 @@ impl Display for Statement {
                                     ExplainOption::Verbose => "VERBOSE",
                                     ExplainOption::Logical => "LOGICAL",
                                     ExplainOption::Optimized => "OPTIMIZED",
+                                    ExplainOption::Graphviz => "GRAPHVIZ",
                                 }
                             })
                             .join(", ")
@@ -418,8 +422,12 @@ impl Display for Statement {
                     write!(f, " {show_options}")?;
                 }
             }
-            Statement::ShowProcessList { show_options } => {
-                write!(f, "SHOW PROCESSLIST")?;
+            Statement::ShowProcessList { full, show_options } => {
+                write!(f, "SHOW")?;
+                if *full {
+                    write!(f, " FULL")?;
+                }
+                write!(f, " PROCESSLIST")?;
                 if let Some(show_options) = show_options {
                     write!(f, " {show_options}")?;
                 }
@@ -514,6 +522,7 @@ impl Display for Statement {
             Statement::UseDatabase { database } => write!(f, "USE {database}")?,
             Statement::ShowTables(stmt) => write!(f, "{stmt}")?,
             Statement::ShowColumns(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowKeys(stmt) => write!(f, "{stmt}")?,
             Statement::ShowCreateTable(stmt) => write!(f, "{stmt}")?,
             Statement::DescribeTable(stmt) => write!(f, "{stmt}")?,
             Statement::ShowTablesStatus(stmt) => write!(f, "{stmt}")?,
@@ -535,6 +544,7 @@ impl Display for Statement {
             Statement::AlterView(stmt) => write!(f, "{stmt}")?,
             Statement::DropView(stmt) => write!(f, "{stmt}")?,
             Statement::ShowViews(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowCreateView(stmt) => write!(f, "{stmt}")?,
             Statement::DescribeView(stmt) => write!(f, "{stmt}")?,
             Statement::CreateStream(stmt) => write!(f, "{stmt}")?,
             Statement::DropStream(stmt) => write!(f, "{stmt}")?,
@@ -261,6 +261,7 @@ pub enum UserOptionItem {
     UnsetNetworkPolicy,
     SetPasswordPolicy(String),
     UnsetPasswordPolicy,
+    SetQuota(UserQuotaItem),
 }
 
 impl Display for UserOptionItem {
@@ -274,6 +275,24 @@ impl Display for UserOptionItem {
             UserOptionItem::SetPasswordPolicy(v) => write!(f, "SET PASSWORD POLICY = '{}'", v),
             UserOptionItem::UnsetPasswordPolicy => write!(f, "UNSET PASSWORD POLICY"),
             UserOptionItem::Disabled(v) => write!(f, "DISABLED = {}", v),
+            UserOptionItem::SetQuota(v) => write!(f, "SET QUOTA {}", v),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub enum UserQuotaItem {
+    MaxConcurrentQueries(u64),
+    MaxMemoryPerQuery(u64),
+    MaxResultRows(u64),
+}
+
+impl Display for UserQuotaItem {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            UserQuotaItem::MaxConcurrentQueries(v) => write!(f, "MAX_CONCURRENT_QUERIES = {}", v),
+            UserQuotaItem::MaxMemoryPerQuery(v) => write!(f, "MAX_MEMORY_PER_QUERY = {}", v),
+            UserQuotaItem::MaxResultRows(v) => write!(f, "MAX_RESULT_ROWS = {}", v),
         }
     }
 }
@@ -509,6 +509,8 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_show_columns(&mut self, _stmt: &'ast ShowColumnsStmt) {}
 
+    fn visit_show_keys(&mut self, _stmt: &'ast ShowKeysStmt) {}
+
     fn visit_show_create_table(&mut self, _stmt: &'ast ShowCreateTableStmt) {}
 
     fn visit_describe_table(&mut self, _stmt: &'ast DescribeTableStmt) {}
@@ -563,6 +565,8 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_show_views(&mut self, _stmt: &'ast ShowViewsStmt) {}
 
+    fn visit_show_create_view(&mut self, _stmt: &'ast ShowCreateViewStmt) {}
+
     fn visit_describe_view(&mut self, _stmt: &'ast DescribeViewStmt) {}
 
     fn visit_create_stream(&mut self, _stmt: &'ast CreateStreamStmt) {}
@@ -521,6 +521,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_show_columns(&mut self, _stmt: &mut ShowColumnsStmt) {}
 
+    fn visit_show_keys(&mut self, _stmt: &mut ShowKeysStmt) {}
+
     fn visit_show_create_table(&mut self, _stmt: &mut ShowCreateTableStmt) {}
 
     fn visit_describe_table(&mut self, _stmt: &mut DescribeTableStmt) {}
@@ -575,6 +577,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_show_views(&mut self, _stmt: &mut ShowViewsStmt) {}
 
+    fn visit_show_create_view(&mut self, _stmt: &mut ShowCreateViewStmt) {}
+
     fn visit_describe_view(&mut self, _stmt: &mut DescribeViewStmt) {}
 
     fn visit_create_stream(&mut self, _stmt: &mut CreateStreamStmt) {}
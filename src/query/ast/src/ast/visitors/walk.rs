@@ -411,7 +411,7 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::CopyIntoTable(stmt) => visitor.visit_copy_into_table(stmt),
         Statement::CopyIntoLocation(stmt) => visitor.visit_copy_into_location(stmt),
         Statement::ShowSettings { show_options } => visitor.visit_show_settings(show_options),
-        Statement::ShowProcessList { show_options } => {
+        Statement::ShowProcessList { show_options, .. } => {
             visitor.visit_show_process_list(show_options)
         }
         Statement::ShowMetrics { show_options } => visitor.visit_show_metrics(show_options),
@@ -453,6 +453,7 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::UseDatabase { database } => visitor.visit_use_database(database),
         Statement::ShowTables(stmt) => visitor.visit_show_tables(stmt),
         Statement::ShowColumns(stmt) => visitor.visit_show_columns(stmt),
+        Statement::ShowKeys(stmt) => visitor.visit_show_keys(stmt),
         Statement::ShowCreateTable(stmt) => visitor.visit_show_create_table(stmt),
         Statement::DescribeTable(stmt) => visitor.visit_describe_table(stmt),
         Statement::ShowTablesStatus(stmt) => visitor.visit_show_tables_status(stmt),
@@ -473,6 +474,7 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::AlterView(stmt) => visitor.visit_alter_view(stmt),
         Statement::DropView(stmt) => visitor.visit_drop_view(stmt),
         Statement::ShowViews(stmt) => visitor.visit_show_views(stmt),
+        Statement::ShowCreateView(stmt) => visitor.visit_show_create_view(stmt),
         Statement::DescribeView(stmt) => visitor.visit_describe_view(stmt),
         Statement::CreateStream(stmt) => visitor.visit_create_stream(stmt),
         Statement::DropStream(stmt) => visitor.visit_drop_stream(stmt),
@@ -103,7 +103,8 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
     let select_stmt = map_res(
         rule! {
             ( FROM ~ ^#comma_separated_list1(table_reference) )?
-            ~ SELECT ~ #hint? ~ DISTINCT? ~ #top_n? ~ ^#comma_separated_list1(select_target)
+            ~ SELECT ~ #hint? ~ DISTINCT? ~ ( SQL_CACHE | SQL_NO_CACHE )? ~ #top_n?
+            ~ ^#comma_separated_list1(select_target)
             ~ ( FROM ~ ^#comma_separated_list1(table_reference) )?
             ~ ( WHERE ~ ^#expr )?
             ~ ( GROUP ~ ^BY ~ ^#group_by_items )?
@@ -116,6 +117,7 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
             _select,
             opt_hints,
             opt_distinct,
+            _opt_query_cache,
             opt_top_n,
             select_list,
             opt_from_block_second,
@@ -280,9 +280,12 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
     let show_stages = value(Statement::ShowStages, rule! { SHOW ~ STAGES });
     let show_process_list = map(
         rule! {
-            SHOW ~ PROCESSLIST ~ #show_options?
+            SHOW ~ FULL? ~ PROCESSLIST ~ #show_options?
+        },
+        |(_, opt_full, _, show_options)| Statement::ShowProcessList {
+            full: opt_full.is_some(),
+            show_options,
         },
-        |(_, _, show_options)| Statement::ShowProcessList { show_options },
     );
     let show_metrics = map(
         rule! {
@@ -314,6 +317,27 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         },
         |(_, _, show_options)| Statement::ShowTableFunctions { show_options },
     );
+    let show_keys = map(
+        rule! {
+            SHOW ~ ( INDEX | INDEXES | KEYS )
+            ~ ( FROM | IN ) ~ #dot_separated_idents_1_to_3
+            ~ (( FROM | IN ) ~ ^#ident)?
+            ~ #show_limit?
+        },
+        |(_, _, _, (catalog, database, table), opt_database, limit)| {
+            // `SHOW INDEX FROM t FROM db` is the same as `SHOW INDEX FROM db.t`.
+            let (catalog, database) = match opt_database {
+                Some((_, database)) => (None, Some(database)),
+                None => (catalog, database),
+            };
+            Statement::ShowKeys(ShowKeysStmt {
+                catalog,
+                database,
+                table,
+                limit,
+            })
+        },
+    );
     let show_indexes = map(
         rule! {
             SHOW ~ INDEXES ~ #show_options?
@@ -355,15 +379,28 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
 
     let set_variable = map(
         rule! {
-            SET ~ GLOBAL? ~ #ident ~ "=" ~ #subexpr(0)
+            SET ~ ( GLOBAL | SESSION )? ~ #ident ~ "=" ~ #subexpr(0)
         },
-        |(_, opt_is_global, variable, _, value)| Statement::SetVariable {
-            is_global: opt_is_global.is_some(),
+        |(_, opt_scope, variable, _, value)| Statement::SetVariable {
+            is_global: opt_scope.is_some_and(|scope| scope.kind == GLOBAL),
             variable,
             value: Box::new(value),
         },
     );
 
+    // `SET [GLOBAL | SESSION] <variable> = DEFAULT` removes the override of the scope.
+    let set_variable_default = map(
+        rule! {
+            SET ~ ( GLOBAL | SESSION )? ~ #ident ~ "=" ~ DEFAULT
+        },
+        |(_, opt_scope, variable, _, _)| {
+            Statement::UnSetVariable(UnSetStmt {
+                session_level: !opt_scope.is_some_and(|scope| scope.kind == GLOBAL),
+                source: UnSetSource::Var { variable },
+            })
+        },
+    );
+
     let unset_variable = map(
         rule! {
             UNSET ~ SESSION? ~ #unset_source
@@ -457,10 +494,14 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
     );
     let show_create_database = map(
         rule! {
-            SHOW ~ CREATE ~ ( DATABASE | SCHEMA ) ~ #dot_separated_idents_1_to_2
+            SHOW ~ CREATE ~ ( DATABASE | SCHEMA ) ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #dot_separated_idents_1_to_2
         },
-        |(_, _, _, (catalog, database))| {
-            Statement::ShowCreateDatabase(ShowCreateDatabaseStmt { catalog, database })
+        |(_, _, _, opt_if_not_exists, (catalog, database))| {
+            Statement::ShowCreateDatabase(ShowCreateDatabaseStmt {
+                catalog,
+                database,
+                if_not_exists: opt_if_not_exists.is_some(),
+            })
         },
     );
 
@@ -945,6 +986,18 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             })
         },
     );
+    let show_create_view = map(
+        rule! {
+            SHOW ~ CREATE ~ VIEW ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, _, (catalog, database, view))| {
+            Statement::ShowCreateView(ShowCreateViewStmt {
+                catalog,
+                database,
+                view,
+            })
+        },
+    );
     let describe_view = map(
         rule! {
             ( DESC | DESCRIBE ) ~ VIEW ~ #dot_separated_idents_1_to_3
@@ -2052,7 +2105,10 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         },
     );
 
-    let begin = value(Statement::Begin, rule! { BEGIN ~ TRANSACTION? });
+    let begin = value(
+        Statement::Begin,
+        rule! { BEGIN ~ TRANSACTION? | START ~ TRANSACTION ~ ( WITH ~ CONSISTENT ~ SNAPSHOT )? },
+    );
     let commit = value(Statement::Commit, rule! { COMMIT });
     let abort = value(Statement::Abort, rule! { ABORT | ROLLBACK });
 
@@ -2079,9 +2135,10 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #show_settings : "`SHOW SETTINGS [<show_limit>]`"
             | #show_stages : "`SHOW STAGES`"
             | #show_engines : "`SHOW ENGINES`"
-            | #show_process_list : "`SHOW PROCESSLIST`"
+            | #show_process_list : "`SHOW [FULL] PROCESSLIST`"
             | #show_metrics : "`SHOW METRICS`"
             | #show_functions : "`SHOW FUNCTIONS [<show_limit>]`"
+            | #show_keys : "`SHOW (INDEX | INDEXES | KEYS) FROM <table> [FROM|IN <database>] [<show_limit>]`"
             | #show_indexes : "`SHOW INDEXES`"
             | #show_locks : "`SHOW LOCKS [IN ACCOUNT] [WHERE ...]`"
             | #kill_stmt : "`KILL (QUERY | CONNECTION) <object_id>`"
@@ -2093,7 +2150,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         rule!(
             #show_databases : "`SHOW [FULL] DATABASES [(FROM | IN) <catalog>] [<show_limit>]`"
             | #undrop_database : "`UNDROP DATABASE <database>`"
-            | #show_create_database : "`SHOW CREATE DATABASE <database>`"
+            | #show_create_database : "`SHOW CREATE DATABASE [IF NOT EXISTS] <database>`"
             | #create_database : "`CREATE [OR REPLACE] DATABASE [IF NOT EXISTS] <database> [ENGINE = <engine>]`"
             | #drop_database : "`DROP DATABASE [IF EXISTS] <database>`"
             | #alter_database : "`ALTER DATABASE [IF EXISTS] <action>`"
@@ -2122,7 +2179,8 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #update : "`UPDATE <table> SET <column> = <expr> [, <column> = <expr> , ... ] [WHERE ...]`"
         ),
         rule!(
-            #set_variable : "`SET <variable> = <value>`"
+            #set_variable_default : "`SET [GLOBAL | SESSION] <variable> = DEFAULT`"
+            | #set_variable : "`SET [GLOBAL | SESSION] <variable> = <value>`"
             | #unset_variable : "`UNSET <variable>`"
             | #begin
             | #commit
@@ -2157,6 +2215,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #drop_view : "`DROP VIEW [IF EXISTS] [<database>.]<view>`"
             | #alter_view : "`ALTER VIEW [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
             | #show_views : "`SHOW [FULL] VIEWS [FROM <database>] [<show_limit>]`"
+            | #show_create_view : "`SHOW CREATE VIEW [<database>.]<view>`"
             | #create_index: "`CREATE [OR REPLACE] AGGREGATING INDEX [IF NOT EXISTS] <index> AS SELECT ...`"
             | #drop_index: "`DROP <index_type> INDEX [IF EXISTS] <index>`"
             | #refresh_index: "`REFRESH <index_type> INDEX <index> [LIMIT <limit>]`"
@@ -3938,6 +3997,12 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
         },
         |(_, _, _)| UserOptionItem::UnsetPasswordPolicy,
     );
+    let set_quota = map(
+        rule! {
+            SET ~ QUOTA ~ ^#user_quota
+        },
+        |(_, _, quota)| UserOptionItem::SetQuota(quota),
+    );
 
     rule!(
         #tenant_setting
@@ -3947,10 +4012,38 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
         | #unset_network_policy
         | #set_password_policy
         | #unset_password_policy
+        | #set_quota
         | #set_disabled_option
     )(i)
 }
 
+pub fn user_quota(i: Input) -> IResult<UserQuotaItem> {
+    let max_concurrent_queries = map(
+        rule! {
+            MAX_CONCURRENT_QUERIES ~ ^"=" ~ ^#literal_u64
+        },
+        |(_, _, v)| UserQuotaItem::MaxConcurrentQueries(v),
+    );
+    let max_memory_per_query = map(
+        rule! {
+            MAX_MEMORY_PER_QUERY ~ ^"=" ~ ^#literal_u64
+        },
+        |(_, _, v)| UserQuotaItem::MaxMemoryPerQuery(v),
+    );
+    let max_result_rows = map(
+        rule! {
+            MAX_RESULT_ROWS ~ ^"=" ~ ^#literal_u64
+        },
+        |(_, _, v)| UserQuotaItem::MaxResultRows(v),
+    );
+
+    rule!(
+        #max_concurrent_queries
+        | #max_memory_per_query
+        | #max_result_rows
+    )(i)
+}
+
 pub fn user_identity(i: Input) -> IResult<UserIdentity> {
     map(
         rule! {
@@ -4245,12 +4338,13 @@ pub fn alter_password_action(i: Input) -> IResult<AlterPasswordAction> {
 pub fn explain_option(i: Input) -> IResult<ExplainOption> {
     map(
         rule! {
-            VERBOSE | LOGICAL | OPTIMIZED
+            VERBOSE | LOGICAL | OPTIMIZED | GRAPHVIZ
         },
         |opt| match &opt.kind {
             VERBOSE => ExplainOption::Verbose,
             LOGICAL => ExplainOption::Logical,
             OPTIMIZED => ExplainOption::Optimized,
+            GRAPHVIZ => ExplainOption::Graphviz,
             _ => unreachable!(),
         },
     )(i)
@@ -428,6 +428,8 @@ pub enum TokenKind {
     CONNECTION,
     #[token("CONNECTIONS", ignore(ascii_case))]
     CONNECTIONS,
+    #[token("CONSISTENT", ignore(ascii_case))]
+    CONSISTENT,
     #[token("CONSUME", ignore(ascii_case))]
     CONSUME,
     #[token("CONTENT_TYPE", ignore(ascii_case))]
@@ -638,6 +640,8 @@ pub enum TokenKind {
     GLOBAL,
     #[token("GRAPH", ignore(ascii_case))]
     GRAPH,
+    #[token("GRAPHVIZ", ignore(ascii_case))]
+    GRAPHVIZ,
     #[token("GROUP", ignore(ascii_case))]
     GROUP,
     #[token("GZIP", ignore(ascii_case))]
@@ -753,8 +757,14 @@ pub enum TokenKind {
     MASKING,
     #[token("MAP", ignore(ascii_case))]
     MAP,
+    #[token("MAX_CONCURRENT_QUERIES", ignore(ascii_case))]
+    MAX_CONCURRENT_QUERIES,
     #[token("MAX_FILE_SIZE", ignore(ascii_case))]
     MAX_FILE_SIZE,
+    #[token("MAX_MEMORY_PER_QUERY", ignore(ascii_case))]
+    MAX_MEMORY_PER_QUERY,
+    #[token("MAX_RESULT_ROWS", ignore(ascii_case))]
+    MAX_RESULT_ROWS,
     #[token("MASTER_KEY", ignore(ascii_case))]
     MASTER_KEY,
     #[token("MEDIUM", ignore(ascii_case))]
@@ -887,6 +897,8 @@ pub enum TokenKind {
     QUARTER,
     #[token("QUERY", ignore(ascii_case))]
     QUERY,
+    #[token("QUOTA", ignore(ascii_case))]
+    QUOTA,
     #[token("QUOTE", ignore(ascii_case))]
     QUOTE,
     #[token("RANGE", ignore(ascii_case))]
@@ -1027,6 +1039,10 @@ pub enum TokenKind {
     SNAPSHOT,
     #[token("SPLIT_SIZE", ignore(ascii_case))]
     SPLIT_SIZE,
+    #[token("SQL_CACHE", ignore(ascii_case))]
+    SQL_CACHE,
+    #[token("SQL_NO_CACHE", ignore(ascii_case))]
+    SQL_NO_CACHE,
     #[token("STAGE", ignore(ascii_case))]
     STAGE,
     #[token("SYNTAX", ignore(ascii_case))]
@@ -1219,6 +1235,8 @@ pub enum TokenKind {
     ROLLUP,
     #[token("INDEXES", ignore(ascii_case))]
     INDEXES,
+    #[token("KEYS", ignore(ascii_case))]
+    KEYS,
     #[token("ADDRESS", ignore(ascii_case))]
     ADDRESS,
     #[token("OWNERSHIP", ignore(ascii_case))]
@@ -1285,6 +1303,8 @@ pub enum TokenKind {
     ABORT,
     #[token("ROLLBACK", ignore(ascii_case))]
     ROLLBACK,
+    #[token("START", ignore(ascii_case))]
+    START,
     #[token("TEMPORARY", ignore(ascii_case))]
     TEMPORARY,
     #[token("SECONDS", ignore(ascii_case))]
@@ -108,10 +108,14 @@ fn test_statement() {
         r#"show full columns in t in db"#,
         r#"show columns in t from ctl.db"#,
         r#"show full columns from t from db like 'id%'"#,
+        r#"show index from db.t1;"#,
         r#"show processlist like 't%' limit 2;"#,
         r#"show processlist where database='default' limit 2;"#,
+        r#"show full processlist;"#,
         r#"show create table a.b;"#,
         r#"show create table a.b format TabSeparatedWithNamesAndTypes;"#,
+        r#"show create view a.v;"#,
+        r#"show create database if not exists db;"#,
         r#"replace into test on(c) select sum(c) as c from source group by v;"#,
         r#"explain pipeline select a from b;"#,
         r#"explain replace into test on(c) select sum(c) as c from source group by v;"#,
@@ -191,6 +195,7 @@ fn test_statement() {
         r#"ALTER USER u1 WITH DEFAULT_ROLE = role1, DISABLED=true, TENANTSETTING;"#,
         r#"ALTER USER u1 WITH SET NETWORK POLICY = 'policy1';"#,
         r#"ALTER USER u1 WITH UNSET NETWORK POLICY;"#,
+        r#"ALTER USER u1 WITH SET QUOTA MAX_CONCURRENT_QUERIES = 2, SET QUOTA max_result_rows = 1000;"#,
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH DEFAULT_ROLE='role123', TENANTSETTING"#,
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH SET NETWORK POLICY='policy1'"#,
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH disabled=true"#,
@@ -574,6 +579,9 @@ fn test_statement() {
         r#"UPDATE db1.tb1 set a = a + 1, b = 2 WHERE c > 3;"#,
         r#"SET max_threads = 10;"#,
         r#"SET max_threads = 10*2;"#,
+        r#"SET SESSION max_threads = 10;"#,
+        r#"SET GLOBAL max_threads = DEFAULT;"#,
+        r#"SET max_threads = DEFAULT;"#,
         r#"UNSET max_threads;"#,
         r#"UNSET session max_threads;"#,
         r#"UNSET (max_threads, sql_dialect);"#,
@@ -815,6 +823,9 @@ fn test_statement() {
             )
             select * from abc;
         "#,
+        r#"start transaction;"#,
+        r#"start transaction with consistent snapshot;"#,
+        r#"select sql_no_cache * from t4;"#,
     ];
 
     for case in cases {
@@ -66,6 +66,8 @@ use databend_common_meta_app::schema::ListIndexesByIdReq;
 use databend_common_meta_app::schema::ListIndexesReq;
 use databend_common_meta_app::schema::ListLockRevReq;
 use databend_common_meta_app::schema::ListLocksReq;
+use databend_common_meta_app::schema::ListTableVersionsReply;
+use databend_common_meta_app::schema::ListTableVersionsReq;
 use databend_common_meta_app::schema::ListVirtualColumnsReq;
 use databend_common_meta_app::schema::LockInfo;
 use databend_common_meta_app::schema::LockMeta;
@@ -105,6 +107,7 @@ use crate::database::Database;
 use crate::table::Table;
 use crate::table_args::TableArgs;
 use crate::table_function::TableFunction;
+use crate::table_function::TableFunctionDescription;
 
 #[derive(Default, Clone)]
 pub struct StorageDescription {
@@ -246,6 +249,16 @@ pub trait Catalog: DynClone + Send + Sync + Debug {
         ))
     }
 
+    /// List the table ids ever bound to the table name, with their metas, oldest first.
+    async fn list_table_versions(
+        &self,
+        _req: ListTableVersionsReq,
+    ) -> Result<ListTableVersionsReply> {
+        Err(ErrorCode::Unimplemented(
+            "'list_table_versions' not implemented",
+        ))
+    }
+
     async fn gc_drop_tables(&self, _req: GcDroppedTableReq) -> Result<GcDroppedTableResp> {
         Err(ErrorCode::Unimplemented("'gc_drop_tables' not implemented"))
     }
@@ -392,6 +405,14 @@ pub trait Catalog: DynClone + Send + Sync + Debug {
         unimplemented!()
     }
 
+    // List all table functions with how they are called.
+    fn list_table_function_descriptions(&self) -> Vec<TableFunctionDescription> {
+        self.list_table_functions()
+            .into_iter()
+            .map(TableFunctionDescription::new)
+            .collect()
+    }
+
     fn as_any(&self) -> &dyn Any;
 
     // Get table engines
@@ -65,6 +65,8 @@ use databend_common_meta_app::schema::ListIndexesByIdReq;
 use databend_common_meta_app::schema::ListIndexesReq;
 use databend_common_meta_app::schema::ListLockRevReq;
 use databend_common_meta_app::schema::ListLocksReq;
+use databend_common_meta_app::schema::ListTableVersionsReply;
+use databend_common_meta_app::schema::ListTableVersionsReq;
 use databend_common_meta_app::schema::ListVirtualColumnsReq;
 use databend_common_meta_app::schema::LockInfo;
 use databend_common_meta_app::schema::LockMeta;
@@ -104,6 +106,7 @@ use crate::database::Database;
 use crate::table::Table;
 use crate::table_args::TableArgs;
 use crate::table_function::TableFunction;
+use crate::table_function::TableFunctionDescription;
 
 #[derive(Clone, Debug)]
 pub struct SessionCatalog {
@@ -317,6 +320,13 @@ impl Catalog for SessionCatalog {
         self.inner.get_drop_table_infos(req).await
     }
 
+    async fn list_table_versions(
+        &self,
+        req: ListTableVersionsReq,
+    ) -> Result<ListTableVersionsReply> {
+        self.inner.list_table_versions(req).await
+    }
+
     async fn gc_drop_tables(&self, req: GcDroppedTableReq) -> Result<GcDroppedTableResp> {
         self.inner.gc_drop_tables(req).await
     }
@@ -449,6 +459,10 @@ impl Catalog for SessionCatalog {
         self.inner.list_table_functions()
     }
 
+    fn list_table_function_descriptions(&self) -> Vec<TableFunctionDescription> {
+        self.inner.list_table_function_descriptions()
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -42,3 +42,10 @@ impl Cluster {
         self.nodes.len() <= 1
     }
 }
+
+/// What a node of the cluster answers about itself when pinged.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct NodeStatus {
+    pub version: String,
+    pub uptime_seconds: u64,
+}
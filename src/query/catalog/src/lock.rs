@@ -12,14 +12,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt::Display;
+use std::fmt::Formatter;
 use std::sync::Arc;
 
 use databend_common_exception::Result;
+use databend_common_meta_app::schema::ListLocksReq;
+use databend_common_meta_app::schema::LockInfo;
 use databend_common_meta_app::schema::LockType;
 use databend_common_pipeline_core::LockGuard;
 
+use crate::catalog::Catalog;
 use crate::table_context::TableContext;
 
+/// Extra info key of the session that requested the lock.
+pub const LOCK_SESSION_ID: &str = "session_id";
+/// Extra info key of the locked object, e.g. the table description.
+pub const LOCK_OBJECT: &str = "object";
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum LockTableOption {
     NoLock,
@@ -37,9 +47,81 @@ pub trait Lock: Sync + Send {
 
     fn tenant_name(&self) -> &str;
 
+    /// The locked object, recorded in the lock so that it can be listed by name.
+    fn object_name(&self) -> String;
+
     async fn try_lock(
         &self,
         ctx: Arc<dyn TableContext>,
         should_retry: bool,
     ) -> Result<Option<Arc<LockGuard>>>;
 }
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LockStatus {
+    Holding,
+    Waiting,
+}
+
+impl Display for LockStatus {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            LockStatus::Holding => write!(f, "HOLDING"),
+            LockStatus::Waiting => write!(f, "WAITING"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct LockEntry {
+    pub status: LockStatus,
+    pub info: LockInfo,
+}
+
+impl LockEntry {
+    pub fn session_id(&self) -> Option<&str> {
+        self.info
+            .meta
+            .extra_info
+            .get(LOCK_SESSION_ID)
+            .map(|v| v.as_str())
+    }
+
+    pub fn object(&self) -> Option<&str> {
+        self.info
+            .meta
+            .extra_info
+            .get(LOCK_OBJECT)
+            .map(|v| v.as_str())
+    }
+}
+
+/// The lock queues of a catalog at one point in time.
+pub struct LockSnapshot {
+    pub entries: Vec<LockEntry>,
+}
+
+impl LockSnapshot {
+    /// Each queue is read at once, and only its lowest revision can be holding the lock.
+    /// A lock being handed over is therefore never reported twice: the previous holder is
+    /// gone from the queue, and the next one is waiting until it has marked itself acquired.
+    #[async_backtrace::framed]
+    pub async fn load(catalog: &dyn Catalog, req: ListLocksReq) -> Result<Self> {
+        let mut infos = catalog.list_locks(req).await?;
+        infos.sort_by_key(|info| (info.table_id, info.revision));
+
+        let mut entries = Vec::with_capacity(infos.len());
+        let mut prev_table_id = None;
+        for info in infos {
+            let is_head = prev_table_id != Some(info.table_id);
+            prev_table_id = Some(info.table_id);
+            let status = if is_head && info.meta.acquired_on.is_some() {
+                LockStatus::Holding
+            } else {
+                LockStatus::Waiting
+            };
+            entries.push(LockEntry { status, info });
+        }
+        Ok(LockSnapshot { entries })
+    }
+}
@@ -94,6 +94,8 @@ pub enum PartitionsShuffleKind {
     Rand,
     // Bind the Partition to executor by broadcast
     Broadcast,
+    // Bind the Partition to the listed executors by broadcast, the others get no partition.
+    BroadcastTo(Vec<String>),
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq)]
@@ -120,7 +122,7 @@ impl Partitions {
         executors_sorted.sort();
 
         let num_executors = executors_sorted.len();
-        let partitions = match self.kind {
+        let partitions = match &self.kind {
             PartitionsShuffleKind::Seq => self.partitions.clone(),
             PartitionsShuffleKind::Mod => {
                 // Sort by hash%executor_nums.
@@ -147,6 +149,21 @@ impl Partitions {
                     );
                 }
 
+                return Ok(executor_part);
+            }
+            PartitionsShuffleKind::BroadcastTo(targets) => {
+                let mut executor_part = HashMap::default();
+                for executor in executors_sorted.iter() {
+                    let parts = match targets.contains(executor) {
+                        true => self.partitions.clone(),
+                        false => vec![],
+                    };
+                    executor_part.insert(
+                        executor.clone(),
+                        Partitions::create(PartitionsShuffleKind::Seq, parts),
+                    );
+                }
+
                 return Ok(executor_part);
             }
         };
@@ -15,6 +15,12 @@
 use databend_common_expression::Expr;
 use xorf::BinaryFuse16;
 
+/// The filters published by the build side of a hash join for the scan of its probe side, they
+/// are keyed by the table index of the scan in [`TableContext::set_runtime_filter`]. The scan
+/// applies the filters published before it reads a block, the blocks read earlier are not
+/// filtered.
+///
+/// [`TableContext::set_runtime_filter`]: crate::table_context::TableContext::set_runtime_filter
 #[derive(Clone, Debug, Default)]
 pub struct RuntimeFilterInfo {
     inlist: Vec<Expr<String>>,
@@ -65,6 +65,7 @@ use xorf::BinaryFuse16;
 
 use crate::catalog::Catalog;
 use crate::cluster_info::Cluster;
+use crate::cluster_info::NodeStatus;
 use crate::lock::LockTableOption;
 use crate::merge_into_join::MergeIntoJoin;
 use crate::plan::DataSourcePlan;
@@ -95,11 +96,26 @@ pub struct ProcessInfo {
     pub created_time: SystemTime,
     pub status_info: Option<String>,
     pub current_query_id: Option<String>,
+    pub query_tag: Option<String>,
+    /// The labels set by the client of the session.
+    pub labels: BTreeMap<String, String>,
+}
+
+/// The counters of the local server, the `GLOBAL_STATUS` of MySQL.
+#[derive(Debug, Clone, Default)]
+pub struct ServerStatus {
+    pub uptime: Duration,
+    /// The queries started since the server started.
+    pub queries: u64,
+    /// The user sessions, i.e. the connections.
+    pub threads_connected: u64,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum ProcessInfoState {
     Query,
+    // Waiting in the queries queue for a running slot.
+    Queued,
     Aborting,
     Idle,
 }
@@ -108,6 +124,7 @@ impl Display for ProcessInfoState {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             ProcessInfoState::Query => write!(f, "Query"),
+            ProcessInfoState::Queued => write!(f, "Queued"),
             ProcessInfoState::Aborting => write!(f, "Aborting"),
             ProcessInfoState::Idle => write!(f, "Idle"),
         }
@@ -216,6 +233,20 @@ pub trait TableContext: Send + Sync {
         privilege: UserPrivilegeType,
         check_current_role_only: bool,
     ) -> Result<()>;
+
+    /// Like `validate_privilege` on all the roles of the user, but lacking the privilege is
+    /// not an error.
+    async fn has_privilege(
+        &self,
+        object: &GrantObject,
+        privilege: UserPrivilegeType,
+    ) -> Result<bool> {
+        match self.validate_privilege(object, privilege, false).await {
+            Ok(_) => Ok(true),
+            Err(e) if e.code() == ErrorCode::PERMISSION_DENIED => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
     async fn get_available_roles(&self) -> Result<Vec<RoleInfo>>;
     async fn get_visibility_checker(&self) -> Result<GrantObjectVisibilityChecker>;
     fn get_fuse_version(&self) -> String;
@@ -224,13 +255,21 @@ pub trait TableContext: Send + Sync {
     /// Get the kind of session running query.
     fn get_query_kind(&self) -> QueryKind;
     fn get_function_context(&self) -> Result<FunctionContext>;
-    fn get_connection_id(&self) -> String;
+    fn get_connection_id(&self) -> u64;
     fn get_settings(&self) -> Arc<Settings>;
     fn get_shared_settings(&self) -> Arc<Settings>;
     fn get_cluster(&self) -> Arc<Cluster>;
     fn get_processes_info(&self) -> Vec<ProcessInfo>;
     fn get_queued_queries(&self) -> Vec<ProcessInfo>;
+    fn get_server_status(&self) -> ServerStatus;
     fn get_queries_profile(&self) -> HashMap<String, Vec<PlanProfile>>;
+
+    /// Pings every node of the cluster, the nodes that did not answer within the timeout are
+    /// mapped to the error.
+    async fn ping_cluster_nodes(
+        &self,
+        timeout: Duration,
+    ) -> HashMap<String, std::result::Result<NodeStatus, String>>;
     fn get_stage_attachment(&self) -> Option<StageAttachment>;
     fn get_last_query_id(&self, index: i32) -> String;
     fn get_query_id_history(&self) -> HashSet<String>;
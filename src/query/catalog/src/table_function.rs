@@ -16,9 +16,44 @@ use std::sync::Arc;
 
 use crate::table::Table;
 
+/// How a table function is called, listed by `system.table_functions`.
+///
+/// A function registered without a description leaves the fields as `None`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TableFunctionDescription {
+    pub name: String,
+    pub description: Option<String>,
+    pub signature: Option<String>,
+    pub example: Option<String>,
+}
+
+impl TableFunctionDescription {
+    pub fn new(name: impl Into<String>) -> Self {
+        TableFunctionDescription {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn create(name: &str, description: &str, signature: &str, example: &str) -> Self {
+        TableFunctionDescription {
+            name: name.to_string(),
+            description: Some(description.to_string()),
+            signature: Some(signature.to_string()),
+            example: Some(example.to_string()),
+        }
+    }
+}
+
 pub trait TableFunction: Sync + Send + Table {
     fn function_name(&self) -> &str;
 
+    /// Describes the function registered as `name`, `None` if it has no description.
+    fn describe(_name: &str) -> Option<TableFunctionDescription>
+    where Self: Sized {
+        None
+    }
+
     fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
     where Self: 'a;
 }
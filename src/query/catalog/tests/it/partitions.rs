@@ -216,6 +216,27 @@ fn test_partition_reshuffle() {
         let e2_parts = shuffle.get(&executors_2[1]).unwrap();
         writeln!(file, "{:?}", e2_parts).unwrap();
     }
+
+    // BroadcastTo.
+    {
+        let kind = PartitionsShuffleKind::BroadcastTo(vec!["node-2".to_string()]);
+        let partitions = gen_parts(kind, 3);
+        let shuffle = partitions.reshuffle(executors_3.clone()).unwrap();
+
+        writeln!(
+            file,
+            "PartitionsShuffleKind::BroadcastTo: 3 partitions of 3 executors"
+        )
+        .unwrap();
+        let e1_parts = shuffle.get(&executors_3[0]).unwrap();
+        writeln!(file, "{:?}", e1_parts).unwrap();
+
+        let e2_parts = shuffle.get(&executors_3[1]).unwrap();
+        writeln!(file, "{:?}", e2_parts).unwrap();
+
+        let e3_parts = shuffle.get(&executors_3[2]).unwrap();
+        writeln!(file, "{:?}", e3_parts).unwrap();
+    }
 }
 
 #[test]
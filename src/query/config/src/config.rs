@@ -71,6 +71,7 @@ use crate::background_config::BackgroundConfig;
 use crate::builtin::BuiltInConfig;
 use crate::builtin::UDFConfig;
 use crate::builtin::UserConfig;
+use crate::mysql_federated::MySQLFederatedRuleConfig;
 use crate::DATABEND_COMMIT_VERSION;
 
 const CATALOG_HIVE: &str = "hive";
@@ -1390,6 +1391,19 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value = "120")]
     pub mysql_handler_tcp_keepalive_timeout_secs: u64,
 
+    /// The server version the MySQL clients see, empty means `8.0.26-databend-<version>`.
+    #[clap(long, value_name = "VALUE", default_value_t)]
+    pub mysql_handler_server_version: String,
+
+    /// The MySQL federated rules of the operator, `[[query.mysql_federated_rules]]` in the config file.
+    #[clap(skip)]
+    pub mysql_federated_rules: Vec<MySQLFederatedRuleConfig>,
+
+    /// The federated rules of the MySQL and ClickHouse handlers first match the prefix of this
+    /// size of a longer query, and only the queries with a matched prefix are matched in full.
+    #[clap(long, value_name = "VALUE", default_value = "1024")]
+    pub federated_max_query_bytes: u64,
+
     #[clap(long, value_name = "VALUE", default_value_t)]
     pub mysql_tls_server_cert: String,
 
@@ -1402,6 +1416,10 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value = "8")]
     pub max_running_queries: u64,
 
+    /// The max number of queries waiting for a running slot, 0 means unlimited.
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub max_queued_queries: u64,
+
     /// The max total memory in bytes that can be used by this process.
     #[clap(long, value_name = "VALUE", default_value = "0")]
     pub max_server_memory_usage: u64,
@@ -1452,6 +1470,10 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value = "127.0.0.1:7070")]
     pub metric_api_address: String,
 
+    /// Drop the metric label sets not updated for the given hours, 0 means keep them forever.
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub metric_label_retention_hours: u64,
+
     #[clap(long, value_name = "VALUE", default_value_t)]
     pub http_handler_tls_server_cert: String,
 
@@ -1467,6 +1489,59 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value_t)]
     pub flight_sql_tls_server_key: String,
 
+    /// gRPC message compression of the FlightSQL service, one of none|gzip|zstd.
+    #[clap(long, value_name = "VALUE", default_value = "none")]
+    pub flight_sql_grpc_compression: String,
+
+    /// Peers, as IPs or CIDRs, allowed to report the original client address of FlightSQL
+    /// connections through `flight_sql_client_address_header`.
+    #[clap(long, value_name = "VALUE")]
+    pub flight_sql_trusted_proxies: Vec<String>,
+
+    /// Header carrying the original client address when the peer is a trusted proxy.
+    #[clap(long, value_name = "VALUE", default_value = "x-forwarded-for")]
+    pub flight_sql_client_address_header: String,
+
+    /// Max number of FlightSQL cursors opened by `databend-fetch-size` at the same time,
+    /// 0 disables the fetch size mode.
+    #[clap(long, value_name = "VALUE", default_value = "64")]
+    pub flight_sql_max_cursors: u64,
+
+    /// An idle FlightSQL cursor is closed and its query is killed after this many seconds.
+    #[clap(long, value_name = "VALUE", default_value = "300")]
+    pub flight_sql_cursor_idle_timeout_secs: u64,
+
+    /// Max bytes of a SQL text sent through FlightSQL, a longer one is rejected before planning.
+    ///
+    /// It also bounds the size of a decoded FlightSQL message.
+    #[clap(long, value_name = "VALUE", default_value = "4194304")]
+    pub flight_sql_max_query_size: u64,
+
+    /// Max bytes of a FlightSQL ticket, a larger one is rejected before decoding.
+    #[clap(long, value_name = "VALUE", default_value = "1048576")]
+    pub flight_sql_max_ticket_size: u64,
+
+    /// Timeout of a FlightSQL metadata command listing the databases or tables of the catalogs.
+    #[clap(long, value_name = "VALUE", default_value = "30")]
+    pub flight_sql_metadata_timeout_secs: u64,
+
+    /// Statements a user can run through FlightSQL per second, 0 means no limit.
+    /// It is the default of the setting with the same name, which can be changed by SET GLOBAL.
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub flight_sql_statements_per_second: u64,
+
+    /// Statements a user can run through FlightSQL at once, before the rate applies.
+    #[clap(long, value_name = "VALUE", default_value = "200")]
+    pub flight_sql_statements_burst: u64,
+
+    /// FlightSQL metadata commands a user can run per second, 0 means no limit.
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub flight_sql_metadata_per_second: u64,
+
+    /// FlightSQL metadata commands a user can run at once, before the rate applies.
+    #[clap(long, value_name = "VALUE", default_value = "1000")]
+    pub flight_sql_metadata_burst: u64,
+
     #[clap(long, value_name = "VALUE", default_value_t)]
     pub api_tls_server_cert: String,
 
@@ -1509,6 +1584,15 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value = "10000")]
     pub max_query_log_size: usize,
 
+    /// Max rows kept by system.query_log when a query is logged, 0 keeps up to max_query_log_size.
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub query_log_max_rows: u64,
+
+    /// Hours system.query_log keeps a row, older rows are evicted when a query is logged,
+    /// 0 disables it.
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub query_log_retention_hours: u64,
+
     #[clap(long, value_name = "VALUE")]
     pub databend_enterprise_license: Option<String>,
     /// If in management mode, only can do some meta level operations(database/table/user/stage etc.) with metasrv.
@@ -1696,10 +1780,14 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             mysql_handler_host: self.mysql_handler_host,
             mysql_handler_port: self.mysql_handler_port,
             mysql_handler_tcp_keepalive_timeout_secs: self.mysql_handler_tcp_keepalive_timeout_secs,
+            mysql_handler_server_version: self.mysql_handler_server_version,
+            mysql_federated_rules: self.mysql_federated_rules,
+            federated_max_query_bytes: self.federated_max_query_bytes,
             mysql_tls_server_cert: self.mysql_tls_server_cert,
             mysql_tls_server_key: self.mysql_tls_server_key,
             max_active_sessions: self.max_active_sessions,
             max_running_queries: self.max_running_queries,
+            max_queued_queries: self.max_queued_queries,
             max_server_memory_usage: self.max_server_memory_usage,
             max_memory_limit_enabled: self.max_memory_limit_enabled,
             clickhouse_http_handler_host: self.clickhouse_http_handler_host,
@@ -1712,6 +1800,7 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             flight_sql_handler_port: self.flight_sql_handler_port,
             admin_api_address: self.admin_api_address,
             metric_api_address: self.metric_api_address,
+            metric_label_retention_hours: self.metric_label_retention_hours,
             http_handler_tls_server_cert: self.http_handler_tls_server_cert,
             http_handler_tls_server_key: self.http_handler_tls_server_key,
             http_handler_tls_server_root_ca_cert: self.http_handler_tls_server_root_ca_cert,
@@ -1720,6 +1809,18 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             api_tls_server_root_ca_cert: self.api_tls_server_root_ca_cert,
             flight_sql_tls_server_cert: self.flight_sql_tls_server_cert,
             flight_sql_tls_server_key: self.flight_sql_tls_server_key,
+            flight_sql_grpc_compression: self.flight_sql_grpc_compression,
+            flight_sql_trusted_proxies: self.flight_sql_trusted_proxies,
+            flight_sql_client_address_header: self.flight_sql_client_address_header,
+            flight_sql_max_cursors: self.flight_sql_max_cursors,
+            flight_sql_cursor_idle_timeout_secs: self.flight_sql_cursor_idle_timeout_secs,
+            flight_sql_max_query_size: self.flight_sql_max_query_size,
+            flight_sql_max_ticket_size: self.flight_sql_max_ticket_size,
+            flight_sql_metadata_timeout_secs: self.flight_sql_metadata_timeout_secs,
+            flight_sql_statements_per_second: self.flight_sql_statements_per_second,
+            flight_sql_statements_burst: self.flight_sql_statements_burst,
+            flight_sql_metadata_per_second: self.flight_sql_metadata_per_second,
+            flight_sql_metadata_burst: self.flight_sql_metadata_burst,
             rpc_tls_server_cert: self.rpc_tls_server_cert,
             rpc_tls_server_key: self.rpc_tls_server_key,
             rpc_tls_query_server_root_ca_cert: self.rpc_tls_query_server_root_ca_cert,
@@ -1728,6 +1829,8 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             table_engine_memory_enabled: self.table_engine_memory_enabled,
             shutdown_wait_timeout_ms: self.shutdown_wait_timeout_ms,
             max_query_log_size: self.max_query_log_size,
+            query_log_max_rows: self.query_log_max_rows,
+            query_log_retention_hours: self.query_log_retention_hours,
             databend_enterprise_license: self.databend_enterprise_license,
             management_mode: self.management_mode,
             parquet_fast_read_bytes: self.parquet_fast_read_bytes,
@@ -1778,10 +1881,14 @@ impl From<InnerQueryConfig> for QueryConfig {
             mysql_handler_port: inner.mysql_handler_port,
             mysql_handler_tcp_keepalive_timeout_secs: inner
                 .mysql_handler_tcp_keepalive_timeout_secs,
+            mysql_handler_server_version: inner.mysql_handler_server_version,
+            mysql_federated_rules: inner.mysql_federated_rules,
+            federated_max_query_bytes: inner.federated_max_query_bytes,
             mysql_tls_server_cert: inner.mysql_tls_server_cert,
             mysql_tls_server_key: inner.mysql_tls_server_key,
             max_active_sessions: inner.max_active_sessions,
             max_running_queries: inner.max_running_queries,
+            max_queued_queries: inner.max_queued_queries,
             max_server_memory_usage: inner.max_server_memory_usage,
             max_memory_limit_enabled: inner.max_memory_limit_enabled,
 
@@ -1799,6 +1906,7 @@ impl From<InnerQueryConfig> for QueryConfig {
             flight_sql_handler_port: inner.flight_sql_handler_port,
             admin_api_address: inner.admin_api_address,
             metric_api_address: inner.metric_api_address,
+            metric_label_retention_hours: inner.metric_label_retention_hours,
             http_handler_tls_server_cert: inner.http_handler_tls_server_cert,
             http_handler_tls_server_key: inner.http_handler_tls_server_key,
             http_handler_tls_server_root_ca_cert: inner.http_handler_tls_server_root_ca_cert,
@@ -1807,6 +1915,18 @@ impl From<InnerQueryConfig> for QueryConfig {
             api_tls_server_root_ca_cert: inner.api_tls_server_root_ca_cert,
             flight_sql_tls_server_cert: inner.flight_sql_tls_server_cert,
             flight_sql_tls_server_key: inner.flight_sql_tls_server_key,
+            flight_sql_grpc_compression: inner.flight_sql_grpc_compression,
+            flight_sql_trusted_proxies: inner.flight_sql_trusted_proxies,
+            flight_sql_client_address_header: inner.flight_sql_client_address_header,
+            flight_sql_max_cursors: inner.flight_sql_max_cursors,
+            flight_sql_cursor_idle_timeout_secs: inner.flight_sql_cursor_idle_timeout_secs,
+            flight_sql_max_query_size: inner.flight_sql_max_query_size,
+            flight_sql_max_ticket_size: inner.flight_sql_max_ticket_size,
+            flight_sql_metadata_timeout_secs: inner.flight_sql_metadata_timeout_secs,
+            flight_sql_statements_per_second: inner.flight_sql_statements_per_second,
+            flight_sql_statements_burst: inner.flight_sql_statements_burst,
+            flight_sql_metadata_per_second: inner.flight_sql_metadata_per_second,
+            flight_sql_metadata_burst: inner.flight_sql_metadata_burst,
             rpc_tls_server_cert: inner.rpc_tls_server_cert,
             rpc_tls_server_key: inner.rpc_tls_server_key,
             rpc_tls_query_server_root_ca_cert: inner.rpc_tls_query_server_root_ca_cert,
@@ -1815,6 +1935,8 @@ impl From<InnerQueryConfig> for QueryConfig {
             table_engine_memory_enabled: inner.table_engine_memory_enabled,
             shutdown_wait_timeout_ms: inner.shutdown_wait_timeout_ms,
             max_query_log_size: inner.max_query_log_size,
+            query_log_max_rows: inner.query_log_max_rows,
+            query_log_retention_hours: inner.query_log_retention_hours,
             databend_enterprise_license: inner.databend_enterprise_license,
             management_mode: inner.management_mode,
             parquet_fast_read_bytes: inner.parquet_fast_read_bytes,
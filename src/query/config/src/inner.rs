@@ -36,6 +36,9 @@ use super::config::Commands;
 use super::config::Config;
 use crate::background_config::InnerBackgroundConfig;
 use crate::BuiltInConfig;
+use crate::MySQLFederatedRuleConfig;
+use crate::MYSQL_VERSION;
+use crate::QUERY_GIT_SEMVER;
 
 /// Inner config for query.
 ///
@@ -162,10 +165,17 @@ pub struct QueryConfig {
     pub mysql_handler_host: String,
     pub mysql_handler_port: u16,
     pub mysql_handler_tcp_keepalive_timeout_secs: u64,
+    /// The server version the MySQL clients see, empty means `8.0.26-databend-<version>`.
+    pub mysql_handler_server_version: String,
+    pub mysql_federated_rules: Vec<MySQLFederatedRuleConfig>,
+    /// The prefix of a longer query the federated rules first match.
+    pub federated_max_query_bytes: u64,
     pub mysql_tls_server_cert: String,
     pub mysql_tls_server_key: String,
     pub max_active_sessions: u64,
     pub max_running_queries: u64,
+    /// The max number of queries waiting for a running slot, 0 means unlimited.
+    pub max_queued_queries: u64,
     pub max_server_memory_usage: u64,
     pub max_memory_limit_enabled: bool,
     pub clickhouse_http_handler_host: String,
@@ -178,6 +188,8 @@ pub struct QueryConfig {
     pub flight_sql_handler_port: u16,
     pub admin_api_address: String,
     pub metric_api_address: String,
+    /// Drop the metric label sets not updated for the given hours, 0 means keep them forever.
+    pub metric_label_retention_hours: u64,
     pub http_handler_tls_server_cert: String,
     pub http_handler_tls_server_key: String,
     pub http_handler_tls_server_root_ca_cert: String,
@@ -186,6 +198,34 @@ pub struct QueryConfig {
     pub api_tls_server_root_ca_cert: String,
     pub flight_sql_tls_server_cert: String,
     pub flight_sql_tls_server_key: String,
+    /// gRPC message compression of the FlightSQL service, one of none|gzip|zstd.
+    pub flight_sql_grpc_compression: String,
+    /// Peers, as IPs or CIDRs, allowed to report the original client address of FlightSQL
+    /// connections through `flight_sql_client_address_header`.
+    pub flight_sql_trusted_proxies: Vec<String>,
+    /// Header carrying the original client address when the peer is a trusted proxy.
+    pub flight_sql_client_address_header: String,
+    /// Max number of FlightSQL cursors opened by `databend-fetch-size` at the same time,
+    /// 0 disables the fetch size mode.
+    pub flight_sql_max_cursors: u64,
+    /// An idle FlightSQL cursor is closed and its query is killed after this many seconds.
+    pub flight_sql_cursor_idle_timeout_secs: u64,
+    /// Max bytes of a SQL text sent through FlightSQL, a longer one is rejected before planning.
+    /// It also bounds the size of a decoded FlightSQL message.
+    pub flight_sql_max_query_size: u64,
+    /// Max bytes of a FlightSQL ticket, a larger one is rejected before decoding.
+    pub flight_sql_max_ticket_size: u64,
+    /// Timeout of a FlightSQL metadata command listing the databases or tables of the catalogs.
+    pub flight_sql_metadata_timeout_secs: u64,
+    /// Statements a user can run through FlightSQL per second, 0 means no limit.
+    /// It is the default of the setting with the same name, which can be changed by SET GLOBAL.
+    pub flight_sql_statements_per_second: u64,
+    /// Statements a user can run through FlightSQL at once, before the rate applies.
+    pub flight_sql_statements_burst: u64,
+    /// FlightSQL metadata commands a user can run per second, 0 means no limit.
+    pub flight_sql_metadata_per_second: u64,
+    /// FlightSQL metadata commands a user can run at once, before the rate applies.
+    pub flight_sql_metadata_burst: u64,
     /// rpc server cert
     pub rpc_tls_server_cert: String,
     /// key for rpc server cert
@@ -199,6 +239,11 @@ pub struct QueryConfig {
     /// Graceful shutdown timeout
     pub shutdown_wait_timeout_ms: u64,
     pub max_query_log_size: usize,
+    /// Max rows kept by system.query_log when a query is logged, 0 keeps up to max_query_log_size.
+    pub query_log_max_rows: u64,
+    /// Hours system.query_log keeps a row, older rows are evicted when a query is logged,
+    /// 0 disables it.
+    pub query_log_retention_hours: u64,
     pub databend_enterprise_license: Option<String>,
     /// If in management mode, only can do some meta level operations(database/table/user/stage etc.) with metasrv.
     pub management_mode: bool,
@@ -250,10 +295,14 @@ impl Default for QueryConfig {
             mysql_handler_host: "127.0.0.1".to_string(),
             mysql_handler_port: 3307,
             mysql_handler_tcp_keepalive_timeout_secs: 120,
+            mysql_handler_server_version: "".to_string(),
+            mysql_federated_rules: vec![],
+            federated_max_query_bytes: 1024,
             mysql_tls_server_cert: "".to_string(),
             mysql_tls_server_key: "".to_string(),
             max_active_sessions: 256,
             max_running_queries: 8,
+            max_queued_queries: 0,
             max_server_memory_usage: 0,
             max_memory_limit_enabled: false,
             clickhouse_http_handler_host: "127.0.0.1".to_string(),
@@ -266,6 +315,7 @@ impl Default for QueryConfig {
             flight_sql_handler_port: 8900,
             admin_api_address: "127.0.0.1:8080".to_string(),
             metric_api_address: "127.0.0.1:7070".to_string(),
+            metric_label_retention_hours: 0,
             api_tls_server_cert: "".to_string(),
             api_tls_server_key: "".to_string(),
             api_tls_server_root_ca_cert: "".to_string(),
@@ -281,6 +331,8 @@ impl Default for QueryConfig {
             table_engine_memory_enabled: true,
             shutdown_wait_timeout_ms: 5000,
             max_query_log_size: 10_000,
+            query_log_max_rows: 0,
+            query_log_retention_hours: 0,
             databend_enterprise_license: None,
             management_mode: false,
             parquet_fast_read_bytes: None,
@@ -297,6 +349,18 @@ impl Default for QueryConfig {
             internal_merge_on_read_mutation: false,
             disable_system_table_load: false,
             flight_sql_tls_server_key: "".to_string(),
+            flight_sql_grpc_compression: "none".to_string(),
+            flight_sql_trusted_proxies: Vec::new(),
+            flight_sql_client_address_header: "x-forwarded-for".to_string(),
+            flight_sql_max_cursors: 64,
+            flight_sql_cursor_idle_timeout_secs: 300,
+            flight_sql_max_query_size: 4 * 1024 * 1024,
+            flight_sql_max_ticket_size: 1024 * 1024,
+            flight_sql_metadata_timeout_secs: 30,
+            flight_sql_statements_per_second: 0,
+            flight_sql_statements_burst: 200,
+            flight_sql_metadata_per_second: 0,
+            flight_sql_metadata_burst: 1000,
             openai_api_chat_base_url: "https://api.openai.com/v1/".to_string(),
             openai_api_embedding_base_url: "https://api.openai.com/v1/".to_string(),
             openai_api_key: "".to_string(),
@@ -315,6 +379,15 @@ impl Default for QueryConfig {
 }
 
 impl QueryConfig {
+    /// The version in the MySQL handshake, `VERSION()` and `@@version`, so they never disagree.
+    pub fn mysql_server_version(&self) -> String {
+        if self.mysql_handler_server_version.is_empty() {
+            format!("{}-databend-{}", MYSQL_VERSION, *QUERY_GIT_SEMVER)
+        } else {
+            self.mysql_handler_server_version.clone()
+        }
+    }
+
     pub fn to_rpc_client_tls_config(&self) -> RpcClientTlsConfig {
         RpcClientTlsConfig {
             rpc_tls_server_root_ca_cert: self.rpc_tls_query_server_root_ca_cert.clone(),
@@ -34,6 +34,7 @@ mod config;
 mod global;
 mod inner;
 mod mask;
+mod mysql_federated;
 mod obsolete;
 mod version;
 
@@ -51,7 +52,11 @@ pub use inner::CatalogHiveConfig;
 pub use inner::DiskCacheKeyReloadPolicy;
 pub use inner::InnerConfig;
 pub use inner::ThriftProtocol;
+pub use mysql_federated::MySQLFederatedColumnConfig;
+pub use mysql_federated::MySQLFederatedResponse;
+pub use mysql_federated::MySQLFederatedRuleConfig;
 pub use version::DATABEND_COMMIT_VERSION;
+pub use version::MYSQL_VERSION;
 pub use version::QUERY_GIT_SEMVER;
 pub use version::QUERY_GIT_SHA;
 pub use version::QUERY_SEMVER;
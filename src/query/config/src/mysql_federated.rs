@@ -0,0 +1,60 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A MySQL federated rule of the operator, it answers the probe queries of the BI tools
+/// and is checked after the built-in rules, e.g.
+///
+/// ```toml
+/// [[query.mysql_federated_rules]]
+/// name = "mybi_probe"
+/// pattern = "^SELECT\\s+mybi_version\\(\\)"
+/// response = "table"
+/// columns = [{ name = "version", type = "String" }]
+/// rows = [["1.0"]]
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MySQLFederatedRuleConfig {
+    pub name: String,
+    /// The case-insensitive regex of the query.
+    pub pattern: String,
+    #[serde(default)]
+    pub response: MySQLFederatedResponse,
+    /// The columns of the `table` response.
+    #[serde(default)]
+    pub columns: Vec<MySQLFederatedColumnConfig>,
+    /// The rows of the `table` response, the values are parsed as the column types.
+    #[serde(default)]
+    pub rows: Vec<Vec<String>>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MySQLFederatedResponse {
+    /// An OK packet without the result set.
+    #[default]
+    Empty,
+    /// A constant table of the `columns` and `rows`.
+    Table,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MySQLFederatedColumnConfig {
+    pub name: String,
+    /// One of `String`, `Int64`, `UInt64`, `Float64` and `Boolean`.
+    #[serde(rename = "type")]
+    pub data_type: String,
+}
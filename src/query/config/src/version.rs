@@ -16,6 +16,9 @@ use std::sync::LazyLock;
 
 use semver::Version;
 
+/// The MySQL version the MySQL handler is compatible with.
+pub const MYSQL_VERSION: &str = "8.0.26";
+
 pub static DATABEND_COMMIT_VERSION: LazyLock<String> = LazyLock::new(|| {
     let semver = option_env!("DATABEND_GIT_SEMVER");
     let git_sha = option_env!("VERGEN_GIT_SHA");
@@ -19,6 +19,7 @@ use databend_common_base::base::tokio::sync::mpsc::Sender;
 use databend_common_base::base::tokio::sync::Mutex;
 use databend_common_base::base::uuid::Uuid;
 use databend_common_base::base::GlobalInstance;
+use databend_common_base::base::ServerWarnings;
 use databend_common_config::InnerConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -115,6 +116,13 @@ impl BackgroundServiceHandler for RealBackgroundService {
                 "Background service is only available in enterprise edition. error: {}",
                 e
             );
+            ServerWarnings::instance().warn(
+                "license",
+                format!(
+                    "background service is only available in enterprise edition: {}",
+                    e
+                ),
+            );
         }
 
         let scheduler = self.scheduler.clone();
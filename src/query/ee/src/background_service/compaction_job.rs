@@ -25,6 +25,7 @@ use databend_common_base::base::tokio::sync::mpsc::Sender;
 use databend_common_base::base::tokio::sync::Mutex;
 use databend_common_base::base::tokio::time::Instant;
 use databend_common_base::base::uuid::Uuid;
+use databend_common_base::base::ServerWarnings;
 use databend_common_config::InnerConfig;
 use databend_common_exception::Result;
 use databend_common_meta_api::BackgroundApi;
@@ -218,6 +219,10 @@ impl CompactionJob {
                             "compaction job failed, db: {}, table: {}, err: {}",
                             db_name, tb_name, e
                         );
+                        ServerWarnings::instance().error(
+                            "background_compaction",
+                            format!("compaction of {}.{} failed: {}", db_name, tb_name, e),
+                        );
                     }
                 }
             }
@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use dashmap::DashMap;
 use databend_common_base::base::GlobalInstance;
+use databend_common_base::base::ServerWarnings;
 use databend_common_exception::exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_exception::ToErrorCode;
@@ -130,10 +131,9 @@ impl RealLicenseManager {
         match l.expires_at {
             Some(expire_at) => {
                 if now > expire_at {
-                    return Err(ErrorCode::LicenseKeyInvalid(format!(
-                        "license key expired in {:?}",
-                        expire_at
-                    )));
+                    let message = format!("license key expired in {:?}", expire_at);
+                    ServerWarnings::instance().warn("license", &message);
+                    return Err(ErrorCode::LicenseKeyInvalid(message));
                 }
             }
             None => {
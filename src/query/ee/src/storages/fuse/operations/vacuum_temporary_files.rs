@@ -19,6 +19,7 @@ use std::time::UNIX_EPOCH;
 
 use databend_common_exception::Result;
 use databend_common_storage::DataOperator;
+use databend_common_storage::SpillFiles;
 use futures_util::stream;
 use futures_util::TryStreamExt;
 use log::info;
@@ -115,8 +116,9 @@ pub async fn do_vacuum_temporary_files(
             let cur_removed = remove_temp_files_path.len();
             total_cleaned_size += batch_size;
             operator
-                .remove_via(stream::iter(remove_temp_files_path))
+                .remove_via(stream::iter(remove_temp_files_path.clone()))
                 .await?;
+            SpillFiles::instance().forget(&remove_temp_files_path);
 
             // Log for the current batch
             info!(
@@ -211,8 +213,9 @@ async fn vacuum_finished_query(
             let cur_removed = remove_temp_files_path.len();
             *total_cleaned_size += *batch_size;
             operator
-                .remove_via(stream::iter(remove_temp_files_path))
+                .remove_via(stream::iter(remove_temp_files_path.clone()))
                 .await?;
+            SpillFiles::instance().forget(&remove_temp_files_path);
 
             // Log for the current batch
             info!(
@@ -17,16 +17,20 @@ use std::time::Duration;
 
 use databend_common_base::base::tokio;
 use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::ScalarRef;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_storage::DataOperator;
+use databend_common_storage::SpillFiles;
 use databend_common_storages_fuse::TableContext;
 use databend_enterprise_query::storages::fuse::do_vacuum_drop_tables;
 use databend_enterprise_query::storages::fuse::operations::vacuum_drop_tables::do_vacuum_drop_table;
 use databend_enterprise_query::storages::fuse::operations::vacuum_temporary_files::do_vacuum_temporary_files;
 use databend_query::test_kits::*;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use futures_util::TryStreamExt;
 use opendal::raw::Access;
 use opendal::raw::AccessorInfo;
 use opendal::raw::OpStat;
@@ -146,6 +150,84 @@ async fn test_do_vacuum_temporary_files() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_vacuum_temporary_files_of_spilled_query() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    ctx.get_settings().set_setting(
+        "sort_spilling_bytes_threshold_per_proc".to_string(),
+        "8".to_string(),
+    )?;
+    execute_command(
+        ctx.clone(),
+        "select number from numbers(10000) order by number desc",
+    )
+    .await?;
+    let query_id = ctx.get_id();
+
+    let spilled = SpillFiles::instance().list_query(&query_id);
+    assert!(!spilled.is_empty());
+    assert!(spilled.iter().all(|file| file.operator == "OrderBy"));
+    let spilled_bytes = spilled.iter().map(|file| file.bytes).sum::<u64>();
+    assert!(SpillFiles::instance().total_bytes() >= spilled_bytes);
+
+    let sql = format!(
+        "select operator, file_content_length from system.temp_files where query_id = '{query_id}'"
+    );
+    let blocks = fixture
+        .execute_query(&sql)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let block = DataBlock::concat(&blocks)?;
+    assert_eq!(block.num_rows(), spilled.len());
+    for row in 0..block.num_rows() {
+        assert_eq!(
+            block.get_by_offset(0).value.index(row),
+            Some(ScalarRef::String("OrderBy"))
+        );
+    }
+
+    // The rows vanish with the files, and the registry forgets them.
+    let (spill_prefix, _) = spilled[0].location.rsplit_once('/').unwrap();
+    do_vacuum_temporary_files(spill_prefix.to_string(), Some(Duration::from_secs(0)), 1000).await?;
+
+    assert!(SpillFiles::instance().list_query(&query_id).is_empty());
+    let blocks = fixture
+        .execute_query(&sql)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(blocks.iter().map(|b| b.num_rows()).sum::<usize>(), 0);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_spill_files_forgotten_when_query_finishes() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    ctx.get_settings().set_setting(
+        "sort_spilling_bytes_threshold_per_proc".to_string(),
+        "8".to_string(),
+    )?;
+    execute_command(
+        ctx.clone(),
+        "select number from numbers(10000) order by number desc",
+    )
+    .await?;
+    let query_id = ctx.get_id();
+    assert!(!SpillFiles::instance().list_query(&query_id).is_empty());
+
+    // The files are left to the vacuum, but the registry does not keep them.
+    drop(ctx);
+    assert!(SpillFiles::instance().list_query(&query_id).is_empty());
+
+    Ok(())
+}
+
 mod test_accessor {
     use std::sync::atomic::AtomicBool;
     use std::sync::atomic::Ordering;
@@ -247,6 +247,77 @@ pub fn gerenate_like_pattern(pattern: &[u8]) -> LikePattern {
     }
 }
 
+/// The matcher of a LIKE pattern in the SHOW and metadata commands, built by
+/// [`like_pattern_to_matcher`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LikeMatcher {
+    tokens: Vec<LikeToken>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum LikeToken {
+    // '%', any characters.
+    Any,
+    // '_', exactly one character.
+    One,
+    Char(char),
+}
+
+impl LikeMatcher {
+    pub fn matches(&self, name: &str) -> bool {
+        let haystack = name.to_lowercase().chars().collect::<Vec<_>>();
+        let (mut tx, mut hx) = (0, 0);
+        // The position after the last '%' and the haystack position it is retried from.
+        let mut backtrack = None;
+        while hx < haystack.len() {
+            match self.tokens.get(tx) {
+                Some(LikeToken::Any) => {
+                    tx += 1;
+                    backtrack = Some((tx, hx));
+                }
+                Some(LikeToken::One) => {
+                    tx += 1;
+                    hx += 1;
+                }
+                Some(LikeToken::Char(c)) if *c == haystack[hx] => {
+                    tx += 1;
+                    hx += 1;
+                }
+                _ => match backtrack {
+                    Some((next_tx, next_hx)) => {
+                        tx = next_tx;
+                        hx = next_hx + 1;
+                        backtrack = Some((next_tx, hx));
+                    }
+                    None => return false,
+                },
+            }
+        }
+        self.tokens[tx..]
+            .iter()
+            .all(|token| *token == LikeToken::Any)
+    }
+}
+
+/// Builds the matcher of a LIKE pattern in `SHOW ... LIKE` and the metadata commands, the same
+/// as MySQL: `%` matches any characters, `_` matches one character, `\` makes the next
+/// character literal, and the names compare case-insensitively like MySQL identifiers.
+pub fn like_pattern_to_matcher(pattern: &str) -> LikeMatcher {
+    let pattern = pattern.to_lowercase();
+    let mut chars = pattern.chars();
+    let mut tokens = vec![];
+    while let Some(c) = chars.next() {
+        tokens.push(match c {
+            '%' => LikeToken::Any,
+            '_' => LikeToken::One,
+            // A trailing backslash matches itself.
+            '\\' => LikeToken::Char(chars.next().unwrap_or('\\')),
+            c => LikeToken::Char(c),
+        });
+    }
+    LikeMatcher { tokens }
+}
+
 #[inline]
 fn decode_one(data: &[u8]) -> Option<(u8, usize)> {
     if data.is_empty() {
@@ -345,3 +416,41 @@ fn test_gerenate_like_pattern() {
         assert_eq!(pattern_type, gerenate_like_pattern(pattern.as_bytes()));
     }
 }
+
+#[test]
+fn test_like_pattern_to_matcher() {
+    // The results of MySQL for `SELECT name LIKE pattern`.
+    let test_cases = vec![
+        ("t1", "t%", true),
+        ("t1", "T%", true),
+        ("T1", "t_", true),
+        ("t12", "t_", false),
+        ("t", "t_", false),
+        ("t", "t%", true),
+        ("abc", "%", true),
+        ("", "%", true),
+        ("", "_", false),
+        ("abc", "a%c", true),
+        ("abcbc", "a%bc", true),
+        ("abcbd", "a%bc", false),
+        ("a_b", "a\\_b", true),
+        ("axb", "a\\_b", false),
+        ("a%b", "a\\%b", true),
+        ("axxb", "a\\%b", false),
+        ("a\\b", "a\\\\b", true),
+        ("ab\\", "ab\\", true),
+        ("wait_timeout", "%timeout%", true),
+        ("net_read_timeout", "%_timeout", true),
+        ("max_allowed_packet", "max\\_%", true),
+        ("maxallowed", "max\\_%", false),
+        ("数据库", "数_库", true),
+        ("数据库", "%库", true),
+    ];
+    for (name, pattern, expected) in test_cases {
+        assert_eq!(
+            like_pattern_to_matcher(pattern).matches(name),
+            expected,
+            "{name} LIKE {pattern}"
+        );
+    }
+}
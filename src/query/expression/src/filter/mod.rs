@@ -24,6 +24,8 @@ mod selector;
 pub use filter_executor::FilterExecutor;
 pub use like::gerenate_like_pattern;
 pub use like::is_like_pattern_escape;
+pub use like::like_pattern_to_matcher;
+pub use like::LikeMatcher;
 pub use like::LikePattern;
 pub use select_expr::SelectExpr;
 pub use select_expr::SelectExprBuilder;
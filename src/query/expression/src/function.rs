@@ -34,6 +34,7 @@ use serde::Serialize;
 
 use crate::date_helper::TzLUT;
 use crate::property::Domain;
+use crate::property::FunctionDoc;
 use crate::property::FunctionProperty;
 use crate::type_check::try_unify_signature;
 use crate::types::nullable::NullableColumn;
@@ -164,6 +165,9 @@ pub struct FunctionRegistry {
     pub auto_try_cast_rules: Vec<(DataType, DataType)>,
 
     pub properties: HashMap<String, FunctionProperty>,
+
+    /// The documentation of the functions, by function name.
+    pub docs: HashMap<String, FunctionDoc>,
 }
 
 impl Function {
@@ -408,6 +412,20 @@ impl FunctionRegistry {
         }
     }
 
+    /// The documentation of a function, or of the function an alias stands for.
+    pub fn get_doc(&self, func_name: &str) -> Option<&FunctionDoc> {
+        let func_name = func_name.to_lowercase();
+        self.docs.get(&func_name).or_else(|| {
+            self.aliases
+                .get(&func_name)
+                .and_then(|name| self.docs.get(name))
+        })
+    }
+
+    pub fn register_doc(&mut self, fn_name: &str, doc: FunctionDoc) {
+        self.docs.insert(fn_name.to_string(), doc);
+    }
+
     pub fn register_function(&mut self, func: Function) {
         let name = func.signature.name.clone();
         let id = self.next_function_id(&name);
@@ -59,6 +59,34 @@ impl FunctionProperty {
     }
 }
 
+/// The documentation of a function, as listed in `system.functions`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FunctionDoc {
+    pub category: &'static str,
+    /// Introduce the function in brief.
+    pub description: &'static str,
+    /// How the function is called, e.g. `LENGTH(<expr>)`.
+    pub syntax: &'static str,
+    /// SQL using the function that can be run directly.
+    pub example: &'static str,
+}
+
+impl FunctionDoc {
+    pub const fn new(
+        category: &'static str,
+        description: &'static str,
+        syntax: &'static str,
+        example: &'static str,
+    ) -> Self {
+        FunctionDoc {
+            category,
+            description,
+            syntax,
+            example,
+        }
+    }
+}
+
 impl Default for FunctionProperty {
     fn default() -> Self {
         FunctionProperty {
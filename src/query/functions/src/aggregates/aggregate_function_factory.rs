@@ -19,6 +19,7 @@ use std::sync::LazyLock;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
+use databend_common_expression::FunctionDoc;
 use databend_common_expression::Scalar;
 
 use super::AggregateFunctionCombinatorNull;
@@ -46,6 +47,7 @@ static FACTORY: LazyLock<Arc<AggregateFunctionFactory>> = LazyLock::new(|| {
     let mut factory = AggregateFunctionFactory::create();
     Aggregators::register(&mut factory);
     Aggregators::register_combinator(&mut factory);
+    crate::docs::register_aggregate_docs(&mut factory);
     Arc::new(factory)
 });
 
@@ -140,6 +142,31 @@ impl AggregateFunctionFactory {
         case_insensitive_desc.insert(name.to_lowercase(), desc);
     }
 
+    /// Documents a registered function, the unknown names are ignored.
+    pub fn register_doc(&mut self, name: &str, doc: FunctionDoc) {
+        if let Some(desc) = self.case_insensitive_desc.get_mut(&name.to_lowercase()) {
+            desc.features.category = doc.category;
+            desc.features.description = doc.description;
+            desc.features.definition = doc.syntax;
+            desc.features.example = doc.example;
+        }
+    }
+
+    /// The documentation of a function, if it has been written.
+    pub fn get_doc(&self, name: &str) -> Option<FunctionDoc> {
+        let features = &self
+            .case_insensitive_desc
+            .get(&name.to_lowercase())?
+            .features;
+        let doc = FunctionDoc::new(
+            features.category,
+            features.description,
+            features.definition,
+            features.example,
+        );
+        (doc != FunctionDoc::default()).then_some(doc)
+    }
+
     pub fn register_combinator(&mut self, suffix: &str, desc: CombinatorDescription) {
         for (exists_suffix, _) in &self.case_insensitive_combinator_desc {
             if exists_suffix.eq_ignore_ascii_case(suffix) {
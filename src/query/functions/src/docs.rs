@@ -0,0 +1,72 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The documentation of the builtin functions, listed in `system.functions`.
+//!
+//! A function is documented by adding a line to the table of its kind, the functions without one
+//! are listed with empty documentation.
+
+use databend_common_expression::FunctionDoc;
+use databend_common_expression::FunctionRegistry;
+
+use crate::aggregates::AggregateFunctionFactory;
+
+/// A table of `name => category, description, syntax, example;` lines.
+macro_rules! function_docs {
+    ($($name:literal => $category:literal, $description:literal, $syntax:literal, $example:literal;)*) => {
+        &[$(($name, FunctionDoc::new($category, $description, $syntax, $example))),*]
+    };
+}
+
+pub const SCALAR_FUNCTION_DOCS: &[(&str, FunctionDoc)] = function_docs! {
+    "abs" => "math", "Returns the absolute value of a number.", "ABS(<expr>)", "SELECT ABS(-5)";
+    "round" => "math", "Rounds a number to the given number of decimal places.", "ROUND(<expr>[, <places>])", "SELECT ROUND(3.14159, 2)";
+    "length" => "string", "Returns the number of characters of a string, or of elements of an array.", "LENGTH(<expr>)", "SELECT LENGTH('databend')";
+    "substr" => "string", "Returns the part of a string starting at a position, optionally of a given length.", "SUBSTR(<expr>, <pos>[, <len>])", "SELECT SUBSTR('databend', 5, 3)";
+    "now" => "datetime", "Returns the current timestamp.", "NOW()", "SELECT NOW()";
+    "today" => "datetime", "Returns the current date.", "TODAY()", "SELECT TODAY()";
+    "to_date" => "datetime", "Converts a string, a timestamp or a number of days since the epoch to a date.", "TO_DATE(<expr>[, <format>])", "SELECT TO_DATE('2024-01-31')";
+    "if" => "conditional", "Returns the value of the first true condition, or the last value if none is true.", "IF(<cond1>, <expr1>[, <cond2>, <expr2> ...], <else>)", "SELECT IF(1 > 2, 'yes', 'no')";
+};
+
+pub const WINDOW_FUNCTION_DOCS: &[(&str, FunctionDoc)] = function_docs! {
+    "row_number" => "window", "Returns the number of the row within its partition, starting at 1.", "ROW_NUMBER() OVER (...)", "SELECT number, ROW_NUMBER() OVER (ORDER BY number) FROM numbers(3)";
+    "rank" => "window", "Returns the rank of the row within its partition, with gaps after ties.", "RANK() OVER (...)", "SELECT number % 2, RANK() OVER (ORDER BY number % 2) FROM numbers(4)";
+    "dense_rank" => "window", "Returns the rank of the row within its partition, without gaps after ties.", "DENSE_RANK() OVER (...)", "SELECT number % 2, DENSE_RANK() OVER (ORDER BY number % 2) FROM numbers(4)";
+    "lag" => "window", "Returns the value of an expression on a preceding row of the partition.", "LAG(<expr>[, <offset>[, <default>]]) OVER (...)", "SELECT number, LAG(number) OVER (ORDER BY number) FROM numbers(3)";
+    "lead" => "window", "Returns the value of an expression on a following row of the partition.", "LEAD(<expr>[, <offset>[, <default>]]) OVER (...)", "SELECT number, LEAD(number) OVER (ORDER BY number) FROM numbers(3)";
+    "first_value" => "window", "Returns the value of an expression on the first row of the window frame.", "FIRST_VALUE(<expr>) OVER (...)", "SELECT number, FIRST_VALUE(number) OVER (ORDER BY number) FROM numbers(3)";
+    "last_value" => "window", "Returns the value of an expression on the last row of the window frame.", "LAST_VALUE(<expr>) OVER (...)", "SELECT number, LAST_VALUE(number) OVER (ORDER BY number) FROM numbers(3)";
+    "ntile" => "window", "Divides the partition into a number of buckets and returns the bucket of the row.", "NTILE(<buckets>) OVER (...)", "SELECT number, NTILE(2) OVER (ORDER BY number) FROM numbers(4)";
+};
+
+pub const AGGREGATE_FUNCTION_DOCS: &[(&str, FunctionDoc)] = function_docs! {
+    "sum" => "aggregate", "Returns the sum of the values.", "SUM(<expr>)", "SELECT SUM(number) FROM numbers(10)";
+    "count" => "aggregate", "Returns the number of rows, or of the values that are not NULL.", "COUNT(*) | COUNT(<expr>)", "SELECT COUNT(*) FROM numbers(10)";
+    "avg" => "aggregate", "Returns the average of the values.", "AVG(<expr>)", "SELECT AVG(number) FROM numbers(10)";
+    "min" => "aggregate", "Returns the smallest value.", "MIN(<expr>)", "SELECT MIN(number) FROM numbers(10)";
+    "max" => "aggregate", "Returns the largest value.", "MAX(<expr>)", "SELECT MAX(number) FROM numbers(10)";
+};
+
+pub fn register(registry: &mut FunctionRegistry) {
+    for (name, doc) in SCALAR_FUNCTION_DOCS.iter().chain(WINDOW_FUNCTION_DOCS) {
+        registry.register_doc(name, *doc);
+    }
+}
+
+pub fn register_aggregate_docs(factory: &mut AggregateFunctionFactory) {
+    for (name, doc) in AGGREGATE_FUNCTION_DOCS {
+        factory.register_doc(name, *doc);
+    }
+}
@@ -30,6 +30,7 @@ use databend_common_expression::FunctionRegistry;
 
 pub mod aggregates;
 mod cast_rules;
+pub mod docs;
 pub mod scalars;
 pub mod srfs;
 
@@ -79,6 +80,7 @@ fn builtin_functions() -> FunctionRegistry {
     cast_rules::register(&mut registry);
     scalars::register(&mut registry);
     srfs::register(&mut registry);
+    docs::register(&mut registry);
 
     registry
 }
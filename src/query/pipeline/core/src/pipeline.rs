@@ -32,6 +32,8 @@ use crate::finished_chain::ExecutionInfo;
 use crate::finished_chain::FinishedCallbackChain;
 use crate::pipe::Pipe;
 use crate::pipe::PipeItem;
+use crate::processors::DispatchPolicy;
+use crate::processors::DispatchProcessor;
 use crate::processors::DuplicateProcessor;
 use crate::processors::InputPort;
 use crate::processors::OutputPort;
@@ -318,6 +320,8 @@ impl Pipeline {
         self.resize(new_size, false)
     }
 
+    /// The blocks of the M outputs go to the N inputs which need data first,
+    /// the order of the blocks isn't kept.
     pub fn resize(&mut self, new_size: usize, force: bool) -> Result<()> {
         match self.pipes.last() {
             None => Err(ErrorCode::Internal("Cannot resize empty pipe.")),
@@ -416,6 +420,41 @@ impl Pipeline {
         }
     }
 
+    /// Dispatch the blocks of the pipe outputs to `n` outputs by the policy.
+    ///
+    /// Unlike `resize`, the policy decides which output a block goes to, e.g. the
+    /// same values always go to the same output with `DispatchPolicy::Hash`.
+    /// The output k of the dispatcher of every pipe is merged into the output k.
+    pub fn dispatch(&mut self, policy: DispatchPolicy, n: usize) -> Result<()> {
+        match self.pipes.last() {
+            Some(pipe) if pipe.output_length > 0 && n > 0 => {
+                let width = pipe.output_length;
+                let mut items = Vec::with_capacity(width);
+                for _ in 0..width {
+                    let input = InputPort::create();
+                    let outputs = (0..n).map(|_| OutputPort::create()).collect::<Vec<_>>();
+                    let processor =
+                        DispatchProcessor::create(input.clone(), outputs.clone(), policy.clone());
+                    items.push(PipeItem::create(
+                        ProcessorPtr::create(Box::new(processor)),
+                        vec![input],
+                        outputs,
+                    ));
+                }
+                self.add_pipe(Pipe::create(width, width * n, items));
+
+                if width > 1 {
+                    // The output i * n + k of the dispatchers goes to the range of the output k.
+                    let rule = (0..width * n).map(|i| (i % n) * width + i / n).collect();
+                    self.reorder_inputs(rule);
+                    self.resize_partial_one_with_width(vec![width; n])?;
+                }
+                Ok(())
+            }
+            _ => Err(ErrorCode::Internal("Cannot dispatch empty pipe.")),
+        }
+    }
+
     /// Used to re-order the input data according to the rule.
     ///
     /// `rule` is a vector of [usize], each element is the index of the output port.
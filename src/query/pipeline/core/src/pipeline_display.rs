@@ -22,6 +22,11 @@ impl Pipeline {
     pub fn display_indent(&self) -> impl std::fmt::Display + '_ {
         PipelineIndentDisplayWrapper { pipeline: self }
     }
+
+    /// Displays the processors and the connections of their ports as a graphviz dot graph.
+    pub fn display_graphviz(&self) -> impl std::fmt::Display + '_ {
+        PipelineGraphvizDisplayWrapper { pipeline: self }
+    }
 }
 
 struct PipelineIndentDisplayWrapper<'a> {
@@ -60,3 +65,47 @@ impl<'a> Display for PipelineIndentDisplayWrapper<'a> {
         Ok(())
     }
 }
+
+struct PipelineGraphvizDisplayWrapper<'a> {
+    pipeline: &'a Pipeline,
+}
+
+impl<'a> Display for PipelineGraphvizDisplayWrapper<'a> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        writeln!(f, "digraph {{")?;
+
+        let mut node = 0;
+        let mut edges = vec![];
+        // The nodes of the output ports of the previous pipe, they are connected to the input
+        // ports of the next pipe in order.
+        let mut upstream_nodes: Vec<usize> = vec![];
+        for pipe in &self.pipeline.pipes {
+            let mut upstream_nodes_iter = upstream_nodes.into_iter();
+            let mut output_nodes = Vec::with_capacity(pipe.output_length);
+            for item in &pipe.items {
+                let name = unsafe { item.processor.name() };
+                writeln!(f, "    {node} [ label = {name:?} ]")?;
+
+                for _ in &item.inputs_port {
+                    if let Some(upstream_node) = upstream_nodes_iter.next() {
+                        edges.push((upstream_node, node));
+                    }
+                }
+
+                for _ in &item.outputs_port {
+                    output_nodes.push(node);
+                }
+
+                node += 1;
+            }
+
+            upstream_nodes = output_nodes;
+        }
+
+        for (from, to) in edges {
+            writeln!(f, "    {from} -> {to} [ ]")?;
+        }
+
+        write!(f, "}}")
+    }
+}
@@ -0,0 +1,230 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::group_hash_columns;
+use databend_common_expression::DataBlock;
+use databend_common_expression::InputColumns;
+
+use crate::processors::Event;
+use crate::processors::InputPort;
+use crate::processors::OutputPort;
+use crate::processors::Processor;
+
+/// How the blocks of the input are dispatched to the outputs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DispatchPolicy {
+    /// Each block goes to the next output in turn.
+    RoundRobin,
+    /// Each block goes to the output with the fewest bytes not consumed yet,
+    /// which keeps all the outputs busy when the blocks have skewed sizes.
+    LeastBuffered,
+    /// Each row goes to the output chosen by the hash of the columns at the offsets,
+    /// the rows with the same values always go to the same output.
+    Hash(Vec<usize>),
+}
+
+/// This processor dispatches the input data to multiple outputs by the policy.
+pub struct DispatchProcessor {
+    policy: DispatchPolicy,
+
+    input: Arc<InputPort>,
+    outputs: Vec<Arc<OutputPort>>,
+
+    /// The blocks waiting for their output to be pushed.
+    buffers: Vec<VecDeque<DataBlock>>,
+    /// The bytes of the blocks in the buffer and the port of each output.
+    pending_bytes: Vec<usize>,
+    /// The bytes of the block pushed to the port of each output.
+    port_bytes: Vec<usize>,
+
+    next_output: usize,
+    input_data: Option<DataBlock>,
+}
+
+impl DispatchProcessor {
+    pub fn create(
+        input: Arc<InputPort>,
+        outputs: Vec<Arc<OutputPort>>,
+        policy: DispatchPolicy,
+    ) -> Self {
+        let size = outputs.len();
+        DispatchProcessor {
+            policy,
+            input,
+            outputs,
+            buffers: (0..size).map(|_| VecDeque::new()).collect(),
+            pending_bytes: vec![0; size],
+            port_bytes: vec![0; size],
+            next_output: 0,
+            input_data: None,
+        }
+    }
+
+    /// Pushes the buffered blocks to the outputs which can accept them.
+    fn flush_outputs(&mut self) {
+        for (index, output) in self.outputs.iter().enumerate() {
+            if output.is_finished() {
+                self.buffers[index].clear();
+                self.pending_bytes[index] = 0;
+                self.port_bytes[index] = 0;
+                continue;
+            }
+
+            if !output.can_push() {
+                continue;
+            }
+
+            // The block in the port is consumed.
+            self.pending_bytes[index] -= std::mem::take(&mut self.port_bytes[index]);
+
+            if let Some(data_block) = self.buffers[index].pop_front() {
+                self.port_bytes[index] = data_block.memory_size();
+                output.push_data(Ok(data_block));
+            }
+        }
+    }
+
+    fn buffer(&mut self, index: usize, data_block: DataBlock) {
+        if !self.outputs[index].is_finished() {
+            self.pending_bytes[index] += data_block.memory_size();
+            self.buffers[index].push_back(data_block);
+        }
+    }
+
+    /// Picks the output of a block, the finished outputs are skipped.
+    fn pick_output(&mut self) -> usize {
+        let size = self.outputs.len();
+        match &self.policy {
+            DispatchPolicy::LeastBuffered => (0..size)
+                .filter(|index| !self.outputs[*index].is_finished())
+                .min_by_key(|index| self.pending_bytes[*index])
+                .unwrap_or(0),
+            _ => {
+                let mut index = self.next_output;
+                for _ in 0..size {
+                    if !self.outputs[index].is_finished() {
+                        break;
+                    }
+                    index = (index + 1) % size;
+                }
+                self.next_output = (index + 1) % size;
+                index
+            }
+        }
+    }
+
+    fn dispatch_by_hash(&mut self, data_block: DataBlock, offsets: &[usize]) -> Result<()> {
+        let num_rows = data_block.num_rows();
+        let columns = offsets
+            .iter()
+            .map(|offset| {
+                let entry = data_block.get_by_offset(*offset);
+                entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, num_rows)
+            })
+            .collect::<Vec<_>>();
+
+        let mut hashes = vec![0; num_rows];
+        group_hash_columns(InputColumns::Slice(&columns), &mut hashes);
+
+        let size = self.outputs.len() as u64;
+        let indices = hashes
+            .iter()
+            .map(|hash| (hash % size) as u32)
+            .collect::<Vec<_>>();
+
+        let blocks = data_block.scatter(&indices, self.outputs.len())?;
+        for (index, block) in blocks.into_iter().enumerate() {
+            if !block.is_empty() {
+                self.buffer(index, block);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for DispatchProcessor {
+    fn name(&self) -> String {
+        "Dispatch".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        loop {
+            self.flush_outputs();
+
+            if self.outputs.iter().all(|output| output.is_finished()) {
+                self.input.finish();
+                return Ok(Event::Finished);
+            }
+
+            if self.input_data.is_some() {
+                return Ok(Event::Sync);
+            }
+
+            if self.input.is_finished() {
+                if self.buffers.iter().all(|buffer| buffer.is_empty()) {
+                    for output in &self.outputs {
+                        output.finish();
+                    }
+                    return Ok(Event::Finished);
+                }
+
+                return Ok(Event::NeedConsume);
+            }
+
+            // Bound the buffered blocks, the input waits for the outputs to consume.
+            let buffered = self.buffers.iter().map(VecDeque::len).sum::<usize>();
+            if buffered >= self.outputs.len() {
+                self.input.set_not_need_data();
+                return Ok(Event::NeedConsume);
+            }
+
+            if !self.input.has_data() {
+                self.input.set_need_data();
+                return Ok(Event::NeedData);
+            }
+
+            let data_block = self.input.pull_data().unwrap()?;
+            if matches!(self.policy, DispatchPolicy::Hash(_)) {
+                self.input_data = Some(data_block);
+                return Ok(Event::Sync);
+            }
+
+            let index = self.pick_output();
+            self.buffer(index, data_block);
+        }
+    }
+
+    fn process(&mut self) -> Result<()> {
+        if let Some(data_block) = self.input_data.take() {
+            if let DispatchPolicy::Hash(offsets) = &self.policy {
+                let offsets = offsets.clone();
+                self.dispatch_by_hash(data_block, &offsets)?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -15,12 +15,15 @@
 mod port;
 mod processor;
 
+mod dispatch_processor;
 mod duplicate_processor;
 mod port_trigger;
 mod profile;
 mod resize_processor;
 mod shuffle_processor;
 
+pub use dispatch_processor::DispatchPolicy;
+pub use dispatch_processor::DispatchProcessor;
 pub use duplicate_processor::DuplicateProcessor;
 pub use port::connect;
 pub use port::InputPort;
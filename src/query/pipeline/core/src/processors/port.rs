@@ -125,6 +125,7 @@ impl SharedStatus {
 }
 
 pub struct InputPort {
+    record_profile: UnSafeCellWrap<bool>,
     shared: UnSafeCellWrap<Arc<SharedStatus>>,
     update_trigger: UnSafeCellWrap<*mut UpdateTrigger>,
 }
@@ -132,6 +133,7 @@ pub struct InputPort {
 impl InputPort {
     pub fn create() -> Arc<InputPort> {
         Arc::new(InputPort {
+            record_profile: UnSafeCellWrap::create(false),
             shared: UnSafeCellWrap::create(SharedStatus::create()),
             update_trigger: UnSafeCellWrap::create(std::ptr::null_mut()),
         })
@@ -194,6 +196,17 @@ impl InputPort {
 
                     if let Ok(data_block) = &data_block {
                         ThreadTracker::movein_memory(data_block.memory_size() as i64);
+
+                        if *self.record_profile {
+                            Profile::record_usize_profile(
+                                ProfileStatisticsName::InputRows,
+                                data_block.num_rows(),
+                            );
+                            Profile::record_usize_profile(
+                                ProfileStatisticsName::InputBytes,
+                                data_block.memory_size(),
+                            );
+                        }
                     }
 
                     Some(data_block)
@@ -215,6 +228,13 @@ impl InputPort {
     pub unsafe fn set_trigger(&self, update_trigger: *mut UpdateTrigger) {
         self.update_trigger.set_value(update_trigger)
     }
+
+    /// # Safety
+    ///
+    /// Method is thread unsafe and require thread safe call
+    pub unsafe fn record_profile(&self) {
+        self.record_profile.set_value(true);
+    }
 }
 
 pub struct OutputPort {
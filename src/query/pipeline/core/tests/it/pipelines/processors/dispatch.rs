@@ -0,0 +1,232 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::Int32Type;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_pipeline_core::processors::connect;
+use databend_common_pipeline_core::processors::DispatchPolicy;
+use databend_common_pipeline_core::processors::DispatchProcessor;
+use databend_common_pipeline_core::processors::Event;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::Processor;
+
+struct Simulation {
+    /// The ticks until all the rows are processed.
+    ticks: usize,
+    /// The values received by each consumer.
+    received: Vec<Vec<i32>>,
+}
+
+/// Feeds the blocks to a dispatcher with `n` outputs, each consumer of the outputs
+/// processes one row per tick and only asks for the next block when it is idle.
+fn simulate(policy: DispatchPolicy, blocks: Vec<DataBlock>, n: usize) -> Result<Simulation> {
+    let input = InputPort::create();
+    let outputs = (0..n).map(|_| OutputPort::create()).collect::<Vec<_>>();
+    let mut processor = DispatchProcessor::create(input.clone(), outputs.clone(), policy);
+
+    let source = OutputPort::create();
+    let consumers = outputs
+        .iter()
+        .map(|output| {
+            let consumer = InputPort::create();
+            unsafe { connect(&consumer, output) };
+            consumer
+        })
+        .collect::<Vec<_>>();
+    unsafe { connect(&input, &source) };
+
+    let mut blocks = VecDeque::from(blocks);
+    let mut finished = false;
+    let mut work = vec![0; n];
+    let mut received = vec![vec![]; n];
+    let mut ticks = 0;
+
+    loop {
+        if source.can_push() {
+            match blocks.pop_front() {
+                Some(block) => source.push_data(Ok(block)),
+                None => source.finish(),
+            }
+        }
+
+        while !finished {
+            match processor.event()? {
+                Event::Sync => processor.process()?,
+                Event::Finished => finished = true,
+                Event::NeedData | Event::NeedConsume => break,
+                event => unreachable!("{event:?}"),
+            }
+        }
+
+        let mut idle = true;
+        for (index, consumer) in consumers.iter().enumerate() {
+            if work[index] == 0 {
+                match consumer.has_data() {
+                    true => {
+                        let block = consumer.pull_data().unwrap()?;
+                        let column = block.columns()[0].value.as_column().unwrap();
+                        let values = Int32Type::try_downcast_column(column).unwrap();
+                        received[index].extend(values.iter());
+                        work[index] = block.num_rows();
+                    }
+                    false => consumer.set_need_data(),
+                }
+            }
+
+            if work[index] > 0 {
+                work[index] -= 1;
+                idle = false;
+            }
+        }
+
+        ticks += 1;
+        if finished && idle && consumers.iter().all(|consumer| !consumer.has_data()) {
+            return Ok(Simulation { ticks, received });
+        }
+    }
+}
+
+fn block(rows: usize, value: i32) -> DataBlock {
+    DataBlock::new_from_columns(vec![Int32Type::from_data(vec![value; rows])])
+}
+
+#[test]
+fn test_dispatch_round_robin() -> Result<()> {
+    let blocks = (0..8).map(|value| block(1, value)).collect();
+    let simulation = simulate(DispatchPolicy::RoundRobin, blocks, 4)?;
+
+    assert_eq!(simulation.received, vec![
+        vec![0, 4],
+        vec![1, 5],
+        vec![2, 6],
+        vec![3, 7]
+    ]);
+    Ok(())
+}
+
+#[test]
+fn test_dispatch_least_buffered_balances_skewed_blocks() -> Result<()> {
+    // Every 4th block is much larger, round-robin sends all of them to the same output.
+    let skewed_blocks = || {
+        (0..32)
+            .flat_map(|_| [64, 1, 1, 1])
+            .enumerate()
+            .map(|(value, rows)| block(rows, value as i32))
+            .collect::<Vec<_>>()
+    };
+
+    let round_robin = simulate(DispatchPolicy::RoundRobin, skewed_blocks(), 4)?;
+    let least_buffered = simulate(DispatchPolicy::LeastBuffered, skewed_blocks(), 4)?;
+
+    let rows = |simulation: &Simulation| {
+        simulation
+            .received
+            .iter()
+            .map(|values| values.len())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(rows(&round_robin).iter().sum::<usize>(), 32 * 67);
+    assert_eq!(rows(&least_buffered).iter().sum::<usize>(), 32 * 67);
+
+    // The consumer of the large blocks is the bottleneck of round-robin.
+    assert!(round_robin.ticks >= 32 * 64, "{}", round_robin.ticks);
+    assert!(
+        least_buffered.ticks * 2 < round_robin.ticks,
+        "least buffered: {}, round robin: {}",
+        least_buffered.ticks,
+        round_robin.ticks
+    );
+    assert!(
+        rows(&least_buffered)
+            .iter()
+            .all(|rows| *rows >= 32 * 67 / 8),
+        "{:?}",
+        rows(&least_buffered)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dispatch_hash_is_deterministic() -> Result<()> {
+    let values = (0..200).map(|value| value % 13).collect::<Vec<i32>>();
+
+    // The same values are dispatched in blocks of different sizes.
+    let mut outputs_of_values = vec![];
+    for block_size in [1, 7, 200] {
+        let blocks = values
+            .chunks(block_size)
+            .map(|values| DataBlock::new_from_columns(vec![Int32Type::from_data(values.to_vec())]))
+            .collect();
+        let simulation = simulate(DispatchPolicy::Hash(vec![0]), blocks, 4)?;
+
+        let mut output_of_value = HashMap::new();
+        for (index, received) in simulation.received.iter().enumerate() {
+            for value in received {
+                let output = output_of_value.entry(*value).or_insert(index);
+                assert_eq!(*output, index, "value {value} is dispatched to 2 outputs");
+            }
+        }
+        assert_eq!(simulation.received.iter().map(Vec::len).sum::<usize>(), 200);
+        assert_eq!(output_of_value.len(), 13);
+        outputs_of_values.push(output_of_value);
+    }
+
+    assert_eq!(outputs_of_values[0], outputs_of_values[1]);
+    assert_eq!(outputs_of_values[0], outputs_of_values[2]);
+    Ok(())
+}
+
+#[test]
+fn test_dispatch_output_finish() -> Result<()> {
+    let input = InputPort::create();
+    let outputs = (0..2).map(|_| OutputPort::create()).collect::<Vec<_>>();
+    let mut processor =
+        DispatchProcessor::create(input.clone(), outputs.clone(), DispatchPolicy::RoundRobin);
+
+    let upstream_output = OutputPort::create();
+    let downstream_inputs = outputs
+        .iter()
+        .map(|output| {
+            let input = InputPort::create();
+            unsafe { connect(&input, output) };
+            input
+        })
+        .collect::<Vec<_>>();
+    unsafe { connect(&input, &upstream_output) };
+
+    // The blocks go to the output which isn't finished.
+    downstream_inputs[0].finish();
+    downstream_inputs[1].set_need_data();
+    assert!(matches!(processor.event()?, Event::NeedData));
+    for value in 0..2 {
+        upstream_output.push_data(Ok(block(1, value)));
+        processor.event()?;
+        assert!(downstream_inputs[1].has_data());
+        downstream_inputs[1].pull_data().unwrap()?;
+        downstream_inputs[1].set_need_data();
+    }
+
+    downstream_inputs[1].finish();
+    assert!(matches!(processor.event()?, Event::Finished));
+    assert!(input.is_finished());
+    Ok(())
+}
@@ -15,7 +15,10 @@
 use std::sync::Arc;
 
 use databend_common_exception::Result;
+use databend_common_expression::types::Int32Type;
+use databend_common_expression::types::ValueType;
 use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
 use databend_common_pipeline_core::processors::connect;
 use databend_common_pipeline_core::processors::EventCause;
 use databend_common_pipeline_core::processors::InputPort;
@@ -55,6 +58,73 @@ async fn test_resize_output_finish() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_resize_split_and_merge() -> Result<()> {
+    let mut split = ResizeProcessor::create(1, 4);
+    let mut merge = ResizeProcessor::create(4, 1);
+    let source = connect_inputs(split.get_inputs()).remove(0);
+    let middle_inputs = connect_outputs(split.get_outputs());
+    let middle_outputs = connect_inputs(merge.get_inputs());
+    let sink = connect_outputs(merge.get_outputs()).remove(0);
+
+    let mut received = [0; 4];
+    let mut values = vec![];
+    let mut drain_sink = |merge: &mut ResizeProcessor| -> Result<()> {
+        if sink.has_data() {
+            let block = sink.pull_data().unwrap()?;
+            let column = block.columns()[0].value.as_column().unwrap();
+            values.extend(Int32Type::try_downcast_column(column).unwrap().iter());
+        }
+        sink.set_need_data();
+        merge.event_with_cause(EventCause::Output(0))?;
+        Ok(())
+    };
+
+    drain_sink(&mut merge)?;
+    for (index, input) in middle_inputs.iter().enumerate() {
+        input.set_need_data();
+        split.event_with_cause(EventCause::Output(index))?;
+    }
+
+    // 1 -> 4, each of the middle processors forwards what it received, then 4 -> 1.
+    for value in 0..16 {
+        assert!(source.can_push());
+        source.push_data(Ok(DataBlock::new_from_columns(vec![Int32Type::from_data(
+            vec![value],
+        )])));
+        split.event_with_cause(EventCause::Input(0))?;
+
+        for (index, input) in middle_inputs.iter().enumerate() {
+            if input.has_data() {
+                received[index] += 1;
+                assert!(middle_outputs[index].can_push());
+                middle_outputs[index].push_data(input.pull_data().unwrap());
+                merge.event_with_cause(EventCause::Input(index))?;
+                drain_sink(&mut merge)?;
+
+                input.set_need_data();
+                split.event_with_cause(EventCause::Output(index))?;
+            }
+        }
+    }
+
+    source.finish();
+    split.event_with_cause(EventCause::Input(0))?;
+    for (index, output) in middle_outputs.iter().enumerate() {
+        assert!(middle_inputs[index].is_finished());
+        output.finish();
+        merge.event_with_cause(EventCause::Input(index))?;
+    }
+    assert!(sink.is_finished());
+
+    // The order isn't kept, the rows are.
+    values.sort();
+    assert_eq!(values, (0..16).collect::<Vec<_>>());
+    assert!(received.iter().all(|count| *count > 0), "{received:?}");
+
+    Ok(())
+}
+
 fn connect_inputs(inputs: Vec<Arc<InputPort>>) -> Vec<Arc<OutputPort>> {
     let mut outputs = Vec::with_capacity(inputs.len());
 
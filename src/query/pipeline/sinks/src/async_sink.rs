@@ -19,6 +19,7 @@ use async_trait::async_trait;
 use async_trait::unboxed_simple;
 use databend_common_base::runtime::drop_guard;
 use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::MemoryReservation;
 use databend_common_base::runtime::TrySpawn;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
@@ -53,6 +54,8 @@ pub struct AsyncSinker<T: AsyncSink + 'static> {
     finished: bool,
     input: Arc<InputPort>,
     input_data: Option<DataBlock>,
+    // The pulled block waiting to be consumed.
+    memory: MemoryReservation,
     called_on_start: bool,
     called_on_finish: bool,
 }
@@ -63,6 +66,7 @@ impl<T: AsyncSink + 'static> AsyncSinker<T> {
             input,
             finished: false,
             input_data: None,
+            memory: MemoryReservation::create(T::NAME),
             inner: Some(inner),
             called_on_start: false,
             called_on_finish: false,
@@ -137,7 +141,9 @@ impl<T: AsyncSink + 'static> Processor for AsyncSinker<T> {
         match self.input.has_data() {
             true => {
                 // Wake up upstream while executing async work
-                self.input_data = Some(self.input.pull_data().unwrap()?);
+                let data_block = self.input.pull_data().unwrap()?;
+                self.memory.grow(data_block.memory_size())?;
+                self.input_data = Some(data_block);
                 self.input.set_need_data();
                 Ok(Event::Async)
             }
@@ -155,6 +161,7 @@ impl<T: AsyncSink + 'static> Processor for AsyncSinker<T> {
             self.inner.as_mut().unwrap().on_start().await?;
         } else if let Some(data_block) = self.input_data.take() {
             self.finished = self.inner.as_mut().unwrap().consume(data_block).await?;
+            self.memory.free();
         } else if !self.called_on_finish {
             self.called_on_finish = true;
             self.inner.as_mut().unwrap().on_finish().await?;
@@ -0,0 +1,45 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::Processor;
+use parking_lot::Mutex;
+
+use crate::Sink;
+use crate::Sinker;
+
+/// Collects the blocks of its input into a Vec shared with the caller, e.g. to gather the
+/// results of a pipeline in the tests.
+pub struct BlockCollectSink {
+    blocks: Arc<Mutex<Vec<DataBlock>>>,
+}
+
+impl BlockCollectSink {
+    pub fn create(input: Arc<InputPort>, blocks: Arc<Mutex<Vec<DataBlock>>>) -> Box<dyn Processor> {
+        Sinker::create(input, BlockCollectSink { blocks })
+    }
+}
+
+impl Sink for BlockCollectSink {
+    const NAME: &'static str = "BlockCollectSink";
+
+    fn consume(&mut self, data_block: DataBlock) -> Result<()> {
+        self.blocks.lock().push(data_block);
+        Ok(())
+    }
+}
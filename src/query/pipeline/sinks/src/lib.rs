@@ -17,6 +17,7 @@
 
 mod async_mpsc_sink;
 mod async_sink;
+mod block_collect_sink;
 mod empty_sink;
 mod sync_mpsc_sink;
 mod sync_sink;
@@ -27,6 +28,7 @@ pub use async_mpsc_sink::AsyncMpscSink;
 pub use async_mpsc_sink::AsyncMpscSinker;
 pub use async_sink::AsyncSink;
 pub use async_sink::AsyncSinker;
+pub use block_collect_sink::BlockCollectSink;
 pub use empty_sink::EmptySink;
 pub use sync_mpsc_sink::SyncMpscSink;
 pub use sync_mpsc_sink::SyncMpscSinker;
@@ -16,6 +16,7 @@ use std::any::Any;
 use std::sync::Arc;
 
 use databend_common_base::runtime::drop_guard;
+use databend_common_base::runtime::MemoryReservation;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
 use databend_common_pipeline_core::processors::Event;
@@ -42,6 +43,8 @@ pub struct Sinker<T: Sink + 'static> {
     inner: T,
     input: Arc<InputPort>,
     input_data: Option<DataBlock>,
+    // The pulled block waiting to be consumed.
+    memory: MemoryReservation,
     called_on_start: bool,
     called_on_finish: bool,
 }
@@ -63,6 +66,7 @@ impl<T: Sink + 'static> Sinker<T> {
             inner,
             input,
             input_data: None,
+            memory: MemoryReservation::create(T::NAME),
             called_on_start: false,
             called_on_finish: false,
         })
@@ -97,7 +101,9 @@ impl<T: Sink + 'static> Processor for Sinker<T> {
 
         match self.input.has_data() {
             true => {
-                self.input_data = Some(self.input.pull_data().unwrap()?);
+                let data_block = self.input.pull_data().unwrap()?;
+                self.memory.grow(data_block.memory_size())?;
+                self.input_data = Some(data_block);
                 Ok(Event::Sync)
             }
             false => {
@@ -117,6 +123,7 @@ impl<T: Sink + 'static> Processor for Sinker<T> {
             self.inner.on_start()?;
         } else if let Some(data_block) = self.input_data.take() {
             self.inner.consume(data_block)?;
+            self.memory.free();
         } else if !self.called_on_finish {
             self.called_on_finish = true;
             self.inner.on_finish()?;
@@ -17,3 +17,4 @@
 
 mod async_mpsc_sink;
 mod sync_mpsc_sink;
+mod sync_sink;
@@ -0,0 +1,107 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_pipeline_core::processors::connect;
+use databend_common_pipeline_core::processors::Event;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_sinks::BlockCollectSink;
+use databend_common_pipeline_sinks::Sink;
+use databend_common_pipeline_sinks::Sinker;
+use parking_lot::Mutex;
+
+struct FailingSink {
+    finish_count: Arc<AtomicUsize>,
+}
+
+impl Sink for FailingSink {
+    const NAME: &'static str = "FailingSink";
+
+    fn on_finish(&mut self) -> Result<()> {
+        self.finish_count.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn consume(&mut self, _: DataBlock) -> Result<()> {
+        Err(ErrorCode::Internal("consume failure"))
+    }
+}
+
+#[test]
+fn test_block_collect_sink() -> Result<()> {
+    let input = InputPort::create();
+    let upstream_output = OutputPort::create();
+    unsafe { connect(&input, &upstream_output) };
+
+    let blocks = Arc::new(Mutex::new(vec![]));
+    let mut sink = BlockCollectSink::create(input, blocks.clone());
+
+    // on start
+    assert!(matches!(sink.event()?, Event::Sync));
+    sink.process()?;
+
+    for num_rows in [1, 2] {
+        assert!(matches!(sink.event()?, Event::NeedData));
+        upstream_output.push_data(Ok(DataBlock::new(vec![], num_rows)));
+        assert!(matches!(sink.event()?, Event::Sync));
+        sink.process()?;
+    }
+
+    upstream_output.finish();
+    // on finish
+    assert!(matches!(sink.event()?, Event::Sync));
+    sink.process()?;
+    assert!(matches!(sink.event()?, Event::Finished));
+
+    let rows = blocks
+        .lock()
+        .iter()
+        .map(|b| b.num_rows())
+        .collect::<Vec<_>>();
+    assert_eq!(rows, vec![1, 2]);
+    Ok(())
+}
+
+#[test]
+fn test_sink_consume_error() -> Result<()> {
+    let input = InputPort::create();
+    let upstream_output = OutputPort::create();
+    unsafe { connect(&input, &upstream_output) };
+
+    let finish_count = Arc::new(AtomicUsize::new(0));
+    let mut sink = Sinker::create(input, FailingSink {
+        finish_count: finish_count.clone(),
+    });
+
+    assert!(matches!(sink.event()?, Event::Sync));
+    sink.process()?;
+
+    upstream_output.push_data(Ok(DataBlock::new(vec![], 1)));
+    assert!(matches!(sink.event()?, Event::Sync));
+    let error = sink.process().unwrap_err();
+    assert_eq!(error.message(), "consume failure");
+
+    // The executor drops the processor of the failed pipeline, which still finishes the sink.
+    assert_eq!(finish_count.load(Ordering::SeqCst), 0);
+    drop(sink);
+    assert_eq!(finish_count.load(Ordering::SeqCst), 1);
+    Ok(())
+}
@@ -19,6 +19,7 @@ use databend_common_base::base::Progress;
 use databend_common_base::base::ProgressValues;
 use databend_common_base::runtime::profile::Profile;
 use databend_common_base::runtime::profile::ProfileStatisticsName;
+use databend_common_base::runtime::MemoryReservation;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
@@ -28,10 +29,15 @@ use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 
+/// Asynchronous source for the IO that is awaited instead of blocking the executor workers,
+/// such as the listings of the object storage or the requests to the meta service.
+/// The pending `generate` is dropped if the query is aborted, the same as the other async processors.
 #[async_trait::async_trait]
 pub trait AsyncSource: Send {
     const NAME: &'static str;
     const SKIP_EMPTY_DATA_BLOCK: bool = true;
+    /// Same as [`crate::SyncSource::RECORD_SCAN_PROGRESS`].
+    const RECORD_SCAN_PROGRESS: bool = true;
 
     #[async_trait::unboxed_simple]
     async fn generate(&mut self) -> Result<Option<DataBlock>>;
@@ -50,6 +56,8 @@ pub struct AsyncSourcer<T: 'static + AsyncSource> {
     inner: T,
     output: Arc<OutputPort>,
     scan_progress: Arc<Progress>,
+    // The generated block waiting for the output.
+    memory: MemoryReservation,
     generated_data: Option<DataBlock>,
 }
 
@@ -64,6 +72,7 @@ impl<T: 'static + AsyncSource> AsyncSourcer<T> {
             inner,
             output,
             scan_progress,
+            memory: MemoryReservation::create(T::NAME),
             is_finish: false,
             generated_data: None,
         })))
@@ -97,6 +106,7 @@ impl<T: 'static + AsyncSource> Processor for AsyncSourcer<T> {
         match self.generated_data.take() {
             None => Ok(Event::Async),
             Some(data_block) => {
+                self.memory.free();
                 self.output.push_data(Ok(data_block));
                 Ok(Event::NeedConsume)
             }
@@ -116,9 +126,7 @@ impl<T: 'static + AsyncSource> Processor for AsyncSourcer<T> {
         match self.inner.generate().await? {
             None => self.is_finish = true,
             Some(data_block) => {
-                // Don't need to record the scan progress of `MaterializedCteSource`
-                // Because it reads data from memory.
-                if !data_block.is_empty() && self.name() != "MaterializedCteSource" {
+                if !data_block.is_empty() && T::RECORD_SCAN_PROGRESS {
                     let progress_values = ProgressValues {
                         rows: data_block.num_rows(),
                         bytes: data_block.memory_size(),
@@ -131,7 +139,8 @@ impl<T: 'static + AsyncSource> Processor for AsyncSourcer<T> {
                 }
 
                 if !T::SKIP_EMPTY_DATA_BLOCK || !data_block.is_empty() {
-                    self.generated_data = Some(data_block)
+                    self.memory.grow(data_block.memory_size())?;
+                    self.generated_data = Some(data_block);
                 }
             }
         };
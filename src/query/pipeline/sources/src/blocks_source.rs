@@ -37,6 +37,20 @@ impl BlocksSource {
     ) -> Result<ProcessorPtr> {
         SyncSourcer::create(ctx, output, BlocksSource { data_blocks })
     }
+
+    /// The blocks are split to `max_block_size` rows before they are generated.
+    pub fn create_with_blocks(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        data_blocks: Vec<DataBlock>,
+    ) -> Result<ProcessorPtr> {
+        let max_block_size = ctx.get_settings().get_max_block_size()? as usize;
+        let data_blocks = data_blocks
+            .iter()
+            .flat_map(|block| block.split_by_rows_no_tail(max_block_size))
+            .collect::<VecDeque<_>>();
+        Self::create(ctx, output, Arc::new(Mutex::new(data_blocks)))
+    }
 }
 
 impl SyncSource for BlocksSource {
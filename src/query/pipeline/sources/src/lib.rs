@@ -23,6 +23,7 @@ mod async_source;
 mod blocks_source;
 mod empty_source;
 mod one_block_source;
+mod retry_source;
 mod stream_source;
 mod sync_source;
 mod sync_source_receiver;
@@ -36,6 +37,9 @@ pub use empty_source::EmptySource;
 pub use one_block_source::OneBlockSource;
 pub use prefetch_async_source::PrefetchAsyncSource;
 pub use prefetch_async_source::PrefetchAsyncSourcer;
+pub use retry_source::RetryAsyncSource;
+pub use retry_source::RetryPolicy;
+pub use retry_source::RetrySource;
 pub use stream_source::AsyncStreamSource;
 pub use stream_source::StreamSource;
 pub use stream_source::StreamSourceNoSkipEmpty;
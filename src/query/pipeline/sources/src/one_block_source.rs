@@ -15,8 +15,10 @@
 use std::any::Any;
 use std::sync::Arc;
 
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
 use databend_common_pipeline_core::processors::Event;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
@@ -34,6 +36,44 @@ impl OneBlockSource {
             data_block: Some(data_block),
         })))
     }
+
+    /// Same as [`OneBlockSource::create`], the block is checked against the schema of `name`
+    /// so a mismatch fails when building the pipeline instead of in the downstream processors.
+    pub fn create_with_schema(
+        output: Arc<OutputPort>,
+        name: &str,
+        schema: &DataSchema,
+        data_block: DataBlock,
+    ) -> Result<ProcessorPtr> {
+        check_block_schema(name, schema, &data_block)?;
+        Self::create(output, data_block)
+    }
+}
+
+fn check_block_schema(name: &str, schema: &DataSchema, data_block: &DataBlock) -> Result<()> {
+    let fields = schema.fields();
+    if fields.len() != data_block.num_columns() {
+        return Err(ErrorCode::Internal(format!(
+            "The block of {} has {} columns, but its schema has {}",
+            name,
+            data_block.num_columns(),
+            fields.len()
+        )));
+    }
+
+    for (field, entry) in fields.iter().zip(data_block.columns()) {
+        if field.data_type() != &entry.data_type {
+            return Err(ErrorCode::Internal(format!(
+                "The column {} of {} is {}, but its schema is {}",
+                field.name(),
+                name,
+                entry.data_type,
+                field.data_type()
+            )));
+        }
+    }
+
+    Ok(())
 }
 
 #[async_trait::async_trait]
@@ -32,6 +32,8 @@ use databend_common_pipeline_core::processors::ProcessorPtr;
 pub trait PrefetchAsyncSource: Send {
     const NAME: &'static str;
     const SKIP_EMPTY_DATA_BLOCK: bool = true;
+    /// Same as [`crate::SyncSource::RECORD_SCAN_PROGRESS`].
+    const RECORD_SCAN_PROGRESS: bool = true;
 
     #[async_trait::unboxed_simple]
     async fn generate(&mut self) -> Result<Option<DataBlock>>;
@@ -117,9 +119,7 @@ impl<T: 'static + PrefetchAsyncSource> Processor for PrefetchAsyncSourcer<T> {
         match self.inner.generate().await? {
             None => self.is_inner_finish = true,
             Some(data_block) => {
-                // Don't need to record the scan progress of `MaterializedCteSource`
-                // Because it reads data from memory.
-                if !data_block.is_empty() && self.name() != "MaterializedCteSource" {
+                if !data_block.is_empty() && T::RECORD_SCAN_PROGRESS {
                     let progress_values = ProgressValues {
                         rows: data_block.num_rows(),
                         bytes: data_block.memory_size(),
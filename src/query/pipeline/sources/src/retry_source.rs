@@ -0,0 +1,194 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+use std::time::Instant;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::AbortChecker;
+use databend_common_expression::DataBlock;
+use log::info;
+
+use crate::AsyncSource;
+use crate::SyncSource;
+
+// The abort of the query is checked at least this often during a backoff.
+const SLEEP_STEP: Duration = Duration::from_millis(10);
+
+/// How the failed `generate` of a source is retried.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    /// The attempts of a `generate`, including the first one.
+    pub max_attempts: usize,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// The errors of the object storage and the remote services which may not occur again.
+    ///
+    /// `STORAGE_OTHER` is left out, it also holds the errors of a wrong configuration which
+    /// no retry can fix.
+    pub fn is_transient(error: &ErrorCode) -> bool {
+        matches!(
+            error.code(),
+            ErrorCode::STORAGE_UNAVAILABLE | ErrorCode::TIMEOUT
+        )
+    }
+
+    /// The backoff before the retry of the `attempt`th failure, doubled for each failure.
+    pub fn backoff(&self, attempt: usize) -> Duration {
+        let factor = 1_u32.checked_shl(attempt.saturating_sub(1) as u32);
+        factor
+            .and_then(|factor| self.initial_backoff.checked_mul(factor))
+            .map_or(self.max_backoff, |backoff| backoff.min(self.max_backoff))
+    }
+
+    /// Returns the backoff if the `attempt`th failure should be retried, otherwise
+    /// the error to give up with.
+    fn on_failure(&self, name: &str, attempt: usize, error: ErrorCode) -> Result<Duration> {
+        if attempt >= self.max_attempts || !Self::is_transient(&error) {
+            return match attempt {
+                1 => Err(error),
+                _ => Err(error.add_message_back(format!("(failed after {attempt} attempts)"))),
+            };
+        }
+
+        let backoff = self.backoff(attempt);
+        info!(
+            "Retry source {} in {:?} after {} attempts, error: {:?}",
+            name, backoff, attempt, error
+        );
+        Ok(backoff)
+    }
+}
+
+/// Retries the transient failures of the `generate` of the inner source.
+///
+/// A failed `generate` must not produce any block, so the retry never emits a block twice.
+pub struct RetrySource<T: SyncSource> {
+    inner: T,
+    policy: RetryPolicy,
+    abort_checker: AbortChecker,
+}
+
+impl<T: SyncSource> RetrySource<T> {
+    pub fn create(inner: T, policy: RetryPolicy, abort_checker: AbortChecker) -> Self {
+        RetrySource {
+            inner,
+            policy,
+            abort_checker,
+        }
+    }
+
+    /// Sleeps the backoff in short steps, so a killed query doesn't wait for it.
+    fn sleep(&self, backoff: Duration) -> Result<()> {
+        let deadline = Instant::now() + backoff;
+        loop {
+            self.abort_checker.try_check_aborting()?;
+            let now = Instant::now();
+            if now >= deadline {
+                return Ok(());
+            }
+            std::thread::sleep((deadline - now).min(SLEEP_STEP));
+        }
+    }
+}
+
+impl<T: SyncSource> SyncSource for RetrySource<T> {
+    const NAME: &'static str = T::NAME;
+    const RECORD_SCAN_PROGRESS: bool = T::RECORD_SCAN_PROGRESS;
+
+    fn generate(&mut self) -> Result<Option<DataBlock>> {
+        let mut attempt = 1;
+        loop {
+            match self.inner.generate() {
+                Ok(data_block) => return Ok(data_block),
+                Err(error) => {
+                    let backoff = self.policy.on_failure(T::NAME, attempt, error)?;
+                    self.sleep(backoff)?;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+}
+
+/// Same as [`RetrySource`] for the async sources.
+pub struct RetryAsyncSource<T: AsyncSource> {
+    inner: T,
+    policy: RetryPolicy,
+    abort_checker: AbortChecker,
+}
+
+impl<T: AsyncSource> RetryAsyncSource<T> {
+    pub fn create(inner: T, policy: RetryPolicy, abort_checker: AbortChecker) -> Self {
+        RetryAsyncSource {
+            inner,
+            policy,
+            abort_checker,
+        }
+    }
+
+    /// Same as [`RetrySource::sleep`], without blocking the worker.
+    async fn sleep(&self, backoff: Duration) -> Result<()> {
+        let deadline = Instant::now() + backoff;
+        loop {
+            self.abort_checker.try_check_aborting()?;
+            let now = Instant::now();
+            if now >= deadline {
+                return Ok(());
+            }
+            tokio::time::sleep((deadline - now).min(SLEEP_STEP)).await;
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: AsyncSource> AsyncSource for RetryAsyncSource<T> {
+    const NAME: &'static str = T::NAME;
+    const SKIP_EMPTY_DATA_BLOCK: bool = T::SKIP_EMPTY_DATA_BLOCK;
+    const RECORD_SCAN_PROGRESS: bool = T::RECORD_SCAN_PROGRESS;
+
+    #[async_trait::unboxed_simple]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        let mut attempt = 1;
+        loop {
+            match self.inner.generate().await {
+                Ok(data_block) => return Ok(data_block),
+                Err(error) => {
+                    let backoff = self.policy.on_failure(T::NAME, attempt, error)?;
+                    self.sleep(backoff).await?;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    fn un_reacted(&self) -> Result<()> {
+        self.inner.un_reacted()
+    }
+}
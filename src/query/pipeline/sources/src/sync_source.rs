@@ -19,8 +19,10 @@ use databend_common_base::base::Progress;
 use databend_common_base::base::ProgressValues;
 use databend_common_base::runtime::profile::Profile;
 use databend_common_base::runtime::profile::ProfileStatisticsName;
+use databend_common_base::runtime::MemoryReservation;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
+use databend_common_expression::AbortChecker;
 use databend_common_expression::DataBlock;
 use databend_common_pipeline_core::processors::Event;
 use databend_common_pipeline_core::processors::OutputPort;
@@ -30,8 +32,15 @@ use databend_common_pipeline_core::processors::ProcessorPtr;
 /// Synchronized source. such as:
 ///     - Memory storage engine.
 ///     - SELECT * FROM numbers_mt(1000)
+///
+/// [`SyncSourcer`] checks whether the query is killed before each `generate`, the sources
+/// producing a lot of rows in one `generate` should also hold the [`AbortChecker`] of
+/// `ctx.get_abort_checker()` and call `try_check_aborting` in their loop.
 pub trait SyncSource: Send {
     const NAME: &'static str;
+    /// The rows and bytes of the generated blocks are the scan progress of the query,
+    /// the sources which wrap another scan of the query don't record them again.
+    const RECORD_SCAN_PROGRESS: bool = true;
 
     fn generate(&mut self) -> Result<Option<DataBlock>>;
 }
@@ -43,6 +52,9 @@ pub struct SyncSourcer<T: 'static + SyncSource> {
     output: Arc<OutputPort>,
     generated_data: Option<DataBlock>,
     scan_progress: Arc<Progress>,
+    // The generated block waiting for the output.
+    memory: MemoryReservation,
+    abort_checker: AbortChecker,
 }
 
 impl<T: 'static + SyncSource> SyncSourcer<T> {
@@ -52,10 +64,13 @@ impl<T: 'static + SyncSource> SyncSourcer<T> {
         inner: T,
     ) -> Result<ProcessorPtr> {
         let scan_progress = ctx.get_scan_progress();
+        let abort_checker = ctx.get_abort_checker();
         Ok(ProcessorPtr::create(Box::new(Self {
             inner,
             output,
             scan_progress,
+            abort_checker,
+            memory: MemoryReservation::create(T::NAME),
             is_finish: false,
             generated_data: None,
         })))
@@ -89,6 +104,7 @@ impl<T: 'static + SyncSource> Processor for SyncSourcer<T> {
         match self.generated_data.take() {
             None => Ok(Event::Sync),
             Some(data_block) => {
+                self.memory.free();
                 self.output.push_data(Ok(data_block));
                 Ok(Event::NeedConsume)
             }
@@ -96,6 +112,9 @@ impl<T: 'static + SyncSource> Processor for SyncSourcer<T> {
     }
 
     fn process(&mut self) -> Result<()> {
+        // A killed query stops between the blocks, with AbortedQuery unless a cause was given.
+        self.abort_checker.try_check_aborting()?;
+
         match self.inner.generate()? {
             None => self.is_finish = true,
             Some(data_block) => {
@@ -103,16 +122,19 @@ impl<T: 'static + SyncSource> Processor for SyncSourcer<T> {
                     // A part was pruned by runtime filter
                     return Ok(());
                 }
-                let progress_values = ProgressValues {
-                    rows: data_block.num_rows(),
-                    bytes: data_block.memory_size(),
-                };
-                self.scan_progress.incr(&progress_values);
-                Profile::record_usize_profile(
-                    ProfileStatisticsName::ScanBytes,
-                    data_block.memory_size(),
-                );
-                self.generated_data = Some(data_block)
+                if T::RECORD_SCAN_PROGRESS {
+                    let progress_values = ProgressValues {
+                        rows: data_block.num_rows(),
+                        bytes: data_block.memory_size(),
+                    };
+                    self.scan_progress.incr(&progress_values);
+                    Profile::record_usize_profile(
+                        ProfileStatisticsName::ScanBytes,
+                        data_block.memory_size(),
+                    );
+                }
+                self.memory.grow(data_block.memory_size())?;
+                self.generated_data = Some(data_block);
             }
         };
 
@@ -19,6 +19,7 @@ mod transform_accumulating_async;
 mod transform_async;
 mod transform_block_compact;
 mod transform_block_compact_for_copy;
+mod transform_block_compact_rows;
 mod transform_blocking;
 mod transform_compact;
 mod transform_dummy;
@@ -37,9 +38,11 @@ pub use transform_accumulating_async::*;
 pub use transform_async::*;
 pub use transform_block_compact::*;
 pub use transform_block_compact_for_copy::*;
+pub use transform_block_compact_rows::TransformBlockCompact;
 pub use transform_blocking::*;
 pub use transform_compact::*;
 pub use transform_dummy::*;
+pub use transform_multi_sort_merge::create_multi_sort_merge_processor;
 pub use transform_multi_sort_merge::try_add_multi_sort_merge;
 pub use transform_pipeline_helper::TransformPipelineHelper;
 pub use transform_retry_async::*;
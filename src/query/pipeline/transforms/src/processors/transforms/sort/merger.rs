@@ -170,7 +170,13 @@ where
             (true, None)
         } else {
             let next_cursor = &self.sorted_cursors.peek_top2().0;
-            if cursor.last().le(&next_cursor.current()) {
+            // The equal rows are output in the order of the inputs.
+            let drain_whole_block = if cursor.input_index < next_cursor.input_index {
+                cursor.last().le(&next_cursor.current())
+            } else {
+                cursor.last().lt(&next_cursor.current())
+            };
+            if drain_whole_block {
                 // Short Path:
                 // If the last row of current block is smaller than the next cursor,
                 // we can drain the whole block.
@@ -66,7 +66,9 @@ impl<T: AccumulatingTransform + 'static> Drop for AccumulatingTransformer<T> {
     fn drop(&mut self) {
         drop_guard(move || {
             if !self.called_on_finish {
-                self.inner.on_finish(false).unwrap();
+                self.called_on_finish = true;
+                // The error is ignored, the pipeline already finished with the first error.
+                let _ = self.inner.on_finish(false);
             }
         })
     }
@@ -188,7 +190,9 @@ impl<B: BlockMetaInfo, T: BlockMetaAccumulatingTransform<B>> Drop
     fn drop(&mut self) {
         drop_guard(move || {
             if !self.called_on_finish {
-                self.inner.on_finish(false).unwrap();
+                self.called_on_finish = true;
+                // The error is ignored, the pipeline already finished with the first error.
+                let _ = self.inner.on_finish(false);
             }
         })
     }
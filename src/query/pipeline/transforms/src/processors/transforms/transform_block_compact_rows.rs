@@ -0,0 +1,85 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+
+use super::AccumulatingTransform;
+
+/// Compacts the blocks of a pipe to blocks of `min_rows..=max_rows` rows, the oversized blocks
+/// are sliced and the undersized blocks are concatenated. Only the last block may have less than
+/// `min_rows` rows. At most `max_rows` rows are buffered.
+pub struct TransformBlockCompact {
+    min_rows: usize,
+    max_rows: usize,
+    buffer: Vec<DataBlock>,
+    buffered_rows: usize,
+}
+
+impl TransformBlockCompact {
+    pub fn try_create(min_rows: usize, max_rows: usize) -> Result<Self> {
+        if max_rows == 0 || min_rows > max_rows {
+            return Err(ErrorCode::BadArguments(format!(
+                "Invalid block compact thresholds, min rows {min_rows}, max rows {max_rows}"
+            )));
+        }
+
+        Ok(TransformBlockCompact {
+            min_rows,
+            max_rows,
+            buffer: vec![],
+            buffered_rows: 0,
+        })
+    }
+
+    fn flush(&mut self) -> Result<DataBlock> {
+        self.buffered_rows = 0;
+        DataBlock::concat(&std::mem::take(&mut self.buffer))
+    }
+}
+
+impl AccumulatingTransform for TransformBlockCompact {
+    const NAME: &'static str = "TransformBlockCompact";
+
+    fn transform(&mut self, mut data: DataBlock) -> Result<Vec<DataBlock>> {
+        let mut blocks = vec![];
+        while data.num_rows() != 0 {
+            let num_rows = data.num_rows();
+            let need_rows = self.max_rows - self.buffered_rows;
+            if num_rows < need_rows {
+                self.buffered_rows += num_rows;
+                self.buffer.push(data);
+                break;
+            }
+
+            self.buffer.push(data.slice(0..need_rows));
+            blocks.push(self.flush()?);
+            data = data.slice(need_rows..num_rows);
+        }
+
+        if self.buffered_rows != 0 && self.buffered_rows >= self.min_rows {
+            blocks.push(self.flush()?);
+        }
+
+        Ok(blocks)
+    }
+
+    fn on_finish(&mut self, output: bool) -> Result<Vec<DataBlock>> {
+        match output && self.buffered_rows != 0 {
+            true => Ok(vec![self.flush()?]),
+            false => Ok(vec![]),
+        }
+    }
+}
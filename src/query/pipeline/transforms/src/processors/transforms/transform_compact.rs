@@ -16,6 +16,7 @@ use std::any::Any;
 use std::collections::VecDeque;
 use std::sync::Arc;
 
+use databend_common_base::runtime::MemoryReservation;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
@@ -29,6 +30,8 @@ pub type Aborting = Arc<Box<dyn Fn() -> bool + Send + Sync + 'static>>;
 pub struct TransformCompact<T: Compactor + Send + 'static> {
     state: ProcessorState,
     compactor: T,
+    // The input blocks waiting to be compacted.
+    memory: MemoryReservation,
 }
 
 /// Compactor is a trait that defines how to compact blocks.
@@ -64,7 +67,11 @@ impl<T: Compactor + Send + 'static> TransformCompact<T> {
             output_data_blocks: VecDeque::new(),
         });
 
-        Ok(Box::new(Self { state, compactor }))
+        Ok(Box::new(Self {
+            state,
+            compactor,
+            memory: MemoryReservation::create(T::name()),
+        }))
     }
 
     #[inline(always)]
@@ -80,6 +87,7 @@ impl<T: Compactor + Send + 'static> TransformCompact<T> {
 
             if state.input_port.has_data() {
                 let data_block = state.input_port.pull_data().unwrap()?;
+                self.memory.grow(data_block.memory_size())?;
                 state.input_data_blocks.push(data_block);
 
                 if T::use_partial_compact(&self.compactor) {
@@ -153,6 +161,14 @@ impl<T: Compactor + Send + 'static> Processor for TransformCompact<T> {
                     .compactor
                     .compact_partial(&mut state.input_data_blocks)?;
 
+                let remain_size = state
+                    .input_data_blocks
+                    .iter()
+                    .map(|b| b.memory_size())
+                    .sum::<usize>();
+                self.memory
+                    .shrink(self.memory.size().saturating_sub(remain_size));
+
                 for b in compacted_blocks {
                     state.output_data_blocks.push_back(b);
                 }
@@ -162,6 +178,7 @@ impl<T: Compactor + Send + 'static> Processor for TransformCompact<T> {
                 let compacted_blocks = self
                     .compactor
                     .compact_final(std::mem::take(&mut state.blocks))?;
+                self.memory.free();
 
                 let mut temp_state = ProcessorState::Finished;
                 std::mem::swap(&mut self.state, &mut temp_state);
@@ -29,6 +29,7 @@ use crate::processors::AsyncAccumulatingTransformer;
 use crate::processors::AsyncTransform;
 use crate::processors::AsyncTransformer;
 use crate::processors::Transform;
+use crate::processors::TransformBlockCompact;
 use crate::processors::Transformer;
 
 pub trait TransformPipelineHelper {
@@ -115,6 +116,13 @@ pub trait TransformPipelineHelper {
             .unwrap()
     }
 
+    /// Compacts the blocks of each pipe to `min_rows..=max_rows` rows.
+    fn add_block_compact(&mut self, min_rows: usize, max_rows: usize) -> Result<()> {
+        self.try_add_accumulating_transformer(|| {
+            TransformBlockCompact::try_create(min_rows, max_rows)
+        })
+    }
+
     fn try_create_transform_pipeline_builder_with_len<F, R>(
         &mut self,
         f: F,
@@ -0,0 +1,72 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::Int32Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_pipeline_transforms::processors::AccumulatingTransform;
+use databend_common_pipeline_transforms::processors::TransformBlockCompact;
+
+fn block(start: i32, num_rows: usize) -> DataBlock {
+    let data = (start..start + num_rows as i32).collect::<Vec<_>>();
+    DataBlock::new_from_columns(vec![Int32Type::from_data(data)])
+}
+
+/// Returns the rows of the output blocks and checks no row is lost or reordered.
+fn compact(min_rows: usize, max_rows: usize, rows: &[usize]) -> Result<Vec<usize>> {
+    let mut transform = TransformBlockCompact::try_create(min_rows, max_rows)?;
+    let mut inputs = vec![];
+    let mut outputs = vec![];
+    let mut start = 0;
+    for num_rows in rows {
+        let input = block(start, *num_rows);
+        start += *num_rows as i32;
+        inputs.push(input.clone());
+        outputs.extend(transform.transform(input)?);
+    }
+    outputs.extend(transform.on_finish(true)?);
+
+    let total_rows = rows.iter().sum::<usize>();
+    if total_rows != 0 {
+        let output = DataBlock::concat(&outputs)?;
+        assert_eq!(output.columns(), DataBlock::concat(&inputs)?.columns());
+    }
+    Ok(outputs.iter().map(|b| b.num_rows()).collect())
+}
+
+#[test]
+fn test_block_compact_tiny_blocks() -> Result<()> {
+    assert_eq!(compact(8, 10, &[3; 10])?, vec![9, 9, 9, 3]);
+    assert_eq!(compact(10, 10, &[3; 10])?, vec![10, 10, 10]);
+    Ok(())
+}
+
+#[test]
+fn test_block_compact_huge_block() -> Result<()> {
+    assert_eq!(compact(8, 10, &[25])?, vec![10, 10, 5]);
+    assert_eq!(compact(8, 10, &[4, 25, 1])?, vec![10, 10, 9, 1]);
+    Ok(())
+}
+
+#[test]
+fn test_block_compact_boundary() -> Result<()> {
+    assert_eq!(compact(8, 10, &[])?, Vec::<usize>::new());
+    assert_eq!(compact(8, 10, &[0, 0])?, Vec::<usize>::new());
+    assert_eq!(compact(8, 10, &[10, 8, 20])?, vec![10, 8, 10, 10]);
+    assert_eq!(compact(8, 10, &[7, 3])?, vec![10]);
+    assert!(TransformBlockCompact::try_create(11, 10).is_err());
+    assert!(TransformBlockCompact::try_create(0, 0).is_err());
+    Ok(())
+}
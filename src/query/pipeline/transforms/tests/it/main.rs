@@ -12,4 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod block_compact;
 mod merger;
+mod multi_sort_merge;
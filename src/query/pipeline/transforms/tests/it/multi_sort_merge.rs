@@ -0,0 +1,226 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::block_debug::pretty_format_blocks;
+use databend_common_expression::types::binary::BinaryColumn;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::Int32Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::FromData;
+use databend_common_expression::RowConverter as CommonRowConverter;
+use databend_common_expression::SortColumnDescription;
+use databend_common_expression::Value;
+use databend_common_pipeline_core::processors::connect;
+use databend_common_pipeline_core::processors::Event;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_transforms::processors::create_multi_sort_merge_processor;
+use databend_common_pipeline_transforms::processors::sort::RowConverter;
+use databend_common_pipeline_transforms::processors::sort::Rows;
+
+fn schema(key_type: DataType) -> DataSchemaRef {
+    DataSchemaRefExt::create(vec![
+        DataField::new("key", key_type),
+        DataField::new("tag", DataType::Number(NumberDataType::Int32)),
+    ])
+}
+
+fn sort_desc(nulls_first: bool, is_nullable: bool) -> Arc<Vec<SortColumnDescription>> {
+    Arc::new(vec![SortColumnDescription {
+        offset: 0,
+        asc: true,
+        nulls_first,
+        is_nullable,
+    }])
+}
+
+/// Feeds the blocks of each input to the processor and collects its output.
+fn run_merge(
+    schema: DataSchemaRef,
+    sort_desc: Arc<Vec<SortColumnDescription>>,
+    data: Vec<Vec<DataBlock>>,
+    enable_loser_tree: bool,
+) -> Result<Vec<DataBlock>> {
+    let inputs = (0..data.len())
+        .map(|_| InputPort::create())
+        .collect::<Vec<_>>();
+    let output = OutputPort::create();
+    let mut processor = create_multi_sort_merge_processor(
+        inputs.clone(),
+        output.clone(),
+        schema,
+        4,
+        None,
+        sort_desc,
+        true,
+        enable_loser_tree,
+    )?;
+
+    let upstreams = inputs
+        .iter()
+        .map(|input| {
+            let upstream = OutputPort::create();
+            unsafe { connect(input, &upstream) };
+            upstream
+        })
+        .collect::<Vec<_>>();
+    let downstream = InputPort::create();
+    unsafe { connect(&downstream, &output) };
+
+    let mut data = data.into_iter().map(VecDeque::from).collect::<Vec<_>>();
+    let mut result = vec![];
+    loop {
+        for (upstream, blocks) in upstreams.iter().zip(data.iter_mut()) {
+            if !upstream.is_finished() && upstream.can_push() {
+                match blocks.pop_front() {
+                    Some(block) => upstream.push_data(Ok(block)),
+                    None => upstream.finish(),
+                }
+            }
+        }
+
+        if downstream.has_data() {
+            result.push(downstream.pull_data().unwrap()?);
+        }
+        downstream.set_need_data();
+
+        match processor.event()? {
+            Event::Sync => processor.process()?,
+            Event::Finished => break,
+            Event::NeedData | Event::NeedConsume => {}
+            event => unreachable!("{event:?}"),
+        }
+    }
+
+    if downstream.has_data() {
+        result.push(downstream.pull_data().unwrap()?);
+    }
+    Ok(result)
+}
+
+fn check_result(result: Vec<DataBlock>, expected: DataBlock) {
+    assert!(result.iter().all(|block| block.num_rows() <= 4));
+    let result = pretty_format_blocks(&result).unwrap();
+    let expected = pretty_format_blocks(&[expected]).unwrap();
+    assert_eq!(
+        expected, result,
+        "\nexpected:\n{expected}\nactual:\n{result}"
+    );
+}
+
+#[test]
+fn test_multi_sort_merge_stable() -> Result<()> {
+    // The tag of a row is the index of its input * 10 + its index in the input.
+    let block = |keys: Vec<i32>, tags: Vec<i32>| {
+        let order_col = Int32Type::from_data(keys.clone());
+        DataBlock::new_from_columns(vec![
+            Int32Type::from_data(keys),
+            Int32Type::from_data(tags),
+            order_col,
+        ])
+    };
+
+    for enable_loser_tree in [false, true] {
+        let data = vec![
+            vec![block(vec![1, 2], vec![0, 1]), block(vec![2, 3], vec![2, 3])],
+            vec![block(vec![2, 2], vec![10, 11]), block(vec![4], vec![12])],
+            vec![block(vec![1, 2], vec![20, 21])],
+        ];
+        let result = run_merge(
+            schema(DataType::Number(NumberDataType::Int32)),
+            sort_desc(true, false),
+            data,
+            enable_loser_tree,
+        )?;
+
+        // The equal keys keep the order of the inputs, then the order in the input.
+        let expected = DataBlock::new_from_columns(vec![
+            Int32Type::from_data(vec![1, 1, 2, 2, 2, 2, 2, 3, 4]),
+            Int32Type::from_data(vec![0, 20, 1, 2, 10, 11, 21, 3, 12]),
+        ]);
+        check_result(result, expected);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_multi_sort_merge_nulls() -> Result<()> {
+    let key_type = DataType::Number(NumberDataType::Int32).wrap_nullable();
+
+    for nulls_first in [true, false] {
+        let schema = schema(key_type.clone());
+        let sort_desc = sort_desc(nulls_first, true);
+        let mut converter =
+            <CommonRowConverter as RowConverter<BinaryColumn>>::create(&sort_desc, schema.clone())?;
+
+        let mut block = |keys: Vec<Option<i32>>, tags: Vec<i32>| -> Result<DataBlock> {
+            let mut block = DataBlock::new_from_columns(vec![
+                Int32Type::from_opt_data(keys),
+                Int32Type::from_data(tags),
+            ]);
+            let rows = converter.convert(&block.columns()[..1], block.num_rows())?;
+            let order_col = rows.to_column();
+            block.add_column(BlockEntry {
+                data_type: order_col.data_type(),
+                value: Value::Column(order_col),
+            });
+            Ok(block)
+        };
+
+        // Each input is sorted with the nulls at the same end as the merge.
+        let sorted = |mut keys: Vec<Option<i32>>| {
+            keys.sort_by(|a, b| match (a, b) {
+                (None, None) => std::cmp::Ordering::Equal,
+                (None, _) if nulls_first => std::cmp::Ordering::Less,
+                (None, _) => std::cmp::Ordering::Greater,
+                (_, None) if nulls_first => std::cmp::Ordering::Greater,
+                (_, None) => std::cmp::Ordering::Less,
+                (a, b) => a.cmp(b),
+            });
+            keys
+        };
+
+        let data = vec![
+            vec![block(sorted(vec![None, Some(1), Some(3)]), vec![0, 1, 2])?],
+            vec![block(sorted(vec![Some(2), None]), vec![10, 11])?],
+        ];
+        let result = run_merge(schema, sort_desc, data, true)?;
+
+        let (keys, tags) = match nulls_first {
+            true => (vec![None, None, Some(1), Some(2), Some(3)], vec![
+                0, 10, 1, 11, 2,
+            ]),
+            false => (vec![Some(1), Some(2), Some(3), None, None], vec![
+                0, 10, 1, 2, 11,
+            ]),
+        };
+        let expected = DataBlock::new_from_columns(vec![
+            Int32Type::from_opt_data(keys),
+            Int32Type::from_data(tags),
+        ]);
+        check_result(result, expected);
+    }
+
+    Ok(())
+}
@@ -22,6 +22,7 @@ use databend_common_catalog::catalog::StorageDescription;
 use databend_common_catalog::database::Database;
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_catalog::table_function::TableFunction;
+use databend_common_catalog::table_function::TableFunctionDescription;
 use databend_common_config::InnerConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -73,6 +74,8 @@ use databend_common_meta_app::schema::ListIndexesByIdReq;
 use databend_common_meta_app::schema::ListIndexesReq;
 use databend_common_meta_app::schema::ListLockRevReq;
 use databend_common_meta_app::schema::ListLocksReq;
+use databend_common_meta_app::schema::ListTableVersionsReply;
+use databend_common_meta_app::schema::ListTableVersionsReq;
 use databend_common_meta_app::schema::ListVirtualColumnsReq;
 use databend_common_meta_app::schema::LockInfo;
 use databend_common_meta_app::schema::LockMeta;
@@ -678,6 +681,10 @@ impl Catalog for DatabaseCatalog {
         self.table_function_factory.list()
     }
 
+    fn list_table_function_descriptions(&self) -> Vec<TableFunctionDescription> {
+        self.table_function_factory.list_descriptions()
+    }
+
     fn get_table_engines(&self) -> Vec<StorageDescription> {
         // only return mutable_catalog storage table engines
         self.mutable_catalog.get_table_engines()
@@ -715,6 +722,13 @@ impl Catalog for DatabaseCatalog {
         self.mutable_catalog.get_drop_table_infos(req).await
     }
 
+    async fn list_table_versions(
+        &self,
+        req: ListTableVersionsReq,
+    ) -> Result<ListTableVersionsReply> {
+        self.mutable_catalog.list_table_versions(req).await
+    }
+
     async fn gc_drop_tables(&self, req: GcDroppedTableReq) -> Result<GcDroppedTableResp> {
         self.mutable_catalog.gc_drop_tables(req).await
     }
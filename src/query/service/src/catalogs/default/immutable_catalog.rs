@@ -154,7 +154,10 @@ impl Catalog for ImmutableCatalog {
     async fn get_database(&self, _tenant: &Tenant, db_name: &str) -> Result<Arc<dyn Database>> {
         match db_name {
             "system" => Ok(self.sys_db.clone()),
-            "information_schema" => Ok(self.info_schema_db.clone()),
+            // The names of information_schema are case-insensitive as MySQL.
+            _ if db_name.eq_ignore_ascii_case("information_schema") => {
+                Ok(self.info_schema_db.clone())
+            }
             _ => Err(ErrorCode::UnknownDatabase(format!(
                 "Unknown database {}",
                 db_name
@@ -263,6 +266,11 @@ impl Catalog for ImmutableCatalog {
     ) -> Result<Arc<dyn Table>> {
         let _db = self.get_database(tenant, db_name).await?;
 
+        if db_name.eq_ignore_ascii_case("information_schema") {
+            return self
+                .sys_db_meta
+                .get_by_name("information_schema", &table_name.to_lowercase());
+        }
         self.sys_db_meta.get_by_name(db_name, table_name)
     }
 
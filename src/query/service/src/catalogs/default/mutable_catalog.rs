@@ -80,6 +80,8 @@ use databend_common_meta_app::schema::ListIndexesByIdReq;
 use databend_common_meta_app::schema::ListIndexesReq;
 use databend_common_meta_app::schema::ListLockRevReq;
 use databend_common_meta_app::schema::ListLocksReq;
+use databend_common_meta_app::schema::ListTableVersionsReply;
+use databend_common_meta_app::schema::ListTableVersionsReq;
 use databend_common_meta_app::schema::ListVirtualColumnsReq;
 use databend_common_meta_app::schema::LockInfo;
 use databend_common_meta_app::schema::LockMeta;
@@ -452,6 +454,14 @@ impl Catalog for MutableCatalog {
         Ok((tables, drop_ids))
     }
 
+    async fn list_table_versions(
+        &self,
+        req: ListTableVersionsReq,
+    ) -> Result<ListTableVersionsReply> {
+        let reply = self.ctx.meta.list_table_versions(req).await?;
+        Ok(reply)
+    }
+
     async fn gc_drop_tables(&self, req: GcDroppedTableReq) -> Result<GcDroppedTableResp> {
         let meta = self.ctx.meta.clone();
         let resp = meta.gc_drop_tables(req).await?;
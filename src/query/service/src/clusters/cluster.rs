@@ -23,6 +23,7 @@ use std::time::Duration;
 use std::time::Instant;
 
 use databend_common_arrow::arrow_format::flight::service::flight_service_client::FlightServiceClient;
+use databend_common_base::base::tokio;
 use databend_common_base::base::tokio::sync::Mutex;
 use databend_common_base::base::tokio::sync::Notify;
 use databend_common_base::base::tokio::task::JoinHandle;
@@ -32,6 +33,7 @@ use databend_common_base::base::GlobalInstance;
 use databend_common_base::base::SignalStream;
 use databend_common_base::base::SignalType;
 pub use databend_common_catalog::cluster_info::Cluster;
+use databend_common_catalog::cluster_info::NodeStatus;
 use databend_common_config::GlobalConfig;
 use databend_common_config::InnerConfig;
 use databend_common_config::DATABEND_COMMIT_VERSION;
@@ -56,6 +58,8 @@ use rand::Rng;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::servers::flight::v1::actions::local_node_status;
+use crate::servers::flight::v1::actions::GET_NODE_STATUS;
 use crate::servers::flight::FlightClient;
 
 pub struct ClusterDiscovery {
@@ -66,6 +70,7 @@ pub struct ClusterDiscovery {
     cluster_id: String,
     tenant_id: String,
     flight_address: String,
+    started_at: Instant,
 }
 
 // avoid leak FlightClient to common-xxx
@@ -79,12 +84,21 @@ pub trait ClusterHelper {
 
     fn get_nodes(&self) -> Vec<Arc<NodeInfo>>;
 
+    /// Sends the message of each node to it, the call of a node fails if it does not answer
+    /// within `timeout` seconds, connecting included, so that a sick node can not hang the others.
     async fn do_action<T: Serialize + Send, Res: for<'de> Deserialize<'de> + Send>(
         &self,
         path: &str,
         message: HashMap<String, T>,
         timeout: u64,
     ) -> Result<HashMap<String, Res>>;
+
+    /// Pings all the nodes at once, a node that did not answer within the timeout is mapped to
+    /// the error. The local node answers from memory.
+    async fn ping_nodes(
+        &self,
+        timeout: Duration,
+    ) -> HashMap<String, std::result::Result<NodeStatus, String>>;
 }
 
 #[async_trait::async_trait]
@@ -145,12 +159,18 @@ impl ClusterHelper for Cluster {
                 let node_secret = node.secret.clone();
 
                 async move {
-                    let mut conn = create_client(&config, &flight_address).await?;
-                    Ok::<_, ErrorCode>((
-                        id,
+                    let call = async {
+                        let mut conn = create_client(&config, &flight_address).await?;
                         conn.do_action::<_, Res>(path, node_secret, message, timeout)
-                            .await?,
-                    ))
+                            .await
+                    };
+                    match tokio::time::timeout(Duration::from_secs(timeout), call).await {
+                        Ok(res) => Ok::<_, ErrorCode>((id, res?)),
+                        Err(_) => Err(ErrorCode::Timeout(format!(
+                            "Node {} did not answer {} within {} seconds",
+                            id, path, timeout
+                        ))),
+                    }
                 }
             });
         }
@@ -158,6 +178,45 @@ impl ClusterHelper for Cluster {
         let responses: Vec<(String, Res)> = futures::future::try_join_all(futures).await?;
         Ok(responses.into_iter().collect::<HashMap<String, Res>>())
     }
+
+    async fn ping_nodes(
+        &self,
+        timeout: Duration,
+    ) -> HashMap<String, std::result::Result<NodeStatus, String>> {
+        let futures = self.nodes.iter().map(|node| {
+            let config = GlobalConfig::instance();
+            let is_local = self.is_local(node);
+            let node = node.clone();
+
+            async move {
+                if is_local {
+                    return (node.id.clone(), Ok(local_node_status()));
+                }
+
+                let ping = async {
+                    let mut conn = create_client(&config, &node.flight_address).await?;
+                    conn.do_action::<_, NodeStatus>(
+                        GET_NODE_STATUS,
+                        node.secret.clone(),
+                        (),
+                        timeout.as_secs().max(1),
+                    )
+                    .await
+                };
+                let status = match tokio::time::timeout(timeout, ping).await {
+                    Ok(Ok(status)) => Ok(status),
+                    Ok(Err(cause)) => Err(cause.message()),
+                    Err(_) => Err(format!("no answer within {:?}", timeout)),
+                };
+                (node.id.clone(), status)
+            }
+        });
+
+        futures::future::join_all(futures)
+            .await
+            .into_iter()
+            .collect()
+    }
 }
 
 impl ClusterDiscovery {
@@ -200,6 +259,7 @@ impl ClusterDiscovery {
             cluster_id: cfg.query.cluster_id.clone(),
             tenant_id: cfg.query.tenant_id.tenant_name().to_string(),
             flight_address: cfg.query.flight_api_address.clone(),
+            started_at: Instant::now(),
         }))
     }
 
@@ -207,6 +267,11 @@ impl ClusterDiscovery {
         GlobalInstance::get()
     }
 
+    /// How long this node has been up.
+    pub fn uptime(&self) -> Duration {
+        self.started_at.elapsed()
+    }
+
     fn create_provider(
         cfg: &InnerConfig,
         metastore: MetaStore,
@@ -20,12 +20,15 @@ use databend_common_meta_app::schema::DatabaseInfo;
 use databend_common_meta_app::schema::DatabaseMeta;
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_storages_information_schema::ColumnsTable;
+use databend_common_storages_information_schema::EnginesTable;
 use databend_common_storages_information_schema::KeyColumnUsageTable;
 use databend_common_storages_information_schema::KeywordsTable;
+use databend_common_storages_information_schema::ProcessListTable;
 use databend_common_storages_information_schema::SchemataTable;
 use databend_common_storages_information_schema::StatisticsTable;
 use databend_common_storages_information_schema::TablesTable;
 use databend_common_storages_information_schema::ViewsTable;
+use databend_common_storages_system::GlobalStatusTable;
 
 use crate::catalogs::InMemoryMetas;
 use crate::databases::Database;
@@ -46,6 +49,9 @@ impl InformationSchemaDatabase {
             SchemataTable::create(sys_db_meta.next_table_id()),
             StatisticsTable::create(sys_db_meta.next_table_id()),
             KeyColumnUsageTable::create(sys_db_meta.next_table_id()),
+            ProcessListTable::create(sys_db_meta.next_table_id()),
+            EnginesTable::create(sys_db_meta.next_table_id()),
+            GlobalStatusTable::create(sys_db_meta.next_table_id()),
         ];
 
         let db = "information_schema";
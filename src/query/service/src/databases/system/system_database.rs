@@ -35,8 +35,10 @@ use databend_common_storages_system::ContributorsTable;
 use databend_common_storages_system::CreditsTable;
 use databend_common_storages_system::DatabasesTable;
 use databend_common_storages_system::EnginesTable;
+use databend_common_storages_system::FlightSessionsTable;
 use databend_common_storages_system::FullStreamsTable;
 use databend_common_storages_system::FunctionsTable;
+use databend_common_storages_system::GrantsTable;
 use databend_common_storages_system::IndexesTable;
 use databend_common_storages_system::LocksTable;
 use databend_common_storages_system::MallocStatsTable;
@@ -46,10 +48,12 @@ use databend_common_storages_system::NotificationHistoryTable;
 use databend_common_storages_system::NotificationsTable;
 use databend_common_storages_system::OneTable;
 use databend_common_storages_system::PasswordPoliciesTable;
+use databend_common_storages_system::PreparedStatementsTable;
 use databend_common_storages_system::ProcessesTable;
 use databend_common_storages_system::QueriesProfilingTable;
 use databend_common_storages_system::QueryCacheTable;
 use databend_common_storages_system::QueryLogTable;
+use databend_common_storages_system::QueryProfileTable;
 use databend_common_storages_system::RolesTable;
 use databend_common_storages_system::SettingsTable;
 use databend_common_storages_system::StagesTable;
@@ -65,6 +69,7 @@ use databend_common_storages_system::UsersTable;
 use databend_common_storages_system::ViewsTableWithHistory;
 use databend_common_storages_system::ViewsTableWithoutHistory;
 use databend_common_storages_system::VirtualColumnsTable;
+use databend_common_storages_system::WarningsTable;
 
 use crate::catalogs::InMemoryMetas;
 use crate::databases::Database;
@@ -117,8 +122,12 @@ impl SystemDatabase {
             )),
             EnginesTable::create(sys_db_meta.next_table_id()),
             RolesTable::create(sys_db_meta.next_table_id()),
+            GrantsTable::create(sys_db_meta.next_table_id()),
             StagesTable::create(sys_db_meta.next_table_id()),
-            BuildOptionsTable::create(sys_db_meta.next_table_id()),
+            BuildOptionsTable::create(
+                sys_db_meta.next_table_id(),
+                option_env!("VERGEN_CARGO_FEATURES"),
+            ),
             CatalogsTable::create(sys_db_meta.next_table_id()),
             QueryCacheTable::create(sys_db_meta.next_table_id()),
             TableFunctionsTable::create(sys_db_meta.next_table_id()),
@@ -131,6 +140,7 @@ impl SystemDatabase {
             TasksTable::create(sys_db_meta.next_table_id()),
             TaskHistoryTable::create(sys_db_meta.next_table_id()),
             QueriesProfilingTable::create(sys_db_meta.next_table_id()),
+            QueryProfileTable::create(sys_db_meta.next_table_id()),
             LocksTable::create(sys_db_meta.next_table_id()),
             VirtualColumnsTable::create(sys_db_meta.next_table_id()),
             PasswordPoliciesTable::create(sys_db_meta.next_table_id()),
@@ -139,6 +149,9 @@ impl SystemDatabase {
             NotificationHistoryTable::create(sys_db_meta.next_table_id()),
             ViewsTableWithHistory::create(sys_db_meta.next_table_id()),
             ViewsTableWithoutHistory::create(sys_db_meta.next_table_id()),
+            FlightSessionsTable::create(sys_db_meta.next_table_id()),
+            PreparedStatementsTable::create(sys_db_meta.next_table_id()),
+            WarningsTable::create(sys_db_meta.next_table_id()),
         ];
 
         let disable_tables = Self::disable_system_tables();
@@ -30,6 +30,9 @@ use databend_common_storage::DataOperator;
 use databend_common_storage::ShareTableConfig;
 use databend_common_storages_hive::HiveCreator;
 use databend_common_storages_iceberg::IcebergCreator;
+use databend_common_storages_result_cache::ResultCacheStatistics;
+use databend_common_storages_system::FlightSessionsRegistry;
+use databend_common_storages_system::PreparedStatementsRegistry;
 use databend_common_storages_system::ProfilesLogQueue;
 use databend_common_tracing::GlobalLogger;
 use databend_common_users::builtin::BuiltIn;
@@ -47,6 +50,7 @@ use crate::locks::LockManager;
 use crate::pipelines::executor::GlobalQueriesExecutor;
 use crate::servers::flight::v1::exchange::DataExchangeManager;
 use crate::servers::http::v1::HttpQueryManager;
+use crate::servers::MySQLFederatedRules;
 use crate::sessions::QueriesQueueManager;
 use crate::sessions::SessionManager;
 
@@ -105,12 +109,16 @@ impl GlobalServices {
             CatalogManager::init(config, Arc::new(default_catalog), catalog_creator).await?;
         }
 
-        QueriesQueueManager::init(config.query.max_running_queries as usize)?;
+        QueriesQueueManager::init(
+            config.query.max_running_queries as usize,
+            config.query.max_queued_queries as usize,
+        )?;
         HttpQueryManager::init(config).await?;
         DataExchangeManager::init()?;
         SessionManager::init(config)?;
         LockManager::init()?;
         AuthMgr::init(config)?;
+        MySQLFederatedRules::init(config)?;
 
         // Init user manager.
         // Builtin users and udfs are created here.
@@ -146,12 +154,16 @@ impl GlobalServices {
             &config.query.max_server_memory_usage,
             config.query.tenant_id.tenant_name().to_string(),
         )?;
+        ResultCacheStatistics::init()?;
+        CacheManager::instance().register_cache(ResultCacheStatistics::instance());
 
         if let Some(addr) = config.query.cloud_control_grpc_server_address.clone() {
             CloudControlApiProvider::init(addr, config.query.cloud_control_grpc_timeout).await?;
         }
 
         ProfilesLogQueue::init(config.query.max_cached_queries_profiles);
+        FlightSessionsRegistry::init()?;
+        PreparedStatementsRegistry::init()?;
 
         #[cfg(feature = "enable_queries_executor")]
         {
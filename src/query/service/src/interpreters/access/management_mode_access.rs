@@ -47,6 +47,7 @@ impl AccessChecker for ManagementModeAccess {
                             RewriteKind::ShowDatabases
                             | RewriteKind::ShowTables(_, _)
                             | RewriteKind::ShowColumns(_, _, _)
+                            | RewriteKind::ShowKeys(_, _, _)
                             | RewriteKind::ShowEngines
                             | RewriteKind::ShowSettings
                             | RewriteKind::ShowFunctions
@@ -68,6 +69,7 @@ impl AccessChecker for ManagementModeAccess {
                 // Show.
                 Plan::ShowCreateDatabase(_)
                 | Plan::ShowCreateTable(_)
+                | Plan::ShowCreateView(_)
 
                 // Set
                 | Plan::SetVariable(_)
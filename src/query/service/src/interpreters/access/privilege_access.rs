@@ -56,8 +56,9 @@ enum ObjectId {
 
 // some statements like `SELECT 1`, `SHOW USERS`, `SHOW ROLES`, `SHOW TABLES` will be
 // rewritten to the queries on the system tables, we need to skip the privilege check on
-// these tables.
-const SYSTEM_TABLES_ALLOW_LIST: [&str; 19] = [
+// these tables. `processes` and `query_log` only show the rows of the current user to the
+// users without the SUPER privilege.
+const SYSTEM_TABLES_ALLOW_LIST: [&str; 18] = [
     "catalogs",
     "columns",
     "databases",
@@ -69,16 +70,23 @@ const SYSTEM_TABLES_ALLOW_LIST: [&str; 19] = [
     "streams",
     "streams_terse",
     "virtual_columns",
-    "users",
-    "roles",
     "stages",
     "one",
     "processes",
+    "query_log",
     "user_functions",
     "functions",
     "indexes",
 ];
 
+// System tables showing the statements of other users, the configs of the node, or expensive to
+// read like the task dump of the backtrace, only readable with the SUPER privilege.
+const SYSTEM_TABLES_SUPER_LIST: [&str; 3] = ["prepared_statements", "backtrace", "configs"];
+
+// System tables listing the users, roles and their grants, only readable by the users allowed to
+// manage the grants.
+const SYSTEM_TABLES_GRANT_LIST: [&str; 3] = ["users", "roles", "grants"];
+
 impl PrivilegeAccess {
     pub fn create(ctx: Arc<QueryContext>) -> Box<dyn AccessChecker> {
         Box::new(PrivilegeAccess { ctx })
@@ -248,6 +256,18 @@ impl PrivilegeAccess {
         privilege: UserPrivilegeType,
         if_exists: bool,
     ) -> Result<()> {
+        if db_name == "system" && SYSTEM_TABLES_SUPER_LIST.contains(&table_name) {
+            return self
+                .validate_access(&GrantObject::Global, UserPrivilegeType::Super, false)
+                .await;
+        }
+
+        if db_name == "system" && SYSTEM_TABLES_GRANT_LIST.contains(&table_name) {
+            return self
+                .validate_access(&GrantObject::Global, UserPrivilegeType::Grant, false)
+                .await;
+        }
+
         // skip checking the privilege on system tables.
         if ((db_name == "system" && SYSTEM_TABLES_ALLOW_LIST.iter().any(|x| x == &table_name))
             || db_name == "information_schema")
@@ -543,6 +563,23 @@ impl PrivilegeAccess {
         Ok(ObjectId::Database(db_id))
     }
 
+    // The id is the numeric connection id or the session id, as in KILL.
+    fn is_own_session(&self, id: &str) -> Result<bool> {
+        let session_id = match id.parse::<u32>() {
+            Ok(conn_id) => self.ctx.get_id_by_mysql_conn_id(&Some(conn_id)),
+            Err(_) => Some(id.to_string()),
+        };
+        let Some(session) = session_id.and_then(|id| self.ctx.get_session_by_id(&id)) else {
+            return Ok(false);
+        };
+
+        let current_user = self.ctx.get_current_user()?;
+        Ok(match session.get_current_user() {
+            Ok(user) => user.identity() == current_user.identity(),
+            Err(_) => false,
+        })
+    }
+
     async fn validate_insert_source(
         &self,
         ctx: &Arc<QueryContext>,
@@ -630,7 +667,8 @@ impl AccessChecker for PrivilegeAccess {
                         let roles_name: Vec<String> = roles.iter().map(|role| role.name.to_string()).collect();
                         check_ownership_access(&identity, &ctl_name, database, show_db_id, &ownerships, &roles_name)?;
                     }
-                    Some(RewriteKind::ShowColumns(catalog_name, database, table)) => {
+                    Some(RewriteKind::ShowColumns(catalog_name, database, table))
+                    | Some(RewriteKind::ShowKeys(catalog_name, database, table)) => {
                         let session = self.ctx.get_current_session();
                         if self.has_ownership(&session, &GrantObject::Table(catalog_name.clone(), database.clone(), table.clone()), false).await? ||
                             self.has_ownership(&session, &GrantObject::Database(catalog_name.clone(), database.clone()), false).await?   {
@@ -1014,6 +1052,9 @@ impl AccessChecker for PrivilegeAccess {
             Plan::DropView(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, UserPrivilegeType::Drop, plan.if_exists).await?
             }
+            Plan::ShowCreateView(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.view_name, UserPrivilegeType::Select, false).await?
+            }
             Plan::DescribeView(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.view_name, UserPrivilegeType::Select, false).await?
             }
@@ -1069,10 +1110,17 @@ impl AccessChecker for PrivilegeAccess {
                 self.validate_access(&GrantObject::Global, UserPrivilegeType::Grant,false)
                     .await?;
             }
-            Plan::SetVariable(_) | Plan::UnSetVariable(_) | Plan::Kill(_) | Plan::SetPriority(_) | Plan::System(_) => {
+            Plan::SetVariable(_) | Plan::UnSetVariable(_) | Plan::SetPriority(_) | Plan::System(_) => {
                 self.validate_access(&GrantObject::Global, UserPrivilegeType::Super, false)
                     .await?;
             }
+            Plan::Kill(plan) => {
+                // Users can kill their own sessions and queries without the SUPER privilege.
+                if !self.is_own_session(&plan.id)? {
+                    self.validate_access(&GrantObject::Global, UserPrivilegeType::Super, false)
+                        .await?;
+                }
+            }
             Plan::AlterUser(plan) => {
                 let current_user = self.ctx.get_current_user()?;
                 // Only alter current user's password do not need to check privileges.
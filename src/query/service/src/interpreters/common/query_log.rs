@@ -14,12 +14,14 @@
 
 use std::fmt::Write;
 use std::sync::Arc;
+use std::time::Duration;
 use std::time::SystemTime;
 
 use databend_common_config::GlobalConfig;
 use databend_common_config::DATABEND_COMMIT_VERSION;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_storages_system::LogRetention;
 use databend_common_storages_system::LogType;
 use databend_common_storages_system::QueryLogElement;
 use databend_common_storages_system::QueryLogQueue;
@@ -70,7 +72,17 @@ impl InterpreterQueryLog {
         info!(target: "databend::log::query", "{}", event_str);
         // log the query event in the system log
         info!("query: {} becomes {:?}", event.query_id, event.log_type);
-        QueryLogQueue::instance()?.append_data(event)
+
+        // The log is shared by the whole node, so are its limits.
+        let config = GlobalConfig::instance();
+        let retention = LogRetention {
+            max_rows: config.query.query_log_max_rows as usize,
+            max_age: match config.query.query_log_retention_hours {
+                0 => None,
+                hours => Some(Duration::from_secs(hours * 3600)),
+            },
+        };
+        QueryLogQueue::instance()?.append_data_with_retention(event, retention)
     }
 
     pub fn fail_to_start(ctx: Arc<QueryContext>, err: ErrorCode) {
@@ -85,6 +97,7 @@ impl InterpreterQueryLog {
         let cluster_id = GlobalConfig::instance().query.cluster_id.clone();
         let node_id = ctx.get_cluster().local_id.clone();
         let user = ctx.get_current_user()?;
+        let sql_user_host = user.hostname;
         let sql_user = user.name;
         let sql_user_quota = format!("{:?}", user.quota);
         let sql_user_privileges = user.grants.to_string();
@@ -135,6 +148,8 @@ impl InterpreterQueryLog {
             None => "".to_string(),
         };
         let user_agent = ctx.get_ua();
+        let query_tag = ctx.get_query_tag();
+        let labels = ctx.get_current_session().get_labels();
         // Session settings
         let mut session_settings = String::new();
         let current_session = ctx.get_current_session();
@@ -164,6 +179,7 @@ impl InterpreterQueryLog {
             cluster_id,
             node_id,
             sql_user,
+            sql_user_host,
             sql_user_quota,
             sql_user_privileges,
             query_id,
@@ -208,6 +224,8 @@ impl InterpreterQueryLog {
             client_info: "".to_string(),
             client_address,
             user_agent,
+            query_tag,
+            labels,
 
             exception_code,
             exception_text,
@@ -216,6 +234,7 @@ impl InterpreterQueryLog {
             session_settings,
             extra: "".to_string(),
             has_profiles: false,
+            result_cache_hit: false,
             txn_state,
             txn_id,
         })
@@ -238,6 +257,7 @@ impl InterpreterQueryLog {
         let cluster_id = GlobalConfig::instance().query.cluster_id.clone();
         let node_id = ctx.get_cluster().local_id.clone();
         let user = ctx.get_current_user()?;
+        let sql_user_host = user.hostname;
         let sql_user = user.name;
         let sql_user_quota = format!("{:?}", user.quota);
         let sql_user_privileges = user.grants.to_string();
@@ -296,6 +316,8 @@ impl InterpreterQueryLog {
             None => "".to_string(),
         };
         let user_agent = ctx.get_ua();
+        let query_tag = ctx.get_query_tag();
+        let labels = ctx.get_current_session().get_labels();
 
         // Schema.
         let current_database = ctx.get_current_database();
@@ -331,6 +353,7 @@ impl InterpreterQueryLog {
             cluster_id,
             node_id,
             sql_user,
+            sql_user_host,
             sql_user_quota,
             sql_user_privileges,
             query_id,
@@ -374,6 +397,8 @@ impl InterpreterQueryLog {
             client_info: "".to_string(),
             client_address,
             user_agent,
+            query_tag,
+            labels,
             current_database,
 
             exception_code,
@@ -383,6 +408,7 @@ impl InterpreterQueryLog {
             session_settings,
             extra: "".to_string(),
             has_profiles,
+            result_cache_hit: ctx.get_result_cache_hit().is_some(),
             txn_state,
             txn_id,
         })
@@ -31,6 +31,7 @@ use databend_common_pipeline_core::always_callback;
 use databend_common_pipeline_core::processors::PlanProfile;
 use databend_common_pipeline_core::ExecutionInfo;
 use databend_common_pipeline_core::SourcePipeBuilder;
+use databend_common_sql::get_query_kind;
 use databend_common_sql::plans::Plan;
 use databend_common_sql::PlanExtras;
 use databend_common_sql::Planner;
@@ -70,6 +71,12 @@ pub trait Interpreter: Sync + Send {
 
     fn is_ddl(&self) -> bool;
 
+    /// Whether the statement writes data which a transaction can not roll back,
+    /// like the writes to a non-fuse table or to a stage.
+    async fn writes_outside_transaction(&self) -> Result<bool> {
+        Ok(false)
+    }
+
     /// The core of the databend processor which will execute the logical plan and get the DataBlock
     #[async_backtrace::framed]
     #[minitrace::trace]
@@ -87,11 +94,33 @@ pub trait Interpreter: Sync + Send {
     async fn execute_inner(&self, ctx: Arc<QueryContext>) -> Result<SendableDataBlockStream> {
         ctx.set_status_info("building pipeline");
         ctx.check_aborting()?;
+        let in_txn = ctx.txn_mgr().lock().is_active();
         if self.is_ddl() {
+            if in_txn {
+                if ctx.get_settings().get_strict_transaction_emulation()? {
+                    return Err(ErrorCode::ImplicitCommitInTransaction(
+                        "DDL statement would commit the current transaction, run COMMIT or ROLLBACK first",
+                    ));
+                }
+                let message =
+                    "The current transaction was committed implicitly before a DDL statement";
+                ctx.push_warning(message.to_string());
+            }
             CommitInterpreter::try_create(ctx.clone())?
                 .execute2()
                 .await?;
             ctx.clear_tables_cache();
+        } else if in_txn && self.writes_outside_transaction().await? {
+            if ctx.get_settings().get_strict_transaction_emulation()? {
+                return Err(ErrorCode::NonTransactionalWriteInTransaction(
+                    "The statement writes outside of the current transaction, run COMMIT or ROLLBACK first",
+                ));
+            }
+            ctx.push_warning(
+                "The statement writes outside of the current transaction, ROLLBACK will not undo it"
+                    .to_string(),
+            );
+            ctx.txn_mgr().lock().add_non_transactional_write();
         }
         if !self.is_txn_command() && ctx.txn_mgr().lock().is_fail() {
             let err = ErrorCode::CurrentTransactionIsAborted(
@@ -239,14 +268,30 @@ fn log_query_finished(ctx: &QueryContext, error: Option<ErrorCode>, has_profiles
 ///
 /// This function is used to plan the SQL. If an error occurs, we will log the query start and finished.
 pub async fn interpreter_plan_sql(ctx: Arc<QueryContext>, sql: &str) -> Result<(Plan, PlanExtras)> {
+    interpreter_plan_sql_with_query_text(ctx, sql, sql).await
+}
+
+/// Plan the `sql` rewritten from the `query_text` the client sent, the query log keeps the `query_text`.
+/// The statement text is logged instead if it masks the secrets of the query.
+pub async fn interpreter_plan_sql_with_query_text(
+    ctx: Arc<QueryContext>,
+    sql: &str,
+    query_text: &str,
+) -> Result<(Plan, PlanExtras)> {
     let mut planner = Planner::new(ctx.clone());
     let result = planner.plan_sql(sql).await;
+    let rewritten = sql != query_text;
+    let query_text = short_sql(query_text.to_string());
     let short_sql = short_sql(sql.to_string());
     let mut stmt = if let Ok((_, extras)) = &result {
-        Some(extras.statement.clone())
+        let stmt = &extras.statement;
+        if rewritten && stmt.to_mask_sql() == stmt.to_string() {
+            ctx.attach_query_str(get_query_kind(stmt), query_text);
+        }
+        Some(stmt.clone())
     } else {
         // Only log if there's an error
-        ctx.attach_query_str(QueryKind::Unknown, short_sql.to_string());
+        ctx.attach_query_str(QueryKind::Unknown, query_text);
         log_query_start(&ctx);
         log_query_finished(&ctx, result.as_ref().err().cloned(), false);
         None
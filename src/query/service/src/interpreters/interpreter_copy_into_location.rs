@@ -131,6 +131,11 @@ impl Interpreter for CopyIntoLocationInterpreter {
         false
     }
 
+    async fn writes_outside_transaction(&self) -> Result<bool> {
+        // The unloaded files are not removed by a rollback.
+        Ok(true)
+    }
+
     #[minitrace::trace]
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
@@ -55,7 +55,12 @@ impl Interpreter for ShowCreateDatabaseInterpreter {
         let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
         let db = catalog.get_database(&tenant, &self.plan.database).await?;
         let name = db.name();
-        let mut info = format!("CREATE DATABASE `{}`", name);
+        // Same as MySQL, the version comment keeps the text runnable on old servers.
+        let if_not_exists = match self.plan.if_not_exists {
+            true => "/*!32312 IF NOT EXISTS*/ ",
+            false => "",
+        };
+        let mut info = format!("CREATE DATABASE {}`{}`", if_not_exists, name);
         if !db.engine().is_empty() {
             let engine = format!(" ENGINE={}", db.engine().to_uppercase());
             let engine_options = db
@@ -28,6 +28,7 @@ use databend_common_expression::DataSchemaRef;
 use databend_common_expression::FromData;
 use databend_common_expression::ROW_ID_COL_NAME;
 use databend_common_pipeline_core::processors::PlanProfile;
+use databend_common_pipeline_core::Pipeline;
 use databend_common_sql::binder::ExplainConfig;
 use databend_common_sql::optimizer::ColumnSet;
 use databend_common_sql::plans::FunctionCall;
@@ -210,7 +211,7 @@ impl Interpreter for ExplainInterpreter {
                     _ => PipelineBuildResult::create(),
                 };
 
-                Self::format_pipeline(&pipeline)
+                Self::format_pipeline(&pipeline, self.config.graphviz)
             }
 
             ExplainKind::Fragments => match &self.plan {
@@ -332,10 +333,15 @@ impl ExplainInterpreter {
         Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])])
     }
 
-    fn format_pipeline(build_res: &PipelineBuildResult) -> Vec<DataBlock> {
+    fn format_pipeline(build_res: &PipelineBuildResult, graphviz: bool) -> Vec<DataBlock> {
+        let display = |pipeline: &Pipeline| match graphviz {
+            true => pipeline.display_graphviz().to_string(),
+            false => pipeline.display_indent().to_string(),
+        };
+
         let mut blocks = Vec::with_capacity(1 + build_res.sources_pipelines.len());
         // Format root pipeline
-        let line_split_result = format!("{}", build_res.main_pipeline.display_indent())
+        let line_split_result = display(&build_res.main_pipeline)
             .lines()
             .map(|l| l.to_string())
             .collect::<Vec<_>>();
@@ -343,7 +349,7 @@ impl ExplainInterpreter {
         blocks.push(DataBlock::new_from_columns(vec![column]));
         // Format child pipelines
         for pipeline in build_res.sources_pipelines.iter() {
-            let line_split_result = format!("\n{}", pipeline.display_indent())
+            let line_split_result = format!("\n{}", display(pipeline))
                 .lines()
                 .map(|l| l.to_string())
                 .collect::<Vec<_>>();
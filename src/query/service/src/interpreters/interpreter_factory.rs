@@ -63,6 +63,7 @@ use crate::interpreters::interpreter_txn_abort::AbortInterpreter;
 use crate::interpreters::interpreter_txn_begin::BeginInterpreter;
 use crate::interpreters::interpreter_txn_commit::CommitInterpreter;
 use crate::interpreters::interpreter_view_describe::DescribeViewInterpreter;
+use crate::interpreters::interpreter_view_show_create::ShowCreateViewInterpreter;
 use crate::interpreters::AlterUserInterpreter;
 use crate::interpreters::CreateShareEndpointInterpreter;
 use crate::interpreters::CreateShareInterpreter;
@@ -268,6 +269,9 @@ impl InterpreterFactory {
                 ctx,
                 *drop_view.clone(),
             )?)),
+            Plan::ShowCreateView(show_create_view) => Ok(Arc::new(
+                ShowCreateViewInterpreter::try_create(ctx, *show_create_view.clone())?,
+            )),
             Plan::DescribeView(describe_view) => Ok(Arc::new(DescribeViewInterpreter::try_create(
                 ctx,
                 *describe_view.clone(),
@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use databend_common_catalog::lock::LockTableOption;
 use databend_common_catalog::table::AppendMode;
+use databend_common_catalog::table::Table;
 use databend_common_catalog::table::TableExt;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -72,6 +73,20 @@ impl InsertInterpreter {
         let cast_needed = select_schema.as_ref() != &DataSchema::from(output_schema.as_ref());
         Ok(cast_needed)
     }
+
+    async fn get_table(&self) -> Result<Arc<dyn Table>> {
+        if let Some(table_info) = &self.plan.table_info {
+            // if table_info is provided, we should instantiated table with it.
+            self.ctx
+                .get_catalog(&self.plan.catalog)
+                .await?
+                .get_table_by_info(table_info)
+        } else {
+            self.ctx
+                .get_table(&self.plan.catalog, &self.plan.database, &self.plan.table)
+                .await
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -84,22 +99,17 @@ impl Interpreter for InsertInterpreter {
         false
     }
 
+    async fn writes_outside_transaction(&self) -> Result<bool> {
+        // Only the writes to the fuse tables are kept in the transaction.
+        Ok(self.get_table().await?.engine() != "FUSE")
+    }
+
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         if check_deduplicate_label(self.ctx.clone()).await? {
             return Ok(PipelineBuildResult::create());
         }
-        let table = if let Some(table_info) = &self.plan.table_info {
-            // if table_info is provided, we should instantiated table with it.
-            self.ctx
-                .get_catalog(&self.plan.catalog)
-                .await?
-                .get_table_by_info(table_info)?
-        } else {
-            self.ctx
-                .get_table(&self.plan.catalog, &self.plan.database, &self.plan.table)
-                .await?
-        };
+        let table = self.get_table().await?;
 
         // check mutability
         table.check_mutable()?;
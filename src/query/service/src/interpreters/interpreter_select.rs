@@ -326,11 +326,12 @@ impl Interpreter for SelectInterpreter {
             );
 
             // 2. Check the cache.
-            match cache_reader.try_read_cached_result().await {
-                Ok(Some(blocks)) => {
+            match cache_reader.try_read_cached_entry().await {
+                Ok(Some((value, blocks))) => {
                     // 2.0 update query_id -> result_cache_meta_key in session.
                     self.ctx
                         .set_query_id_result_cache(self.ctx.get_id(), cache_reader.get_meta_key());
+                    self.ctx.set_result_cache_hit(value.query_time);
                     // 2.1 If found, return the result directly.
                     return PipelineBuildResult::from_blocks(blocks);
                 }
@@ -276,7 +276,7 @@ impl ShowCreateTableInterpreter {
         Ok(table_create_sql)
     }
 
-    fn show_create_view_query(table: &dyn Table, database: &str) -> Result<String> {
+    pub fn show_create_view_query(table: &dyn Table, database: &str) -> Result<String> {
         let name = table.name();
         let view_create_sql = if let Some(query) = table.options().get(QUERY) {
             Ok(format!(
@@ -22,6 +22,7 @@ use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
 pub struct AbortInterpreter {
+    ctx: Arc<QueryContext>,
     txn_manager: TxnManagerRef,
 }
 
@@ -29,6 +30,7 @@ impl AbortInterpreter {
     pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
         Ok(Self {
             txn_manager: ctx.txn_mgr(),
+            ctx,
         })
     }
 }
@@ -49,7 +51,17 @@ impl Interpreter for AbortInterpreter {
 
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
-        self.txn_manager.lock().clear();
+        let mut txn_manager = self.txn_manager.lock();
+        if !txn_manager.is_active() && !txn_manager.is_fail() {
+            let message = "ROLLBACK outside of a transaction, nothing was rolled back";
+            self.ctx.push_warning(message.to_string());
+        } else if txn_manager.non_transactional_writes() > 0 {
+            self.ctx.push_warning(format!(
+                "ROLLBACK did not undo {} statement(s) which wrote outside of the transaction",
+                txn_manager.non_transactional_writes()
+            ));
+        }
+        txn_manager.clear();
         Ok(PipelineBuildResult::create())
     }
 }
@@ -20,7 +20,6 @@ use databend_common_exception::Result;
 use databend_common_management::UserApi;
 use databend_common_meta_app::principal::UserGrantSet;
 use databend_common_meta_app::principal::UserInfo;
-use databend_common_meta_app::principal::UserQuota;
 use databend_common_meta_types::MatchSeq;
 use databend_common_sql::plans::CreateUserPlan;
 use databend_common_users::UserApiProvider;
@@ -79,7 +78,7 @@ impl Interpreter for CreateUserInterpreter {
             name: plan.user.username,
             hostname: plan.user.hostname,
             grants: UserGrantSet::empty(),
-            quota: UserQuota::no_limit(),
+            quota: plan.quota,
             option: plan.user_option,
             history_auth_infos: vec![plan.auth_info.clone()],
             password_fails: Vec::new(),
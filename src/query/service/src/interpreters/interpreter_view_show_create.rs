@@ -0,0 +1,80 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_sql::plans::ShowCreateViewPlan;
+use databend_common_storages_fuse::TableContext;
+use databend_common_storages_view::view_table::VIEW_ENGINE;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::ShowCreateTableInterpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct ShowCreateViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ShowCreateViewPlan,
+}
+
+impl ShowCreateViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ShowCreateViewPlan) -> Result<Self> {
+        Ok(ShowCreateViewInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ShowCreateViewInterpreter {
+    fn name(&self) -> &str {
+        "ShowCreateViewInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.plan.catalog.as_str();
+        let database = self.plan.database.as_str();
+        let view = self.plan.view_name.as_str();
+        let table = self.ctx.get_table(catalog, database, view).await?;
+        let engine = table.get_table_info().engine();
+        if engine != VIEW_ENGINE {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} is not VIEW, please use `SHOW CREATE TABLE {}.{}`",
+                &self.plan.database,
+                &self.plan.view_name,
+                &self.plan.database,
+                &self.plan.view_name
+            )));
+        }
+
+        let create_sql =
+            ShowCreateTableInterpreter::show_create_view_query(table.as_ref(), database)?;
+
+        // The charset columns are the ones reported to MySQL clients for the connection.
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(vec![table.name()]),
+            StringType::from_data(vec![create_sql]),
+            StringType::from_data(vec!["utf8mb4"]),
+            StringType::from_data(vec!["utf8mb4_general_ci"]),
+        ])])
+    }
+}
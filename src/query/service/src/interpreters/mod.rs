@@ -144,6 +144,7 @@ mod interpreter_view_alter;
 mod interpreter_view_create;
 mod interpreter_view_describe;
 mod interpreter_view_drop;
+mod interpreter_view_show_create;
 mod interpreter_virtual_column_alter;
 mod interpreter_virtual_column_create;
 mod interpreter_virtual_column_drop;
@@ -154,6 +155,7 @@ pub use access::ManagementModeAccess;
 pub use common::InterpreterQueryLog;
 pub use hook::HookOperator;
 pub use interpreter::interpreter_plan_sql;
+pub use interpreter::interpreter_plan_sql_with_query_text;
 pub use interpreter::Interpreter;
 pub use interpreter::InterpreterPtr;
 pub use interpreter_cluster_key_alter::AlterTableClusterKeyInterpreter;
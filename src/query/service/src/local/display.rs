@@ -381,6 +381,7 @@ fn replace_newline_in_box_display(stmt: &Statement) -> bool {
             | Statement::ShowCreateCatalog(_)
             | Statement::ShowCreateDatabase(_)
             | Statement::ShowCreateTable(_)
+            | Statement::ShowCreateView(_)
     )
 }
 
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
@@ -22,6 +23,8 @@ use databend_common_base::base::GlobalInstance;
 use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_base::runtime::TrySpawn;
 use databend_common_catalog::lock::Lock;
+use databend_common_catalog::lock::LOCK_OBJECT;
+use databend_common_catalog::lock::LOCK_SESSION_ID;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -99,7 +102,7 @@ impl LockManager {
     ) -> Result<Option<Arc<LockGuard>>> {
         let user = ctx.get_current_user()?.name;
         let node = ctx.get_cluster().local_id.clone();
-        let query_id = ctx.get_current_session_id();
+        let query_id = ctx.get_id();
         let expire_secs = ctx.get_settings().get_table_lock_expire_secs()?;
 
         let catalog = ctx.get_catalog(lock.get_catalog()).await?;
@@ -112,7 +115,18 @@ impl LockManager {
             table_id,
         };
 
-        let req = CreateLockRevReq::new(lock_key.clone(), user, node, query_id, expire_secs);
+        let extra_info = BTreeMap::from([
+            (LOCK_SESSION_ID.to_string(), ctx.get_current_session_id()),
+            (LOCK_OBJECT.to_string(), lock.object_name()),
+        ]);
+        let req = CreateLockRevReq::new(
+            lock_key.clone(),
+            user,
+            node,
+            query_id,
+            expire_secs,
+            extra_info,
+        );
 
         // get a new table lock revision.
         let res = catalog.create_lock_revision(req).await?;
@@ -55,6 +55,10 @@ impl Lock for TableLock {
         &self.table_info.tenant
     }
 
+    fn object_name(&self) -> String {
+        self.table_info.desc.clone()
+    }
+
     async fn try_lock(
         &self,
         ctx: Arc<dyn TableContext>,
@@ -16,6 +16,7 @@ use databend_common_exception::Result;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_sql::executor::physical_plans::Limit;
 
+use crate::pipelines::processors::LimitCounter;
 use crate::pipelines::processors::TransformLimit;
 use crate::pipelines::PipelineBuilder;
 
@@ -24,15 +25,17 @@ impl PipelineBuilder {
         self.build_pipeline(&limit.input)?;
 
         if limit.limit.is_some() || limit.offset != 0 {
-            self.main_pipeline.try_resize(1)?;
-            return self.main_pipeline.add_transform(|input, output| {
+            // The limit is applied on every pipe with a shared counter, so all the
+            // upstream pipes stop as soon as the limit is reached.
+            let counter = LimitCounter::create(limit.limit, limit.offset);
+            self.main_pipeline.add_transform(|input, output| {
                 Ok(ProcessorPtr::create(TransformLimit::try_create(
-                    limit.limit,
-                    limit.offset,
+                    counter.clone(),
                     input,
                     output,
                 )?))
-            });
+            })?;
+            return self.main_pipeline.try_resize(1);
         }
         Ok(())
     }
@@ -14,6 +14,7 @@
 
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
+use databend_common_pipeline_core::processors::DispatchPolicy;
 use databend_common_sql::executor::physical_plans::RecursiveCteScan;
 use databend_common_sql::executor::physical_plans::UnionAll;
 
@@ -34,7 +35,9 @@ impl PipelineBuilder {
             },
             1,
         )?;
-        self.main_pipeline.resize(max_threads as usize, true)
+        // The blocks of the iterations have skewed sizes, each goes to the least busy pipe.
+        self.main_pipeline
+            .dispatch(DispatchPolicy::LeastBuffered, max_threads as usize)
     }
 
     pub fn build_recursive_cte_scan(&mut self, r_cte_scan: &RecursiveCteScan) -> Result<()> {
@@ -49,6 +52,7 @@ impl PipelineBuilder {
             },
             1,
         )?;
-        self.main_pipeline.resize(max_threads as usize, true)
+        self.main_pipeline
+            .dispatch(DispatchPolicy::LeastBuffered, max_threads as usize)
     }
 }
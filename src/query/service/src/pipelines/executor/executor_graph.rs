@@ -324,6 +324,10 @@ impl ExecutingGraph {
                         graph[source_node].outputs_port[source_port].record_profile();
                     }
 
+                    if target_plan_id.is_some() && source_plan_id != target_plan_id {
+                        graph[target_node].inputs_port[target_port].record_profile();
+                    }
+
                     connect(
                         &graph[target_node].inputs_port[target_port],
                         &graph[source_node].outputs_port[source_port],
@@ -827,13 +831,16 @@ impl RunningGraph {
             return Ok(());
         }
         self.0.should_finish.store(true, Ordering::SeqCst);
-        self.0.finished_notify.notify_waiters();
-        self.interrupt_running_nodes();
         let mut finished_error = self.0.finished_error.lock();
         if finished_error.is_none() {
             *finished_error = cause.err();
-            drop(finished_error);
         }
+        drop(finished_error);
+
+        // Interrupt after saving the cause, the interrupted processors may fail with an
+        // aborted error.
+        self.0.finished_notify.notify_waiters();
+        self.interrupt_running_nodes();
 
         if let Some(notify) = self.0.finish_condvar_notify.clone() {
             let (lock, cvar) = &*notify;
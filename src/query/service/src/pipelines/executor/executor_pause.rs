@@ -0,0 +1,92 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use parking_lot::Condvar;
+use parking_lot::Mutex;
+
+#[derive(Default)]
+struct PauseState {
+    paused_at: Option<Instant>,
+    paused_time: Duration,
+    finished: bool,
+}
+
+/// Pauses and resumes the scheduling of the processors of a running pipeline, e.g. to give way
+/// to the interactive queries.
+///
+/// The processes in flight finish when pausing, then the workers wait until resuming. The paused
+/// time isn't the cpu time of any processor.
+pub struct ExecutorPauseHandle {
+    state: Mutex<PauseState>,
+    resumed: Condvar,
+}
+
+impl ExecutorPauseHandle {
+    pub fn create() -> Arc<ExecutorPauseHandle> {
+        Arc::new(ExecutorPauseHandle {
+            state: Mutex::new(PauseState::default()),
+            resumed: Condvar::new(),
+        })
+    }
+
+    pub fn pause(&self) {
+        let mut state = self.state.lock();
+        if !state.finished && state.paused_at.is_none() {
+            state.paused_at = Some(Instant::now());
+        }
+    }
+
+    pub fn resume(&self) {
+        let mut state = self.state.lock();
+        self.resume_locked(&mut state);
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.state.lock().paused_at.is_some()
+    }
+
+    /// The total time of the pipeline being paused, including the current pause.
+    pub fn get_paused_time(&self) -> Duration {
+        let state = self.state.lock();
+        match state.paused_at {
+            None => state.paused_time,
+            Some(paused_at) => state.paused_time + paused_at.elapsed(),
+        }
+    }
+
+    /// Resumes the finished pipeline for the workers to exit, it can't be paused any more.
+    pub(crate) fn finish(&self) {
+        let mut state = self.state.lock();
+        state.finished = true;
+        self.resume_locked(&mut state);
+    }
+
+    pub(crate) fn wait_resumed(&self) {
+        let mut state = self.state.lock();
+        while state.paused_at.is_some() {
+            self.resumed.wait(&mut state);
+        }
+    }
+
+    fn resume_locked(&self, state: &mut PauseState) {
+        if let Some(paused_at) = state.paused_at.take() {
+            state.paused_time += paused_at.elapsed();
+            self.resumed.notify_all();
+        }
+    }
+}
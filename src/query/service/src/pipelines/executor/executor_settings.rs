@@ -25,6 +25,7 @@ pub struct ExecutorSettings {
     pub enable_queries_executor: bool,
     pub max_execute_time_in_seconds: Duration,
     pub executor_node_id: String,
+    pub max_pipeline_memory_usage: usize,
 }
 
 impl ExecutorSettings {
@@ -40,6 +41,7 @@ impl ExecutorSettings {
             max_execute_time_in_seconds: Duration::from_secs(max_execute_time_in_seconds),
             max_threads,
             executor_node_id: ctx.get_cluster().local_id.clone(),
+            max_pipeline_memory_usage: settings.get_max_pipeline_memory_usage()? as usize,
         })
     }
 }
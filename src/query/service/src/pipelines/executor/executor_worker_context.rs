@@ -170,6 +170,7 @@ impl ExecutorWorkerContext {
         let nanos = instant.elapsed().as_nanos();
         assume(nanos < 18446744073709551615_u128);
         Profile::record_usize_profile(ProfileStatisticsName::CpuTime, nanos as usize);
+        Profile::record_usize_profile(ProfileStatisticsName::ProcessCount, 1);
         Ok(Some((proc.processor.id(), proc.graph)))
     }
 
@@ -16,6 +16,7 @@ mod query_pipeline_executor;
 
 mod executor_condvar;
 mod executor_graph;
+mod executor_pause;
 mod executor_settings;
 mod executor_worker_context;
 mod global_queries_executor;
@@ -32,6 +33,7 @@ pub use databend_common_base::base::WatchNotify;
 pub use executor_condvar::WorkersCondvar;
 pub use executor_condvar::WorkersWaitingStatus;
 pub use executor_graph::RunningGraph;
+pub use executor_pause::ExecutorPauseHandle;
 pub use executor_settings::ExecutorSettings;
 pub use executor_worker_context::CompletedAsyncTask;
 pub use executor_worker_context::ExecutorTask;
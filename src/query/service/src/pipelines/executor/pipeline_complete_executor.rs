@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use databend_common_base::runtime::drop_guard;
 use databend_common_base::runtime::MemStat;
+use databend_common_base::runtime::PipelineMemory;
 use databend_common_base::runtime::Thread;
 use databend_common_base::runtime::ThreadTracker;
 use databend_common_base::runtime::TrackingPayload;
@@ -35,12 +36,14 @@ pub struct PipelineCompleteExecutor {
 
 // Use this executor when the pipeline is complete pipeline (has source and sink)
 impl PipelineCompleteExecutor {
-    fn execution_tracking_payload(query_id: &str) -> TrackingPayload {
+    fn execution_tracking_payload(settings: &ExecutorSettings) -> TrackingPayload {
         let mut tracking_payload = ThreadTracker::new_tracking_payload();
         tracking_payload.mem_stat = Some(MemStat::create(format!(
             "QueryExecutionMemStat-{}",
-            query_id
+            settings.query_id
         )));
+        tracking_payload.pipeline_memory =
+            Some(PipelineMemory::create(settings.max_pipeline_memory_usage));
         tracking_payload
     }
 
@@ -48,7 +51,7 @@ impl PipelineCompleteExecutor {
         pipeline: Pipeline,
         settings: ExecutorSettings,
     ) -> Result<PipelineCompleteExecutor> {
-        let tracking_payload = Self::execution_tracking_payload(settings.query_id.as_ref());
+        let tracking_payload = Self::execution_tracking_payload(&settings);
         let _guard = ThreadTracker::tracking(tracking_payload.clone());
 
         if !pipeline.is_complete_pipeline()? {
@@ -68,7 +71,7 @@ impl PipelineCompleteExecutor {
         pipelines: Vec<Pipeline>,
         settings: ExecutorSettings,
     ) -> Result<Arc<PipelineCompleteExecutor>> {
-        let tracking_payload = Self::execution_tracking_payload(settings.query_id.as_ref());
+        let tracking_payload = Self::execution_tracking_payload(&settings);
         let _guard = ThreadTracker::tracking(tracking_payload.clone());
 
         for pipeline in &pipelines {
@@ -35,6 +35,7 @@ use log::info;
 use parking_lot::Condvar;
 use parking_lot::Mutex;
 
+use crate::pipelines::executor::ExecutorPauseHandle;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::GlobalQueriesExecutor;
 use crate::pipelines::executor::QueryPipelineExecutor;
@@ -286,6 +287,14 @@ impl PipelineExecutor {
         }
     }
 
+    /// The queries executor can't be paused yet.
+    pub fn get_pause_handle(&self) -> Option<Arc<ExecutorPauseHandle>> {
+        match self {
+            PipelineExecutor::QueryPipelineExecutor(executor) => Some(executor.get_pause_handle()),
+            PipelineExecutor::QueriesPipelineExecutor(_) => None,
+        }
+    }
+
     pub fn change_priority(&self, priority: u8) {
         match self {
             PipelineExecutor::QueryPipelineExecutor(_) => {
@@ -213,6 +213,7 @@ impl Future for ProcessorAsyncTask {
                 Poll::Pending
             }
             Ok(Poll::Ready(res)) => {
+                Profile::record_usize_profile(ProfileStatisticsName::AsyncProcessCount, 1);
                 self.queue.completed_async_task(
                     self.workers_condvar.clone(),
                     CompletedAsyncTask::create(
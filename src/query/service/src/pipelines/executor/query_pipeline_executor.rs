@@ -45,6 +45,7 @@ use parking_lot::Mutex;
 use petgraph::matrix_graph::Zero;
 
 use crate::pipelines::executor::executor_graph::ScheduleQueue;
+use crate::pipelines::executor::ExecutorPauseHandle;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::ExecutorWorkerContext;
 use crate::pipelines::executor::QueryExecutorTasksQueue;
@@ -65,6 +66,7 @@ pub struct QueryPipelineExecutor {
     settings: ExecutorSettings,
     finished_notify: Arc<WatchNotify>,
     finished_error: Mutex<Option<ErrorCode>>,
+    pause_handle: Arc<ExecutorPauseHandle>,
     #[allow(unused)]
     lock_guards: Vec<Arc<LockGuard>>,
 }
@@ -188,6 +190,7 @@ impl QueryPipelineExecutor {
             settings,
             finished_error: Mutex::new(None),
             finished_notify: Arc::new(WatchNotify::new()),
+            pause_handle: ExecutorPauseHandle::create(),
             lock_guards,
         }))
     }
@@ -219,6 +222,7 @@ impl QueryPipelineExecutor {
 
         drop(finished_error);
         self.global_tasks_queue.finish(self.workers_condvar.clone());
+        self.pause_handle.finish();
         self.graph.interrupt_running_nodes();
         self.finished_notify.notify_waiters();
     }
@@ -227,6 +231,10 @@ impl QueryPipelineExecutor {
         self.global_tasks_queue.is_finished()
     }
 
+    pub fn get_pause_handle(&self) -> Arc<ExecutorPauseHandle> {
+        self.pause_handle.clone()
+    }
+
     #[minitrace::trace]
     pub fn execute(self: &Arc<Self>) -> Result<()> {
         self.init(self.graph.clone())?;
@@ -415,6 +423,11 @@ impl QueryPipelineExecutor {
             }
 
             while !self.global_tasks_queue.is_finished() && context.has_task() {
+                self.pause_handle.wait_resumed();
+                if self.global_tasks_queue.is_finished() {
+                    break;
+                }
+
                 let task_info = context.get_task_info();
                 let execute_res = context.execute_task(None);
                 match execute_res {
@@ -436,6 +449,9 @@ impl QueryPipelineExecutor {
                                         executed_pid,
                                         NodeErrorType::ScheduleEventError(cause.clone()),
                                     );
+                                    // Save the error before interrupting the other processors,
+                                    // whose aborted errors must not be reported instead of it.
+                                    self.finish(Some(cause.clone()));
                                     graph.should_finish(Err(cause.clone()))?;
                                     return Err(cause);
                                 }
@@ -446,6 +462,7 @@ impl QueryPipelineExecutor {
                         let cause = error_type.get_error_code();
                         if let Some((graph, node_index)) = task_info {
                             graph.record_node_error(node_index, *error_type);
+                            self.finish(Some(cause.clone()));
                             graph.should_finish(Err(cause.clone()))?;
                         }
                         return Err(cause);
@@ -32,6 +32,7 @@ use databend_common_pipeline_core::processors::Event;
 use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
+use databend_common_storage::SpillFiles;
 use futures_util::future::BoxFuture;
 use log::info;
 use opendal::Operator;
@@ -265,6 +266,12 @@ pub fn agg_spilling_aggregate_payload<Method: HashMethodBounds>(
             }
 
             writer.close().await?;
+            SpillFiles::instance().record(
+                &ctx.get_id(),
+                "Aggregate",
+                &location,
+                write_bytes as u64,
+            );
         }
 
         // perf
@@ -362,6 +369,12 @@ pub fn spilling_aggregate_payload<Method: HashMethodBounds>(
             }
 
             writer.close().await?;
+            SpillFiles::instance().record(
+                &ctx.get_id(),
+                "Aggregate",
+                &location,
+                write_bytes as u64,
+            );
         }
 
         // perf
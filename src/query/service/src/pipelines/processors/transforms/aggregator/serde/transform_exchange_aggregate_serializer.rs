@@ -44,6 +44,7 @@ use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_transforms::processors::BlockMetaTransform;
 use databend_common_pipeline_transforms::processors::BlockMetaTransformer;
 use databend_common_settings::FlightCompression;
+use databend_common_storage::SpillFiles;
 use futures_util::future::BoxFuture;
 use log::info;
 use opendal::Operator;
@@ -308,6 +309,12 @@ fn agg_spilling_aggregate_payload<Method: HashMethodBounds>(
             }
 
             writer.close().await?;
+            SpillFiles::instance().record(
+                &ctx.get_id(),
+                "Aggregate",
+                &location,
+                write_bytes as u64,
+            );
 
             // perf
             {
@@ -429,6 +436,12 @@ fn spilling_aggregate_payload<Method: HashMethodBounds>(
             }
 
             writer.close().await?;
+            SpillFiles::instance().record(
+                &ctx.get_id(),
+                "Aggregate",
+                &location,
+                write_bytes as u64,
+            );
 
             // perf
             {
@@ -50,6 +50,7 @@ use databend_common_pipeline_transforms::processors::BlockMetaTransform;
 use databend_common_pipeline_transforms::processors::BlockMetaTransformer;
 use databend_common_pipeline_transforms::processors::UnknownMode;
 use databend_common_settings::FlightCompression;
+use databend_common_storage::SpillFiles;
 use futures_util::future::BoxFuture;
 use log::info;
 use opendal::Operator;
@@ -362,6 +363,7 @@ fn agg_spilling_group_by_payload<Method: HashMethodBounds>(
             }
 
             writer.close().await?;
+            SpillFiles::instance().record(&ctx.get_id(), "GroupBy", &location, write_bytes as u64);
 
             // perf
             {
@@ -481,6 +483,7 @@ fn spilling_group_by_payload<Method: HashMethodBounds>(
             }
 
             writer.close().await?;
+            SpillFiles::instance().record(&ctx.get_id(), "GroupBy", &location, write_bytes as u64);
 
             // perf
             {
@@ -32,6 +32,7 @@ use databend_common_pipeline_core::processors::Event;
 use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
+use databend_common_storage::SpillFiles;
 use futures_util::future::BoxFuture;
 use log::info;
 use opendal::Operator;
@@ -263,6 +264,7 @@ pub fn agg_spilling_group_by_payload<Method: HashMethodBounds>(
             }
 
             writer.close().await?;
+            SpillFiles::instance().record(&ctx.get_id(), "GroupBy", &location, write_bytes as u64);
         }
 
         // perf
@@ -356,6 +358,7 @@ pub fn spilling_group_by_payload<Method: HashMethodBounds>(
             }
 
             writer.close().await?;
+            SpillFiles::instance().record(&ctx.get_id(), "GroupBy", &location, write_bytes as u64);
         }
 
         // perf
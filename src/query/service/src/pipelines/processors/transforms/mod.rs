@@ -52,6 +52,7 @@ pub use transform_cast_schema::TransformCastSchema;
 pub use transform_create_sets::TransformCreateSets;
 pub use transform_expression_scan::TransformExpressionScan;
 pub use transform_filter::TransformFilter;
+pub use transform_limit::LimitCounter;
 pub use transform_limit::TransformLimit;
 pub use transform_materialized_cte::MaterializedCteSink;
 pub use transform_materialized_cte::MaterializedCteSource;
@@ -13,6 +13,9 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::ops::Range;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use databend_common_exception::Result;
@@ -23,34 +26,60 @@ use crate::pipelines::processors::InputPort;
 use crate::pipelines::processors::OutputPort;
 use crate::pipelines::processors::Processor;
 
-pub struct TransformLimit;
+/// The rows seen by all the pipes of a limit.
+///
+/// Every `TransformLimit` of the pipes reserves the rows of its blocks here, so
+/// the offset and the limit apply to the rows of the whole pipeline, and all the
+/// pipes stop pulling from upstream once the limit is reached by any of them.
+pub struct LimitCounter {
+    limit: Option<usize>,
+    offset: usize,
+    rows: AtomicUsize,
+}
 
-impl TransformLimit {
-    pub fn try_create(
-        limit: Option<usize>,
-        offset: usize,
-        input: Arc<InputPort>,
-        output: Arc<OutputPort>,
-    ) -> Result<Box<dyn Processor>> {
-        match (limit, offset) {
-            (Some(_), 0) => OnlyLimitTransform::create(input, output, limit, offset),
-            (None, _) => OnlyOffsetTransform::create(input, output, limit, offset),
-            (Some(_), _) => OffsetAndLimitTransform::create(input, output, limit, offset),
+impl LimitCounter {
+    pub fn create(limit: Option<usize>, offset: usize) -> Arc<LimitCounter> {
+        Arc::new(LimitCounter {
+            limit,
+            offset,
+            rows: AtomicUsize::new(0),
+        })
+    }
+
+    /// The rows after which no row is taken anymore.
+    fn end(&self) -> Option<usize> {
+        self.limit.map(|limit| self.offset.saturating_add(limit))
+    }
+
+    pub fn is_finished(&self) -> bool {
+        match (self.limit, self.end()) {
+            (Some(0), _) => true,
+            (_, None) => false,
+            (_, Some(end)) => self.rows.load(Ordering::Acquire) >= end,
         }
     }
-}
 
-const ONLY_LIMIT: usize = 0;
-const ONLY_OFFSET: usize = 1;
-const OFFSET_AND_LIMIT: usize = 2;
+    /// All the offset is skipped and there is no limit, the blocks can be passed through.
+    fn is_passthrough(&self) -> bool {
+        self.limit.is_none() && self.rows.load(Ordering::Acquire) >= self.offset
+    }
 
-type OnlyLimitTransform = TransformLimitImpl<ONLY_LIMIT>;
-type OnlyOffsetTransform = TransformLimitImpl<ONLY_OFFSET>;
-type OffsetAndLimitTransform = TransformLimitImpl<OFFSET_AND_LIMIT>;
+    /// Reserves the next `rows` rows, returns the range of them which is taken.
+    fn reserve(&self, rows: usize) -> Range<usize> {
+        let start = self.rows.fetch_add(rows, Ordering::AcqRel);
+        let end = start.saturating_add(rows);
+
+        let take_start = self.offset.clamp(start, end);
+        let take_end = match self.end() {
+            None => end,
+            Some(limit_end) => limit_end.clamp(take_start, end),
+        };
+        (take_start - start)..(take_end - start)
+    }
+}
 
-struct TransformLimitImpl<const MODE: usize> {
-    take_remaining: usize,
-    skip_remaining: usize,
+pub struct TransformLimit {
+    counter: Arc<LimitCounter>,
 
     input: Arc<InputPort>,
     output: Arc<OutputPort>,
@@ -59,70 +88,29 @@ struct TransformLimitImpl<const MODE: usize> {
     output_data_block: Option<DataBlock>,
 }
 
-impl<const MODE: usize> TransformLimitImpl<MODE> {
-    pub fn create(
+impl TransformLimit {
+    pub fn try_create(
+        counter: Arc<LimitCounter>,
         input: Arc<InputPort>,
         output: Arc<OutputPort>,
-        limit: Option<usize>,
-        offset: usize,
     ) -> Result<Box<dyn Processor>> {
-        Ok(Box::new(Self {
+        Ok(Box::new(TransformLimit {
+            counter,
             input,
             output,
             input_data_block: None,
             output_data_block: None,
-            skip_remaining: offset,
-            take_remaining: limit.unwrap_or(0),
         }))
     }
-
-    pub fn take_rows(&mut self, data_block: DataBlock) -> DataBlock {
-        let rows = data_block.num_rows();
-        debug_assert_ne!(self.take_remaining, 0);
-
-        if self.take_remaining >= rows {
-            self.take_remaining -= rows;
-            return data_block;
-        }
-
-        let remaining = self.take_remaining;
-        self.take_remaining = 0;
-        data_block.slice(0..remaining)
-    }
-
-    pub fn skip_rows(&mut self, data_block: DataBlock) -> Option<DataBlock> {
-        let rows = data_block.num_rows();
-
-        if self.skip_remaining >= rows {
-            self.skip_remaining -= rows;
-            return None;
-        }
-
-        match MODE {
-            OFFSET_AND_LIMIT => {
-                let offset = self.skip_remaining;
-                self.skip_remaining = 0;
-                let length = std::cmp::min(self.take_remaining, rows - offset);
-                self.take_remaining -= length;
-                Some(data_block.slice(offset..(offset + length)))
-            }
-            _ => {
-                let offset = self.skip_remaining;
-                self.skip_remaining = 0;
-                Some(data_block.slice(offset..rows))
-            }
-        }
-    }
 }
 
 #[async_trait::async_trait]
-impl<const MODE: usize> Processor for TransformLimitImpl<MODE> {
+impl Processor for TransformLimit {
     fn name(&self) -> String {
-        match MODE {
-            ONLY_LIMIT => "LimitTransform",
-            ONLY_OFFSET => "OffsetTransform",
-            OFFSET_AND_LIMIT => "OffsetAndLimitTransform",
-            _ => unreachable!(),
+        match (self.counter.limit, self.counter.offset) {
+            (Some(_), 0) => "LimitTransform",
+            (None, _) => "OffsetTransform",
+            (Some(_), _) => "OffsetAndLimitTransform",
         }
         .to_string()
     }
@@ -142,32 +130,22 @@ impl<const MODE: usize> Processor for TransformLimitImpl<MODE> {
             return Ok(Event::NeedConsume);
         }
 
+        // Close the input as soon as the limit is reached, so the upstream stops
+        // generating blocks, even if this pipe still has a block to push.
+        let finished = self.counter.is_finished();
+        if finished {
+            self.input.finish();
+            self.input_data_block = None;
+        }
+
         if let Some(data_block) = self.output_data_block.take() {
             self.output.push_data(Ok(data_block));
             return Ok(Event::NeedConsume);
         }
 
-        if self.skip_remaining == 0 && self.take_remaining == 0 {
-            if MODE == ONLY_LIMIT || MODE == OFFSET_AND_LIMIT {
-                self.input.finish();
-                self.output.finish();
-                return Ok(Event::Finished);
-            }
-
-            if MODE == ONLY_OFFSET {
-                if self.input.is_finished() {
-                    self.output.finish();
-                    return Ok(Event::Finished);
-                }
-
-                if !self.input.has_data() {
-                    self.input.set_need_data();
-                    return Ok(Event::NeedData);
-                }
-
-                self.output.push_data(self.input.pull_data().unwrap());
-                return Ok(Event::NeedConsume);
-            }
+        if finished {
+            self.output.finish();
+            return Ok(Event::Finished);
         }
 
         if self.input_data_block.is_some() {
@@ -184,19 +162,25 @@ impl<const MODE: usize> Processor for TransformLimitImpl<MODE> {
             return Ok(Event::NeedData);
         }
 
+        if self.counter.is_passthrough() {
+            self.output.push_data(self.input.pull_data().unwrap());
+            return Ok(Event::NeedConsume);
+        }
+
         self.input_data_block = Some(self.input.pull_data().unwrap()?);
         Ok(Event::Sync)
     }
 
     fn process(&mut self) -> Result<()> {
         if let Some(data_block) = self.input_data_block.take() {
-            self.output_data_block = match MODE {
-                ONLY_OFFSET => self.skip_rows(data_block),
-                ONLY_LIMIT => Some(self.take_rows(data_block)),
-                OFFSET_AND_LIMIT if self.skip_remaining != 0 => self.skip_rows(data_block),
-                OFFSET_AND_LIMIT => Some(self.take_rows(data_block)),
-                _ => unreachable!(),
-            }
+            let rows = data_block.num_rows();
+            let range = self.counter.reserve(rows);
+
+            self.output_data_block = match range.len() {
+                0 => None,
+                len if len == rows => Some(data_block),
+                _ => Some(data_block.slice(range)),
+            };
         }
 
         Ok(())
@@ -160,6 +160,8 @@ impl MaterializedCteSource {
 #[async_trait::async_trait]
 impl AsyncSource for MaterializedCteSource {
     const NAME: &'static str = "MaterializedCteSource";
+    // The blocks are read from memory, the scans of the CTE recorded them.
+    const RECORD_SCAN_PROGRESS: bool = false;
 
     #[async_trait::unboxed_simple]
     #[async_backtrace::framed]
@@ -15,7 +15,6 @@
 use std::sync::Arc;
 
 use databend_common_ast::ast::Engine;
-use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
@@ -30,8 +29,8 @@ use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::ProcessorPtr;
-use databend_common_pipeline_sources::SyncSource;
-use databend_common_pipeline_sources::SyncSourcer;
+use databend_common_pipeline_sources::AsyncSource;
+use databend_common_pipeline_sources::AsyncSourcer;
 use databend_common_sql::executor::physical_plans::UnionAll;
 use databend_common_sql::executor::PhysicalPlan;
 use databend_common_sql::plans::CreateTablePlan;
@@ -89,7 +88,7 @@ impl TransformRecursiveCteSource {
                 }
             })
             .collect::<Vec<_>>();
-        SyncSourcer::create(ctx.clone(), output_port, TransformRecursiveCteSource {
+        AsyncSourcer::create(ctx.clone(), output_port, TransformRecursiveCteSource {
             ctx,
             union_plan,
             left_outputs,
@@ -141,17 +140,23 @@ impl TransformRecursiveCteSource {
 }
 
 #[async_trait::async_trait]
-impl SyncSource for TransformRecursiveCteSource {
+impl AsyncSource for TransformRecursiveCteSource {
     const NAME: &'static str = "TransformRecursiveCteSource";
+    // The blocks are the results of the inner pipelines, their scans recorded them.
+    const RECORD_SCAN_PROGRESS: bool = false;
 
-    fn generate(&mut self) -> Result<Option<DataBlock>> {
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
         let mut res = None;
         let mut data = DataBlock::empty();
-        match GlobalIORuntime::instance().block_on(Self::execute_r_cte(
+        match Self::execute_r_cte(
             self.ctx.clone(),
             self.recursive_step,
             self.union_plan.clone(),
-        )) {
+        )
+        .await
+        {
             Ok(res) => {
                 if !res.0.is_empty() {
                     data = DataBlock::concat(&res.0)?;
@@ -191,7 +196,7 @@ impl SyncSource for TransformRecursiveCteSource {
             let ctx = self.ctx.clone();
             let table_names = self.union_plan.cte_scan_names.clone();
             // Recursive end, remove all tables
-            let _ = GlobalIORuntime::instance().block_on(drop_tables(ctx, table_names));
+            let _ = drop_tables(ctx, table_names).await;
         }
         Ok(res)
     }
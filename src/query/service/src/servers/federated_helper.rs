@@ -14,43 +14,395 @@
 
 // The servers module used for external communication with user, such as MySQL wired protocol, etc.
 
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use databend_common_config::GlobalConfig;
+use databend_common_config::InnerConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
 use databend_common_expression::TableSchemaRef;
 use databend_common_expression::TableSchemaRefExt;
+use databend_common_settings::Settings;
 use regex::Regex;
+use regex::RegexBuilder;
+
+use crate::servers::http::ClickHouseFederated;
+use crate::servers::http::CLICKHOUSE_VERSION;
+use crate::servers::mysql::MySQLFederated;
 
 pub type LazyBlockFunc = fn(&str) -> Option<(TableSchemaRef, DataBlock)>;
 
+/// The named capture groups matched by a rule, group name -> matched text.
+pub type FederatedCaptures<'a> = HashMap<&'a str, &'a str>;
+
+pub type CapturesBlockFunc = fn(&str, &FederatedCaptures) -> Option<(TableSchemaRef, DataBlock)>;
+
+#[derive(Clone, Copy)]
+pub enum LazyBlockRule {
+    /// The handler only receives the query.
+    Query(LazyBlockFunc),
+    /// The handler also receives the named capture groups of the rule.
+    Captures(CapturesBlockFunc),
+}
+
+impl From<LazyBlockFunc> for LazyBlockRule {
+    fn from(func: LazyBlockFunc) -> Self {
+        LazyBlockRule::Query(func)
+    }
+}
+
+/// A built-in rule, the stable name of it is the label of the metrics.
+pub type FederatedRule = (&'static str, Regex, Option<(TableSchemaRef, DataBlock)>);
+
+/// The built-in rules of a protocol handler, each set is built once and matched in order.
+pub struct FederatedRules {
+    name: &'static str,
+    rules: Vec<FederatedRule>,
+    index: FederatedRuleIndex,
+}
+
+impl FederatedRules {
+    /// The rules of the MySQL handler, for the probes of the MySQL drivers and tools.
+    pub fn mysql() -> Result<&'static FederatedRules> {
+        static RULES: LazyLock<Result<FederatedRules>> =
+            LazyLock::new(|| FederatedRules::create("mysql", MySQLFederated::builtin_rules()));
+        RULES.as_ref().map_err(Clone::clone)
+    }
+
+    /// The rules of the ClickHouse handler, for the probes of the ClickHouse clients.
+    pub fn clickhouse() -> Result<&'static FederatedRules> {
+        static RULES: LazyLock<Result<FederatedRules>> = LazyLock::new(|| {
+            FederatedRules::create("clickhouse", ClickHouseFederated::builtin_rules())
+        });
+        RULES.as_ref().map_err(Clone::clone)
+    }
+
+    /// The built-in patterns must be anchored at `^`, so a long query is never scanned by them.
+    fn create(name: &'static str, rules: Result<Vec<FederatedRule>>) -> Result<FederatedRules> {
+        let rules = rules?;
+        for (rule, regex, _) in &rules {
+            if !FederatedRuleIndex::is_anchored(regex.as_str()) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "The federated rule '{}' of {} is not anchored at '^'",
+                    rule, name
+                )));
+            }
+        }
+        let index = FederatedRuleIndex::create(&rules);
+        Ok(FederatedRules { name, rules, index })
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The rules in the order they are matched.
+    pub fn rules(&self) -> &[FederatedRule] {
+        &self.rules
+    }
+
+    /// Returns the name of the matched rule with its block.
+    pub fn check(&self, query: &str) -> Option<(&str, (TableSchemaRef, DataBlock))> {
+        self.index.block_match_rule(query, &self.rules)
+    }
+}
+
+/// The rules of a set bucketed by the first keyword of their patterns, e.g. `SHOW` of
+/// `^(SHOW MASTER STATUS)`. A query only runs the rules of its first keyword and the rules
+/// without one, in the order of the set, so the first matched rule is the same as a full scan.
+pub(crate) struct FederatedRuleIndex {
+    buckets: HashMap<String, Vec<usize>>,
+    // The rules without a keyword, e.g. `^(/\*!\d{5} SET(.*)\*/)$`, they are in every bucket.
+    unkeyed: Vec<usize>,
+    // All the rules, for the queries the keyword can't be taken from.
+    all: Vec<usize>,
+}
+
+impl FederatedRuleIndex {
+    pub(crate) fn create<N>(rules: &[(N, Regex, Option<(TableSchemaRef, DataBlock)>)]) -> Self {
+        let keywords = rules
+            .iter()
+            .map(|(_, regex, _)| Self::pattern_keyword(regex.as_str()))
+            .collect::<Vec<_>>();
+
+        let mut buckets: HashMap<String, Vec<usize>> = HashMap::new();
+        for keyword in keywords.iter().flatten() {
+            buckets.entry(keyword.clone()).or_insert_with(|| {
+                keywords
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, k)| k.is_none() || k.as_ref() == Some(keyword))
+                    .map(|(i, _)| i)
+                    .collect()
+            });
+        }
+        FederatedRuleIndex {
+            buckets,
+            unkeyed: (0..rules.len())
+                .filter(|i| keywords[*i].is_none())
+                .collect(),
+            all: (0..rules.len()).collect(),
+        }
+    }
+
+    /// Match the rules of the first keyword of the query in order,
+    /// returns the name of the matched rule with its block.
+    pub(crate) fn block_match_rule<'a, N: AsRef<str>>(
+        &self,
+        query: &str,
+        rules: &'a [(N, Regex, Option<(TableSchemaRef, DataBlock)>)],
+    ) -> Option<(&'a str, (TableSchemaRef, DataBlock))> {
+        let query = FederatedHelper::strip_leading_comments(query);
+        let candidates = match Self::query_keyword(query) {
+            None => &self.all,
+            Some(keyword) => self.buckets.get(&keyword).unwrap_or(&self.unkeyed),
+        };
+        for i in candidates {
+            let (name, regex, data) = &rules[*i];
+            if regex.is_match(query) {
+                return Some((name.as_ref(), FederatedHelper::rule_block(data)));
+            }
+        }
+        None
+    }
+
+    fn is_anchored(pattern: &str) -> bool {
+        Self::skip_flags(pattern).is_some_and(|(rest, _)| rest.starts_with('^'))
+    }
+
+    // Skip the leading inline flags, e.g. `(?s)`, returns the rest and whether a flag
+    // changes the meaning of `^` or the spaces.
+    fn skip_flags(mut pattern: &str) -> Option<(&str, bool)> {
+        let mut unsafe_flags = false;
+        while let Some(rest) = pattern.strip_prefix("(?") {
+            let end = rest.find(|c: char| !c.is_ascii_alphabetic() && c != '-')?;
+            if !rest[end..].starts_with(')') {
+                break;
+            }
+            unsafe_flags |= rest[..end].contains(['m', 'x']);
+            pattern = &rest[end + 1..];
+        }
+        Some((pattern, unsafe_flags))
+    }
+
+    // The keyword a pattern only matches after, e.g. `SET` of `^SET\s+send_logs_level\s*=`.
+    // It is taken only if the keyword is followed by a space, so a matched query starts with
+    // exactly the keyword, and there's no alternation which could match something else.
+    fn pattern_keyword(pattern: &str) -> Option<String> {
+        let (rest, unsafe_flags) = Self::skip_flags(pattern)?;
+        let mut rest = rest.strip_prefix('^').filter(|_| !unsafe_flags)?;
+
+        // The groups before the keyword, e.g. `^(SHOW ...)`.
+        let mut depth = 0;
+        loop {
+            if let Some(group) = rest.strip_prefix("(?:") {
+                rest = group;
+            } else if let Some(group) = rest.strip_prefix('(').filter(|g| !g.starts_with('?')) {
+                rest = group;
+            } else {
+                break;
+            }
+            depth += 1;
+        }
+
+        let end = rest
+            .find(|c: char| !c.is_ascii_alphabetic() && c != '_')
+            .unwrap_or(rest.len());
+        let (keyword, after) = rest.split_at(end);
+        let space = after
+            .strip_prefix(' ')
+            .or_else(|| after.strip_prefix("\\s"))?;
+        if keyword.is_empty() || space.starts_with(['*', '?', '{']) {
+            return None;
+        }
+        if Self::has_alternation(after, depth)? {
+            return None;
+        }
+        Some(keyword.to_ascii_uppercase())
+    }
+
+    // Whether there is a `|` in the groups of the keyword or after them,
+    // `None` if the pattern can't be followed.
+    fn has_alternation(pattern: &str, depth: usize) -> Option<bool> {
+        let mut current = depth;
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => {
+                    chars.next()?;
+                }
+                '[' => {
+                    // A `]` first in the class is a literal, e.g. `[]a]` and `[^]a]`.
+                    let mut first = true;
+                    if chars.clone().next() == Some('^') {
+                        chars.next();
+                    }
+                    loop {
+                        match chars.next()? {
+                            '\\' => {
+                                chars.next()?;
+                            }
+                            ']' if !first => break,
+                            _ => {}
+                        }
+                        first = false;
+                    }
+                }
+                '(' => current += 1,
+                ')' => current = current.checked_sub(1)?,
+                '|' if current <= depth => return Some(true),
+                _ => {}
+            }
+        }
+        Some(false)
+    }
+
+    // The first keyword of the query, `None` if it has the letters out of ASCII,
+    // the case-insensitive patterns may match them.
+    fn query_keyword(query: &str) -> Option<String> {
+        let end = query
+            .find(|c: char| !c.is_alphabetic() && c != '_')
+            .unwrap_or(query.len());
+        let keyword = &query[..end];
+        match keyword.is_ascii() {
+            true => Some(keyword.to_ascii_uppercase()),
+            false => None,
+        }
+    }
+}
+
 pub struct FederatedHelper {}
 
 impl FederatedHelper {
-    pub(crate) fn block_match_rule(
+    /// The server version of the MySQL handler, the handshake, `@@version` and `version()` agree.
+    pub fn mysql_server_version() -> String {
+        match GlobalConfig::try_get_instance() {
+            Some(config) => config.query.mysql_server_version(),
+            None => InnerConfig::default().query.mysql_server_version(),
+        }
+    }
+
+    /// The server version of the ClickHouse handler, some clients parse it for the features.
+    pub fn clickhouse_server_version() -> String {
+        CLICKHOUSE_VERSION.to_string()
+    }
+
+    /// The time zone of the session, `@@time_zone` of MySQL and `timezone()` of ClickHouse.
+    pub fn session_timezone(settings: Option<&Settings>) -> Result<String> {
+        match settings {
+            Some(settings) => settings.get_timezone(),
+            None => Ok("UTC".to_string()),
+        }
+    }
+
+    /// The federated rules of a query longer than it are first matched with the prefix of this
+    /// size, so the long queries which are not probes are not scanned by the rules in full.
+    /// `0` means no limit.
+    pub fn max_query_bytes() -> usize {
+        let bytes = match GlobalConfig::try_get_instance() {
+            Some(config) => config.query.federated_max_query_bytes,
+            None => InnerConfig::default().query.federated_max_query_bytes,
+        };
+        match bytes {
+            0 => usize::MAX,
+            bytes => bytes as usize,
+        }
+    }
+
+    /// The prefix of the query in `max_query_bytes`, cut at a char boundary.
+    pub fn query_prefix(query: &str) -> &str {
+        let mut end = FederatedHelper::max_query_bytes();
+        if query.len() <= end {
+            return query;
+        }
+        while !query.is_char_boundary(end) {
+            end -= 1;
+        }
+        &query[..end]
+    }
+
+    /// Build a case-insensitive rule regex, a rule can opt out with an inline `(?-i)`.
+    pub fn regex(pattern: &str) -> Result<Regex> {
+        RegexBuilder::new(pattern)
+            .case_insensitive(true)
+            .build()
+            .map_err(|e| {
+                ErrorCode::BadArguments(format!(
+                    "Invalid federated rule pattern '{}': {}",
+                    pattern, e
+                ))
+            })
+    }
+
+    /// Strip the leading whitespace and `/* ... */` comments of the query.
+    /// MySQL hint comments like `/*!40101 ... */` are executable and are kept.
+    pub fn strip_leading_comments(query: &str) -> &str {
+        let mut query = query.trim_start();
+        while query.starts_with("/*") && !query.starts_with("/*!") {
+            match query[2..].find("*/") {
+                Some(end) => query = query[end + 4..].trim_start(),
+                None => break,
+            }
+        }
+        query
+    }
+
+    /// Match the rule, returns the named capture groups of it.
+    pub fn captures<'a>(regex: &'a Regex, query: &'a str) -> Option<FederatedCaptures<'a>> {
+        let caps = regex.captures(query)?;
+        Some(
+            regex
+                .capture_names()
+                .flatten()
+                .filter_map(|name| caps.name(name).map(|m| (name, m.as_str())))
+                .collect(),
+        )
+    }
+
+    /// Match the named rules in order, returns the name of the matched rule with its block.
+    /// It's the full scan the rule sets are bucketed from.
+    pub fn block_match_rule<'a, N: AsRef<str>>(
         query: &str,
-        rules: &[(Regex, Option<(TableSchemaRef, DataBlock)>)],
-    ) -> Option<(TableSchemaRef, DataBlock)> {
-        for (regex, data) in rules.iter() {
+        rules: &'a [(N, Regex, Option<(TableSchemaRef, DataBlock)>)],
+    ) -> Option<(&'a str, (TableSchemaRef, DataBlock))> {
+        let query = Self::strip_leading_comments(query);
+        for (name, regex, data) in rules.iter() {
             if regex.is_match(query) {
-                return match data {
-                    None => Some((TableSchemaRefExt::create(vec![]), DataBlock::empty())),
-                    Some((schema, data_block)) => Some((schema.clone(), data_block.clone())),
-                };
+                return Some((name.as_ref(), Self::rule_block(data)));
             }
         }
 
         None
     }
 
+    fn rule_block(data: &Option<(TableSchemaRef, DataBlock)>) -> (TableSchemaRef, DataBlock) {
+        match data {
+            None => (TableSchemaRefExt::create(vec![]), DataBlock::empty()),
+            Some((schema, data_block)) => (schema.clone(), data_block.clone()),
+        }
+    }
+
     pub fn lazy_block_match_rule(
         query: &str,
-        rules: &[(Regex, LazyBlockFunc)],
+        rules: &[(Regex, LazyBlockRule)],
     ) -> Option<(TableSchemaRef, DataBlock)> {
-        for (regex, func) in rules.iter() {
-            if regex.is_match(query) {
-                return match func(query) {
-                    None => Some((TableSchemaRefExt::create(vec![]), DataBlock::empty())),
-                    Some((schema, data_block)) => Some((schema, data_block)),
-                };
-            }
+        let query = Self::strip_leading_comments(query);
+        for (regex, rule) in rules.iter() {
+            let data = match rule {
+                LazyBlockRule::Query(func) if regex.is_match(query) => func(query),
+                LazyBlockRule::Captures(func) => match Self::captures(regex, query) {
+                    Some(captures) => func(query, &captures),
+                    None => continue,
+                },
+                _ => continue,
+            };
+
+            return match data {
+                None => Some((TableSchemaRefExt::create(vec![]), DataBlock::empty())),
+                Some((schema, data_block)) => Some((schema, data_block)),
+            };
         }
         None
     }
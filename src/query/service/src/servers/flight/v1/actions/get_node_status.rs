@@ -0,0 +1,32 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_catalog::cluster_info::NodeStatus;
+use databend_common_config::DATABEND_COMMIT_VERSION;
+use databend_common_exception::Result;
+
+use crate::clusters::ClusterDiscovery;
+
+pub static GET_NODE_STATUS: &str = "/actions/get_node_status";
+
+pub async fn get_node_status(_: ()) -> Result<NodeStatus> {
+    Ok(local_node_status())
+}
+
+pub fn local_node_status() -> NodeStatus {
+    NodeStatus {
+        version: DATABEND_COMMIT_VERSION.to_string(),
+        uptime_seconds: ClusterDiscovery::instance().uptime().as_secs(),
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod flight_actions;
+mod get_node_status;
 mod get_profile;
 mod init_query_env;
 mod init_query_fragments;
@@ -29,6 +30,8 @@ use databend_common_exception::Result;
 use databend_common_settings::Settings;
 pub use flight_actions::flight_actions;
 pub use flight_actions::FlightActions;
+pub use get_node_status::local_node_status;
+pub use get_node_status::GET_NODE_STATUS;
 pub use get_profile::GET_PROFILE;
 pub use init_query_env::INIT_QUERY_ENV;
 pub use init_query_fragments::init_query_fragments;
@@ -23,17 +23,27 @@ use databend_common_config::InnerConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use log::info;
+use tonic::codec::CompressionEncoding;
+use tonic::server::NamedService;
 use tonic::transport::server::TcpIncoming;
 use tonic::transport::Identity;
 use tonic::transport::Server;
 use tonic::transport::ServerTlsConfig;
+use tonic_health::server::health_reporter;
+use tonic_health::server::HealthReporter;
+use tonic_health::ServingStatus;
 
 use crate::servers::flight_sql::flight_sql_service::FlightSqlServiceImpl;
+use crate::servers::flight_sql::flight_sql_service::TicketSizeLimit;
 use crate::servers::Server as DatabendQueryServer;
 
+// Room for the protobuf envelope around a SQL text or a ticket.
+const MESSAGE_ENVELOPE_SIZE: usize = 1024;
+
 pub struct FlightSQLServer {
     pub config: InnerConfig,
     pub abort_notify: Arc<Notify>,
+    pub health_reporter: Option<HealthReporter>,
 }
 
 impl FlightSQLServer {
@@ -41,6 +51,7 @@ impl FlightSQLServer {
         Ok(Box::new(Self {
             config,
             abort_notify: Arc::new(Notify::new()),
+            health_reporter: None,
         }))
     }
 
@@ -61,9 +72,59 @@ impl FlightSQLServer {
         Ok(tls_conf)
     }
 
+    fn compression_encoding(conf: &InnerConfig) -> Result<Option<CompressionEncoding>> {
+        match conf
+            .query
+            .flight_sql_grpc_compression
+            .to_lowercase()
+            .as_str()
+        {
+            "none" | "" => Ok(None),
+            "gzip" => Ok(Some(CompressionEncoding::Gzip)),
+            "zstd" => Ok(Some(CompressionEncoding::Zstd)),
+            other => Err(ErrorCode::InvalidConfig(format!(
+                "invalid flight_sql_grpc_compression: {other}, expect one of none|gzip|zstd"
+            ))),
+        }
+    }
+
+    /// Build the FlightSQL tonic service with the configured message compression.
+    ///
+    /// The compression is only applied when the client negotiates it through `grpc-encoding`
+    /// and `grpc-accept-encoding`, other clients still get plain messages.
+    pub fn flight_service(conf: &InnerConfig) -> Result<FlightServiceServer<TicketSizeLimit>> {
+        let compression = Self::compression_encoding(conf)?;
+        // The limits are checked against the frames on the wire, keep them explicit so that
+        // they stay the same whatever compression is negotiated. A message that can hold
+        // neither an acceptable query nor an acceptable ticket is rejected before decoding.
+        let max_decoding_message_size =
+            conf.query
+                .flight_sql_max_query_size
+                .max(conf.query.flight_sql_max_ticket_size) as usize
+                + MESSAGE_ENVELOPE_SIZE;
+        let service = FlightServiceServer::new(TicketSizeLimit::create(
+            FlightSqlServiceImpl::create()?,
+            conf.query.flight_sql_max_ticket_size as usize,
+        ))
+        .max_decoding_message_size(max_decoding_message_size)
+        .max_encoding_message_size(usize::MAX);
+        Ok(match compression {
+            None => service,
+            Some(encoding) => {
+                info!("databend query flight sql grpc compression {encoding:?} enabled");
+                service
+                    .accept_compressed(encoding)
+                    .send_compressed(encoding)
+            }
+        })
+    }
+
     #[async_backtrace::framed]
     pub async fn start_with_incoming(&mut self, addr: SocketAddr) -> Result<()> {
-        let flight_sql_service = FlightSqlServiceImpl::create();
+        let incoming = TcpIncoming::new(addr, true, None)
+            .map_err(|e| ErrorCode::CannotListenerPort(format!("{e}")))?;
+
+        let flight_sql_service = Self::flight_service(&self.config)?;
         let builder = Server::builder();
         let mut builder = if self.config.flight_sql_tls_server_enabled() {
             info!("databend query tls flight sql enabled");
@@ -80,22 +141,49 @@ impl FlightSQLServer {
             builder
         };
 
-        let incoming = TcpIncoming::new(addr, true, None)
-            .map_err(|e| ErrorCode::CannotListenerPort(format!("{e}")))?;
+        // grpc.health.v1.Health reports NOT_SERVING until the service is spawned.
+        let (mut health_reporter, health_service) = health_reporter();
+        Self::set_health_status(&mut health_reporter, ServingStatus::NotServing).await;
 
         let server = builder
-            .add_service(FlightServiceServer::new(flight_sql_service))
+            .add_service(health_service)
+            .add_service(flight_sql_service)
             .serve_with_incoming_shutdown(incoming, self.shutdown_notify());
 
         databend_common_base::runtime::spawn(server);
+
+        Self::set_health_status(&mut health_reporter, ServingStatus::Serving).await;
+        self.health_reporter = Some(health_reporter);
         Ok(())
     }
+
+    async fn set_health_status(health_reporter: &mut HealthReporter, status: ServingStatus) {
+        // The empty service name stands for the health of the whole server.
+        health_reporter.set_service_status("", status).await;
+        health_reporter
+            .set_service_status(
+                <FlightServiceServer<TicketSizeLimit> as NamedService>::NAME,
+                status,
+            )
+            .await;
+    }
 }
 
 #[async_trait::async_trait]
 impl DatabendQueryServer for FlightSQLServer {
+    /// The graceful shutdown only starts draining: the health check flips to NOT_SERVING so
+    /// that load balancers stop sending new traffic, while the listener keeps serving the
+    /// sessions in flight. The listener is closed by the following non-graceful shutdown.
     #[async_backtrace::framed]
-    async fn shutdown(&mut self, _graceful: bool) {}
+    async fn shutdown(&mut self, graceful: bool) {
+        if let Some(health_reporter) = self.health_reporter.as_mut() {
+            Self::set_health_status(health_reporter, ServingStatus::NotServing).await;
+        }
+
+        if !graceful {
+            self.abort_notify.notify_one();
+        }
+    }
 
     #[async_backtrace::framed]
     async fn start(&mut self, addr: SocketAddr) -> Result<SocketAddr> {
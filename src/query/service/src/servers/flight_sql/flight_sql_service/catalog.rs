@@ -12,27 +12,55 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
+use std::future::Future;
 use std::sync::Arc;
+use std::time::Duration;
 
+use arrow_array::builder::BinaryBuilder;
 use arrow_array::builder::StringBuilder;
 use arrow_array::ArrayRef;
 use arrow_array::RecordBatch;
 use arrow_flight::utils::batches_to_flight_data;
+use arrow_flight::IpcMessage;
+use arrow_flight::SchemaAsIpc;
+use arrow_ipc::writer::IpcWriteOptions;
 use arrow_schema::DataType;
 use arrow_schema::Field;
 use arrow_schema::Schema;
+use databend_common_base::base::tokio;
 use databend_common_catalog::catalog::Catalog;
 use databend_common_catalog::catalog::CatalogManager;
+use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
+use databend_common_expression::filter::like_pattern_to_matcher;
+use databend_common_functions::BUILTIN_FUNCTIONS;
 use futures_util::stream;
 use log::warn;
 use tonic::Status;
 
 use crate::servers::flight_sql::flight_sql_service::DoGetStream;
 
+/// Column comment, the key defined by FlightSQL for the column remarks.
+const REMARKS_KEY: &str = "ARROW:FLIGHT:SQL:REMARKS";
+/// Default expression of the column.
+const DEFAULT_EXPR_KEY: &str = "DATABEND:DEFAULT_EXPR";
+/// "true" if the column is referenced by the cluster key of the table.
+const CLUSTER_KEY_KEY: &str = "DATABEND:CLUSTER_KEY";
+
 pub(super) struct CatalogInfoProvider {}
 
+#[derive(Default)]
+struct TableRows {
+    catalog_names: Vec<String>,
+    database_names: Vec<String>,
+    table_names: Vec<String>,
+    table_types: Vec<String>,
+    // Only filled if the schema is asked for.
+    table_schemas: Vec<Vec<u8>>,
+}
+
 impl CatalogInfoProvider {
     fn batch_to_get_stream(batch: RecordBatch) -> Result<DoGetStream, Status> {
         let schema = (*batch.schema()).clone();
@@ -45,43 +73,98 @@ impl CatalogInfoProvider {
         Ok(Box::pin(stream))
     }
 
-    async fn get_tables_internal(
-        ctx: Arc<dyn TableContext>,
+    /// Runs a listing of the catalogs, an external catalog may be slow to answer.
+    async fn with_timeout<T>(
+        timeout: Duration,
+        fut: impl Future<Output = databend_common_exception::Result<T>>,
+    ) -> Result<T, Status> {
+        match tokio::time::timeout(timeout, fut).await {
+            Ok(Ok(res)) => Ok(res),
+            Ok(Err(e)) if e.code() == ErrorCode::UNKNOWN_CATALOG => {
+                Err(Status::not_found(e.message()))
+            }
+            Ok(Err(e)) => Err(Status::internal(format!("{e:?}"))),
+            Err(_) => Err(Status::deadline_exceeded(format!(
+                "listing catalogs takes more than {} seconds",
+                timeout.as_secs()
+            ))),
+        }
+    }
+
+    /// The catalogs to look at, all of them if the command names none.
+    async fn resolve_catalogs(
+        ctx: &Arc<dyn TableContext>,
         catalog_name: Option<String>,
-        database_name: Option<String>,
-    ) -> databend_common_exception::Result<(Vec<String>, Vec<String>, Vec<String>, Vec<String>)>
-    {
+    ) -> databend_common_exception::Result<Vec<(String, Arc<dyn Catalog>)>> {
         let tenant = ctx.get_tenant();
         let catalog_mgr = CatalogManager::instance();
-        let catalogs: Vec<(String, Arc<dyn Catalog>)> = if let Some(catalog_name) = catalog_name {
-            vec![(
+        match catalog_name.filter(|name| !name.is_empty()) {
+            Some(catalog_name) => Ok(vec![(
                 catalog_name.clone(),
                 catalog_mgr
                     .get_catalog(tenant.tenant_name(), &catalog_name, ctx.txn_mgr())
                     .await?,
-            )]
-        } else {
-            catalog_mgr
+            )]),
+            None => Ok(catalog_mgr
                 .list_catalogs(&tenant, ctx.txn_mgr())
                 .await?
                 .iter()
                 .map(|r| (r.name(), r.clone()))
-                .collect()
-        };
+                .collect()),
+        }
+    }
 
+    async fn get_schemas_internal(
+        ctx: Arc<dyn TableContext>,
+        catalog_name: Option<String>,
+        db_schema_filter_pattern: Option<String>,
+    ) -> databend_common_exception::Result<(Vec<String>, Vec<String>)> {
+        let tenant = ctx.get_tenant();
+        let db_matcher = db_schema_filter_pattern.map(|p| like_pattern_to_matcher(&p));
+        let visibility_checker = ctx.get_visibility_checker().await?;
         let mut catalog_names = vec![];
         let mut database_names = vec![];
-        let mut table_names = vec![];
-        let mut table_types = vec![];
+        for (catalog_name, catalog) in Self::resolve_catalogs(&ctx, catalog_name).await? {
+            for db in catalog.list_databases(&tenant).await? {
+                if db_matcher.as_ref().is_some_and(|m| !m.matches(db.name())) {
+                    continue;
+                }
+                let db_id = db.get_db_info().ident.db_id;
+                if !visibility_checker.check_database_visibility(&catalog_name, db.name(), db_id) {
+                    continue;
+                }
+                catalog_names.push(catalog_name.clone());
+                database_names.push(db.name().to_string());
+            }
+        }
+        Ok((catalog_names, database_names))
+    }
+
+    async fn get_tables_internal(
+        ctx: Arc<dyn TableContext>,
+        catalog_name: Option<String>,
+        db_schema_filter_pattern: Option<String>,
+        table_name_filter_pattern: Option<String>,
+        include_schema: bool,
+    ) -> databend_common_exception::Result<TableRows> {
+        let tenant = ctx.get_tenant();
+        let catalogs = Self::resolve_catalogs(&ctx, catalog_name).await?;
+        let db_matcher = db_schema_filter_pattern.map(|p| like_pattern_to_matcher(&p));
+        let table_matcher = table_name_filter_pattern.map(|p| like_pattern_to_matcher(&p));
+        let visibility_checker = ctx.get_visibility_checker().await?;
+
+        let mut rows = TableRows::default();
         let table_type = "table".to_string();
         for (catalog_name, catalog) in catalogs.into_iter() {
-            let dbs = if let Some(database_name) = &database_name {
-                vec![catalog.get_database(&tenant, database_name).await?]
-            } else {
-                catalog.list_databases(&tenant).await?
-            };
-            for db in dbs {
+            for db in catalog.list_databases(&tenant).await? {
                 let db_name = db.name();
+                if db_matcher.as_ref().is_some_and(|m| !m.matches(db_name)) {
+                    continue;
+                }
+                let db_id = db.get_db_info().ident.db_id;
+                if !visibility_checker.check_database_visibility(&catalog_name, db_name, db_id) {
+                    continue;
+                }
                 let tables = match catalog.list_tables(&tenant, db_name).await {
                     Ok(tables) => tables,
                     Err(err) if err.code() == ErrorCode::EMPTY_SHARE_ENDPOINT_CONFIG => {
@@ -91,41 +174,146 @@ impl CatalogInfoProvider {
                     Err(err) => return Err(err),
                 };
                 for table in tables {
-                    catalog_names.push(catalog_name.clone());
-                    database_names.push(db_name.to_string());
-                    table_names.push(table.name().to_string());
-                    table_types.push(table_type.clone());
+                    if table_matcher
+                        .as_ref()
+                        .is_some_and(|m| !m.matches(table.name()))
+                    {
+                        continue;
+                    }
+                    // A visible database may hold tables the user has no privilege on.
+                    if !visibility_checker.check_table_visibility(
+                        &catalog_name,
+                        db_name,
+                        table.name(),
+                        db_id,
+                        table.get_id(),
+                    ) {
+                        continue;
+                    }
+                    rows.catalog_names.push(catalog_name.clone());
+                    rows.database_names.push(db_name.to_string());
+                    rows.table_names.push(table.name().to_string());
+                    rows.table_types.push(table_type.clone());
+                    if include_schema {
+                        rows.table_schemas
+                            .push(Self::table_schema_to_ipc(ctx.clone(), table.as_ref())?);
+                    }
                 }
             }
         }
-        Ok((catalog_names, database_names, table_names, table_types))
+        Ok(rows)
     }
 
-    pub(crate) async fn get_tables(
+    /// The arrow schema of a table as an IPC message, the column comment, default expression
+    /// and cluster key membership go to the field metadata, only if the column has them.
+    fn table_schema_to_ipc(
+        ctx: Arc<dyn TableContext>,
+        table: &dyn Table,
+    ) -> databend_common_exception::Result<Vec<u8>> {
+        let cluster_key_columns = table
+            .cluster_keys(ctx)
+            .iter()
+            .flat_map(|key| key.as_expr(&BUILTIN_FUNCTIONS).column_refs().into_keys())
+            .collect::<HashSet<_>>();
+        let comments = table.field_comments();
+
+        let table_schema = table.schema();
+        let fields = table_schema
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                let arrow_field = Field::from(field);
+                let mut metadata = arrow_field.metadata().clone();
+                if let Some(comment) = comments.get(i).filter(|c| !c.is_empty()) {
+                    metadata.insert(REMARKS_KEY.to_string(), comment.clone());
+                }
+                if let Some(default_expr) = field.default_expr() {
+                    metadata.insert(DEFAULT_EXPR_KEY.to_string(), default_expr.clone());
+                }
+                if cluster_key_columns.contains(field.name()) {
+                    metadata.insert(CLUSTER_KEY_KEY.to_string(), "true".to_string());
+                }
+                arrow_field.with_metadata(metadata)
+            })
+            .collect::<Vec<_>>();
+
+        let schema = Schema::new(fields);
+        let IpcMessage(bytes) = SchemaAsIpc::new(&schema, &IpcWriteOptions::default())
+            .try_into()
+            .map_err(|e| ErrorCode::Internal(format!("Unable to serialize schema: {e:?}")))?;
+        Ok(bytes.to_vec())
+    }
+
+    /// The filter patterns are LIKE patterns the same as `SHOW DATABASES LIKE`.
+    pub(crate) async fn get_schemas(
         ctx: Arc<dyn TableContext>,
         catalog_name: Option<String>,
-        database_name: Option<String>,
+        db_schema_filter_pattern: Option<String>,
+        timeout: Duration,
     ) -> Result<DoGetStream, Status> {
         let schema = Arc::new(Schema::new(vec![
             Field::new("catalog_name", DataType::Utf8, false),
             Field::new("db_schema_name", DataType::Utf8, false),
-            Field::new("table_name", DataType::Utf8, false),
-            Field::new("table_type", DataType::Utf8, false),
         ]));
-        let (catalog_name, db_schema_name, table_name, table_type) =
-            Self::get_tables_internal(ctx.clone(), catalog_name, database_name)
-                .await
-                .map_err(|e| Status::internal(format!("{e:?}")))?;
+        let (catalog_name, db_schema_name) = Self::with_timeout(
+            timeout,
+            Self::get_schemas_internal(ctx, catalog_name, db_schema_filter_pattern),
+        )
+        .await?;
         let batch = RecordBatch::try_new(schema, vec![
             Self::string_array(catalog_name),
             Self::string_array(db_schema_name),
-            Self::string_array(table_name),
-            Self::string_array(table_type),
         ])
         .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
         Self::batch_to_get_stream(batch)
     }
 
+    /// The filter patterns are LIKE patterns the same as `SHOW TABLES LIKE`.
+    pub(crate) async fn get_tables(
+        ctx: Arc<dyn TableContext>,
+        catalog_name: Option<String>,
+        db_schema_filter_pattern: Option<String>,
+        table_name_filter_pattern: Option<String>,
+        include_schema: bool,
+        timeout: Duration,
+    ) -> Result<DoGetStream, Status> {
+        let mut fields = vec![
+            Field::new("catalog_name", DataType::Utf8, false),
+            Field::new("db_schema_name", DataType::Utf8, false),
+            Field::new("table_name", DataType::Utf8, false),
+            Field::new("table_type", DataType::Utf8, false),
+        ];
+        if include_schema {
+            fields.push(Field::new("table_schema", DataType::Binary, false));
+        }
+        let schema = Arc::new(Schema::new(fields));
+
+        let rows = Self::with_timeout(
+            timeout,
+            Self::get_tables_internal(
+                ctx,
+                catalog_name,
+                db_schema_filter_pattern,
+                table_name_filter_pattern,
+                include_schema,
+            ),
+        )
+        .await?;
+        let mut columns = vec![
+            Self::string_array(rows.catalog_names),
+            Self::string_array(rows.database_names),
+            Self::string_array(rows.table_names),
+            Self::string_array(rows.table_types),
+        ];
+        if include_schema {
+            columns.push(Self::binary_array(rows.table_schemas));
+        }
+        let batch = RecordBatch::try_new(schema, columns)
+            .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
     fn string_array(values: Vec<String>) -> ArrayRef {
         let mut builder = StringBuilder::new();
         for v in &values {
@@ -133,4 +321,12 @@ impl CatalogInfoProvider {
         }
         Arc::new(builder.finish())
     }
+
+    fn binary_array(values: Vec<Vec<u8>>) -> ArrayRef {
+        let mut builder = BinaryBuilder::new();
+        for v in &values {
+            builder.append_value(v);
+        }
+        Arc::new(builder.finish())
+    }
 }
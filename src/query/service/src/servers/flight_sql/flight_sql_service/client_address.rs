@@ -0,0 +1,81 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::IpAddr;
+use std::net::SocketAddr;
+
+use cidr::IpCidr;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use tonic::metadata::MetadataMap;
+
+/// Finds out the address of the client behind a chain of trusted proxies.
+///
+/// The header is only looked at when the peer of the connection is a trusted proxy, so a client
+/// connecting directly can not spoof its address.
+pub struct ClientAddressResolver {
+    trusted_proxies: Vec<IpCidr>,
+    header: String,
+}
+
+impl ClientAddressResolver {
+    pub fn try_create(trusted_proxies: &[String], header: &str) -> Result<Self> {
+        let trusted_proxies = trusted_proxies
+            .iter()
+            .map(|proxy| {
+                proxy.trim().parse::<IpCidr>().map_err(|e| {
+                    ErrorCode::InvalidConfig(format!(
+                        "invalid flight_sql_trusted_proxies: {proxy}, {e}"
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(ClientAddressResolver {
+            trusted_proxies,
+            header: header.to_lowercase(),
+        })
+    }
+
+    /// Walks the addresses of the header from right to left, skipping the trusted proxies.
+    /// The first untrusted one is the client, falls back to the peer if the header is missing
+    /// or malformed.
+    pub fn resolve(&self, peer: Option<SocketAddr>, metadata: &MetadataMap) -> Option<IpAddr> {
+        let peer = peer?.ip();
+        if !self.is_trusted(&peer) {
+            return Some(peer);
+        }
+
+        let Some(value) = metadata.get(&self.header).and_then(|v| v.to_str().ok()) else {
+            return Some(peer);
+        };
+
+        let mut client = peer;
+        for hop in value.rsplit(',') {
+            match hop.trim().parse::<IpAddr>() {
+                Ok(addr) => {
+                    client = addr;
+                    if !self.is_trusted(&addr) {
+                        break;
+                    }
+                }
+                Err(_) => return Some(peer),
+            }
+        }
+        Some(client)
+    }
+
+    fn is_trusted(&self, addr: &IpAddr) -> bool {
+        self.trusted_proxies.iter().any(|cidr| cidr.contains(addr))
+    }
+}
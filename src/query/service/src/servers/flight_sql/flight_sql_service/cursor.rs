@@ -0,0 +1,300 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use arrow_flight::sql::ProstMessageExt;
+use arrow_flight::FlightData;
+use databend_common_base::base::tokio::sync::mpsc;
+use databend_common_base::base::tokio::sync::mpsc::Sender;
+use databend_common_base::base::tokio::sync::Mutex as AsyncMutex;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::SendableDataBlockStream;
+use databend_common_metrics::flight_sql::decr_flight_sql_open_cursors;
+use databend_common_metrics::flight_sql::incr_flight_sql_open_cursors;
+use futures::StreamExt;
+use parking_lot::Mutex;
+use prost::Message;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::Status;
+use uuid::Uuid;
+
+use super::service::FetchCursor;
+use super::status;
+use super::DoGetStream;
+use super::FlightSqlServiceImpl;
+use crate::servers::http::v1::Expirable;
+use crate::servers::http::v1::ExpiringMap;
+use crate::servers::http::v1::ExpiringState;
+
+/// Cursors opened by the DoGet requests with a `databend-fetch-size` header.
+///
+/// A cursor owns the result stream of its query, every DoGet on the cursor ticket returns the
+/// next `fetch_size` rows. The number of open cursors is capped, an idle cursor is dropped by
+/// the expiring map, which also drops the stream and so kills the query.
+pub(super) struct Cursors {
+    cursors: Mutex<ExpiringMap<String, Arc<Cursor>>>,
+    open_cursors: Arc<AtomicUsize>,
+    max_cursors: usize,
+    idle_timeout: Duration,
+}
+
+/// Accounts one open cursor, released on drop.
+pub(super) struct CursorSlot {
+    open_cursors: Arc<AtomicUsize>,
+}
+
+impl Drop for CursorSlot {
+    fn drop(&mut self) {
+        self.open_cursors.fetch_sub(1, Ordering::SeqCst);
+        decr_flight_sql_open_cursors();
+    }
+}
+
+pub(super) struct Cursor {
+    query_id: String,
+    session_id: String,
+    fetch_size: usize,
+    data_schema: DataSchemaRef,
+    timezone: Option<String>,
+    last_access: Mutex<Instant>,
+    state: AsyncMutex<CursorState>,
+}
+
+struct CursorState {
+    data_stream: SendableDataBlockStream,
+    // Rows read from the stream but not returned yet.
+    pending: Option<DataBlock>,
+    _slot: CursorSlot,
+}
+
+impl Expirable for Arc<Cursor> {
+    fn expire_state(&self) -> ExpiringState {
+        // The state is locked while a page is being sent.
+        if self.state.try_lock().is_err() {
+            ExpiringState::InUse(self.query_id.clone())
+        } else {
+            ExpiringState::Idle {
+                idle_time: Instant::now() - *self.last_access.lock(),
+            }
+        }
+    }
+
+    fn on_expire(&self) {}
+}
+
+impl CursorState {
+    async fn next_block(&mut self) -> Option<databend_common_exception::Result<DataBlock>> {
+        if let Some(block) = self.pending.take() {
+            return Some(Ok(block));
+        }
+        loop {
+            match self.data_stream.next().await {
+                Some(Ok(block)) if block.is_empty() => continue,
+                other => return other,
+            }
+        }
+    }
+}
+
+impl Cursor {
+    pub fn create(
+        query_id: String,
+        session_id: String,
+        fetch_size: usize,
+        data_schema: DataSchemaRef,
+        timezone: Option<String>,
+        data_stream: SendableDataBlockStream,
+        slot: CursorSlot,
+    ) -> Self {
+        Cursor {
+            query_id,
+            session_id,
+            fetch_size,
+            data_schema,
+            timezone,
+            last_access: Mutex::new(Instant::now()),
+            state: AsyncMutex::new(CursorState {
+                data_stream,
+                pending: None,
+                _slot: slot,
+            }),
+        }
+    }
+
+    /// Sends the schema and the next `fetch_size` rows, returns true if the result is exhausted.
+    ///
+    /// The last FlightData of a page that is not the final one carries the ticket of the cursor
+    /// in its app_metadata, a client gets the following rows by a DoGet with this ticket.
+    async fn next_page(&self, token: &str, sender: &Sender<Result<FlightData, Status>>) -> bool {
+        let mut state = self.state.lock().await;
+
+        let schema = FlightSqlServiceImpl::schema_to_flight_data(
+            &self.data_schema,
+            self.timezone.as_deref(),
+        );
+        if sender.send(Ok(schema)).await.is_err() {
+            return false;
+        }
+
+        let mut remaining = self.fetch_size;
+        let mut last = None;
+        while remaining > 0 {
+            let block = match state.next_block().await {
+                Some(Ok(block)) => block,
+                Some(Err(err)) => {
+                    let _ = sender
+                        .send(Err(status!("Could not fetch block", err)))
+                        .await;
+                    return true;
+                }
+                None => {
+                    if let Some(last) = last {
+                        let _ = sender.send(Ok(last)).await;
+                    }
+                    return true;
+                }
+            };
+
+            let block = if block.num_rows() > remaining {
+                state.pending = Some(block.slice(remaining..block.num_rows()));
+                block.slice(0..remaining)
+            } else {
+                block
+            };
+            remaining -= block.num_rows();
+
+            let flight_data =
+                match FlightSqlServiceImpl::block_to_flight_data(block, &self.data_schema) {
+                    Ok(flight_data) => flight_data,
+                    Err(err) => {
+                        let _ = sender
+                            .send(Err(status!("Could not convert batches", err)))
+                            .await;
+                        return true;
+                    }
+                };
+            if let Some(last) = last.replace(flight_data) {
+                if sender.send(Ok(last)).await.is_err() {
+                    return false;
+                }
+            }
+        }
+
+        // Look ahead, so that the final page does not hand out a ticket leading to nothing.
+        let finished = match state.next_block().await {
+            Some(Ok(block)) => {
+                state.pending = Some(block);
+                false
+            }
+            Some(Err(err)) => {
+                let _ = sender
+                    .send(Err(status!("Could not fetch block", err)))
+                    .await;
+                return true;
+            }
+            None => true,
+        };
+
+        if let Some(mut last) = last {
+            if !finished {
+                let ticket = FetchCursor {
+                    token: token.to_string(),
+                };
+                last = last.with_app_metadata(ticket.as_any().encode_to_vec());
+            }
+            let _ = sender.send(Ok(last)).await;
+        }
+        finished
+    }
+}
+
+impl Cursors {
+    pub fn create(max_cursors: u64, idle_timeout_secs: u64) -> Self {
+        Cursors {
+            cursors: Mutex::new(Default::default()),
+            open_cursors: Arc::new(AtomicUsize::new(0)),
+            max_cursors: max_cursors as usize,
+            idle_timeout: Duration::from_secs(idle_timeout_secs),
+        }
+    }
+
+    /// Takes a slot for a new cursor, before the query is started.
+    pub fn reserve(&self) -> Result<CursorSlot, Status> {
+        if self.max_cursors == 0 {
+            return Err(Status::failed_precondition(
+                "fetch size is disabled, flight_sql_max_cursors is 0",
+            ));
+        }
+
+        let reserved = self
+            .open_cursors
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |open| {
+                (open < self.max_cursors).then_some(open + 1)
+            });
+        match reserved {
+            Ok(_) => {
+                incr_flight_sql_open_cursors();
+                Ok(CursorSlot {
+                    open_cursors: self.open_cursors.clone(),
+                })
+            }
+            Err(open) => Err(Status::resource_exhausted(format!(
+                "too many open flight sql cursors: {open}, close or drain some of them first"
+            ))),
+        }
+    }
+
+    pub fn open(&self, cursor: Cursor) -> (String, Arc<Cursor>) {
+        let token = Uuid::new_v4().to_string();
+        let cursor = Arc::new(cursor);
+        self.cursors
+            .lock()
+            .insert(token.clone(), cursor.clone(), Some(self.idle_timeout));
+        (token, cursor)
+    }
+
+    /// Only the session which opened the cursor can read from it.
+    pub fn get(&self, token: &str, session_id: &str) -> Result<Arc<Cursor>, Status> {
+        match self.cursors.lock().get(token) {
+            Some(cursor) if cursor.session_id == session_id => Ok(cursor),
+            _ => Err(Status::not_found(format!(
+                "cursor {token} not found, it may be exhausted or expired"
+            ))),
+        }
+    }
+
+    fn close(&self, token: &str) {
+        self.cursors.lock().remove(token);
+    }
+
+    /// Streams the next page of the cursor, the cursor is closed once its result is exhausted.
+    pub fn fetch(self: &Arc<Self>, token: String, cursor: Arc<Cursor>) -> DoGetStream {
+        let cursors = self.clone();
+        let (sender, receiver) = mpsc::channel(2);
+        databend_common_base::runtime::spawn(async move {
+            let finished = cursor.next_page(&token, &sender).await;
+            *cursor.last_access.lock() = Instant::now();
+            if finished {
+                cursors.close(&token);
+            }
+        });
+        Box::pin(ReceiverStream::new(receiver))
+    }
+}
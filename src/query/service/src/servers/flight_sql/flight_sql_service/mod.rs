@@ -15,22 +15,39 @@
 // The servers module used for external communication with user, such as MySQL wired protocol, etc.
 
 mod catalog;
+mod client_address;
+mod cursor;
 mod query;
+mod rate_limiter;
+mod registry;
 mod service;
 mod session;
 mod sql_info;
+mod ticket_limit;
 
 use std::pin::Pin;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::time::Duration;
 
 use arrow_flight::FlightData;
 use catalog::CatalogInfoProvider;
+use chrono::Utc;
+pub use client_address::ClientAddressResolver;
+use cursor::Cursors;
 use dashmap::DashMap;
+use databend_common_config::GlobalConfig;
 use databend_common_sql::plans::Plan;
 use databend_common_sql::PlanExtras;
 use futures::Stream;
 use parking_lot::Mutex;
+use rate_limiter::RateLimiter;
+use registry::SessionsProvider;
+pub use service::KeepAliveResult;
+pub use service::KEEP_ALIVE_ACTION;
 use sql_info::SqlInfoProvider;
+pub use ticket_limit::TicketSizeLimit;
 use tonic::Status;
 use uuid::Uuid;
 
@@ -47,19 +64,97 @@ macro_rules! status {
 }
 pub(crate) use status;
 
+/// Longest query tag kept from the `databend-query-tag` header, in chars.
+const MAX_QUERY_TAG_LEN: usize = 256;
+
 type DoGetStream = Pin<Box<dyn Stream<Item = Result<FlightData, Status>> + Send + 'static>>;
 
+/// A statement prepared by `CreatePreparedStatement`, until it is closed.
+pub(super) struct PreparedStatement {
+    pub plan: Plan,
+    pub plan_extras: PlanExtras,
+    pub session_id: String,
+    pub user: String,
+    pub sql: String,
+    // In unix microseconds.
+    pub created_at: i64,
+    pub last_executed_at: Mutex<Option<i64>>,
+    pub execution_count: AtomicU64,
+    // Rough size of the plan, by its inline size and the length of the SQL text it grows with.
+    pub estimated_memory: u64,
+}
+
+impl PreparedStatement {
+    pub fn create(plan: Plan, plan_extras: PlanExtras, session: &Session, sql: String) -> Self {
+        let estimated_memory =
+            (std::mem::size_of::<Plan>() + std::mem::size_of::<PlanExtras>() + sql.len()) as u64;
+        PreparedStatement {
+            plan,
+            plan_extras,
+            session_id: session.get_id(),
+            user: session
+                .get_current_user()
+                .map(|user| user.name)
+                .unwrap_or_default(),
+            sql,
+            created_at: Utc::now().timestamp_micros(),
+            last_executed_at: Mutex::new(None),
+            execution_count: AtomicU64::new(0),
+            estimated_memory,
+        }
+    }
+
+    /// Records an execution for `system.prepared_statements`.
+    pub fn executed(&self) {
+        self.execution_count.fetch_add(1, Ordering::Relaxed);
+        *self.last_executed_at.lock() = Some(Utc::now().timestamp_micros());
+    }
+}
+
 pub struct FlightSqlServiceImpl {
-    pub sessions: Mutex<ExpiringMap<String, Arc<Session>>>,
-    statements: Arc<DashMap<Uuid, (Plan, PlanExtras)>>,
+    pub sessions: Arc<Mutex<ExpiringMap<String, Arc<Session>>>>,
+    statements: Arc<DashMap<Uuid, Arc<PreparedStatement>>>,
+    client_address: ClientAddressResolver,
+    cursors: Arc<Cursors>,
+    max_query_size: usize,
+    metadata_timeout: Duration,
+    rate_limiter: RateLimiter,
+    // Held for `system.flight_sessions` and `system.prepared_statements`, which only keep weak
+    // references.
+    _sessions_provider: Arc<SessionsProvider>,
 }
 
 /// in current official JDBC driver, Statement is based on PreparedStatement too, so we impl it first.
 impl FlightSqlServiceImpl {
-    pub fn create() -> Self {
-        FlightSqlServiceImpl {
-            sessions: Mutex::new(Default::default()),
-            statements: Arc::new(Default::default()),
-        }
+    pub fn create() -> databend_common_exception::Result<Self> {
+        let config = GlobalConfig::instance();
+        let sessions = Arc::new(Mutex::new(Default::default()));
+        let statements = Arc::new(DashMap::new());
+        let sessions_provider = SessionsProvider::register(sessions.clone(), statements.clone());
+        Ok(FlightSqlServiceImpl {
+            sessions,
+            statements,
+            client_address: ClientAddressResolver::try_create(
+                &config.query.flight_sql_trusted_proxies,
+                &config.query.flight_sql_client_address_header,
+            )?,
+            cursors: Arc::new(Cursors::create(
+                config.query.flight_sql_max_cursors,
+                config.query.flight_sql_cursor_idle_timeout_secs,
+            )),
+            max_query_size: config.query.flight_sql_max_query_size as usize,
+            metadata_timeout: Duration::from_secs(config.query.flight_sql_metadata_timeout_secs),
+            rate_limiter: RateLimiter::create(config.query.tenant_id.clone()),
+            _sessions_provider: sessions_provider,
+        })
+    }
+
+    /// The prepared statement of a handle. The guard of the map is released before returning,
+    /// so that it is never held across an await.
+    fn get_statement(&self, handle: &Uuid) -> Result<Arc<PreparedStatement>, Status> {
+        self.statements
+            .get(handle)
+            .map(|statement| statement.value().clone())
+            .ok_or_else(|| Status::not_found(format!("prepared statement not found: {handle}")))
     }
 }
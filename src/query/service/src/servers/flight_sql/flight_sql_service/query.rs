@@ -25,13 +25,19 @@ use arrow_ipc::writer::IpcWriteOptions;
 use arrow_ipc::MessageBuilder;
 use arrow_ipc::MessageHeader;
 use arrow_ipc::MetadataVersion;
+use arrow_schema::DataType as ArrowDataType;
+use arrow_schema::Field as ArrowField;
 use arrow_schema::Schema as ArrowSchema;
 use bytes::Bytes;
+use databend_common_ast::ast::quote::QuotedIdent;
+use databend_common_ast::ast::Statement;
 use databend_common_base::base::tokio;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
 use databend_common_expression::DataSchema;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::SendableDataBlockStream;
 use databend_common_sql::get_query_kind;
 use databend_common_sql::plans::Plan;
 use databend_common_sql::PlanExtras;
@@ -42,15 +48,29 @@ use futures::StreamExt;
 use prost::bytes;
 use serde::Deserialize;
 use serde::Serialize;
+use tonic::metadata::MetadataValue;
+use tonic::Response;
 use tonic::Status;
 
+use super::cursor::Cursor;
+use super::service::FetchTable;
 use super::status;
 use super::DoGetStream;
 use super::FlightSqlServiceImpl;
 use crate::interpreters::InterpreterFactory;
+use crate::sessions::QueriesQueueManager;
 use crate::sessions::QueryContext;
+use crate::sessions::QueryEntry;
 use crate::sessions::Session;
 
+/// Set to `hit` when the result is served by the query result cache.
+const RESULT_CACHE_HEADER: &str = "x-databend-result-cache";
+/// Unix seconds when the cache entry serving the result was created.
+const RESULT_CACHE_CREATED_AT_HEADER: &str = "x-databend-result-cache-created-at";
+
+/// JDBC `Statement.SUCCESS_NO_INFO`, the statement succeeded but changes no rows by nature.
+const SUCCESS_NO_INFO: i64 = -1;
+
 /// A app_metakey which indicates the data is a progress type
 static H_PROGRESS: u8 = 0x01;
 
@@ -78,8 +98,25 @@ static DATA_HEADER_PROGRESS: LazyLock<Bytes> = LazyLock::new(|| {
 });
 
 impl FlightSqlServiceImpl {
-    pub(crate) fn schema_to_flight_data(data_schema: DataSchema) -> FlightData {
-        let arrow_schema = ArrowSchema::from(&data_schema);
+    /// The Arrow schema of a result, the timestamps carry the timezone the client asked for.
+    pub(crate) fn arrow_schema(data_schema: &DataSchema, timezone: Option<&str>) -> ArrowSchema {
+        let arrow_schema = ArrowSchema::from(data_schema);
+        let Some(timezone) = timezone else {
+            return arrow_schema;
+        };
+        let fields = arrow_schema
+            .fields()
+            .iter()
+            .map(|field| Arc::new(with_timezone(field, timezone)))
+            .collect::<Vec<_>>();
+        ArrowSchema::new_with_metadata(fields, arrow_schema.metadata().clone())
+    }
+
+    pub(crate) fn schema_to_flight_data(
+        data_schema: &DataSchema,
+        timezone: Option<&str>,
+    ) -> FlightData {
+        let arrow_schema = Self::arrow_schema(data_schema, timezone);
         let options = IpcWriteOptions::default();
         SchemaAsIpc::new(&arrow_schema, &options).into()
     }
@@ -109,37 +146,124 @@ impl FlightSqlServiceImpl {
             .with_data_body(Bytes::from(progress)))
     }
 
+    /// Rejects a SQL text over `flight_sql_max_query_size`, before it reaches the parser.
+    pub(super) fn check_query_size(&self, query: &str) -> std::result::Result<(), Status> {
+        if query.len() > self.max_query_size {
+            return Err(Status::invalid_argument(format!(
+                "query of {} bytes exceeds flight_sql_max_query_size {}",
+                query.len(),
+                self.max_query_size
+            )));
+        }
+        Ok(())
+    }
+
     #[async_backtrace::framed]
     pub async fn plan_sql(
         &self,
         session: &Arc<Session>,
         query: &str,
+        query_tag: Option<&str>,
+        timezone: Option<&str>,
     ) -> Result<(Plan, PlanExtras)> {
         let context = session
             .create_query_context()
             .await
             .map_err(|e| status!("Could not create_query_context", e))?;
+        attach_query_tag(&context, query_tag);
+        attach_timezone(&context, timezone)?;
 
         let mut planner = Planner::new(context.clone());
         planner.plan_sql(query).await
     }
 
+    /// Resolve the table of a path descriptor and plan a scan over it.
+    ///
+    /// The scan goes through the normal planner, so the user's SELECT privilege is checked
+    /// when the interpreter is built.
     #[async_backtrace::framed]
+    pub(super) async fn plan_fetch_table(
+        &self,
+        session: &Arc<Session>,
+        fetch_table: &FetchTable,
+        query_tag: Option<&str>,
+        timezone: Option<&str>,
+    ) -> std::result::Result<(Plan, PlanExtras), Status> {
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+
+        let FetchTable {
+            catalog,
+            database,
+            table,
+            columns,
+            limit,
+        } = fetch_table;
+        if let Err(e) = context.get_table(catalog, database, table).await {
+            return Err(match e.code() {
+                ErrorCode::UNKNOWN_CATALOG
+                | ErrorCode::UNKNOWN_DATABASE
+                | ErrorCode::UNKNOWN_TABLE => Status::not_found(e.message()),
+                _ => status!("Could not get table", e),
+            });
+        }
+
+        let projection = if columns.is_empty() {
+            "*".to_string()
+        } else {
+            columns
+                .iter()
+                .map(|c| QuotedIdent(c, '`').to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let mut query = format!(
+            "SELECT {projection} FROM {}.{}.{}",
+            QuotedIdent(catalog, '`'),
+            QuotedIdent(database, '`'),
+            QuotedIdent(table, '`'),
+        );
+        if let Some(limit) = limit {
+            query.push_str(&format!(" LIMIT {limit}"));
+        }
+
+        self.plan_sql(session, &query, query_tag, timezone)
+            .await
+            .map_err(|e| match e.code() {
+                ErrorCode::SEMANTIC_ERROR => Status::invalid_argument(e.message()),
+                _ => status!("Could not plan table scan", e),
+            })
+    }
+
+    #[async_backtrace::framed]
+    /// Returns the rows changed by a DML statement, or [`SUCCESS_NO_INFO`] for other statements
+    /// (DDL, SET etc.), so that a DML matching no rows is not mistaken for a DDL.
+    ///
+    /// The count comes from the write progress, which only accounts the rows really written,
+    /// e.g. an INSERT skipped by its deduplicate label changes 0 rows.
     pub(super) async fn execute_update(
         &self,
         session: Arc<Session>,
         plan: &Plan,
         plan_extras: &PlanExtras,
+        query_tag: Option<&str>,
+        timezone: Option<&str>,
     ) -> Result<i64> {
         let context = session
             .create_query_context()
             .await
             .map_err(|e| status!("Could not create_query_context", e))?;
+        attach_query_tag(&context, query_tag);
+        attach_timezone(&context, timezone)?;
 
         context.attach_query_str(
             get_query_kind(&plan_extras.statement),
             plan_extras.statement.to_mask_sql(),
         );
+        let entry = QueryEntry::create(&context, plan, plan_extras)?;
+        let _guard = QueriesQueueManager::instance().acquire(entry).await?;
         let interpreter = InterpreterFactory::get(context.clone(), plan).await?;
 
         let mut blocks = interpreter.execute(context.clone()).await?;
@@ -147,37 +271,140 @@ impl FlightSqlServiceImpl {
             block?;
         }
 
+        if !is_dml(&plan_extras.statement) {
+            return Ok(SUCCESS_NO_INFO);
+        }
         let affected_rows = context.get_write_progress_value().rows;
         Ok(affected_rows as i64)
     }
 
-    pub async fn execute_query(
+    /// Runs the query of a DoGet, through a cursor if the client asked for a fetch size.
+    ///
+    /// A result served by the query result cache is flagged by the `x-databend-result-cache`
+    /// response header, along with the creation time of the cache entry.
+    #[async_backtrace::framed]
+    pub(super) async fn do_get_query(
         &self,
         session: Arc<Session>,
         plan: &Plan,
         plan_extras: &PlanExtras,
-    ) -> Result<DoGetStream> {
-        let is_native_client = session.get_status().read().is_native_client;
+        query_tag: Option<&str>,
+        timezone: Option<&str>,
+        disable_result_cache: bool,
+        fetch_size: Option<usize>,
+    ) -> std::result::Result<Response<DoGetStream>, Status> {
+        let Some(fetch_size) = fetch_size else {
+            let (context, stream) = self
+                .execute_query(
+                    session,
+                    plan,
+                    plan_extras,
+                    query_tag,
+                    timezone,
+                    disable_result_cache,
+                )
+                .await
+                .map_err(|e| status!("fail to execute", e))?;
+            return Ok(with_result_cache_header(&context, stream));
+        };
+
+        let slot = self.cursors.reserve()?;
+        let session_id = session.get_id();
+        let (context, data_schema, data_stream) = self
+            .start_query(
+                session,
+                plan,
+                plan_extras,
+                query_tag,
+                timezone,
+                disable_result_cache,
+            )
+            .await
+            .map_err(|e| status!("fail to execute", e))?;
+        let cursor = Cursor::create(
+            context.get_id(),
+            session_id,
+            fetch_size,
+            data_schema,
+            timezone.map(|tz| tz.to_string()),
+            data_stream,
+            slot,
+        );
+        let (token, cursor) = self.cursors.open(cursor);
+        Ok(with_result_cache_header(
+            &context,
+            self.cursors.fetch(token, cursor),
+        ))
+    }
 
+    #[async_backtrace::framed]
+    async fn start_query(
+        &self,
+        session: Arc<Session>,
+        plan: &Plan,
+        plan_extras: &PlanExtras,
+        query_tag: Option<&str>,
+        timezone: Option<&str>,
+        disable_result_cache: bool,
+    ) -> Result<(Arc<QueryContext>, DataSchemaRef, SendableDataBlockStream)> {
         let context = session
             .create_query_context()
             .await
             .map_err(|e| status!("Could not create_query_context", e))?;
+        attach_query_tag(&context, query_tag);
+        attach_timezone(&context, timezone)?;
+        if disable_result_cache {
+            // Only the settings of this query are changed, the session keeps its own.
+            context
+                .get_settings()
+                .set_enable_query_result_cache(false)?;
+        }
 
         context.attach_query_str(
             get_query_kind(&plan_extras.statement),
             plan_extras.statement.to_mask_sql(),
         );
+        let entry = QueryEntry::create(&context, plan, plan_extras)?;
+        let queue_guard = QueriesQueueManager::instance().acquire(entry).await?;
         let interpreter = InterpreterFactory::get(context.clone(), plan).await?;
 
         let data_schema = plan.schema();
         let data_stream = interpreter.execute(context.clone()).await?;
+        // The results are streamed after returning, so the stream holds the running slot.
+        let data_stream: SendableDataBlockStream = Box::pin(data_stream.map(move |block| {
+            let _guard = &queue_guard;
+            block
+        }));
+        Ok((context, data_schema, data_stream))
+    }
+
+    async fn execute_query(
+        &self,
+        session: Arc<Session>,
+        plan: &Plan,
+        plan_extras: &PlanExtras,
+        query_tag: Option<&str>,
+        timezone: Option<&str>,
+        disable_result_cache: bool,
+    ) -> Result<(Arc<QueryContext>, DoGetStream)> {
+        let is_native_client = session.get_status().read().is_native_client;
+
+        let (context, data_schema, data_stream) = self
+            .start_query(
+                session,
+                plan,
+                plan_extras,
+                query_tag,
+                timezone,
+                disable_result_cache,
+            )
+            .await?;
 
         let is_finished = Arc::new(AtomicBool::new(false));
         let is_finished_clone = is_finished.clone();
         let (sender, receiver) = tokio::sync::mpsc::channel(2);
         let _ = sender
-            .send(Ok(Self::schema_to_flight_data((*data_schema).clone())))
+            .send(Ok(Self::schema_to_flight_data(&data_schema, timezone)))
             .await;
 
         let s1 = sender.clone();
@@ -206,6 +433,7 @@ impl FlightSqlServiceImpl {
             is_finished_clone.store(true, Ordering::SeqCst);
         });
 
+        let query_context = context.clone();
         if is_native_client {
             databend_common_base::runtime::spawn(async move {
                 let total_scan_value = context.get_total_scan_value();
@@ -276,7 +504,7 @@ impl FlightSqlServiceImpl {
         }
 
         let st = receiver_to_stream(receiver);
-        Ok(Box::pin(st))
+        Ok((query_context, Box::pin(st)))
     }
 }
 
@@ -291,3 +519,66 @@ struct ProgressValue {
     pub write_rows: usize,
     pub write_bytes: usize,
 }
+
+fn is_dml(stmt: &Statement) -> bool {
+    matches!(
+        stmt,
+        Statement::Insert(_)
+            | Statement::InsertMultiTable(_)
+            | Statement::Replace(_)
+            | Statement::Update(_)
+            | Statement::Delete(_)
+            | Statement::MergeInto(_)
+            | Statement::CopyIntoTable(_)
+    )
+}
+
+fn with_result_cache_header(context: &QueryContext, stream: DoGetStream) -> Response<DoGetStream> {
+    let mut response = Response::new(stream);
+    if let Some(created_at) = context.get_result_cache_hit() {
+        let metadata = response.metadata_mut();
+        metadata.insert(RESULT_CACHE_HEADER, MetadataValue::from_static("hit"));
+        metadata.insert(
+            RESULT_CACHE_CREATED_AT_HEADER,
+            MetadataValue::from(created_at),
+        );
+    }
+    response
+}
+
+fn attach_query_tag(context: &QueryContext, query_tag: Option<&str>) {
+    if let Some(query_tag) = query_tag {
+        context.set_query_tag(query_tag.to_string());
+    }
+}
+
+/// Only the settings of this query are changed, the session keeps its own timezone.
+fn attach_timezone(context: &QueryContext, timezone: Option<&str>) -> Result<()> {
+    if let Some(timezone) = timezone {
+        context.get_settings().set_timezone(timezone.to_string())?;
+    }
+    Ok(())
+}
+
+fn with_timezone(field: &ArrowField, timezone: &str) -> ArrowField {
+    let data_type = match field.data_type() {
+        ArrowDataType::Timestamp(unit, None) => {
+            ArrowDataType::Timestamp(*unit, Some(timezone.into()))
+        }
+        ArrowDataType::List(inner) => ArrowDataType::List(Arc::new(with_timezone(inner, timezone))),
+        ArrowDataType::LargeList(inner) => {
+            ArrowDataType::LargeList(Arc::new(with_timezone(inner, timezone)))
+        }
+        ArrowDataType::Map(inner, sorted) => {
+            ArrowDataType::Map(Arc::new(with_timezone(inner, timezone)), *sorted)
+        }
+        ArrowDataType::Struct(fields) => ArrowDataType::Struct(
+            fields
+                .iter()
+                .map(|f| Arc::new(with_timezone(f, timezone)))
+                .collect(),
+        ),
+        _ => return field.clone(),
+    };
+    field.clone().with_data_type(data_type)
+}
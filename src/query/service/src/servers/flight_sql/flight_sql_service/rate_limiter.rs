@@ -0,0 +1,227 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::time::Duration;
+use std::time::Instant;
+
+use databend_common_base::base::tokio::sync::Mutex as AsyncMutex;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_metrics::flight_sql::incr_flight_sql_throttled_metadata_requests;
+use databend_common_metrics::flight_sql::incr_flight_sql_throttled_statements;
+use databend_common_settings::Settings;
+use log::warn;
+use parking_lot::Mutex;
+use parking_lot::RwLock;
+use tonic::metadata::MetadataMap;
+use tonic::metadata::MetadataValue;
+use tonic::Code;
+use tonic::Status;
+
+/// How often the limits are reloaded from the global settings.
+const LIMITS_REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+/// How often the buckets of idle users are dropped.
+const BUCKETS_EVICT_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Clone, Copy, Debug)]
+pub(super) enum RateLimitKind {
+    Statement,
+    Metadata,
+}
+
+#[derive(Clone, Copy, Default)]
+struct RateLimit {
+    per_second: u64,
+    burst: u64,
+}
+
+impl RateLimit {
+    /// Size of the bucket, at least one request gets through.
+    fn capacity(&self) -> f64 {
+        self.burst.max(1) as f64
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+struct RateLimits {
+    statement: RateLimit,
+    metadata: RateLimit,
+}
+
+struct TokenBucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    /// Takes one token, or returns how long to wait for the next one.
+    fn try_take(&mut self, limit: RateLimit, now: Instant) -> Result<(), Duration> {
+        let per_second = limit.per_second as f64;
+        let capacity = limit.capacity();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * per_second).min(capacity);
+        self.last_refill = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - self.tokens) / per_second))
+        }
+    }
+
+    /// A bucket refilled to the full is the same as a new one, so it can be dropped.
+    fn is_full(&self, limit: RateLimit, now: Instant) -> bool {
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens + elapsed * limit.per_second as f64 >= limit.capacity()
+    }
+}
+
+struct TokenBuckets {
+    buckets: HashMap<String, TokenBucket>,
+    evicted_at: Instant,
+}
+
+impl TokenBuckets {
+    fn new() -> Self {
+        TokenBuckets {
+            buckets: HashMap::new(),
+            evicted_at: Instant::now(),
+        }
+    }
+
+    fn try_take(&mut self, user: &str, limit: RateLimit, now: Instant) -> Result<(), Duration> {
+        if now.duration_since(self.evicted_at) >= BUCKETS_EVICT_INTERVAL {
+            self.buckets.retain(|_, bucket| !bucket.is_full(limit, now));
+            self.evicted_at = now;
+        }
+
+        self.buckets
+            .entry(user.to_string())
+            .or_insert_with(|| TokenBucket {
+                tokens: limit.capacity(),
+                last_refill: now,
+            })
+            .try_take(limit, now)
+    }
+}
+
+/// Throttles the FlightSQL requests of each user by token buckets, independent of the
+/// admission of the queries by the queries queue.
+///
+/// The limits are the `flight_sql_*_per_second` and `flight_sql_*_burst` settings, read from
+/// the global settings, so an admin can change them by SET GLOBAL without a restart, while the
+/// session level values are ignored, a user can not lift its own limit.
+pub(super) struct RateLimiter {
+    tenant: Tenant,
+    limits: RwLock<(RateLimits, Option<Instant>)>,
+    /// Held by the only request reloading the limits.
+    reloading: AsyncMutex<()>,
+    statement_buckets: Mutex<TokenBuckets>,
+    metadata_buckets: Mutex<TokenBuckets>,
+}
+
+impl RateLimiter {
+    pub fn create(tenant: Tenant) -> Self {
+        RateLimiter {
+            tenant,
+            limits: RwLock::new((RateLimits::default(), None)),
+            reloading: AsyncMutex::new(()),
+            statement_buckets: Mutex::new(TokenBuckets::new()),
+            metadata_buckets: Mutex::new(TokenBuckets::new()),
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn acquire(&self, user: &str, kind: RateLimitKind) -> Result<(), Status> {
+        let limits = self.limits().await;
+        let (limit, buckets) = match kind {
+            RateLimitKind::Statement => (limits.statement, &self.statement_buckets),
+            RateLimitKind::Metadata => (limits.metadata, &self.metadata_buckets),
+        };
+        if limit.per_second == 0 {
+            return Ok(());
+        }
+
+        let taken = buckets.lock().try_take(user, limit, Instant::now());
+
+        taken.map_err(|wait| {
+            match kind {
+                RateLimitKind::Statement => incr_flight_sql_throttled_statements(),
+                RateLimitKind::Metadata => incr_flight_sql_throttled_metadata_requests(),
+            }
+            Self::exhausted(user, kind, wait)
+        })
+    }
+
+    async fn limits(&self) -> RateLimits {
+        let (limits, loaded_at) = *self.limits.read();
+        if loaded_at.is_some_and(|t| t.elapsed() < LIMITS_REFRESH_INTERVAL) {
+            return limits;
+        }
+
+        // A single request reloads the limits, the others go on with the limits in use,
+        // but before the first load, when there are none yet.
+        let _reloading = match self.reloading.try_lock() {
+            Ok(guard) => guard,
+            Err(_) if loaded_at.is_some() => return limits,
+            Err(_) => self.reloading.lock().await,
+        };
+        let (limits, loaded_at) = *self.limits.read();
+        if loaded_at.is_some_and(|t| t.elapsed() < LIMITS_REFRESH_INTERVAL) {
+            return limits;
+        }
+
+        let limits = match self.load_limits().await {
+            Ok(limits) => limits,
+            Err(e) => {
+                // Keep the limits in use, try again at the next refresh.
+                warn!("fail to load flight sql rate limits: {e}");
+                limits
+            }
+        };
+        *self.limits.write() = (limits, Some(Instant::now()));
+        limits
+    }
+
+    async fn load_limits(&self) -> databend_common_exception::Result<RateLimits> {
+        let settings = Settings::create(self.tenant.clone());
+        settings.load_changes().await?;
+        Ok(RateLimits {
+            statement: RateLimit {
+                per_second: settings.get_flight_sql_statements_per_second()?,
+                burst: settings.get_flight_sql_statements_burst()?,
+            },
+            metadata: RateLimit {
+                per_second: settings.get_flight_sql_metadata_per_second()?,
+                burst: settings.get_flight_sql_metadata_burst()?,
+            },
+        })
+    }
+
+    fn exhausted(user: &str, kind: RateLimitKind, wait: Duration) -> Status {
+        let retry_after = wait.as_secs_f64().ceil().max(1.0) as u64;
+        let mut metadata = MetadataMap::new();
+        metadata.insert("retry-after", MetadataValue::from(retry_after));
+        let what = match kind {
+            RateLimitKind::Statement => "statements",
+            RateLimitKind::Metadata => "metadata requests",
+        };
+        Status::with_metadata(
+            Code::ResourceExhausted,
+            format!("too many flight sql {what} of user {user}, retry after {retry_after} seconds"),
+            metadata,
+        )
+    }
+}
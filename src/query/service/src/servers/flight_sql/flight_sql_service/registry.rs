@@ -0,0 +1,125 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Instant;
+
+use chrono::Utc;
+use dashmap::DashMap;
+use databend_common_storages_system::FlightSessionInfo;
+use databend_common_storages_system::FlightSessionsProvider;
+use databend_common_storages_system::FlightSessionsRegistry;
+use databend_common_storages_system::PreparedStatementInfo;
+use databend_common_storages_system::PreparedStatementsProvider;
+use databend_common_storages_system::PreparedStatementsRegistry;
+use parking_lot::Mutex;
+use sha2::Digest;
+use sha2::Sha256;
+use uuid::Uuid;
+
+use super::PreparedStatement;
+use crate::servers::http::v1::ExpiringMap;
+use crate::sessions::Session;
+
+/// Hex chars of the token hash shown in `system.flight_sessions`.
+const TOKEN_HASH_LEN: usize = 16;
+
+/// Lists the sessions of a FlightSQL service in `system.flight_sessions`, and its prepared
+/// statements in `system.prepared_statements`.
+pub(super) struct SessionsProvider {
+    sessions: Arc<Mutex<ExpiringMap<String, Arc<Session>>>>,
+    statements: Arc<DashMap<Uuid, Arc<PreparedStatement>>>,
+}
+
+impl SessionsProvider {
+    pub fn register(
+        sessions: Arc<Mutex<ExpiringMap<String, Arc<Session>>>>,
+        statements: Arc<DashMap<Uuid, Arc<PreparedStatement>>>,
+    ) -> Arc<Self> {
+        let provider = Arc::new(SessionsProvider {
+            sessions,
+            statements,
+        });
+        let weak = Arc::downgrade(&provider);
+        FlightSessionsRegistry::instance().register(weak.clone());
+        PreparedStatementsRegistry::instance().register(weak);
+        provider
+    }
+}
+
+impl FlightSessionsProvider for SessionsProvider {
+    fn list_sessions(&self) -> Vec<FlightSessionInfo> {
+        let mut statement_counts: HashMap<String, u64> = HashMap::new();
+        for statement in self.statements.iter() {
+            *statement_counts
+                .entry(statement.session_id.clone())
+                .or_default() += 1;
+        }
+
+        // The session status keeps instants, turn them into wall clock times.
+        let now = Instant::now();
+        let now_micros = Utc::now().timestamp_micros();
+        let to_micros = |t: Instant| now_micros - now.duration_since(t).as_micros() as i64;
+
+        let sessions = self.sessions.lock().entries();
+        sessions
+            .into_iter()
+            .filter(|(_, session)| !session.is_aborting())
+            .map(|(token, session)| {
+                let (created_at, last_active_at) = {
+                    let status = session.get_status();
+                    let status = status.read();
+                    (status.session_started_at, status.last_access())
+                };
+                let token_hash = hex::encode(Sha256::digest(token.as_bytes()));
+                FlightSessionInfo {
+                    token_hash: token_hash[..TOKEN_HASH_LEN].to_string(),
+                    user: session
+                        .get_current_user()
+                        .map(|user| user.name)
+                        .unwrap_or_default(),
+                    client_address: session.get_client_host(),
+                    created_at: to_micros(created_at),
+                    last_active_at: to_micros(last_active_at),
+                    prepared_statement_count: statement_counts
+                        .get(&session.get_id())
+                        .copied()
+                        .unwrap_or_default(),
+                    database: session.get_current_database(),
+                }
+            })
+            .collect()
+    }
+}
+
+impl PreparedStatementsProvider for SessionsProvider {
+    fn list_prepared_statements(&self) -> Vec<PreparedStatementInfo> {
+        self.statements
+            .iter()
+            .map(|statement| PreparedStatementInfo {
+                handle: statement.key().to_string(),
+                handler: "FlightSQL".to_string(),
+                user: statement.user.clone(),
+                session_id: statement.session_id.clone(),
+                sql: statement.sql.clone(),
+                created_at: statement.created_at,
+                last_executed_at: *statement.last_executed_at.lock(),
+                execution_count: statement.execution_count.load(Ordering::Relaxed),
+                estimated_plan_memory: statement.estimated_memory,
+            })
+            .collect()
+    }
+}
@@ -33,6 +33,7 @@ use arrow_flight::sql::ActionCreatePreparedSubstraitPlanRequest;
 use arrow_flight::sql::ActionEndSavepointRequest;
 use arrow_flight::sql::ActionEndTransactionRequest;
 use arrow_flight::sql::Any;
+use arrow_flight::sql::Command;
 use arrow_flight::sql::CommandGetCatalogs;
 use arrow_flight::sql::CommandGetCrossReference;
 use arrow_flight::sql::CommandGetDbSchemas;
@@ -54,6 +55,7 @@ use arrow_flight::sql::ProstMessageExt;
 use arrow_flight::sql::SqlInfo;
 use arrow_flight::sql::TicketStatementQuery;
 use arrow_flight::Action;
+use arrow_flight::ActionType;
 use arrow_flight::FlightDescriptor;
 use arrow_flight::FlightEndpoint;
 use arrow_flight::FlightInfo;
@@ -71,6 +73,7 @@ use databend_common_expression::DataSchema;
 use futures::Stream;
 use log::info;
 use prost::Message;
+use tonic::metadata::MetadataMap;
 use tonic::metadata::MetadataValue;
 use tonic::server::NamedService;
 use tonic::Request;
@@ -78,9 +81,15 @@ use tonic::Response;
 use tonic::Status;
 use tonic::Streaming;
 
+use super::rate_limiter::RateLimitKind;
 use super::status;
+use super::PreparedStatement;
 use crate::servers::flight_sql::flight_sql_service::FlightSqlServiceImpl;
 
+/// Type of the custom action keeping a session alive, e.g. for the validation of pooled
+/// connections. The action takes no body and answers a [`KeepAliveResult`].
+pub const KEEP_ALIVE_ACTION: &str = "KeepAlive";
+
 fn try_unpack_any<T: ProstMessageExt>(message: Any) -> std::result::Result<T, Status> {
     message
         .unpack()
@@ -137,16 +146,38 @@ impl FlightSqlService for FlightSqlServiceImpl {
     #[async_backtrace::framed]
     async fn do_handshake(
         &self,
-        request: Request<Streaming<HandshakeRequest>>,
+        mut request: Request<Streaming<HandshakeRequest>>,
     ) -> Result<
         Response<Pin<Box<dyn Stream<Item = Result<HandshakeResponse, Status>> + Send>>>,
         Status,
     > {
-        let (user, password) = FlightSqlServiceImpl::get_user_password(request.metadata())
-            .map_err(Status::invalid_argument)?;
-        let client_ip = request.remote_addr().map(|a| a.ip().to_string());
+        let metadata = request.metadata().clone();
+        let remote_addr = request.remote_addr();
+        let (user, password) = if metadata.contains_key("authorization") {
+            FlightSqlServiceImpl::get_user_password(&metadata).map_err(Status::invalid_argument)?
+        } else {
+            FlightSqlServiceImpl::get_payload_user_password(request.get_mut()).await?
+        };
+        let client_ip = self
+            .client_address
+            .resolve(remote_addr, &metadata)
+            .map(|ip| ip.to_string());
+        info!(
+            "flight sql handshake, user: {user}, client address: {:?}, peer: {:?}",
+            client_ip, remote_addr
+        );
+        let timezone = FlightSqlServiceImpl::get_timezone_header(&metadata)?;
         let session =
             FlightSqlServiceImpl::auth_user_password(user, password, client_ip.as_deref()).await?;
+        session.set_client_host(client_ip);
+        session.add_labels(FlightSqlServiceImpl::get_labels(&metadata));
+        if let Some(timezone) = timezone {
+            session
+                .get_settings()
+                .set_timezone(timezone)
+                .map_err(|e| status!("Could not set timezone", e))?;
+            session.get_status().write().timezone_in_results = true;
+        }
         let token = Uuid::new_v4().to_string();
         let result = HandshakeResponse {
             protocol_version: 0,
@@ -157,23 +188,23 @@ impl FlightSqlService for FlightSqlServiceImpl {
         let output = futures::stream::iter(vec![result]);
         let mut resp: Response<Pin<Box<dyn Stream<Item = Result<_, _>> + Send>>> =
             Response::new(Box::pin(output));
-        let metadata = MetadataValue::try_from(str)
+        let authorization = MetadataValue::try_from(str)
             .map_err(|_| Status::internal("authorization not parsable"))?;
-        resp.metadata_mut().insert("authorization", metadata);
+        resp.metadata_mut().insert("authorization", authorization);
 
         session.get_status().write().is_native_client =
-            FlightSqlServiceImpl::get_header_value(request.metadata(), "bendsql").is_some();
+            FlightSqlServiceImpl::get_header_value(&metadata, "bendsql").is_some();
 
         let session_keep_alive =
-            FlightSqlServiceImpl::get_header_value(request.metadata(), "session_keep_alive")
+            FlightSqlServiceImpl::get_header_value(&metadata, "session_keep_alive")
                 .map(|v| v.parse::<u64>().unwrap_or(360))
                 .unwrap_or(360);
 
-        self.sessions.lock().insert(
-            token,
-            session,
-            Some(Duration::from_secs(session_keep_alive)),
-        );
+        let session_keep_alive = Duration::from_secs(session_keep_alive);
+        session.get_status().write().max_idle_time = Some(session_keep_alive);
+        self.sessions
+            .lock()
+            .insert(token, session, Some(session_keep_alive));
 
         Ok(resp)
     }
@@ -185,6 +216,47 @@ impl FlightSqlService for FlightSqlServiceImpl {
         message: Any,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         let session = self.get_session(&request)?;
+
+        if message.type_url == FetchCursor::type_url() {
+            let fetch_cursor: FetchCursor = try_unpack_any(message)?;
+            info!("do_get_fallback with cursor={}", fetch_cursor.token);
+
+            let cursor = self.cursors.get(&fetch_cursor.token, &session.get_id())?;
+            let stream = self.cursors.fetch(fetch_cursor.token, cursor);
+            return Ok(Response::new(stream));
+        }
+
+        self.throttle(&session, RateLimitKind::Statement).await?;
+        let query_tag = FlightSqlServiceImpl::get_query_tag(request.metadata());
+        let disable_result_cache =
+            FlightSqlServiceImpl::get_disable_result_cache(request.metadata());
+        let fetch_size = FlightSqlServiceImpl::get_fetch_size(request.metadata())?;
+        let timezone = FlightSqlServiceImpl::get_timezone(&session, request.metadata())?;
+        if message.type_url == FetchTable::type_url() {
+            let fetch_table: FetchTable = try_unpack_any(message)?;
+            info!("do_get_fallback with table={fetch_table:?}");
+
+            let (plan, plan_extras) = self
+                .plan_fetch_table(
+                    &session,
+                    &fetch_table,
+                    query_tag.as_deref(),
+                    timezone.as_deref(),
+                )
+                .await?;
+            return self
+                .do_get_query(
+                    session,
+                    &plan,
+                    &plan_extras,
+                    query_tag.as_deref(),
+                    timezone.as_deref(),
+                    disable_result_cache,
+                    fetch_size,
+                )
+                .await;
+        }
+
         let fetch_results: FetchResults = try_unpack_any(message)?;
 
         let handle = Uuid::try_parse(&fetch_results.handle).map_err(|e| {
@@ -196,13 +268,18 @@ impl FlightSqlService for FlightSqlServiceImpl {
 
         info!("do_get_fallback with handle={handle}");
 
-        let handle_plan = self.statements.get(&handle).unwrap();
-        let stream = self
-            .execute_query(session, &handle_plan.value().0, &handle_plan.value().1)
-            .await
-            .map_err(|e| status!("fail to execute", e))?;
-        let resp = Response::new(stream);
-        Ok(resp)
+        let handle_plan = self.get_statement(&handle)?;
+        handle_plan.executed();
+        self.do_get_query(
+            session,
+            &handle_plan.plan,
+            &handle_plan.plan_extras,
+            query_tag.as_deref(),
+            timezone.as_deref(),
+            disable_result_cache,
+            fetch_size,
+        )
+        .await
     }
 
     #[async_backtrace::framed]
@@ -211,8 +288,9 @@ impl FlightSqlService for FlightSqlServiceImpl {
         query: CommandStatementQuery,
         request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        info!("get_flight_info_sql_info(query={})", query.query);
         let _session = self.get_session(&request)?;
+        self.check_query_size(&query.query)?;
+        info!("get_flight_info_sql_info(query={})", query.query);
         Ok(simple_flight_info(query))
     }
 
@@ -222,14 +300,16 @@ impl FlightSqlService for FlightSqlServiceImpl {
         cmd: CommandPreparedStatementQuery,
         request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        let _session = self.get_session(&request);
+        let session = self.get_session(&request)?;
+        let timezone = FlightSqlServiceImpl::get_timezone(&session, request.metadata())?;
         let handle = Uuid::from_slice(cmd.prepared_statement_handle.as_ref())
             .map_err(|e| Status::internal(format!("Error decoding handle: {e}")))?;
 
         info!("get_flight_info_prepared_statement with handle={handle}");
 
-        let handle_plan_ref = self.statements.get(&handle).unwrap();
-        let schema = handle_plan_ref.value().0.schema().as_ref().into();
+        let handle_plan = self.get_statement(&handle)?;
+        let schema =
+            FlightSqlServiceImpl::arrow_schema(&handle_plan.plan.schema(), timezone.as_deref());
         let loc = Location {
             uri: "grpc+tcp://127.0.0.1".to_string(),
         };
@@ -372,6 +452,63 @@ impl FlightSqlService for FlightSqlServiceImpl {
         ))
     }
 
+    /// Serve `DescriptorType::Path` descriptors of the form `[catalog, database, table]`,
+    /// so that clients can fetch a whole table without writing any SQL.
+    ///
+    /// The optional `databend-columns` (comma separated) and `databend-limit` headers
+    /// restrict the columns and the number of rows returned by the following DoGet.
+    #[async_backtrace::framed]
+    async fn get_flight_info_fallback(
+        &self,
+        cmd: Command,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        if request.get_ref().r#type() != DescriptorType::Path {
+            return Err(Status::unimplemented(format!(
+                "get_flight_info: The defined request is invalid: {}",
+                cmd.type_url()
+            )));
+        }
+
+        let session = self.get_session(&request)?;
+        let fetch_table = FetchTable::try_create(request.get_ref(), request.metadata())?;
+        info!("get_flight_info_fallback with table={fetch_table:?}");
+
+        let query_tag = FlightSqlServiceImpl::get_query_tag(request.metadata());
+        let timezone = FlightSqlServiceImpl::get_timezone(&session, request.metadata())?;
+        let (plan, _) = self
+            .plan_fetch_table(
+                &session,
+                &fetch_table,
+                query_tag.as_deref(),
+                timezone.as_deref(),
+            )
+            .await?;
+        let schema = FlightSqlServiceImpl::arrow_schema(&plan.schema(), timezone.as_deref());
+        let message = SchemaAsIpc::new(&schema, &IpcWriteOptions::default())
+            .try_into()
+            .map_err(|e| status!("Unable to serialize schema", e))?;
+        let IpcMessage(schema_bytes) = message;
+
+        let buf = fetch_table.as_any().encode_to_vec().into();
+        let endpoint = FlightEndpoint {
+            ticket: Some(Ticket { ticket: buf }),
+            location: vec![],
+            expiration_time: None,
+            app_metadata: Default::default(),
+        };
+        let info = FlightInfo {
+            schema: schema_bytes,
+            flight_descriptor: Some(request.into_inner()),
+            endpoint: vec![endpoint],
+            total_records: -1,
+            total_bytes: -1,
+            ordered: false,
+            app_metadata: Default::default(),
+        };
+        Ok(Response::new(info))
+    }
+
     // do_get
     #[async_backtrace::framed]
     async fn do_get_statement(
@@ -409,10 +546,24 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_schemas(
         &self,
         query: CommandGetDbSchemas,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_schemas({query:?}");
-        Err(Status::unimplemented("do_get_schemas not implemented"))
+        let session = self.get_session(&request)?;
+        self.throttle(&session, RateLimitKind::Metadata).await?;
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_schemas(
+                context.clone(),
+                query.catalog.clone(),
+                query.db_schema_filter_pattern.clone(),
+                self.metadata_timeout,
+            )
+            .await?,
+        ))
     }
 
     #[async_backtrace::framed]
@@ -423,13 +574,21 @@ impl FlightSqlService for FlightSqlServiceImpl {
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_tables({query:?})");
         let session = self.get_session(&request)?;
+        self.throttle(&session, RateLimitKind::Metadata).await?;
         let context = session
             .create_query_context()
             .await
             .map_err(|e| status!("Could not create_query_context", e))?;
         Ok(Response::new(
-            super::CatalogInfoProvider::get_tables(context.clone(), query.catalog.clone(), None)
-                .await?,
+            super::CatalogInfoProvider::get_tables(
+                context.clone(),
+                query.catalog.clone(),
+                query.db_schema_filter_pattern.clone(),
+                query.table_name_filter_pattern.clone(),
+                query.include_schema,
+                self.metadata_timeout,
+            )
+            .await?,
         ))
     }
 
@@ -507,15 +666,26 @@ impl FlightSqlService for FlightSqlServiceImpl {
         request: Request<PeekableFlightDataStream>,
     ) -> Result<i64, Status> {
         let session = self.get_session(&request)?;
+        let query_tag = FlightSqlServiceImpl::get_query_tag(request.metadata());
+        let timezone = FlightSqlServiceImpl::get_timezone(&session, request.metadata())?;
         let query = ticket.query;
+        self.check_query_size(&query)?;
         info!("do_put_statement_update with query = {query}");
 
+        // Throttled before planning, so that retrying a statement failing to plan is limited too.
+        self.throttle(&session, RateLimitKind::Statement).await?;
         let (plan, plan_extras) = self
-            .plan_sql(&session, &query)
+            .plan_sql(&session, &query, query_tag.as_deref(), timezone.as_deref())
             .await
             .map_err(|e| status!("Error getting result schema", e))?;
         let res = self
-            .execute_update(session, &plan, &plan_extras)
+            .execute_update(
+                session,
+                &plan,
+                &plan_extras,
+                query_tag.as_deref(),
+                timezone.as_deref(),
+            )
             .await
             .map_err(|e| status!("fail to execute", e))?;
         Ok(res)
@@ -528,14 +698,24 @@ impl FlightSqlService for FlightSqlServiceImpl {
         request: Request<PeekableFlightDataStream>,
     ) -> Result<DoPutPreparedStatementResult, Status> {
         let session = self.get_session(&request)?;
+        let query_tag = FlightSqlServiceImpl::get_query_tag(request.metadata());
+        let timezone = FlightSqlServiceImpl::get_timezone(&session, request.metadata())?;
         let handle = Uuid::from_slice(query.prepared_statement_handle.as_ref())
             .map_err(|e| Status::internal(format!("Error decoding handle: {e}")))?;
 
         info!("do_put_prepared_statement_query with handle={handle}");
 
-        let handle_plan = self.statements.get(&handle).unwrap();
+        let handle_plan = self.get_statement(&handle)?;
+        self.throttle(&session, RateLimitKind::Statement).await?;
+        handle_plan.executed();
         let record_count = self
-            .execute_update(session, &handle_plan.value().0, &handle_plan.value().1)
+            .execute_update(
+                session,
+                &handle_plan.plan,
+                &handle_plan.plan_extras,
+                query_tag.as_deref(),
+                timezone.as_deref(),
+            )
             .await
             .map_err(|e| status!("fail to execute", e))?;
         let result = DoPutUpdateResult { record_count };
@@ -556,14 +736,24 @@ impl FlightSqlService for FlightSqlServiceImpl {
         request: Request<PeekableFlightDataStream>,
     ) -> Result<i64, Status> {
         let session = self.get_session(&request)?;
+        let query_tag = FlightSqlServiceImpl::get_query_tag(request.metadata());
+        let timezone = FlightSqlServiceImpl::get_timezone(&session, request.metadata())?;
         let handle = Uuid::from_slice(query.prepared_statement_handle.as_ref())
             .map_err(|e| Status::internal(format!("Error decoding handle: {e}")))?;
 
         info!("do_put_prepared_statement_update with handle={handle}");
 
-        let handle_plan = self.statements.get(&handle).unwrap();
+        let handle_plan = self.get_statement(&handle)?;
+        self.throttle(&session, RateLimitKind::Statement).await?;
+        handle_plan.executed();
         let res = self
-            .execute_update(session, &handle_plan.value().0, &handle_plan.value().1)
+            .execute_update(
+                session,
+                &handle_plan.plan,
+                &handle_plan.plan_extras,
+                query_tag.as_deref(),
+                timezone.as_deref(),
+            )
             .await
             .map_err(|e| status!("fail to execute", e))?;
 
@@ -578,10 +768,13 @@ impl FlightSqlService for FlightSqlServiceImpl {
         request: Request<Action>,
     ) -> Result<ActionCreatePreparedStatementResult, Status> {
         let session = self.get_session(&request)?;
+        let query_tag = FlightSqlServiceImpl::get_query_tag(request.metadata());
+        let timezone = FlightSqlServiceImpl::get_timezone(&session, request.metadata())?;
+        self.check_query_size(&query.query)?;
         let sql = query.query.clone();
         let handle = Uuid::new_v4();
         let plan = self
-            .plan_sql(&session, &sql)
+            .plan_sql(&session, &sql, query_tag.as_deref(), timezone.as_deref())
             .await
             .map_err(|e| status!("Error getting result schema", e))?;
         info!(
@@ -598,8 +791,11 @@ impl FlightSqlService for FlightSqlServiceImpl {
             "do_action_create_prepared_statement with handler={handle}, query={:?}, return schema={data_schema:?}",
             query.query
         );
-        let schema = (&*data_schema).into();
-        self.statements.insert(handle, plan);
+        let schema = FlightSqlServiceImpl::arrow_schema(&data_schema, timezone.as_deref());
+        self.statements.insert(
+            handle,
+            Arc::new(PreparedStatement::create(plan.0, plan.1, &session, sql)),
+        );
         let message = SchemaAsIpc::new(&schema, &IpcWriteOptions::default())
             .try_into()
             .map_err(|e| status!("Unable to serialize schema", e))?;
@@ -619,27 +815,67 @@ impl FlightSqlService for FlightSqlServiceImpl {
         request: Request<Action>,
     ) -> Result<(), Status> {
         let handle = query.prepared_statement_handle.as_ref();
-        if let Ok(handle) = std::str::from_utf8(handle) {
-            info!(
-                "do_action_close_prepared_statement with handle {:?}",
-                handle
-            );
-            match Uuid::try_parse(handle) {
-                Ok(handle) => {
-                    if self.get_session(&request).is_ok() {
-                        self.statements.remove(&handle);
-                    }
-                }
-                Err(e) => {
-                    return Err(Status::internal(format!(
+        // The handle returned by `CreatePreparedStatement` is the raw bytes of the uuid, while
+        // some clients send back its text form.
+        let handle = match Uuid::from_slice(handle) {
+            Ok(handle) => handle,
+            Err(_) => match std::str::from_utf8(handle) {
+                Ok(handle) => Uuid::try_parse(handle).map_err(|e| {
+                    Status::internal(format!(
                         "do_action_close_prepared_statement Error decoding handle: {e} {handle:?}"
-                    )));
-                }
-            }
+                    ))
+                })?,
+                Err(_) => return Ok(()),
+            },
+        };
+        info!("do_action_close_prepared_statement with handle {handle}");
+        if self.get_session(&request).is_ok() {
+            self.statements.remove(&handle);
         }
         Ok(())
     }
 
+    /// Handles [`KEEP_ALIVE_ACTION`], which keeps an idle session from expiring.
+    ///
+    /// It only validates the bearer token and refreshes the session, no query context is created.
+    /// The result body is a [`KeepAliveResult`] packed into an `Any`.
+    #[async_backtrace::framed]
+    async fn do_action_fallback(
+        &self,
+        request: Request<Action>,
+    ) -> Result<Response<<Self as FlightService>::DoActionStream>, Status> {
+        if request.get_ref().r#type != KEEP_ALIVE_ACTION {
+            return Err(Status::invalid_argument(format!(
+                "do_action: The defined request is invalid: {:?}",
+                request.get_ref().r#type
+            )));
+        }
+
+        let session = self.get_session(&request)?;
+        let remaining_ttl_secs = {
+            let status = session.get_status();
+            let mut status = status.write();
+            status.keep_alive();
+            status
+                .max_idle_time
+                .map(|d| d.as_secs())
+                .unwrap_or_default()
+        };
+        let result = KeepAliveResult { remaining_ttl_secs };
+        let output = futures::stream::iter(vec![Ok(arrow_flight::Result {
+            body: result.as_any().encode_to_vec().into(),
+        })]);
+        Ok(Response::new(Box::pin(output)))
+    }
+
+    async fn list_custom_actions(&self) -> Option<Vec<Result<ActionType, Status>>> {
+        Some(vec![Ok(ActionType {
+            r#type: KEEP_ALIVE_ACTION.to_string(),
+            description: "Keep the session alive, returns the seconds it can stay idle from now."
+                .to_string(),
+        })])
+    }
+
     #[async_backtrace::framed]
     async fn register_sql_info(&self, id: i32, result: &SqlInfo) {
         info!("register_sql_info({id}, {result:?})");
@@ -746,3 +982,117 @@ impl ProstMessageExt for FetchResults {
         }
     }
 }
+
+/// Ticket of a whole table scan, issued for `DescriptorType::Path` descriptors.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FetchTable {
+    #[prost(string, tag = "1")]
+    pub catalog: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub database: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub table: ::prost::alloc::string::String,
+    /// Projected columns, empty means all columns.
+    #[prost(string, repeated, tag = "4")]
+    pub columns: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(uint64, optional, tag = "5")]
+    pub limit: ::core::option::Option<u64>,
+}
+
+impl FetchTable {
+    const COLUMNS_HEADER: &'static str = "databend-columns";
+    const LIMIT_HEADER: &'static str = "databend-limit";
+
+    fn try_create(
+        descriptor: &FlightDescriptor,
+        metadata: &MetadataMap,
+    ) -> std::result::Result<Self, Status> {
+        let (catalog, database, table) = match descriptor.path.as_slice() {
+            [catalog, database, table] => (catalog.clone(), database.clone(), table.clone()),
+            path => {
+                return Err(Status::invalid_argument(format!(
+                    "expect descriptor path [catalog, database, table], got {path:?}"
+                )));
+            }
+        };
+
+        let columns = FlightSqlServiceImpl::get_header_value(metadata, Self::COLUMNS_HEADER)
+            .map(|v| {
+                v.split(',')
+                    .map(|c| c.trim().to_string())
+                    .filter(|c| !c.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let limit = FlightSqlServiceImpl::get_header_value(metadata, Self::LIMIT_HEADER)
+            .map(|v| {
+                v.trim().parse::<u64>().map_err(|e| {
+                    Status::invalid_argument(format!("invalid {}: {v}, {e}", Self::LIMIT_HEADER))
+                })
+            })
+            .transpose()?;
+
+        Ok(FetchTable {
+            catalog,
+            database,
+            table,
+            columns,
+            limit,
+        })
+    }
+}
+
+impl ProstMessageExt for FetchTable {
+    fn type_url() -> &'static str {
+        "type.googleapis.com/databend.flight.protocol.sql.FetchTable"
+    }
+
+    fn as_any(&self) -> Any {
+        Any {
+            type_url: FetchTable::type_url().to_string(),
+            value: ::prost::Message::encode_to_vec(self).into(),
+        }
+    }
+}
+
+/// Ticket of the next page of a cursor, see `databend-fetch-size`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FetchCursor {
+    #[prost(string, tag = "1")]
+    pub token: ::prost::alloc::string::String,
+}
+
+impl ProstMessageExt for FetchCursor {
+    fn type_url() -> &'static str {
+        "type.googleapis.com/databend.flight.protocol.sql.FetchCursor"
+    }
+
+    fn as_any(&self) -> Any {
+        Any {
+            type_url: FetchCursor::type_url().to_string(),
+            value: ::prost::Message::encode_to_vec(self).into(),
+        }
+    }
+}
+
+/// Result of [`KEEP_ALIVE_ACTION`].
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct KeepAliveResult {
+    /// Seconds the session can stay idle before it expires.
+    #[prost(uint64, tag = "1")]
+    pub remaining_ttl_secs: u64,
+}
+
+impl ProstMessageExt for KeepAliveResult {
+    fn type_url() -> &'static str {
+        "type.googleapis.com/databend.flight.protocol.sql.KeepAliveResult"
+    }
+
+    fn as_any(&self) -> Any {
+        Any {
+            type_url: KeepAliveResult::type_url().to_string(),
+            value: ::prost::Message::encode_to_vec(self).into(),
+        }
+    }
+}
@@ -13,21 +13,35 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
+use arrow_flight::BasicAuth;
+use arrow_flight::HandshakeRequest;
 use base64::prelude::BASE64_STANDARD;
 use base64::Engine;
+use chrono_tz::Tz;
+use databend_common_base::base::tokio;
 use databend_common_meta_app::principal::AuthInfo;
 use databend_common_meta_app::principal::UserIdentity;
 use databend_common_users::UserApiProvider;
+use prost::Message;
 use tonic::metadata::MetadataMap;
 use tonic::Request;
 use tonic::Status;
+use tonic::Streaming;
 
+use super::rate_limiter::RateLimitKind;
 use super::status;
+use super::MAX_QUERY_TAG_LEN;
 use crate::servers::flight_sql::flight_sql_service::FlightSqlServiceImpl;
+use crate::sessions::parse_labels;
 use crate::sessions::Session;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionType;
+use crate::sessions::APP_NAME_LABEL;
+
+/// How long the handshake waits for a payload carrying the credentials.
+const HANDSHAKE_PAYLOAD_TIMEOUT: Duration = Duration::from_secs(10);
 
 impl FlightSqlServiceImpl {
     pub(super) fn get_session<T>(&self, req: &Request<T>) -> Result<Arc<Session>, Status> {
@@ -45,7 +59,15 @@ impl FlightSqlServiceImpl {
         }
         let session_id = authorization[bearer.len()..].to_string();
 
-        if let Some(session) = self.sessions.lock().get(&session_id) {
+        let mut sessions = self.sessions.lock();
+        if let Some(session) = sessions.get(&session_id) {
+            // The session is closed by the idle sweep of the session manager.
+            if session.is_aborting() {
+                sessions.remove(&session_id);
+                return Err(Status::unauthenticated(format!(
+                    "session_id is closed: {session_id}"
+                )));
+            }
             Ok(session)
         } else {
             Err(Status::unauthenticated(format!(
@@ -54,6 +76,21 @@ impl FlightSqlServiceImpl {
         }
     }
 
+    /// Counts a request of the current user against its rate limit.
+    #[async_backtrace::framed]
+    pub(super) async fn throttle(
+        &self,
+        session: &Session,
+        kind: RateLimitKind,
+    ) -> Result<(), Status> {
+        let user = session
+            .get_current_user()
+            .map_err(|e| status!("Could not get current user", e))?;
+        self.rate_limiter
+            .acquire(&user.identity().display().to_string(), kind)
+            .await
+    }
+
     pub(super) fn get_header_value(metadata: &MetadataMap, key: &str) -> Option<String> {
         metadata
             .get(key)
@@ -61,6 +98,75 @@ impl FlightSqlServiceImpl {
             .map(|v| v.to_string())
     }
 
+    /// The label given by the client in `databend-query-tag`, cut to [`MAX_QUERY_TAG_LEN`] chars.
+    pub(super) fn get_query_tag(metadata: &MetadataMap) -> Option<String> {
+        Self::get_header_value(metadata, "databend-query-tag")
+            .filter(|v| !v.is_empty())
+            .map(|v| v.chars().take(MAX_QUERY_TAG_LEN).collect())
+    }
+
+    /// The labels of the session from `databend-labels`, like `team=data,env=prod`, and the
+    /// application name from `databend-app-name`.
+    pub(super) fn get_labels(metadata: &MetadataMap) -> Vec<(String, String)> {
+        let mut labels = Self::get_header_value(metadata, "databend-labels")
+            .map(|v| parse_labels(&v))
+            .unwrap_or_default();
+        if let Some(app_name) = Self::get_header_value(metadata, "databend-app-name") {
+            labels.push((APP_NAME_LABEL.to_string(), app_name));
+        }
+        labels
+    }
+
+    /// Whether the client asked to bypass the query result cache by `databend-disable-result-cache`.
+    pub(super) fn get_disable_result_cache(metadata: &MetadataMap) -> bool {
+        Self::get_header_value(metadata, "databend-disable-result-cache")
+            .is_some_and(|v| matches!(v.trim().to_lowercase().as_str(), "1" | "true"))
+    }
+
+    /// Rows per DoGet when the client reads the result through a cursor, from `databend-fetch-size`.
+    pub(super) fn get_fetch_size(metadata: &MetadataMap) -> Result<Option<usize>, Status> {
+        Self::get_header_value(metadata, "databend-fetch-size")
+            .map(|v| match v.trim().parse::<usize>() {
+                Ok(fetch_size) if fetch_size > 0 => Ok(fetch_size),
+                _ => Err(Status::invalid_argument(format!(
+                    "invalid databend-fetch-size: {v}, expect a positive integer"
+                ))),
+            })
+            .transpose()
+    }
+
+    /// A timezone of the tz database from `databend-timezone`.
+    pub(super) fn get_timezone_header(metadata: &MetadataMap) -> Result<Option<String>, Status> {
+        Self::get_header_value(metadata, "databend-timezone")
+            .map(|v| match v.trim().parse::<Tz>() {
+                Ok(tz) => Ok(tz.name().to_string()),
+                Err(_) => Err(Status::invalid_argument(format!(
+                    "invalid databend-timezone: {v}, expect a name of the tz database, \
+                     e.g. UTC, Asia/Shanghai, America/New_York, Europe/Berlin"
+                ))),
+            })
+            .transpose()
+    }
+
+    /// The timezone a request runs in and its timestamps are returned in, if the client asked
+    /// for one, either by the `databend-timezone` header of the request or at the handshake.
+    pub(super) fn get_timezone(
+        session: &Session,
+        metadata: &MetadataMap,
+    ) -> Result<Option<String>, Status> {
+        if let Some(timezone) = Self::get_timezone_header(metadata)? {
+            return Ok(Some(timezone));
+        }
+        if !session.get_status().read().timezone_in_results {
+            return Ok(None);
+        }
+        session
+            .get_settings()
+            .get_timezone()
+            .map(Some)
+            .map_err(|e| status!("Could not get timezone", e))
+    }
+
     pub(super) fn get_user_password(metadata: &MetadataMap) -> Result<(String, String), String> {
         let basic = "Basic ";
         let authorization = Self::get_header_value(metadata, "authorization")
@@ -83,6 +189,54 @@ impl FlightSqlServiceImpl {
         Ok((user.to_string(), pass.to_string()))
     }
 
+    /// Reads the credentials from the payloads of the handshake requests, for the clients which
+    /// send them in the handshake instead of the authorization header.
+    ///
+    /// Empty payloads are skipped, the first non-empty one is used and the rest are ignored, the
+    /// client may keep its side of the stream open until it gets the response.
+    #[async_backtrace::framed]
+    pub(super) async fn get_payload_user_password(
+        stream: &mut Streaming<HandshakeRequest>,
+    ) -> Result<(String, String), Status> {
+        loop {
+            let message = tokio::time::timeout(HANDSHAKE_PAYLOAD_TIMEOUT, stream.message())
+                .await
+                .map_err(|_| {
+                    Status::unauthenticated(format!(
+                        "no authorization header, and no handshake payload in {} seconds",
+                        HANDSHAKE_PAYLOAD_TIMEOUT.as_secs()
+                    ))
+                })??;
+            match message {
+                Some(request) if request.payload.is_empty() => continue,
+                Some(request) => {
+                    return Self::parse_handshake_payload(&request.payload).ok_or_else(|| {
+                        Status::unauthenticated("handshake payload carries no credentials")
+                    });
+                }
+                None => {
+                    return Err(Status::unauthenticated(
+                        "no credentials in the authorization header or the handshake payload",
+                    ));
+                }
+            }
+        }
+    }
+
+    /// The Java client sends a `BasicAuth` message, the Go client a plain `user:password`.
+    fn parse_handshake_payload(payload: &[u8]) -> Option<(String, String)> {
+        if let Ok(auth) = BasicAuth::decode(payload) {
+            if !auth.username.is_empty() {
+                return Some((auth.username, auth.password));
+            }
+        }
+        let (user, pass) = std::str::from_utf8(payload).ok()?.split_once(':')?;
+        if user.is_empty() {
+            return None;
+        }
+        Some((user.to_string(), pass.to_string()))
+    }
+
     #[async_backtrace::framed]
     pub(super) async fn auth_user_password(
         user: String,
@@ -0,0 +1,146 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use arrow_flight::flight_service_server::FlightService;
+use arrow_flight::Action;
+use arrow_flight::ActionType;
+use arrow_flight::Criteria;
+use arrow_flight::Empty;
+use arrow_flight::FlightData;
+use arrow_flight::FlightDescriptor;
+use arrow_flight::FlightInfo;
+use arrow_flight::HandshakeRequest;
+use arrow_flight::PollInfo;
+use arrow_flight::SchemaResult;
+use arrow_flight::Ticket;
+use tonic::Request;
+use tonic::Response;
+use tonic::Status;
+use tonic::Streaming;
+
+use super::FlightSqlServiceImpl;
+
+type Inner = FlightSqlServiceImpl;
+
+/// The FlightSQL service with the size of the tickets checked on their raw bytes.
+///
+/// arrow-flight decodes the ticket into a command before the handlers of `FlightSqlService`
+/// see it, so an oversized ticket is rejected here instead, ahead of any decoding. The whole
+/// message is bounded by the `max_decoding_message_size` of the tonic service.
+pub struct TicketSizeLimit {
+    inner: Inner,
+    max_ticket_size: usize,
+}
+
+impl TicketSizeLimit {
+    pub fn create(inner: Inner, max_ticket_size: usize) -> Self {
+        TicketSizeLimit {
+            inner,
+            max_ticket_size,
+        }
+    }
+
+    fn check_ticket_size(&self, ticket: &Ticket) -> Result<(), Status> {
+        let ticket_size = ticket.ticket.len();
+        if ticket_size > self.max_ticket_size {
+            return Err(Status::invalid_argument(format!(
+                "ticket of {ticket_size} bytes exceeds flight_sql_max_ticket_size {}",
+                self.max_ticket_size
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[tonic::async_trait]
+impl FlightService for TicketSizeLimit {
+    type HandshakeStream = <Inner as FlightService>::HandshakeStream;
+    type ListFlightsStream = <Inner as FlightService>::ListFlightsStream;
+    type DoGetStream = <Inner as FlightService>::DoGetStream;
+    type DoPutStream = <Inner as FlightService>::DoPutStream;
+    type DoActionStream = <Inner as FlightService>::DoActionStream;
+    type ListActionsStream = <Inner as FlightService>::ListActionsStream;
+    type DoExchangeStream = <Inner as FlightService>::DoExchangeStream;
+
+    async fn handshake(
+        &self,
+        request: Request<Streaming<HandshakeRequest>>,
+    ) -> Result<Response<Self::HandshakeStream>, Status> {
+        self.inner.handshake(request).await
+    }
+
+    async fn list_flights(
+        &self,
+        request: Request<Criteria>,
+    ) -> Result<Response<Self::ListFlightsStream>, Status> {
+        self.inner.list_flights(request).await
+    }
+
+    async fn get_flight_info(
+        &self,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        self.inner.get_flight_info(request).await
+    }
+
+    async fn poll_flight_info(
+        &self,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<PollInfo>, Status> {
+        self.inner.poll_flight_info(request).await
+    }
+
+    async fn get_schema(
+        &self,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<SchemaResult>, Status> {
+        self.inner.get_schema(request).await
+    }
+
+    async fn do_get(
+        &self,
+        request: Request<Ticket>,
+    ) -> Result<Response<Self::DoGetStream>, Status> {
+        self.check_ticket_size(request.get_ref())?;
+        self.inner.do_get(request).await
+    }
+
+    async fn do_put(
+        &self,
+        request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoPutStream>, Status> {
+        self.inner.do_put(request).await
+    }
+
+    async fn do_action(
+        &self,
+        request: Request<Action>,
+    ) -> Result<Response<Self::DoActionStream>, Status> {
+        self.inner.do_action(request).await
+    }
+
+    async fn list_actions(
+        &self,
+        request: Request<Empty>,
+    ) -> Result<Response<Self::ListActionsStream>, Status> {
+        self.inner.list_actions(request).await
+    }
+
+    async fn do_exchange(
+        &self,
+        request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoExchangeStream>, Status> {
+        self.inner.do_exchange(request).await
+    }
+}
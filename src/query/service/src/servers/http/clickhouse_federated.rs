@@ -12,19 +12,141 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use ctor::ctor;
+use std::sync::Arc;
+use std::sync::LazyLock;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_settings::Settings;
+use log::debug;
 use regex::Regex;
 
-pub struct ClickHouseFederated {}
+use crate::servers::federated_helper::FederatedHelper;
+use crate::servers::federated_helper::FederatedRule;
+use crate::servers::federated_helper::FederatedRules;
+
+pub struct ClickHouseFederated {
+    settings: Option<Arc<Settings>>,
+}
 
-#[ctor]
-static FORMAT_REGEX: Regex = Regex::new(r".*(?i)FORMAT\s*([[:alpha:]]*)\s*;?$").unwrap();
+static FORMAT_REGEX: LazyLock<Result<Regex>> =
+    LazyLock::new(|| FederatedHelper::regex(r".*FORMAT\s*([[:alpha:]]*)\s*;?$"));
 
 impl ClickHouseFederated {
-    pub fn get_format(query: &str) -> Option<String> {
-        match FORMAT_REGEX.captures(query) {
+    pub fn create() -> Self {
+        ClickHouseFederated { settings: None }
+    }
+
+    /// The session values, e.g. `timezone()`, are answered from the session settings.
+    pub fn create_with_settings(settings: Arc<Settings>) -> Self {
+        ClickHouseFederated {
+            settings: Some(settings),
+        }
+    }
+
+    /// Compile the rules, so a malformed pattern fails the handler startup.
+    pub fn check_rules() -> Result<()> {
+        FORMAT_REGEX.as_ref().map_err(Clone::clone)?;
+        ClickHouseFederated::create().check("")?;
+        Ok(())
+    }
+
+    pub fn get_format(query: &str) -> Result<Option<String>> {
+        let format_regex = FORMAT_REGEX.as_ref().map_err(Clone::clone)?;
+        Ok(match format_regex.captures(query) {
             Some(x) => x.get(1).map(|s| s.as_str().to_owned()),
             None => None,
+        })
+    }
+
+    // Build block for select function, the column is named as the function like ClickHouse.
+    // Format:
+    // |function_name|
+    // |value|
+    fn select_function_block(name: &str, value: &str) -> Option<(TableSchemaRef, DataBlock)> {
+        let schema = TableSchemaRefExt::create(vec![TableField::new(name, TableDataType::String)]);
+        let block = DataBlock::new_from_columns(vec![StringType::from_data(vec![value])]);
+        Some((schema, block))
+    }
+
+    /// The built-in rules of the ClickHouse handler, they are built once by `FederatedRules::clickhouse`.
+    pub(crate) fn builtin_rules() -> Result<Vec<FederatedRule>> {
+        Ok(vec![
+            // The probes may end with the FORMAT of the result, e.g. `SELECT version() FORMAT JSON`.
+            (
+                "select_version",
+                FederatedHelper::regex(r"^SELECT\s+version\(\)(\s+FORMAT\s+\w+)?\s*;?\s*$")?,
+                ClickHouseFederated::select_function_block(
+                    "version()",
+                    &FederatedHelper::clickhouse_server_version(),
+                ),
+            ),
+            // clickhouse-client, the level of the server logs sent to the client.
+            (
+                "set_send_logs_level",
+                FederatedHelper::regex(r"^SET\s+send_logs_level\s*=")?,
+                None,
+            ),
+            // The settings of the ClickHouse server which Databend doesn't have.
+            (
+                "set_ignored_settings",
+                FederatedHelper::regex(
+                    r"^SET\s+(log_comment|log_queries|allow_experimental_\w+)\s*=",
+                )?,
+                None,
+            ),
+        ])
+    }
+
+    fn federated_select_timezone_check(
+        &self,
+        query: &str,
+    ) -> Result<Option<(TableSchemaRef, DataBlock)>> {
+        static SELECT_TIMEZONE_RULE: LazyLock<Result<Regex>> = LazyLock::new(|| {
+            FederatedHelper::regex(r"^SELECT\s+timezone\(\)(\s+FORMAT\s+\w+)?\s*;?\s*$")
+        });
+
+        let rule = SELECT_TIMEZONE_RULE.as_ref().map_err(Clone::clone)?;
+        if !rule.is_match(FederatedHelper::strip_leading_comments(query)) {
+            return Ok(None);
+        }
+        let timezone = FederatedHelper::session_timezone(self.settings.as_deref())?;
+        Ok(ClickHouseFederated::select_function_block(
+            "timezone()",
+            &timezone,
+        ))
+    }
+
+    // Check the query is a probe of the ClickHouse clients.
+    // The result is answered without planning the query.
+    pub fn check(&self, query: &str) -> Result<Option<(TableSchemaRef, DataBlock)>> {
+        // A long query is only matched in full if its prefix is matched by a rule,
+        // the prefix may be cut in the middle of a probe, so an error is not a miss.
+        let prefix = FederatedHelper::query_prefix(query);
+        if prefix.len() < query.len() && matches!(self.match_rule(prefix), Ok(None)) {
+            return Ok(None);
+        }
+
+        Ok(self.match_rule(query)?.map(|(rule, block)| {
+            debug!("ClickHouse federated rule '{}' matched: {}", rule, query);
+            block
+        }))
+    }
+
+    fn match_rule(&self, query: &str) -> Result<Option<(&str, (TableSchemaRef, DataBlock))>> {
+        // First to check the session values.
+        if let Some(block) = self.federated_select_timezone_check(query)? {
+            return Ok(Some(("select_timezone", block)));
         }
+
+        // Then to check the built-in rules.
+        let rules = FederatedRules::clickhouse()?;
+        Ok(rules.check(query))
     }
 }
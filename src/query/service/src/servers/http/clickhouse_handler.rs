@@ -23,10 +23,13 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_exception::ToErrorCode;
 use databend_common_expression::infer_table_schema;
+use databend_common_expression::DataBlock;
 use databend_common_expression::DataSchemaRef;
+use databend_common_expression::TableSchemaRef;
 use databend_common_formats::ClickhouseFormatType;
 use databend_common_formats::FileFormatOptionsExt;
 use databend_common_formats::FileFormatTypeExt;
+use databend_common_settings::Settings;
 use databend_common_sql::Planner;
 use futures::StreamExt;
 use futures::TryStreamExt;
@@ -55,12 +58,15 @@ use crate::interpreters::interpreter_plan_sql;
 use crate::interpreters::InterpreterFactory;
 use crate::interpreters::InterpreterPtr;
 use crate::servers::http::middleware::sanitize_request_headers;
+use crate::servers::http::ClickHouseFederated;
 use crate::servers::http::v1::HttpQueryContext;
+use crate::sessions::parse_labels;
 use crate::sessions::QueriesQueueManager;
 use crate::sessions::QueryContext;
 use crate::sessions::QueryEntry;
 use crate::sessions::SessionType;
 use crate::sessions::TableContext;
+use crate::sessions::APP_NAME_LABEL;
 
 // accept all clickhouse params, so they do not go to settings.
 #[derive(Serialize, Deserialize, Debug)]
@@ -88,6 +94,9 @@ pub struct StatementHandlerParams {
     // in secs
     #[allow(unused)]
     with_stacktrace: Option<u8>,
+    /// The labels of the session, like `team=data,env=prod`.
+    labels: Option<String>,
+    app_name: Option<String>,
     #[serde(flatten)]
     settings: HashMap<String, String>,
 }
@@ -100,6 +109,14 @@ impl StatementHandlerParams {
     pub fn query(&self) -> String {
         self.query.clone().unwrap_or_default()
     }
+
+    pub fn labels(&self) -> Vec<(String, String)> {
+        let mut labels = self.labels.as_deref().map(parse_labels).unwrap_or_default();
+        if let Some(app_name) = &self.app_name {
+            labels.push((APP_NAME_LABEL.to_string(), app_name.clone()));
+        }
+        labels
+    }
 }
 
 async fn execute(
@@ -233,6 +250,7 @@ pub async fn clickhouse_handler_get(
         if let Some(db) = &params.database {
             session.set_current_database(db.clone());
         }
+        session.add_labels(params.labels());
         let context = session
             .create_query_context()
             .await
@@ -255,6 +273,16 @@ pub async fn clickhouse_handler_get(
 
         let default_format = get_default_format(&params, headers).map_err(BadRequest)?;
         let sql = params.query();
+        if let Some((schema, block)) = ClickHouseFederated::create_with_settings(settings.clone())
+            .check(&sql)
+            .map_err(BadRequest)?
+        {
+            let format = ClickHouseFederated::get_format(&sql).map_err(BadRequest)?;
+            let format = get_format_with_default(format, default_format)?;
+            return federated_response(schema, block, format, &params, &settings)
+                .map_err(InternalServerError);
+        }
+
         // Use interpreter_plan_sql, we can write the query log if an error occurs.
         let (plan, extras) = interpreter_plan_sql(context.clone(), &sql)
             .await
@@ -300,6 +328,7 @@ pub async fn clickhouse_handler_post(
         if let Some(db) = &params.database {
             session.set_current_database(db.clone());
         }
+        session.add_labels(params.labels());
         let ctx = session
             .create_query_context()
             .await
@@ -336,6 +365,16 @@ pub async fn clickhouse_handler_post(
         };
         info!("receive clickhouse http post, (query + body) = {}", &msg);
 
+        if let Some((schema, block)) = ClickHouseFederated::create_with_settings(settings.clone())
+            .check(&sql)
+            .map_err(BadRequest)?
+        {
+            let format = ClickHouseFederated::get_format(&sql).map_err(BadRequest)?;
+            let format = get_format_with_default(format, default_format)?;
+            return federated_response(schema, block, format, &params, &settings)
+                .map_err(InternalServerError);
+        }
+
         let mut planner = Planner::new(ctx.clone());
         let (mut plan, extras) = planner
             .plan_sql(&sql)
@@ -384,6 +423,26 @@ pub fn clickhouse_router() -> impl Endpoint {
         .with(poem::middleware::Compression::default())
 }
 
+// The answer of a federated probe, the query is not planned.
+fn federated_response(
+    schema: TableSchemaRef,
+    block: DataBlock,
+    format: ClickhouseFormatType,
+    params: &StatementHandlerParams,
+    settings: &Settings,
+) -> Result<WithContentType<Body>> {
+    let content_type = format.typ.get_content_type();
+    let mut output_format =
+        FileFormatOptionsExt::get_output_format_from_clickhouse_format(format, schema, settings)?;
+    let mut body = output_format.serialize_prefix()?;
+    body.extend(output_format.serialize_block(&block)?);
+    body.extend(output_format.finalize()?);
+    if params.compress() {
+        body = compress_block(body)?;
+    }
+    Ok(Body::from(body).with_content_type(content_type))
+}
+
 // default codec is always lz4
 fn compress_block(input: Vec<u8>) -> Result<Vec<u8>> {
     if input.is_empty() {
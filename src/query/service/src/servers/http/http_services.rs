@@ -43,6 +43,7 @@ use crate::servers::http::v1::clickhouse_router;
 use crate::servers::http::v1::list_suggestions;
 use crate::servers::http::v1::login_handler;
 use crate::servers::http::v1::query_route;
+use crate::servers::http::ClickHouseFederated;
 use crate::servers::Server;
 
 #[derive(Copy, Clone)]
@@ -183,6 +184,10 @@ impl Server for HttpHandler {
 
     #[async_backtrace::framed]
     async fn start(&mut self, listening: SocketAddr) -> Result<SocketAddr, ErrorCode> {
+        if let HttpHandlerKind::Clickhouse = self.kind {
+            ClickHouseFederated::check_rules()?;
+        }
+
         let config = GlobalConfig::instance();
 
         let res = match config.query.http_handler_tls_server_key.is_empty()
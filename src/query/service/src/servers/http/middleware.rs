@@ -55,13 +55,18 @@ use super::v1::HttpQueryContext;
 use crate::auth::AuthMgr;
 use crate::auth::Credential;
 use crate::servers::HttpHandlerKind;
+use crate::sessions::parse_labels;
+use crate::sessions::Session;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionType;
+use crate::sessions::APP_NAME_LABEL;
 
 const DEDUPLICATE_LABEL: &str = "X-DATABEND-DEDUPLICATE-LABEL";
 const USER_AGENT: &str = "User-Agent";
 const QUERY_ID: &str = "X-DATABEND-QUERY-ID";
 const NODE_ID: &str = "X-DATABEND-NODE-ID";
+const APP_NAME: &str = "X-DATABEND-APP-NAME";
+const LABELS: &str = "X-DATABEND-LABELS";
 
 const TRACE_PARENT: &str = "traceparent";
 
@@ -253,6 +258,7 @@ impl<E> HTTPSessionEndpoint<E> {
         self.auth_manager.auth(&mut session, &credential).await?;
 
         let session = session_manager.register_session(session)?;
+        add_session_labels(&session, req.headers());
 
         let deduplicate_label = req
             .headers()
@@ -295,6 +301,17 @@ impl<E> HTTPSessionEndpoint<E> {
     }
 }
 
+/// Sets the labels of the session from the `X-DATABEND-LABELS` header, like `team=data,env=prod`,
+/// and the application name from `X-DATABEND-APP-NAME`.
+fn add_session_labels(session: &Session, headers: &HeaderMap) {
+    if let Some(labels) = headers.get(LABELS).and_then(|v| v.to_str().ok()) {
+        session.add_labels(parse_labels(labels));
+    }
+    if let Some(app_name) = headers.get(APP_NAME).and_then(|v| v.to_str().ok()) {
+        session.add_labels([(APP_NAME_LABEL.to_string(), app_name.to_string())]);
+    }
+}
+
 impl<E: Endpoint> Endpoint for HTTPSessionEndpoint<E> {
     type Output = Response;
 
@@ -28,6 +28,7 @@ pub use http_query_handlers::QueryResponse;
 pub use http_query_handlers::QueryStats;
 pub(crate) use login::login_handler;
 pub use query::ExecuteStateKind;
+pub use query::Expirable;
 pub use query::ExpiringMap;
 pub use query::ExpiringState;
 pub use query::HttpQueryContext;
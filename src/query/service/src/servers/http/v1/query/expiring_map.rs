@@ -122,6 +122,14 @@ where
         self.map.get(k).map(|i| i.value().value.clone())
     }
 
+    pub fn entries(&self) -> Vec<(K, V)>
+    where K: Clone {
+        self.map
+            .iter()
+            .map(|i| (i.key().clone(), i.value().value.clone()))
+            .collect()
+    }
+
     pub fn remove<Q: ?Sized>(&mut self, k: &Q)
     where
         K: Borrow<Q>,
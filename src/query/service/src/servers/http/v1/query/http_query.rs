@@ -355,6 +355,8 @@ impl HttpQuery {
                     return Err(ErrorCode::Internal("last query stop but not released"));
                 }
             }
+            // The labels in the headers of this request are added to the ones of the session.
+            session.add_labels(ctx.session.get_labels());
             session
         } else {
             ctx.upgrade_session(SessionType::HTTPQuery)
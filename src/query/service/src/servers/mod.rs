@@ -20,13 +20,17 @@ pub use server::ShutdownHandle;
 pub use self::flight_sql::FlightSQLServer;
 pub use self::http::HttpHandler;
 pub use self::http::HttpHandlerKind;
+pub use self::mysql::mysql_version_id;
+pub use self::mysql::strip_mysql_comments;
 pub use self::mysql::MySQLConnection;
 pub use self::mysql::MySQLFederated;
+pub use self::mysql::MySQLFederatedRules;
 pub use self::mysql::MySQLHandler;
 pub use self::mysql::MySQLTlsConfig;
+pub use self::mysql::MySQLTransactionIsolation;
 
 pub mod admin;
-pub(crate) mod federated_helper;
+pub mod federated_helper;
 pub mod flight;
 pub mod flight_sql;
 pub mod http;
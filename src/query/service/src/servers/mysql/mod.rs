@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod mysql_comments;
 mod mysql_federated;
+mod mysql_federated_rules;
 mod mysql_handler;
 mod mysql_interactive_worker;
 mod mysql_session;
@@ -21,9 +23,11 @@ mod reject_connection;
 mod tls;
 mod writers;
 
+pub use self::mysql_comments::mysql_version_id;
+pub use self::mysql_comments::strip_mysql_comments;
 pub use self::mysql_federated::MySQLFederated;
+pub use self::mysql_federated::MySQLTransactionIsolation;
+pub use self::mysql_federated_rules::MySQLFederatedRules;
 pub use self::mysql_handler::MySQLHandler;
 pub use self::mysql_session::MySQLConnection;
 pub use self::tls::MySQLTlsConfig;
-
-const MYSQL_VERSION: &str = "8.0.26";
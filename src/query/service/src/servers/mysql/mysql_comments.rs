@@ -0,0 +1,143 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Cow;
+
+/// The MySQL version id of a version string, e.g. `8.0.26-databend-v1.2.3` is 80026.
+pub fn mysql_version_id(version: &str) -> Option<u32> {
+    let version = version.split('-').next()?;
+    let mut parts = version.split('.').map(|p| p.parse::<u32>().ok());
+    let major = parts.next()??;
+    let minor = parts.next().unwrap_or(Some(0))?;
+    let patch = parts.next().unwrap_or(Some(0))?;
+    Some(major * 10000 + minor * 100 + patch)
+}
+
+/// Rewrite the MySQL comments which are not plain comments:
+/// - `/*! ... */` and `/*!NNNNN ... */` are executable, the content is inlined
+///   when NNNNN is not greater than `version_id`, otherwise it is dropped.
+/// - `/*+ ... */` optimizer hints are dropped, except the `SET_VAR` hints Databend applies.
+///
+/// The string literals, quoted identifiers and line comments are kept as is,
+/// a `/*` inside of them doesn't start a comment.
+pub fn strip_mysql_comments(query: &str, version_id: u32) -> Cow<'_, str> {
+    if !query.contains("/*!") && !query.contains("/*+") {
+        return Cow::Borrowed(query);
+    }
+
+    let bytes = query.as_bytes();
+    let mut res = String::with_capacity(query.len());
+    // The text from `copied` to `pos` is not written to `res` yet.
+    let mut copied = 0;
+    let mut pos = 0;
+    // Inside of an executable comment, `Some(true)` if its content is inlined.
+    let mut executable = None;
+    let mut rewritten = false;
+
+    while pos < bytes.len() {
+        match bytes[pos] {
+            quote @ (b'\'' | b'"' | b'`') => pos = skip_quoted(bytes, pos, quote),
+            b'#' => pos = skip_line(bytes, pos),
+            b'-' if bytes[pos..].starts_with(b"--")
+                && bytes.get(pos + 2).map_or(true, |c| c.is_ascii_whitespace()) =>
+            {
+                pos = skip_line(bytes, pos)
+            }
+            b'*' if executable.is_some() && bytes.get(pos + 1) == Some(&b'/') => {
+                if executable == Some(true) {
+                    res.push_str(&query[copied..pos]);
+                }
+                res.push(' ');
+                pos += 2;
+                copied = pos;
+                executable = None;
+            }
+            b'/' if bytes.get(pos + 1) == Some(&b'*') => match bytes.get(pos + 2) {
+                Some(b'!') if executable.is_none() => {
+                    res.push_str(&query[copied..pos]);
+                    res.push(' ');
+                    pos += 3;
+
+                    let digits = bytes[pos..]
+                        .iter()
+                        .take(5)
+                        .take_while(|c| c.is_ascii_digit())
+                        .count();
+                    let inline = match digits {
+                        5 => query[pos..pos + 5].parse::<u32>().unwrap_or(0) <= version_id,
+                        _ => true,
+                    };
+                    if digits == 5 {
+                        pos += 5;
+                    }
+                    copied = pos;
+                    executable = Some(inline);
+                    rewritten = true;
+                }
+                Some(b'+') if !is_set_var_hint(&query[pos + 3..]) => {
+                    let Some(end) = query[pos + 3..].find("*/") else {
+                        break;
+                    };
+                    if executable != Some(false) {
+                        res.push_str(&query[copied..pos]);
+                        res.push(' ');
+                    }
+                    pos += 3 + end + 2;
+                    copied = pos;
+                    rewritten = true;
+                }
+                _ => match query[pos + 2..].find("*/") {
+                    Some(end) => pos += 2 + end + 2,
+                    None => break,
+                },
+            },
+            _ => pos += 1,
+        }
+    }
+
+    if !rewritten {
+        return Cow::Borrowed(query);
+    }
+    if executable != Some(false) {
+        res.push_str(&query[copied..]);
+    }
+    Cow::Owned(res.trim().to_string())
+}
+
+// The position after the closing quote, a quote is escaped by a backslash or doubled.
+fn skip_quoted(bytes: &[u8], start: usize, quote: u8) -> usize {
+    let mut pos = start + 1;
+    while pos < bytes.len() {
+        match bytes[pos] {
+            b'\\' if quote != b'`' => pos += 2,
+            c if c == quote && bytes.get(pos + 1) == Some(&quote) => pos += 2,
+            c if c == quote => return pos + 1,
+            _ => pos += 1,
+        }
+    }
+    bytes.len()
+}
+
+fn skip_line(bytes: &[u8], start: usize) -> usize {
+    match bytes[start..].iter().position(|c| *c == b'\n') {
+        Some(end) => start + end + 1,
+        None => bytes.len(),
+    }
+}
+
+fn is_set_var_hint(hint: &str) -> bool {
+    hint.trim_start()
+        .get(..7)
+        .map_or(false, |name| name.eq_ignore_ascii_case("SET_VAR"))
+}
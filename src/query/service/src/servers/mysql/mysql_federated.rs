@@ -12,12 +12,23 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
 use std::sync::Arc;
 use std::sync::LazyLock;
+use std::time::Instant;
 
+use chrono_tz::TZ_VARIANTS;
+use databend_common_ast::ast::Identifier;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::filter::like_pattern_to_matcher;
+use databend_common_expression::filter::LikeMatcher;
+use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
 use databend_common_expression::utils::FromData;
+use databend_common_expression::Column;
 use databend_common_expression::DataBlock;
 use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRef;
@@ -25,16 +36,565 @@ use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchemaRef;
 use databend_common_expression::TableSchemaRefExt;
+use databend_common_metrics::mysql::metrics_inc_federated_rule_matched;
+use databend_common_settings::Settings;
+use databend_common_sql::normalize_identifier;
+use databend_common_sql::NameResolutionContext;
+use log::debug;
+use parking_lot::RwLock;
 use regex::Regex;
 
+use crate::servers::federated_helper::FederatedCaptures;
 use crate::servers::federated_helper::FederatedHelper;
-use crate::servers::federated_helper::LazyBlockFunc;
+use crate::servers::federated_helper::FederatedRule;
+use crate::servers::federated_helper::FederatedRules;
+use crate::servers::mysql::MySQLFederatedRules;
+use crate::sessions::SessionWarnings;
+use crate::sessions::WarningLevel;
+use crate::sessions::WARN_UNKNOWN_VARIABLE;
 
-pub struct MySQLFederated {}
+enum VariableValue {
+    UInt64(u64),
+    String(String),
+}
+
+impl VariableValue {
+    fn data_type(&self) -> TableDataType {
+        match self {
+            VariableValue::UInt64(_) => TableDataType::Number(NumberDataType::UInt64),
+            VariableValue::String(_) => TableDataType::String,
+        }
+    }
+
+    fn to_column(&self) -> Column {
+        match self {
+            VariableValue::UInt64(v) => UInt64Type::from_data(vec![*v]),
+            VariableValue::String(v) => StringType::from_data(vec![v.as_str()]),
+        }
+    }
+}
+
+impl Display for VariableValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VariableValue::UInt64(v) => write!(f, "{}", v),
+            VariableValue::String(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+fn uint64(v: u64) -> Result<VariableValue> {
+    Ok(VariableValue::UInt64(v))
+}
+
+fn string(v: &str) -> Result<VariableValue> {
+    Ok(VariableValue::String(v.to_string()))
+}
+
+fn server_version() -> Result<VariableValue> {
+    string(&FederatedHelper::mysql_server_version())
+}
+
+type VariableFunc = fn(Option<&Settings>) -> Result<VariableValue>;
+
+// The MySQL variables probed by the drivers and tools, sorted by name.
+// The value is read from the session settings where the name maps to one,
+// the constants are what Databend behaves like.
+static MYSQL_VARIABLES: &[(&str, VariableFunc)] = &[
+    ("auto_increment_increment", |_| uint64(1)),
+    ("autocommit", |settings| match settings {
+        Some(settings) => uint64(settings.get_autocommit()? as u64),
+        None => uint64(1),
+    }),
+    ("character_set_client", |_| string("utf8mb4")),
+    ("character_set_connection", |_| string("utf8mb4")),
+    ("character_set_results", |_| string("utf8mb4")),
+    ("character_set_database", |_| string("utf8mb4")),
+    ("character_set_server", |_| string("utf8mb4")),
+    ("collation_connection", |_| string("utf8mb4_general_ci")),
+    // mysqldump reads it after `USE db`.
+    ("collation_database", |_| string("utf8mb4_general_ci")),
+    ("collation_server", |_| string("utf8mb4_general_ci")),
+    ("init_connect", |_| string("")),
+    ("interactive_timeout", |settings| match settings {
+        Some(settings) => uint64(settings.get_idle_transaction_timeout_secs()?),
+        None => uint64(31536000),
+    }),
+    ("license", |_| string("Apache-2.0")),
+    // Quoted identifiers are lowercased too, so the names are case-insensitive.
+    ("lower_case_table_names", |settings| match settings {
+        Some(settings) if !settings.get_quoted_ident_case_sensitive()? => uint64(1),
+        _ => uint64(0),
+    }),
+    // 128M
+    ("max_allowed_packet", |_| uint64(134217728)),
+    ("max_execution_time", |settings| match settings {
+        Some(settings) => uint64(
+            settings
+                .get_max_execute_time_in_seconds()?
+                .saturating_mul(1000),
+        ),
+        None => uint64(0),
+    }),
+    // MySQL ODBC.
+    ("net_read_timeout", |_| uint64(31536000)),
+    ("net_write_timeout", |_| uint64(31536000)),
+    ("performance_schema", |_| uint64(0)),
+    // Connector/J reads them from the servers before 8.0.3.
+    ("query_cache_size", |_| uint64(0)),
+    ("query_cache_type", |_| string("OFF")),
+    // sqlalchemy < 1.4.30
+    ("sql_mode", |_| {
+        string(
+            "ONLY_FULL_GROUP_BY STRICT_TRANS_TABLES NO_ZERO_IN_DATE NO_ZERO_DATE ERROR_FOR_DIVISION_BY_ZERO NO_ENGINE_SUBSTITUTION",
+        )
+    }),
+    // MySQL uses the max u64 for no limit.
+    ("sql_select_limit", |settings| match settings {
+        Some(settings) if settings.get_max_result_rows()? != 0 => {
+            uint64(settings.get_max_result_rows()?)
+        }
+        _ => uint64(u64::MAX),
+    }),
+    ("system_time_zone", |_| string("UTC")),
+    ("time_zone", |settings| {
+        string(&FederatedHelper::session_timezone(settings)?)
+    }),
+    // DBeaver. The session value of the isolation is the one recorded by the SET.
+    ("transaction_isolation", |_| string(DEFAULT_ISOLATION)),
+    ("transaction_read_only", |_| uint64(0)),
+    ("tx_isolation", |_| string(DEFAULT_ISOLATION)),
+    ("tx_read_only", |_| uint64(0)),
+    // The same as the handshake and VERSION(), some clients parse it for the features.
+    ("version", |_| server_version()),
+    ("version_comment", |_| server_version()),
+    ("wait_timeout", |settings| match settings {
+        Some(settings) => uint64(settings.get_idle_transaction_timeout_secs()?),
+        None => uint64(31536000),
+    }),
+];
+
+// The handler startup forces it in `check_rules`, the `Uptime` status counts from it.
+static SERVER_STARTED_AT: LazyLock<Instant> = LazyLock::new(Instant::now);
+
+// The MySQL status variables of SHOW STATUS, sorted by name.
+// MySQL ODBC reads `Ssl_cipher` at connect, empty is a connection without TLS.
+static MYSQL_STATUS: &[(&str, fn() -> Result<VariableValue>)] = &[
+    ("Ssl_cipher", || string("")),
+    ("Ssl_version", || string("")),
+    ("Uptime", || uint64(SERVER_STARTED_AT.elapsed().as_secs())),
+];
+
+type SetVariableFunc = fn(&Settings, &str) -> Result<()>;
+
+// The MySQL variables a SET applies to the session settings, the value is unquoted and lowercase.
+// A value which can't be honored safely is an error rather than ignored.
+static MYSQL_SET_VARIABLES: &[(&str, SetVariableFunc)] = &[
+    // PyMySQL and mysqlclient send `SET autocommit=0` at connect,
+    // the statements then run in a transaction until COMMIT or ROLLBACK.
+    ("autocommit", |settings, value| {
+        let autocommit = match value {
+            "1" | "on" | "true" | "default" => 1,
+            "0" | "off" | "false" => 0,
+            _ => {
+                return Err(ErrorCode::WrongValueForVariable(format!(
+                    "Variable 'autocommit' can't be set to the value of '{}'",
+                    value
+                )));
+            }
+        };
+        settings.set_setting("autocommit".to_string(), autocommit.to_string())
+    }),
+    ("interactive_timeout", set_idle_timeout),
+    ("max_execution_time", |settings, value| {
+        // MySQL is in milliseconds, round up to the seconds.
+        let millis = match value {
+            "default" => 0,
+            _ => parse_u64("max_execution_time", value)?,
+        };
+        settings.set_setting(
+            "max_execute_time_in_seconds".to_string(),
+            millis.div_ceil(1000).to_string(),
+        )
+    }),
+    ("sql_select_limit", |settings, value| {
+        // MySQL uses the max u64 for no limit.
+        let limit = match value {
+            "default" => 0,
+            _ => match parse_u64("sql_select_limit", value)? {
+                u64::MAX => 0,
+                limit => limit,
+            },
+        };
+        settings.set_setting("max_result_rows".to_string(), limit.to_string())
+    }),
+    ("time_zone", |settings, value| {
+        settings.set_setting("timezone".to_string(), mysql_time_zone(value)?)
+    }),
+    ("transaction_read_only", set_read_only),
+    ("tx_read_only", set_read_only),
+    ("wait_timeout", set_idle_timeout),
+];
+
+// The MySQL variables which are harmless to ignore, a SET of them is a no-op with a warning.
+static MYSQL_IGNORED_VARIABLES: &[&str] = &[
+    "character_set_client",
+    "character_set_connection",
+    "character_set_results",
+    "character_set_server",
+    "collation_connection",
+    "collation_server",
+    "foreign_key_checks",
+    "max_allowed_packet",
+    "names",
+    "net_read_timeout",
+    "net_write_timeout",
+    "session_track_schema",
+    "session_track_state_change",
+    "session_track_system_variables",
+    "sql_auto_is_null",
+    "sql_log_bin",
+    "sql_mode",
+    "sql_notes",
+    "sql_quote_show_create",
+    "sql_safe_updates",
+    "unique_checks",
+];
+
+// The variables of the isolation level, `transaction_isolation` of MySQL 8.0 and `tx_isolation` of 5.7.
+static MYSQL_ISOLATION_VARIABLES: &[&str] = &["transaction_isolation", "tx_isolation"];
+
+// The default of MySQL, the transactions of Databend read the snapshot of their start like it.
+const DEFAULT_ISOLATION: &str = "REPEATABLE-READ";
+
+// The isolation levels of MySQL, the value of the variables is the one with the dashes.
+static MYSQL_ISOLATION_LEVELS: &[&str] = &[
+    "READ-UNCOMMITTED",
+    "READ-COMMITTED",
+    "REPEATABLE-READ",
+    "SERIALIZABLE",
+];
+
+// SHOW CHARSET, the strings are UTF-8 and `binary` is what mysqldump switches the results to.
+// (Charset, Description, Default collation, Maxlen)
+static MYSQL_CHARSETS: &[(&str, &str, &str, u64)] = &[
+    ("binary", "Binary pseudo charset", "binary", 1),
+    ("utf8", "UTF-8 Unicode", "utf8_general_ci", 3),
+    ("utf8mb4", "UTF-8 Unicode", "utf8mb4_general_ci", 4),
+];
+
+// SHOW COLLATION, the ids are the MySQL ones as the drivers map them to the charsets.
+// (Collation, Charset, Id, Default)
+static MYSQL_COLLATIONS: &[(&str, &str, u64, bool)] = &[
+    ("binary", "binary", 63, true),
+    ("utf8_bin", "utf8", 83, false),
+    ("utf8_general_ci", "utf8", 33, true),
+    ("utf8mb4_bin", "utf8mb4", 46, false),
+    ("utf8mb4_general_ci", "utf8mb4", 45, true),
+];
+
+fn parse_u64(name: &str, value: &str) -> Result<u64> {
+    value.parse::<u64>().map_err(|_| {
+        ErrorCode::WrongValueForVariable(format!(
+            "Variable '{}' can't be set to the value of '{}'",
+            name, value
+        ))
+    })
+}
+
+fn set_idle_timeout(settings: &Settings, value: &str) -> Result<()> {
+    match value {
+        "default" => Ok(()),
+        _ => settings.set_setting(
+            "idle_transaction_timeout_secs".to_string(),
+            parse_u64("wait_timeout", value)?.to_string(),
+        ),
+    }
+}
+
+fn set_read_only(_: &Settings, value: &str) -> Result<()> {
+    match value {
+        "0" | "off" | "false" | "default" => Ok(()),
+        _ => Err(read_only_unsupported()),
+    }
+}
+
+fn read_only_unsupported() -> ErrorCode {
+    ErrorCode::Unimplemented("Read only transactions are not supported")
+}
+
+// `READ COMMITTED` of SET TRANSACTION and `read-committed` of the variables are the same level.
+fn mysql_isolation_level(name: &str, value: &str) -> Result<&'static str> {
+    let value = value.split_whitespace().collect::<Vec<_>>().join("-");
+    match value.as_str() {
+        "default" => Ok(DEFAULT_ISOLATION),
+        _ => MYSQL_ISOLATION_LEVELS
+            .iter()
+            .find(|level| level.eq_ignore_ascii_case(&value))
+            .copied()
+            .ok_or_else(|| {
+                ErrorCode::WrongValueForVariable(format!(
+                    "Variable '{}' can't be set to the value of '{}'",
+                    name, value
+                ))
+            }),
+    }
+}
+
+// MySQL time zone is 'SYSTEM', a named time zone or an offset like '+08:00'.
+// An offset maps to the Etc/GMT zones, which only have the whole hours.
+fn mysql_time_zone(value: &str) -> Result<String> {
+    if value == "system" {
+        return Ok("UTC".to_string());
+    }
+
+    let offset = value
+        .strip_prefix('+')
+        .map(|v| (true, v))
+        .or_else(|| value.strip_prefix('-').map(|v| (false, v)));
+    if let Some((east, offset)) = offset {
+        let parsed = offset
+            .split_once(':')
+            .and_then(|(h, m)| Some((h.parse::<u32>().ok()?, m.parse::<u32>().ok()?)));
+        return match parsed {
+            Some((0, 0)) => Ok("UTC".to_string()),
+            // The sign is inverted, Etc/GMT-8 is UTC+08:00.
+            Some((hours, 0)) if east && hours <= 14 => Ok(format!("Etc/GMT-{}", hours)),
+            Some((hours, 0)) if !east && hours <= 12 => Ok(format!("Etc/GMT+{}", hours)),
+            _ => Err(ErrorCode::InvalidTimezone(format!(
+                "Unsupported time zone offset: {}, only the whole hours are supported",
+                value
+            ))),
+        };
+    }
+
+    // The value is lowercase, use the name of the time zone, e.g. 'Asia/Shanghai'.
+    match TZ_VARIANTS
+        .iter()
+        .find(|tz| tz.name().eq_ignore_ascii_case(value))
+    {
+        Some(tz) => Ok(tz.name().to_string()),
+        None => Err(ErrorCode::InvalidTimezone(format!(
+            "Invalid Timezone: {}",
+            value
+        ))),
+    }
+}
+
+// SET [SESSION | LOCAL] var = value [, var = value] ...
+// SET NAMES utf8mb4 [COLLATE ...], SET CHARACTER SET utf8mb4
+// Returns the lowercase variable names with the unquoted values, and whether
+// the statement uses the MySQL only syntax, `@@var`, `SESSION` or `NAMES`.
+// None if it is left to the SET statement, e.g. `SET GLOBAL` or `SET @user_var = 1`.
+fn parse_set_assignments(assignments: &str) -> Option<(Vec<(String, String)>, bool)> {
+    let mut res = vec![];
+    let mut mysql_syntax = false;
+    for assignment in split_unquoted(assignments, ',') {
+        let assignment = assignment.trim();
+        let lower = assignment.to_lowercase();
+        if let Some(value) = ["names ", "character set ", "charset "]
+            .iter()
+            .find_map(|prefix| lower.strip_prefix(prefix))
+        {
+            mysql_syntax = true;
+            res.push(("names".to_string(), value.trim().to_string()));
+            continue;
+        }
+
+        // `=` or `:=`, the name is before the first one.
+        let Some((name, value)) = assignment.split_once('=') else {
+            return None;
+        };
+
+        let mut name = name.trim().trim_end_matches(':').trim().to_lowercase();
+        for (scope, global) in [
+            ("session ", false),
+            ("local ", false),
+            ("global ", true),
+            ("@@session.", false),
+            ("@@local.", false),
+            ("@@global.", true),
+            ("@@", false),
+        ] {
+            if let Some(stripped) = name.strip_prefix(scope) {
+                if global {
+                    return None;
+                }
+                mysql_syntax = true;
+                name = stripped.trim().to_string();
+                break;
+            }
+        }
+
+        if name.starts_with('@') {
+            return None;
+        }
+
+        res.push((name, unquote(value.trim()).to_string()));
+    }
+
+    Some((res, mysql_syntax))
+}
+
+// Split by the separator outside of the quotes.
+fn split_unquoted(s: &str, sep: char) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut quote = None;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '\'' || c == '"' || c == '`' => quote = Some(c),
+            None if c == sep => {
+                parts.push(&s[start..i]);
+                start = i + c.len_utf8();
+            }
+            None => {}
+        }
+    }
+    parts.push(&s[start..]);
+    parts
+}
+
+fn unquote(value: &str) -> &str {
+    for q in ['\'', '"', '`'] {
+        if let Some(v) = value.strip_prefix(q).and_then(|v| v.strip_suffix(q)) {
+            return v;
+        }
+    }
+    value
+}
+
+static SHOW_WARNINGS_RULE: LazyLock<Result<Regex>> = LazyLock::new(|| {
+    FederatedHelper::regex(
+        r"^SHOW\s+(?:(?P<count>COUNT\(\s*\*\s*\))\s+)?(?P<kind>WARNINGS|ERRORS)(?:\s+LIMIT\s+(?:(?P<offset>\d+)\s*,\s*)?(?P<limit>\d+))?\s*;?\s*$",
+    )
+});
+
+/// The transaction isolation level of a MySQL connection, the drivers read back what they set.
+/// It is recorded only, Databend doesn't enforce the levels.
+pub struct MySQLTransactionIsolation {
+    level: RwLock<&'static str>,
+}
+
+impl Default for MySQLTransactionIsolation {
+    fn default() -> Self {
+        MySQLTransactionIsolation {
+            level: RwLock::new(DEFAULT_ISOLATION),
+        }
+    }
+}
+
+impl MySQLTransactionIsolation {
+    pub fn get(&self) -> &'static str {
+        *self.level.read()
+    }
+
+    pub fn reset(&self) {
+        self.set(DEFAULT_ISOLATION);
+    }
+
+    fn set(&self, level: &'static str) {
+        *self.level.write() = level;
+    }
+}
+
+pub struct MySQLFederated {
+    settings: Option<Arc<Settings>>,
+    rules: Option<Arc<MySQLFederatedRules>>,
+    warnings: Option<Arc<SessionWarnings>>,
+    isolation: Option<Arc<MySQLTransactionIsolation>>,
+}
 
 impl MySQLFederated {
     pub fn create() -> Self {
-        MySQLFederated {}
+        MySQLFederated {
+            settings: None,
+            rules: None,
+            warnings: None,
+            isolation: None,
+        }
+    }
+
+    /// The variables are answered from the session settings where they map.
+    pub fn create_with_settings(settings: Arc<Settings>) -> Self {
+        MySQLFederated {
+            settings: Some(settings),
+            rules: None,
+            warnings: None,
+            isolation: None,
+        }
+    }
+
+    /// The rules of the operator are checked after the built-in rules.
+    pub fn with_rules(mut self, rules: Arc<MySQLFederatedRules>) -> Self {
+        self.rules = Some(rules);
+        self
+    }
+
+    /// The ignored statements raise the warnings of the session, `SHOW WARNINGS` lists them.
+    pub fn with_warnings(mut self, warnings: Arc<SessionWarnings>) -> Self {
+        self.warnings = Some(warnings);
+        self
+    }
+
+    /// The isolation level of the connection, `SET SESSION TRANSACTION ISOLATION LEVEL` records it.
+    pub fn with_isolation(mut self, isolation: Arc<MySQLTransactionIsolation>) -> Self {
+        self.isolation = Some(isolation);
+        self
+    }
+
+    /// `SHOW WARNINGS` and `SHOW ERRORS` keep the warnings of the previous statement.
+    pub fn is_show_warnings(query: &str) -> bool {
+        match SHOW_WARNINGS_RULE.as_ref() {
+            Ok(rule) => rule.is_match(FederatedHelper::strip_leading_comments(query)),
+            Err(_) => false,
+        }
+    }
+
+    /// The database of `USE db`. It is dispatched on the prefix before planning,
+    /// so the names the clients send are taken as they are, e.g. ``USE `my-db` ``.
+    /// `None` if the query isn't a `USE`.
+    pub fn use_database(&self, query: &str) -> Result<Option<String>> {
+        let query = FederatedHelper::strip_leading_comments(query).trim_end();
+        let query = query.strip_suffix(';').unwrap_or(query).trim_end();
+        match query.get(..3) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("USE") => {}
+            _ => return Ok(None),
+        }
+        match query[3..].starts_with(char::is_whitespace) {
+            true => self.database_name(query[3..].trim_start()),
+            false => Ok(None),
+        }
+    }
+
+    /// The database of `USE` and COM_INIT_DB, the backticks are stripped and the
+    /// case follows the rules of the identifiers, the same as CREATE DATABASE.
+    pub fn database_name(&self, name: &str) -> Result<Option<String>> {
+        let identifier = match name.strip_prefix('`').and_then(|n| n.strip_suffix('`')) {
+            Some(quoted) if !quoted.replace("``", "").contains('`') => {
+                Identifier::from_name_with_quoted(None, quoted.replace("``", "`"), Some('`'))
+            }
+            Some(_) => return Ok(None),
+            None if name.is_empty() || name.contains(|c: char| c.is_whitespace() || c == '`') => {
+                return Ok(None);
+            }
+            None => Identifier::from_name(None, name),
+        };
+        let context = match &self.settings {
+            Some(settings) => NameResolutionContext::try_from(settings.as_ref())?,
+            None => NameResolutionContext::default(),
+        };
+        Ok(Some(normalize_identifier(&identifier, &context).name))
+    }
+
+    /// Compile all the rule sets by running an empty query through them,
+    /// so a malformed pattern fails the handler startup rather than the queries.
+    pub fn check_rules() -> Result<()> {
+        LazyLock::force(&SERVER_STARTED_AT);
+        MySQLFederated::create().check("")?;
+        Ok(())
     }
 
     // Build block for select function.
@@ -48,228 +608,742 @@ impl MySQLFederated {
         Some((schema, block))
     }
 
-    // Build block for show variable statement.
-    // Format is:
-    // |variable_name| Value|
-    // | xx          | yy   |
-    fn show_variables_block(name: &str, value: &str) -> Option<(TableSchemaRef, DataBlock)> {
+    // Build an empty block with the columns of the MySQL result set,
+    // the tools read the result by position even if there are no rows.
+    fn empty_block(names: &[&str]) -> Option<(TableSchemaRef, DataBlock)> {
+        let schema = TableSchemaRefExt::create(
+            names
+                .iter()
+                .map(|name| TableField::new(name, TableDataType::String))
+                .collect(),
+        );
+        let block = DataBlock::new_from_columns(
+            names
+                .iter()
+                .map(|_| StringType::from_data(Vec::<String>::new()))
+                .collect(),
+        );
+        Some((schema, block))
+    }
+
+    // @@session.time_zone and @@time_zone are the same variable.
+    fn variable_value(&self, name: &str) -> Result<VariableValue> {
+        let global = name.starts_with("global.");
+        let name = ["session.", "global.", "local."]
+            .iter()
+            .find_map(|scope| name.strip_prefix(scope))
+            .unwrap_or(name);
+        match MYSQL_VARIABLES.iter().find(|(n, _)| *n == name) {
+            Some((_, func)) if global => func(self.settings.as_deref()),
+            Some((name, func)) => self.session_variable_value(name, func),
+            None => string("0"),
+        }
+    }
+
+    // The variables recorded by the connection, the others are read by the func.
+    fn session_variable_value(&self, name: &str, func: &VariableFunc) -> Result<VariableValue> {
+        match &self.isolation {
+            Some(isolation) if MYSQL_ISOLATION_VARIABLES.contains(&name) => string(isolation.get()),
+            _ => func(self.settings.as_deref()),
+        }
+    }
+
+    // SELECT @@aa, @@bb as cc, @dd...
+    // Block is built by the `variables` capture, the list after the SELECT.
+    fn select_variable_data_block(
+        &self,
+        captures: &FederatedCaptures,
+    ) -> Result<(TableSchemaRef, DataBlock)> {
+        let mut fields = vec![];
+        let mut columns = vec![];
+
+        let variables = captures
+            .get("variables")
+            .copied()
+            .unwrap_or_default()
+            .to_lowercase();
+        // @@aa, @@bb, @@cc as yy, @@dd
+        for variable in variables.split(',') {
+            let Some(variable) = variable.trim().strip_prefix("@@") else {
+                continue;
+            };
+
+            // @@cc as yy or @@cc yy: 'cc' is the variable and 'yy' is the field name.
+            let mut tokens = variable.split_whitespace();
+            let var = tokens.next().unwrap_or_default();
+            let field = match (tokens.next(), tokens.next()) {
+                (Some("as"), Some(var_as)) | (Some(var_as), None) if var_as != "as" => var_as
+                    .trim_matches(|c| c == '`' || c == '\'' || c == '"')
+                    .to_string(),
+                _ => format!("@@{}", var),
+            };
+
+            let value = self.variable_value(var)?;
+            fields.push(TableField::new(&field, value.data_type()));
+            columns.push(value.to_column());
+        }
+
+        let schema = TableSchemaRefExt::create(fields);
+        let block = DataBlock::new_from_columns(columns);
+        Ok((schema, block))
+    }
+
+    // The matcher of the `pattern` capture, which is the text inside the quotes of the string
+    // literal, so `\\` is unescaped first as MySQL does before the LIKE.
+    fn like_matcher(captures: &FederatedCaptures) -> Option<LikeMatcher> {
+        captures
+            .get("pattern")
+            .map(|p| like_pattern_to_matcher(&p.replace("\\\\", "\\")))
+    }
+
+    // SHOW VARIABLES [LIKE 'xx']
+    // Block is built by the variables matching the `pattern` capture with MySQL LIKE semantics.
+    fn show_variables_like_block(
+        &self,
+        captures: &FederatedCaptures,
+    ) -> Result<(TableSchemaRef, DataBlock)> {
+        let matcher = Self::like_matcher(captures);
+
+        let mut names = vec![];
+        let mut values = vec![];
+        for (name, func) in MYSQL_VARIABLES {
+            if matcher.as_ref().is_some_and(|m| !m.matches(name)) {
+                continue;
+            }
+            names.push(name.to_string());
+            values.push(self.session_variable_value(name, func)?.to_string());
+        }
+
         let schema = TableSchemaRefExt::create(vec![
             TableField::new("Variable_name", TableDataType::String),
             TableField::new("Value", TableDataType::String),
         ]);
         let block = DataBlock::new_from_columns(vec![
-            StringType::from_data(vec![name.to_string()]),
-            StringType::from_data(vec![value.to_string()]),
+            StringType::from_data(names),
+            StringType::from_data(values),
         ]);
-        Some((schema, block))
+        Ok((schema, block))
     }
 
-    // SELECT @@aa, @@bb as cc, @dd...
-    // Block is built by the variables.
-    fn select_variable_data_block(query: &str) -> Option<(TableSchemaRef, DataBlock)> {
-        let mut default_map = HashMap::new();
-        // DBeaver.
-        default_map.insert("tx_isolation", "REPEATABLE-READ");
-        default_map.insert("session.tx_isolation", "REPEATABLE-READ");
-        default_map.insert("transaction_isolation", "REPEATABLE-READ");
-        default_map.insert("session.transaction_isolation", "REPEATABLE-READ");
-        default_map.insert("session.transaction_read_only", "0");
-        default_map.insert("time_zone", "UTC");
-        default_map.insert("system_time_zone", "UTC");
-        // 128M
-        default_map.insert("max_allowed_packet", "134217728");
-        default_map.insert("interactive_timeout", "31536000");
-        default_map.insert("wait_timeout", "31536000");
-        default_map.insert("net_write_timeout", "31536000");
+    // SHOW [SESSION | GLOBAL] STATUS [LIKE 'xx']
+    // The names are matched case-insensitively as MySQL does.
+    fn show_status_block(captures: &FederatedCaptures) -> Result<(TableSchemaRef, DataBlock)> {
+        let matcher = Self::like_matcher(captures);
 
-        let mut fields = vec![];
+        let mut names = vec![];
         let mut values = vec![];
+        for (name, func) in MYSQL_STATUS {
+            if matcher.as_ref().is_some_and(|m| !m.matches(name)) {
+                continue;
+            }
+            names.push(name.to_string());
+            values.push(func()?.to_string());
+        }
+
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("Variable_name", TableDataType::String),
+            TableField::new("Value", TableDataType::String),
+        ]);
+        let block = DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(values),
+        ]);
+        Ok((schema, block))
+    }
+
+    // SHOW CHARSET [LIKE 'xx']
+    // Format is:
+    // |Charset|Description|Default collation|Maxlen|
+    fn show_charset_block(captures: &FederatedCaptures) -> (TableSchemaRef, DataBlock) {
+        let matcher = Self::like_matcher(captures);
+        let charsets = MYSQL_CHARSETS
+            .iter()
+            .filter(|(name, ..)| matcher.as_ref().map_or(true, |m| m.matches(name)))
+            .collect::<Vec<_>>();
+
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("Charset", TableDataType::String),
+            TableField::new("Description", TableDataType::String),
+            TableField::new("Default collation", TableDataType::String),
+            TableField::new("Maxlen", TableDataType::Number(NumberDataType::UInt64)),
+        ]);
+        let block = DataBlock::new_from_columns(vec![
+            StringType::from_data(charsets.iter().map(|c| c.0).collect::<Vec<_>>()),
+            StringType::from_data(charsets.iter().map(|c| c.1).collect::<Vec<_>>()),
+            StringType::from_data(charsets.iter().map(|c| c.2).collect::<Vec<_>>()),
+            UInt64Type::from_data(charsets.iter().map(|c| c.3).collect::<Vec<_>>()),
+        ]);
+        (schema, block)
+    }
+
+    // SHOW COLLATION [LIKE 'xx' | WHERE Charset = 'xx']
+    // Format is:
+    // |Collation|Charset|Id|Default|Compiled|Sortlen|Pad_attribute|
+    fn show_collation_block(captures: &FederatedCaptures) -> (TableSchemaRef, DataBlock) {
+        let matcher = Self::like_matcher(captures);
+        let charset = captures.get("charset").map(|c| c.to_lowercase());
+        let collations = MYSQL_COLLATIONS
+            .iter()
+            .filter(|(name, name_charset, ..)| {
+                matcher.as_ref().map_or(true, |m| m.matches(name))
+                    && charset.as_ref().map_or(true, |c| c == name_charset)
+            })
+            .collect::<Vec<_>>();
+
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("Collation", TableDataType::String),
+            TableField::new("Charset", TableDataType::String),
+            TableField::new("Id", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("Default", TableDataType::String),
+            TableField::new("Compiled", TableDataType::String),
+            TableField::new("Sortlen", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("Pad_attribute", TableDataType::String),
+        ]);
+        let block = DataBlock::new_from_columns(vec![
+            StringType::from_data(collations.iter().map(|c| c.0).collect::<Vec<_>>()),
+            StringType::from_data(collations.iter().map(|c| c.1).collect::<Vec<_>>()),
+            UInt64Type::from_data(collations.iter().map(|c| c.2).collect::<Vec<_>>()),
+            StringType::from_data(
+                collations
+                    .iter()
+                    .map(|c| if c.3 { "Yes" } else { "" })
+                    .collect::<Vec<_>>(),
+            ),
+            StringType::from_data(vec!["Yes"; collations.len()]),
+            UInt64Type::from_data(vec![1u64; collations.len()]),
+            StringType::from_data(
+                collations
+                    .iter()
+                    .map(|c| {
+                        if c.0 == "binary" {
+                            "NO PAD"
+                        } else {
+                            "PAD SPACE"
+                        }
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+        ]);
+        (schema, block)
+    }
+
+    fn warn_ignored_set(&self, name: &str, value: &str) {
+        let message = format!("Ignored SET {} = {}", name, value);
+        if let Some(warnings) = &self.warnings {
+            warnings.warn(WARN_UNKNOWN_VARIABLE, message);
+        }
+    }
+
+    // SHOW WARNINGS and SHOW ERRORS [LIMIT [offset,] row_count]
+    // Format is:
+    // |Level|Code|Message|
+    // SHOW COUNT(*) WARNINGS and SHOW COUNT(*) ERRORS
+    // Format is:
+    // |@@session.warning_count|
+    fn show_warnings_block(&self, captures: &FederatedCaptures) -> (TableSchemaRef, DataBlock) {
+        let errors = captures
+            .get("kind")
+            .map_or(false, |kind| kind.eq_ignore_ascii_case("ERRORS"));
+
+        if captures.contains_key("count") {
+            let name = match errors {
+                true => "@@session.error_count",
+                false => "@@session.warning_count",
+            };
+            let count = match &self.warnings {
+                Some(warnings) if errors => warnings.error_count(),
+                Some(warnings) => warnings.warning_count(),
+                None => 0,
+            };
+            let schema = TableSchemaRefExt::create(vec![TableField::new(
+                name,
+                TableDataType::Number(NumberDataType::UInt64),
+            )]);
+            let block =
+                DataBlock::new_from_columns(vec![UInt64Type::from_data(vec![count as u64])]);
+            return (schema, block);
+        }
+
+        let offset = captures
+            .get("offset")
+            .and_then(|v| v.parse::<usize>().ok())
+            .unwrap_or(0);
+        let limit = captures
+            .get("limit")
+            .and_then(|v| v.parse::<usize>().ok())
+            .unwrap_or(usize::MAX);
+        let warnings = self
+            .warnings
+            .as_ref()
+            .map(|warnings| warnings.list())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|w| !errors || w.level == WarningLevel::Error)
+            .skip(offset)
+            .take(limit)
+            .collect::<Vec<_>>();
+
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("Level", TableDataType::String),
+            TableField::new("Code", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("Message", TableDataType::String),
+        ]);
+        let block = DataBlock::new_from_columns(vec![
+            StringType::from_data(
+                warnings
+                    .iter()
+                    .map(|w| w.level.to_string())
+                    .collect::<Vec<_>>(),
+            ),
+            UInt64Type::from_data(warnings.iter().map(|w| w.code as u64).collect::<Vec<_>>()),
+            StringType::from_data(
+                warnings
+                    .iter()
+                    .map(|w| w.message.as_str())
+                    .collect::<Vec<_>>(),
+            ),
+        ]);
+        (schema, block)
+    }
+
+    // Check SHOW [COUNT(*)] WARNINGS and SHOW [COUNT(*)] ERRORS.
+    fn federated_show_warnings_check(
+        &self,
+        query: &str,
+    ) -> Result<Option<(&'static str, (TableSchemaRef, DataBlock))>> {
+        let rule = SHOW_WARNINGS_RULE.as_ref().map_err(Clone::clone)?;
+        let query = FederatedHelper::strip_leading_comments(query);
+        let Some(captures) = FederatedHelper::captures(rule, query) else {
+            return Ok(None);
+        };
+        let name = match captures.get("kind") {
+            Some(kind) if kind.eq_ignore_ascii_case("ERRORS") => "show_errors",
+            _ => "show_warnings",
+        };
+        Ok(Some((name, self.show_warnings_block(&captures))))
+    }
+
+    // Check SELECT @@variable, @@variable
+    fn federated_select_variable_check(
+        &self,
+        query: &str,
+    ) -> Result<Option<(TableSchemaRef, DataBlock)>> {
+        static SELECT_VARIABLES_RULE: LazyLock<Result<Regex>> = LazyLock::new(|| {
+            FederatedHelper::regex(r"(?s)^SELECT\s+(?P<variables>@@.*?)\s*;?\s*$")
+        });
+
+        let rule = SELECT_VARIABLES_RULE.as_ref().map_err(Clone::clone)?;
+        let query = FederatedHelper::strip_leading_comments(query);
+        match FederatedHelper::captures(rule, query) {
+            Some(captures) => Ok(Some(self.select_variable_data_block(&captures)?)),
+            None => Ok(None),
+        }
+    }
+
+    // Check SHOW VARIABLES [LIKE].
+    fn federated_show_variables_check(
+        &self,
+        query: &str,
+    ) -> Result<Option<(TableSchemaRef, DataBlock)>> {
+        static SHOW_VARIABLES_RULE: LazyLock<Result<Regex>> = LazyLock::new(|| {
+            FederatedHelper::regex(
+                r#"^SHOW\s+(?:SESSION\s+|GLOBAL\s+)?VARIABLES(?:\s+LIKE\s+['"]?(?P<pattern>[^'"\s;]+)['"]?)?"#,
+            )
+        });
+
+        let rule = SHOW_VARIABLES_RULE.as_ref().map_err(Clone::clone)?;
+        let query = FederatedHelper::strip_leading_comments(query);
+        match FederatedHelper::captures(rule, query) {
+            Some(captures) => Ok(Some(self.show_variables_like_block(&captures)?)),
+            None => Ok(None),
+        }
+    }
+
+    // Check SHOW [SESSION | GLOBAL] STATUS [LIKE].
+    fn federated_show_status_check(
+        &self,
+        query: &str,
+    ) -> Result<Option<(TableSchemaRef, DataBlock)>> {
+        static SHOW_STATUS_RULE: LazyLock<Result<Regex>> = LazyLock::new(|| {
+            FederatedHelper::regex(
+                r#"^SHOW\s+(?:SESSION\s+|GLOBAL\s+|LOCAL\s+)?STATUS(?:\s+LIKE\s+['"]?(?P<pattern>[^'"\s;]+)['"]?)?\s*;?\s*$"#,
+            )
+        });
+
+        let rule = SHOW_STATUS_RULE.as_ref().map_err(Clone::clone)?;
+        let query = FederatedHelper::strip_leading_comments(query);
+        match FederatedHelper::captures(rule, query) {
+            Some(captures) => Ok(Some(MySQLFederated::show_status_block(&captures)?)),
+            None => Ok(None),
+        }
+    }
 
-        let query = query.to_lowercase();
-        // select @@aa, @@bb, @@cc as yy, @@dd
-        let mut vars: Vec<&str> = query.split("@@").collect();
-        if vars.len() > 1 {
-            vars.remove(0);
-            for var in vars {
-                let var = var.trim_end_matches(|c| c == ' ' || c == ',');
-                let vars_as: Vec<&str> = var.split(" as ").collect();
-                if vars_as.len() == 2 {
-                    // @@cc as yy:
-                    // var_as is 'yy' as the field name.
-                    let var_as = vars_as[1];
-                    fields.push(TableField::new(var_as, TableDataType::String));
-
-                    // var is 'cc'.
-                    let var = vars_as[0];
-                    let value = default_map.get(var).unwrap_or(&"0").to_string();
-                    values.push(StringType::from_data(vec![value]));
-                } else {
-                    // @@aa
-                    // var is 'aa'
-                    fields.push(TableField::new(
-                        &format!("@@{}", var),
-                        TableDataType::String,
-                    ));
-
-                    let value = default_map.get(var).unwrap_or(&"0").to_string();
-                    values.push(StringType::from_data(vec![value]));
+    // Check SHOW CHARSET and SHOW COLLATION [LIKE 'xx' | WHERE Charset = 'xx'].
+    // Other WHERE conditions are not evaluated, all the rows are returned.
+    fn federated_show_charset_check(
+        &self,
+        query: &str,
+    ) -> Result<Option<(&'static str, (TableSchemaRef, DataBlock))>> {
+        static SHOW_CHARSET_RULE: LazyLock<Result<Regex>> = LazyLock::new(|| {
+            FederatedHelper::regex(
+                r#"^SHOW\s+(?:CHARSET|CHARACTER\s+SET)(?:\s+LIKE\s+['"]?(?P<pattern>[^'"\s;]+)['"]?)?"#,
+            )
+        });
+        static SHOW_COLLATION_RULE: LazyLock<Result<Regex>> = LazyLock::new(|| {
+            FederatedHelper::regex(
+                r#"^SHOW\s+COLLATION(?:\s+LIKE\s+['"]?(?P<pattern>[^'"\s;]+)['"]?|\s+WHERE\s+`?Charset`?\s*=\s*['"](?P<charset>[^'"]+)['"])?"#,
+            )
+        });
+
+        let charset_rule = SHOW_CHARSET_RULE.as_ref().map_err(Clone::clone)?;
+        let collation_rule = SHOW_COLLATION_RULE.as_ref().map_err(Clone::clone)?;
+        let query = FederatedHelper::strip_leading_comments(query);
+        if let Some(captures) = FederatedHelper::captures(charset_rule, query) {
+            let block = MySQLFederated::show_charset_block(&captures);
+            return Ok(Some(("show_charset", block)));
+        }
+        if let Some(captures) = FederatedHelper::captures(collation_rule, query) {
+            let block = MySQLFederated::show_collation_block(&captures);
+            return Ok(Some(("show_collation", block)));
+        }
+        Ok(None)
+    }
+
+    // Check SET of the MySQL variables, the mapped ones are applied to the session settings.
+    fn federated_set_check(&self, query: &str) -> Result<Option<(TableSchemaRef, DataBlock)>> {
+        static SET_RULE: LazyLock<Result<Regex>> =
+            LazyLock::new(|| FederatedHelper::regex(r"(?s)^SET\s+(?P<assignments>.+?)\s*;?\s*$"));
+
+        let rule = SET_RULE.as_ref().map_err(Clone::clone)?;
+        let query = FederatedHelper::strip_leading_comments(query);
+        let Some((assignments, mysql_syntax)) = FederatedHelper::captures(rule, query)
+            .and_then(|captures| parse_set_assignments(captures.get("assignments")?))
+        else {
+            return Ok(None);
+        };
+
+        // Leave the SET of Databend settings to the SET statement.
+        let is_mysql_variable = |name: &str| {
+            MYSQL_SET_VARIABLES.iter().any(|(n, _)| *n == name)
+                || MYSQL_ISOLATION_VARIABLES.contains(&name)
+                || MYSQL_IGNORED_VARIABLES.contains(&name)
+        };
+        if !mysql_syntax && !assignments.iter().any(|(name, _)| is_mysql_variable(name)) {
+            return Ok(None);
+        }
+
+        for (name, value) in assignments {
+            // The variable references can't be evaluated, e.g. `SET TIME_ZONE=@OLD_TIME_ZONE` of mysqldump.
+            if value.starts_with('@') {
+                self.warn_ignored_set(&name, &value);
+                continue;
+            }
+            if MYSQL_ISOLATION_VARIABLES.contains(&name.as_str()) {
+                self.set_isolation(mysql_isolation_level(&name, &value)?);
+                continue;
+            }
+            match (
+                MYSQL_SET_VARIABLES.iter().find(|(n, _)| *n == name),
+                &self.settings,
+            ) {
+                (Some((_, func)), Some(settings)) => func(settings, &value.to_lowercase())?,
+                (None, Some(settings))
+                    if !MYSQL_IGNORED_VARIABLES.contains(&name.as_str())
+                        && settings.has_setting(&name)? =>
+                {
+                    settings.set_setting(name, value)?
                 }
+                _ => self.warn_ignored_set(&name, &value),
             }
         }
 
-        let schema = TableSchemaRefExt::create(fields);
-        let block = DataBlock::new_from_columns(values);
-        Some((schema, block))
+        Ok(Some((
+            TableSchemaRefExt::create(vec![]),
+            DataBlock::empty(),
+        )))
     }
 
-    // Check SELECT @@variable, @@variable
-    fn federated_select_variable_check(&self, query: &str) -> Option<(TableSchemaRef, DataBlock)> {
-        static SELECT_VARIABLES_LAZY_RULES: LazyLock<Vec<(Regex, LazyBlockFunc)>> =
-            LazyLock::new(|| {
-                vec![
-                    (
-                        Regex::new("(?i)^(SELECT @@(.*))").unwrap(),
-                        MySQLFederated::select_variable_data_block,
-                    ),
-                    (
-                        Regex::new("(?i)^(/\\* mysql-connector-java(.*))").unwrap(),
-                        MySQLFederated::select_variable_data_block,
-                    ),
-                ]
-            });
-
-        FederatedHelper::lazy_block_match_rule(query, &SELECT_VARIABLES_LAZY_RULES)
-    }
-
-    // Check SHOW VARIABLES LIKE.
-    fn federated_show_variables_check(&self, query: &str) -> Option<(TableSchemaRef, DataBlock)> {
-        #![allow(clippy::type_complexity)]
-        static SHOW_VARIABLES_RULES: LazyLock<Vec<(Regex, Option<(TableSchemaRef, DataBlock)>)>> =
-            LazyLock::new(|| {
-                vec![
-                    (
-                        // sqlalchemy < 1.4.30
-                        Regex::new("(?i)^(SHOW VARIABLES LIKE 'sql_mode'(.*))").unwrap(),
-                        MySQLFederated::show_variables_block(
-                            "sql_mode",
-                            "ONLY_FULL_GROUP_BY STRICT_TRANS_TABLES NO_ZERO_IN_DATE NO_ZERO_DATE ERROR_FOR_DIVISION_BY_ZERO NO_ENGINE_SUBSTITUTION",
-                        ),
-                    ),
-                    (
-                        Regex::new("(?i)^(SHOW VARIABLES LIKE 'lower_case_table_names'(.*))")
-                            .unwrap(),
-                        MySQLFederated::show_variables_block("lower_case_table_names", "0"),
-                    ),
-                    (
-                        Regex::new("(?i)^(show collation where(.*))").unwrap(),
-                        MySQLFederated::show_variables_block("", ""),
-                    ),
-                    (
-                        Regex::new("(?i)^(SHOW VARIABLES(.*))").unwrap(),
-                        MySQLFederated::show_variables_block("", ""),
-                    ),
-                ]
-            });
-
-        FederatedHelper::block_match_rule(query, &SHOW_VARIABLES_RULES)
-    }
-
-    // Check for SET or others query, this is the final check of the federated query.
-    fn federated_mixed_check(&self, query: &str) -> Option<(TableSchemaRef, DataBlock)> {
-        #![allow(clippy::type_complexity)]
-        static MIXED_RULES: LazyLock<Vec<(Regex, Option<(TableSchemaRef, DataBlock)>)>> =
-            LazyLock::new(|| {
-                vec![
-            // Txn.
-            (Regex::new("(?i)^(START(.*))").unwrap(), None),
-            (Regex::new("(?i)^(SET NAMES(.*))").unwrap(), None),
-            (Regex::new("(?i)^(SET character_set_results(.*))").unwrap(), None),
-            (Regex::new("(?i)^(SET net_write_timeout(.*))").unwrap(), None),
-            (Regex::new("(?i)^(SET FOREIGN_KEY_CHECKS(.*))").unwrap(), None),
-            (Regex::new("(?i)^(SET AUTOCOMMIT(.*))").unwrap(), None),
-            (Regex::new("(?i)^(SET SQL_LOG_BIN(.*))").unwrap(), None),
-            (Regex::new("(?i)^(SET sql_mode(.*))").unwrap(), None),
-            (Regex::new("(?i)^(SET SQL_SELECT_LIMIT(.*))").unwrap(), None),
-            (Regex::new("(?i)^(SET @@(.*))").unwrap(), None),
-            // Now databend not support charset and collation
-            // https://github.com/datafuselabs/databend/issues/5853
-            (Regex::new("(?i)^(SHOW COLLATION)").unwrap(), None),
-            (Regex::new("(?i)^(SHOW CHARSET)").unwrap(), None),
+    // Check SET [SESSION] TRANSACTION characteristic [, characteristic]
+    // characteristic: ISOLATION LEVEL level | READ WRITE | READ ONLY
+    // The SESSION levels are recorded, without the scope it's only for the next transaction.
+    fn federated_set_transaction_check(
+        &self,
+        query: &str,
+    ) -> Result<Option<(TableSchemaRef, DataBlock)>> {
+        static SET_TRANSACTION_RULE: LazyLock<Result<Regex>> = LazyLock::new(|| {
+            FederatedHelper::regex(
+                r"(?s)^SET\s+(?:(?P<scope>SESSION)\s+)?TRANSACTION\s+(?P<characteristics>.+?)\s*;?\s*$",
+            )
+        });
+
+        let rule = SET_TRANSACTION_RULE.as_ref().map_err(Clone::clone)?;
+        let query = FederatedHelper::strip_leading_comments(query);
+        let Some(captures) = FederatedHelper::captures(rule, query) else {
+            return Ok(None);
+        };
+
+        // The level is recorded after all the characteristics are accepted.
+        let mut isolation = None;
+        let characteristics = captures.get("characteristics").copied().unwrap_or_default();
+        for characteristic in characteristics.split(',') {
+            let characteristic = characteristic
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+                .to_lowercase();
+            if let Some(level) = characteristic.strip_prefix("isolation level ") {
+                isolation = Some(mysql_isolation_level("transaction_isolation", level)?);
+                continue;
+            }
+            match characteristic.as_str() {
+                "read write" => {}
+                "read only" => return Err(read_only_unsupported()),
+                _ => {
+                    return Err(ErrorCode::SyntaxException(format!(
+                        "Unknown transaction characteristic '{}'",
+                        characteristic
+                    )));
+                }
+            }
+        }
+        if let Some(level) = isolation.filter(|_| captures.contains_key("scope")) {
+            self.set_isolation(level);
+        }
+
+        Ok(Some((
+            TableSchemaRefExt::create(vec![]),
+            DataBlock::empty(),
+        )))
+    }
+
+    fn set_isolation(&self, level: &'static str) {
+        if let Some(isolation) = &self.isolation {
+            isolation.set(level);
+        }
+    }
+
+    /// The built-in rules of the MySQL handler, they are built once by `FederatedRules::mysql`.
+    pub(crate) fn builtin_rules() -> Result<Vec<FederatedRule>> {
+        Ok(vec![
             (
-                // SELECT TIMEDIFF(NOW(), UTC_TIMESTAMP());
-                Regex::new("(?i)^(SELECT TIMEDIFF\\(NOW\\(\\), UTC_TIMESTAMP\\(\\)\\))").unwrap(),
-                MySQLFederated::select_function_block("TIMEDIFF(NOW(), UTC_TIMESTAMP())", "00:00:00"),
+                "set_system_variable",
+                FederatedHelper::regex("^(SET @@(.*))")?,
+                None,
+            ),
+            // mysqldump saves the session variables to the user variables, e.g. `SET @OLD_SQL_MODE=@@SQL_MODE`.
+            (
+                "set_user_variable_from_system",
+                FederatedHelper::regex(r"^(SET\s+@\w+\s*:?=\s*@@(.*))")?,
+                None,
+            ),
+            // SELECT TIMEDIFF(NOW(), UTC_TIMESTAMP());
+            (
+                "select_timediff_utc",
+                FederatedHelper::regex("^(SELECT TIMEDIFF\\(NOW\\(\\), UTC_TIMESTAMP\\(\\)\\))")?,
+                MySQLFederated::select_function_block(
+                    "TIMEDIFF(NOW(), UTC_TIMESTAMP())",
+                    "00:00:00",
+                ),
             ),
             // mysqldump.
-            (Regex::new("(?i)^(SET SESSION(.*))").unwrap(), None),
-            (Regex::new("(?i)^(SET SQL_QUOTE_SHOW_CREATE(.*))").unwrap(), None),
-            (Regex::new("(?i)^(LOCK TABLES(.*))").unwrap(), None),
-            (Regex::new("(?i)^(UNLOCK TABLES(.*))").unwrap(), None),
             (
-                Regex::new("(?i)^(SELECT LOGFILE_GROUP_NAME, FILE_NAME, TOTAL_EXTENTS, INITIAL_SIZE, ENGINE, EXTRA FROM INFORMATION_SCHEMA.FILES(.*))").unwrap(),
+                "set_session",
+                FederatedHelper::regex("^(SET SESSION(.*))")?,
                 None,
             ),
+            (
+                "lock_tables",
+                FederatedHelper::regex("^(LOCK TABLES(.*))")?,
+                None,
+            ),
+            (
+                "unlock_tables",
+                FederatedHelper::regex("^(UNLOCK TABLES(.*))")?,
+                None,
+            ),
+            (
+                "flush_tables",
+                FederatedHelper::regex(r"^(FLUSH\s+TABLES(.*))")?,
+                None,
+            ),
+            (
+                "information_schema_files_logfile_groups",
+                FederatedHelper::regex(
+                    r"^(SELECT\s+LOGFILE_GROUP_NAME,\s*FILE_NAME,\s*TOTAL_EXTENTS,\s*INITIAL_SIZE,\s*ENGINE,\s*EXTRA\s+FROM\s+INFORMATION_SCHEMA.FILES(.*))",
+                )?,
+                MySQLFederated::empty_block(&[
+                    "LOGFILE_GROUP_NAME",
+                    "FILE_NAME",
+                    "TOTAL_EXTENTS",
+                    "INITIAL_SIZE",
+                    "ENGINE",
+                    "EXTRA",
+                ]),
+            ),
+            (
+                "information_schema_files_tablespaces",
+                FederatedHelper::regex(
+                    r"^(SELECT\s+DISTINCT\s+TABLESPACE_NAME,\s*FILE_NAME,\s*LOGFILE_GROUP_NAME,\s*EXTENT_SIZE,\s*INITIAL_SIZE,\s*ENGINE\s+FROM\s+INFORMATION_SCHEMA.FILES(.*))",
+                )?,
+                MySQLFederated::empty_block(&[
+                    "TABLESPACE_NAME",
+                    "FILE_NAME",
+                    "LOGFILE_GROUP_NAME",
+                    "EXTENT_SIZE",
+                    "INITIAL_SIZE",
+                    "ENGINE",
+                ]),
+            ),
+            (
+                "show_triggers",
+                FederatedHelper::regex(r"^(SHOW\s+TRIGGERS(.*))")?,
+                MySQLFederated::empty_block(&[
+                    "Trigger",
+                    "Event",
+                    "Table",
+                    "Statement",
+                    "Timing",
+                    "Created",
+                    "sql_mode",
+                    "Definer",
+                    "character_set_client",
+                    "collation_connection",
+                    "Database Collation",
+                ]),
+            ),
+            // mysqldump 8.0 with the column statistics.
+            (
+                "column_statistics",
+                FederatedHelper::regex(
+                    r#"(?s)^(SELECT\s+COLUMN_NAME,\s*JSON_EXTRACT\(HISTOGRAM,\s*'\$\."number-of-buckets-specified"'\)\s+FROM\s+information_schema.COLUMN_STATISTICS(.*))"#,
+                )?,
+                MySQLFederated::empty_block(&[
+                    "COLUMN_NAME",
+                    r#"JSON_EXTRACT(HISTOGRAM, '$."number-of-buckets-specified"')"#,
+                ]),
+            ),
             // mydumper.
-            (Regex::new("(?i)^(/\\*!80003 SET(.*) \\*/)$").unwrap(), None),
-            (Regex::new("(?i)^(SHOW MASTER STATUS)").unwrap(), None),
-            (Regex::new("(?i)^(SHOW ALL SLAVES STATUS)").unwrap(), None),
-            (Regex::new("(?i)^(LOCK BINLOG FOR BACKUP)").unwrap(), None),
-            (Regex::new("(?i)^(LOCK TABLES FOR BACKUP)").unwrap(), None),
-            (Regex::new("(?i)^(UNLOCK BINLOG(.*))").unwrap(), None),
-            (Regex::new("(?i)^(/\\*!40101 SET(.*) \\*/)$").unwrap(), None),
+            (
+                "show_master_status",
+                FederatedHelper::regex("^(SHOW MASTER STATUS)")?,
+                None,
+            ),
+            (
+                "show_binary_log_status",
+                FederatedHelper::regex("^(SHOW BINARY LOG STATUS)")?,
+                None,
+            ),
+            (
+                "show_all_slaves_status",
+                FederatedHelper::regex("^(SHOW ALL SLAVES STATUS)")?,
+                None,
+            ),
+            (
+                "lock_binlog_for_backup",
+                FederatedHelper::regex("^(LOCK BINLOG FOR BACKUP)")?,
+                None,
+            ),
+            (
+                "lock_tables_for_backup",
+                FederatedHelper::regex("^(LOCK TABLES FOR BACKUP)")?,
+                None,
+            ),
+            (
+                "unlock_binlog",
+                FederatedHelper::regex("^(UNLOCK BINLOG(.*))")?,
+                None,
+            ),
             // DBeaver.
-            (Regex::new("(?i)^(SHOW WARNINGS)").unwrap(), None),
-            (Regex::new("(?i)^(/\\* ApplicationName=(.*)SHOW WARNINGS)").unwrap(), None),
-            (Regex::new("(?i)^(/\\* ApplicationName=(.*)SHOW PLUGINS)").unwrap(), None),
-            (Regex::new("(?i)^(/\\* ApplicationName=(.*)SHOW COLLATION)").unwrap(), None),
-            (Regex::new("(?i)^(/\\* ApplicationName=(.*)SHOW CHARSET)").unwrap(), None),
-            (Regex::new("(?i)^(/\\* ApplicationName=(.*)SHOW ENGINES)").unwrap(), None),
-            (Regex::new("(?i)^(/\\* ApplicationName=(.*)SELECT @@(.*))").unwrap(), None),
-            (Regex::new("(?i)^(/\\* ApplicationName=(.*)SHOW @@(.*))").unwrap(), None),
+            // The leading `/* ApplicationName=... */` comment is stripped before matching.
             (
-                Regex::new("(?i)^(/\\* ApplicationName=(.*)SET net_write_timeout(.*))").unwrap(),
+                "show_plugins",
+                FederatedHelper::regex("^(SHOW PLUGINS)")?,
                 None,
             ),
             (
-                Regex::new("(?i)^(/\\* ApplicationName=(.*)SET SQL_SELECT_LIMIT(.*))").unwrap(),
+                "show_system_variable",
+                FederatedHelper::regex("^(SHOW @@(.*))")?,
                 None,
             ),
-            (Regex::new("(?i)^(/\\* ApplicationName=(.*)SHOW VARIABLES(.*))").unwrap(), None),
-            // mysqldump 5.7.16
-            (Regex::new("(?i)^(/\\*!40100 SET(.*) \\*/)$").unwrap(), None),
-            (Regex::new("(?i)^(/\\*!40103 SET(.*) \\*/)$").unwrap(), None),
-            (Regex::new("(?i)^(/\\*!40111 SET(.*) \\*/)$").unwrap(), None),
-            (Regex::new("(?i)^(/\\*!40101 SET(.*) \\*/)$").unwrap(), None),
-            (Regex::new("(?i)^(/\\*!40014 SET(.*) \\*/)$").unwrap(), None),
-            (Regex::new("(?i)^(/\\*!40000 SET(.*) \\*/)$").unwrap(), None),
-            (Regex::new("(?i)^(/\\*!40000 ALTER(.*) \\*/)$").unwrap(), None),
-        ]
-            });
-
-        FederatedHelper::block_match_rule(query, &MIXED_RULES)
+            // The version comments of mysqldump and mydumper, e.g. `/*!40101 SET NAMES binary*/`.
+            (
+                "version_comment_set",
+                FederatedHelper::regex(r"^(/\*!\d{5} SET(.*)\*/)$")?,
+                None,
+            ),
+            (
+                "version_comment_alter",
+                FederatedHelper::regex(r"^(/\*!40000 ALTER(.*)\*/)$")?,
+                None,
+            ),
+        ])
     }
 
     // Check the query is a federated or driver setup command.
     // Here we fake some values for the command which Databend not supported.
-    pub fn check(&self, query: &str) -> Option<(DataSchemaRef, DataBlock)> {
+    pub fn check(&self, query: &str) -> Result<Option<(DataSchemaRef, DataBlock)>> {
+        // A long query is only matched in full if its prefix is matched by a rule,
+        // the prefix may be cut in the middle of a probe, so an error is not a miss.
+        let prefix = FederatedHelper::query_prefix(query);
+        if prefix.len() < query.len() && matches!(self.match_rule(prefix), Ok(None)) {
+            return Ok(None);
+        }
+        let Some((rule, (schema, block))) = self.match_rule(query)? else {
+            return Ok(None);
+        };
+
+        metrics_inc_federated_rule_matched(rule);
+        debug!(
+            "MySQL federated rule '{}' matched: {}",
+            rule,
+            query.chars().take(100).collect::<String>()
+        );
+        Ok(Some((Arc::new(DataSchema::from(schema)), block)))
+    }
+
+    // Returns the stable name of the matched rule, it's the label of the metrics.
+    fn match_rule(&self, query: &str) -> Result<Option<(&str, (TableSchemaRef, DataBlock))>> {
         // First to check the select @@variables.
-        let select_variable = self
-            .federated_select_variable_check(query)
-            .map(|(schema, chunk)| (Arc::new(DataSchema::from(schema)), chunk));
-        if select_variable.is_some() {
-            return select_variable;
+        if let Some(block) = self.federated_select_variable_check(query)? {
+            return Ok(Some(("select_variables", block)));
+        }
+
+        // Then to check the show warnings and errors.
+        if let Some(matched) = self.federated_show_warnings_check(query)? {
+            return Ok(Some(matched));
         }
 
         // Then to check the show variables like ''.
-        let show_variables = self
-            .federated_show_variables_check(query)
-            .map(|(schema, chunk)| (Arc::new(DataSchema::from(schema)), chunk));
-        if show_variables.is_some() {
-            return show_variables;
+        if let Some(block) = self.federated_show_variables_check(query)? {
+            return Ok(Some(("show_variables", block)));
+        }
+
+        // Then to check the show status like ''.
+        if let Some(block) = self.federated_show_status_check(query)? {
+            return Ok(Some(("show_status", block)));
+        }
+
+        // Then to check the show charset and collation.
+        if let Some(matched) = self.federated_show_charset_check(query)? {
+            return Ok(Some(matched));
+        }
+
+        // Then to check the SET of the MySQL variables.
+        if let Some(block) = self.federated_set_check(query)? {
+            return Ok(Some(("set_variables", block)));
+        }
+
+        // Then to check the SET of the transaction characteristics.
+        if let Some(block) = self.federated_set_transaction_check(query)? {
+            return Ok(Some(("set_transaction", block)));
+        }
+
+        // Then to check the built-in rules.
+        if let Some(matched) = FederatedRules::mysql()?.check(query) {
+            return Ok(Some(matched));
         }
 
-        // Last check.
-        self.federated_mixed_check(query)
-            .map(|(schema, chunk)| (Arc::new(DataSchema::from(schema)), chunk))
+        // Last check the rules of the operator.
+        Ok(self.rules.as_ref().and_then(|rules| rules.check(query)))
     }
 }
@@ -0,0 +1,195 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::GlobalInstance;
+use databend_common_config::InnerConfig;
+use databend_common_config::MySQLFederatedColumnConfig;
+use databend_common_config::MySQLFederatedResponse;
+use databend_common_config::MySQLFederatedRuleConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::Float64Type;
+use databend_common_expression::types::Int64Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+use regex::Regex;
+
+use crate::servers::federated_helper::FederatedHelper;
+use crate::servers::federated_helper::FederatedRuleIndex;
+
+/// The MySQL federated rules of the operator, built from `query.mysql_federated_rules`
+/// at startup and checked after the built-in rules.
+pub struct MySQLFederatedRules {
+    rules: Vec<(String, Regex, Option<(TableSchemaRef, DataBlock)>)>,
+    index: FederatedRuleIndex,
+}
+
+impl MySQLFederatedRules {
+    pub fn init(config: &InnerConfig) -> Result<()> {
+        let rules = MySQLFederatedRules::create(&config.query.mysql_federated_rules)?;
+        GlobalInstance::set(Arc::new(rules));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<MySQLFederatedRules> {
+        GlobalInstance::get()
+    }
+
+    /// A bad pattern or a value not of its column type fails with the name of the rule.
+    pub fn create(configs: &[MySQLFederatedRuleConfig]) -> Result<MySQLFederatedRules> {
+        let rules = configs
+            .iter()
+            .map(|config| {
+                Self::create_rule(config).map_err(|e| {
+                    ErrorCode::InvalidConfig(format!(
+                        "Invalid MySQL federated rule '{}': {}",
+                        config.name,
+                        e.message()
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let index = FederatedRuleIndex::create(&rules);
+        Ok(MySQLFederatedRules { rules, index })
+    }
+
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Returns the name of the matched rule with its block.
+    pub fn check(&self, query: &str) -> Option<(&str, (TableSchemaRef, DataBlock))> {
+        self.index.block_match_rule(query, &self.rules)
+    }
+
+    fn create_rule(
+        config: &MySQLFederatedRuleConfig,
+    ) -> Result<(String, Regex, Option<(TableSchemaRef, DataBlock)>)> {
+        let name = config.name.clone();
+        let regex = FederatedHelper::regex(&config.pattern)?;
+        match config.response {
+            MySQLFederatedResponse::Empty => {
+                if !config.columns.is_empty() || !config.rows.is_empty() {
+                    return Err(ErrorCode::InvalidConfig(
+                        "columns and rows are only for the table response",
+                    ));
+                }
+                Ok((name, regex, None))
+            }
+            MySQLFederatedResponse::Table => {
+                if config.columns.is_empty() {
+                    return Err(ErrorCode::InvalidConfig(
+                        "the table response needs at least one column",
+                    ));
+                }
+                for (i, row) in config.rows.iter().enumerate() {
+                    if row.len() != config.columns.len() {
+                        return Err(ErrorCode::InvalidConfig(format!(
+                            "row {} has {} values, but there are {} columns",
+                            i + 1,
+                            row.len(),
+                            config.columns.len()
+                        )));
+                    }
+                }
+
+                let mut fields = Vec::with_capacity(config.columns.len());
+                let mut columns = Vec::with_capacity(config.columns.len());
+                for (i, column) in config.columns.iter().enumerate() {
+                    let values = config.rows.iter().map(|row| row[i].as_str());
+                    let (data_type, data) = Self::create_column(column, values)?;
+                    fields.push(TableField::new(&column.name, data_type));
+                    columns.push(data);
+                }
+                let schema = TableSchemaRefExt::create(fields);
+                let block = DataBlock::new_from_columns(columns);
+                Ok((name, regex, Some((schema, block))))
+            }
+        }
+    }
+
+    fn create_column<'a>(
+        column: &MySQLFederatedColumnConfig,
+        values: impl Iterator<Item = &'a str>,
+    ) -> Result<(TableDataType, Column)> {
+        fn parse<'a, T>(
+            column: &MySQLFederatedColumnConfig,
+            values: impl Iterator<Item = &'a str>,
+            parse_value: impl Fn(&str) -> Option<T>,
+        ) -> Result<Vec<T>> {
+            values
+                .enumerate()
+                .map(|(i, value)| {
+                    parse_value(value.trim()).ok_or_else(|| {
+                        ErrorCode::InvalidConfig(format!(
+                            "the value '{}' of column '{}' in row {} is not a {}",
+                            value,
+                            column.name,
+                            i + 1,
+                            column.data_type
+                        ))
+                    })
+                })
+                .collect()
+        }
+
+        match column.data_type.to_lowercase().as_str() {
+            "string" => Ok((
+                TableDataType::String,
+                StringType::from_data(values.collect::<Vec<_>>()),
+            )),
+            "int64" => Ok((
+                TableDataType::Number(NumberDataType::Int64),
+                Int64Type::from_data(parse(column, values, |v| v.parse::<i64>().ok())?),
+            )),
+            "uint64" => Ok((
+                TableDataType::Number(NumberDataType::UInt64),
+                UInt64Type::from_data(parse(column, values, |v| v.parse::<u64>().ok())?),
+            )),
+            "float64" => Ok((
+                TableDataType::Number(NumberDataType::Float64),
+                Float64Type::from_data(parse(column, values, |v| v.parse::<f64>().ok())?),
+            )),
+            "boolean" => Ok((
+                TableDataType::Boolean,
+                BooleanType::from_data(parse(column, values, |v| {
+                    match v.to_lowercase().as_str() {
+                        "true" => Some(true),
+                        "false" => Some(false),
+                        _ => None,
+                    }
+                })?),
+            )),
+            _ => Err(ErrorCode::InvalidConfig(format!(
+                "unknown type '{}' of column '{}', the types are String, Int64, UInt64, Float64 and Boolean",
+                column.data_type, column.name
+            ))),
+        }
+    }
+}
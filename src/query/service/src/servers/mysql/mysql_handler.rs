@@ -39,6 +39,7 @@ use tokio_stream::wrappers::TcpListenerStream;
 use crate::servers::mysql::mysql_session::MySQLConnection;
 use crate::servers::mysql::reject_connection::RejectConnection;
 use crate::servers::mysql::tls::MySQLTlsConfig;
+use crate::servers::mysql::MySQLFederated;
 use crate::servers::server::ListeningStream;
 use crate::servers::server::Server;
 use crate::sessions::SessionManager;
@@ -61,6 +62,7 @@ impl MySQLHandler {
         let keepalive = TcpKeepalive::new()
             .with_time(std::time::Duration::from_secs(tcp_keepalive_timeout_secs));
         let tls = tls_config.setup()?.map(Arc::new);
+        MySQLFederated::check_rules()?;
 
         Ok(Box::new(MySQLHandler {
             abort_handle,
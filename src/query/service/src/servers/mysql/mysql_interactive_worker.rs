@@ -14,25 +14,32 @@
 
 use std::sync::Arc;
 use std::time::Instant;
+use std::time::SystemTime;
 
 use databend_common_base::base::convert_byte_size;
 use databend_common_base::base::convert_number_size;
 use databend_common_base::base::tokio::io::AsyncWrite;
 use databend_common_base::runtime::ThreadTracker;
 use databend_common_base::runtime::TrySpawn;
-use databend_common_config::DATABEND_COMMIT_VERSION;
+use databend_common_catalog::query_kind::QueryKind;
+use databend_common_config::GlobalConfig;
+use databend_common_config::MYSQL_VERSION;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_exception::ToErrorCode;
 use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::SendableDataBlockStream;
 use databend_common_io::prelude::FormatSettings;
 use databend_common_meta_app::principal::UserIdentity;
 use databend_common_metrics::mysql::*;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::plans::UseDatabasePlan;
 use databend_common_users::CertifiedInfo;
 use databend_common_users::UserApiProvider;
 use futures_util::StreamExt;
+use log::debug;
 use log::error;
 use log::info;
 use minitrace::full_name;
@@ -46,24 +53,33 @@ use opensrv_mysql::StatementMetaWriter;
 use rand::RngCore;
 use uuid::Uuid;
 
-use crate::interpreters::interpreter_plan_sql;
+use crate::interpreters::interpreter_plan_sql_with_query_text;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterFactory;
+use crate::servers::mysql::mysql_version_id;
+use crate::servers::mysql::strip_mysql_comments;
 use crate::servers::mysql::writers::DFInitResultWriter;
 use crate::servers::mysql::writers::DFQueryResultWriter;
 use crate::servers::mysql::writers::ProgressReporter;
 use crate::servers::mysql::writers::QueryResult;
 use crate::servers::mysql::MySQLFederated;
-use crate::servers::mysql::MYSQL_VERSION;
+use crate::servers::mysql::MySQLFederatedRules;
+use crate::servers::mysql::MySQLTransactionIsolation;
 use crate::sessions::QueriesQueueManager;
 use crate::sessions::QueryContext;
 use crate::sessions::QueryEntry;
 use crate::sessions::Session;
+use crate::sessions::SessionManager;
 use crate::sessions::TableContext;
+use crate::sessions::APP_NAME_LABEL;
 use crate::stream::DataBlockStream;
 
 struct InteractiveWorkerBase {
     session: Arc<Session>,
+    isolation: Arc<MySQLTransactionIsolation>,
+    // The database of the handshake, or of the COM_INIT_DB right after it,
+    // COM_RESET_CONNECTION goes back to it.
+    connect_database: Option<String>,
 }
 
 pub struct InteractiveWorker {
@@ -139,16 +155,12 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
         writer: StatementMetaWriter<'a, W>,
     ) -> Result<()> {
         if self.base.session.is_aborting() {
+            let message = self.base.aborting_message();
             writer
-                .error(
-                    ErrorKind::ER_ABORTING_CONNECTION,
-                    "Aborting this connection. because we are try aborting server.".as_bytes(),
-                )
+                .error(ErrorKind::ER_ABORTING_CONNECTION, message.as_bytes())
                 .await?;
 
-            return Err(ErrorCode::AbortedSession(
-                "Aborting this connection. because we are try aborting server.",
-            ));
+            return Err(ErrorCode::AbortedSession(message));
         }
 
         self.base.do_prepare(query, writer).await
@@ -162,16 +174,12 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
         writer: QueryResultWriter<'a, W>,
     ) -> Result<()> {
         if self.base.session.is_aborting() {
+            let message = self.base.aborting_message();
             writer
-                .error(
-                    ErrorKind::ER_ABORTING_CONNECTION,
-                    "Aborting this connection. because we are try aborting server.".as_bytes(),
-                )
+                .error(ErrorKind::ER_ABORTING_CONNECTION, message.as_bytes())
                 .await?;
 
-            return Err(ErrorCode::AbortedSession(
-                "Aborting this connection. because we are try aborting server.",
-            ));
+            return Err(ErrorCode::AbortedSession(message));
         }
 
         self.base.do_execute(id, param, writer).await
@@ -200,16 +208,12 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
 
         ThreadTracker::tracking_future(async {
             if self.base.session.is_aborting() {
+                let message = self.base.aborting_message();
                 writer
-                    .error(
-                        ErrorKind::ER_ABORTING_CONNECTION,
-                        "Aborting this connection. because we are try aborting server.".as_bytes(),
-                    )
+                    .error(ErrorKind::ER_ABORTING_CONNECTION, message.as_bytes())
                     .await?;
 
-                return Err(ErrorCode::AbortedSession(
-                    "Aborting this connection. because we are try aborting server.",
-                ));
+                return Err(ErrorCode::AbortedSession(message));
             }
 
             let mut writer = DFQueryResultWriter::create(writer, self.base.session.clone());
@@ -245,25 +249,74 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
         writer: InitWriter<'a, W>,
     ) -> Result<()> {
         if self.base.session.is_aborting() {
+            let message = self.base.aborting_message();
             writer
-                .error(
-                    ErrorKind::ER_ABORTING_CONNECTION,
-                    "Aborting this connection. because we are try aborting server.".as_bytes(),
-                )
+                .error(ErrorKind::ER_ABORTING_CONNECTION, message.as_bytes())
                 .await?;
 
-            return Err(ErrorCode::AbortedSession(
-                "Aborting this connection. because we are try aborting server.",
-            ));
+            return Err(ErrorCode::AbortedSession(message));
         }
 
         DFInitResultWriter::create(writer)
             .write(self.base.do_init(database_name).await)
             .await
     }
+
+    /// https://dev.mysql.com/doc/dev/mysql-server/latest/page_protocol_com_reset_connection.html
+    #[async_backtrace::framed]
+    async fn on_reset_connection<'a>(&'a mut self, writer: InitWriter<'a, W>) -> Result<()> {
+        if self.base.session.is_aborting() {
+            let message = self.base.aborting_message();
+            writer
+                .error(ErrorKind::ER_ABORTING_CONNECTION, message.as_bytes())
+                .await?;
+
+            return Err(ErrorCode::AbortedSession(message));
+        }
+
+        self.base.do_reset_connection();
+        DFInitResultWriter::create(writer).write(Ok(())).await
+    }
+
+    /// https://dev.mysql.com/doc/dev/mysql-server/latest/page_protocol_com_statistics.html
+    fn statistics(&self) -> String {
+        let status = SessionManager::instance().get_current_session_status();
+        let uptime = SystemTime::now()
+            .duration_since(status.instance_started_at)
+            .unwrap_or_default()
+            .as_secs();
+        format!(
+            "Uptime: {}  Threads: {}  Questions: {}  Queries per second avg: {:.3}",
+            uptime,
+            status.active_sessions_count,
+            status.total_queries_count,
+            status.total_queries_count as f64 / uptime.max(1) as f64
+        )
+    }
+
+    /// The connection attributes of the handshake are kept as the labels of the session,
+    /// `program_name` is the application name. The attributes starting with `_` are the ones of
+    /// the client library, like `_os` and `_client_version`, they are skipped.
+    fn on_connect_attrs(&self, attrs: &[(String, String)]) {
+        let labels = attrs.iter().filter_map(|(key, value)| match key.as_str() {
+            "program_name" => Some((APP_NAME_LABEL.to_string(), value.clone())),
+            _ if key.starts_with('_') => None,
+            _ => Some((key.clone(), value.clone())),
+        });
+        self.base.session.add_labels(labels);
+    }
 }
 
 impl InteractiveWorkerBase {
+    /// The error of the commands after the session is aborted, by the server shutdown or
+    /// the idle timeout.
+    fn aborting_message(&self) -> &'static str {
+        match self.session.is_closed_by_idle_timeout() {
+            true => "MySQL server has gone away, the session was closed after being idle too long.",
+            false => "Aborting this connection. because we are try aborting server.",
+        }
+    }
+
     #[async_backtrace::framed]
     async fn authenticate(&self, salt: &[u8], info: CertifiedInfo) -> Result<bool> {
         let ctx = self.session.create_query_context().await?;
@@ -324,7 +377,10 @@ impl InteractiveWorkerBase {
 
     // Check the query is a federated or driver setup command.
     // Here we fake some values for the command which Databend not supported.
-    fn federated_server_command_check(&self, query: &str) -> Option<(DataSchemaRef, DataBlock)> {
+    fn federated_server_command_check(
+        &self,
+        query: &str,
+    ) -> Result<Option<(DataSchemaRef, DataBlock)>> {
         // INSERT don't need MySQL federated check
         // Ensure the query is start with ASCII chars so we won't
         // panic when we slice the query string.
@@ -332,9 +388,12 @@ impl InteractiveWorkerBase {
             && query.char_indices().take(6).all(|(_, c)| c.is_ascii())
             && query[..6].eq_ignore_ascii_case("INSERT")
         {
-            return None;
+            return Ok(None);
         }
-        let federated = MySQLFederated::create();
+        let federated = MySQLFederated::create_with_settings(self.session.get_settings())
+            .with_rules(MySQLFederatedRules::instance())
+            .with_warnings(self.session.get_warnings())
+            .with_isolation(self.isolation.clone());
         federated.check(query)
     }
 
@@ -345,7 +404,32 @@ impl InteractiveWorkerBase {
         query_id: String,
         query: &str,
     ) -> Result<(QueryResult, Option<FormatSettings>)> {
-        match self.federated_server_command_check(query) {
+        // The version comments and hints are rewritten for matching and planning,
+        // the query log keeps the query as it was sent.
+        let version = GlobalConfig::instance().query.mysql_server_version();
+        let version_id = mysql_version_id(&version)
+            .or_else(|| mysql_version_id(MYSQL_VERSION))
+            .unwrap_or_default();
+        let rewritten = strip_mysql_comments(query, version_id);
+
+        // A statement clears the warnings of the previous one, except the statements showing them.
+        if !MySQLFederated::is_show_warnings(&rewritten) {
+            self.session.get_warnings().clear();
+        }
+
+        // USE is dispatched on the prefix before planning, the same as COM_INIT_DB.
+        let use_database = MySQLFederated::create_with_settings(self.session.get_settings())
+            .use_database(&rewritten)?;
+        if let Some(database) = use_database {
+            return self.do_use_database(query_id, query, database).await;
+        }
+
+        // Nothing is left if the version comments are all for the later versions, e.g. `/*!90000 ... */`.
+        let federated = match rewritten.is_empty() && !query.is_empty() {
+            true => Some((Arc::new(DataSchema::empty()), DataBlock::empty())),
+            false => self.federated_server_command_check(&rewritten)?,
+        };
+        match federated {
             Some((schema, data_block)) => {
                 info!("Federated query: {}", query);
                 if data_block.num_rows() > 0 {
@@ -368,13 +452,35 @@ impl InteractiveWorkerBase {
                 let context = self.session.create_query_context().await?;
                 context.set_id(query_id);
 
-                // Use interpreter_plan_sql, we can write the query log if an error occurs.
-                let (plan, extras) = interpreter_plan_sql(context.clone(), query).await?;
+                // Use interpreter_plan_sql_with_query_text, we can write the query log if an error occurs.
+                let (plan, extras) =
+                    interpreter_plan_sql_with_query_text(context.clone(), &rewritten, query)
+                        .await
+                        .inspect_err(|e| {
+                            // A driver probe of MySQL only, a candidate of the next federated rules.
+                            if matches!(
+                                e.code(),
+                                ErrorCode::UNKNOWN_VARIABLE
+                                    | ErrorCode::UNKNOWN_TABLE
+                                    | ErrorCode::UNKNOWN_DATABASE
+                            ) {
+                                metrics_inc_federated_miss_candidate();
+                                debug!("MySQL federated miss candidate: {}", query);
+                            }
+                        })?;
 
                 let entry = QueryEntry::create(&context, &plan, &extras)?;
                 let _guard = QueriesQueueManager::instance().acquire(entry).await?;
 
                 let interpreter = InterpreterFactory::get(context.clone(), &plan).await?;
+
+                // With autocommit off, MySQL starts a transaction implicitly which ends at COMMIT or ROLLBACK.
+                if !interpreter.is_ddl()
+                    && !interpreter.is_txn_command()
+                    && !self.session.get_settings().get_autocommit()?
+                {
+                    self.session.txn_mgr().lock().begin();
+                }
                 let has_result_set = plan.has_result_set();
 
                 let (blocks, extra_info) = Self::exec_query(interpreter.clone(), &context).await?;
@@ -446,11 +552,55 @@ impl InteractiveWorkerBase {
         tracking_payload.query_id = Some(query_id.clone());
         let _guard = ThreadTracker::tracking(tracking_payload);
 
-        let do_query = ThreadTracker::tracking_future(self.do_query(query_id, &init_query)).await;
-        match do_query {
-            Ok((_, _)) => Ok(()),
-            Err(error_code) => Err(error_code),
+        // The name of COM_INIT_DB is taken as it is if it isn't an identifier, e.g. `my db`.
+        let database = MySQLFederated::create_with_settings(self.session.get_settings())
+            .database_name(database_name)?
+            .unwrap_or_else(|| database_name.to_string());
+        let do_use = self.do_use_database(query_id, &init_query, database);
+        ThreadTracker::tracking_future(do_use).await?;
+        if self.connect_database.is_none() {
+            self.connect_database = Some(self.session.get_current_database());
         }
+        Ok(())
+    }
+
+    // Same as MySQL, the session is back to the state after connecting with the same user.
+    fn do_reset_connection(&mut self) {
+        info!(
+            "MySQL reset connection of session {}",
+            self.session.get_id()
+        );
+        let database = self
+            .connect_database
+            .clone()
+            .unwrap_or_else(|| "default".to_string());
+        self.session.get_settings().unset_session_settings();
+        self.session.set_current_database(database);
+        self.session.txn_mgr().lock().clear();
+        self.session.get_warnings().clear();
+        self.isolation.reset();
+    }
+
+    // The database is checked by the interpreter, it must exist and be visible to the user.
+    #[async_backtrace::framed]
+    async fn do_use_database(
+        &mut self,
+        query_id: String,
+        query: &str,
+        database: String,
+    ) -> Result<(QueryResult, Option<FormatSettings>)> {
+        info!("Use database: {}", query);
+        let context = self.session.create_query_context().await?;
+        context.set_id(query_id);
+        context.attach_query_str(QueryKind::Other, query.to_string());
+
+        let plan = Plan::UseDatabase(Box::new(UseDatabasePlan { database }));
+        let interpreter = InterpreterFactory::get(context.clone(), &plan).await?;
+        let (blocks, extra_info) = Self::exec_query(interpreter, &context).await?;
+        Ok((
+            QueryResult::create(blocks, extra_info, false, plan.schema(), query.to_string()),
+            None,
+        ))
     }
 }
 
@@ -469,9 +619,13 @@ impl InteractiveWorker {
         }
 
         InteractiveWorker {
-            base: InteractiveWorkerBase { session },
+            base: InteractiveWorkerBase {
+                session,
+                isolation: Default::default(),
+                connect_database: None,
+            },
             salt: scramble,
-            version: format!("{}-{}", MYSQL_VERSION, *DATABEND_COMMIT_VERSION),
+            version: GlobalConfig::instance().query.mysql_server_version(),
             client_addr,
         }
     }
@@ -506,4 +660,8 @@ impl ProgressReporter for ContextProgressReporter {
         let progress = self.context.get_write_progress_value();
         progress.rows as u64
     }
+
+    fn warnings(&self) -> Vec<String> {
+        self.context.pop_warnings()
+    }
 }
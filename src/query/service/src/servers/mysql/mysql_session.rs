@@ -66,7 +66,8 @@ impl MySQLConnection {
 
                 let mut interactive_worker = InteractiveWorker::create(session, client_addr);
                 let opts = IntermediaryOptions {
-                    process_use_statement_on_query: true,
+                    // USE is answered by the worker, opensrv strips the backticks the case rules need.
+                    process_use_statement_on_query: false,
                     reject_connection_on_dbname_absence: false,
                 };
                 let (r, w) = non_blocking_stream.into_split();
@@ -18,6 +18,8 @@ use databend_common_exception::Result;
 use log::error;
 use opensrv_mysql::*;
 
+use crate::servers::mysql::writers::query_result_writer::mysql_error_kind;
+
 pub struct DFInitResultWriter<'a, W: AsyncWrite + Send + Unpin> {
     inner: Option<InitWriter<'a, W>>,
 }
@@ -49,7 +51,7 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFInitResultWriter<'a, W> {
     async fn err(error: &ErrorCode, writer: InitWriter<'a, W>) -> Result<()> {
         error!("OnInit Error: {:?}", error);
         writer
-            .error(ErrorKind::ER_UNKNOWN_ERROR, error.to_string().as_bytes())
+            .error(mysql_error_kind(error), error.to_string().as_bytes())
             .await?;
         Ok(())
     }
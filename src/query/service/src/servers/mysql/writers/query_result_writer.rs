@@ -32,6 +32,7 @@ use log::error;
 use opensrv_mysql::*;
 
 use crate::sessions::Session;
+use crate::sessions::WARN_UNKNOWN;
 /// Reports progress information as string, intend to be put into the mysql Ok packet.
 /// Mainly for decoupling with concrete type like `QueryContext`
 ///
@@ -40,8 +41,13 @@ use crate::sessions::Session;
 pub trait ProgressReporter {
     fn progress_info(&self) -> String;
     fn affected_rows(&self) -> u64;
+    /// Takes the warnings raised by the query.
+    fn warnings(&self) -> Vec<String>;
 }
 
+// The type of the session state change of the current schema.
+const SESSION_TRACK_SCHEMA: u8 = 0x01;
+
 pub struct QueryResult {
     blocks: SendableDataBlockStream,
     extra_info: Option<Box<dyn ProgressReporter + Send>>,
@@ -71,6 +77,8 @@ impl QueryResult {
 pub struct DFQueryResultWriter<'a, W: AsyncWrite + Send + Unpin> {
     inner: Option<QueryResultWriter<'a, W>>,
     session: Arc<Session>,
+    // The current database before the query, a change of it is tracked in the OK packet.
+    database: String,
 }
 
 fn write_field<W: AsyncWrite + Unpin>(
@@ -93,6 +101,7 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
     ) -> DFQueryResultWriter<'a, W> {
         DFQueryResultWriter::<'a, W> {
             inner: Some(inner),
+            database: session.get_current_database(),
             session,
         }
     }
@@ -150,14 +159,20 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
 
             let affected_rows = query_result
                 .extra_info
+                .as_ref()
                 .map(|r| r.affected_rows())
                 .unwrap_or_default();
-            dataset_writer
-                .completed(OkResponse {
-                    affected_rows,
-                    ..Default::default()
-                })
-                .await?;
+            let warnings = self.take_warnings(&query_result);
+            let mut ok = OkResponse {
+                affected_rows,
+                warnings,
+                ..Default::default()
+            };
+            if let Some(session_state_info) = self.schema_change() {
+                ok.status_flags |= StatusFlags::SERVER_SESSION_STATE_CHANGED;
+                ok.session_state_info = session_state_info;
+            }
+            dataset_writer.completed(ok).await?;
             return Ok(());
         }
 
@@ -197,12 +212,36 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
             }
         }
 
+        // The flags clients decode the values by, e.g. Connector/J reads an UNSIGNED BIGINT with getLong.
+        // The numbers, dates and binaries are sent with BINARY_FLAG as the binary charset does.
+        fn convert_field_flags(field: &DataField) -> ColumnFlags {
+            let mut flags = ColumnFlags::empty();
+            if !field.data_type().is_nullable_or_null() {
+                flags |= ColumnFlags::NOT_NULL_FLAG;
+            }
+            match field.data_type().remove_nullable() {
+                DataType::Number(num_ty) => {
+                    flags |= ColumnFlags::BINARY_FLAG | ColumnFlags::NUM_FLAG;
+                    if !num_ty.is_signed() {
+                        flags |= ColumnFlags::UNSIGNED_FLAG;
+                    }
+                }
+                DataType::Decimal(_) => flags |= ColumnFlags::BINARY_FLAG | ColumnFlags::NUM_FLAG,
+                DataType::Boolean | DataType::Date | DataType::Timestamp => {
+                    flags |= ColumnFlags::BINARY_FLAG
+                }
+                DataType::Binary => flags |= ColumnFlags::BINARY_FLAG | ColumnFlags::BLOB_FLAG,
+                _ => {}
+            }
+            flags
+        }
+
         fn make_column_from_field(field: &DataField) -> Result<Column> {
             convert_field_type(field).map(|column_type| Column {
                 table: "".to_string(),
                 column: field.name().to_string(),
                 coltype: column_type,
-                colflags: ColumnFlags::empty(),
+                colflags: convert_field_flags(field),
             })
         }
 
@@ -309,6 +348,8 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
                     }
                 }
 
+                // The EOF packet of the rows doesn't carry the count, SHOW WARNINGS lists them.
+                self.take_warnings(&query_result);
                 let info = query_result
                     .extra_info
                     .map(|r| r.progress_info())
@@ -320,13 +361,37 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
         }
     }
 
+    // The SESSION_TRACK_SCHEMA of the changed database, the drivers update their current schema by it.
+    // The lengths are single bytes of ASCII, the longer names aren't tracked.
+    fn schema_change(&self) -> Option<String> {
+        let database = self.session.get_current_database();
+        if database == self.database || database.len() >= 0x7e {
+            return None;
+        }
+        let mut info = vec![SESSION_TRACK_SCHEMA, database.len() as u8 + 1];
+        info.push(database.len() as u8);
+        info.extend(database.as_bytes());
+        String::from_utf8(info).ok()
+    }
+
+    // Moves the warnings of the query to the session, returns the count of the OK packet.
+    fn take_warnings(&self, query_result: &QueryResult) -> u16 {
+        let warnings = self.session.get_warnings();
+        if let Some(reporter) = &query_result.extra_info {
+            for message in reporter.warnings() {
+                warnings.warn(WARN_UNKNOWN, message);
+            }
+        }
+        warnings.warning_count().min(u16::MAX as usize) as u16
+    }
+
     #[async_backtrace::framed]
     async fn err(&self, error: &ErrorCode, writer: QueryResultWriter<'a, W>) -> Result<()> {
         self.session.txn_mgr().lock().set_fail();
         if error.code() != ErrorCode::ABORTED_QUERY && error.code() != ErrorCode::ABORTED_SESSION {
             error!("OnQuery Error: {:?}", error);
             writer
-                .error(ErrorKind::ER_UNKNOWN_ERROR, error.to_string().as_bytes())
+                .error(mysql_error_kind(error), error.to_string().as_bytes())
                 .await?;
         } else {
             writer
@@ -340,3 +405,12 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
         Ok(())
     }
 }
+
+// The MySQL error codes which the clients act on, the others are ER_UNKNOWN_ERROR.
+pub(crate) fn mysql_error_kind(error: &ErrorCode) -> ErrorKind {
+    match error.code() {
+        ErrorCode::UNKNOWN_TABLE => ErrorKind::ER_NO_SUCH_TABLE,
+        ErrorCode::UNKNOWN_DATABASE => ErrorKind::ER_BAD_DB_ERROR,
+        _ => ErrorKind::ER_UNKNOWN_ERROR,
+    }
+}
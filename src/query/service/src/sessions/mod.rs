@@ -19,11 +19,14 @@ mod queue_mgr;
 mod session;
 mod session_ctx;
 mod session_info;
+mod session_labels;
 mod session_mgr;
 mod session_mgr_status;
 mod session_privilege_mgr;
 mod session_status;
 mod session_type;
+mod session_warnings;
+mod user_query_quota;
 
 pub use databend_common_catalog::table_context::TableContext;
 pub use query_affect::QueryAffect;
@@ -38,7 +41,17 @@ pub use queue_mgr::QueueManager;
 pub use session::Session;
 pub use session_ctx::SessionContext;
 pub use session_info::ProcessInfo;
+pub use session_labels::parse_labels;
+pub use session_labels::SessionLabels;
+pub use session_labels::APP_NAME_LABEL;
 pub use session_mgr::SessionManager;
 pub use session_mgr_status::SessionManagerStatus;
 pub use session_status::SessionStatus;
 pub use session_type::SessionType;
+pub use session_warnings::SessionWarning;
+pub use session_warnings::SessionWarnings;
+pub use session_warnings::WarningLevel;
+pub use session_warnings::WARN_UNKNOWN;
+pub use session_warnings::WARN_UNKNOWN_VARIABLE;
+pub use user_query_quota::UserQueryCounter;
+pub use user_query_quota::UserQuerySlot;
@@ -38,6 +38,7 @@ use databend_common_base::base::ProgressValues;
 use databend_common_base::runtime::profile::Profile;
 use databend_common_base::runtime::profile::ProfileStatisticsName;
 use databend_common_base::runtime::TrySpawn;
+use databend_common_catalog::cluster_info::NodeStatus;
 use databend_common_catalog::lock::LockTableOption;
 use databend_common_catalog::merge_into_join::MergeIntoJoin;
 use databend_common_catalog::plan::DataSourceInfo;
@@ -52,6 +53,7 @@ use databend_common_catalog::statistics::data_cache_statistics::DataCacheMetrics
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_catalog::table_context::FilteredCopyFiles;
 use databend_common_catalog::table_context::MaterializedCtesBlocks;
+use databend_common_catalog::table_context::ServerStatus;
 use databend_common_catalog::table_context::StageAttachment;
 use databend_common_config::GlobalConfig;
 use databend_common_config::DATABEND_COMMIT_VERSION;
@@ -114,8 +116,11 @@ use xorf::BinaryFuse16;
 
 use crate::catalogs::Catalog;
 use crate::clusters::Cluster;
+use crate::clusters::ClusterHelper;
 use crate::locks::LockManager;
+use crate::pipelines::executor::ExecutorPauseHandle;
 use crate::pipelines::executor::PipelineExecutor;
+use crate::servers::federated_helper::FederatedHelper;
 use crate::servers::flight::v1::exchange::DataExchangeManager;
 use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::ProcessInfo;
@@ -127,8 +132,6 @@ use crate::sessions::SessionType;
 use crate::sql::binder::get_storage_params_from_options;
 use crate::storages::Table;
 
-const MYSQL_VERSION: &str = "8.0.26";
-const CLICKHOUSE_VERSION: &str = "8.12.14";
 const COPIED_FILES_FILTER_BATCH_SIZE: usize = 1000;
 
 #[derive(Clone)]
@@ -158,8 +161,8 @@ impl QueryContext {
         Arc::new(QueryContext {
             partition_queue: Arc::new(RwLock::new(VecDeque::new())),
             version: format!("Databend Query {}", *DATABEND_COMMIT_VERSION),
-            mysql_version: format!("{}-{}", MYSQL_VERSION, *DATABEND_COMMIT_VERSION),
-            clickhouse_version: CLICKHOUSE_VERSION.to_string(),
+            mysql_version: FederatedHelper::mysql_server_version(),
+            clickhouse_version: FederatedHelper::clickhouse_server_version(),
             shared,
             query_settings,
             fragment_id: Arc::new(AtomicUsize::new(0)),
@@ -288,6 +291,11 @@ impl QueryContext {
         self.shared.set_executor(weak_ptr)
     }
 
+    /// The handle to pause the running pipeline of the query, None if no pipeline is running.
+    pub fn get_pause_handle(&self) -> Option<Arc<ExecutorPauseHandle>> {
+        self.shared.get_pause_handle()
+    }
+
     pub fn attach_stage(&self, attachment: StageAttachment) {
         self.shared.attach_stage(attachment);
     }
@@ -301,6 +309,28 @@ impl QueryContext {
         ua.clone()
     }
 
+    pub fn set_query_tag(&self, query_tag: String) {
+        *self.shared.query_tag.write() = query_tag;
+    }
+
+    pub fn get_query_tag(&self) -> String {
+        self.shared.get_query_tag()
+    }
+
+    /// Marks the query as served by the result cache entry created at `created_at` (unix seconds).
+    pub fn set_result_cache_hit(&self, created_at: u64) {
+        *self.shared.result_cache_hit.write() = Some(created_at);
+    }
+
+    pub fn get_result_cache_hit(&self) -> Option<u64> {
+        *self.shared.result_cache_hit.read()
+    }
+
+    /// Marks the query as waiting in the queries queue for a running slot.
+    pub fn set_queued(&self, queued: bool) {
+        self.shared.queued.store(queued, Ordering::Release);
+    }
+
     pub fn get_query_duration_ms(&self) -> i64 {
         let query_start_time = convert_query_log_timestamp(self.shared.created_time);
         let finish_time = *self.shared.finish_time.read();
@@ -309,6 +339,17 @@ impl QueryContext {
         (finish_time - query_start_time) / 1_000
     }
 
+    /// Takes a slot of the user quota for the query, at its admission.
+    pub fn acquire_user_query_slot(&self) -> Result<()> {
+        self.shared.acquire_user_query_slot()
+    }
+
+    /// Lowers the memory and result rows limits of the query settings to the user quota, at the
+    /// admission of the query. The query is rejected if they can't be lowered.
+    pub fn apply_user_quota(&self) -> Result<()> {
+        self.shared.apply_user_quota(&self.get_settings())
+    }
+
     pub fn get_created_time(&self) -> SystemTime {
         self.shared.created_time
     }
@@ -695,7 +736,7 @@ impl TableContext for QueryContext {
         })
     }
 
-    fn get_connection_id(&self) -> String {
+    fn get_connection_id(&self) -> u64 {
         self.shared.get_connection_id()
     }
 
@@ -706,7 +747,6 @@ impl TableContext for QueryContext {
                     .unchecked_apply_changes(self.shared.get_settings().changes());
             }
         }
-
         self.query_settings.clone()
     }
 
@@ -723,6 +763,15 @@ impl TableContext for QueryContext {
         SessionManager::instance().processes_info()
     }
 
+    fn get_server_status(&self) -> ServerStatus {
+        let status = SessionManager::instance().get_current_session_status();
+        ServerStatus {
+            uptime: status.instance_started_at.elapsed().unwrap_or_default(),
+            queries: status.total_queries_count,
+            threads_connected: status.active_sessions_count,
+        }
+    }
+
     fn get_queued_queries(&self) -> Vec<ProcessInfo> {
         let queries = QueriesQueueManager::instance()
             .list()
@@ -1029,6 +1078,14 @@ impl TableContext for QueryContext {
         SessionManager::instance().get_queries_profiles()
     }
 
+    #[async_backtrace::framed]
+    async fn ping_cluster_nodes(
+        &self,
+        timeout: Duration,
+    ) -> HashMap<String, std::result::Result<NodeStatus, String>> {
+        self.get_cluster().ping_nodes(timeout).await
+    }
+
     fn set_merge_into_join(&self, join: MergeIntoJoin) {
         let mut merge_into_join = self.shared.merge_into_join.write();
         *merge_into_join = join;
@@ -40,6 +40,7 @@ use databend_common_meta_app::principal::OnErrorMode;
 use databend_common_meta_app::principal::RoleInfo;
 use databend_common_meta_app::principal::UserDefinedConnection;
 use databend_common_meta_app::principal::UserInfo;
+use databend_common_meta_app::principal::UserQuota;
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_pipeline_core::processors::PlanProfile;
 use databend_common_pipeline_core::InputError;
@@ -49,6 +50,7 @@ use databend_common_storage::CopyStatus;
 use databend_common_storage::DataOperator;
 use databend_common_storage::MergeStatus;
 use databend_common_storage::MultiTableInsertStatus;
+use databend_common_storage::SpillFiles;
 use databend_common_storage::StorageMetrics;
 use databend_common_storages_stream::stream_table::StreamTable;
 use databend_common_users::UserApiProvider;
@@ -57,9 +59,13 @@ use parking_lot::RwLock;
 use uuid::Uuid;
 
 use crate::clusters::Cluster;
+use crate::pipelines::executor::ExecutorPauseHandle;
 use crate::pipelines::executor::PipelineExecutor;
 use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::Session;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::UserQuerySlot;
 use crate::storages::Table;
 
 type DatabaseAndTable = (String, String, String);
@@ -120,6 +126,10 @@ pub struct QueryContextShared {
 
     // Client User-Agent
     pub(in crate::sessions) user_agent: Arc<RwLock<String>>,
+    // Client supplied label of the query, empty if none.
+    pub(in crate::sessions) query_tag: Arc<RwLock<String>>,
+    // Creation time of the query result cache entry which served the query, if any.
+    pub(in crate::sessions) result_cache_hit: Arc<RwLock<Option<u64>>>,
     /// Key is (cte index, used_count), value contains cte's materialized blocks
     pub(in crate::sessions) materialized_cte_tables: MaterializedCtesBlocks,
 
@@ -133,6 +143,14 @@ pub struct QueryContextShared {
     pub(in crate::sessions) query_cache_metrics: DataCacheMetrics,
 
     pub(in crate::sessions) query_queued_duration: Arc<RwLock<Duration>>,
+    // Whether the query is waiting in the queries queue.
+    pub(in crate::sessions) queued: AtomicBool,
+
+    // Quota of the user running the query, None if the query is not limited by it.
+    pub(in crate::sessions) user_quota: Option<UserQuota>,
+    // Taken when the query is admitted and held for the lifetime of the query to count it
+    // against `max_concurrent_queries`.
+    pub(in crate::sessions) user_query_slot: Mutex<Option<UserQuerySlot>>,
 }
 
 impl QueryContextShared {
@@ -140,6 +158,15 @@ impl QueryContextShared {
         session: Arc<Session>,
         cluster_cache: Arc<Cluster>,
     ) -> Result<Arc<QueryContextShared>> {
+        // The fragments of a cluster query were admitted on the node the query was submitted to.
+        let mut user_quota = None;
+        let typ = session.get_type();
+        if typ.is_user_session() && typ != SessionType::FlightRPC {
+            if let Ok(user) = session.get_current_user() {
+                user_quota = Some(user.quota);
+            }
+        }
+
         Ok(Arc::new(QueryContextShared {
             catalog_manager: CatalogManager::instance(),
             session,
@@ -175,6 +202,8 @@ impl QueryContextShared {
             enable_sort_spill: Arc::new(AtomicBool::new(true)),
             status: Arc::new(RwLock::new("null".to_string())),
             user_agent: Arc::new(RwLock::new("null".to_string())),
+            query_tag: Arc::new(RwLock::new(String::new())),
+            result_cache_hit: Arc::new(RwLock::new(None)),
             materialized_cte_tables: Arc::new(Default::default()),
             join_spill_progress: Arc::new(Progress::create()),
             agg_spill_progress: Arc::new(Progress::create()),
@@ -185,6 +214,9 @@ impl QueryContextShared {
             merge_into_join: Default::default(),
             multi_table_insert_status: Default::default(),
             query_queued_duration: Arc::new(RwLock::new(Duration::from_secs(0))),
+            queued: AtomicBool::new(false),
+            user_quota,
+            user_query_slot: Mutex::new(None),
         }))
     }
 
@@ -301,6 +333,37 @@ impl QueryContextShared {
         self.session.get_settings()
     }
 
+    /// Takes a slot of the user for the query when it's admitted, it's given back when the
+    /// query context is dropped. Fails if all the slots of the user are taken.
+    pub(in crate::sessions) fn acquire_user_query_slot(&self) -> Result<()> {
+        if self.user_quota.is_none() {
+            return Ok(());
+        }
+
+        let mut slot = self.user_query_slot.lock();
+        if slot.is_none() {
+            let user = self.session.get_current_user()?;
+            *slot = SessionManager::instance().user_queries.try_acquire(&user)?;
+        }
+        Ok(())
+    }
+
+    /// Lowers the memory and result rows limits of the query settings to the user quota.
+    pub(in crate::sessions) fn apply_user_quota(&self, settings: &Settings) -> Result<()> {
+        let Some(quota) = &self.user_quota else {
+            return Ok(());
+        };
+
+        let exceeds = |value: u64, limit: u64| limit != 0 && (value == 0 || value > limit);
+        if exceeds(settings.get_max_memory_usage()?, quota.max_memory_per_query) {
+            settings.set_max_memory_usage(quota.max_memory_per_query)?;
+        }
+        if exceeds(settings.get_max_result_rows()?, quota.max_result_rows) {
+            settings.set_max_result_rows(quota.max_result_rows)?;
+        }
+        Ok(())
+    }
+
     pub fn attach_table(&self, catalog: &str, database: &str, name: &str, table: Arc<dyn Table>) {
         let mut tables_refs = self.tables_refs.lock();
         let table_meta_key = (catalog.to_string(), database.to_string(), name.to_string());
@@ -532,8 +595,9 @@ impl QueryContextShared {
             .unwrap_or(QueryKind::Unknown)
     }
 
-    pub fn get_connection_id(&self) -> String {
-        self.session.get_id()
+    /// The numeric connection id of the session, the one of the MySQL handshake for the MySQL sessions, as KILL takes it.
+    pub fn get_connection_id(&self) -> u64 {
+        self.session.get_mysql_conn_id().unwrap_or_default() as u64
     }
 
     pub fn get_affect(&self) -> Option<QueryAffect> {
@@ -573,11 +637,20 @@ impl QueryContextShared {
         self.created_time
     }
 
+    pub fn is_queued(&self) -> bool {
+        self.queued.load(Ordering::Acquire)
+    }
+
     pub fn get_status_info(&self) -> String {
         let status = self.status.read();
         status.clone()
     }
 
+    pub fn get_query_tag(&self) -> String {
+        let query_tag = self.query_tag.read();
+        query_tag.clone()
+    }
+
     pub async fn get_connection(&self, name: &str) -> Result<UserDefinedConnection> {
         let user_mgr = UserApiProvider::instance();
         let tenant = self.get_tenant();
@@ -594,6 +667,13 @@ impl QueryContextShared {
         }
     }
 
+    pub fn get_pause_handle(&self) -> Option<Arc<ExecutorPauseHandle>> {
+        self.executor
+            .read()
+            .upgrade()
+            .and_then(|executor| executor.get_pause_handle())
+    }
+
     pub fn get_query_profiles(&self) -> Vec<PlanProfile> {
         if let Some(executor) = self.executor.read().upgrade() {
             self.add_query_profiles(&executor.fetch_profiling(false));
@@ -626,7 +706,9 @@ impl Drop for QueryContextShared {
             // to avoid returning the query_id of the current statement.
             self.session
                 .session_ctx
-                .update_query_ids_results(self.init_query_id.read().clone(), None)
+                .update_query_ids_results(self.init_query_id.read().clone(), None);
+
+            SpillFiles::instance().forget_query(&self.init_query_id.read());
         })
     }
 }
@@ -38,6 +38,7 @@ use databend_common_metrics::session::inc_session_running_acquired_queries;
 use databend_common_metrics::session::incr_session_queue_abort_count;
 use databend_common_metrics::session::incr_session_queue_acquire_error_count;
 use databend_common_metrics::session::incr_session_queue_acquire_timeout_count;
+use databend_common_metrics::session::incr_session_queue_reject_count;
 use databend_common_metrics::session::record_session_queue_acquire_duration_ms;
 use databend_common_metrics::session::set_session_queued_queries;
 use databend_common_sql::plans::Plan;
@@ -77,13 +78,18 @@ pub(crate) struct Inner<Data: QueueData> {
 
 pub struct QueueManager<Data: QueueData> {
     semaphore: Arc<Semaphore>,
+    // The max length of the queue, 0 means unlimited.
+    max_queued: usize,
     queue: Mutex<HashMap<Data::Key, Inner<Data>>>,
 }
 
 impl<Data: QueueData> QueueManager<Data> {
-    pub fn init(permits: usize) -> Result<()> {
-        info!("queue manager permits: {:?}", permits);
-        GlobalInstance::set(Self::create(permits));
+    pub fn init(permits: usize, max_queued: usize) -> Result<()> {
+        info!(
+            "queue manager permits: {:?}, max queued: {:?}",
+            permits, max_queued
+        );
+        GlobalInstance::set(Self::create(permits, max_queued));
         Ok(())
     }
 
@@ -91,12 +97,13 @@ impl<Data: QueueData> QueueManager<Data> {
         GlobalInstance::get::<Arc<Self>>()
     }
 
-    pub fn create(mut permits: usize) -> Arc<QueueManager<Data>> {
+    pub fn create(mut permits: usize, max_queued: usize) -> Arc<QueueManager<Data>> {
         if permits == 0 {
             permits = usize::MAX >> 4;
         }
 
         Arc::new(QueueManager {
+            max_queued,
             queue: Mutex::new(HashMap::new()),
             semaphore: Arc::new(Semaphore::new(permits)),
         })
@@ -129,8 +136,20 @@ impl<Data: QueueData> QueueManager<Data> {
         }
     }
 
+    /// Waits for a running slot in FIFO order, the data is listed in the queue meanwhile.
+    ///
+    /// Fails at once if the data would wait behind `max_queued` others, and once the timeout
+    /// of the data elapses.
     pub async fn acquire(self: &Arc<Self>, data: Data) -> Result<AcquireQueueGuard> {
         if data.need_acquire_to_queue() {
+            if self.is_full() {
+                incr_session_queue_reject_count();
+                return Err(ErrorCode::TooManyQueuedQueries(format!(
+                    "Too many queued queries, the max is {}",
+                    self.max_queued
+                )));
+            }
+
             let timeout = data.timeout();
             let future = AcquireQueueFuture::create(
                 Arc::new(data),
@@ -167,6 +186,13 @@ impl<Data: QueueData> QueueManager<Data> {
         Ok(AcquireQueueGuard::create(None))
     }
 
+    // The semaphore is fair, so a free permit means no one is waiting.
+    fn is_full(&self) -> bool {
+        self.max_queued != 0
+            && self.semaphore.available_permits() == 0
+            && self.length() >= self.max_queued
+    }
+
     pub(crate) fn add_entity(&self, inner: Inner<Data>) -> Data::Key {
         inner.data.enter_wait_pending();
 
@@ -231,6 +257,18 @@ where T: Future<Output = Result<Result<OwnedSemaphorePermit, AcquireError>, Elap
     key: Option<Data::Key>,
     manager: Arc<QueueManager<Data>>,
 }
+
+    // A query given up while waiting, e.g. by a disconnected client, leaves the queue.
+    impl<Data: QueueData, T> PinnedDrop for AcquireQueueFuture<Data, T>
+    where T: Future<Output = Result<Result<OwnedSemaphorePermit, AcquireError>, Elapsed>>
+    {
+        fn drop(this: Pin<&mut Self>) {
+            let this = this.project();
+            if let Some(key) = this.key.take() {
+                this.manager.remove_entity(&key);
+            }
+        }
+    }
 }
 
 impl<Data: QueueData, T> AcquireQueueFuture<Data, T>
@@ -311,6 +349,10 @@ impl QueryEntry {
         plan_extras: &PlanExtras,
         need_acquire_to_queue: bool,
     ) -> Result<QueryEntry> {
+        // The query is admitted here, before it waits in the queue.
+        ctx.acquire_user_query_slot()?;
+        ctx.apply_user_quota()?;
+
         let settings = ctx.get_settings();
         Ok(QueryEntry {
             ctx: ctx.clone(),
@@ -436,10 +478,12 @@ impl QueueData for QueryEntry {
     }
 
     fn enter_wait_pending(&self) {
+        self.ctx.set_queued(true);
         self.ctx.set_status_info("resources scheduling");
     }
 
     fn exit_wait_pending(&self, wait_time: Duration) {
+        self.ctx.set_queued(false);
         self.ctx
             .set_status_info(format!("resource scheduled(elapsed: {:?})", wait_time).as_str());
         self.ctx.set_query_queued_duration(wait_time)
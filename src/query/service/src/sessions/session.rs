@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
 
 use databend_common_base::runtime::drop_guard;
 use databend_common_catalog::cluster_info::Cluster;
@@ -44,12 +46,14 @@ use crate::sessions::SessionContext;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionStatus;
 use crate::sessions::SessionType;
+use crate::sessions::SessionWarnings;
 
 pub struct Session {
     pub(in crate::sessions) id: String,
     pub(in crate::sessions) typ: RwLock<SessionType>,
     pub(in crate::sessions) session_ctx: Box<SessionContext>,
     status: Arc<RwLock<SessionStatus>>,
+    // The numeric id of the session, assigned by the session manager on creation.
     pub(in crate::sessions) mysql_connection_id: Option<u32>,
     format_settings: FormatSettings,
 }
@@ -143,6 +147,33 @@ impl Session {
         }
     }
 
+    /// How long the session may stay without any query, `None` if it never expires.
+    ///
+    /// It's the idle timeout setting of the interactive or the pooled sessions. The
+    /// `max_idle_time` of the handler owning the session is left to that handler, e.g. the
+    /// FlightSQL sessions are dropped by the handler once their keep-alive expires.
+    pub fn get_idle_timeout(&self) -> Result<Option<Duration>> {
+        let settings = self.get_settings();
+        let timeout_secs = match settings.get_pooled_session()? {
+            true => settings.get_pooled_idle_session_timeout_secs()?,
+            false => settings.get_idle_session_timeout_secs()?,
+        };
+        Ok((timeout_secs != 0).then(|| Duration::from_secs(timeout_secs)))
+    }
+
+    /// Closes the idle session, the query still attached to it is killed.
+    pub fn close_idle(&self) {
+        self.status.write().closed_by_idle_timeout = true;
+        self.force_kill_query(ErrorCode::AbortedSession(
+            "Aborted query, because the session was closed after being idle too long",
+        ));
+        self.kill();
+    }
+
+    pub fn is_closed_by_idle_timeout(&self) -> bool {
+        self.status.read().closed_by_idle_timeout
+    }
+
     /// Create a query context for query.
     /// For a query, execution environment(e.g cluster) should be immutable.
     /// We can bind the environment to the context in create_context method.
@@ -185,6 +216,10 @@ impl Session {
         self.session_ctx.set_io_shutdown_tx(io_shutdown);
     }
 
+    pub fn get_client_host(&self) -> Option<String> {
+        self.session_ctx.get_client_host()
+    }
+
     pub fn set_client_host(&self, host: Option<String>) {
         self.session_ctx.set_client_host(host);
     }
@@ -344,6 +379,20 @@ impl Session {
         self.session_ctx.set_txn_mgr(txn_mgr)
     }
 
+    pub fn get_warnings(&self) -> Arc<SessionWarnings> {
+        self.session_ctx.get_warnings()
+    }
+
+    pub fn get_labels(&self) -> BTreeMap<String, String> {
+        self.session_ctx.get_labels()
+    }
+
+    /// Adds the labels of the client to the session, they are sanitized and limited in number
+    /// and size.
+    pub fn add_labels(&self, labels: impl IntoIterator<Item = (String, String)>) {
+        self.session_ctx.add_labels(labels)
+    }
+
     pub fn set_query_priority(&self, priority: u8) {
         if let Some(context_shared) = self.session_ctx.get_query_context_shared() {
             context_shared.set_priority(priority);
@@ -362,7 +411,7 @@ impl Drop for Session {
     fn drop(&mut self) {
         drop_guard(move || {
             debug!("Drop session {}", self.id.clone());
-            SessionManager::instance().destroy_session(&self.id.clone());
+            SessionManager::instance().destroy_session(self);
         })
     }
 }
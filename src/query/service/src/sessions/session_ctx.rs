@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashSet;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
@@ -31,6 +32,8 @@ use parking_lot::RwLock;
 
 use super::SessionType;
 use crate::sessions::QueryContextShared;
+use crate::sessions::SessionLabels;
+use crate::sessions::SessionWarnings;
 
 pub struct SessionContext {
     abort: AtomicBool,
@@ -69,6 +72,10 @@ pub struct SessionContext {
     query_ids_results: RwLock<Vec<(String, Option<String>)>>,
     typ: SessionType,
     txn_mgr: Mutex<TxnManagerRef>,
+    /// The warnings of the last statement, shown by `SHOW WARNINGS` of the MySQL handler.
+    warnings: Arc<SessionWarnings>,
+    /// The labels set by the client, like the application name.
+    labels: SessionLabels,
 }
 
 impl SessionContext {
@@ -89,6 +96,8 @@ impl SessionContext {
             query_ids_results: Default::default(),
             typ,
             txn_mgr: Mutex::new(TxnManager::init()),
+            warnings: Default::default(),
+            labels: Default::default(),
         })
     }
 
@@ -299,4 +308,16 @@ impl SessionContext {
     pub fn set_txn_mgr(&self, txn_mgr: TxnManagerRef) {
         *self.txn_mgr.lock() = txn_mgr;
     }
+
+    pub fn get_warnings(&self) -> Arc<SessionWarnings> {
+        self.warnings.clone()
+    }
+
+    pub fn get_labels(&self) -> BTreeMap<String, String> {
+        self.labels.get()
+    }
+
+    pub fn add_labels(&self, labels: impl IntoIterator<Item = (String, String)>) {
+        self.labels.extend(labels)
+    }
 }
@@ -54,12 +54,16 @@ impl Session {
             memory_usage,
             data_metrics: Self::query_data_metrics(session_ctx),
             scan_progress_value: Self::query_scan_progress_value(session_ctx),
-            mysql_connection_id: self.mysql_connection_id,
+            mysql_connection_id: self.get_mysql_conn_id(),
             created_time: Self::query_created_time(session_ctx),
             status_info: shared_query_context
                 .as_ref()
                 .map(|qry_ctx| qry_ctx.get_status_info()),
             current_query_id: self.get_current_query_id(),
+            query_tag: shared_query_context
+                .as_ref()
+                .map(|qry_ctx| qry_ctx.get_query_tag()),
+            labels: session_ctx.get_labels(),
         }
     }
 
@@ -67,6 +71,7 @@ impl Session {
         match status.get_query_context_shared() {
             _ if status.get_abort() => ProcessInfoState::Aborting,
             None => ProcessInfoState::Idle,
+            Some(shared) if shared.is_queued() => ProcessInfoState::Queued,
             Some(_) => ProcessInfoState::Query,
         }
     }
@@ -0,0 +1,80 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use parking_lot::RwLock;
+
+/// The labels a session keeps, the labels beyond it are dropped.
+const MAX_SESSION_LABELS: usize = 16;
+/// The longer keys are truncated.
+const MAX_LABEL_KEY_LEN: usize = 64;
+/// The longer values are truncated.
+const MAX_LABEL_VALUE_LEN: usize = 256;
+
+/// The label the application name of the client is kept as.
+pub const APP_NAME_LABEL: &str = "app";
+
+/// The labels set by the client of a session, like the application connecting, shown with the
+/// session in `system.processes` and with its queries in `system.query_log`.
+///
+/// The keys are lowercase, made of letters, digits, `_`, `-` and `.`, the other ones are dropped.
+/// The control characters of the values are removed.
+#[derive(Default)]
+pub struct SessionLabels {
+    labels: RwLock<BTreeMap<String, String>>,
+}
+
+impl SessionLabels {
+    pub fn get(&self) -> BTreeMap<String, String> {
+        self.labels.read().clone()
+    }
+
+    /// Adds the labels, replacing the ones with the same keys.
+    pub fn extend(&self, labels: impl IntoIterator<Item = (String, String)>) {
+        let mut lock = self.labels.write();
+        for (key, value) in labels {
+            let Some((key, value)) = sanitize_label(&key, &value) else {
+                continue;
+            };
+            if lock.len() < MAX_SESSION_LABELS || lock.contains_key(&key) {
+                lock.insert(key, value);
+            }
+        }
+    }
+}
+
+/// Parses the labels in the form of `key1=value1,key2=value2`, the items without `=` are ignored.
+pub fn parse_labels(text: &str) -> Vec<(String, String)> {
+    text.split(',')
+        .filter_map(|item| item.split_once('='))
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+fn sanitize_label(key: &str, value: &str) -> Option<(String, String)> {
+    let key = key.trim().to_lowercase();
+    let valid = |c: char| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.');
+    if key.is_empty() || !key.chars().all(valid) {
+        return None;
+    }
+    let key = key.chars().take(MAX_LABEL_KEY_LEN).collect();
+    let value = value
+        .trim()
+        .chars()
+        .filter(|c| !c.is_control())
+        .take(MAX_LABEL_VALUE_LEN)
+        .collect();
+    Some((key, value))
+}
@@ -14,22 +14,24 @@
 
 use std::collections::HashMap;
 use std::future::Future;
-use std::ops::DerefMut;
 use std::sync::atomic::AtomicU32;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Weak;
 use std::time::Duration;
+use std::time::Instant;
 use std::time::SystemTime;
 
 use databend_common_base::base::tokio;
 use databend_common_base::base::GlobalInstance;
 use databend_common_base::base::SignalStream;
+use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_catalog::table_context::ProcessInfoState;
 use databend_common_config::GlobalConfig;
 use databend_common_config::InnerConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal::UserIdentity;
 use databend_common_metrics::session::*;
 use databend_common_pipeline_core::PlanProfile;
 use databend_common_settings::Settings;
@@ -43,20 +45,30 @@ use crate::sessions::ProcessInfo;
 use crate::sessions::SessionContext;
 use crate::sessions::SessionManagerStatus;
 use crate::sessions::SessionType;
+use crate::sessions::UserQueryCounter;
+
+/// How often the idle sessions are looked for.
+const IDLE_SESSIONS_SWEEP_INTERVAL: Duration = Duration::from_secs(10);
 
 pub struct SessionManager {
     pub(in crate::sessions) max_sessions: usize,
     pub(in crate::sessions) active_sessions: Arc<RwLock<HashMap<String, Weak<Session>>>>,
     pub status: Arc<RwLock<SessionManagerStatus>>,
 
-    // When typ is MySQL, insert into this map, key is id, val is MySQL connection id.
+    // When typ is MySQL, insert into this map, key is the connection id, val is the session id.
+    // The other sessions have a numeric id as well, they are looked up by a scan.
     pub(crate) mysql_conn_map: Arc<RwLock<HashMap<Option<u32>, String>>>,
     pub(in crate::sessions) mysql_basic_conn_id: AtomicU32,
+
+    // Running queries of the users limited by `max_concurrent_queries`.
+    pub(in crate::sessions) user_queries: UserQueryCounter,
 }
 
 impl SessionManager {
     pub fn init(conf: &InnerConfig) -> Result<()> {
-        GlobalInstance::set(Self::create(conf));
+        let session_manager = Self::create(conf);
+        Self::spawn_idle_sessions_sweeper(Arc::downgrade(&session_manager));
+        GlobalInstance::set(session_manager);
 
         Ok(())
     }
@@ -69,6 +81,7 @@ impl SessionManager {
             status: Arc::new(RwLock::new(SessionManagerStatus::default())),
             mysql_conn_map: Arc::new(RwLock::new(HashMap::with_capacity(max_sessions))),
             active_sessions: Arc::new(RwLock::new(HashMap::with_capacity(max_sessions))),
+            user_queries: UserQueryCounter::default(),
         })
     }
 
@@ -112,6 +125,12 @@ impl SessionManager {
             self.validate_max_active_sessions(sessions.len(), "active sessions")?;
             sessions.insert(session.get_id(), Arc::downgrade(&session));
             set_session_active_connections(sessions.len());
+
+            if let (SessionType::MySQL, Some(mysql_conn_id)) = (&typ, session.get_mysql_conn_id()) {
+                self.mysql_conn_map
+                    .write()
+                    .insert(Some(mysql_conn_id), session.get_id());
+            }
         }
         incr_session_connect_numbers();
         Ok(())
@@ -141,10 +160,8 @@ impl SessionManager {
         settings: Arc<Settings>,
     ) -> Result<Session> {
         let id = uuid::Uuid::new_v4().to_string();
-        let mysql_conn_id = match typ {
-            SessionType::MySQL => Some(self.mysql_basic_conn_id.fetch_add(1, Ordering::Relaxed)),
-            _ => None,
-        };
+        // Every session has a numeric connection id, the one of the handshake for MySQL.
+        let mysql_conn_id = Some(self.mysql_basic_conn_id.fetch_add(1, Ordering::Relaxed));
 
         let session_ctx = SessionContext::try_create(settings, typ.clone())?;
         let session = Session::try_create(
@@ -161,19 +178,10 @@ impl SessionManager {
     ///
     /// Return a shared reference to the session.
     pub fn register_session(&self, session: Session) -> Result<Arc<Session>> {
-        let id = session.get_id();
         let typ = session.get_type();
-        let mysql_conn_id = session.get_mysql_conn_id();
 
         let session = Arc::new(session);
-        self.try_add_session(session.clone(), typ.clone())?;
-
-        if let SessionType::MySQL = typ {
-            let mut mysql_conn_map = self.mysql_conn_map.write();
-            self.validate_max_active_sessions(mysql_conn_map.len(), "mysql conns")?;
-
-            mysql_conn_map.insert(mysql_conn_id, id);
-        }
+        self.try_add_session(session.clone(), typ)?;
 
         Ok(session)
     }
@@ -184,37 +192,111 @@ impl SessionManager {
     }
 
     pub fn get_id_by_mysql_conn_id(&self, mysql_conn_id: &Option<u32>) -> Option<String> {
-        let sessions = self.mysql_conn_map.read();
-        sessions.get(mysql_conn_id).cloned()
+        if let Some(id) = self.mysql_conn_map.read().get(mysql_conn_id) {
+            return Some(id.clone());
+        }
+
+        // Not a MySQL session, KILL is rare enough to scan the others.
+        let active_sessions = {
+            let active_sessions_guard = self.active_sessions.read();
+            active_sessions_guard.values().cloned().collect::<Vec<_>>()
+        };
+        active_sessions
+            .iter()
+            .filter_map(|weak_ptr| weak_ptr.upgrade())
+            .find(|session| session.get_mysql_conn_id() == *mysql_conn_id)
+            .map(|session| session.get_id())
     }
 
-    pub fn destroy_session(&self, session_id: &String) {
+    pub fn destroy_session(&self, session: &Session) {
         // NOTE: order and scope of lock are very important. It's will cause deadlock
+        self.untrack_session(session);
+
+        {
+            let sessions_count = { self.active_sessions.read().len() };
 
+            incr_session_close_numbers();
+            set_session_active_connections(sessions_count);
+        }
+    }
+
+    fn untrack_session(&self, session: &Session) {
         // stop tracking session
         {
             // Make sure this write lock has been released before dropping.
             // Because dropping session could re-enter `destroy_session`.
-            let weak_session = { self.active_sessions.write().remove(session_id) };
+            let weak_session = { self.active_sessions.write().remove(&session.get_id()) };
             drop(weak_session);
         }
 
         // also need remove mysql_conn_map
-        {
-            let mut mysql_conns_map = self.mysql_conn_map.write();
-            for (k, v) in mysql_conns_map.deref_mut().clone() {
-                if &v == session_id {
-                    mysql_conns_map.remove(&k);
-                }
-            }
+        if let Some(mysql_conn_id) = session.get_mysql_conn_id() {
+            self.mysql_conn_map.write().remove(&Some(mysql_conn_id));
         }
+    }
 
-        {
-            let sessions_count = { self.active_sessions.read().len() };
+    /// Closes the sessions which have no query for longer than their idle timeout, and
+    /// stops tracking them, returns the ids of the closed sessions.
+    ///
+    /// `now` is taken by the caller, so the tests can move the clock forward.
+    pub fn close_idle_sessions(&self, now: Instant) -> Vec<String> {
+        // Same as `processes_info`, the lock is released before upgrading the sessions.
+        let active_sessions = {
+            let active_sessions_guard = self.active_sessions.read();
+            active_sessions_guard.values().cloned().collect::<Vec<_>>()
+        };
 
-            incr_session_close_numbers();
-            set_session_active_connections(sessions_count);
+        let mut closed = vec![];
+        for weak_ptr in active_sessions {
+            let Some(session) = weak_ptr.upgrade() else {
+                continue;
+            };
+
+            if session.is_aborting() || session.get_current_query_id().is_some() {
+                continue;
+            }
+
+            let idle_timeout = match session.get_idle_timeout() {
+                Ok(Some(idle_timeout)) => idle_timeout,
+                _ => continue,
+            };
+
+            let idle_time = {
+                let status = session.get_status();
+                let status = status.read();
+                now.saturating_duration_since(status.last_access())
+            };
+
+            if idle_time >= idle_timeout {
+                info!(
+                    "Close the {} session {}, it's idle for {:?}",
+                    session.get_type(),
+                    session.get_id(),
+                    idle_time
+                );
+                // The session is destroyed when the handler drops it.
+                session.close_idle();
+                self.untrack_session(&session);
+                set_session_active_connections(self.active_sessions.read().len());
+                closed.push(session.get_id());
+            }
         }
+
+        closed
+    }
+
+    fn spawn_idle_sessions_sweeper(session_manager: Weak<SessionManager>) {
+        GlobalIORuntime::instance().spawn(async move {
+            loop {
+                tokio::time::sleep(IDLE_SESSIONS_SWEEP_INTERVAL).await;
+                match session_manager.upgrade() {
+                    None => break,
+                    Some(session_manager) => {
+                        session_manager.close_idle_sessions(Instant::now());
+                    }
+                }
+            }
+        });
     }
 
     pub fn graceful_shutdown(
@@ -313,6 +395,10 @@ impl SessionManager {
         }
     }
 
+    pub fn get_user_running_queries(&self, user: &UserIdentity) -> u64 {
+        self.user_queries.running_queries(user)
+    }
+
     fn validate_max_active_sessions(&self, count: usize, reason: &str) -> Result<()> {
         if count >= self.max_sessions {
             return Err(ErrorCode::TooManyUserConnections(format!(
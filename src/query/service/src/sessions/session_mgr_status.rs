@@ -17,6 +17,8 @@ use std::time::SystemTime;
 #[derive(Clone)]
 pub struct SessionManagerStatus {
     pub running_queries_count: u64,
+    /// The queries started since the instance started.
+    pub total_queries_count: u64,
     pub active_sessions_count: u64,
     pub max_running_query_execute_time: u64,
     pub last_query_started_at: Option<SystemTime>,
@@ -27,6 +29,7 @@ pub struct SessionManagerStatus {
 impl SessionManagerStatus {
     pub(crate) fn query_start(&mut self, now: SystemTime) {
         self.running_queries_count += 1;
+        self.total_queries_count += 1;
         self.last_query_started_at = Some(now)
     }
 
@@ -42,6 +45,7 @@ impl Default for SessionManagerStatus {
     fn default() -> Self {
         SessionManagerStatus {
             running_queries_count: 0,
+            total_queries_count: 0,
             active_sessions_count: 0,
             max_running_query_execute_time: 0,
             last_query_started_at: None,
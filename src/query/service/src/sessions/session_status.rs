@@ -12,12 +12,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::time::Duration;
 use std::time::Instant;
 
 pub struct SessionStatus {
     pub session_started_at: Instant,
     pub last_query_finished_at: Option<Instant>,
     pub is_native_client: bool,
+    pub last_keep_alive_at: Option<Instant>,
+    /// How long the session may stay idle before the handler owning it drops it.
+    pub max_idle_time: Option<Duration>,
+    /// Whether the timestamps of the results carry the session timezone, asked by a FlightSQL
+    /// client sending the `databend-timezone` header at the handshake.
+    pub timezone_in_results: bool,
+    /// Whether the session is closed by the server after it stayed idle too long.
+    pub closed_by_idle_timeout: bool,
 }
 
 impl SessionStatus {
@@ -25,9 +34,18 @@ impl SessionStatus {
         self.last_query_finished_at = Some(Instant::now())
     }
 
+    pub(crate) fn keep_alive(&mut self) {
+        self.last_keep_alive_at = Some(Instant::now())
+    }
+
     pub(crate) fn last_access(&self) -> Instant {
-        self.last_query_finished_at
-            .unwrap_or(self.session_started_at)
+        let last_query_access = self
+            .last_query_finished_at
+            .unwrap_or(self.session_started_at);
+        match self.last_keep_alive_at {
+            Some(keep_alive_at) => last_query_access.max(keep_alive_at),
+            None => last_query_access,
+        }
     }
 }
 
@@ -37,6 +55,10 @@ impl Default for SessionStatus {
             session_started_at: Instant::now(),
             last_query_finished_at: None,
             is_native_client: false,
+            last_keep_alive_at: None,
+            max_idle_time: None,
+            timezone_in_results: false,
+            closed_by_idle_timeout: false,
         }
     }
 }
@@ -0,0 +1,110 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use parking_lot::Mutex;
+
+/// The MySQL `max_error_count`, the warnings beyond it are only counted.
+const MAX_WARNINGS: usize = 1024;
+/// The MySQL `MYSQL_ERRMSG_SIZE`, the longer messages are truncated.
+const MAX_MESSAGE_LEN: usize = 512;
+
+/// The error code of the warnings without a specific one, `ER_UNKNOWN_ERROR` of MySQL.
+pub const WARN_UNKNOWN: u16 = 1105;
+/// The SET of a variable Databend doesn't have, `ER_UNKNOWN_SYSTEM_VARIABLE` of MySQL.
+pub const WARN_UNKNOWN_VARIABLE: u16 = 1193;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WarningLevel {
+    Note,
+    Warning,
+    Error,
+}
+
+impl fmt::Display for WarningLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WarningLevel::Note => write!(f, "Note"),
+            WarningLevel::Warning => write!(f, "Warning"),
+            WarningLevel::Error => write!(f, "Error"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SessionWarning {
+    pub level: WarningLevel,
+    pub code: u16,
+    pub message: String,
+}
+
+/// The warnings of the last statement of the session, `SHOW WARNINGS` of MySQL.
+///
+/// A statement clears the warnings of the previous one, except the statements showing them.
+#[derive(Default)]
+pub struct SessionWarnings {
+    // The warnings and the count of all the warnings, including the dropped ones.
+    warnings: Mutex<(Vec<SessionWarning>, usize)>,
+}
+
+impl SessionWarnings {
+    pub fn push(&self, level: WarningLevel, code: u16, message: impl ToString) {
+        let mut message = message.to_string();
+        if message.len() > MAX_MESSAGE_LEN {
+            let mut end = MAX_MESSAGE_LEN;
+            while !message.is_char_boundary(end) {
+                end -= 1;
+            }
+            message.truncate(end);
+        }
+
+        let mut guard = self.warnings.lock();
+        let (warnings, count) = &mut *guard;
+        *count += 1;
+        if warnings.len() < MAX_WARNINGS {
+            warnings.push(SessionWarning {
+                level,
+                code,
+                message,
+            });
+        }
+    }
+
+    pub fn warn(&self, code: u16, message: impl ToString) {
+        self.push(WarningLevel::Warning, code, message)
+    }
+
+    pub fn clear(&self) {
+        *self.warnings.lock() = Default::default();
+    }
+
+    /// The count of the warnings, `@@warning_count` of MySQL.
+    pub fn warning_count(&self) -> usize {
+        self.warnings.lock().1
+    }
+
+    /// The count of the errors, `@@error_count` of MySQL.
+    pub fn error_count(&self) -> usize {
+        self.list()
+            .iter()
+            .filter(|w| w.level == WarningLevel::Error)
+            .count()
+    }
+
+    /// The warnings in the order they were raised.
+    pub fn list(&self) -> Vec<SessionWarning> {
+        self.warnings.lock().0.clone()
+    }
+}
@@ -0,0 +1,81 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::UserIdentity;
+use databend_common_meta_app::principal::UserInfo;
+use parking_lot::Mutex;
+
+type RunningQueries = Arc<Mutex<HashMap<String, u64>>>;
+
+/// Counts the running queries of the users whose quota limits `max_concurrent_queries`.
+#[derive(Default)]
+pub struct UserQueryCounter {
+    running: RunningQueries,
+}
+
+impl UserQueryCounter {
+    /// Takes a slot of the user for a new query, fails if all of them are taken.
+    /// Returns None if the user has no concurrency limit.
+    pub fn try_acquire(&self, user: &UserInfo) -> Result<Option<UserQuerySlot>> {
+        let limit = user.quota.max_concurrent_queries;
+        if limit == 0 {
+            return Ok(None);
+        }
+
+        let key = user.identity().display().to_string();
+        let mut running = self.running.lock();
+        let count = running.entry(key.clone()).or_default();
+        if *count >= limit {
+            return Err(ErrorCode::UserQuotaExceeded(format!(
+                "quota exceeded for user {}: {}/{} concurrent queries",
+                user.name, count, limit
+            )));
+        }
+
+        *count += 1;
+        Ok(Some(UserQuerySlot {
+            user: key,
+            running: self.running.clone(),
+        }))
+    }
+
+    pub fn running_queries(&self, user: &UserIdentity) -> u64 {
+        let key = user.display().to_string();
+        self.running.lock().get(&key).copied().unwrap_or_default()
+    }
+}
+
+/// A slot taken by a running query, it's given back when the query context is dropped,
+/// whether the query succeeded, failed or was killed.
+pub struct UserQuerySlot {
+    user: String,
+    running: RunningQueries,
+}
+
+impl Drop for UserQuerySlot {
+    fn drop(&mut self) {
+        let mut running = self.running.lock();
+        if let Some(count) = running.get_mut(&self.user) {
+            *count -= 1;
+            if *count == 0 {
+                running.remove(&self.user);
+            }
+        }
+    }
+}
@@ -30,6 +30,7 @@ use databend_common_exception::Result;
 use databend_common_expression::arrow::deserialize_column;
 use databend_common_expression::arrow::serialize_column;
 use databend_common_expression::DataBlock;
+use databend_common_storage::SpillFiles;
 use opendal::Operator;
 
 use crate::sessions::QueryContext;
@@ -78,7 +79,7 @@ pub struct Spiller {
     ctx: Arc<QueryContext>,
     operator: Operator,
     config: SpillerConfig,
-    _spiller_type: SpillerType,
+    spiller_type: SpillerType,
     spiller_buffer: SpillerBuffer,
     pub join_spilling_partition_bits: usize,
     /// 1 partition -> N partition files
@@ -102,7 +103,7 @@ impl Spiller {
             ctx: ctx.clone(),
             operator,
             config,
-            _spiller_type: spiller_type,
+            spiller_type,
             spiller_buffer: SpillerBuffer::create(ctx)?,
             join_spilling_partition_bits,
             partition_location: Default::default(),
@@ -175,6 +176,12 @@ impl Spiller {
             writer.write(data).await?;
         }
         writer.close().await?;
+        SpillFiles::instance().record(
+            &self.ctx.get_id(),
+            &self.spiller_type.to_string(),
+            &location,
+            write_bytes as u64,
+        );
 
         Profile::record_usize_profile(ProfileStatisticsName::SpillWriteCount, 1);
         Profile::record_usize_profile(ProfileStatisticsName::SpillWriteBytes, write_bytes);
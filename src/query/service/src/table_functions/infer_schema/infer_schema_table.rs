@@ -21,6 +21,7 @@ use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_args::TableArgs;
+use databend_common_catalog::table_function::TableFunctionDescription;
 use databend_common_exception::Result;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::TableDataType;
@@ -128,6 +129,15 @@ impl TableFunction for InferSchemaTable {
         self.name()
     }
 
+    fn describe(name: &str) -> Option<TableFunctionDescription> {
+        Some(TableFunctionDescription::create(
+            name,
+            "Infers the schema of staged parquet files",
+            "infer_schema(location => '@stage/path' [, pattern => ..., file_format => ...])",
+            "SELECT * FROM infer_schema(location => '@my_stage/data.parquet')",
+        ))
+    }
+
     fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
     where Self: 'a {
         self
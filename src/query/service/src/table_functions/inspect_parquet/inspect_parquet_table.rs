@@ -22,6 +22,7 @@ use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_args::TableArgs;
+use databend_common_catalog::table_function::TableFunctionDescription;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
@@ -170,6 +171,15 @@ impl TableFunction for InspectParquetTable {
         self.name()
     }
 
+    fn describe(name: &str) -> Option<TableFunctionDescription> {
+        Some(TableFunctionDescription::create(
+            name,
+            "Shows the metadata of a staged parquet file",
+            "inspect_parquet('@stage/path')",
+            "SELECT * FROM inspect_parquet('@my_stage/data.parquet')",
+        ))
+    }
+
     fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
     where Self: 'a {
         self
@@ -23,6 +23,7 @@ use databend_common_catalog::table::Table;
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_catalog::table_function::TableFunction;
+use databend_common_catalog::table_function::TableFunctionDescription;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::NumberDataType;
@@ -149,6 +150,15 @@ impl TableFunction for ListStageTable {
         self.name()
     }
 
+    fn describe(name: &str) -> Option<TableFunctionDescription> {
+        Some(TableFunctionDescription::create(
+            name,
+            "Lists the files in a stage",
+            "list_stage(location => '@stage/path' [, pattern => ...])",
+            "SELECT * FROM list_stage(location => '@my_stage/')",
+        ))
+    }
+
     fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
     where Self: 'a {
         self
@@ -25,6 +25,7 @@ mod srf;
 mod sync_crash_me;
 mod table_function;
 mod table_function_factory;
+mod table_history;
 
 pub use numbers::generate_numbers_parts;
 pub use numbers::NumbersPartInfo;
@@ -24,6 +24,7 @@ use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table::TableStatistics;
 use databend_common_catalog::table_args::TableArgs;
+use databend_common_catalog::table_function::TableFunctionDescription;
 use databend_common_exception::Result;
 use databend_common_expression::type_check::check_number;
 use databend_common_expression::types::number::NumberScalar;
@@ -272,6 +273,24 @@ impl TableFunction for NumbersTable {
         self.name()
     }
 
+    fn describe(name: &str) -> Option<TableFunctionDescription> {
+        let description = match name {
+            "numbers_mt" => {
+                "Generates the UInt64 column number, from 0 to count - 1, with multiple threads"
+            }
+            "numbers_local" => {
+                "Generates the UInt64 column number, from 0 to count - 1, on the local node only"
+            }
+            _ => "Generates the UInt64 column number, from 0 to count - 1",
+        };
+        Some(TableFunctionDescription::create(
+            name,
+            description,
+            &format!("{name}(count)"),
+            &format!("SELECT * FROM {name}(10)"),
+        ))
+    }
+
     fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
     where Self: 'a {
         self
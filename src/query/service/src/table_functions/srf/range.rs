@@ -23,6 +23,7 @@ use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_catalog::table_function::TableFunction;
+use databend_common_catalog::table_function::TableFunctionDescription;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::infer_schema_type;
@@ -133,6 +134,19 @@ impl TableFunction for RangeTable {
         self.name()
     }
 
+    fn describe(name: &str) -> Option<TableFunctionDescription> {
+        let description = match name {
+            "generate_series" => "Generates the values from start to stop, both inclusive, by step",
+            _ => "Generates the values from start to stop, stop exclusive, by step",
+        };
+        Some(TableFunctionDescription::create(
+            name,
+            description,
+            &format!("{name}(start, stop [, step])"),
+            &format!("SELECT * FROM {name}(1, 10, 2)"),
+        ))
+    }
+
     fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
     where Self: 'a {
         self
@@ -228,6 +242,7 @@ struct RangeSource<const INCLUSIVE: bool> {
     start: i64,
     end: i64,
     step: i64,
+    max_block_size: i64,
 }
 
 fn get_i64_number(scalar: &Scalar) -> Result<i64> {
@@ -264,24 +279,19 @@ impl<const INCLUSIVE: bool> RangeSource<INCLUSIVE> {
             }
         }
 
+        // A step going away from the end makes an empty series rather than an error.
         if step == 0 {
             return Err(ErrorCode::BadArguments("step must not be zero".to_string()));
-        } else if step > 0 && start > end {
-            return Err(ErrorCode::BadArguments(
-                "start must be less than or equal to end when step is positive".to_string(),
-            ));
-        } else if step < 0 && start < end {
-            return Err(ErrorCode::BadArguments(
-                "start must be greater than or equal to end when step is negative".to_string(),
-            ));
         }
 
+        let max_block_size = ctx.get_settings().get_max_block_size()? as i64;
         SyncSourcer::create(ctx.clone(), output, Self {
             current_idx: 0,
             data_type,
             start,
             end,
             step,
+            max_block_size,
             finished: false,
         })
     }
@@ -306,10 +316,8 @@ impl<const INCLUSIVE: bool> SyncSource for RangeSource<INCLUSIVE> {
             return Ok(None);
         }
 
-        static MAX_BLOCK_SIZE: i64 = 1024 * 1024;
-
-        let size =
-            ((self.end - current_start + (self.step + offset)) / self.step).min(MAX_BLOCK_SIZE);
+        let size = ((self.end - current_start + (self.step + offset)) / self.step)
+            .min(self.max_block_size.max(1));
 
         let column = match self.data_type {
             DataType::Number(_) => Int64Type::from_data(
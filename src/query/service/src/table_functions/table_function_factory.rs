@@ -16,6 +16,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use databend_common_catalog::table_args::TableArgs;
+use databend_common_catalog::table_function::TableFunctionDescription;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_types::MetaId;
@@ -48,6 +49,7 @@ use crate::table_functions::numbers::NumbersTable;
 use crate::table_functions::show_grants::ShowGrants;
 use crate::table_functions::srf::RangeTable;
 use crate::table_functions::sync_crash_me::SyncCrashMeTable;
+use crate::table_functions::table_history::TableHistoryTable;
 use crate::table_functions::GPT2SQLTable;
 use crate::table_functions::TableFunction;
 
@@ -82,6 +84,7 @@ where
 #[derive(Default)]
 pub struct TableFunctionFactory {
     creators: TableFunctionCreators,
+    descriptions: HashMap<String, TableFunctionDescription>,
 }
 
 impl TableFunctionFactory {
@@ -237,8 +240,39 @@ impl TableFunctionFactory {
             (next_id(), Arc::new(TaskHistoryTable::create)),
         );
 
+        creators.insert(
+            "table_history".to_string(),
+            (next_id(), Arc::new(TableHistoryTable::create)),
+        );
+
+        let descriptions = [
+            NumbersTable::describe("numbers"),
+            NumbersTable::describe("numbers_mt"),
+            NumbersTable::describe("numbers_local"),
+            FuseSnapshotTable::describe("fuse_snapshot"),
+            FuseSegmentTable::describe("fuse_segment"),
+            FuseBlockTable::describe("fuse_block"),
+            FuseColumnTable::describe("fuse_column"),
+            FuseStatisticTable::describe("fuse_statistic"),
+            FuseEncodingTable::describe("fuse_encoding"),
+            ClusteringInformationTable::describe("clustering_information"),
+            StreamStatusTable::describe("stream_status"),
+            InferSchemaTable::describe("infer_schema"),
+            InspectParquetTable::describe("inspect_parquet"),
+            ListStageTable::describe("list_stage"),
+            RangeTable::describe("generate_series"),
+            RangeTable::describe("range"),
+            TableHistoryTable::describe("table_history"),
+        ];
+        let descriptions = descriptions
+            .into_iter()
+            .flatten()
+            .map(|description| (description.name.clone(), description))
+            .collect();
+
         TableFunctionFactory {
             creators: RwLock::new(creators),
+            descriptions,
         }
     }
 
@@ -267,4 +301,16 @@ impl TableFunctionFactory {
             .map(|(name, _)| name.clone())
             .collect()
     }
+
+    /// The registered table functions in the order of [`Self::list`], a function without a
+    /// description is listed with only its name.
+    pub fn list_descriptions(&self) -> Vec<TableFunctionDescription> {
+        self.list()
+            .into_iter()
+            .map(|name| match self.descriptions.get(&name) {
+                Some(description) => description.clone(),
+                None => TableFunctionDescription::new(name),
+            })
+            .collect()
+    }
 }
@@ -0,0 +1,353 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use databend_common_catalog::catalog_kind::CATALOG_DEFAULT;
+use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::plan::PartStatistics;
+use databend_common_catalog::plan::Partitions;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_args::TableArgs;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_catalog::table_function::TableFunction;
+use databend_common_catalog::table_function::TableFunctionDescription;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::ListTableVersionsReq;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_meta_app::schema::TableVersion;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sources::AsyncSource;
+use databend_common_pipeline_sources::AsyncSourcer;
+use databend_common_storages_fuse::table_functions::parse_db_tb_args;
+use databend_common_storages_fuse::table_functions::string_literal;
+
+const TABLE_HISTORY: &str = "table_history";
+
+/// `table_history(database, table)` lists the versions the meta service keeps for a table name.
+///
+/// Every `CREATE [OR REPLACE] TABLE` binds a new table id to the name, the ids are kept in
+/// the order they were created until vacuum purges them. An id yields a `create` row at its
+/// creation, or an `alter` row if its schema differs from the one of the previous id, and a
+/// `drop` row if it has been dropped or replaced. An `ALTER TABLE` or `UNDROP TABLE` updates
+/// the meta of the id in place, the meta keeps these changes and they yield their own rows.
+///
+/// An id whose meta has been purged is listed as `purged`, and `truncated` is set on all the
+/// rows, since the history before and around it can not be reconstructed. So is it if the
+/// oldest changes of an id have been discarded.
+///
+/// Being a table function, it is not checked by the privilege access of a `SELECT`, the
+/// table must be visible to the user as in `system.tables`.
+pub struct TableHistoryTable {
+    db_name: String,
+    table_name: String,
+    table_info: TableInfo,
+}
+
+impl TableHistoryTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let (db_name, table_name) = parse_db_tb_args(&table_args, TABLE_HISTORY)?;
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: Self::schema(),
+                engine: TABLE_HISTORY.to_owned(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        Ok(Arc::new(Self {
+            db_name,
+            table_name,
+            table_info,
+        }))
+    }
+
+    fn schema() -> Arc<TableSchema> {
+        TableSchemaRefExt::create(vec![
+            TableField::new("version", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("table_id", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "timestamp",
+                TableDataType::Nullable(Box::new(TableDataType::Timestamp)),
+            ),
+            TableField::new("operation", TableDataType::String),
+            TableField::new(
+                "schema",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new("truncated", TableDataType::Boolean),
+        ])
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for TableHistoryTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        Ok((PartStatistics::default(), Partitions::default()))
+    }
+
+    fn table_args(&self) -> Option<TableArgs> {
+        Some(TableArgs::new_positioned(vec![
+            string_literal(self.db_name.as_str()),
+            string_literal(self.table_name.as_str()),
+        ]))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+        _put_cache: bool,
+    ) -> Result<()> {
+        pipeline.add_source(
+            |output| {
+                TableHistorySource::create(
+                    ctx.clone(),
+                    output,
+                    self.db_name.clone(),
+                    self.table_name.clone(),
+                )
+            },
+            1,
+        )?;
+
+        Ok(())
+    }
+}
+
+impl TableFunction for TableHistoryTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn describe(name: &str) -> Option<TableFunctionDescription> {
+        Some(TableFunctionDescription::create(
+            name,
+            "Lists the versions of a table kept in the meta service, with the create, alter, drop and undrop operations",
+            "table_history(database, table)",
+            "SELECT * FROM table_history('default', 't')",
+        ))
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
+
+struct TableHistorySource {
+    ctx: Arc<dyn TableContext>,
+    db_name: String,
+    table_name: String,
+    finished: bool,
+}
+
+impl TableHistorySource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        db_name: String,
+        table_name: String,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, TableHistorySource {
+            ctx,
+            db_name,
+            table_name,
+            finished: false,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for TableHistorySource {
+    const NAME: &'static str = TABLE_HISTORY;
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.finished {
+            return Ok(None);
+        }
+        self.finished = true;
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(CATALOG_DEFAULT).await?;
+        let reply = catalog
+            .list_table_versions(ListTableVersionsReq::new(
+                &tenant,
+                &self.db_name,
+                &self.table_name,
+            ))
+            .await?;
+
+        // The last id is the one bound to the name, or last bound if the table is dropped.
+        if let Some(version) = reply.versions.last() {
+            let db_id = catalog
+                .get_database(&tenant, &self.db_name)
+                .await?
+                .get_db_info()
+                .ident
+                .db_id;
+            let visibility_checker = self.ctx.get_visibility_checker().await?;
+            if !visibility_checker.check_table_visibility(
+                CATALOG_DEFAULT,
+                &self.db_name,
+                &self.table_name,
+                db_id,
+                version.table_id,
+            ) {
+                return Err(ErrorCode::PermissionDenied(format!(
+                    "Permission denied: no privilege on table '{}'.'{}' for user {}",
+                    self.db_name,
+                    self.table_name,
+                    self.ctx.get_current_user()?.identity().display(),
+                )));
+            }
+        }
+
+        Ok(Some(history_block(&reply.versions)))
+    }
+}
+
+fn history_block(versions: &[TableVersion]) -> DataBlock {
+    let truncated = versions.iter().any(|v| match &v.meta {
+        Some(meta) => meta.changes_truncated,
+        None => true,
+    });
+
+    let mut version_numbers = vec![];
+    let mut table_ids = vec![];
+    let mut timestamps = vec![];
+    let mut operations = vec![];
+    let mut schemas = vec![];
+
+    let mut previous_schema: Option<String> = None;
+    for (idx, version) in versions.iter().enumerate() {
+        let Some(meta) = &version.meta else {
+            version_numbers.push(idx as u64 + 1);
+            table_ids.push(version.table_id);
+            timestamps.push(None);
+            operations.push("purged".to_string());
+            schemas.push(None);
+            previous_schema = None;
+            continue;
+        };
+
+        // An `alter` change keeps the schema it replaced, walk them back from the current schema
+        // to get the schema after each change and the one the id has been created with.
+        let mut schema = display_schema(&meta.schema);
+        let mut changed_schemas = Vec::with_capacity(meta.changes.len());
+        for change in meta.changes.iter().rev() {
+            changed_schemas.push(schema.clone());
+            if let Some(replaced) = &change.schema {
+                schema = display_schema(replaced);
+            }
+        }
+        changed_schemas.reverse();
+
+        let operation = match &previous_schema {
+            Some(previous) if previous != &schema => "alter",
+            _ => "create",
+        };
+
+        version_numbers.push(idx as u64 + 1);
+        table_ids.push(version.table_id);
+        timestamps.push(Some(meta.created_on.timestamp_micros()));
+        operations.push(operation.to_string());
+        schemas.push(Some(schema));
+
+        for (change, changed_schema) in meta.changes.iter().zip(changed_schemas) {
+            version_numbers.push(idx as u64 + 1);
+            table_ids.push(version.table_id);
+            timestamps.push(Some(change.changed_on.timestamp_micros()));
+            operations.push(change.operation.to_string());
+            schemas.push(Some(changed_schema));
+        }
+
+        let schema = display_schema(&meta.schema);
+        if let Some(drop_on) = meta.drop_on {
+            version_numbers.push(idx as u64 + 1);
+            table_ids.push(version.table_id);
+            timestamps.push(Some(drop_on.timestamp_micros()));
+            operations.push("drop".to_string());
+            schemas.push(Some(schema.clone()));
+        }
+
+        previous_schema = Some(schema);
+    }
+
+    let rows = version_numbers.len();
+    DataBlock::new_from_columns(vec![
+        UInt64Type::from_data(version_numbers),
+        UInt64Type::from_data(table_ids),
+        TimestampType::from_opt_data(timestamps),
+        StringType::from_data(operations),
+        StringType::from_opt_data(schemas),
+        BooleanType::from_data(vec![truncated; rows]),
+    ])
+}
+
+/// Render the columns as in a `CREATE TABLE` statement, e.g. `a INT, b VARCHAR NULL`.
+fn display_schema(schema: &TableSchemaRef) -> String {
+    schema
+        .fields()
+        .iter()
+        .map(|field| format!("{} {}", field.name(), field.data_type().sql_name()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
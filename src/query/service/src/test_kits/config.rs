@@ -15,6 +15,7 @@
 use databend_common_base::base::GlobalUniqName;
 use databend_common_config::BuiltInConfig;
 use databend_common_config::InnerConfig;
+use databend_common_config::MySQLFederatedRuleConfig;
 use databend_common_config::UDFConfig;
 use databend_common_config::UserAuthConfig;
 use databend_common_config::UserConfig;
@@ -92,6 +93,21 @@ ADDRESS = 'https://databend.com';"
         self
     }
 
+    pub fn mysql_handler_server_version(mut self, value: impl Into<String>) -> ConfigBuilder {
+        self.conf.query.mysql_handler_server_version = value.into();
+        self
+    }
+
+    pub fn mysql_federated_rule(mut self, rule: MySQLFederatedRuleConfig) -> ConfigBuilder {
+        self.conf.query.mysql_federated_rules.push(rule);
+        self
+    }
+
+    pub fn federated_max_query_bytes(mut self, value: u64) -> ConfigBuilder {
+        self.conf.query.federated_max_query_bytes = value;
+        self
+    }
+
     pub fn parquet_fast_read_bytes(mut self, value: u64) -> ConfigBuilder {
         self.conf.query.parquet_fast_read_bytes = Some(value);
         self
@@ -154,6 +170,11 @@ ADDRESS = 'https://databend.com';"
         self
     }
 
+    pub fn flight_sql_grpc_compression(mut self, value: impl Into<String>) -> ConfigBuilder {
+        self.conf.query.flight_sql_grpc_compression = value.into();
+        self
+    }
+
     pub fn build(self) -> InnerConfig {
         self.conf
     }
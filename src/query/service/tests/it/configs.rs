@@ -22,6 +22,9 @@ use databend_common_config::CacheStorageTypeConfig;
 use databend_common_config::CatalogConfig;
 use databend_common_config::CatalogHiveConfig;
 use databend_common_config::InnerConfig;
+use databend_common_config::MySQLFederatedColumnConfig;
+use databend_common_config::MySQLFederatedResponse;
+use databend_common_config::MySQLFederatedRuleConfig;
 use databend_common_config::ThriftProtocol;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -1020,3 +1023,66 @@ fn test_env_cache_config_and_defaults() -> Result<()> {
     );
     Ok(())
 }
+
+#[test]
+fn test_override_config_mysql_federated_rules() -> Result<()> {
+    let file_path = temp_dir().join("databend_test_override_config_mysql_federated_rules.toml");
+
+    let mut f = fs::File::create(&file_path)?;
+    f.write_all(
+        r#"
+[[query.mysql_federated_rules]]
+name = "mybi_probe"
+pattern = "^SELECT\\s+mybi_version\\(\\)"
+response = "table"
+columns = [{ name = "version", type = "String" }, { name = "build", type = "UInt64" }]
+rows = [["1.0", "42"]]
+
+[[query.mysql_federated_rules]]
+name = "mybi_set"
+pattern = "^SET\\s+mybi_mode"
+"#
+        .as_bytes(),
+    )?;
+
+    // Make sure all data flushed.
+    f.flush()?;
+
+    temp_env::with_vars(
+        vec![("CONFIG_FILE", Some(file_path.to_string_lossy().as_ref()))],
+        || {
+            let cfg = InnerConfig::load_for_test().expect("config load success");
+
+            assert_eq!(cfg.query.mysql_federated_rules, vec![
+                MySQLFederatedRuleConfig {
+                    name: "mybi_probe".to_string(),
+                    pattern: r"^SELECT\s+mybi_version\(\)".to_string(),
+                    response: MySQLFederatedResponse::Table,
+                    columns: vec![
+                        MySQLFederatedColumnConfig {
+                            name: "version".to_string(),
+                            data_type: "String".to_string(),
+                        },
+                        MySQLFederatedColumnConfig {
+                            name: "build".to_string(),
+                            data_type: "UInt64".to_string(),
+                        },
+                    ],
+                    rows: vec![vec!["1.0".to_string(), "42".to_string()]],
+                },
+                MySQLFederatedRuleConfig {
+                    name: "mybi_set".to_string(),
+                    pattern: r"^SET\s+mybi_mode".to_string(),
+                    response: MySQLFederatedResponse::Empty,
+                    columns: vec![],
+                    rows: vec![],
+                },
+            ]);
+        },
+    );
+
+    // remove temp file
+    fs::remove_file(file_path)?;
+
+    Ok(())
+}
@@ -562,6 +562,7 @@ async fn create_executor_with_simple_pipeline(
         enable_queries_executor: false,
         max_threads: 8,
         executor_node_id: "".to_string(),
+        max_pipeline_memory_usage: 0,
     };
     QueryPipelineExecutor::create(pipeline, settings)
 }
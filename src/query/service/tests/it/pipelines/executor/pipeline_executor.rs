@@ -12,17 +12,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::time::Duration;
 
 use databend_common_base::base::tokio;
 use databend_common_base::base::tokio::sync::mpsc::channel;
-use databend_common_base::base::tokio::sync::mpsc::Receiver;
 use databend_common_base::base::tokio::sync::mpsc::Sender;
+use databend_common_base::base::tokio::time::timeout;
+use databend_common_base::runtime::profile::ProfileStatisticsName;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::Int32Type;
+use databend_common_expression::types::ValueType;
 use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_pipeline_core::processors::DispatchPolicy;
 use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::ProcessorPtr;
@@ -30,12 +38,22 @@ use databend_common_pipeline_core::ExecutionInfo;
 use databend_common_pipeline_core::Pipe;
 use databend_common_pipeline_core::PipeItem;
 use databend_common_pipeline_core::Pipeline;
-use databend_common_pipeline_sinks::SyncSenderSink;
+use databend_common_pipeline_sinks::BlockCollectSink;
+use databend_common_pipeline_sinks::EmptySink;
 use databend_common_pipeline_sources::SyncReceiverSource;
+use databend_common_pipeline_sources::SyncSource;
+use databend_common_pipeline_sources::SyncSourcer;
+use databend_common_pipeline_transforms::processors::Transform;
+use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
 use databend_query::pipelines::executor::ExecutorSettings;
 use databend_query::pipelines::executor::QueryPipelineExecutor;
 use databend_query::sessions::QueryContext;
+use databend_query::sessions::TableContext;
+use databend_query::test_kits::execute_command;
+use databend_query::test_kits::execute_query;
 use databend_query::test_kits::TestFixture;
+use futures::StreamExt;
+use parking_lot::Mutex;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_always_call_on_finished() -> Result<()> {
@@ -47,6 +65,7 @@ async fn test_always_call_on_finished() -> Result<()> {
         enable_queries_executor: false,
         max_threads: 8,
         executor_node_id: "".to_string(),
+        max_pipeline_memory_usage: 0,
     };
 
     {
@@ -68,7 +87,7 @@ async fn test_always_call_on_finished() -> Result<()> {
     let ctx = fixture.new_query_ctx().await?;
     {
         let (called_finished, mut pipeline) = create_pipeline();
-        let (_rx, sink_pipe) = create_sink_pipe(1)?;
+        let (_blocks, sink_pipe) = create_sink_pipe(1)?;
         let (_tx, source_pipe) = create_source_pipe(ctx, 1)?;
         pipeline.add_pipe(source_pipe);
         pipeline.add_pipe(sink_pipe);
@@ -94,6 +113,249 @@ async fn test_always_call_on_finished() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_aggregate_input_profile() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    let sql = "select number % 3, sum(number) from numbers(1000) group by number % 3";
+    execute_command(ctx.clone(), sql).await?;
+
+    let profiles = ctx.get_query_profiles();
+    let aggregate = profiles
+        .iter()
+        .find(|profile| profile.name.as_deref() == Some("AggregatePartial"))
+        .unwrap();
+    let child = profiles
+        .iter()
+        .find(|profile| profile.parent_id.is_some() && profile.parent_id == aggregate.id)
+        .unwrap();
+
+    // The rows the aggregate pulls are the rows the plan below it pushes.
+    let input_rows = aggregate.statistics[ProfileStatisticsName::InputRows as usize];
+    let output_rows = child.statistics[ProfileStatisticsName::OutputRows as usize];
+    assert_eq!(input_rows, 1000);
+    assert_eq!(input_rows, output_rows);
+    assert!(aggregate.statistics[ProfileStatisticsName::ProcessCount as usize] > 0);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_pipeline_memory_exceeded() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+    ctx.get_settings()
+        .set_setting("max_pipeline_memory_usage".to_string(), "1".to_string())?;
+
+    let error = execute_command(ctx.clone(), "select * from numbers(1000)")
+        .await
+        .unwrap_err();
+    assert_eq!(error.code(), ErrorCode::PIPELINE_MEMORY_EXCEEDED);
+    assert!(
+        error
+            .message()
+            .starts_with("NumbersSourceTransform exceeds"),
+        "{}",
+        error.message()
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_pause_and_resume() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+    ctx.get_settings().set_max_threads(1)?;
+
+    let mut stream = execute_query(ctx.clone(), "SELECT * FROM numbers(10000000)").await?;
+    assert!(stream.next().await.unwrap().is_ok());
+
+    let pause_handle = ctx.get_pause_handle().unwrap();
+    pause_handle.pause();
+
+    // Drain the blocks generated before pausing.
+    while let Ok(Some(block)) = timeout(Duration::from_millis(100), stream.next()).await {
+        block?;
+    }
+
+    let scanned_rows = ctx.get_scan_progress_value().rows;
+    assert!(
+        timeout(Duration::from_millis(200), stream.next())
+            .await
+            .is_err()
+    );
+    assert_eq!(ctx.get_scan_progress_value().rows, scanned_rows);
+
+    pause_handle.resume();
+    while let Some(block) = stream.next().await {
+        block?;
+    }
+
+    assert_eq!(ctx.get_scan_progress_value().rows, 10000000);
+    assert!(pause_handle.get_paused_time() >= Duration::from_millis(200));
+    Ok(())
+}
+
+struct CountingSource {
+    generated: Arc<AtomicUsize>,
+}
+
+impl SyncSource for CountingSource {
+    const NAME: &'static str = "CountingSource";
+
+    fn generate(&mut self) -> Result<Option<DataBlock>> {
+        self.generated.fetch_add(1, Ordering::SeqCst);
+        Ok(Some(DataBlock::new_from_columns(vec![
+            Int32Type::from_data(vec![1]),
+        ])))
+    }
+}
+
+struct FailingTransform {
+    transformed: Arc<AtomicUsize>,
+}
+
+impl Transform for FailingTransform {
+    const NAME: &'static str = "FailingTransform";
+
+    fn transform(&mut self, data: DataBlock) -> Result<DataBlock> {
+        if self.transformed.fetch_add(1, Ordering::SeqCst) == 2 {
+            return Err(ErrorCode::Internal("transform failure"));
+        }
+
+        Ok(data)
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fail_fast_on_error() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    let settings = ExecutorSettings {
+        query_id: Arc::new("".to_string()),
+        max_execute_time_in_seconds: Default::default(),
+        enable_queries_executor: false,
+        max_threads: 4,
+        executor_node_id: "".to_string(),
+        max_pipeline_memory_usage: 0,
+    };
+
+    // The sources never finish, the pipeline only finishes with the error of the transform.
+    let generated = Arc::new(AtomicUsize::new(0));
+    let transformed = Arc::new(AtomicUsize::new(0));
+    let mut pipeline = Pipeline::create();
+    pipeline.add_source(
+        |output| {
+            SyncSourcer::create(ctx.clone(), output, CountingSource {
+                generated: generated.clone(),
+            })
+        },
+        4,
+    )?;
+    pipeline.add_transformer(|| FailingTransform {
+        transformed: transformed.clone(),
+    });
+    pipeline.add_sink(|input| Ok(ProcessorPtr::create(EmptySink::create(input))))?;
+    pipeline.set_max_threads(4);
+
+    let executor = QueryPipelineExecutor::create(pipeline, settings)?;
+    let error = executor.execute().unwrap_err();
+    assert_eq!(error.code(), ErrorCode::INTERNAL);
+    assert_eq!(error.message(), "transform failure");
+
+    // Each source generates at most a few blocks more, which wait in the ports.
+    drop(executor);
+    assert!(generated.load(Ordering::SeqCst) <= 3 + 4 * 3);
+    Ok(())
+}
+
+struct ValuesSource {
+    blocks: Vec<DataBlock>,
+}
+
+impl SyncSource for ValuesSource {
+    const NAME: &'static str = "ValuesSource";
+
+    fn generate(&mut self) -> Result<Option<DataBlock>> {
+        Ok(self.blocks.pop())
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_dispatch_hash_with_two_input_pipes() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    let settings = ExecutorSettings {
+        query_id: Arc::new("".to_string()),
+        max_execute_time_in_seconds: Default::default(),
+        enable_queries_executor: false,
+        max_threads: 4,
+        executor_node_id: "".to_string(),
+        max_pipeline_memory_usage: 0,
+    };
+
+    // Both sources generate the same values, in blocks of different sizes.
+    let sources = AtomicUsize::new(0);
+    let mut pipeline = Pipeline::create();
+    pipeline.add_source(
+        |output| {
+            let block_size = [3, 10][sources.fetch_add(1, Ordering::SeqCst)];
+            let values = (0..100).map(|value| value % 7).collect::<Vec<i32>>();
+            let blocks = values
+                .chunks(block_size)
+                .map(|values| {
+                    DataBlock::new_from_columns(vec![Int32Type::from_data(values.to_vec())])
+                })
+                .collect();
+            SyncSourcer::create(ctx.clone(), output, ValuesSource { blocks })
+        },
+        2,
+    )?;
+    pipeline.dispatch(DispatchPolicy::Hash(vec![0]), 3)?;
+    assert_eq!(pipeline.output_len(), 3);
+
+    let received = (0..3)
+        .map(|_| Arc::new(Mutex::new(vec![])))
+        .collect::<Vec<_>>();
+    let items = received
+        .iter()
+        .map(|blocks| {
+            let input = InputPort::create();
+            PipeItem::create(
+                ProcessorPtr::create(BlockCollectSink::create(input.clone(), blocks.clone())),
+                vec![input],
+                vec![],
+            )
+        })
+        .collect();
+    pipeline.add_pipe(Pipe::create(3, 0, items));
+    pipeline.set_max_threads(4);
+
+    let executor = QueryPipelineExecutor::create(pipeline, settings)?;
+    executor.execute()?;
+
+    // A value goes to the same downstream pipe from both sources.
+    let mut pipe_of_value = HashMap::new();
+    let mut rows = 0;
+    for (index, blocks) in received.iter().enumerate() {
+        for block in blocks.lock().iter() {
+            let column = block.columns()[0].value.as_column().unwrap();
+            for value in Int32Type::try_downcast_column(column).unwrap().iter() {
+                let pipe = pipe_of_value.entry(*value).or_insert(index);
+                assert_eq!(*pipe, index, "value {value} is dispatched to 2 pipes");
+                rows += 1;
+            }
+        }
+    }
+    assert_eq!(rows, 200);
+    assert_eq!(pipe_of_value.len(), 7);
+    Ok(())
+}
+
 fn create_pipeline() -> (Arc<AtomicBool>, Pipeline) {
     let called_finished = Arc::new(AtomicBool::new(false));
     let mut pipeline = Pipeline::create();
@@ -129,19 +391,17 @@ fn create_source_pipe(
     Ok((txs, Pipe::create(0, size, items)))
 }
 
-fn create_sink_pipe(size: usize) -> Result<(Vec<Receiver<Result<DataBlock>>>, Pipe)> {
-    let mut rxs = Vec::with_capacity(size);
+fn create_sink_pipe(size: usize) -> Result<(Arc<Mutex<Vec<DataBlock>>>, Pipe)> {
+    let blocks = Arc::new(Mutex::new(vec![]));
     let mut items = Vec::with_capacity(size);
     for _index in 0..size {
         let input = InputPort::create();
-        let (tx, rx) = channel(1);
-        rxs.push(rx);
         items.push(PipeItem::create(
-            ProcessorPtr::create(SyncSenderSink::create(tx, input.clone())),
+            ProcessorPtr::create(BlockCollectSink::create(input.clone(), blocks.clone())),
             vec![input],
             vec![],
         ));
     }
 
-    Ok((rxs, Pipe::create(size, 0, items)))
+    Ok((blocks, Pipe::create(size, 0, items)))
 }
@@ -0,0 +1,154 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::types::Int32Type;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sinks::BlockCollectSink;
+use databend_common_pipeline_sources::SyncSource;
+use databend_common_pipeline_sources::SyncSourcer;
+use databend_query::pipelines::executor::ExecutorSettings;
+use databend_query::pipelines::executor::QueryPipelineExecutor;
+use databend_query::pipelines::processors::LimitCounter;
+use databend_query::pipelines::processors::TransformLimit;
+use databend_query::test_kits::TestFixture;
+use parking_lot::Mutex;
+
+/// Generates blocks of 2 rows forever, the rows of all the sources are numbered in sequence.
+struct SequenceSource {
+    generated: Arc<AtomicUsize>,
+}
+
+impl SyncSource for SequenceSource {
+    const NAME: &'static str = "SequenceSource";
+
+    fn generate(&mut self) -> Result<Option<DataBlock>> {
+        let start = self.generated.fetch_add(1, Ordering::SeqCst) as i32 * 2;
+        Ok(Some(DataBlock::new_from_columns(vec![
+            Int32Type::from_data(vec![start, start + 1]),
+        ])))
+    }
+}
+
+/// Runs `SequenceSource`s on `pipes` pipes with the limit, returns the collected
+/// blocks and the number of the generated blocks.
+async fn run_limit(
+    pipes: usize,
+    limit: Option<usize>,
+    offset: usize,
+) -> Result<(Vec<DataBlock>, usize)> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    let settings = ExecutorSettings {
+        query_id: Arc::new("".to_string()),
+        max_execute_time_in_seconds: Default::default(),
+        enable_queries_executor: false,
+        max_threads: pipes as u64,
+        executor_node_id: "".to_string(),
+        max_pipeline_memory_usage: 0,
+    };
+
+    let generated = Arc::new(AtomicUsize::new(0));
+    let blocks = Arc::new(Mutex::new(vec![]));
+    let counter = LimitCounter::create(limit, offset);
+
+    let mut pipeline = Pipeline::create();
+    pipeline.add_source(
+        |output| {
+            SyncSourcer::create(ctx.clone(), output, SequenceSource {
+                generated: generated.clone(),
+            })
+        },
+        pipes,
+    )?;
+    pipeline.add_transform(|input, output| {
+        Ok(ProcessorPtr::create(TransformLimit::try_create(
+            counter.clone(),
+            input,
+            output,
+        )?))
+    })?;
+    pipeline.add_sink(|input| {
+        Ok(ProcessorPtr::create(BlockCollectSink::create(
+            input,
+            blocks.clone(),
+        )))
+    })?;
+    pipeline.set_max_threads(pipes);
+
+    // The sources never finish, the pipeline only finishes by the limit.
+    let executor = QueryPipelineExecutor::create(pipeline, settings)?;
+    executor.execute()?;
+    drop(executor);
+
+    let blocks = std::mem::take(&mut *blocks.lock());
+    Ok((blocks, generated.load(Ordering::SeqCst)))
+}
+
+fn collect_rows(blocks: &[DataBlock]) -> Vec<i32> {
+    let mut rows = vec![];
+    for block in blocks {
+        let column = block.get_by_offset(0).value.as_column().unwrap();
+        let column = Int32Type::try_downcast_column(column).unwrap();
+        rows.extend(column.iter().copied());
+    }
+    rows
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_limit_stops_sources() -> Result<()> {
+    let (blocks, generated) = run_limit(1, Some(5), 0).await?;
+    assert_eq!(collect_rows(&blocks), vec![0, 1, 2, 3, 4]);
+    // 3 blocks are needed, the source may generate a few more waiting in the ports.
+    assert!(generated <= 3 + 2, "generated {generated} blocks");
+
+    let (blocks, generated) = run_limit(4, Some(10), 0).await?;
+    assert_eq!(collect_rows(&blocks).len(), 10);
+    assert!(generated <= 5 + 4 * 2, "generated {generated} blocks");
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_offset_and_limit_stops_sources() -> Result<()> {
+    // The offset ends and the limit ends in the middle of a block.
+    let (blocks, generated) = run_limit(1, Some(5), 3).await?;
+    assert_eq!(collect_rows(&blocks), vec![3, 4, 5, 6, 7]);
+    assert!(generated <= 4 + 2, "generated {generated} blocks");
+
+    // The skipped rows of all the pipes are counted once.
+    let (blocks, generated) = run_limit(4, Some(10), 7).await?;
+    let rows = collect_rows(&blocks);
+    assert_eq!(rows.len(), 10);
+    assert!(generated <= 9 + 4 * 2, "generated {generated} blocks");
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_zero_limit_stops_sources() -> Result<()> {
+    // The processors finish before pulling any block.
+    let (blocks, generated) = run_limit(4, Some(0), 2).await?;
+    assert!(collect_rows(&blocks).is_empty());
+    assert!(generated <= 4 * 2, "generated {generated} blocks");
+    Ok(())
+}
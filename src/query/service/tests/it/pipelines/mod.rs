@@ -15,3 +15,6 @@
 mod builders;
 mod executor;
 mod filter;
+mod limit;
+mod pipeline_display;
+mod sources;
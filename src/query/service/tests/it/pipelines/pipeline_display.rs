@@ -0,0 +1,59 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sinks::EmptySink;
+use databend_common_pipeline_sources::OneBlockSource;
+
+fn create_pipeline() -> Result<Pipeline> {
+    let mut pipeline = Pipeline::create();
+    pipeline.add_source(
+        |output| OneBlockSource::create(output, DataBlock::empty()),
+        2,
+    )?;
+    pipeline.try_resize(1)?;
+    pipeline.add_sink(|input| Ok(ProcessorPtr::create(EmptySink::create(input))))?;
+    Ok(pipeline)
+}
+
+#[test]
+fn test_pipeline_display_indent() -> Result<()> {
+    let pipeline = create_pipeline()?;
+    assert_eq!(
+        pipeline.display_indent().to_string(),
+        "EmptySink × 1\n  Merge to Resize × 1\n    OneBlockSource × 2"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_pipeline_display_graphviz() -> Result<()> {
+    let pipeline = create_pipeline()?;
+    assert_eq!(
+        pipeline.display_graphviz().to_string(),
+        "digraph {\
+        \n    0 [ label = \"OneBlockSource\" ]\
+        \n    1 [ label = \"OneBlockSource\" ]\
+        \n    2 [ label = \"Resize\" ]\
+        \n    3 [ label = \"EmptySink\" ]\
+        \n    0 -> 2 [ ]\
+        \n    1 -> 2 [ ]\
+        \n    2 -> 3 [ ]\
+        \n}"
+    );
+    Ok(())
+}
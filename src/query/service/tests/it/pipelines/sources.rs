@@ -0,0 +1,283 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::Int32Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::AbortChecker;
+use databend_common_expression::CheckAbort;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchema;
+use databend_common_expression::FromData;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_sources::AsyncSource;
+use databend_common_pipeline_sources::OneBlockSource;
+use databend_common_pipeline_sources::RetryAsyncSource;
+use databend_common_pipeline_sources::RetryPolicy;
+use databend_common_pipeline_sources::RetrySource;
+use databend_common_pipeline_sources::SyncSource;
+
+#[test]
+fn test_one_block_source_with_schema() -> Result<()> {
+    let block = DataBlock::new_from_columns(vec![
+        Int32Type::from_data(vec![1, 2, 3]),
+        StringType::from_data(vec!["a", "b", "c"]),
+    ]);
+
+    let schema = DataSchema::new(vec![
+        DataField::new("a", DataType::Number(NumberDataType::Int32)),
+        DataField::new("b", DataType::String),
+    ]);
+    assert!(
+        OneBlockSource::create_with_schema(OutputPort::create(), "t", &schema, block.clone())
+            .is_ok()
+    );
+
+    let schema = DataSchema::new(vec![DataField::new(
+        "a",
+        DataType::Number(NumberDataType::Int32),
+    )]);
+    let error =
+        OneBlockSource::create_with_schema(OutputPort::create(), "t", &schema, block.clone())
+            .err()
+            .unwrap();
+    assert_eq!(
+        error.message(),
+        "The block of t has 2 columns, but its schema has 1"
+    );
+
+    let schema = DataSchema::new(vec![
+        DataField::new("a", DataType::Number(NumberDataType::Int64)),
+        DataField::new("b", DataType::String),
+    ]);
+    let error = OneBlockSource::create_with_schema(OutputPort::create(), "t", &schema, block)
+        .err()
+        .unwrap();
+    assert_eq!(
+        error.message(),
+        "The column a of t is Int32, but its schema is Int64"
+    );
+
+    Ok(())
+}
+
+/// Returns the scripted results of `generate` in order.
+struct ScriptedSource {
+    results: VecDeque<Result<Option<DataBlock>>>,
+    calls: Arc<AtomicUsize>,
+}
+
+impl ScriptedSource {
+    fn create(results: Vec<Result<Option<DataBlock>>>) -> (Arc<AtomicUsize>, Self) {
+        let calls = Arc::new(AtomicUsize::new(0));
+        (calls.clone(), ScriptedSource {
+            results: VecDeque::from(results),
+            calls,
+        })
+    }
+
+    fn next(&mut self) -> Result<Option<DataBlock>> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        self.results.pop_front().unwrap_or(Ok(None))
+    }
+}
+
+impl SyncSource for ScriptedSource {
+    const NAME: &'static str = "ScriptedSource";
+
+    fn generate(&mut self) -> Result<Option<DataBlock>> {
+        self.next()
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for ScriptedSource {
+    const NAME: &'static str = "ScriptedSource";
+
+    #[async_trait::unboxed_simple]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        self.next()
+    }
+}
+
+struct Aborting(AtomicBool);
+
+impl CheckAbort for Aborting {
+    fn is_aborting(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+
+    fn try_check_aborting(&self) -> Result<()> {
+        match self.is_aborting() {
+            true => Err(ErrorCode::AbortedQuery("aborted")),
+            false => Ok(()),
+        }
+    }
+}
+
+fn abort_checker(aborting: bool) -> AbortChecker {
+    Arc::new(Aborting(AtomicBool::new(aborting)))
+}
+
+fn retry_policy() -> RetryPolicy {
+    RetryPolicy {
+        max_attempts: 3,
+        initial_backoff: Duration::from_millis(1),
+        max_backoff: Duration::from_millis(4),
+    }
+}
+
+fn scripted_block() -> DataBlock {
+    DataBlock::new_from_columns(vec![Int32Type::from_data(vec![1, 2, 3])])
+}
+
+#[test]
+fn test_retry_source_transient_errors() -> Result<()> {
+    let (calls, inner) = ScriptedSource::create(vec![
+        Err(ErrorCode::StorageUnavailable("503")),
+        Err(ErrorCode::Timeout("timeout")),
+        Ok(Some(scripted_block())),
+    ]);
+    let mut source = RetrySource::create(inner, retry_policy(), abort_checker(false));
+
+    // The block of the third attempt is emitted once.
+    let block = source.generate()?.unwrap();
+    assert_eq!(block.num_rows(), 3);
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+    assert!(source.generate()?.is_none());
+    assert_eq!(calls.load(Ordering::SeqCst), 4);
+    Ok(())
+}
+
+#[test]
+fn test_retry_source_give_up() -> Result<()> {
+    let (calls, inner) = ScriptedSource::create(vec![
+        Err(ErrorCode::StorageUnavailable("503")),
+        Err(ErrorCode::StorageUnavailable("503")),
+        Err(ErrorCode::StorageUnavailable("503")),
+        Ok(Some(scripted_block())),
+    ]);
+    let mut source = RetrySource::create(inner, retry_policy(), abort_checker(false));
+
+    let error = source.generate().unwrap_err();
+    assert_eq!(error.code(), ErrorCode::STORAGE_UNAVAILABLE);
+    assert_eq!(error.message(), "503\n(failed after 3 attempts)");
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+    // The errors which are not transient are not retried.
+    let (calls, inner) = ScriptedSource::create(vec![
+        Err(ErrorCode::StorageNotFound("404")),
+        Ok(Some(scripted_block())),
+    ]);
+    let mut source = RetrySource::create(inner, retry_policy(), abort_checker(false));
+
+    let error = source.generate().unwrap_err();
+    assert_eq!(error.code(), ErrorCode::STORAGE_NOT_FOUND);
+    assert_eq!(error.message(), "404");
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    // Nor the other storage errors, which hold the wrong configurations too.
+    let (calls, inner) = ScriptedSource::create(vec![
+        Err(ErrorCode::StorageOther("invalid endpoint")),
+        Ok(Some(scripted_block())),
+    ]);
+    let mut source = RetrySource::create(inner, retry_policy(), abort_checker(false));
+
+    let error = source.generate().unwrap_err();
+    assert_eq!(error.code(), ErrorCode::STORAGE_OTHER);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    Ok(())
+}
+
+#[test]
+fn test_retry_source_aborted() -> Result<()> {
+    let (calls, inner) = ScriptedSource::create(vec![
+        Err(ErrorCode::StorageUnavailable("503")),
+        Ok(Some(scripted_block())),
+    ]);
+    let mut source = RetrySource::create(inner, retry_policy(), abort_checker(true));
+
+    let error = source.generate().unwrap_err();
+    assert_eq!(error.code(), ErrorCode::ABORTED_QUERY);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_retry_async_source_transient_errors() -> Result<()> {
+    let (calls, inner) = ScriptedSource::create(vec![
+        Err(ErrorCode::Timeout("timeout")),
+        Err(ErrorCode::StorageUnavailable("503")),
+        Ok(Some(scripted_block())),
+    ]);
+    let mut source = RetryAsyncSource::create(inner, retry_policy(), abort_checker(false));
+
+    let block = source.generate().await?.unwrap();
+    assert_eq!(block.num_rows(), 3);
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+    assert!(source.generate().await?.is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_retry_async_source_aborted_in_backoff() -> Result<()> {
+    let (calls, inner) = ScriptedSource::create(vec![
+        Err(ErrorCode::StorageUnavailable("503")),
+        Ok(Some(scripted_block())),
+    ]);
+    let policy = RetryPolicy {
+        max_attempts: 3,
+        initial_backoff: Duration::from_secs(60),
+        max_backoff: Duration::from_secs(60),
+    };
+    let aborting = Arc::new(Aborting(AtomicBool::new(false)));
+    let mut source = RetryAsyncSource::create(inner, policy, aborting.clone());
+
+    // The query is killed while the source waits for the backoff.
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        aborting.0.store(true, Ordering::SeqCst);
+    });
+    let generate = tokio::time::timeout(Duration::from_secs(5), source.generate());
+    let error = generate.await.unwrap().unwrap_err();
+    assert_eq!(error.code(), ErrorCode::ABORTED_QUERY);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    Ok(())
+}
+
+#[test]
+fn test_retry_policy_backoff() {
+    let policy = RetryPolicy {
+        max_attempts: 10,
+        initial_backoff: Duration::from_millis(100),
+        max_backoff: Duration::from_secs(1),
+    };
+    assert_eq!(policy.backoff(1), Duration::from_millis(100));
+    assert_eq!(policy.backoff(2), Duration::from_millis(200));
+    assert_eq!(policy.backoff(4), Duration::from_millis(800));
+    assert_eq!(policy.backoff(5), Duration::from_secs(1));
+    assert_eq!(policy.backoff(100), Duration::from_secs(1));
+}
@@ -107,7 +107,7 @@ async fn test_status() -> Result<()> {
         while (stream.next().await).is_some() {}
     }
     let session = query_ctx.get_current_session();
-    SessionManager::instance().destroy_session(&session.get_id());
+    SessionManager::instance().destroy_session(&session);
 
     let status = get_status(&ep).await;
     assert_eq!(
@@ -0,0 +1,91 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::IpAddr;
+use std::net::SocketAddr;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_query::servers::flight_sql::flight_sql_service::ClientAddressResolver;
+use tonic::metadata::MetadataMap;
+
+fn resolve(resolver: &ClientAddressResolver, peer: &str, forwarded: Option<&str>) -> String {
+    let peer: SocketAddr = format!("{peer}:50000").parse().unwrap();
+    let mut metadata = MetadataMap::new();
+    if let Some(forwarded) = forwarded {
+        metadata.insert("x-forwarded-for", forwarded.parse().unwrap());
+    }
+    let addr: IpAddr = resolver.resolve(Some(peer), &metadata).unwrap();
+    addr.to_string()
+}
+
+#[test]
+fn test_client_address_trusted_proxy() -> Result<()> {
+    let resolver = ClientAddressResolver::try_create(
+        &["10.0.0.0/8".to_string(), "192.168.1.1".to_string()],
+        "X-Forwarded-For",
+    )?;
+
+    assert_eq!(
+        resolve(&resolver, "10.1.2.3", Some("203.0.113.7")),
+        "203.0.113.7"
+    );
+    // Proxies in the chain are skipped, the spoofed left-most entry is not trusted.
+    assert_eq!(
+        resolve(
+            &resolver,
+            "10.1.2.3",
+            Some("1.1.1.1, 203.0.113.7, 192.168.1.1")
+        ),
+        "203.0.113.7"
+    );
+    // A malformed header falls back to the peer.
+    assert_eq!(resolve(&resolver, "10.1.2.3", Some("unknown")), "10.1.2.3");
+    Ok(())
+}
+
+#[test]
+fn test_client_address_untrusted_peer() -> Result<()> {
+    let resolver =
+        ClientAddressResolver::try_create(&["10.0.0.0/8".to_string()], "x-forwarded-for")?;
+    assert_eq!(
+        resolve(&resolver, "203.0.113.7", Some("10.1.2.3")),
+        "203.0.113.7"
+    );
+
+    // Nothing is trusted by default.
+    let resolver = ClientAddressResolver::try_create(&[], "x-forwarded-for")?;
+    assert_eq!(resolve(&resolver, "10.1.2.3", Some("1.1.1.1")), "10.1.2.3");
+    Ok(())
+}
+
+#[test]
+fn test_client_address_missing_header() -> Result<()> {
+    let resolver =
+        ClientAddressResolver::try_create(&["10.0.0.0/8".to_string()], "x-forwarded-for")?;
+    assert_eq!(resolve(&resolver, "10.1.2.3", None), "10.1.2.3");
+
+    let mut metadata = MetadataMap::new();
+    metadata.insert("x-forwarded-for", "203.0.113.7".parse().unwrap());
+    assert!(resolver.resolve(None, &metadata).is_none());
+    Ok(())
+}
+
+#[test]
+fn test_client_address_invalid_trusted_proxies() {
+    let err = ClientAddressResolver::try_create(&["10.0.0.0/33".to_string()], "x-forwarded-for")
+        .err()
+        .unwrap();
+    assert_eq!(err.code(), ErrorCode::INVALID_CONFIG);
+}
@@ -15,13 +15,32 @@
 // The servers module used for external communication with user, such as MySQL wired protocol, etc.
 
 use std::fs;
+use std::future::Future;
 use std::io::Write;
 
+use arrow_array::Array;
+use arrow_array::BinaryArray;
 use arrow_array::RecordBatch;
+use arrow_array::StringArray;
 use arrow_cast::pretty::pretty_format_batches;
+use arrow_flight::flight_descriptor::DescriptorType;
+use arrow_flight::flight_service_client::FlightServiceClient;
+use arrow_flight::flight_service_server::FlightService;
 use arrow_flight::flight_service_server::FlightServiceServer;
 use arrow_flight::sql::client::FlightSqlServiceClient;
+use arrow_flight::sql::Any;
+use arrow_flight::sql::CommandGetDbSchemas;
+use arrow_flight::sql::CommandGetTables;
+use arrow_flight::utils::flight_data_to_batches;
+use arrow_flight::Action;
+use arrow_flight::BasicAuth;
+use arrow_flight::FlightData;
+use arrow_flight::FlightDescriptor;
+use arrow_flight::HandshakeRequest;
+use arrow_flight::Ticket;
 use arrow_schema::ArrowError;
+use arrow_schema::DataType;
+use arrow_schema::TimeUnit;
 use databend_common_base::base::tokio;
 use databend_common_base::runtime::Runtime;
 use databend_common_config::InnerConfig;
@@ -30,18 +49,26 @@ use databend_common_config::UserConfig;
 use databend_common_exception::Result;
 use databend_common_meta_app::principal::PasswordHashMethod;
 use databend_query::servers::flight_sql::flight_sql_service::FlightSqlServiceImpl;
+use databend_query::servers::flight_sql::flight_sql_service::KeepAliveResult;
+use databend_query::servers::flight_sql::flight_sql_service::KEEP_ALIVE_ACTION;
+use databend_query::servers::FlightSQLServer;
 use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestFixture;
+use futures::StreamExt;
 use futures::TryStreamExt;
 use goldenfile::Mint;
 use log::debug;
+use prost::Message;
 use tempfile::NamedTempFile;
 use tokio::net::UnixListener;
 use tokio::net::UnixStream;
 use tokio_stream::wrappers::UnixListenerStream;
+use tonic::codec::CompressionEncoding;
 use tonic::transport::Channel;
 use tonic::transport::Endpoint;
 use tonic::transport::Server;
+use tonic::Code;
+use tonic::Request;
 use tower::service_fn;
 
 const TEST_USER: &str = "test_user";
@@ -57,6 +84,55 @@ async fn client_with_uds(path: String) -> FlightSqlServiceClient<Channel> {
     FlightSqlServiceClient::new(channel)
 }
 
+/// Serves FlightSQL on a unix socket and runs `client` with the path of the socket, the server
+/// is shut down when the client finishes.
+fn run_with_uds_server<F, Fut>(client: F) -> Result<()>
+where
+    F: FnOnce(String) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    let service = FlightServiceServer::new(FlightSqlServiceImpl::create()?);
+    run_with_uds_service(service, client)
+}
+
+fn run_with_uds_service<S, F, Fut>(service: FlightServiceServer<S>, client: F) -> Result<()>
+where
+    S: FlightService,
+    F: FnOnce(String) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    let runtime = Runtime::with_default_worker_threads()?;
+    runtime.block_on(async {
+        let file = NamedTempFile::new().unwrap();
+        let path = file.into_temp_path().to_str().unwrap().to_string();
+        let _ = fs::remove_file(path.clone());
+
+        let uds = UnixListener::bind(path.clone()).unwrap();
+        let stream = UnixListenerStream::new(uds);
+
+        // We would just listen on TCP, but it seems impossible to know when tonic is ready to serve
+        let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+        let serve_future = Server::builder()
+            .add_service(service)
+            .serve_with_incoming_shutdown(stream, async { shutdown_rx.await.unwrap() });
+
+        let request_future = client(path);
+        tokio::pin!(serve_future);
+
+        tokio::select! {
+            _ = &mut serve_future => panic!("server returned first"),
+            _ = request_future => {
+                debug!("Client finished!");
+            }
+        }
+        shutdown_tx.send(()).unwrap();
+        serve_future.await.unwrap();
+        debug!("Server shutdown!");
+
+        Ok(())
+    })
+}
+
 async fn run_query(
     client: &mut FlightSqlServiceClient<Channel>,
     sql: &str,
@@ -95,64 +171,1001 @@ fn prepare_config() -> InnerConfig {
 async fn test_query() -> Result<()> {
     let _fixture = TestFixture::setup_with_config(&prepare_config()).await?;
 
-    let runtime = Runtime::with_default_worker_threads()?;
-    runtime.block_on(async {
-        let file = NamedTempFile::new().unwrap();
-        let path = file.into_temp_path().to_str().unwrap().to_string();
-        let _ = fs::remove_file(path.clone());
+    run_with_uds_server(|path| async move {
+        let mut mint = Mint::new("tests/it/servers/flight_sql/testdata");
+        let mut file = mint.new_goldenfile("query.txt").unwrap();
 
-        let uds = UnixListener::bind(path.clone()).unwrap();
-        let stream = UnixListenerStream::new(uds);
+        let mut client = client_with_uds(path).await;
+        let token = client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
 
-        // We would just listen on TCP, but it seems impossible to know when tonic is ready to serve
-        let service = FlightSqlServiceImpl::create();
-        let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
-        let serve_future = Server::builder()
-            .add_service(FlightServiceServer::new(service))
-            .serve_with_incoming_shutdown(stream, async { shutdown_rx.await.unwrap() });
+        debug!("Auth succeeded with token: {:?}", token);
+        let cases = [
+            "select 1, 'abc', 1.1, 1.1::float32, 1::nullable(int)",
+            "select [1, 2]",
+            "select (1, 1.1)",
+            "select {1: 11, 2: 22}",
+            "show tables",
+            "drop table if exists test1",
+            "create table test1(a int, b string)",
+            "insert into table test1(a, b) values (1, 'x'), (2, 'y')",
+            "select * from test1",
+            "update test1 set b = 'z' where a = 100",
+            "delete from test1 where a > 0",
+        ];
+        for case in cases {
+            writeln!(file, "---------- Input ----------").unwrap();
+            writeln!(file, "{}", case).unwrap();
+            writeln!(file, "---------- Output ---------").unwrap();
+            let res = match run_query(&mut client, case).await {
+                Ok(s) => s,
+                Err(e) => format!("{e:?}"),
+            };
+            writeln!(file, "{}", res).unwrap();
+        }
+    })
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_grpc_gzip_compression() -> Result<()> {
+    let mut config = prepare_config();
+    config.query.flight_sql_grpc_compression = "gzip".to_string();
+    let _fixture = TestFixture::setup_with_config(&config).await?;
 
-        let request_future = async {
-            let mut mint = Mint::new("tests/it/servers/flight_sql/testdata");
-            let mut file = mint.new_goldenfile("query.txt").unwrap();
-
-            let mut client = client_with_uds(path).await;
-            let token = client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
-
-            debug!("Auth succeeded with token: {:?}", token);
-            let cases = [
-                "select 1, 'abc', 1.1, 1.1::float32, 1::nullable(int)",
-                "select [1, 2]",
-                "select (1, 1.1)",
-                "select {1: 11, 2: 22}",
-                "show tables",
-                "drop table if exists test1",
-                "create table test1(a int, b string)",
-                "insert into table test1(a, b) values (1, 'x'), (2, 'y')",
-                "select * from test1",
-            ];
-            for case in cases {
-                writeln!(file, "---------- Input ----------").unwrap();
-                writeln!(file, "{}", case).unwrap();
-                writeln!(file, "---------- Output ---------").unwrap();
-                let res = match run_query(&mut client, case).await {
-                    Ok(s) => s,
-                    Err(e) => format!("{e:?}"),
-                };
-                writeln!(file, "{}", res).unwrap();
+    let service = FlightSQLServer::flight_service(&config)?;
+    run_with_uds_service(service, |path| async move {
+        let connector = service_fn(move |_| UnixStream::connect(path.clone()));
+        let channel = Endpoint::try_from("http://example.com")
+            .unwrap()
+            .connect_with_connector(connector)
+            .await
+            .unwrap();
+        let inner = FlightServiceClient::new(channel)
+            .send_compressed(CompressionEncoding::Gzip)
+            .accept_compressed(CompressionEncoding::Gzip);
+        let mut client = FlightSqlServiceClient::new_from_inner(inner);
+        let token = client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+        let token = String::from_utf8(token.to_vec()).unwrap();
+
+        let mut stmt = client
+            .prepare(
+                "select repeat('a', 1000) from numbers(100)".to_string(),
+                None,
+            )
+            .await
+            .unwrap();
+        let flight_info = stmt.execute().await.unwrap();
+        let ticket = flight_info.endpoint[0].ticket.clone().unwrap();
+
+        let request = ticket_request(&token, ticket, &[]);
+        let response = client.inner_mut().do_get(request).await.unwrap();
+        let encoding = response.metadata().get("grpc-encoding").unwrap();
+        assert_eq!(encoding, "gzip");
+
+        let flight_data: Vec<FlightData> = response.into_inner().try_collect().await.unwrap();
+        let batches = flight_data_to_batches(&flight_data).unwrap();
+        assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 100);
+        let column = batches[0]
+            .column(0)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(column.value(0), "a".repeat(1000));
+    })
+}
+
+fn path_request(token: &str, path: &[&str], headers: &[(&str, &str)]) -> Request<FlightDescriptor> {
+    let mut request = Request::new(FlightDescriptor {
+        r#type: DescriptorType::Path.into(),
+        cmd: Default::default(),
+        path: path.iter().map(|p| p.to_string()).collect(),
+    });
+    let metadata = request.metadata_mut();
+    metadata.insert("authorization", format!("Bearer {token}").parse().unwrap());
+    for (k, v) in headers {
+        metadata.insert(*k, v.parse().unwrap());
+    }
+    request
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_fetch_table_by_path() -> Result<()> {
+    let _fixture = TestFixture::setup_with_config(&prepare_config()).await?;
+
+    run_with_uds_server(|path| async move {
+        let mut client = client_with_uds(path).await;
+        let token = client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+        let token = String::from_utf8(token.to_vec()).unwrap();
+
+        for sql in [
+            "drop table if exists fetch_t1",
+            "create table fetch_t1(a int, b string)",
+            "insert into table fetch_t1(a, b) values (1, 'x'), (2, 'y'), (3, 'z')",
+        ] {
+            run_query(&mut client, sql).await.unwrap();
+        }
+
+        let request = path_request(&token, &["default", "default", "fetch_t1"], &[
+            ("databend-columns", "a"),
+            ("databend-limit", "2"),
+        ]);
+        let flight_info = client
+            .inner_mut()
+            .get_flight_info(request)
+            .await
+            .unwrap()
+            .into_inner();
+        let ticket = flight_info.endpoint[0].ticket.as_ref().unwrap().clone();
+        let flight_data = client.do_get(ticket).await.unwrap();
+        let batches: Vec<RecordBatch> = flight_data.try_collect().await.unwrap();
+        assert_eq!(batches[0].num_columns(), 1);
+        assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 2);
+
+        let request = path_request(&token, &["default", "default", "not_exists"], &[]);
+        let err = client
+            .inner_mut()
+            .get_flight_info(request)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), Code::NotFound);
+
+        let request = path_request(&token, &["default", "fetch_t1"], &[]);
+        let err = client
+            .inner_mut()
+            .get_flight_info(request)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), Code::InvalidArgument);
+    })
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_query_tag() -> Result<()> {
+    let _fixture = TestFixture::setup_with_config(&prepare_config()).await?;
+
+    run_with_uds_server(|path| async move {
+        let mut client = client_with_uds(path).await;
+        client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+
+        let sql = "select query_tag from system.processes where query_tag <> ''";
+        let res = run_query(&mut client, sql).await.unwrap();
+        assert!(!res.contains("nightly-etl"), "{res}");
+
+        client.set_header("databend-query-tag", "nightly-etl");
+        let res = run_query(&mut client, sql).await.unwrap();
+        assert!(res.contains("| nightly-etl |"), "{res}");
+
+        client.set_header("databend-query-tag", "x".repeat(300));
+        let sql = "select length(query_tag) from system.processes where query_tag like 'xxx%'";
+        let res = run_query(&mut client, sql).await.unwrap();
+        assert!(res.contains("| 256 "), "{res}");
+    })
+}
+
+fn ticket_request(token: &str, ticket: Ticket, headers: &[(&str, &str)]) -> Request<Ticket> {
+    let mut request = Request::new(ticket);
+    let metadata = request.metadata_mut();
+    metadata.insert("authorization", format!("Bearer {token}").parse().unwrap());
+    for (k, v) in headers {
+        metadata.insert(*k, v.parse().unwrap());
+    }
+    request
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_fetch_size_cursor() -> Result<()> {
+    let _fixture = TestFixture::setup_with_config(&prepare_config()).await?;
+
+    run_with_uds_server(|path| async move {
+        let mut client = client_with_uds(path).await;
+        let token = client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+        let token = String::from_utf8(token.to_vec()).unwrap();
+
+        let mut stmt = client
+            .prepare("select number from numbers(5)".to_string(), None)
+            .await
+            .unwrap();
+        let flight_info = stmt.execute().await.unwrap();
+        let mut ticket = flight_info.endpoint[0].ticket.clone().unwrap();
+
+        // Every page ends with the ticket of the next one, but the last page.
+        let mut pages = vec![];
+        loop {
+            let request = ticket_request(&token, ticket.clone(), &[("databend-fetch-size", "2")]);
+            let flight_data: Vec<FlightData> = client
+                .inner_mut()
+                .do_get(request)
+                .await
+                .unwrap()
+                .into_inner()
+                .try_collect()
+                .await
+                .unwrap();
+            let batches = flight_data_to_batches(&flight_data).unwrap();
+            pages.push(batches.iter().map(|b| b.num_rows()).sum::<usize>());
+
+            let next = flight_data.last().unwrap().app_metadata.clone();
+            if next.is_empty() {
+                break;
             }
+            ticket = Ticket { ticket: next };
+        }
+        assert_eq!(pages, vec![2, 2, 1]);
+
+        // The cursor is closed once exhausted.
+        let request = ticket_request(&token, ticket, &[]);
+        let err = client.inner_mut().do_get(request).await.unwrap_err();
+        assert_eq!(err.code(), Code::NotFound);
+
+        let ticket = flight_info.endpoint[0].ticket.clone().unwrap();
+        let request = ticket_request(&token, ticket, &[("databend-fetch-size", "0")]);
+        let err = client.inner_mut().do_get(request).await.unwrap_err();
+        assert_eq!(err.code(), Code::InvalidArgument);
+    })
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_queries_queue() -> Result<()> {
+    let mut config = prepare_config();
+    config.query.max_running_queries = 1;
+    let fixture = TestFixture::setup_with_config(&config).await?;
+    fixture
+        .execute_command("set global statement_queued_timeout_in_seconds = 1")
+        .await?;
+
+    run_with_uds_server(|path| async move {
+        let mut client = client_with_uds(path).await;
+        let token = client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+        let token = String::from_utf8(token.to_vec()).unwrap();
+
+        let statements = [
+            "create or replace table t_queue(a int)",
+            "insert into t_queue values (1), (2), (3)",
+        ];
+        for sql in statements {
+            client.execute_update(sql.to_string(), None).await.unwrap();
+        }
+
+        // An open cursor holds the only running slot until it is exhausted.
+        let mut stmt = client
+            .prepare("select a from t_queue".to_string(), None)
+            .await
+            .unwrap();
+        let flight_info = stmt.execute().await.unwrap();
+        let ticket = flight_info.endpoint[0].ticket.clone().unwrap();
+        let request = ticket_request(&token, ticket.clone(), &[("databend-fetch-size", "1")]);
+        let flight_data: Vec<FlightData> = client
+            .inner_mut()
+            .do_get(request)
+            .await
+            .unwrap()
+            .into_inner()
+            .try_collect()
+            .await
+            .unwrap();
+        assert!(!flight_data.last().unwrap().app_metadata.is_empty());
+
+        // The FlightSQL queries wait in the queries queue, as the queries of the other handlers.
+        let request = ticket_request(&token, ticket, &[]);
+        let err = client.inner_mut().do_get(request).await.unwrap_err();
+        assert!(err.message().contains("query queuing timeout"), "{err}");
+    })
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_keep_alive_action() -> Result<()> {
+    let _fixture = TestFixture::setup_with_config(&prepare_config()).await?;
+
+    run_with_uds_server(|path| async move {
+        let keep_alive = Action {
+            r#type: KEEP_ALIVE_ACTION.to_string(),
+            body: Default::default(),
         };
-        tokio::pin!(serve_future);
 
-        tokio::select! {
-            _ = &mut serve_future => panic!("server returned first"),
-            _ = request_future => {
-                debug!("Client finished!");
+        let mut client = client_with_uds(path).await;
+        let err = client
+            .inner_mut()
+            .do_action(Request::new(keep_alive.clone()))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), Code::Unauthenticated);
+
+        client.set_header("session_keep_alive", "120");
+        client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+        let results: Vec<arrow_flight::Result> = client
+            .do_action(keep_alive)
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        let any = Any::decode(&*results[0].body).unwrap();
+        let result: KeepAliveResult = any.unpack().unwrap().unwrap();
+        assert_eq!(result.remaining_ttl_secs, 120);
+    })
+}
+
+/// Returns the result cache headers of the response and the number of rows.
+async fn do_get_cache_header(
+    client: &mut FlightSqlServiceClient<Channel>,
+    token: &str,
+    ticket: &Ticket,
+    headers: &[(&str, &str)],
+) -> (Option<String>, Option<String>, usize) {
+    let request = ticket_request(token, ticket.clone(), headers);
+    let response = client.inner_mut().do_get(request).await.unwrap();
+    let header = |key: &str| {
+        response
+            .metadata()
+            .get(key)
+            .map(|v| v.to_str().unwrap().to_string())
+    };
+    let hit = header("x-databend-result-cache");
+    let created_at = header("x-databend-result-cache-created-at");
+
+    let flight_data: Vec<FlightData> = response.into_inner().try_collect().await.unwrap();
+    let batches = flight_data_to_batches(&flight_data).unwrap();
+    let rows = batches.iter().map(|b| b.num_rows()).sum::<usize>();
+    (hit, created_at, rows)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_result_cache_hit() -> Result<()> {
+    let _fixture = TestFixture::setup_with_config(&prepare_config()).await?;
+
+    run_with_uds_server(|path| async move {
+        let mut client = client_with_uds(path).await;
+        let token = client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+        let token = String::from_utf8(token.to_vec()).unwrap();
+
+        for sql in [
+            "set enable_query_result_cache = 1",
+            "set query_result_cache_min_execute_secs = 0",
+            "create or replace table t_result_cache(a int)",
+            "insert into t_result_cache values (1), (2)",
+        ] {
+            client.execute_update(sql.to_string(), None).await.unwrap();
+        }
+
+        let mut stmt = client
+            .prepare("select a from t_result_cache order by a".to_string(), None)
+            .await
+            .unwrap();
+        let flight_info = stmt.execute().await.unwrap();
+        let ticket = flight_info.endpoint[0].ticket.clone().unwrap();
+
+        // The first run fills the cache.
+        let (hit, _, rows) = do_get_cache_header(&mut client, &token, &ticket, &[]).await;
+        assert!(hit.is_none());
+        assert_eq!(rows, 2);
+
+        let (hit, created_at, rows) = do_get_cache_header(&mut client, &token, &ticket, &[]).await;
+        assert_eq!(hit.unwrap(), "hit");
+        assert!(created_at.unwrap().parse::<u64>().unwrap() > 0);
+        assert_eq!(rows, 2);
+
+        let headers = [("databend-disable-result-cache", "true")];
+        let (hit, _, rows) = do_get_cache_header(&mut client, &token, &ticket, &headers).await;
+        assert!(hit.is_none());
+        assert_eq!(rows, 2);
+    })
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_query_and_ticket_size_limits() -> Result<()> {
+    let mut config = prepare_config();
+    config.query.flight_sql_max_query_size = 1024;
+    config.query.flight_sql_max_ticket_size = 512;
+    let _fixture = TestFixture::setup_with_config(&config).await?;
+
+    let service = FlightSQLServer::flight_service(&config)?;
+    run_with_uds_service(service, |path| async move {
+        let mut client = client_with_uds(path).await;
+        let token = client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+        let token = String::from_utf8(token.to_vec()).unwrap();
+
+        // Just under the limit.
+        let sql = format!("select '{}' as s", "x".repeat(1000));
+        let res = run_query(&mut client, &sql).await.unwrap();
+        assert!(res.contains(&"x".repeat(1000)), "{res}");
+
+        let sql = format!("select '{}' as s", "x".repeat(1024));
+        let err = client.prepare(sql.clone(), None).await.unwrap_err();
+        assert!(
+            err.to_string().contains("flight_sql_max_query_size 1024"),
+            "{err}"
+        );
+        let err = client.execute_update(sql, None).await.unwrap_err();
+        assert!(
+            err.to_string().contains("flight_sql_max_query_size 1024"),
+            "{err}"
+        );
+
+        // Far over the limit, rejected before the message is decoded.
+        let sql = format!("select '{}' as s", "x".repeat(4096));
+        let err = client.prepare(sql, None).await.unwrap_err();
+        assert!(
+            err.to_string().contains("decoded message length too large"),
+            "{err}"
+        );
+
+        let any = Any {
+            type_url: "type.googleapis.com/databend.flight.protocol.sql.FetchResults".to_string(),
+            value: vec![0; 1024].into(),
+        };
+        let ticket = Ticket {
+            ticket: any.encode_to_vec().into(),
+        };
+        let request = ticket_request(&token, ticket, &[]);
+        let err = client.inner_mut().do_get(request).await.unwrap_err();
+        assert_eq!(err.code(), Code::InvalidArgument);
+        assert!(
+            err.message().contains("flight_sql_max_ticket_size 512"),
+            "{err}"
+        );
+
+        // Not even a message, the size is checked on the raw bytes before they are decoded.
+        let ticket = Ticket {
+            ticket: vec![0xff; 1024].into(),
+        };
+        let request = ticket_request(&token, ticket, &[]);
+        let err = client.inner_mut().do_get(request).await.unwrap_err();
+        assert_eq!(err.code(), Code::InvalidArgument);
+        assert!(
+            err.message().contains("flight_sql_max_ticket_size 512"),
+            "{err}"
+        );
+    })
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_metadata_catalogs() -> Result<()> {
+    let _fixture = TestFixture::setup_with_config(&prepare_config()).await?;
+
+    run_with_uds_server(|path| async move {
+        let mut client = client_with_uds(path).await;
+        let token = client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+        let token = String::from_utf8(token.to_vec()).unwrap();
+
+        for catalog in [Some("default"), Some(""), None] {
+            let flight_info = client
+                .get_db_schemas(CommandGetDbSchemas {
+                    catalog: catalog.map(|c| c.to_string()),
+                    db_schema_filter_pattern: None,
+                })
+                .await
+                .unwrap();
+            let ticket = flight_info.endpoint[0].ticket.clone().unwrap();
+            let batches: Vec<RecordBatch> = client
+                .do_get(ticket)
+                .await
+                .unwrap()
+                .try_collect()
+                .await
+                .unwrap();
+            let res = pretty_format_batches(batches.as_slice())
+                .unwrap()
+                .to_string();
+            let is_system = |l: &str| l.starts_with("| default ") && l.contains("| system ");
+            assert!(res.lines().any(is_system), "{res}");
+
+            let flight_info = client
+                .get_tables(CommandGetTables {
+                    catalog: catalog.map(|c| c.to_string()),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+            let ticket = flight_info.endpoint[0].ticket.clone().unwrap();
+            let batches: Vec<RecordBatch> = client
+                .do_get(ticket)
+                .await
+                .unwrap()
+                .try_collect()
+                .await
+                .unwrap();
+            let res = pretty_format_batches(batches.as_slice())
+                .unwrap()
+                .to_string();
+            let is_tables = |l: &str| is_system(l) && l.contains("| tables ");
+            assert!(res.lines().any(is_tables), "{res}");
+        }
+
+        // The filter patterns are LIKE patterns, the names compare case-insensitively.
+        let flight_info = client
+            .get_tables(CommandGetTables {
+                catalog: Some("default".to_string()),
+                db_schema_filter_pattern: Some("SYS%".to_string()),
+                table_name_filter_pattern: Some("tab_es".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let ticket = flight_info.endpoint[0].ticket.clone().unwrap();
+        let batches: Vec<RecordBatch> = client
+            .do_get(ticket)
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        let res = pretty_format_batches(batches.as_slice())
+            .unwrap()
+            .to_string();
+        let rows = res
+            .lines()
+            .filter(|l| l.starts_with("| default "))
+            .collect::<Vec<_>>();
+        assert_eq!(rows.len(), 1, "{res}");
+        assert!(
+            rows[0].contains("| system ") && rows[0].contains("| tables "),
+            "{res}"
+        );
+
+        let flight_info = client
+            .get_tables(CommandGetTables {
+                catalog: Some("no_such_catalog".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let ticket = flight_info.endpoint[0].ticket.clone().unwrap();
+        let request = ticket_request(&token, ticket, &[]);
+        let err = client.inner_mut().do_get(request).await.unwrap_err();
+        assert_eq!(err.code(), Code::NotFound);
+    })
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_get_tables_include_schema() -> Result<()> {
+    let _fixture = TestFixture::setup_with_config(&prepare_config()).await?;
+
+    run_with_uds_server(|path| async move {
+        let mut client = client_with_uds(path).await;
+        client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+
+        let sql = "create or replace table t_column_meta(a int comment 'the id', \
+                   b string default 'x', c int) cluster by (a)";
+        client.execute_update(sql.to_string(), None).await.unwrap();
+
+        let flight_info = client
+            .get_tables(CommandGetTables {
+                catalog: Some("default".to_string()),
+                include_schema: true,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let ticket = flight_info.endpoint[0].ticket.clone().unwrap();
+        let batches: Vec<RecordBatch> = client
+            .do_get(ticket)
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+
+        let mut table_schema = None;
+        for batch in &batches {
+            let names = batch
+                .column_by_name("table_name")
+                .unwrap()
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .unwrap();
+            let schemas = batch
+                .column_by_name("table_schema")
+                .unwrap()
+                .as_any()
+                .downcast_ref::<BinaryArray>()
+                .unwrap();
+            for i in 0..batch.num_rows() {
+                if names.value(i) == "t_column_meta" {
+                    let schema = schemas.value(i);
+                    table_schema =
+                        Some(arrow_ipc::convert::try_schema_from_ipc_buffer(schema).unwrap());
+                }
             }
         }
-        shutdown_tx.send(()).unwrap();
-        serve_future.await.unwrap();
-        debug!("Server shutdown!");
+        let table_schema = table_schema.unwrap();
 
-        Ok(())
+        let a = table_schema.field_with_name("a").unwrap().metadata();
+        assert_eq!(a.get("ARROW:FLIGHT:SQL:REMARKS").unwrap(), "the id");
+        assert_eq!(a.get("DATABEND:CLUSTER_KEY").unwrap(), "true");
+        assert!(!a.contains_key("DATABEND:DEFAULT_EXPR"));
+
+        let b = table_schema.field_with_name("b").unwrap().metadata();
+        assert!(b.contains_key("DATABEND:DEFAULT_EXPR"));
+        assert!(!b.contains_key("ARROW:FLIGHT:SQL:REMARKS"));
+        assert!(!b.contains_key("DATABEND:CLUSTER_KEY"));
+
+        let c = table_schema.field_with_name("c").unwrap().metadata();
+        assert!(c.is_empty(), "{c:?}");
+    })
+}
+
+/// Lists the databases and the tables matching `meta_%` as the user, as `database` and
+/// `database.table`.
+async fn list_meta_objects(path: String, user: &str, password: &str) -> (Vec<String>, Vec<String>) {
+    let mut client = client_with_uds(path).await;
+    client.handshake(user, password).await.unwrap();
+
+    let flight_info = client
+        .get_db_schemas(CommandGetDbSchemas {
+            catalog: Some("default".to_string()),
+            db_schema_filter_pattern: Some("meta_%".to_string()),
+        })
+        .await
+        .unwrap();
+    let ticket = flight_info.endpoint[0].ticket.clone().unwrap();
+    let batches: Vec<RecordBatch> = client
+        .do_get(ticket)
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+    let mut databases = vec![];
+    for batch in &batches {
+        let names = batch
+            .column_by_name("db_schema_name")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        databases.extend(names.iter().flatten().map(|name| name.to_string()));
+    }
+    databases.sort();
+
+    let flight_info = client
+        .get_tables(CommandGetTables {
+            catalog: Some("default".to_string()),
+            db_schema_filter_pattern: Some("meta_%".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let ticket = flight_info.endpoint[0].ticket.clone().unwrap();
+    let batches: Vec<RecordBatch> = client
+        .do_get(ticket)
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+    let mut tables = vec![];
+    for batch in &batches {
+        let column = |name: &str| {
+            batch
+                .column_by_name(name)
+                .unwrap()
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .unwrap()
+                .clone()
+        };
+        let (databases, names) = (column("db_schema_name"), column("table_name"));
+        for i in 0..batch.num_rows() {
+            tables.push(format!("{}.{}", databases.value(i), names.value(i)));
+        }
+    }
+    tables.sort();
+    (databases, tables)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_metadata_visibility() -> Result<()> {
+    let _fixture = TestFixture::setup_with_config(&prepare_config()).await?;
+
+    run_with_uds_server(|path| async move {
+        let mut client = client_with_uds(path.clone()).await;
+        client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+        let statements = [
+            "create or replace database meta_visible",
+            "create or replace database meta_hidden",
+            "create or replace table meta_visible.t1(a int)",
+            "create or replace table meta_visible.t2(a int)",
+            "create or replace table meta_hidden.t3(a int)",
+            "create user if not exists 'meta_reader' identified by 'meta_password'",
+            "grant select on meta_visible.t1 to 'meta_reader'",
+        ];
+        for sql in statements {
+            client.execute_update(sql.to_string(), None).await.unwrap();
+        }
+
+        // The admin lists every database and table.
+        let (databases, tables) = list_meta_objects(path.clone(), TEST_USER, TEST_PASSWORD).await;
+        assert_eq!(databases, vec!["meta_hidden", "meta_visible"]);
+        assert_eq!(tables, vec![
+            "meta_hidden.t3",
+            "meta_visible.t1",
+            "meta_visible.t2"
+        ]);
+
+        // The other users only list the objects they have a privilege on.
+        let (databases, tables) = list_meta_objects(path, "meta_reader", "meta_password").await;
+        assert_eq!(databases, vec!["meta_visible"]);
+        assert_eq!(tables, vec!["meta_visible.t1"]);
+    })
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_statement_rate_limit() -> Result<()> {
+    let fixture = TestFixture::setup_with_config(&prepare_config()).await?;
+    fixture
+        .execute_command("set global flight_sql_statements_per_second = 1")
+        .await?;
+    fixture
+        .execute_command("set global flight_sql_statements_burst = 2")
+        .await?;
+
+    run_with_uds_server(|path| async move {
+        let mut client = client_with_uds(path).await;
+        client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+
+        let sql = "set max_threads = 4".to_string();
+        client.execute_update(sql.clone(), None).await.unwrap();
+        client.execute_update(sql.clone(), None).await.unwrap();
+        let err = client.execute_update(sql, None).await.unwrap_err();
+        let err = err.to_string();
+        assert!(err.contains("ResourceExhausted"), "{err}");
+        assert!(err.contains("retry-after"), "{err}");
+
+        // Metadata commands have their own bucket.
+        let flight_info = client
+            .get_tables(CommandGetTables::default())
+            .await
+            .unwrap();
+        let ticket = flight_info.endpoint[0].ticket.clone().unwrap();
+        client.do_get(ticket).await.unwrap();
+    })
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_statement_rate_limit_plan_error() -> Result<()> {
+    let fixture = TestFixture::setup_with_config(&prepare_config()).await?;
+    fixture
+        .execute_command("set global flight_sql_statements_per_second = 1")
+        .await?;
+    fixture
+        .execute_command("set global flight_sql_statements_burst = 2")
+        .await?;
+
+    run_with_uds_server(|path| async move {
+        let mut client = client_with_uds(path).await;
+        client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+
+        // A retried statement failing to plan still takes from the bucket.
+        let sql = "delete from no_such_table".to_string();
+        for _ in 0..2 {
+            let err = client.execute_update(sql.clone(), None).await.unwrap_err();
+            let err = err.to_string();
+            assert!(err.contains("no_such_table"), "{err}");
+        }
+        let err = client.execute_update(sql, None).await.unwrap_err();
+        let err = err.to_string();
+        assert!(err.contains("ResourceExhausted"), "{err}");
+        assert!(err.contains("retry-after"), "{err}");
+    })
+}
+
+/// Handshakes with the credentials in the payloads, returns the bearer token.
+async fn payload_handshake(
+    client: &mut FlightSqlServiceClient<Channel>,
+    payloads: Vec<Vec<u8>>,
+) -> std::result::Result<String, tonic::Status> {
+    let requests = payloads
+        .into_iter()
+        .map(|payload| HandshakeRequest {
+            protocol_version: 0,
+            payload: payload.into(),
+        })
+        .collect::<Vec<_>>();
+    // Keep the request stream open, as the clients waiting for the response do.
+    let requests = futures::stream::iter(requests).chain(futures::stream::pending());
+    let response = client.inner_mut().handshake(requests).await?;
+    let authorization = response.metadata().get("authorization").unwrap();
+    let token = authorization.to_str().unwrap().strip_prefix("Bearer ");
+    Ok(token.unwrap().to_string())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_handshake_payload_auth() -> Result<()> {
+    let _fixture = TestFixture::setup_with_config(&prepare_config()).await?;
+
+    run_with_uds_server(|path| async move {
+        // Header auth.
+        let mut client = client_with_uds(path.clone()).await;
+        client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+        run_query(&mut client, "select 1").await.unwrap();
+
+        // Payload auth, a BasicAuth message after an empty payload, as the Java client.
+        let mut client = client_with_uds(path.clone()).await;
+        let basic_auth = BasicAuth {
+            username: TEST_USER.to_string(),
+            password: TEST_PASSWORD.to_string(),
+        };
+        let token = payload_handshake(&mut client, vec![vec![], basic_auth.encode_to_vec()])
+            .await
+            .unwrap();
+        client.set_token(token);
+        run_query(&mut client, "select 1").await.unwrap();
+
+        // Payload auth, a plain `user:password`, as the Go client.
+        let mut client = client_with_uds(path.clone()).await;
+        let credentials = format!("{TEST_USER}:{TEST_PASSWORD}");
+        let token = payload_handshake(&mut client, vec![credentials.into_bytes()])
+            .await
+            .unwrap();
+        client.set_token(token);
+        run_query(&mut client, "select 1").await.unwrap();
+
+        // Wrong password in the payload.
+        let mut client = client_with_uds(path.clone()).await;
+        let credentials = format!("{TEST_USER}:wrong");
+        let err = payload_handshake(&mut client, vec![credentials.into_bytes()])
+            .await
+            .unwrap_err();
+        assert_ne!(err.code(), Code::Ok);
+
+        // Neither header nor payload.
+        let mut client = client_with_uds(path).await;
+        let requests = futures::stream::iter(vec![HandshakeRequest::default()]);
+        let err = client.inner_mut().handshake(requests).await.unwrap_err();
+        assert_eq!(err.code(), Code::Unauthenticated);
+    })
+}
+
+/// Returns the type of the timestamp column and its value rendered by the server.
+async fn query_epoch(client: &mut FlightSqlServiceClient<Channel>) -> (DataType, String) {
+    let sql = "select to_timestamp(0) as ts, to_string(to_timestamp(0)) as s";
+    let mut stmt = client.prepare(sql.to_string(), None).await.unwrap();
+    let flight_info = stmt.execute().await.unwrap();
+    let ticket = flight_info.endpoint[0].ticket.as_ref().unwrap().clone();
+    let flight_data = client.do_get(ticket).await.unwrap();
+    let batches: Vec<RecordBatch> = flight_data.try_collect().await.unwrap();
+    let batch = &batches[0];
+    let data_type = batch.schema().field(0).data_type().clone();
+    let rendered = batch
+        .column(1)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap()
+        .value(0)
+        .to_string();
+    (data_type, rendered)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_timezone_header() -> Result<()> {
+    let _fixture = TestFixture::setup_with_config(&prepare_config()).await?;
+
+    run_with_uds_server(|path| async move {
+        // Per request.
+        let mut client = client_with_uds(path.clone()).await;
+        client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+        client.set_header("databend-timezone", "UTC");
+        let (data_type, rendered) = query_epoch(&mut client).await;
+        assert_eq!(
+            data_type,
+            DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into()))
+        );
+        assert_eq!(rendered, "1970-01-01 00:00:00.000000");
+
+        // Session wide, set at the handshake and kept by the later requests without header.
+        let mut client = client_with_uds(path.clone()).await;
+        client.set_header("databend-timezone", "Asia/Shanghai");
+        let token = client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+        let mut client = client_with_uds(path.clone()).await;
+        client.set_token(String::from_utf8(token.to_vec()).unwrap());
+        let (data_type, rendered) = query_epoch(&mut client).await;
+        assert_eq!(
+            data_type,
+            DataType::Timestamp(TimeUnit::Microsecond, Some("Asia/Shanghai".into()))
+        );
+        assert_eq!(rendered, "1970-01-01 08:00:00.000000");
+
+        // Invalid zone.
+        let mut client = client_with_uds(path).await;
+        client.set_header("databend-timezone", "Mars/Olympus_Mons");
+        let err = client
+            .handshake(TEST_USER, TEST_PASSWORD)
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("InvalidArgument"), "{err}");
+        assert!(err.contains("Asia/Shanghai"), "{err}");
+    })
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_flight_sessions_table() -> Result<()> {
+    let _fixture = TestFixture::setup_with_config(&prepare_config()).await?;
+
+    run_with_uds_server(|path| async move {
+        let mut client = client_with_uds(path).await;
+        let token = client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+        let token = String::from_utf8(token.to_vec()).unwrap();
+
+        // The statement of the query itself is prepared.
+        let sql = "select user, prepared_statement_count, database from system.flight_sessions";
+        let res = run_query(&mut client, sql).await.unwrap();
+        let expected = [
+            "+-----------+--------------------------+----------+",
+            "| user      | prepared_statement_count | database |",
+            "+-----------+--------------------------+----------+",
+            "| test_user | 1                        | default  |",
+            "+-----------+--------------------------+----------+",
+        ];
+        assert_eq!(res, expected.join("\n"));
+
+        // The token is not exposed.
+        let sql = "select token_hash from system.flight_sessions";
+        let res = run_query(&mut client, sql).await.unwrap();
+        assert!(!res.contains(&token), "{res}");
+    })
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_prepared_statements_table() -> Result<()> {
+    let _fixture = TestFixture::setup_with_config(&prepare_config()).await?;
+
+    run_with_uds_server(|path| async move {
+        let mut client = client_with_uds(path.clone()).await;
+        client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+
+        let sql = "select user, execution_count, last_executed_at is null as never_executed \
+            from system.prepared_statements where sql = 'select 42'";
+        let mut stmt = client.prepare("select 42".to_string(), None).await.unwrap();
+        let res = run_query(&mut client, sql).await.unwrap();
+        let expected = [
+            "+-----------+-----------------+----------------+",
+            "| user      | execution_count | never_executed |",
+            "+-----------+-----------------+----------------+",
+            "| test_user | 0               | true           |",
+            "+-----------+-----------------+----------------+",
+        ];
+        assert_eq!(res, expected.join("\n"));
+
+        let flight_info = stmt.execute().await.unwrap();
+        let ticket = flight_info.endpoint[0].ticket.as_ref().unwrap().clone();
+        let flight_data = client.do_get(ticket).await.unwrap();
+        let _: Vec<RecordBatch> = flight_data.try_collect().await.unwrap();
+        let res = run_query(&mut client, sql).await.unwrap();
+        let expected = [
+            "+-----------+-----------------+----------------+",
+            "| user      | execution_count | never_executed |",
+            "+-----------+-----------------+----------------+",
+            "| test_user | 1               | false          |",
+            "+-----------+-----------------+----------------+",
+        ];
+        assert_eq!(res, expected.join("\n"));
+
+        // The closed statement is gone.
+        stmt.close().await.unwrap();
+        let sql = "select count(*) as c from system.prepared_statements where sql = 'select 42'";
+        let res = run_query(&mut client, sql).await.unwrap();
+        let expected = ["+---+", "| c |", "+---+", "| 0 |", "+---+"];
+        assert_eq!(res, expected.join("\n"));
+
+        // The SQL text is truncated by prepared_statements_sql_max_length.
+        run_query(&mut client, "set prepared_statements_sql_max_length = 6")
+            .await
+            .unwrap();
+        let sql = "select count(*) as c from system.prepared_statements where length(sql) > 6";
+        let res = run_query(&mut client, sql).await.unwrap();
+        assert_eq!(res, expected.join("\n"));
+
+        // A user without the SUPER privilege can not read the table.
+        run_query(
+            &mut client,
+            "create user if not exists u1 identified by 'p1'",
+        )
+        .await
+        .unwrap();
+        let mut other = client_with_uds(path).await;
+        other.handshake("u1", "p1").await.unwrap();
+        let res = run_query(&mut other, "select * from system.prepared_statements").await;
+        assert!(res.is_err(), "{res:?}");
     })
 }
@@ -18,7 +18,14 @@ use std::sync::Arc;
 use databend_common_base::base::tokio;
 use databend_common_exception::Result;
 use databend_query::servers::FlightSQLServer;
+use databend_query::servers::Server;
 use databend_query::test_kits::ConfigBuilder;
+use databend_query::test_kits::TestFixture;
+use tonic::transport::Channel;
+use tonic::transport::Endpoint;
+use tonic_health::pb::health_check_response::ServingStatus;
+use tonic_health::pb::health_client::HealthClient;
+use tonic_health::pb::HealthCheckRequest;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_flight_sql_server_port_used() -> Result<()> {
@@ -28,6 +35,7 @@ async fn test_flight_sql_server_port_used() -> Result<()> {
     let mut srv = FlightSQLServer {
         config: ConfigBuilder::create().build(),
         abort_notify: Arc::new(Default::default()),
+        health_reporter: None,
     };
 
     let r = srv.start_with_incoming(local_socket).await;
@@ -35,3 +43,73 @@ async fn test_flight_sql_server_port_used() -> Result<()> {
     assert!(r.is_err());
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_flight_sql_server_invalid_grpc_compression() -> Result<()> {
+    let mut srv = FlightSQLServer {
+        config: ConfigBuilder::create()
+            .flight_sql_grpc_compression("lz4")
+            .build(),
+        abort_notify: Arc::new(Default::default()),
+        health_reporter: None,
+    };
+
+    let r = srv
+        .start_with_incoming("127.0.0.1:0".parse().unwrap())
+        .await;
+
+    assert!(r.is_err());
+    Ok(())
+}
+
+async fn check_health(client: &mut HealthClient<Channel>, service: &str) -> ServingStatus {
+    let request = HealthCheckRequest {
+        service: service.to_string(),
+    };
+    let response = client.check(request).await.unwrap().into_inner();
+    response.status()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_flight_sql_server_health() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let local_socket = listener.local_addr().unwrap();
+    drop(listener);
+
+    let mut srv = FlightSQLServer {
+        config: ConfigBuilder::create().build(),
+        abort_notify: Arc::new(Default::default()),
+        health_reporter: None,
+    };
+    srv.start_with_incoming(local_socket).await?;
+
+    let channel = Endpoint::try_from(format!("http://{local_socket}"))
+        .unwrap()
+        .connect()
+        .await
+        .unwrap();
+    let mut client = HealthClient::new(channel);
+    let flight_service = "arrow.flight.protocol.FlightService";
+
+    assert_eq!(check_health(&mut client, "").await, ServingStatus::Serving);
+    assert_eq!(
+        check_health(&mut client, flight_service).await,
+        ServingStatus::Serving
+    );
+
+    // Draining: the listener is still open but reports NOT_SERVING.
+    srv.shutdown(true).await;
+    assert_eq!(
+        check_health(&mut client, "").await,
+        ServingStatus::NotServing
+    );
+    assert_eq!(
+        check_health(&mut client, flight_service).await,
+        ServingStatus::NotServing
+    );
+
+    srv.shutdown(false).await;
+    Ok(())
+}
@@ -14,5 +14,6 @@
 
 // The servers module used for external communication with user, such as MySQL wired protocol, etc.
 
+mod client_address;
 mod flight_sql_handler;
 mod flight_sql_server;
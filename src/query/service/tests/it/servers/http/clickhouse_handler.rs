@@ -255,6 +255,50 @@ async fn test_federated() -> PoemResult<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_client_startup() -> PoemResult<()> {
+    let _fixture = TestFixture::setup().await.unwrap();
+
+    let server = Server::new().await;
+    // The statements of clickhouse-client and the drivers after connecting.
+    for (sql, exp) in [
+        ("SELECT version()", format!("{CLICKHOUSE_VERSION}\n")),
+        (
+            "SELECT version() FORMAT TSVWithNames",
+            format!("version()\n{CLICKHOUSE_VERSION}\n"),
+        ),
+        ("SELECT timezone()", "UTC\n".to_string()),
+        ("SET send_logs_level = 'warning'", "".to_string()),
+        ("SET log_comment = 'clickhouse-client'", "".to_string()),
+        ("EXISTS TABLE system.tables", "1\n".to_string()),
+        ("SELECT currentDatabase()", "default\n".to_string()),
+    ] {
+        let (status, body) = server.post("", sql).await;
+        assert_ok!(status, body);
+        assert_eq!(body, exp, "{sql}");
+    }
+
+    // The values agree with the planned queries.
+    let (status, body) = server.get("SELECT version(), 1").await;
+    assert_ok!(status, body);
+    assert_eq!(body, format!("{CLICKHOUSE_VERSION}\t1\n"));
+
+    let (status, body) = server
+        .get_response(
+            QueryBuilder::new("SELECT timezone()")
+                .settings(HashMap::from([(
+                    "timezone".to_string(),
+                    "Asia/Shanghai".to_string(),
+                )]))
+                .build(),
+        )
+        .await;
+    assert_ok!(status, body);
+    assert_eq!(&body, "Asia/Shanghai\n");
+
+    Ok(())
+}
+
 struct QueryBuilder {
     sql: String,
     body: Option<Body>,
@@ -412,6 +412,41 @@ async fn test_client_query_id() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_client_labels() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let ep = create_endpoint().await?;
+    let mut headers = HeaderMap::new();
+    headers.insert("x-databend-app-name", "airflow".parse().unwrap());
+    headers.insert(
+        "x-databend-labels",
+        "Team=data, env = prod\t,bad key=1,no_value"
+            .parse()
+            .unwrap(),
+    );
+    let sql = "select labels['app'], labels['team'], labels['env'] from system.processes \
+        where labels['app'] = 'airflow' and labels['bad key'] is null";
+    let (status, result) = post_sql_to_endpoint_new_session(&ep, sql, 5, headers).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+    assert_eq!(
+        result.data,
+        vec![vec!["airflow", "data", "prod"]],
+        "{:?}",
+        result
+    );
+
+    // The queries of the labeled session are found in the query log by their labels.
+    let sql =
+        "select count(*) from system.query_log where log_type = 1 and labels['app'] = 'airflow'";
+    let (status, result) = post_sql(sql, 5).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert_eq!(result.data, vec![vec!["1"]], "{:?}", result);
+
+    Ok(())
+}
+
 // ref: query_log not recorded correctly.
 // It could be uncommented when we remove SEE_YOU_AGAIN stmt
 
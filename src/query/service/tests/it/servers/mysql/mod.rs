@@ -12,5 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod mysql_comments;
 mod mysql_federated;
 mod mysql_handler;
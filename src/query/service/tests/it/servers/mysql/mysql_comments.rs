@@ -0,0 +1,103 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Cow;
+
+use databend_query::servers::mysql_version_id;
+use databend_query::servers::strip_mysql_comments;
+
+#[test]
+fn test_mysql_version_id() {
+    assert_eq!(mysql_version_id("8.0.26"), Some(80026));
+    assert_eq!(mysql_version_id("8.0.26-databend-v1.2.300"), Some(80026));
+    assert_eq!(mysql_version_id("5.7"), Some(50700));
+    assert_eq!(mysql_version_id("databend"), None);
+}
+
+#[test]
+fn test_strip_mysql_comments() {
+    let strip = |query| strip_mysql_comments(query, 80026);
+
+    // mysqldump.
+    for (query, expect) in [
+        (
+            "/*!40103 SET @OLD_TIME_ZONE=@@TIME_ZONE */",
+            "SET @OLD_TIME_ZONE=@@TIME_ZONE",
+        ),
+        (
+            "/*!40103 SET TIME_ZONE='+00:00' */",
+            "SET TIME_ZONE='+00:00'",
+        ),
+        (
+            "/*!40014 SET @OLD_UNIQUE_CHECKS=@@UNIQUE_CHECKS, UNIQUE_CHECKS=0 */;",
+            "SET @OLD_UNIQUE_CHECKS=@@UNIQUE_CHECKS, UNIQUE_CHECKS=0  ;",
+        ),
+        (
+            "/*!40103 SET TIME_ZONE=@OLD_TIME_ZONE */",
+            "SET TIME_ZONE=@OLD_TIME_ZONE",
+        ),
+        (
+            "/*!40101*/select number from numbers_mt(2)",
+            "select number from numbers_mt(2)",
+        ),
+        (
+            "SELECT /*!40001 SQL_NO_CACHE */ * FROM t",
+            "SELECT   SQL_NO_CACHE   * FROM t",
+        ),
+        (
+            "START TRANSACTION /*!40100 WITH CONSISTENT SNAPSHOT */",
+            "START TRANSACTION   WITH CONSISTENT SNAPSHOT",
+        ),
+        ("/*! SET NAMES utf8mb4 */", "SET NAMES utf8mb4"),
+    ] {
+        assert_eq!(strip(query), expect, "{query}");
+    }
+
+    // The versions after the server version are dropped.
+    assert_eq!(strip("/*!80027 SET x = 1 */"), "");
+    assert_eq!(strip("select 1 /*!90000 + 1 */ + 2"), "select 1    + 2");
+    assert_eq!(strip("select 1 /*!80026 + 1 */"), "select 1   + 1");
+
+    // The hints are dropped, except the SET_VAR hints.
+    assert_eq!(
+        strip("SELECT /*+ MAX_EXECUTION_TIME(1000) */ @@version"),
+        "SELECT   @@version"
+    );
+    assert_eq!(strip("/*+ BKA(t1) */select/*+ NO_BKA(t2) */1"), "select 1");
+    let query = "INSERT /*+ SET_VAR(timezone='Asia/Shanghai') */ INTO t VALUES(1)";
+    assert_eq!(strip(query), query);
+
+    // The comments inside of the literals, quoted identifiers and line comments are kept.
+    for query in [
+        "select '/*!40101 x */', \"/*+ h */\", `/*!x*/` from t",
+        "select 'it''s /*!40101 x */', 'a\\' /*+ h */'",
+        "select 1 -- /*!40101 x */",
+        "select 1 # /*+ h */",
+        "/* plain /*!40101 x */ select 1",
+        "select '中文 /*!40101 x */'",
+    ] {
+        assert!(
+            matches!(strip(query), Cow::Borrowed(q) if q == query),
+            "{query}"
+        );
+    }
+    assert_eq!(
+        strip("select '/*!40101 x */' /*!40101 , 1 */ -- /*+ h */"),
+        "select '/*!40101 x */'   , 1   -- /*+ h */"
+    );
+
+    // Unterminated.
+    assert_eq!(strip("/*!40101 SET NAMES utf8"), "SET NAMES utf8");
+    assert_eq!(strip("select /*+ h"), "select /*+ h");
+}
@@ -12,9 +12,37 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_base::base::ServerWarnings;
+use databend_common_config::MySQLFederatedColumnConfig;
+use databend_common_config::MySQLFederatedResponse;
+use databend_common_config::MySQLFederatedRuleConfig;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::block_debug::assert_blocks_eq;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+use databend_query::servers::federated_helper::FederatedCaptures;
+use databend_query::servers::federated_helper::FederatedHelper;
+use databend_query::servers::federated_helper::FederatedRules;
+use databend_query::servers::federated_helper::LazyBlockFunc;
+use databend_query::servers::federated_helper::LazyBlockRule;
+use databend_query::servers::strip_mysql_comments;
 use databend_query::servers::MySQLFederated;
+use databend_query::servers::MySQLFederatedRules;
+use databend_query::servers::MySQLTransactionIsolation;
+use databend_query::sessions::SessionType;
+use databend_query::test_kits::ConfigBuilder;
+use databend_query::test_kits::TestFixture;
+use futures_util::TryStreamExt;
 
 #[test]
 fn test_mysql_federated() -> Result<()> {
@@ -23,14 +51,14 @@ fn test_mysql_federated() -> Result<()> {
     //
     {
         let query = "select 1";
-        let result = federated.check(query);
+        let result = federated.check(query)?;
         assert!(result.is_none());
     }
 
     // variables
     {
         let query = "select @@tx_isolation, @@session.tx_isolation";
-        let result = federated.check(query);
+        let result = federated.check(query)?;
         assert!(result.is_some());
 
         if let Some((_, block)) = result {
@@ -49,21 +77,1081 @@ fn test_mysql_federated() -> Result<()> {
     // complex variables
     {
         let query = "/* mysql-connector-java-8.0.17 (Revision: 16a712ddb3f826a1933ab42b0039f7fb9eebc6ec) */SELECT  @@session.auto_increment_increment AS auto_increment_increment, @@character_set_client AS character_set_client, @@character_set_connection AS character_set_connection, @@character_set_results AS character_set_results, @@character_set_server AS character_set_server, @@collation_server AS collation_server, @@collation_connection AS collation_connection, @@init_connect AS init_connect, @@interactive_timeout AS interactive_timeout, @@license AS license, @@lower_case_table_names AS lower_case_table_names, @@max_allowed_packet AS max_allowed_packet, @@net_write_timeout AS net_write_timeout, @@performance_schema AS performance_schema, @@sql_mode AS sql_mode, @@system_time_zone AS system_time_zone, @@time_zone AS time_zone, @@transaction_isolation AS transaction_isolation, @@wait_timeout AS wait_timeout;";
-        let result = federated.check(query);
+        let result = federated.check(query)?;
         assert!(result.is_some());
 
         if let Some((_, block)) = result {
             let expect = vec![
-                "+----------+----------+----------+----------+----------+----------+----------+----------+------------+----------+-----------+-------------+------------+-----------+-----------+-----------+-----------+-------------------+------------+",
-                "| Column 0 | Column 1 | Column 2 | Column 3 | Column 4 | Column 5 | Column 6 | Column 7 | Column 8   | Column 9 | Column 10 | Column 11   | Column 12  | Column 13 | Column 14 | Column 15 | Column 16 | Column 17         | Column 18  |",
-                "+----------+----------+----------+----------+----------+----------+----------+----------+------------+----------+-----------+-------------+------------+-----------+-----------+-----------+-----------+-------------------+------------+",
-                "| '0'      | '0'      | '0'      | '0'      | '0'      | '0'      | '0'      | '0'      | '31536000' | '0'      | '0'       | '134217728' | '31536000' | '0'       | '0'       | 'UTC'     | 'UTC'     | 'REPEATABLE-READ' | '31536000' |",
-                "+----------+----------+----------+----------+----------+----------+----------+----------+------------+----------+-----------+-------------+------------+-----------+-----------+-----------+-----------+-------------------+------------+",
+                "+----------+-----------+-----------+-----------+-----------+----------------------+----------------------+----------+----------+--------------+-----------+-----------+-----------+-----------+-------------------------------------------------------------------------------------------------------------------------+-----------+-----------+-------------------+-----------+",
+                "| Column 0 | Column 1  | Column 2  | Column 3  | Column 4  | Column 5             | Column 6             | Column 7 | Column 8 | Column 9     | Column 10 | Column 11 | Column 12 | Column 13 | Column 14                                                                                                               | Column 15 | Column 16 | Column 17         | Column 18 |",
+                "+----------+-----------+-----------+-----------+-----------+----------------------+----------------------+----------+----------+--------------+-----------+-----------+-----------+-----------+-------------------------------------------------------------------------------------------------------------------------+-----------+-----------+-------------------+-----------+",
+                "| 1        | 'utf8mb4' | 'utf8mb4' | 'utf8mb4' | 'utf8mb4' | 'utf8mb4_general_ci' | 'utf8mb4_general_ci' | ''       | 31536000 | 'Apache-2.0' | 0         | 134217728 | 31536000  | 0         | 'ONLY_FULL_GROUP_BY STRICT_TRANS_TABLES NO_ZERO_IN_DATE NO_ZERO_DATE ERROR_FOR_DIVISION_BY_ZERO NO_ENGINE_SUBSTITUTION' | 'UTC'     | 'UTC'     | 'REPEATABLE-READ' | 31536000  |",
+                "+----------+-----------+-----------+-----------+-----------+----------------------+----------------------+----------+----------+--------------+-----------+-----------+-----------+-----------+-------------------------------------------------------------------------------------------------------------------------+-----------+-----------+-------------------+-----------+",
+            ];
+
+            assert_blocks_eq(expect, &[block]);
+        }
+    }
+
+    // mixed case
+    {
+        for query in [
+            "SHOW Variables LIKE 'sql_mode'",
+            "Set Names utf8mb4",
+            "show warnings",
+        ] {
+            assert!(federated.check(query)?.is_some(), "{query}");
+        }
+
+        let query = "Select @@Time_Zone";
+        let result = federated.check(query)?;
+        assert!(result.is_some());
+
+        if let Some((schema, block)) = result {
+            assert_eq!(schema.field(0).name(), "@@time_zone");
+            let expect = vec![
+                "+----------+",
+                "| Column 0 |",
+                "+----------+",
+                "| 'UTC'    |",
+                "+----------+",
             ];
 
             assert_blocks_eq(expect, &[block]);
         }
     }
 
+    // leading whitespace and block comments
+    {
+        for query in [
+            "  \n\tSET NAMES utf8",
+            "/* ApplicationName=DBeaver 23.0.0 - Main */ SHOW PLUGINS",
+            "/* a */ /* b */\nshow charset",
+            "/* ApplicationName=DBeaver */ SELECT @@wait_timeout",
+        ] {
+            assert!(federated.check(query)?.is_some(), "{query}");
+        }
+
+        // Only the leading comments are skipped.
+        assert!(federated.check("/* a */ select 1")?.is_none());
+        assert!(federated.check("select 1 /* SET NAMES */")?.is_none());
+        // Unterminated comment.
+        assert!(federated.check("/* SET NAMES utf8")?.is_none());
+    }
+
+    // MySQL hint comments are kept
+    {
+        for query in [
+            "/*!40101 SET NAMES utf8 */",
+            "  /*!40101 set character_set_client = utf8 */",
+            "/* mysqldump */ /*!40014 SET UNIQUE_CHECKS=0 */",
+        ] {
+            assert!(federated.check(query)?.is_some(), "{query}");
+        }
+
+        assert!(federated.check("/*!40101 select 1 */")?.is_none());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_mysql_federated_show_charset_collation() -> Result<()> {
+    let federated = MySQLFederated::create();
+
+    // The column names and types are what the GUI clients match on.
+    {
+        let (schema, block) = federated.check("SHOW CHARSET")?.unwrap();
+        let names = schema
+            .fields()
+            .iter()
+            .map(|f| f.name().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, [
+            "Charset",
+            "Description",
+            "Default collation",
+            "Maxlen"
+        ]);
+        assert_eq!(
+            schema.field(3).data_type(),
+            &TableDataType::Number(NumberDataType::UInt64)
+        );
+        assert_eq!(block.num_rows(), 3);
+
+        let (schema, block) = federated.check("show collation")?.unwrap();
+        let names = schema
+            .fields()
+            .iter()
+            .map(|f| f.name().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, [
+            "Collation",
+            "Charset",
+            "Id",
+            "Default",
+            "Compiled",
+            "Sortlen",
+            "Pad_attribute"
+        ]);
+        assert_eq!(
+            schema.field(2).data_type(),
+            &TableDataType::Number(NumberDataType::UInt64)
+        );
+        assert_eq!(block.num_rows(), 5);
+    }
+
+    // like pattern and charset filter
+    {
+        let (_, block) = federated
+            .check("SHOW CHARACTER SET LIKE 'utf8mb4'")?
+            .unwrap();
+        let expect = vec![
+            "+-----------+-----------------+----------------------+----------+",
+            "| Column 0  | Column 1        | Column 2             | Column 3 |",
+            "+-----------+-----------------+----------------------+----------+",
+            "| 'utf8mb4' | 'UTF-8 Unicode' | 'utf8mb4_general_ci' | 4        |",
+            "+-----------+-----------------+----------------------+----------+",
+        ];
+        assert_blocks_eq(expect, &[block]);
+
+        let (_, block) = federated
+            .check("SHOW COLLATION WHERE Charset = 'utf8mb4'")?
+            .unwrap();
+        let expect = vec![
+            "+----------------------+-----------+----------+----------+----------+----------+-------------+",
+            "| Column 0             | Column 1  | Column 2 | Column 3 | Column 4 | Column 5 | Column 6    |",
+            "+----------------------+-----------+----------+----------+----------+----------+-------------+",
+            "| 'utf8mb4_bin'        | 'utf8mb4' | 46       | ''       | 'Yes'    | 1        | 'PAD SPACE' |",
+            "| 'utf8mb4_general_ci' | 'utf8mb4' | 45       | 'Yes'    | 'Yes'    | 1        | 'PAD SPACE' |",
+            "+----------------------+-----------+----------+----------+----------+----------+-------------+",
+        ];
+        assert_blocks_eq(expect, &[block]);
+
+        let (_, block) = federated.check("SHOW COLLATION LIKE '%\\_bin'")?.unwrap();
+        assert_eq!(block.num_rows(), 2);
+    }
+
+    // SHOW ENGINES is the real statement over the registered engines.
+    assert!(federated.check("SHOW ENGINES")?.is_none());
+
+    Ok(())
+}
+
+#[test]
+fn test_mysql_federated_show_status() -> Result<()> {
+    let federated = MySQLFederated::create();
+
+    // MySQL ODBC at connect.
+    {
+        let (schema, block) = federated
+            .check("SHOW SESSION STATUS LIKE 'Ssl_cipher'")?
+            .unwrap();
+        assert_eq!(schema.field(0).name(), "Variable_name");
+        assert_eq!(schema.field(1).name(), "Value");
+        let expect = vec![
+            "+--------------+----------+",
+            "| Column 0     | Column 1 |",
+            "+--------------+----------+",
+            "| 'Ssl_cipher' | ''       |",
+            "+--------------+----------+",
+        ];
+        assert_blocks_eq(expect, &[block]);
+    }
+
+    // The names are case-insensitive.
+    {
+        let (_, block) = federated
+            .check("show global status like 'uptime'")?
+            .unwrap();
+        assert_eq!(block.num_rows(), 1);
+        let (_, block) = federated.check("SHOW STATUS LIKE 'ssl%'")?.unwrap();
+        assert_eq!(block.num_rows(), 2);
+        let (_, block) = federated
+            .check("SHOW STATUS LIKE 'Threads_connected'")?
+            .unwrap();
+        assert_eq!(block.num_rows(), 0);
+        let (_, block) = federated.check("SHOW STATUS")?.unwrap();
+        assert_eq!(block.num_rows(), 3);
+    }
+
+    // The SHOW TABLE STATUS statement.
+    assert!(federated.check("SHOW TABLE STATUS LIKE 't'")?.is_none());
+
+    Ok(())
+}
+
+#[test]
+fn test_mysql_federated_bad_rule() -> Result<()> {
+    MySQLFederated::check_rules()?;
+
+    let pattern = "^(SHOW (VARIABLES";
+    let err = FederatedHelper::regex(pattern).unwrap_err();
+    assert!(err.message().contains(pattern), "{}", err.message());
+
+    Ok(())
+}
+
+#[test]
+fn test_federated_rule_sets() -> Result<()> {
+    let mysql = FederatedRules::mysql()?;
+    let clickhouse = FederatedRules::clickhouse()?;
+    assert_eq!(mysql.name(), "mysql");
+    assert_eq!(clickhouse.name(), "clickhouse");
+
+    // Each set only answers the probes of its protocol.
+    let (rule, _) = mysql.check("LOCK TABLES t READ").unwrap();
+    assert_eq!(rule, "lock_tables");
+    assert!(clickhouse.check("LOCK TABLES t READ").is_none());
+
+    let (rule, _) = clickhouse.check("SET send_logs_level = 'warning'").unwrap();
+    assert_eq!(rule, "set_send_logs_level");
+    assert!(mysql.check("SET send_logs_level = 'warning'").is_none());
+
+    // The sets are built once.
+    assert!(std::ptr::eq(mysql, FederatedRules::mysql()?));
+
+    Ok(())
+}
+
+fn mybi_probe_rule() -> MySQLFederatedRuleConfig {
+    MySQLFederatedRuleConfig {
+        name: "mybi_probe".to_string(),
+        pattern: r"^SELECT\s+mybi_version\(\)".to_string(),
+        response: MySQLFederatedResponse::Table,
+        columns: vec![
+            MySQLFederatedColumnConfig {
+                name: "version".to_string(),
+                data_type: "String".to_string(),
+            },
+            MySQLFederatedColumnConfig {
+                name: "build".to_string(),
+                data_type: "UInt64".to_string(),
+            },
+        ],
+        rows: vec![vec!["1.0".to_string(), "42".to_string()]],
+    }
+}
+
+#[test]
+fn test_federated_rule_index() -> Result<()> {
+    let log = std::fs::read_to_string("tests/it/servers/mysql/testdata/federated_query_log.txt")?;
+    let mut queries = log
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| line.to_string())
+        .collect::<Vec<_>>();
+    // The payload of a long INSERT, and the queries with the letters out of ASCII.
+    queries.push(format!(
+        "INSERT INTO t1 VALUES {}",
+        vec!["(1, 'SHOW PLUGINS')"; 1000].join(", ")
+    ));
+    queries.push("ſET @@x = 1".to_string());
+    queries.push("SELECT 'ĸ'".to_string());
+
+    // The bucketed rules match the same rule as a full scan of the set.
+    for rules in [FederatedRules::mysql()?, FederatedRules::clickhouse()?] {
+        let mut matched = vec![];
+        for query in &queries {
+            let rule = rules.check(query).map(|(rule, _)| rule);
+            let expect = FederatedHelper::block_match_rule(query, rules.rules()).map(|(r, _)| r);
+            assert_eq!(rule, expect, "{}: {query}", rules.name());
+            matched.extend(rule);
+        }
+
+        // The log has the probes of all the rules, `LOCK TABLES FOR BACKUP` is answered by `lock_tables`.
+        for (rule, _, _) in rules.rules() {
+            if *rule != "lock_tables_for_backup" {
+                assert!(matched.contains(rule), "{}: {rule}", rules.name());
+            }
+        }
+    }
+
+    // The long queries aren't probes, they skip the rules.
+    let federated = MySQLFederated::create();
+    let query = format!("SET @@x = '{}'", "x".repeat(2048));
+    assert!(FederatedRules::mysql()?.check(&query).is_some());
+    assert!(federated.check(&query)?.is_none());
+    assert!(federated.check("SET @@x = 'x'")?.is_some());
+
+    Ok(())
+}
+
+#[test]
+fn test_mysql_federated_custom_rules() -> Result<()> {
+    let rules = MySQLFederatedRules::create(&[mybi_probe_rule(), MySQLFederatedRuleConfig {
+        name: "mybi_set".to_string(),
+        pattern: r"^SET\s+mybi_mode".to_string(),
+        response: MySQLFederatedResponse::Empty,
+        columns: vec![],
+        rows: vec![],
+    }])?;
+    assert_eq!(rules.len(), 2);
+    let federated = MySQLFederated::create().with_rules(Arc::new(rules));
+
+    // A one-row constant table.
+    {
+        let (schema, block) = federated
+            .check("/* mybi */ select mybi_version()")?
+            .unwrap();
+        assert_eq!(
+            schema.field(1).data_type(),
+            &TableDataType::Number(NumberDataType::UInt64)
+        );
+        let expect = vec![
+            "+----------+----------+",
+            "| Column 0 | Column 1 |",
+            "+----------+----------+",
+            "| '1.0'    | 42       |",
+            "+----------+----------+",
+        ];
+        assert_blocks_eq(expect, &[block]);
+    }
+
+    // An empty response.
+    {
+        let (schema, block) = federated.check("SET mybi_mode = 1")?.unwrap();
+        assert_eq!(schema.num_fields(), 0);
+        assert_eq!(block.num_rows(), 0);
+    }
+
+    // The built-in rules are checked first.
+    {
+        let (schema, _) = federated.check("select @@version_comment")?.unwrap();
+        assert_eq!(schema.field(0).name(), "@@version_comment");
+        assert!(federated.check("select mybi_edition()")?.is_none());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_mysql_federated_bad_custom_rules() -> Result<()> {
+    let check = |rule: MySQLFederatedRuleConfig, expect: &str| {
+        let err = MySQLFederatedRules::create(&[rule]).err().unwrap();
+        assert_eq!(err.code(), ErrorCode::INVALID_CONFIG);
+        assert!(err.message().contains(expect), "{}", err.message());
+        assert!(err.message().contains("'mybi_probe'"), "{}", err.message());
+    };
+
+    let mut rule = mybi_probe_rule();
+    rule.pattern = "^(SELECT mybi_version(".to_string();
+    check(rule, "^(SELECT mybi_version(");
+
+    let mut rule = mybi_probe_rule();
+    rule.rows[0][1] = "-1".to_string();
+    check(
+        rule,
+        "the value '-1' of column 'build' in row 1 is not a UInt64",
+    );
+
+    let mut rule = mybi_probe_rule();
+    rule.rows[0].pop();
+    check(rule, "row 1 has 1 values, but there are 2 columns");
+
+    let mut rule = mybi_probe_rule();
+    rule.columns[1].data_type = "Decimal".to_string();
+    check(rule, "unknown type 'Decimal' of column 'build'");
+
+    let mut rule = mybi_probe_rule();
+    rule.response = MySQLFederatedResponse::Empty;
+    check(rule, "columns and rows are only for the table response");
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_mysql_federated_custom_rules_config() -> Result<()> {
+    let conf = ConfigBuilder::create()
+        .mysql_federated_rule(mybi_probe_rule())
+        .build();
+    let fixture = TestFixture::setup_with_config(&conf).await?;
+
+    let session = fixture.new_session_with_type(SessionType::Dummy).await?;
+    let federated = MySQLFederated::create_with_settings(session.get_settings())
+        .with_rules(MySQLFederatedRules::instance());
+    let (_, block) = federated.check("SELECT mybi_version()")?.unwrap();
+    assert_eq!(block.num_rows(), 1);
+
+    // system.configs lists the count of the rules.
+    let blocks = fixture
+        .execute_query("SELECT value FROM system.configs WHERE name = 'mysql_federated_rules'")
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let expect = vec![
+        "+----------+",
+        "| Column 0 |",
+        "+----------+",
+        "| '1'      |",
+        "+----------+",
+    ];
+    assert_blocks_eq(expect, &blocks);
+
+    Ok(())
+}
+
+#[test]
+fn test_mysql_federated_captures() -> Result<()> {
+    let federated = MySQLFederated::create();
+
+    // show variables like, quoted and unquoted
+    for query in [
+        "SHOW VARIABLES LIKE 'lower_case_table_names'",
+        "show session variables like \"lower_case_table_names\"",
+        "SHOW GLOBAL VARIABLES LIKE lower_case_table_names;",
+    ] {
+        let (_, block) = federated.check(query)?.unwrap();
+        let expect = vec![
+            "+--------------------------+----------+",
+            "| Column 0                 | Column 1 |",
+            "+--------------------------+----------+",
+            "| 'lower_case_table_names' | '0'      |",
+            "+--------------------------+----------+",
+        ];
+        assert_blocks_eq(expect, &[block]);
+    }
+
+    // unknown variable
+    {
+        let (_, block) = federated.check("SHOW VARIABLES LIKE 'xyz'")?.unwrap();
+        assert_eq!(block.num_rows(), 0);
+    }
+
+    // select variables with aliases
+    {
+        let query = "SELECT @@wait_timeout AS `timeout`,  @@session.tx_isolation ;";
+        let (schema, block) = federated.check(query)?.unwrap();
+        assert_eq!(schema.field(0).name(), "timeout");
+        assert_eq!(schema.field(1).name(), "@@session.tx_isolation");
+        let expect = vec![
+            "+----------+-------------------+",
+            "| Column 0 | Column 1          |",
+            "+----------+-------------------+",
+            "| 31536000 | 'REPEATABLE-READ' |",
+            "+----------+-------------------+",
+        ];
+        assert_blocks_eq(expect, &[block]);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_federated_lazy_block_rules() -> Result<()> {
+    fn name_block(name: &str) -> Option<(TableSchemaRef, DataBlock)> {
+        let schema =
+            TableSchemaRefExt::create(vec![TableField::new("name", TableDataType::String)]);
+        let block = DataBlock::new_from_columns(vec![StringType::from_data(vec![name])]);
+        Some((schema, block))
+    }
+
+    fn query_func(query: &str) -> Option<(TableSchemaRef, DataBlock)> {
+        name_block(query)
+    }
+
+    fn captures_func(
+        _query: &str,
+        captures: &FederatedCaptures,
+    ) -> Option<(TableSchemaRef, DataBlock)> {
+        name_block(captures.get("name")?)
+    }
+
+    let rules = vec![
+        (
+            FederatedHelper::regex(r"^GET\s+(?P<name>\w+)")?,
+            LazyBlockRule::Captures(captures_func),
+        ),
+        (
+            FederatedHelper::regex(r"^PING")?,
+            LazyBlockRule::from(query_func as LazyBlockFunc),
+        ),
+    ];
+
+    let (_, block) = FederatedHelper::lazy_block_match_rule("/* c */ get  Foo", &rules).unwrap();
+    assert_blocks_eq(
+        vec![
+            "+----------+",
+            "| Column 0 |",
+            "+----------+",
+            "| 'Foo'    |",
+            "+----------+",
+        ],
+        &[block],
+    );
+
+    // The function pointer rule receives the stripped query.
+    let (_, block) = FederatedHelper::lazy_block_match_rule("  ping", &rules).unwrap();
+    assert_blocks_eq(
+        vec![
+            "+----------+",
+            "| Column 0 |",
+            "+----------+",
+            "| 'ping'   |",
+            "+----------+",
+        ],
+        &[block],
+    );
+
+    assert!(FederatedHelper::lazy_block_match_rule("select 1", &rules).is_none());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_mysql_federated_session_settings() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let session = fixture.new_session_with_type(SessionType::Dummy).await?;
+    let settings = session.get_settings();
+    settings.set_setting("timezone".to_string(), "Asia/Shanghai".to_string())?;
+    settings.set_setting("max_result_rows".to_string(), "100".to_string())?;
+    settings.set_setting("max_execute_time_in_seconds".to_string(), "3".to_string())?;
+
+    let federated = MySQLFederated::create_with_settings(settings.clone());
+
+    // numeric variables are integers
+    {
+        let query = "SELECT @@session.time_zone, @@sql_select_limit, @@max_execution_time, @@max_allowed_packet";
+        let (schema, block) = federated.check(query)?.unwrap();
+        assert_eq!(
+            schema.field(1).data_type(),
+            &TableDataType::Number(NumberDataType::UInt64)
+        );
+        let expect = vec![
+            "+-----------------+----------+----------+-----------+",
+            "| Column 0        | Column 1 | Column 2 | Column 3  |",
+            "+-----------------+----------+----------+-----------+",
+            "| 'Asia/Shanghai' | 100      | 3000     | 134217728 |",
+            "+-----------------+----------+----------+-----------+",
+        ];
+        assert_blocks_eq(expect, &[block]);
+    }
+
+    // no limit
+    {
+        settings.set_setting("max_result_rows".to_string(), "0".to_string())?;
+        let (_, block) = federated.check("select @@sql_select_limit")?.unwrap();
+        let expect = vec![
+            "+----------------------+",
+            "| Column 0             |",
+            "+----------------------+",
+            "| 18446744073709551615 |",
+            "+----------------------+",
+        ];
+        assert_blocks_eq(expect, &[block]);
+    }
+
+    // like pattern with the MySQL semantics
+    {
+        let (_, block) = federated.check("SHOW VARIABLES LIKE 'MAX%'")?.unwrap();
+        let expect = vec![
+            "+----------------------+-------------+",
+            "| Column 0             | Column 1    |",
+            "+----------------------+-------------+",
+            "| 'max_allowed_packet' | '134217728' |",
+            "| 'max_execution_time' | '3000'      |",
+            "+----------------------+-------------+",
+        ];
+        assert_blocks_eq(expect, &[block]);
+
+        let (_, block) = federated
+            .check("show variables like '%time_zone'")?
+            .unwrap();
+        let expect = vec![
+            "+--------------------+-----------------+",
+            "| Column 0           | Column 1        |",
+            "+--------------------+-----------------+",
+            "| 'system_time_zone' | 'UTC'           |",
+            "| 'time_zone'        | 'Asia/Shanghai' |",
+            "+--------------------+-----------------+",
+        ];
+        assert_blocks_eq(expect, &[block]);
+
+        let (_, block) = federated.check("SHOW VARIABLES LIKE 'tx\\_%'")?.unwrap();
+        let expect = vec![
+            "+----------------+-------------------+",
+            "| Column 0       | Column 1          |",
+            "+----------------+-------------------+",
+            "| 'tx_isolation' | 'REPEATABLE-READ' |",
+            "| 'tx_read_only' | '0'               |",
+            "+----------------+-------------------+",
+        ];
+        assert_blocks_eq(expect, &[block]);
+    }
+
+    // all the variables without a pattern
+    {
+        let (_, block) = federated.check("SHOW VARIABLES")?.unwrap();
+        assert!(block.num_rows() > 20);
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_mysql_federated_set_variables() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let session = fixture.default_session();
+    let settings = session.get_settings();
+    let federated = MySQLFederated::create_with_settings(settings.clone())
+        .with_warnings(session.get_warnings());
+
+    // time_zone round-trips into the session timezone
+    {
+        assert!(federated.check("SET time_zone = '+08:00'")?.is_some());
+        let blocks = fixture
+            .execute_query("SELECT timezone()")
+            .await?
+            .try_collect::<Vec<_>>()
+            .await?;
+        let expect = vec![
+            "+-------------+",
+            "| Column 0    |",
+            "+-------------+",
+            "| 'Etc/GMT-8' |",
+            "+-------------+",
+        ];
+        assert_blocks_eq(expect, &blocks);
+    }
+
+    // multi-assignments
+    {
+        let query = "SET @@session.time_zone = 'asia/shanghai', SESSION sql_select_limit = 10, \
+                     wait_timeout := 600, autocommit = 1, max_threads = 3";
+        assert!(federated.check(query)?.is_some());
+        assert_eq!(settings.get_timezone()?, "Asia/Shanghai");
+        assert_eq!(settings.get_max_result_rows()?, 10);
+        assert_eq!(settings.get_idle_transaction_timeout_secs()?, 600);
+        assert_eq!(settings.get_max_threads()?, 3);
+
+        let (_, block) = federated
+            .check("select @@time_zone, @@sql_select_limit, @@wait_timeout")?
+            .unwrap();
+        let expect = vec![
+            "+-----------------+----------+----------+",
+            "| Column 0        | Column 1 | Column 2 |",
+            "+-----------------+----------+----------+",
+            "| 'Asia/Shanghai' | 10       | 600      |",
+            "+-----------------+----------+----------+",
+        ];
+        assert_blocks_eq(expect, &[block]);
+
+        assert!(federated.check("SET sql_select_limit = DEFAULT")?.is_some());
+        assert_eq!(settings.get_max_result_rows()?, 0);
+    }
+
+    // autocommit is tracked in the session
+    {
+        assert!(federated.check("SET autocommit = 0")?.is_some());
+        assert!(!settings.get_autocommit()?);
+        let (_, block) = federated.check("select @@autocommit")?.unwrap();
+        assert_blocks_eq(
+            vec![
+                "+----------+",
+                "| Column 0 |",
+                "+----------+",
+                "| 0        |",
+                "+----------+",
+            ],
+            &[block],
+        );
+
+        assert!(federated.check("SET autocommit = ON")?.is_some());
+        assert!(settings.get_autocommit()?);
+    }
+
+    // harmless variables are ignored with a warning
+    {
+        for query in [
+            "SET NAMES utf8mb4 COLLATE utf8mb4_general_ci",
+            "SET character_set_results = NULL",
+            "SET net_write_timeout = 600, sql_mode = 'STRICT_TRANS_TABLES,NO_ENGINE_SUBSTITUTION'",
+        ] {
+            assert!(federated.check(query)?.is_some(), "{query}");
+        }
+
+        // They are warnings of the session only, not of the node.
+        let warnings = session.get_warnings().list();
+        for message in [
+            "Ignored SET names = utf8mb4 collate utf8mb4_general_ci",
+            "Ignored SET sql_mode = STRICT_TRANS_TABLES,NO_ENGINE_SUBSTITUTION",
+        ] {
+            assert!(
+                warnings.iter().any(|w| w.message.starts_with(message)),
+                "{message} not found"
+            );
+        }
+        assert!(
+            ServerWarnings::instance()
+                .list()
+                .iter()
+                .all(|w| w.component != "mysql_federated")
+        );
+    }
+
+    // values which can't be honored
+    {
+        for query in [
+            "SET time_zone = '+05:30'",
+            "SET time_zone = 'Mars/Base'",
+            "SET tx_read_only = 1",
+            "SET sql_select_limit = -1",
+        ] {
+            assert!(federated.check(query).is_err(), "{query}");
+        }
+        assert_eq!(settings.get_timezone()?, "Asia/Shanghai");
+    }
+
+    // left to the SET statement
+    {
+        for query in [
+            "SET max_threads = 4",
+            "SET GLOBAL time_zone = '+00:00'",
+            "SET @x = 1",
+            "SET timezone = 'UTC'",
+        ] {
+            assert!(federated.check(query)?.is_none(), "{query}");
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_mysql_federated_transaction_isolation() -> Result<()> {
+    let isolation = Arc::new(MySQLTransactionIsolation::default());
+    let federated = MySQLFederated::create().with_isolation(isolation.clone());
+    let select_isolation = |federated: &MySQLFederated| -> Result<Vec<String>> {
+        let (_, block) = federated
+            .check("SELECT @@transaction_isolation, @@tx_isolation, @@session.tx_isolation")?
+            .unwrap();
+        Ok((0..block.num_columns())
+            .map(|i| block.get_by_offset(i).value.index(0).unwrap().to_string())
+            .collect())
+    };
+    assert_eq!(select_isolation(&federated)?, vec!["'REPEATABLE-READ'"; 3]);
+
+    // Both variable names read back the level of the session.
+    assert!(
+        federated
+            .check("SET SESSION TRANSACTION ISOLATION LEVEL READ COMMITTED")?
+            .is_some()
+    );
+    assert_eq!(isolation.get(), "READ-COMMITTED");
+    assert_eq!(select_isolation(&federated)?, vec!["'READ-COMMITTED'"; 3]);
+
+    // The global level is not changed.
+    let (_, block) = federated
+        .check("SELECT @@global.transaction_isolation")?
+        .unwrap();
+    assert_blocks_eq(
+        vec![
+            "+-------------------+",
+            "| Column 0          |",
+            "+-------------------+",
+            "| 'REPEATABLE-READ' |",
+            "+-------------------+",
+        ],
+        &[block],
+    );
+
+    // The probe of Connector/J after the connection.
+    let (schema, block) = federated
+        .check("SELECT @@session.auto_increment_increment AS auto_increment_increment, @@transaction_isolation AS transaction_isolation, @@wait_timeout AS wait_timeout")?
+        .unwrap();
+    let i = schema.index_of("transaction_isolation")?;
+    assert_eq!(
+        block.get_by_offset(i).value.index(0).unwrap().to_string(),
+        "'READ-COMMITTED'"
+    );
+
+    let (_, block) = federated
+        .check("SHOW VARIABLES LIKE '%isolation'")?
+        .unwrap();
+    assert_blocks_eq(
+        vec![
+            "+-------------------------+------------------+",
+            "| Column 0                | Column 1         |",
+            "+-------------------------+------------------+",
+            "| 'transaction_isolation' | 'READ-COMMITTED' |",
+            "| 'tx_isolation'          | 'READ-COMMITTED' |",
+            "+-------------------------+------------------+",
+        ],
+        &[block],
+    );
+
+    // The SET of the variables, the 8.0 and 5.7 names.
+    for (query, level) in [
+        ("SET tx_isolation = 'SERIALIZABLE'", "SERIALIZABLE"),
+        (
+            "SET @@session.transaction_isolation = 'read-uncommitted'",
+            "READ-UNCOMMITTED",
+        ),
+        (
+            "SET SESSION TRANSACTION ISOLATION LEVEL REPEATABLE READ",
+            "REPEATABLE-READ",
+        ),
+        ("SET SESSION TRANSACTION READ WRITE", "REPEATABLE-READ"),
+        ("SET transaction_isolation = DEFAULT", "REPEATABLE-READ"),
+        (
+            "SET SESSION TRANSACTION ISOLATION LEVEL READ COMMITTED, READ WRITE",
+            "READ-COMMITTED",
+        ),
+    ] {
+        assert!(federated.check(query)?.is_some(), "{query}");
+        assert_eq!(isolation.get(), level, "{query}");
+    }
+
+    // Without the scope the level is only for the next transaction, like MySQL.
+    assert!(
+        federated
+            .check("SET TRANSACTION ISOLATION LEVEL SERIALIZABLE")?
+            .is_some()
+    );
+    assert_eq!(isolation.get(), "READ-COMMITTED");
+
+    // READ ONLY is not supported, the same as `SET transaction_read_only = 1`.
+    for query in [
+        "SET SESSION TRANSACTION READ ONLY",
+        "SET TRANSACTION READ ONLY",
+        "SET SESSION TRANSACTION ISOLATION LEVEL SERIALIZABLE, READ ONLY",
+        "SET transaction_read_only = 1",
+    ] {
+        let err = federated.check(query).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::UNIMPLEMENTED, "{query}");
+    }
+    assert_eq!(select_isolation(&federated)?, vec!["'READ-COMMITTED'"; 3]);
+
+    for query in [
+        "SET SESSION TRANSACTION ISOLATION LEVEL SNAPSHOT",
+        "SET tx_isolation = 'READ COMMITED'",
+    ] {
+        let err = federated.check(query).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::WRONG_VALUE_FOR_VARIABLE, "{query}");
+    }
+    assert_eq!(isolation.get(), "READ-COMMITTED");
+
+    Ok(())
+}
+
+#[test]
+fn test_mysql_federated_use_database() -> Result<()> {
+    let federated = MySQLFederated::create();
+    let cases = [
+        ("USE db1", Some("db1")),
+        ("use DB1;", Some("db1")),
+        ("/* client */ USE  Db1 ; ", Some("db1")),
+        ("USE `my-db`", Some("my-db")),
+        ("USE `My-Db`;", Some("My-Db")),
+        ("USE `a``b`", Some("a`b")),
+        ("USE\t`x`", Some("x")),
+        ("USE my-db", Some("my-db")),
+        ("USE `a` b", None),
+        ("USE a b", None),
+        ("USER", None),
+        ("USE", None),
+        ("SELECT 1", None),
+    ];
+    for (query, expected) in cases {
+        assert_eq!(
+            federated.use_database(query)?.as_deref(),
+            expected,
+            "{query}"
+        );
+    }
+
+    // COM_INIT_DB sends the name only.
+    assert_eq!(
+        federated.database_name("`My-Db`")?.as_deref(),
+        Some("My-Db")
+    );
+    assert_eq!(federated.database_name("My_Db")?.as_deref(), Some("my_db"));
+    assert_eq!(federated.database_name("my db")?, None);
+
+    Ok(())
+}
+
+#[test]
+fn test_mysql_federated_connector_j_8() -> Result<()> {
+    let federated = MySQLFederated::create();
+
+    // The handshake of Connector/J 8.0.33, the revision in the comment is elided.
+    {
+        let query = "/* mysql-connector-j-8.0.33 (Revision: ...) */SELECT  @@session.auto_increment_increment AS auto_increment_increment, @@character_set_client AS character_set_client, @@character_set_connection AS character_set_connection, @@character_set_results AS character_set_results, @@character_set_server AS character_set_server, @@collation_server AS collation_server, @@collation_connection AS collation_connection, @@init_connect AS init_connect, @@interactive_timeout AS interactive_timeout, @@license AS license, @@lower_case_table_names AS lower_case_table_names, @@max_allowed_packet AS max_allowed_packet, @@net_write_timeout AS net_write_timeout, @@performance_schema AS performance_schema, @@query_cache_size AS query_cache_size, @@query_cache_type AS query_cache_type, @@sql_mode AS sql_mode, @@system_time_zone AS system_time_zone, @@time_zone AS time_zone, @@transaction_isolation AS transaction_isolation, @@wait_timeout AS wait_timeout";
+        let (schema, block) = federated.check(query)?.unwrap();
+        assert_eq!(block.num_rows(), 1);
+
+        let expect = [
+            ("auto_increment_increment", "1"),
+            ("character_set_client", "'utf8mb4'"),
+            ("character_set_connection", "'utf8mb4'"),
+            ("character_set_results", "'utf8mb4'"),
+            ("character_set_server", "'utf8mb4'"),
+            ("collation_server", "'utf8mb4_general_ci'"),
+            ("collation_connection", "'utf8mb4_general_ci'"),
+            ("init_connect", "''"),
+            ("interactive_timeout", "31536000"),
+            ("license", "'Apache-2.0'"),
+            ("lower_case_table_names", "0"),
+            ("max_allowed_packet", "134217728"),
+            ("net_write_timeout", "31536000"),
+            ("performance_schema", "0"),
+            ("query_cache_size", "0"),
+            ("query_cache_type", "'OFF'"),
+            (
+                "sql_mode",
+                "'ONLY_FULL_GROUP_BY STRICT_TRANS_TABLES NO_ZERO_IN_DATE NO_ZERO_DATE ERROR_FOR_DIVISION_BY_ZERO NO_ENGINE_SUBSTITUTION'",
+            ),
+            ("system_time_zone", "'UTC'"),
+            ("time_zone", "'UTC'"),
+            ("transaction_isolation", "'REPEATABLE-READ'"),
+            ("wait_timeout", "31536000"),
+        ];
+        assert_eq!(schema.num_fields(), expect.len());
+        for (i, (name, value)) in expect.iter().enumerate() {
+            assert_eq!(schema.field(i).name(), name);
+            let actual = block.get_by_offset(i).value.index(0).unwrap();
+            assert_eq!(&actual.to_string(), value, "{name}");
+        }
+        assert_eq!(
+            schema.field_with_name("interactive_timeout")?.data_type(),
+            &TableDataType::Number(NumberDataType::UInt64)
+        );
+    }
+
+    // The queries after the handshake.
+    {
+        for query in [
+            "SET NAMES utf8mb4",
+            "SET character_set_results = NULL",
+            "SET autocommit=1",
+            "SHOW WARNINGS",
+        ] {
+            assert!(federated.check(query)?.is_some(), "{query}");
+        }
+
+        let (schema, block) = federated
+            .check("SELECT @@session.transaction_read_only, @@global.transaction_isolation iso")?
+            .unwrap();
+        assert_eq!(schema.field(0).name(), "@@session.transaction_read_only");
+        assert_eq!(schema.field(1).name(), "iso");
+        let expect = vec![
+            "+----------+-------------------+",
+            "| Column 0 | Column 1          |",
+            "+----------+-------------------+",
+            "| 0        | 'REPEATABLE-READ' |",
+            "+----------+-------------------+",
+        ];
+        assert_blocks_eq(expect, &[block]);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_mysql_federated_long_query() -> Result<()> {
+    let federated = MySQLFederated::create();
+
+    // A probe longer than federated_max_query_bytes is still answered.
+    {
+        let columns = (0..64)
+            .map(|i| format!("@@max_allowed_packet AS max_allowed_packet_{i}"))
+            .collect::<Vec<_>>();
+        let query = format!("SELECT {}", columns.join(", "));
+        assert!(query.len() > 1024);
+        let (schema, block) = federated.check(&query)?.unwrap();
+        assert_eq!(schema.num_fields(), 64);
+        assert_eq!(schema.field(63).name(), "max_allowed_packet_63");
+        assert_eq!(block.num_rows(), 1);
+    }
+
+    // A long query which is not a probe is not matched.
+    {
+        let values = vec!["(1)"; 512];
+        let query = format!("INSERT INTO t VALUES {}", values.join(", "));
+        assert!(federated.check(&query)?.is_none());
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_mysql_federated_mysqldump() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    fixture.create_default_table().await?;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+
+    let federated = MySQLFederated::create_with_settings(fixture.default_session().get_settings());
+
+    // The statements of `mysqldump --single-transaction` 8.0 dumping one table,
+    // those not answered by the federated rules run as the normal queries.
+    let statements = [
+        "/*!40101 SET @OLD_CHARACTER_SET_CLIENT=@@CHARACTER_SET_CLIENT */".to_string(),
+        "/*!40103 SET @OLD_TIME_ZONE=@@TIME_ZONE */".to_string(),
+        "/*!40100 SET @@SQL_MODE='' */".to_string(),
+        "/*!40103 SET TIME_ZONE='+00:00' */".to_string(),
+        "/*!80000 SET SESSION information_schema_stats_expiry=0 */".to_string(),
+        "SET SESSION NET_READ_TIMEOUT= 86400, SESSION NET_WRITE_TIMEOUT= 86400".to_string(),
+        "SET SESSION TRANSACTION ISOLATION LEVEL REPEATABLE READ".to_string(),
+        "START TRANSACTION /*!40100 WITH CONSISTENT SNAPSHOT */".to_string(),
+        "SHOW VARIABLES LIKE 'gtid\\_mode'".to_string(),
+        "SELECT LOGFILE_GROUP_NAME, FILE_NAME, TOTAL_EXTENTS, INITIAL_SIZE, ENGINE, EXTRA FROM INFORMATION_SCHEMA.FILES WHERE ENGINE = 'ndbcluster' AND FILE_TYPE = 'UNDO LOG' AND FILE_NAME IS NOT NULL AND LOGFILE_GROUP_NAME IS NOT NULL GROUP BY LOGFILE_GROUP_NAME, FILE_NAME, ENGINE, TOTAL_EXTENTS, INITIAL_SIZE ORDER BY LOGFILE_GROUP_NAME".to_string(),
+        "SELECT DISTINCT TABLESPACE_NAME, FILE_NAME, LOGFILE_GROUP_NAME, EXTENT_SIZE, INITIAL_SIZE, ENGINE FROM INFORMATION_SCHEMA.FILES WHERE FILE_TYPE = 'DATAFILE' ORDER BY TABLESPACE_NAME, LOGFILE_GROUP_NAME".to_string(),
+        format!("USE `{db}`"),
+        "select @@collation_database".to_string(),
+        format!("LOCK TABLES `{tbl}` READ /*!32311 LOCAL */"),
+        format!("SHOW TABLES LIKE '{tbl}'"),
+        format!("show table status like '{tbl}'"),
+        "SET SQL_QUOTE_SHOW_CREATE=1".to_string(),
+        "SET SESSION character_set_results = 'binary'".to_string(),
+        format!("show create table `{db}`.`{tbl}`"),
+        "SET SESSION character_set_results = 'utf8mb4'".to_string(),
+        format!("show fields from `{db}`.`{tbl}`"),
+        format!("SELECT /*!40001 SQL_NO_CACHE */ * FROM `{db}`.`{tbl}`"),
+        format!("SHOW TRIGGERS LIKE '{tbl}'"),
+        format!("SELECT COLUMN_NAME,                       JSON_EXTRACT(HISTOGRAM, '$.\"number-of-buckets-specified\"')                FROM information_schema.COLUMN_STATISTICS                WHERE SCHEMA_NAME = '{db}' AND TABLE_NAME = '{tbl}';"),
+        "UNLOCK TABLES".to_string(),
+        "/*!40103 SET TIME_ZONE=@OLD_TIME_ZONE */".to_string(),
+        "/*!40101 SET CHARACTER_SET_CLIENT=@OLD_CHARACTER_SET_CLIENT */".to_string(),
+    ];
+
+    // The version comments are inlined as the MySQL handler does.
+    for statement in statements.iter() {
+        let statement = strip_mysql_comments(statement, 80026);
+        match federated.check(&statement)? {
+            Some((schema, block)) => assert_eq!(schema.num_fields(), block.num_columns()),
+            None => {
+                fixture
+                    .execute_query(&statement)
+                    .await?
+                    .try_collect::<Vec<_>>()
+                    .await?;
+            }
+        }
+    }
+
+    // The probes of what Databend doesn't have are empty with the MySQL columns.
+    let (schema, block) = federated
+        .check(&format!("SHOW TRIGGERS LIKE '{tbl}'"))?
+        .unwrap();
+    assert_eq!(block.num_rows(), 0);
+    assert_eq!(schema.num_fields(), 11);
+    assert_eq!(schema.field(0).name(), "Trigger");
+
+    // SHOW TABLE STATUS is the real table metadata.
+    assert!(
+        federated
+            .check(&format!("SHOW TABLE STATUS LIKE '{tbl}'"))?
+            .is_none()
+    );
+    let blocks = fixture
+        .execute_query(&format!("SHOW TABLE STATUS FROM `{db}` LIKE '{tbl}'"))
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(blocks.iter().map(|b| b.num_rows()).sum::<usize>(), 1);
+
     Ok(())
 }
@@ -18,6 +18,9 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use databend_common_base::base::tokio;
+use databend_common_base::base::tokio::io::AsyncReadExt;
+use databend_common_base::base::tokio::io::AsyncWriteExt;
+use databend_common_base::base::tokio::net::TcpStream;
 use databend_common_base::runtime::Runtime;
 use databend_common_base::runtime::TrySpawn;
 use databend_common_exception::ErrorCode;
@@ -27,6 +30,8 @@ use databend_query::servers::MySQLHandler;
 use databend_query::servers::MySQLTlsConfig;
 use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestFixture;
+use mysql_async::consts::ColumnFlags;
+use mysql_async::consts::ColumnType;
 use mysql_async::prelude::FromRow;
 use mysql_async::prelude::Queryable;
 use mysql_async::FromRowError;
@@ -188,6 +193,901 @@ async fn test_rejected_session_with_parallel() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_show_processlist_and_kill() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs, MySQLTlsConfig::default())?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+
+    let mut conn = create_connection(listening.port(), false).await?;
+    let conn_id = conn.id();
+    let long_query = tokio::spawn(async move {
+        conn.query_drop("SELECT max(number) FROM numbers(100000000000)")
+            .await
+    });
+
+    let mut killer = create_connection(listening.port(), false).await?;
+
+    // Wait for the long query to show up with the numeric id of its connection.
+    let mut running = false;
+    for _ in 0..50 {
+        let rows: Vec<Row> = killer.query("SHOW FULL PROCESSLIST").await.unwrap();
+        let columns = rows[0]
+            .columns_ref()
+            .iter()
+            .map(|c| c.name_str().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(columns, [
+            "Id", "User", "Host", "db", "Command", "Time", "State", "Info"
+        ]);
+
+        running = rows.iter().any(|row| {
+            row.get::<Option<u32>, _>("Id").flatten() == Some(conn_id)
+                && row.get::<String, _>("Command").as_deref() == Some("Query")
+        });
+        if running {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    assert!(running);
+
+    killer
+        .query_drop(format!("KILL QUERY {conn_id}"))
+        .await
+        .unwrap();
+    let result = tokio::time::timeout(Duration::from_secs(30), long_query)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(result.is_err());
+
+    // A user without the SUPER privilege can only kill their own sessions.
+    killer
+        .query_drop("CREATE USER 'kill_user' IDENTIFIED BY 'kill_password'")
+        .await
+        .unwrap();
+    let mut user_conn = create_connection_with_user(
+        listening.port(),
+        "kill_user",
+        Some("kill_password".to_string()),
+    )
+    .await?;
+    let user_conn_id = user_conn.id();
+    assert!(
+        user_conn
+            .query_drop(format!("KILL QUERY {user_conn_id}"))
+            .await
+            .is_ok()
+    );
+    assert!(
+        user_conn
+            .query_drop(format!("KILL QUERY {}", killer.id()))
+            .await
+            .is_err()
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_transaction_statements() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs, MySQLTlsConfig::default())?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let mut conn = create_connection(listening.port(), false).await?;
+
+    conn.query_drop("CREATE TABLE t_txn(a INT)").await.unwrap();
+
+    // SQLAlchemy on PyMySQL: autocommit off at connect, ROLLBACK when the
+    // connection returns to the pool, COMMIT after the writes.
+    conn.query_drop("SET autocommit=0").await.unwrap();
+    let autocommit: Option<u64> = conn.query_first("SELECT @@autocommit").await.unwrap();
+    assert_eq!(autocommit, Some(0));
+    conn.query_drop("SELECT 1").await.unwrap();
+    conn.query_drop("ROLLBACK").await.unwrap();
+
+    conn.query_drop("INSERT INTO t_txn VALUES (1)")
+        .await
+        .unwrap();
+    conn.query_drop("COMMIT").await.unwrap();
+    conn.query_drop("INSERT INTO t_txn VALUES (2)")
+        .await
+        .unwrap();
+    conn.query_drop("ROLLBACK").await.unwrap();
+
+    let count: Option<u64> = conn
+        .query_first("SELECT count(*) FROM t_txn")
+        .await
+        .unwrap();
+    assert_eq!(count, Some(1));
+    conn.query_drop("COMMIT").await.unwrap();
+
+    conn.query_drop("SET autocommit=1").await.unwrap();
+    let autocommit: Option<u64> = conn.query_first("SELECT @@autocommit").await.unwrap();
+    assert_eq!(autocommit, Some(1));
+
+    // A DDL commits the transaction implicitly, unless in the strict mode.
+    conn.query_drop("SET strict_transaction_emulation = 1")
+        .await
+        .unwrap();
+    conn.query_drop("START TRANSACTION").await.unwrap();
+    conn.query_drop("INSERT INTO t_txn VALUES (3)")
+        .await
+        .unwrap();
+    let result = conn.query_drop("CREATE TABLE t_txn2(a INT)").await;
+    assert!(result.is_err());
+    conn.query_drop("ROLLBACK").await.unwrap();
+
+    let count: Option<u64> = conn
+        .query_first("SELECT count(*) FROM t_txn")
+        .await
+        .unwrap();
+    assert_eq!(count, Some(1));
+
+    // A write to a non-fuse table is not undone by ROLLBACK, so it is rejected
+    // in the strict mode, and runs with a warning otherwise.
+    conn.query_drop("CREATE TABLE t_txn_mem(a INT) ENGINE = Memory")
+        .await
+        .unwrap();
+    conn.query_drop("START TRANSACTION").await.unwrap();
+    let result = conn.query_drop("INSERT INTO t_txn_mem VALUES (1)").await;
+    assert!(result.is_err());
+    conn.query_drop("ROLLBACK").await.unwrap();
+
+    conn.query_drop("SET strict_transaction_emulation = 0")
+        .await
+        .unwrap();
+    conn.query_drop("START TRANSACTION").await.unwrap();
+    conn.query_drop("INSERT INTO t_txn_mem VALUES (1)")
+        .await
+        .unwrap();
+    conn.query_drop("ROLLBACK").await.unwrap();
+
+    let count: Option<u64> = conn
+        .query_first("SELECT count(*) FROM t_txn_mem")
+        .await
+        .unwrap();
+    assert_eq!(count, Some(1));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_show_index() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs, MySQLTlsConfig::default())?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let mut conn = create_connection(listening.port(), false).await?;
+
+    conn.query_drop("CREATE TABLE t_keys(a INT, b INT) CLUSTER BY (a)")
+        .await
+        .unwrap();
+    let rows: Vec<Row> = conn.query("SHOW INDEX FROM default.t_keys").await.unwrap();
+    assert_eq!(rows.len(), 1);
+    let columns = rows[0]
+        .columns_ref()
+        .iter()
+        .map(|c| c.name_str().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(columns, [
+        "Table",
+        "Non_unique",
+        "Key_name",
+        "Seq_in_index",
+        "Column_name",
+        "Collation",
+        "Cardinality",
+        "Sub_part",
+        "Packed",
+        "Null",
+        "Index_type",
+        "Comment",
+        "Index_comment",
+        "Visible",
+        "Expression"
+    ]);
+    assert_eq!(rows[0].get::<String, _>("Column_name").unwrap(), "a");
+
+    // ER_NO_SUCH_TABLE
+    match conn
+        .query_drop("SHOW INDEX FROM default.t_not_exists")
+        .await
+    {
+        Err(mysql_async::Error::Server(error)) => assert_eq!(error.code, 1146),
+        other => panic!("Expected the unknown table error, got {:?}", other),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_show_table_status() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs, MySQLTlsConfig::default())?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let mut conn = create_connection(listening.port(), false).await?;
+
+    conn.query_drop("CREATE DATABASE db_status").await.unwrap();
+    conn.query_drop("CREATE TABLE db_status.t_status(a INT, b STRING) COMMENT = 'sized'")
+        .await
+        .unwrap();
+    conn.query_drop("CREATE TABLE db_status.t_other(a INT)")
+        .await
+        .unwrap();
+    conn.query_drop("INSERT INTO db_status.t_status VALUES (1, 'a'), (2, 'bb'), (3, 'ccc')")
+        .await
+        .unwrap();
+    conn.query_drop("INSERT INTO db_status.t_status VALUES (4, 'dddd')")
+        .await
+        .unwrap();
+
+    let (row_count, bytes, index_size): (u64, u64, u64) = conn
+        .query_first(
+            "SELECT row_count, bytes_uncompressed, index_size FROM fuse_snapshot('db_status', 't_status') \
+             ORDER BY row_count DESC LIMIT 1",
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(row_count, 4);
+
+    // The LIKE pattern and the FROM database.
+    let rows: Vec<Row> = conn
+        .query("SHOW TABLE STATUS FROM db_status LIKE 't_s%'")
+        .await
+        .unwrap();
+    assert_eq!(rows.len(), 1);
+    let row = &rows[0];
+    assert_eq!(row.get::<String, _>("Name").unwrap(), "t_status");
+    assert_eq!(row.get::<String, _>("Engine").unwrap(), "FUSE");
+    assert_eq!(row.get::<Option<u64>, _>("Rows").unwrap(), Some(row_count));
+    assert_eq!(
+        row.get::<Option<u64>, _>("Data_length").unwrap(),
+        Some(bytes)
+    );
+    assert_eq!(
+        row.get::<Option<u64>, _>("Avg_row_length").unwrap(),
+        Some(bytes / row_count)
+    );
+    assert_eq!(
+        row.get::<Option<u64>, _>("Index_length").unwrap(),
+        Some(index_size)
+    );
+    assert!(
+        row.get::<Option<String>, _>("Create_time")
+            .unwrap()
+            .is_some()
+    );
+    assert!(
+        row.get::<Option<String>, _>("Update_time")
+            .unwrap()
+            .is_some()
+    );
+    assert_eq!(
+        row.get::<Option<String>, _>("Collation")
+            .unwrap()
+            .as_deref(),
+        Some("utf8mb4_bin")
+    );
+    assert_eq!(row.get::<String, _>("Comment").unwrap(), "sized");
+
+    // An empty table.
+    conn.query_drop("USE db_status").await.unwrap();
+    let row: Row = conn
+        .query_first("SHOW TABLE STATUS LIKE 't_other'")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(row.get::<Option<u64>, _>("Rows").unwrap(), Some(0));
+    assert_eq!(
+        row.get::<Option<u64>, _>("Avg_row_length").unwrap(),
+        Some(0)
+    );
+
+    conn.query_drop("DROP DATABASE db_status").await.unwrap();
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_odbc_connect_replay() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs, MySQLTlsConfig::default())?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let mut conn = create_connection(listening.port(), false).await?;
+
+    // The statements of MySQL Connector/ODBC 8.0 connecting from Excel.
+    for query in [
+        "SET NAMES utf8mb4",
+        "SET character_set_results = NULL",
+        "SET SQL_AUTO_IS_NULL = 0",
+        "select database()",
+        "SELECT @@tx_isolation",
+        "SHOW SESSION STATUS LIKE 'Ssl_cipher'",
+        "SELECT @@max_allowed_packet",
+        "SELECT @@global.lower_case_table_names",
+        "SELECT @@net_read_timeout, @@net_write_timeout",
+        "SHOW GLOBAL STATUS LIKE 'Uptime'",
+    ] {
+        conn.query_drop(query)
+            .await
+            .unwrap_or_else(|e| panic!("{query}: {e}"));
+    }
+
+    // The live session values.
+    let row: Row = conn
+        .query_first("SELECT DATABASE(), USER(), CURRENT_USER(), CONNECTION_ID()")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(row.get::<String, _>(0).unwrap(), "default");
+    assert!(row.get::<String, _>(1).unwrap().contains("root"));
+    assert!(row.get::<String, _>(2).unwrap().contains("root"));
+    assert_eq!(row.get::<u64, _>(3).unwrap(), conn.id() as u64);
+
+    conn.query_drop("USE system").await.unwrap();
+    let database: Option<String> = conn.query_first("SELECT DATABASE()").await.unwrap();
+    assert_eq!(database.as_deref(), Some("system"));
+
+    // CONNECTION_ID() works inside of a larger query.
+    let id: Option<u64> = conn
+        .query_first("SELECT CONNECTION_ID() + 0 FROM numbers(1)")
+        .await
+        .unwrap();
+    assert_eq!(id, Some(conn.id() as u64));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_datadog_replay() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs, MySQLTlsConfig::default())?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let mut conn = create_connection(listening.port(), false).await?;
+
+    // The statements of the Datadog MySQL check.
+    for query in [
+        "SHOW /*!50002 GLOBAL */ STATUS",
+        "SELECT engine FROM information_schema.ENGINES",
+        "SELECT * FROM information_schema.PROCESSLIST",
+        "SELECT COUNT(*) FROM `INFORMATION_SCHEMA`.`PROCESSLIST`",
+    ] {
+        conn.query_drop(query)
+            .await
+            .unwrap_or_else(|e| panic!("{query}: {e}"));
+    }
+
+    // The session of the check is in the process list.
+    let query = format!(
+        "SELECT command FROM information_schema.processlist WHERE id = {}",
+        conn.id()
+    );
+    let commands: Vec<String> = conn.query(query).await.unwrap();
+    assert_eq!(commands, vec!["Query".to_string()]);
+
+    let status: Vec<(String, String)> = conn
+        .query("SELECT VARIABLE_NAME, VARIABLE_VALUE FROM INFORMATION_SCHEMA.GLOBAL_STATUS")
+        .await
+        .unwrap();
+    let names = status
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["Queries", "Threads_connected", "Uptime"]);
+    for (name, value) in &status {
+        assert!(value.parse::<u64>().is_ok(), "{name}: {value}");
+    }
+
+    let support: Option<String> = conn
+        .query_first("SELECT support FROM information_schema.engines WHERE engine = 'FUSE'")
+        .await
+        .unwrap();
+    assert_eq!(support.as_deref(), Some("DEFAULT"));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_show_warnings() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs, MySQLTlsConfig::default())?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let mut conn = create_connection(listening.port(), false).await?;
+
+    conn.query_drop("SET sql_auto_is_null = 0").await.unwrap();
+    assert_eq!(conn.get_warnings(), 1);
+
+    // SHOW WARNINGS doesn't clear the warnings.
+    for _ in 0..2 {
+        let warnings: Vec<(String, u64, String)> = conn.query("SHOW WARNINGS").await.unwrap();
+        assert_eq!(warnings, vec![(
+            "Warning".to_string(),
+            1193,
+            "Ignored SET sql_auto_is_null = 0".to_string()
+        )]);
+    }
+
+    let count: Option<u64> = conn.query_first("SHOW COUNT(*) WARNINGS").await.unwrap();
+    assert_eq!(count, Some(1));
+    let errors: Vec<(String, u64, String)> = conn.query("SHOW ERRORS").await.unwrap();
+    assert!(errors.is_empty());
+    let warnings: Vec<(String, u64, String)> =
+        conn.query("SHOW WARNINGS LIMIT 1, 1").await.unwrap();
+    assert!(warnings.is_empty());
+
+    // The next statement clears them.
+    conn.query_drop("SELECT 1").await.unwrap();
+    let warnings: Vec<(String, u64, String)> = conn.query("SHOW WARNINGS").await.unwrap();
+    assert!(warnings.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_server_version() -> Result<()> {
+    let conf = ConfigBuilder::create()
+        .mysql_handler_server_version("8.0.31-databend-test")
+        .build();
+    let _fixture = TestFixture::setup_with_config(&conf).await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs, MySQLTlsConfig::default())?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let mut conn = create_connection(listening.port(), false).await?;
+
+    // The handshake, VERSION(), @@version and @@version_comment agree.
+    assert_eq!(conn.server_version(), (8, 0, 31));
+    for query in [
+        "SELECT VERSION()",
+        "SELECT @@version",
+        "SELECT @@version_comment",
+    ] {
+        let version: Option<String> = conn.query_first(query).await.unwrap();
+        assert_eq!(version.as_deref(), Some("8.0.31-databend-test"), "{query}");
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_version_comments() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs, MySQLTlsConfig::default())?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let mut conn = create_connection(listening.port(), false).await?;
+
+    // The header and footer of a mysqldump file.
+    conn.query_drop("/*!40103 SET @OLD_TIME_ZONE=@@TIME_ZONE */")
+        .await
+        .unwrap();
+    conn.query_drop("/*!40103 SET TIME_ZONE='+08:00' */")
+        .await
+        .unwrap();
+    let time_zone: Option<String> = conn.query_first("SELECT @@time_zone").await.unwrap();
+    assert_eq!(time_zone.as_deref(), Some("Etc/GMT-8"));
+    conn.query_drop("/*!40103 SET TIME_ZONE=@OLD_TIME_ZONE */")
+        .await
+        .unwrap();
+
+    // The versions after the server version are dropped.
+    conn.query_drop("/*!90000 SET TIME_ZONE='+01:00' */")
+        .await
+        .unwrap();
+    let time_zone: Option<String> = conn.query_first("SELECT @@time_zone").await.unwrap();
+    assert_eq!(time_zone.as_deref(), Some("Etc/GMT-8"));
+
+    let sum: Option<u64> = conn
+        .query_first("SELECT 1 /*!40101 + 2 */ /*!90000 + 4 */")
+        .await
+        .unwrap();
+    assert_eq!(sum, Some(3));
+
+    // The hints and the literals.
+    let text: Option<String> = conn
+        .query_first("SELECT /*+ MAX_EXECUTION_TIME(1000) */ '/*!40101 x */'")
+        .await
+        .unwrap();
+    assert_eq!(text.as_deref(), Some("/*!40101 x */"));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_federated_metrics() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs, MySQLTlsConfig::default())?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let mut conn = create_connection(listening.port(), false).await?;
+
+    // The counters are global, compare them before and after.
+    let matched_labels = r#"{"rule":"show_plugins"}"#;
+    let matched = metric_value(&mut conn, "federated_rule_matched_total", matched_labels).await?;
+    let missed = metric_value(&mut conn, "federated_miss_candidate_total", "{}").await?;
+
+    conn.query_drop("/* ApplicationName=DBeaver */ SHOW PLUGINS")
+        .await
+        .unwrap();
+    let result = conn
+        .query_drop("SELECT * FROM federated_metrics_no_such_table")
+        .await;
+    assert!(result.is_err());
+
+    let value = metric_value(&mut conn, "federated_rule_matched_total", matched_labels).await?;
+    assert!(value > matched, "{value} <= {matched}");
+    let value = metric_value(&mut conn, "federated_miss_candidate_total", "{}").await?;
+    assert!(value > missed, "{value} <= {missed}");
+
+    Ok(())
+}
+
+async fn metric_value(conn: &mut mysql_async::Conn, metric: &str, labels: &str) -> Result<f64> {
+    let query = format!(
+        "SELECT value FROM system.metrics WHERE metric = '{metric}' AND labels = '{labels}'"
+    );
+    let value: Option<String> = conn.query_first(query).await.unwrap();
+    Ok(value.map_or(0.0, |v| v.parse().unwrap()))
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_column_definitions() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs, MySQLTlsConfig::default())?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let mut conn = create_connection(listening.port(), false).await?;
+
+    // The column definition packets of the federated and the normal queries.
+    for (query, expects) in [
+        ("SELECT @@interactive_timeout, @@version", vec![
+            (
+                ColumnType::MYSQL_TYPE_LONGLONG,
+                ColumnFlags::NOT_NULL_FLAG
+                    | ColumnFlags::UNSIGNED_FLAG
+                    | ColumnFlags::BINARY_FLAG
+                    | ColumnFlags::NUM_FLAG,
+            ),
+            (ColumnType::MYSQL_TYPE_VARCHAR, ColumnFlags::NOT_NULL_FLAG),
+        ]),
+        ("SHOW VARIABLES LIKE 'wait_timeout'", vec![
+            (ColumnType::MYSQL_TYPE_VARCHAR, ColumnFlags::NOT_NULL_FLAG),
+            (ColumnType::MYSQL_TYPE_VARCHAR, ColumnFlags::NOT_NULL_FLAG),
+        ]),
+        (
+            "SELECT 1::UINT8 AS a, CAST(NULL AS INT32) AS b, 'c' AS c, 1.5::DOUBLE AS d",
+            vec![
+                (
+                    ColumnType::MYSQL_TYPE_TINY,
+                    ColumnFlags::NOT_NULL_FLAG
+                        | ColumnFlags::UNSIGNED_FLAG
+                        | ColumnFlags::BINARY_FLAG
+                        | ColumnFlags::NUM_FLAG,
+                ),
+                (
+                    ColumnType::MYSQL_TYPE_LONG,
+                    ColumnFlags::BINARY_FLAG | ColumnFlags::NUM_FLAG,
+                ),
+                (ColumnType::MYSQL_TYPE_VARCHAR, ColumnFlags::NOT_NULL_FLAG),
+                (
+                    ColumnType::MYSQL_TYPE_DOUBLE,
+                    ColumnFlags::NOT_NULL_FLAG | ColumnFlags::BINARY_FLAG | ColumnFlags::NUM_FLAG,
+                ),
+            ],
+        ),
+    ] {
+        let result = conn.query_iter(query).await.unwrap();
+        let columns = result
+            .columns_ref()
+            .iter()
+            .map(|c| (c.column_type(), c.flags()))
+            .collect::<Vec<_>>();
+        assert_eq!(columns, expects, "{query}");
+        result.drop_result().await.unwrap();
+    }
+
+    // Connector/J reads the unsigned variables as the numbers.
+    let timeout: Option<u64> = conn
+        .query_first("SELECT @@interactive_timeout")
+        .await
+        .unwrap();
+    assert!(timeout.is_some());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_reset_connection() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs, MySQLTlsConfig::default())?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let mut conn = create_connection(listening.port(), false).await?;
+
+    conn.query_drop("SET max_threads = 3").await.unwrap();
+    conn.query_drop("USE system").await.unwrap();
+    conn.query_drop("SET sql_auto_is_null = 0").await.unwrap();
+    conn.query_drop("SET SESSION TRANSACTION ISOLATION LEVEL SERIALIZABLE")
+        .await
+        .unwrap();
+
+    conn.reset().await.unwrap();
+
+    // The session is back to the state after connecting, the user is kept.
+    let level: Option<String> = conn
+        .query_first("SELECT level FROM system.settings WHERE name = 'max_threads'")
+        .await
+        .unwrap();
+    assert_eq!(level.as_deref(), Some("DEFAULT"));
+    let database: Option<String> = conn.query_first("SELECT DATABASE()").await.unwrap();
+    assert_eq!(database.as_deref(), Some("default"));
+    let warnings: Vec<(String, u64, String)> = conn.query("SHOW WARNINGS").await.unwrap();
+    assert!(warnings.is_empty());
+    let isolation: Option<String> = conn
+        .query_first("SELECT @@transaction_isolation")
+        .await
+        .unwrap();
+    assert_eq!(isolation.as_deref(), Some("REPEATABLE-READ"));
+    let user: Option<String> = conn.query_first("SELECT USER()").await.unwrap();
+    assert!(user.unwrap().contains("root"));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_use_database() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs, MySQLTlsConfig::default())?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let mut conn = create_connection(listening.port(), false).await?;
+
+    conn.query_drop("CREATE DATABASE `use-db-1`").await.unwrap();
+    conn.query_drop("CREATE DATABASE `Use-Db-2`").await.unwrap();
+    conn.query_drop("CREATE DATABASE UseDb3").await.unwrap();
+
+    // The quoted names keep the case, the unquoted ones are lowercase as CREATE DATABASE.
+    for (statement, database) in [
+        ("USE `use-db-1`", "use-db-1"),
+        ("use `Use-Db-2`;", "Use-Db-2"),
+        ("USE UseDb3", "usedb3"),
+        ("/* client */ USE USEDB3", "usedb3"),
+        ("USE `usedb3`", "usedb3"),
+        ("USE `use-db-1`", "use-db-1"),
+    ] {
+        conn.query_drop(statement).await.unwrap();
+        let current: Option<String> = conn.query_first("SELECT DATABASE()").await.unwrap();
+        assert_eq!(current.as_deref(), Some(database), "{statement}");
+    }
+
+    // The unknown databases are ER_BAD_DB_ERROR, the current one is kept.
+    for statement in ["USE `use-db-2`", "USE no_such_db"] {
+        match conn.query_drop(statement).await {
+            Err(mysql_async::Error::Server(error)) => assert_eq!(error.code, 1049, "{statement}"),
+            other => panic!("{statement}: {other:?}"),
+        }
+    }
+    let current: Option<String> = conn.query_first("SELECT DATABASE()").await.unwrap();
+    assert_eq!(current.as_deref(), Some("use-db-1"));
+
+    // COM_INIT_DB follows the same rules.
+    let mut stream = connect_raw(listening, &[]).await?;
+    for (database, header) in [
+        ("USEDB3", 0x00),
+        ("`Use-Db-2`", 0x00),
+        ("Use-Db-2", 0xff),
+        ("no_such_db", 0xff),
+    ] {
+        let mut command = vec![0x02];
+        command.extend(database.as_bytes());
+        write_packet(&mut stream, 0, &command).await?;
+        let (_, response) = read_packet(&mut stream).await?;
+        assert_eq!(response[0], header, "{database}");
+        if header == 0xff {
+            assert_eq!(u16::from_le_bytes([response[1], response[2]]), 1049);
+        }
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_statistics_and_unknown_commands() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs, MySQLTlsConfig::default())?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let mut stream = connect_raw(listening, &[]).await?;
+
+    // COM_STATISTICS
+    write_packet(&mut stream, 0, &[0x09]).await?;
+    let (seq, statistics) = read_packet(&mut stream).await?;
+    assert_eq!(seq, 1);
+    let statistics = String::from_utf8(statistics).unwrap();
+    assert!(statistics.starts_with("Uptime: "), "{statistics}");
+    assert!(
+        statistics.contains("Queries per second avg: "),
+        "{statistics}"
+    );
+
+    // COM_DEBUG isn't supported, the connection is kept.
+    write_packet(&mut stream, 0, &[0x0d]).await?;
+    let (_, error) = read_packet(&mut stream).await?;
+    assert_eq!(error[0], 0xff);
+    assert_eq!(u16::from_le_bytes([error[1], error[2]]), 1047);
+
+    // COM_RESET_CONNECTION, then COM_PING still answers.
+    for command in [0x1f, 0x0e] {
+        write_packet(&mut stream, 0, &[command]).await?;
+        let (_, ok) = read_packet(&mut stream).await?;
+        assert_eq!(ok[0], 0x00, "{command:#04x}");
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_reset_connection_with_tls() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let tls_config = MySQLTlsConfig::new(TEST_SERVER_CERT.to_string(), TEST_SERVER_KEY.to_string());
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs, tls_config)?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let opts = mysql_async::OptsBuilder::default()
+        .ip_or_hostname("localhost")
+        .user(Some("root".to_string()))
+        .db_name(Some("system"))
+        .tcp_port(listening.port())
+        .ssl_opts(Some(
+            SslOpts::default().with_root_certs(vec![Path::new(TEST_CA_CERT).into()]),
+        ));
+    let mut conn = mysql_async::Conn::new(opts).await.unwrap();
+
+    // COM_RESET_CONNECTION goes back to the database of the handshake.
+    conn.query_drop("USE default").await.unwrap();
+    conn.reset().await.unwrap();
+    let database: Option<String> = conn.query_first("SELECT database()").await.unwrap();
+    assert_eq!(database, Some("system".to_string()));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_connect_attrs_labels() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs, MySQLTlsConfig::default())?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let _stream = connect_raw(listening, &[
+        ("_os", "linux"),
+        ("_client_name", "libmysql"),
+        ("program_name", "airflow"),
+        ("Team", "data\n"),
+    ])
+    .await?;
+
+    // `program_name` is the application name, the attributes of the client library are skipped.
+    let mut conn = create_connection(listening.port(), false).await?;
+    let labels: Vec<(String, String)> = conn
+        .query(
+            "SELECT labels['app'], labels['team'] FROM system.processes \
+             WHERE labels['app'] = 'airflow' AND labels['_os'] IS NULL",
+        )
+        .await
+        .unwrap();
+    assert_eq!(labels, vec![("airflow".to_string(), "data".to_string())]);
+
+    Ok(())
+}
+
+// The connection of root with the empty password, the commands are written as packets.
+async fn connect_raw(listening: SocketAddr, attrs: &[(&str, &str)]) -> Result<TcpStream> {
+    let mut stream = TcpStream::connect(listening).await?;
+
+    // Greeting, then the HandshakeResponse41.
+    read_packet(&mut stream).await?;
+    let mut capabilities: u32 = 0x0001 | 0x0200 | 0x8000 | 0x0008_0000;
+    if !attrs.is_empty() {
+        capabilities |= 0x0010_0000;
+    }
+    let mut response = capabilities.to_le_bytes().to_vec();
+    response.extend(16_777_216_u32.to_le_bytes());
+    response.push(33);
+    response.extend([0u8; 23]);
+    response.extend(b"root\0");
+    response.push(0);
+    response.extend(b"mysql_native_password\0");
+    if !attrs.is_empty() {
+        // The attributes are short, their lengths fit in one byte.
+        let mut encoded = vec![];
+        for (key, value) in attrs {
+            encoded.push(key.len() as u8);
+            encoded.extend(key.as_bytes());
+            encoded.push(value.len() as u8);
+            encoded.extend(value.as_bytes());
+        }
+        response.push(encoded.len() as u8);
+        response.extend(encoded);
+    }
+    write_packet(&mut stream, 1, &response).await?;
+    let (_, ok) = read_packet(&mut stream).await?;
+    assert_eq!(ok[0], 0x00);
+    Ok(stream)
+}
+
+async fn read_packet(stream: &mut TcpStream) -> Result<(u8, Vec<u8>)> {
+    let mut header = [0u8; 4];
+    stream.read_exact(&mut header).await?;
+    let len = u32::from_le_bytes([header[0], header[1], header[2], 0]) as usize;
+    let mut payload = vec![0u8; len];
+    stream.read_exact(&mut payload).await?;
+    Ok((header[3], payload))
+}
+
+async fn write_packet(stream: &mut TcpStream, seq: u8, payload: &[u8]) -> Result<()> {
+    let len = (payload.len() as u32).to_le_bytes();
+    stream.write_all(&[len[0], len[1], len[2], seq]).await?;
+    stream.write_all(payload).await?;
+    stream.flush().await?;
+    Ok(())
+}
+
 async fn create_connection(port: u16, with_tls: bool) -> Result<mysql_async::Conn> {
     let ssl_opts = if with_tls {
         Some(SslOpts::default().with_root_certs(vec![Path::new(TEST_CA_CERT).into()]))
@@ -205,6 +1105,22 @@ async fn create_connection(port: u16, with_tls: bool) -> Result<mysql_async::Con
         .map_err_to_code(ErrorCode::UnknownException, || "Reject connection")
 }
 
+async fn create_connection_with_user(
+    port: u16,
+    user: &str,
+    password: Option<String>,
+) -> Result<mysql_async::Conn> {
+    let opts = mysql_async::OptsBuilder::default()
+        .ip_or_hostname("localhost")
+        .user(Some(user.to_string()))
+        .pass(password)
+        .tcp_port(port);
+
+    mysql_async::Conn::new(opts)
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Reject connection")
+}
+
 struct EmptyRow;
 
 impl FromRow for EmptyRow {
@@ -13,11 +13,17 @@
 // limitations under the License.
 
 use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal::AuthInfo;
+use databend_common_meta_app::principal::UserInfo;
 use databend_common_meta_app::storage::StorageFsConfig;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_meta_app::storage::StorageS3Config;
+use databend_query::sessions::SessionManager;
+use databend_query::sessions::SessionType;
 use databend_query::sessions::TableContext;
+use databend_query::test_kits::execute_query;
 use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestFixture;
 use wiremock::matchers::method;
@@ -65,3 +71,70 @@ async fn test_get_storage_accessor_fs() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_user_query_quota() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+    let session_manager = SessionManager::instance();
+
+    let mut user = UserInfo::new("quota_user", "%", AuthInfo::None);
+    user.quota.max_concurrent_queries = 2;
+    user.quota.max_memory_per_query = 1024 * 1024;
+    user.quota.max_result_rows = 100;
+    let identity = user.identity();
+
+    let session = session_manager.create_session(SessionType::MySQL).await?;
+    session.set_authed_user(user, None).await?;
+    let session = session_manager.register_session(session)?;
+
+    // Saturate the quota, the slots are taken when the queries are admitted.
+    let failed = session.create_query_context().await?;
+    let cancelled = session.create_query_context().await?;
+    assert_eq!(session_manager.get_user_running_queries(&identity), 0);
+    failed.acquire_user_query_slot()?;
+    failed.acquire_user_query_slot()?;
+    cancelled.acquire_user_query_slot()?;
+    assert_eq!(session_manager.get_user_running_queries(&identity), 2);
+
+    // A context is still created, e.g. for the result pages of the running queries.
+    let ctx = session.create_query_context().await?;
+    let err = ctx.acquire_user_query_slot().unwrap_err();
+    assert_eq!(err.code(), ErrorCode::USER_QUOTA_EXCEEDED);
+    assert_eq!(
+        err.message(),
+        "quota exceeded for user quota_user: 2/2 concurrent queries"
+    );
+    drop(ctx);
+    assert_eq!(session_manager.get_user_running_queries(&identity), 2);
+
+    // A failed query gives its slot back.
+    assert!(
+        execute_query(failed.clone(), "SELECT * FROM system.not_exists")
+            .await
+            .is_err()
+    );
+    drop(failed);
+    assert_eq!(session_manager.get_user_running_queries(&identity), 1);
+
+    // So does a killed one.
+    session.force_kill_query(ErrorCode::AbortedQuery("killed"));
+    drop(cancelled);
+    assert_eq!(session_manager.get_user_running_queries(&identity), 0);
+
+    // The memory and result rows of the query are capped to the quota.
+    let ctx = session.create_query_context().await?;
+    ctx.apply_user_quota()?;
+    assert_eq!(ctx.get_settings().get_max_memory_usage()?, 1024 * 1024);
+    assert_eq!(ctx.get_settings().get_max_result_rows()?, 100);
+    drop(ctx);
+
+    // Lower limits of the session are kept.
+    session
+        .get_settings()
+        .set_setting("max_result_rows".to_string(), "10".to_string())?;
+    let ctx = session.create_query_context().await?;
+    ctx.apply_user_quota()?;
+    assert_eq!(ctx.get_settings().get_max_result_rows()?, 10);
+
+    Ok(())
+}
@@ -18,6 +18,7 @@ use std::time::Instant;
 use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
 
+use databend_common_catalog::table_context::ProcessInfoState;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_sql::Planner;
@@ -25,8 +26,11 @@ use databend_query::interpreters::InterpreterFactory;
 use databend_query::sessions::QueryEntry;
 use databend_query::sessions::QueueData;
 use databend_query::sessions::QueueManager;
+use databend_query::sessions::SessionType;
+use databend_query::sessions::TableContext;
 use databend_query::test_kits::TestFixture;
 use log::error;
+use parking_lot::Mutex;
 
 #[derive(Debug)]
 struct TestData<const PASSED: bool = false>(String);
@@ -61,7 +65,7 @@ async fn test_passed_acquire() -> Result<()> {
         + 5;
 
     let barrier = Arc::new(tokio::sync::Barrier::new(test_count));
-    let queue = QueueManager::<TestData<true>>::create(1);
+    let queue = QueueManager::<TestData<true>>::create(1, 0);
     let mut join_handles = Vec::with_capacity(test_count);
 
     let instant = Instant::now();
@@ -100,7 +104,7 @@ async fn test_serial_acquire() -> Result<()> {
         + 5;
 
     let barrier = Arc::new(tokio::sync::Barrier::new(test_count));
-    let queue = QueueManager::<TestData>::create(1);
+    let queue = QueueManager::<TestData>::create(1, 0);
     let mut join_handles = Vec::with_capacity(test_count);
 
     let instant = Instant::now();
@@ -139,7 +143,7 @@ async fn test_concurrent_acquire() -> Result<()> {
         + 5;
 
     let barrier = Arc::new(tokio::sync::Barrier::new(test_count));
-    let queue = QueueManager::<TestData>::create(2);
+    let queue = QueueManager::<TestData>::create(2, 0);
     let mut join_handles = Vec::with_capacity(test_count);
 
     let instant = Instant::now();
@@ -181,7 +185,7 @@ async fn test_list_acquire() -> Result<()> {
         + 5;
 
     let barrier = Arc::new(tokio::sync::Barrier::new(test_count));
-    let queue = QueueManager::<TestData>::create(1);
+    let queue = QueueManager::<TestData>::create(1, 0);
     let mut join_handles = Vec::with_capacity(test_count);
 
     for index in 0..test_count {
@@ -206,6 +210,183 @@ async fn test_list_acquire() -> Result<()> {
     Ok(())
 }
 
+#[derive(Debug)]
+struct TimeoutData(String, Duration);
+
+impl QueueData for TimeoutData {
+    type Key = String;
+
+    fn get_key(&self) -> Self::Key {
+        self.0.clone()
+    }
+
+    fn remove_error_message(key: Option<Self::Key>) -> ErrorCode {
+        ErrorCode::Internal(format!("{:?}", key))
+    }
+
+    fn timeout(&self) -> Duration {
+        self.1
+    }
+
+    fn need_acquire_to_queue(&self) -> bool {
+        true
+    }
+}
+
+async fn wait_queue_length<Data: QueueData>(queue: &QueueManager<Data>, length: usize) {
+    while queue.length() != length {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fifo_acquire() -> Result<()> {
+    let queue = QueueManager::<TestData>::create(1, 0);
+    let running = queue.acquire(TestData("running".to_string())).await?;
+
+    let order = Arc::new(Mutex::new(vec![]));
+    let mut join_handles = Vec::with_capacity(5);
+    for index in 0..5 {
+        join_handles.push({
+            let queue = queue.clone();
+            let order = order.clone();
+            databend_common_base::runtime::spawn(async move {
+                let _guard = queue
+                    .acquire(TestData(format!("TestData{}", index)))
+                    .await?;
+                order.lock().push(index);
+                Result::<(), ErrorCode>::Ok(())
+            })
+        });
+        // Enqueue the next one once this one waits.
+        wait_queue_length(&queue, index + 1).await;
+    }
+
+    drop(running);
+    for join_handle in join_handles {
+        join_handle.await.unwrap()?;
+    }
+
+    // The queued queries run in the order they arrived.
+    assert_eq!(*order.lock(), (0..5).collect::<Vec<_>>());
+    assert_eq!(queue.length(), 0);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_timeout_acquire() -> Result<()> {
+    let queue = QueueManager::<TimeoutData>::create(1, 0);
+    let _running = queue
+        .acquire(TimeoutData("running".to_string(), Duration::from_secs(1)))
+        .await?;
+
+    let instant = Instant::now();
+    let err = queue
+        .acquire(TimeoutData(
+            "queued".to_string(),
+            Duration::from_millis(500),
+        ))
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err.code(), ErrorCode::TIMEOUT);
+    assert!(instant.elapsed() >= Duration::from_millis(500));
+    assert_eq!(queue.length(), 0);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_max_queued_acquire() -> Result<()> {
+    let timeout = Duration::from_secs(1000);
+    let queue = QueueManager::<TimeoutData>::create(1, 1);
+    let running = queue
+        .acquire(TimeoutData("running".to_string(), timeout))
+        .await?;
+
+    let queued = {
+        let queue = queue.clone();
+        databend_common_base::runtime::spawn(async move {
+            queue
+                .acquire(TimeoutData("queued".to_string(), timeout))
+                .await
+                .map(|_| ())
+        })
+    };
+    wait_queue_length(&queue, 1).await;
+
+    // The queue is full, the query is rejected at once.
+    let err = queue
+        .acquire(TimeoutData("rejected".to_string(), timeout))
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err.code(), ErrorCode::TOO_MANY_QUEUED_QUERIES);
+
+    // A query given up while waiting leaves the queue.
+    queued.abort();
+    let _ = queued.await;
+    assert_eq!(queue.length(), 0);
+
+    // A free slot is taken without queuing.
+    drop(running);
+    let _running = queue
+        .acquire(TimeoutData("running".to_string(), timeout))
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_queued_process_state() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.execute_command("create table t1(a int)").await?;
+
+    let queue = QueueManager::<QueryEntry>::create(1, 0);
+    let mut entries = vec![];
+    let mut sessions = vec![];
+    for _ in 0..2 {
+        let session = fixture.new_session_with_type(SessionType::Dummy).await?;
+        let ctx = session.create_query_context().await?;
+        let (plan, extras) = Planner::new(ctx.clone())
+            .plan_sql("insert into t1 values(1)")
+            .await?;
+        entries.push(QueryEntry::create(&ctx, &plan, &extras)?);
+        sessions.push((session, ctx));
+    }
+
+    let queued_entry = entries.pop().unwrap();
+    let running = queue.acquire(entries.pop().unwrap()).await?;
+    let queued = {
+        let queue = queue.clone();
+        databend_common_base::runtime::spawn(async move {
+            queue.acquire(queued_entry).await.map(|_| ())
+        })
+    };
+    wait_queue_length(&queue, 1).await;
+
+    let (running_session, _) = &sessions[0];
+    let (queued_session, queued_ctx) = &sessions[1];
+    assert_eq!(
+        running_session.process_info().state,
+        ProcessInfoState::Query
+    );
+    assert_eq!(
+        queued_session.process_info().state,
+        ProcessInfoState::Queued
+    );
+
+    // A queued query can be killed while waiting.
+    assert!(queue.remove(queued_ctx.get_id()));
+    let err = queued.await.unwrap().err().unwrap();
+    assert_eq!(err.code(), ErrorCode::ABORTED_QUERY);
+    assert_eq!(queued_session.process_info().state, ProcessInfoState::Query);
+
+    drop(running);
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_heavy_actions() -> Result<()> {
     struct Query {
@@ -12,9 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
 use databend_common_base::base::tokio;
 use databend_common_exception::Result;
 use databend_common_meta_app::tenant::Tenant;
+use databend_query::sessions::Session;
+use databend_query::sessions::SessionManager;
+use databend_query::sessions::SessionType;
 use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestFixture;
 
@@ -64,3 +71,96 @@ async fn test_session_in_management_mode() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_close_idle_sessions() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+    let session_manager = SessionManager::instance();
+
+    let create_session = || {
+        let session_manager = session_manager.clone();
+        async move {
+            let session = session_manager.create_session(SessionType::MySQL).await?;
+            session_manager.register_session(session)
+        }
+    };
+
+    // Interactive with 8 hours, interactive with 10 seconds, and pooled with 60 seconds.
+    let interactive = create_session().await?;
+    interactive
+        .get_settings()
+        .set_setting("idle_session_timeout_secs".to_string(), "28800".to_string())?;
+    let short = create_session().await?;
+    short
+        .get_settings()
+        .set_setting("idle_session_timeout_secs".to_string(), "10".to_string())?;
+    let pooled = create_session().await?;
+    pooled
+        .get_settings()
+        .set_setting("pooled_session".to_string(), "1".to_string())?;
+    pooled.get_settings().set_setting(
+        "pooled_idle_session_timeout_secs".to_string(),
+        "60".to_string(),
+    )?;
+
+    let tracked = |session: &Arc<Session>| {
+        let id = session.get_id();
+        let in_processes = session_manager
+            .processes_info()
+            .iter()
+            .any(|process| process.id == id);
+        in_processes && session_manager.get_session_by_id(&id).is_some()
+    };
+
+    // Other tracked sessions of the fixture may be closed too, only ours are checked.
+    let closed_at = |now: Instant, session: &Arc<Session>| {
+        session_manager
+            .close_idle_sessions(now)
+            .contains(&session.get_id())
+    };
+
+    let now = Instant::now();
+    assert!(!closed_at(now, &short));
+    assert!(tracked(&short));
+
+    assert!(closed_at(now + Duration::from_secs(30), &short));
+    assert!(short.is_aborting());
+    assert!(short.is_closed_by_idle_timeout());
+    assert!(!tracked(&short));
+    assert!(tracked(&pooled) && tracked(&interactive));
+
+    assert!(closed_at(now + Duration::from_secs(120), &pooled));
+    assert!(pooled.is_closed_by_idle_timeout());
+    assert!(tracked(&interactive));
+
+    // The session running a query is in use.
+    let ctx = interactive.create_query_context().await?;
+    let later = now + Duration::from_secs(9 * 60 * 60);
+    assert!(!closed_at(later, &interactive));
+    assert!(!interactive.is_aborting());
+
+    drop(ctx);
+    assert!(closed_at(later, &interactive));
+    assert!(!tracked(&interactive));
+
+    // The pooled and the interactive sessions never expire by default.
+    let pooled = create_session().await?;
+    pooled
+        .get_settings()
+        .set_setting("pooled_session".to_string(), "1".to_string())?;
+    assert!(!closed_at(later, &pooled));
+    assert!(tracked(&pooled));
+
+    let interactive = create_session().await?;
+    assert!(!closed_at(later, &interactive));
+    assert!(tracked(&interactive));
+
+    // The keep-alive of a FlightSQL session is left to the handler, the sweep follows the
+    // settings only.
+    let flight_sql = create_session().await?;
+    flight_sql.get_status().write().max_idle_time = Some(Duration::from_secs(360));
+    assert!(!closed_at(later, &flight_sql));
+    assert!(tracked(&flight_sql));
+
+    Ok(())
+}
@@ -13,8 +13,11 @@
 // limitations under the License.
 
 use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_query::sessions::Session;
 use databend_query::sessions::SessionType;
+use databend_query::sessions::TableContext;
 use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestFixture;
 
@@ -67,5 +70,105 @@ async fn test_session_setting_override() -> Result<()> {
         assert_eq!(actual, expect);
     }
 
+    // The values loaded from the config are reported as such, and kept by a session reset.
+    {
+        let settings = session.get_settings();
+        settings.unset_session_settings();
+        let level = settings
+            .into_iter()
+            .find(|item| item.name == "parquet_fast_read_bytes")
+            .map(|item| format!("{:?}", item.level));
+        assert_eq!(level.as_deref(), Some("CONFIG"));
+        assert_eq!(settings.get_parquet_fast_read_bytes()?, 1000000);
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_session_setting_global() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let level = |session: &Session| {
+        session
+            .get_settings()
+            .into_iter()
+            .find(|item| item.name == "load_file_metadata_expire_hours")
+            .map(|item| format!("{:?}", item.level))
+            .unwrap()
+    };
+
+    let existing = fixture.new_session_with_type(SessionType::Dummy).await?;
+    let running_query = existing.create_query_context().await?;
+    assert_eq!(
+        running_query
+            .get_settings()
+            .get_load_file_metadata_expire_hours()?,
+        24
+    );
+
+    let session = fixture.new_session_with_type(SessionType::Dummy).await?;
+    session
+        .get_settings()
+        .set_global_setting(
+            "load_file_metadata_expire_hours".to_string(),
+            "12".to_string(),
+        )
+        .await?;
+
+    // The existing session and its running query keep their value.
+    assert_eq!(
+        running_query
+            .get_settings()
+            .get_load_file_metadata_expire_hours()?,
+        24
+    );
+    assert_eq!(
+        existing
+            .get_settings()
+            .get_load_file_metadata_expire_hours()?,
+        24
+    );
+
+    // The new sessions pick up the global value.
+    let new_session = fixture.new_session_with_type(SessionType::Dummy).await?;
+    assert_eq!(
+        new_session
+            .get_settings()
+            .get_load_file_metadata_expire_hours()?,
+        12
+    );
+    assert_eq!(level(&new_session), "GLOBAL");
+
+    // A session change overrides the global value.
+    new_session.get_settings().set_setting(
+        "load_file_metadata_expire_hours".to_string(),
+        "6".to_string(),
+    )?;
+    assert_eq!(level(&new_session), "SESSION");
+
+    // Removing the global value restores the default for the new sessions.
+    session
+        .get_settings()
+        .try_drop_global_setting("load_file_metadata_expire_hours")
+        .await?;
+    let new_session = fixture.new_session_with_type(SessionType::Dummy).await?;
+    assert_eq!(
+        new_session
+            .get_settings()
+            .get_load_file_metadata_expire_hours()?,
+        24
+    );
+    assert_eq!(level(&new_session), "DEFAULT");
+
+    // The settings immutable at runtime or bound to a session can't be set globally.
+    for name in ["enterprise_license", "sandbox_tenant"] {
+        let err = session
+            .get_settings()
+            .set_global_setting(name.to_string(), "x".to_string())
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), ErrorCode::IMMUTABLE_VARIABLE, "{name}");
+    }
+
     Ok(())
 }
@@ -24,6 +24,7 @@ use databend_common_base::base::Progress;
 use databend_common_base::base::ProgressValues;
 use databend_common_catalog::catalog::Catalog;
 use databend_common_catalog::cluster_info::Cluster;
+use databend_common_catalog::cluster_info::NodeStatus;
 use databend_common_catalog::database::Database;
 use databend_common_catalog::lock::LockTableOption;
 use databend_common_catalog::merge_into_join::MergeIntoJoin;
@@ -37,6 +38,7 @@ use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::FilteredCopyFiles;
 use databend_common_catalog::table_context::MaterializedCtesBlocks;
 use databend_common_catalog::table_context::ProcessInfo;
+use databend_common_catalog::table_context::ServerStatus;
 use databend_common_catalog::table_context::StageAttachment;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
@@ -653,7 +655,7 @@ impl TableContext for CtxDelegation {
         self.ctx.get_function_context()
     }
 
-    fn get_connection_id(&self) -> String {
+    fn get_connection_id(&self) -> u64 {
         todo!()
     }
 
@@ -817,6 +819,14 @@ impl TableContext for CtxDelegation {
     fn get_queries_profile(&self) -> HashMap<String, Vec<PlanProfile>> {
         todo!()
     }
+
+    async fn ping_cluster_nodes(
+        &self,
+        _timeout: Duration,
+    ) -> HashMap<String, std::result::Result<NodeStatus, String>> {
+        todo!()
+    }
+
     fn add_merge_status(&self, _merge_status: MergeStatus) {
         todo!()
     }
@@ -880,6 +890,10 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn get_server_status(&self) -> ServerStatus {
+        todo!()
+    }
+
     fn get_read_block_thresholds(&self) -> BlockThresholds {
         todo!()
     }
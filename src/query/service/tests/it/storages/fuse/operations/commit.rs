@@ -23,6 +23,7 @@ use databend_common_base::base::Progress;
 use databend_common_base::base::ProgressValues;
 use databend_common_catalog::catalog::Catalog;
 use databend_common_catalog::cluster_info::Cluster;
+use databend_common_catalog::cluster_info::NodeStatus;
 use databend_common_catalog::database::Database;
 use databend_common_catalog::lock::LockTableOption;
 use databend_common_catalog::merge_into_join::MergeIntoJoin;
@@ -36,6 +37,7 @@ use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::FilteredCopyFiles;
 use databend_common_catalog::table_context::MaterializedCtesBlocks;
 use databend_common_catalog::table_context::ProcessInfo;
+use databend_common_catalog::table_context::ServerStatus;
 use databend_common_catalog::table_context::StageAttachment;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
@@ -602,7 +604,7 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
-    fn get_connection_id(&self) -> String {
+    fn get_connection_id(&self) -> u64 {
         todo!()
     }
 
@@ -741,6 +743,13 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    async fn ping_cluster_nodes(
+        &self,
+        _timeout: Duration,
+    ) -> HashMap<String, std::result::Result<NodeStatus, String>> {
+        todo!()
+    }
+
     fn add_merge_status(&self, _merge_status: MergeStatus) {
         todo!()
     }
@@ -804,6 +813,10 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn get_server_status(&self) -> ServerStatus {
+        todo!()
+    }
+
     fn get_read_block_thresholds(&self) -> BlockThresholds {
         todo!()
     }
@@ -25,5 +25,6 @@ mod optimize;
 mod purge_drop;
 mod read_plan;
 mod replace_into;
+mod runtime_filter;
 mod table_analyze;
 mod truncate;
@@ -0,0 +1,101 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::runtime_filter_info::RuntimeFilterInfo;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::Int32Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::HashMethod;
+use databend_common_expression::HashMethodKind;
+use databend_common_hashtable::FastHash;
+use databend_query::sessions::QueryContext;
+use databend_query::test_kits::execute_query;
+use databend_query::test_kits::TestFixture;
+use futures_util::TryStreamExt;
+use xorf::BinaryFuse16;
+
+async fn scan(ctx: Arc<QueryContext>, query: &str) -> Result<Vec<i32>> {
+    let blocks = execute_query(ctx, query)
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+
+    let mut values = vec![];
+    for block in blocks {
+        let column = block.get_by_offset(0).value.as_column().unwrap();
+        values.extend(Int32Type::try_downcast_column(column).unwrap().iter());
+    }
+    Ok(values)
+}
+
+/// Builds the bloom filter of the keys the same way as the build side of the hash join.
+fn bloom_filter(keys: Vec<i32>) -> Result<BinaryFuse16> {
+    let num_rows = keys.len();
+    let data_type = DataType::Number(NumberDataType::Int32);
+    let column = Int32Type::from_data(keys);
+    let HashMethodKind::KeysU32(method) =
+        DataBlock::choose_hash_method_with_types(&[data_type.clone()], false)?
+    else {
+        unreachable!()
+    };
+
+    let keys_state = method.build_keys_state(&[(column, data_type)], num_rows)?;
+    let hashes = method
+        .build_keys_iter(&keys_state)?
+        .map(|key| key.fast_hash())
+        .collect::<Vec<_>>();
+    Ok(BinaryFuse16::try_from(&hashes)?)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_scan_with_bloom_runtime_filter() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+
+    fixture
+        .execute_command(&format!(
+            "create table {db}.t(a int not null) storage_format = Parquet"
+        ))
+        .await?;
+    fixture
+        .execute_command(&format!("insert into {db}.t values(1),(2),(3)"))
+        .await?;
+    fixture
+        .execute_command(&format!("insert into {db}.t values(4),(5),(6)"))
+        .await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let query = format!("select a from {db}.t order by a");
+    assert_eq!(scan(ctx.clone(), &query).await?, vec![1, 2, 3, 4, 5, 6]);
+
+    // Publish the filter of the build keys 2 and 5 for the scan of the table 0.
+    let mut runtime_filter = RuntimeFilterInfo::default();
+    runtime_filter.add_bloom(("a".to_string(), bloom_filter(vec![2, 5])?));
+    ctx.set_runtime_filter((0, runtime_filter));
+    assert!(ctx.has_bloom_runtime_filters(0));
+
+    assert_eq!(scan(ctx.clone(), &query).await?, vec![2, 5]);
+
+    ctx.clear_runtime_filter();
+    assert_eq!(scan(ctx, &query).await?, vec![1, 2, 3, 4, 5, 6]);
+    Ok(())
+}
@@ -0,0 +1,97 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_query::storages::fuse::FuseTable;
+use databend_query::test_kits::*;
+use databend_storages_common_cache::CacheAccessor;
+use databend_storages_common_cache_manager::CacheManager;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_snapshot_table_broken_snapshot() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.new_query_ctx().await?;
+
+    fixture.create_default_database().await?;
+    fixture.create_default_table().await?;
+    for i in 0..3 {
+        let qry = format!("insert into {}.{} values({}, ({}, {}))", db, tbl, i, i, i);
+        fixture.execute_command(&qry).await?;
+    }
+
+    {
+        let expected = vec![
+            "+----------+----------+",
+            "| Column 0 | Column 1 |",
+            "+----------+----------+",
+            "| 1        | true     |",
+            "| 2        | true     |",
+            "| 3        | true     |",
+            "+----------+----------+",
+        ];
+        let qry = format!(
+            "select row_count, error is null from fuse_snapshot('{}', '{}') order by row_count",
+            db, tbl
+        );
+        expects_ok(
+            "all_snapshots_readable",
+            execute_query(ctx.clone(), qry.as_str()).await,
+            expected,
+        )
+        .await?;
+    }
+
+    // Corrupt the parent of the current snapshot.
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let snapshot = fuse_table.read_table_snapshot().await?.unwrap();
+    let (prev_id, prev_version) = snapshot.prev_snapshot_id.unwrap();
+    let prev_location = fuse_table
+        .meta_location_generator()
+        .snapshot_location_from_uuid(&prev_id, prev_version)?;
+    fuse_table
+        .get_operator()
+        .write(&prev_location, b"not a snapshot".to_vec())
+        .await?;
+    if let Some(cache) = CacheManager::instance().get_table_snapshot_cache() {
+        cache.evict(&prev_location);
+    }
+
+    // The broken snapshot is listed with the error, and the listing stops there.
+    {
+        let expected = vec![
+            "+----------+----------+----------+",
+            "| Column 0 | Column 1 | Column 2 |",
+            "+----------+----------+----------+",
+            "| 3        | NULL     | true     |",
+            "| 0        | NULL     | false    |",
+            "+----------+----------+----------+",
+        ];
+        let qry = format!(
+            "select row_count, bytes_added, error is null from fuse_snapshot('{}', '{}') order by row_count desc",
+            db, tbl
+        );
+        expects_ok(
+            "broken_snapshot",
+            execute_query(ctx.clone(), qry.as_str()).await,
+            expected,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
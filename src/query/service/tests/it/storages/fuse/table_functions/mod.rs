@@ -14,3 +14,4 @@
 
 mod clustering_information_table;
 mod fuse_block_table;
+mod fuse_snapshot_table;
@@ -14,23 +14,57 @@
 
 use std::io::Write;
 use std::sync::Arc;
+use std::time::Duration;
 
+use chrono::Utc;
 use databend_common_base::base::tokio;
+use databend_common_base::base::ServerWarnings;
+use databend_common_base::runtime::metrics::register_counter_family;
+use databend_common_base::runtime::metrics::register_histogram_family;
+use databend_common_base::runtime::metrics::GLOBAL_METRICS_REGISTRY;
+use databend_common_catalog::lock::LockTableOption;
+use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::plan::PartitionsShuffleKind;
+use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table::Table;
 use databend_common_exception::Result;
 use databend_common_expression::block_debug::box_render;
 use databend_common_expression::block_debug::pretty_format_blocks;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::types::StringType;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::RemoteExpr;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
 use databend_common_meta_app::principal::AuthInfo;
 use databend_common_meta_app::principal::AuthType;
 use databend_common_meta_app::principal::RoleInfo;
+use databend_common_meta_app::principal::UserIdentity;
 use databend_common_meta_app::principal::UserInfo;
 use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::storage::StorageFsConfig;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_meta_app::storage::StorageS3Config;
+use databend_common_pipeline_core::Pipeline;
 use databend_common_sql::executor::table_read_plan::ToReadDataSourcePlan;
+use databend_common_sql::executor::PhysicalPlanBuilder;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::Planner;
+use databend_common_storages_system::BacktraceTable;
 use databend_common_storages_system::BuildOptionsTable;
 use databend_common_storages_system::CachesTable;
+use databend_common_storages_system::CatalogScope;
 use databend_common_storages_system::CatalogsTable;
 use databend_common_storages_system::ClustersTable;
 use databend_common_storages_system::ColumnsTable;
@@ -40,13 +74,25 @@ use databend_common_storages_system::CreditsTable;
 use databend_common_storages_system::DatabasesTable;
 use databend_common_storages_system::EnginesTable;
 use databend_common_storages_system::FunctionsTable;
+use databend_common_storages_system::LogRetention;
+use databend_common_storages_system::MallocStatsTable;
+use databend_common_storages_system::MallocStatsTotalsTable;
 use databend_common_storages_system::MetricsTable;
 use databend_common_storages_system::RolesTable;
+use databend_common_storages_system::SettingsTable;
+use databend_common_storages_system::SyncOneBlockSystemTable;
+use databend_common_storages_system::SyncSystemTable;
+use databend_common_storages_system::SystemLogElement;
+use databend_common_storages_system::SystemLogQueue;
+use databend_common_storages_system::SystemLogTable;
 use databend_common_storages_system::UsersTable;
 use databend_common_users::UserApiProvider;
+use databend_query::interpreters::InterpreterFactory;
 use databend_query::sessions::QueryContext;
+use databend_query::sessions::SessionType;
 use databend_query::sessions::TableContext;
 use databend_query::stream::ReadDataBlockStream;
+use databend_query::test_kits::execute_query;
 use databend_query::test_kits::ClusterDescriptor;
 use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestFixture;
@@ -58,6 +104,9 @@ use wiremock::Mock;
 use wiremock::MockServer;
 use wiremock::ResponseTemplate;
 
+// Truncating system.metrics resets the global registry, keep the metrics tests apart.
+static METRICS_TABLE_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
 async fn run_table_tests(
     file: &mut impl Write,
     ctx: Arc<QueryContext>,
@@ -101,7 +150,7 @@ async fn test_build_options_table() -> Result<()> {
     let fixture = TestFixture::setup().await?;
     let ctx = fixture.new_query_ctx().await?;
 
-    let table = BuildOptionsTable::create(1);
+    let table = BuildOptionsTable::create(1, Some("default,storage_hdfs,simd"));
     let source_plan = table
         .read_plan(ctx.clone(), None, None, false, true)
         .await?;
@@ -110,7 +159,54 @@ async fn test_build_options_table() -> Result<()> {
     let result = stream.try_collect::<Vec<_>>().await?;
     let block = &result[0];
     assert_eq!(block.num_columns(), 2);
-    assert!(block.num_rows() > 0);
+
+    let options = (0..block.num_rows())
+        .map(|row| {
+            let name = block.get_by_offset(0).value.index(row);
+            let value = block.get_by_offset(1).value.index(row);
+            match (name, value) {
+                (Some(ScalarRef::String(name)), Some(ScalarRef::String(value))) => {
+                    (name.to_string(), value.to_string())
+                }
+                other => panic!("{other:?}"),
+            }
+        })
+        .collect::<Vec<_>>();
+    let option = |name: &str| {
+        options
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.clone())
+            .unwrap_or_else(|| panic!("{name} not found in {options:?}"))
+    };
+
+    // One row per option, the features of the binary are sorted and `default` is left out.
+    assert_eq!(option("cargo_features"), "simd,storage_hdfs");
+    for name in [
+        "version",
+        "git_sha",
+        "build_timestamp",
+        "rustc_version",
+        "target_triple",
+        "opt_level",
+        "debug",
+        "allocator",
+        "tokio",
+    ] {
+        assert!(!option(name).is_empty(), "{name}: {options:?}");
+    }
+    let mut names = options.iter().map(|(n, _)| n.clone()).collect::<Vec<_>>();
+    names.sort();
+    names.dedup();
+    assert_eq!(names.len(), options.len());
+
+    // The table registered in the system database has a row per option too.
+    let rows = rows(
+        &fixture,
+        "select value from system.build_options where name = 'rustc_version'",
+    )
+    .await?;
+    assert_eq!(rows.len(), 5, "{rows:?}");
 
     Ok(())
 }
@@ -141,7 +237,51 @@ async fn test_clusters_table() -> Result<()> {
     let stream = table.read_data_block_stream(ctx, &source_plan).await?;
     let result = stream.try_collect::<Vec<_>>().await?;
     let block = &result[0];
-    assert_eq!(block.num_columns(), 5);
+    assert_eq!(block.num_columns(), 8);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_clusters_table_unreachable_node() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let cluster_desc = ClusterDescriptor::new()
+        .with_node("local-node", "127.0.0.1:9090")
+        .with_node("dead-node", "127.0.0.1:1")
+        .with_local_id("local-node");
+    let ctx = fixture.new_query_ctx_with_cluster(cluster_desc).await?;
+
+    let table = ClustersTable::create(1);
+    let source_plan = table
+        .read_plan(ctx.clone(), None, None, false, true)
+        .await?;
+    let stream = table.read_data_block_stream(ctx, &source_plan).await?;
+    let block = DataBlock::concat(&stream.try_collect::<Vec<_>>().await?)?;
+
+    let string = |column: usize, row: usize| match block.get_by_offset(column).value.index(row) {
+        Some(ScalarRef::String(v)) => Some(v.to_string()),
+        Some(ScalarRef::Null) => None,
+        other => panic!("{other:?}"),
+    };
+    let mut nodes = (0..block.num_rows())
+        .map(|row| {
+            (
+                string(0, row).unwrap(),
+                string(6, row).unwrap(),
+                string(7, row),
+            )
+        })
+        .collect::<Vec<_>>();
+    nodes.sort();
+
+    // The local node answers from memory, the dead one is listed with the error of the ping.
+    assert_eq!(nodes[0].0, "dead-node");
+    assert_eq!(nodes[0].1, "unreachable");
+    assert!(nodes[0].2.is_some());
+    assert_eq!(
+        nodes[1],
+        ("local-node".to_string(), "online".to_string(), None)
+    );
 
     Ok(())
 }
@@ -190,17 +330,174 @@ async fn test_configs_table_redact() -> Result<()> {
     ctx.get_settings().set_max_threads(8)?;
 
     let table = ConfigsTable::create(1);
+    let read_config = |ctx: Arc<QueryContext>, name: &'static str| {
+        let table = table.clone();
+        async move {
+            let source_plan = table
+                .read_plan(ctx.clone(), None, None, false, true)
+                .await?;
+            let stream = table.read_data_block_stream(ctx, &source_plan).await?;
+            let result = stream.try_collect::<Vec<_>>().await?;
+            let block = DataBlock::concat(&result)?;
+            assert_eq!(block.num_columns(), 5);
+            let row = (0..block.num_rows())
+                .find(|row| {
+                    block.get_by_offset(1).value.index(*row) == Some(ScalarRef::String(name))
+                })
+                .unwrap();
+            let value = block.get_by_offset(2).value.index(row).unwrap().to_owned();
+            let is_secret = block.get_by_offset(4).value.index(row).unwrap().to_owned();
+            Result::Ok((value, is_secret))
+        }
+    };
+    // need a method to skip/edit endpoint_url
+    // run_table_tests(file, ctx, table).await?;
+
+    // The root user holds the SUPER privilege and sees the raw values.
+    assert_eq!(
+        read_config(ctx.clone(), "s3.secret_access_key").await?,
+        (
+            Scalar::String("secret_access_key".to_string()),
+            Scalar::Boolean(true)
+        )
+    );
+    assert_eq!(
+        read_config(ctx.clone(), "s3.region").await?,
+        (
+            Scalar::String("us-east-2".to_string()),
+            Scalar::Boolean(false)
+        )
+    );
+
+    // Other users see the secrets masked.
+    ctx.get_current_session()
+        .set_authed_user(UserInfo::new("reader", "%", AuthInfo::None), None)
+        .await?;
+    assert_eq!(
+        read_config(ctx.clone(), "s3.secret_access_key").await?,
+        (Scalar::String("******".to_string()), Scalar::Boolean(true))
+    );
+    assert_eq!(
+        read_config(ctx.clone(), "s3.region").await?,
+        (
+            Scalar::String("us-east-2".to_string()),
+            Scalar::Boolean(false)
+        )
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_settings_table_changed() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    let table = SettingsTable::create(1);
+    let read_setting = |name: &'static str| {
+        let ctx = ctx.clone();
+        let table = table.clone();
+        async move {
+            let source_plan = table
+                .read_plan(ctx.clone(), None, None, false, true)
+                .await?;
+            let stream = table.read_data_block_stream(ctx, &source_plan).await?;
+            let result = stream.try_collect::<Vec<_>>().await?;
+            let block = DataBlock::concat(&result)?;
+            let row = (0..block.num_rows())
+                .find(|row| {
+                    block.get_by_offset(0).value.index(*row) == Some(ScalarRef::String(name))
+                })
+                .unwrap();
+            let column = |offset: usize| {
+                block
+                    .get_by_offset(offset)
+                    .value
+                    .index(row)
+                    .unwrap()
+                    .to_owned()
+            };
+            // (value, default, level, changed)
+            Result::Ok((column(1), column(2), column(4), column(7)))
+        }
+    };
+
+    let (_, default_threads, _, _) = read_setting("max_threads").await?;
+    ctx.get_settings().set_max_threads(123)?;
+    let (value, default, level, changed) = read_setting("max_threads").await?;
+    assert_eq!(value, Scalar::String("123".to_string()));
+    assert_eq!(default, default_threads);
+    assert_eq!(level, Scalar::String("SESSION".to_string()));
+    assert_eq!(changed, Scalar::Boolean(true));
+
+    let (_, _, level, changed) = read_setting("max_memory_usage").await?;
+    assert_eq!(level, Scalar::String("DEFAULT".to_string()));
+    assert_eq!(changed, Scalar::Boolean(false));
+
+    // Setting the default value is still a change, at the session level.
+    let default_memory_usage = ctx.get_settings().get_max_memory_usage()?;
+    ctx.get_settings()
+        .set_max_memory_usage(default_memory_usage)?;
+    let (_, _, level, changed) = read_setting("max_memory_usage").await?;
+    assert_eq!(level, Scalar::String("SESSION".to_string()));
+    assert_eq!(changed, Scalar::Boolean(true));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_backtrace_table() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    // A task pending while the table is read.
+    let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+    let pending = databend_common_base::runtime::spawn(async move {
+        let _ = rx.await;
+    });
+
+    let table = BacktraceTable::create(1);
     let source_plan = table
         .read_plan(ctx.clone(), None, None, false, true)
         .await?;
-
-    let stream = table.read_data_block_stream(ctx, &source_plan).await?;
+    let stream = table
+        .read_data_block_stream(ctx.clone(), &source_plan)
+        .await?;
     let result = stream.try_collect::<Vec<_>>().await?;
-    let block = &result[0];
-    assert_eq!(block.num_columns(), 4);
-    // need a method to skip/edit endpoint_url
-    // run_table_tests(file, ctx, table).await?;
+    let block = DataBlock::concat(&result)?;
+    assert_eq!(block.num_columns(), 6);
+    assert!(block.num_rows() >= 1);
+    assert!(block.num_rows() <= BacktraceTable::MAX_TASKS);
+
+    let local_id = ctx.get_cluster().local_id.clone();
+    for row in 0..block.num_rows() {
+        let node = block.get_by_offset(0).value.index(row).unwrap();
+        assert_eq!(node, ScalarRef::String(&local_id));
+
+        // The tasks are numbered from 1 in the dump.
+        let task_id = block.get_by_offset(2).value.index(row).unwrap();
+        assert_eq!(
+            task_id,
+            ScalarRef::Number(NumberScalar::UInt64(row as u64 + 1))
+        );
+
+        let status = block.get_by_offset(4).value.index(row).unwrap();
+        assert!(matches!(
+            status,
+            ScalarRef::String("PENDING") | ScalarRef::String("RUNNING")
+        ));
+
+        // The stack is cut with `...` past the bound.
+        match block.get_by_offset(5).value.index(row).unwrap() {
+            ScalarRef::String(stack) => {
+                assert!(stack.len() <= BacktraceTable::MAX_STACK_BYTES + "...".len())
+            }
+            other => panic!("unexpected stack {other:?}"),
+        }
+    }
 
+    let _ = tx.send(());
+    pending.await.unwrap();
     Ok(())
 }
 
@@ -296,13 +593,115 @@ async fn test_functions_table() -> Result<()> {
     let stream = table.read_data_block_stream(ctx, &source_plan).await?;
     let result = stream.try_collect::<Vec<_>>().await?;
     let block = &result[0];
-    assert_eq!(block.num_columns(), 5);
+    assert_eq!(block.num_columns(), 7);
+
+    // The kind of well-known functions, and their documentation.
+    let blocks = fixture
+        .execute_query(
+            "select name, is_aggregate, is_window, category, example <> '' from system.functions \
+            where name in ('sum', 'now', 'length', 'row_number') order by name",
+        )
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let block = DataBlock::concat(&blocks)?;
+    let rows = (0..block.num_rows())
+        .map(|row| {
+            block
+                .columns()
+                .iter()
+                .map(|entry| match entry.value.index(row) {
+                    Some(ScalarRef::String(v)) => v.to_string(),
+                    Some(ScalarRef::Boolean(v)) => v.to_string(),
+                    other => panic!("{other:?}"),
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(rows, vec![
+        vec!["length", "false", "false", "string", "true"],
+        vec!["now", "false", "false", "datetime", "true"],
+        vec!["row_number", "false", "true", "window", "true"],
+        vec!["sum", "true", "false", "aggregate", "true"],
+    ]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_system_table_limit_push_down() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    let table = FunctionsTable::create(1);
+    let read_rows = |push_downs: PushDownInfo| {
+        let ctx = ctx.clone();
+        let table = table.clone();
+        async move {
+            let source_plan = table
+                .read_plan(ctx.clone(), Some(push_downs), None, false, true)
+                .await?;
+            let stream = table.read_data_block_stream(ctx, &source_plan).await?;
+            let result = stream.try_collect::<Vec<_>>().await?;
+            Result::Ok(result.iter().map(|b| b.num_rows()).sum::<usize>())
+        }
+    };
+
+    let limited = read_rows(PushDownInfo {
+        limit: Some(3),
+        ..Default::default()
+    })
+    .await?;
+    assert_eq!(limited, 3);
+
+    // The rows are not sorted by the table, the limit is taken above the sort.
+    let order_by = RemoteExpr::ColumnRef {
+        span: None,
+        id: "name".to_string(),
+        data_type: DataType::String,
+        display_name: "name".to_string(),
+    };
+    let ordered = read_rows(PushDownInfo {
+        limit: Some(3),
+        order_by: vec![(order_by, true, false)],
+        ..Default::default()
+    })
+    .await?;
+    assert!(ordered > 3);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_malloc_stats_tables() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    // Either the stats or a row telling why they are unavailable, never an error.
+    let table = MallocStatsTable::create(1);
+    let source_plan = table
+        .read_plan(ctx.clone(), None, None, false, true)
+        .await?;
+    let stream = table
+        .read_data_block_stream(ctx.clone(), &source_plan)
+        .await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    assert_eq!(result.iter().map(|b| b.num_rows()).sum::<usize>(), 1);
+
+    let table = MallocStatsTotalsTable::create(1);
+    let source_plan = table
+        .read_plan(ctx.clone(), None, None, false, true)
+        .await?;
+    let stream = table.read_data_block_stream(ctx, &source_plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    assert!(result.iter().map(|b| b.num_rows()).sum::<usize>() > 0);
 
     Ok(())
 }
 
 #[tokio::test(flavor = "multi_thread")]
 async fn test_metrics_table() -> Result<()> {
+    let _guard = METRICS_TABLE_LOCK.lock().await;
     let fixture = TestFixture::setup().await?;
     let ctx = fixture.new_query_ctx().await?;
 
@@ -339,6 +738,144 @@ async fn test_metrics_table() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_metrics_table_histogram_rows() -> Result<()> {
+    let _guard = METRICS_TABLE_LOCK.lock().await;
+    let fixture = TestFixture::setup().await?;
+
+    let histogram = register_histogram_family::<Vec<(&'static str, &'static str)>>(
+        "test_metrics_table_labeled_histogram",
+        [1.0, 10.0].into_iter(),
+    );
+    let labels = vec![("handler", "http"), ("db", "default")];
+    histogram.get_or_create(&labels).observe(2.0);
+    histogram.get_or_create(&labels).observe(20.0);
+
+    let sql = "select concat(metric, ' ', kind, ' ', labels, ' ', value) from system.metrics \
+               where metric like 'test_metrics_table_labeled_histogram%'";
+    let rows = sorted_rows(&fixture, sql).await?;
+    let expected = [
+        r#"test_metrics_table_labeled_histogram_bucket histogram {"db":"default","handler":"http","le":"1.0"} 0.0"#,
+        r#"test_metrics_table_labeled_histogram_bucket histogram {"db":"default","handler":"http","le":"10.0"} 1.0"#,
+        r#"test_metrics_table_labeled_histogram_bucket histogram {"db":"default","handler":"http","le":"+Inf"} 2.0"#,
+        r#"test_metrics_table_labeled_histogram_count histogram {"db":"default","handler":"http"} 2.0"#,
+        r#"test_metrics_table_labeled_histogram_sum histogram {"db":"default","handler":"http"} 22.0"#,
+    ];
+    for row in expected {
+        assert!(
+            rows.iter().any(|r| r.contains(row)),
+            "{row} not found in {rows:?}"
+        );
+    }
+    // The bucket, sum and count rows of the only label set, besides the table borders.
+    let data_rows = rows
+        .iter()
+        .filter(|r| r.contains("test_metrics_table"))
+        .count();
+    assert_eq!(data_rows, expected.len());
+
+    // The rows and their labels come in the same order every time.
+    let first = fixture
+        .execute_query(sql)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let second = fixture
+        .execute_query(sql)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(
+        pretty_format_blocks(&first)?,
+        pretty_format_blocks(&second)?
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_truncate_metrics_table() -> Result<()> {
+    let _guard = METRICS_TABLE_LOCK.lock().await;
+    let fixture = TestFixture::setup().await?;
+
+    let counter = register_counter_family::<Vec<(&'static str, &'static str)>>(
+        "test_truncate_metrics_table_count",
+    );
+    counter.get_or_create(&vec![("table", "dropped")]).inc();
+
+    let sql = "select concat(metric, ' ', labels) from system.metrics \
+               where metric like 'test_truncate_metrics_table%'";
+    let before = rows(&fixture, sql).await?;
+    assert!(
+        before
+            .iter()
+            .any(|r| r.contains(r#"test_truncate_metrics_table_count_total {"table":"dropped"}"#)),
+        "{before:?}"
+    );
+    let exposition = GLOBAL_METRICS_REGISTRY.render_metrics()?;
+    assert!(exposition.contains(r#"test_truncate_metrics_table_count_total{table="dropped"}"#));
+
+    fixture
+        .execute_command("truncate table system.metrics")
+        .await?;
+
+    let after = rows(&fixture, sql).await?;
+    assert!(
+        after
+            .iter()
+            .all(|r| !r.contains("test_truncate_metrics_table")),
+        "{after:?}"
+    );
+    let exposition = GLOBAL_METRICS_REGISTRY.render_metrics()?;
+    assert!(!exposition.contains("test_truncate_metrics_table_count_total{"));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_warnings_table() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    let warnings = ServerWarnings::instance();
+    warnings.warn("test_fake_component", "cache init fallback");
+    warnings.warn("test_fake_component", "cache init fallback");
+    warnings.error("test_fake_component", "compaction failed");
+
+    let sql = "select concat(severity, ' ', message, ' ', count::string) from system.warnings \
+               where component = 'test_fake_component'";
+    let before = sorted_rows(&fixture, sql).await?;
+    let expected = ["ERROR compaction failed 1", "WARN cache init fallback 2"];
+    for row in expected {
+        assert!(
+            before.iter().any(|r| r.contains(row)),
+            "{row} not found in {before:?}"
+        );
+    }
+    let data_rows = before
+        .iter()
+        .filter(|r| r.contains("WARN") || r.contains("ERROR"));
+    assert_eq!(data_rows.count(), expected.len());
+
+    fixture
+        .execute_command("truncate table system.warnings")
+        .await?;
+
+    let after = rows(&fixture, sql).await?;
+    assert!(
+        after
+            .iter()
+            .all(|r| !r.contains("compaction failed") && !r.contains("cache init fallback")),
+        "{after:?}"
+    );
+    // Other tests in this binary may push warnings concurrently.
+    assert!(warnings
+        .list()
+        .iter()
+        .all(|w| w.component != "test_fake_component"));
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_roles_table() -> Result<()> {
     let fixture = TestFixture::setup().await?;
@@ -429,6 +966,76 @@ async fn test_users_table() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_grants_table() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    for sql in [
+        "create database grants_db",
+        "create table grants_db.t(a int)",
+        "create user grants_user identified by 'password'",
+        "create role grants_role",
+        "grant select on grants_db.t to grants_user",
+        "grant insert on grants_db.* to role grants_role",
+        "grant role grants_role to grants_user",
+    ] {
+        fixture.execute_command(sql).await?;
+    }
+
+    let blocks = fixture
+        .execute_query(
+            "select grantee_type, grantee_name, object_type, object_name, object_id is not null, privileges \
+            from system.grants where grantee_name like 'grants_%' order by grantee_type",
+        )
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let block = DataBlock::concat(&blocks)?;
+    let rows = (0..block.num_rows())
+        .map(|row| {
+            block
+                .columns()
+                .iter()
+                .map(|entry| match entry.value.index(row) {
+                    Some(ScalarRef::String(v)) => v.to_string(),
+                    Some(ScalarRef::Boolean(v)) => v.to_string(),
+                    other => panic!("{other:?}"),
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    // Only the privileges granted directly are listed, the granted objects are named.
+    assert_eq!(rows, vec![
+        vec![
+            "ROLE",
+            "grants_role",
+            "DATABASE",
+            "default.grants_db.*",
+            "true",
+            "INSERT"
+        ],
+        vec![
+            "USER",
+            "grants_user",
+            "TABLE",
+            "default.grants_db.t",
+            "true",
+            "SELECT"
+        ],
+    ]);
+
+    // The objects dropped since are left out.
+    fixture.execute_command("drop table grants_db.t").await?;
+    let blocks = fixture
+        .execute_query("select * from system.grants where grantee_name = 'grants_user'")
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(blocks.iter().map(|b| b.num_rows()).sum::<usize>(), 0);
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_caches_table() -> Result<()> {
     let mut mint = Mint::new("tests/it/storages/testdata");
@@ -445,3 +1052,978 @@ async fn test_caches_table() -> Result<()> {
 
     Ok(())
 }
+
+async fn catalog_scope(
+    fixture: &TestFixture,
+    sql: &str,
+    database_column: &str,
+    table_column: &str,
+) -> Result<CatalogScope> {
+    let source = data_source(fixture, sql).await?;
+    Ok(CatalogScope::from_push_downs(
+        &source.push_downs,
+        database_column,
+        table_column,
+    ))
+}
+
+async fn data_source(fixture: &TestFixture, sql: &str) -> Result<DataSourcePlan> {
+    let ctx = fixture.new_query_ctx().await?;
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    let Plan::Query {
+        s_expr,
+        metadata,
+        bind_context,
+        ..
+    } = plan
+    else {
+        unreachable!("Query plan expected")
+    };
+    let mut builder = PhysicalPlanBuilder::new(metadata.clone(), ctx, false);
+    let physical_plan = builder.build(&s_expr, bind_context.column_set()).await?;
+    Ok(physical_plan.try_find_single_data_source().unwrap().clone())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_locks_table() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    fixture.create_default_table().await?;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let catalog = fixture.default_catalog_name();
+    let table_id = fixture.latest_default_table().await?.get_id();
+
+    let holder_session = fixture.new_session_with_type(SessionType::Dummy).await?;
+    let holder_ctx = holder_session.create_query_context().await?;
+    let guard = holder_ctx
+        .clone()
+        .acquire_table_lock(&catalog, &db, &tbl, &LockTableOption::LockNoRetry)
+        .await?;
+    assert!(guard.is_some());
+
+    let waiter_session = fixture.new_session_with_type(SessionType::Dummy).await?;
+    let waiter_ctx = waiter_session.create_query_context().await?;
+    let waiter = tokio::spawn({
+        let (catalog, db, tbl) = (catalog.clone(), db.clone(), tbl.clone());
+        let waiter_ctx = waiter_ctx.clone();
+        async move {
+            waiter_ctx
+                .acquire_table_lock(&catalog, &db, &tbl, &LockTableOption::LockWithRetry)
+                .await
+        }
+    });
+
+    let sql = format!(
+        "select status, object, session_id, query_id, acquired_on is not null \
+        from system.locks where table_id = {table_id} order by revision"
+    );
+    let mut locks = vec![];
+    for _ in 0..100 {
+        let blocks = fixture
+            .execute_query(&sql)
+            .await?
+            .try_collect::<Vec<_>>()
+            .await?;
+        let block = DataBlock::concat(&blocks)?;
+        let string = |column: usize, row: usize| match block.get_by_offset(column).value.index(row)
+        {
+            Some(ScalarRef::String(v)) => v.to_string(),
+            other => panic!("{other:?}"),
+        };
+        locks = (0..block.num_rows())
+            .map(|row| {
+                let acquired = block.get_by_offset(4).value.index(row);
+                (
+                    string(0, row),
+                    string(1, row),
+                    string(2, row),
+                    string(3, row),
+                    matches!(acquired, Some(ScalarRef::Boolean(true))),
+                )
+            })
+            .collect::<Vec<_>>();
+        if locks.len() == 2 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+
+    let object = format!("'{db}'.'{tbl}'");
+    assert_eq!(locks, vec![
+        (
+            "HOLDING".to_string(),
+            object.clone(),
+            holder_session.get_id(),
+            holder_ctx.get_id(),
+            true,
+        ),
+        (
+            "WAITING".to_string(),
+            object,
+            waiter_session.get_id(),
+            waiter_ctx.get_id(),
+            false,
+        ),
+    ]);
+
+    // Once released, the lock is handed over to the waiter.
+    drop(guard);
+    let waiter_guard = waiter.await.unwrap()?;
+    assert!(waiter_guard.is_some());
+
+    Ok(())
+}
+
+async fn explain_lines(fixture: &TestFixture, sql: &str) -> Result<Vec<String>> {
+    let blocks = fixture
+        .execute_query(sql)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let block = DataBlock::concat(&blocks)?;
+    Ok((0..block.num_rows())
+        .map(|row| match block.get_by_offset(0).value.index(row) {
+            Some(ScalarRef::String(line)) => line.to_string(),
+            other => panic!("{other:?}"),
+        })
+        .collect())
+}
+
+/// The rows and bytes the scans of the query have read.
+async fn scan_progress(fixture: &TestFixture, sql: &str) -> Result<(Vec<DataBlock>, usize)> {
+    let ctx = fixture.new_query_ctx().await?;
+    let blocks = execute_query(ctx.clone(), sql)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    Ok((blocks, ctx.get_scan_progress_value().bytes))
+}
+
+async fn rows(fixture: &TestFixture, sql: &str) -> Result<Vec<String>> {
+    let blocks = fixture
+        .execute_query(sql)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let formatted = pretty_format_blocks(&blocks)?;
+    Ok(formatted.lines().map(|l| l.to_string()).collect())
+}
+
+async fn sorted_rows(fixture: &TestFixture, sql: &str) -> Result<Vec<String>> {
+    let mut lines = rows(fixture, sql).await?;
+    lines.sort();
+    Ok(lines)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_system_tables_projection_push_down() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    // The scans read the projected columns only.
+    let (blocks, bytes) = scan_progress(&fixture, "select dummy from system.one").await?;
+    assert_eq!(bytes, 1);
+    assert_eq!(blocks[0].num_columns(), 1);
+    let (blocks, bytes) = scan_progress(&fixture, "select 1 from system.one").await?;
+    assert_eq!(bytes, 0);
+    assert_eq!(blocks[0].num_rows(), 1);
+
+    let (_, all_bytes) = scan_progress(&fixture, "select * from system.configs").await?;
+    let (_, name_bytes) = scan_progress(&fixture, "select name from system.configs").await?;
+    let (blocks, no_bytes) = scan_progress(&fixture, "select 1 from system.configs").await?;
+    assert!(
+        0 < name_bytes && name_bytes < all_bytes,
+        "{name_bytes} {all_bytes}"
+    );
+    assert_eq!(no_bytes, 0);
+    assert!(blocks.iter().map(|b| b.num_rows()).sum::<usize>() > 0);
+
+    // The columns are returned in the requested order, not in the order of the table.
+    let sql =
+        "select name, `group` from system.configs where `group` = 'query' and name = 'tenant_id'";
+    let lines = rows(&fixture, sql).await?;
+    let row = lines
+        .iter()
+        .find(|l| l.contains("'tenant_id'"))
+        .unwrap_or_else(|| panic!("{lines:?}"));
+    assert!(row.find("'tenant_id'") < row.find("'query'"), "{row}");
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_metadata_tables_stable_order() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    // Created out of order, listed in order.
+    for db in ["order_c", "order_a", "order_b"] {
+        fixture
+            .execute_command(&format!("create database {db}"))
+            .await?;
+        for table in ["t3", "t1", "t2"] {
+            fixture
+                .execute_command(&format!("create table {db}.{table}(z int, a int, m int)"))
+                .await?;
+        }
+    }
+
+    for (sql, sorted) in [
+        ("select catalog, name from system.databases", true),
+        ("select catalog, database, name from system.tables", true),
+        ("select name from system.settings", true),
+        ("select name, is_aggregate from system.functions", true),
+        // The columns of a table keep the order of its schema.
+        (
+            "select database, table, name from system.columns where database like 'order_%'",
+            false,
+        ),
+    ] {
+        let first = rows(&fixture, sql).await?;
+        let second = rows(&fixture, sql).await?;
+        assert_eq!(first, second, "{sql}");
+
+        let data = &first[3..first.len() - 1];
+        if sorted {
+            let mut expected = data.to_vec();
+            expected.sort();
+            assert_eq!(data, expected.as_slice(), "{sql}");
+        }
+    }
+
+    let columns = rows(
+        &fixture,
+        "select database, table, name from system.columns where database like 'order_%'",
+    )
+    .await?;
+    let expected = ["order_a", "order_b", "order_c"]
+        .iter()
+        .flat_map(|db| {
+            ["t1", "t2", "t3"].iter().flat_map(move |table| {
+                ["z", "a", "m"]
+                    .iter()
+                    .map(move |column| (db.to_string(), table.to_string(), column.to_string()))
+            })
+        })
+        .collect::<Vec<_>>();
+    let actual = columns[3..columns.len() - 1]
+        .iter()
+        .map(|line| {
+            let cells = line
+                .trim_matches('|')
+                .split('|')
+                .map(|cell| cell.trim().trim_matches('\'').to_string())
+                .collect::<Vec<_>>();
+            (cells[0].clone(), cells[1].clone(), cells[2].clone())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(actual, expected);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_catalog_tables_filter_push_down() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    for db in ["push_down_a", "push_down_b"] {
+        fixture
+            .execute_command(&format!("create database {db}"))
+            .await?;
+        for table in ["t1", "t2"] {
+            fixture
+                .execute_command(&format!("create table {db}.{table}(a int, b string)"))
+                .await?;
+        }
+    }
+
+    let names = |values: &[&str]| Some(values.iter().map(|v| v.to_string()).collect::<Vec<_>>());
+    // (query, database and table columns, scope, the same query without push down)
+    let cases = [
+        (
+            "select * from system.columns where database = 'push_down_a' and table = 't1'",
+            ("database", "table"),
+            CatalogScope {
+                databases: names(&["push_down_a"]),
+                tables: names(&["t1"]),
+            },
+            "select * from system.columns where concat(database, '') = 'push_down_a' and concat(table, '') = 't1'",
+        ),
+        (
+            "select * from system.columns where database in ('push_down_a', 'push_down_b', 'missing') and table = 't2'",
+            ("database", "table"),
+            CatalogScope {
+                databases: names(&["push_down_a", "push_down_b", "missing"]),
+                tables: names(&["t2"]),
+            },
+            "select * from system.columns where concat(database, '') in ('push_down_a', 'push_down_b', 'missing') and concat(table, '') = 't2'",
+        ),
+        (
+            "select * from system.columns where database like 'push_down_%' or table = 't1'",
+            ("database", "table"),
+            CatalogScope::default(),
+            "select * from system.columns where concat(database, '') like 'push_down_%' or concat(table, '') = 't1'",
+        ),
+        (
+            "select database, name, engine from system.tables where database = 'push_down_b' and name in ('t1', 'missing')",
+            ("database", "name"),
+            CatalogScope {
+                databases: names(&["push_down_b"]),
+                tables: names(&["t1", "missing"]),
+            },
+            "select database, name, engine from system.tables where concat(database, '') = 'push_down_b' and concat(name, '') in ('t1', 'missing')",
+        ),
+    ];
+
+    for (sql, (database_column, table_column), expected, baseline) in cases {
+        let scope = catalog_scope(&fixture, sql, database_column, table_column).await?;
+        assert_eq!(scope, expected, "{sql}");
+
+        let baseline_scope =
+            catalog_scope(&fixture, baseline, database_column, table_column).await?;
+        assert_eq!(baseline_scope, CatalogScope::default(), "{baseline}");
+
+        let rows = sorted_rows(&fixture, sql).await?;
+        assert_eq!(rows, sorted_rows(&fixture, baseline).await?, "{sql}");
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_table_functions_table() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let blocks = fixture
+        .execute_query("select name, signature, example from system.table_functions")
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let block = DataBlock::concat(&blocks)?;
+
+    let mut rows = Vec::with_capacity(block.num_rows());
+    for row in 0..block.num_rows() {
+        let value = |offset: usize| match block.get_by_offset(offset).value.index(row) {
+            Some(ScalarRef::String(v)) => Some(v.to_string()),
+            Some(ScalarRef::Null) => None,
+            other => panic!("{other:?}"),
+        };
+        rows.push((value(0).unwrap(), value(1), value(2)));
+    }
+
+    // Listed once each, by name.
+    let names = rows.iter().map(|r| r.0.clone()).collect::<Vec<_>>();
+    let mut sorted = names.clone();
+    sorted.sort();
+    sorted.dedup();
+    assert_eq!(names, sorted);
+
+    let find = |name: &str| rows.iter().find(|r| r.0 == name).unwrap().clone();
+    assert_eq!(
+        find("numbers"),
+        (
+            "numbers".to_string(),
+            Some("numbers(count)".to_string()),
+            Some("SELECT * FROM numbers(10)".to_string())
+        )
+    );
+    // A function without a description is still listed.
+    assert_eq!(find("unnest"), ("unnest".to_string(), None, None));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_tables_table_statistics() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.execute_command("create database stats_db").await?;
+    fixture
+        .execute_command("create table stats_db.t(a int, b string)")
+        .await?;
+    for _ in 0..2 {
+        fixture
+            .execute_command("insert into stats_db.t values (1, 'a'), (2, 'b'), (3, 'c')")
+            .await?;
+    }
+
+    let columns = [
+        "num_rows",
+        "data_size",
+        "data_compressed_size",
+        "number_of_segments",
+        "number_of_blocks",
+    ];
+    let sql = format!(
+        "select {} from system.tables where database = 'stats_db' and name = 't'",
+        columns.join(", ")
+    );
+    let blocks = fixture
+        .execute_query(&sql)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let block = DataBlock::concat(&blocks)?;
+    assert_eq!(block.num_rows(), 1);
+
+    let value = |offset: usize| match block.get_by_offset(offset).value.index(0) {
+        Some(ScalarRef::Number(NumberScalar::UInt64(v))) => v,
+        other => panic!("{}: {other:?}", columns[offset]),
+    };
+    assert_eq!(value(0), 6);
+    assert!(value(1) > 0);
+    assert!(value(2) > 0);
+    // Writes may be compacted afterwards, only the rows are exact.
+    assert!(value(3) > 0);
+    assert!(value(4) > 0);
+
+    // Tables of an engine without statistics report NULL.
+    fixture
+        .execute_command("create table stats_db.m(a int) engine = memory")
+        .await?;
+    let blocks = fixture
+        .execute_query("select num_rows, data_size from system.tables where database = 'stats_db' and name = 'm'")
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let block = DataBlock::concat(&blocks)?;
+    assert_eq!(block.num_rows(), 1);
+    for entry in block.columns() {
+        assert_eq!(entry.value.index(0), Some(ScalarRef::Null));
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_system_tables_estimated_rows() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture
+        .execute_command("create database estimate_db")
+        .await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    // The read is planned with the estimated rows, never marked exact.
+    let table = DatabasesTable::create(1);
+    let source_plan = table
+        .read_plan(ctx.clone(), None, None, false, true)
+        .await?;
+    assert!(source_plan.statistics.read_rows >= 1);
+    assert!(!source_plan.statistics.is_exact);
+
+    // The estimate is not taken as the row count, `count(*)` still scans the table.
+    let sql = "explain select count(*) from system.databases";
+    let lines = explain_lines(&fixture, sql).await?;
+    assert!(
+        lines
+            .iter()
+            .any(|line| line.contains("table: default.system.databases")),
+        "{sql}: {lines:#?}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_information_schema_push_down() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.execute_command("create database info_db").await?;
+    fixture
+        .execute_command("create table info_db.t(a int not null)")
+        .await?;
+    fixture
+        .execute_command("create view info_db.v as select a from info_db.t")
+        .await?;
+
+    // The predicates on the renamed columns reach the scans of the system tables.
+    for (sql, system_table) in [
+        (
+            "explain select * from information_schema.schemata where schema_name = 'info_db'",
+            "system.databases",
+        ),
+        (
+            "explain select * from information_schema.tables where table_schema = 'info_db' and table_name = 't'",
+            "system.tables",
+        ),
+        (
+            "explain select * from information_schema.columns where table_schema = 'info_db' and table_name = 't'",
+            "system.columns",
+        ),
+        (
+            "explain select * from information_schema.views where table_schema = 'info_db'",
+            "system.views",
+        ),
+    ] {
+        let lines = explain_lines(&fixture, sql).await?;
+        let scan = lines
+            .iter()
+            .position(|line| line.contains(&format!("table: default.{system_table}")))
+            .unwrap_or_else(|| panic!("{sql}: {lines:#?}"));
+        let push_downs = lines[scan..]
+            .iter()
+            .find(|line| line.contains("push downs:"))
+            .unwrap_or_else(|| panic!("{sql}: {lines:#?}"));
+        assert!(push_downs.contains("'info_db'"), "{sql}: {lines:#?}");
+    }
+
+    // The rows are those of the underlying tables.
+    let rows = rows(
+        &fixture,
+        "select concat(table_name, ' ', column_name, ' ', ordinal_position::string, ' ', \
+         is_nullable, ' ', data_type) from information_schema.columns \
+         where table_schema = 'info_db' order by table_name",
+    )
+    .await?;
+    for expected in ["t a 1 NO int", "v a 1 NO int"] {
+        assert!(rows.iter().any(|r| r.contains(expected)), "{rows:#?}");
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_processes_node_filter_push_down() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    let nodes = |values: &[&str]| values.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+    let cases = [
+        (
+            "select * from system.processes where node = 'node-2'",
+            PartitionsShuffleKind::BroadcastTo(nodes(&["node-2"])),
+        ),
+        (
+            "select * from system.processes where node in ('node-1', 'node-3') and user = 'root'",
+            PartitionsShuffleKind::BroadcastTo(nodes(&["node-1", "node-3"])),
+        ),
+        (
+            "select * from system.processes where node like 'node-%'",
+            PartitionsShuffleKind::Broadcast,
+        ),
+        (
+            "select * from system.processes",
+            PartitionsShuffleKind::Broadcast,
+        ),
+    ];
+
+    for (sql, expected) in cases {
+        let source = data_source(&fixture, sql).await?;
+        assert_eq!(source.parts.kind, expected, "{sql}");
+    }
+
+    // A single node reads its own rows and the predicate is evaluated on them.
+    let sql = "select * from system.processes where node = 'missing'";
+    assert_eq!(
+        sorted_rows(&fixture, sql).await?,
+        sorted_rows(&fixture, "select * from system.processes limit 0").await?
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_system_tables_build_read_pipeline() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    // Every system table reads through its own sources, none goes through a stream adapter.
+    let catalog = ctx.get_catalog("default").await?;
+    let tables = catalog.list_tables(&ctx.get_tenant(), "system").await?;
+    assert!(!tables.is_empty());
+    for table in tables {
+        let source_plan = table
+            .read_plan(ctx.clone(), None, None, false, true)
+            .await?;
+        let mut pipeline = Pipeline::create();
+        table
+            .read_data(ctx.clone(), &source_plan, &mut pipeline, false)
+            .unwrap_or_else(|e| panic!("system.{}: {e}", table.name()));
+        assert!(!pipeline.is_empty(), "system.{}", table.name());
+    }
+
+    Ok(())
+}
+
+#[derive(Clone)]
+struct TestLogElement {
+    id: u64,
+    time: i64,
+}
+
+impl SystemLogElement for TestLogElement {
+    const TABLE_NAME: &'static str = "test_log";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![TableField::new(
+            "id",
+            TableDataType::Number(NumberDataType::UInt64),
+        )])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        columns[0].push(Scalar::Number(NumberScalar::UInt64(self.id)).as_ref());
+        Ok(())
+    }
+
+    fn event_time(&self) -> Option<i64> {
+        Some(self.time)
+    }
+}
+
+fn log_ids(queue: &SystemLogQueue<TestLogElement>) -> Vec<u64> {
+    let mut ids = queue
+        .data
+        .read()
+        .event_queue
+        .iter()
+        .flatten()
+        .map(|e| e.id)
+        .collect::<Vec<_>>();
+    ids.sort();
+    ids
+}
+
+#[test]
+fn test_system_log_queue_retention() -> Result<()> {
+    let now = Utc::now().timestamp_micros();
+    let queue = SystemLogQueue::<TestLogElement>::create(10);
+
+    // Fill, then truncate.
+    for id in 0..5 {
+        queue.append_data(TestLogElement { id, time: now })?;
+    }
+    assert_eq!(log_ids(&queue), vec![0, 1, 2, 3, 4]);
+    queue.clear();
+    assert!(log_ids(&queue).is_empty());
+
+    // Past the max rows, the oldest rows are gone, also after the ring buffer wraps.
+    let retention = LogRetention {
+        max_rows: 3,
+        max_age: None,
+    };
+    for id in 0..25 {
+        queue.append_data_with_retention(TestLogElement { id, time: now }, retention)?;
+    }
+    assert_eq!(log_ids(&queue), vec![22, 23, 24]);
+
+    // Without a max rows, the capacity applies.
+    for id in 25..40 {
+        queue.append_data(TestLogElement { id, time: now })?;
+    }
+    assert_eq!(log_ids(&queue), (30..40).collect::<Vec<_>>());
+
+    // The rows older than the max age are evicted as new ones arrive.
+    queue.clear();
+    let hour = 3_600_000_000;
+    for id in 0..3 {
+        queue.append_data(TestLogElement {
+            id,
+            time: now - 3 * hour + id as i64 * hour,
+        })?;
+    }
+    let retention = LogRetention {
+        max_rows: 0,
+        max_age: Some(Duration::from_secs(90 * 60)),
+    };
+    queue.append_data_with_retention(TestLogElement { id: 3, time: now }, retention)?;
+    assert_eq!(log_ids(&queue), vec![2, 3]);
+
+    Ok(())
+}
+
+#[test]
+fn test_system_log_queue_truncate_with_readers() -> Result<()> {
+    let queue = SystemLogQueue::<TestLogElement>::create(100);
+    let readers = (0..4)
+        .map(|_| {
+            let queue = queue.clone();
+            std::thread::spawn(move || {
+                for _ in 0..1000 {
+                    // A reader sees the rows before or after a truncate, never a part of them.
+                    let ids = log_ids(&queue);
+                    assert!(ids.is_empty() || ids.len() == 50, "{ids:?}");
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for _ in 0..200 {
+        let mut data = queue.data.write();
+        for id in 0..50 {
+            data.event_queue[id as usize] = Some(TestLogElement { id, time: 0 });
+        }
+        drop(data);
+        queue.clear();
+    }
+    for reader in readers {
+        reader.join().unwrap();
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_system_log_table_parallel_read() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+    ctx.get_settings().set_max_threads(4)?;
+    ctx.get_settings()
+        .set_setting("max_block_size".to_string(), "100".to_string())?;
+
+    let table = Arc::new(SystemLogTable::<TestLogElement>::create(1, 1000));
+    let queue = SystemLogQueue::<TestLogElement>::instance()?;
+    queue.clear();
+
+    let read_ids = |source_plan: DataSourcePlan| {
+        let ctx = ctx.clone();
+        let table = table.clone();
+        async move {
+            let stream = table.read_data_block_stream(ctx, &source_plan).await?;
+            let blocks = stream.try_collect::<Vec<_>>().await?;
+            let block = DataBlock::concat(&blocks)?;
+            let mut ids = (0..block.num_rows())
+                .map(|row| match block.get_by_offset(0).value.index(row) {
+                    Some(ScalarRef::Number(NumberScalar::UInt64(v))) => v,
+                    other => panic!("{other:?}"),
+                })
+                .collect::<Vec<_>>();
+            ids.sort();
+            Result::Ok(ids)
+        }
+    };
+
+    // Few rows are read by a single part.
+    for id in 0..10 {
+        queue.append_data(TestLogElement { id, time: 0 })?;
+    }
+    let source_plan = table
+        .read_plan(ctx.clone(), None, None, false, true)
+        .await?;
+    assert_eq!(source_plan.parts.len(), 1);
+    assert_eq!(read_ids(source_plan).await?, (0..10).collect::<Vec<_>>());
+
+    // Many rows are split across a part per thread, each row read once.
+    for id in 10..1000 {
+        queue.append_data(TestLogElement { id, time: 0 })?;
+    }
+    let source_plan = table
+        .read_plan(ctx.clone(), None, None, false, true)
+        .await?;
+    assert_eq!(source_plan.parts.len(), 4);
+    assert_eq!(read_ids(source_plan).await?, (0..1000).collect::<Vec<_>>());
+
+    // A limit bounds the rows, and so the parts.
+    let push_downs = PushDownInfo {
+        limit: Some(150),
+        ..Default::default()
+    };
+    let source_plan = table
+        .read_plan(ctx.clone(), Some(push_downs), None, false, true)
+        .await?;
+    assert_eq!(source_plan.parts.len(), 2);
+    assert_eq!(read_ids(source_plan).await?.len(), 150);
+
+    queue.clear();
+    Ok(())
+}
+
+struct UserLogElement {
+    id: u64,
+    user: UserIdentity,
+}
+
+impl SystemLogElement for UserLogElement {
+    const TABLE_NAME: &'static str = "user_log";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![TableField::new(
+            "id",
+            TableDataType::Number(NumberDataType::UInt64),
+        )])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        columns[0].push(Scalar::Number(NumberScalar::UInt64(self.id)).as_ref());
+        Ok(())
+    }
+
+    fn user(&self) -> Option<UserIdentity> {
+        Some(self.user.clone())
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_system_log_table_user_filter() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    let table = Arc::new(SystemLogTable::<UserLogElement>::create(1, 1000));
+    let queue = SystemLogQueue::<UserLogElement>::instance()?;
+    for id in 0..12 {
+        // The third user shares the name of the second one but not its host.
+        let user = match id % 3 {
+            0 => UserIdentity::new("root", "%"),
+            1 => UserIdentity::new("reader", "%"),
+            _ => UserIdentity::new("reader", "10.0.0.1"),
+        };
+        queue.append_data(UserLogElement { id, user })?;
+    }
+
+    let read_ids = |ctx: Arc<QueryContext>| {
+        let table = table.clone();
+        async move {
+            let source_plan = table
+                .read_plan(ctx.clone(), None, None, false, true)
+                .await?;
+            let stream = table.read_data_block_stream(ctx, &source_plan).await?;
+            let blocks = stream.try_collect::<Vec<_>>().await?;
+            let block = DataBlock::concat(&blocks)?;
+            let mut ids = (0..block.num_rows())
+                .map(|row| match block.get_by_offset(0).value.index(row) {
+                    Some(ScalarRef::Number(NumberScalar::UInt64(v))) => v,
+                    other => panic!("{other:?}"),
+                })
+                .collect::<Vec<_>>();
+            ids.sort();
+            Result::Ok(ids)
+        }
+    };
+
+    // The root user holds the SUPER privilege and reads the events of every user.
+    assert_eq!(read_ids(ctx.clone()).await?, (0..12).collect::<Vec<_>>());
+
+    // Other users only read their own events, told apart by both the name and the host.
+    ctx.get_current_session()
+        .set_authed_user(UserInfo::new("reader", "%", AuthInfo::None), None)
+        .await?;
+    assert_eq!(read_ids(ctx.clone()).await?, vec![1, 4, 7, 10]);
+
+    // The user to filter by comes with the parts, without them nothing is read.
+    let mut source_plan = table
+        .read_plan(ctx.clone(), None, None, false, true)
+        .await?;
+    source_plan.parts.partitions.clear();
+    let stream = table.read_data_block_stream(ctx, &source_plan).await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+    assert_eq!(blocks.iter().map(|b| b.num_rows()).sum::<usize>(), 0);
+
+    queue.clear();
+    Ok(())
+}
+
+/// A system table building a block not matching its schema.
+struct MismatchedTable {
+    table_info: TableInfo,
+}
+
+impl SyncSystemTable for MismatchedTable {
+    const NAME: &'static str = "system.mismatched";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, _ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        Ok(DataBlock::new_from_columns(vec![StringType::from_data(
+            vec!["1"],
+        )]))
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_sync_system_table_schema_mismatch() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    let table = SyncOneBlockSystemTable::create(MismatchedTable {
+        table_info: TableInfo {
+            name: "mismatched".to_string(),
+            ident: TableIdent::new(1, 0),
+            meta: TableMeta {
+                schema: TableSchemaRefExt::create(vec![TableField::new(
+                    "id",
+                    TableDataType::Number(NumberDataType::UInt64),
+                )]),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    });
+    let source_plan = table
+        .read_plan(ctx.clone(), None, None, false, true)
+        .await?;
+    let error = table
+        .read_data_block_stream(ctx, &source_plan)
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        error.message(),
+        "The column id of system.mismatched is String, but its schema is UInt64"
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_query_profile_table() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let sql = "select number % 3, sum(number) from numbers(1000) group by number % 3";
+    let (plan, _) = Planner::new(ctx.clone()).plan_sql(sql).await?;
+    let executor = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    executor
+        .execute(ctx.clone())
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let query_id = ctx.get_id();
+
+    let blocks = fixture
+        .execute_query(&format!(
+            "select operator_name, parent_id is null, output_rows from system.query_profile where query_id = '{query_id}'"
+        ))
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let block = DataBlock::concat(&blocks)?;
+    let rows = (0..block.num_rows())
+        .map(|row| {
+            let name = match block.get_by_offset(0).value.index(row) {
+                Some(ScalarRef::String(v)) => v.to_string(),
+                other => panic!("{other:?}"),
+            };
+            let is_root = match block.get_by_offset(1).value.index(row) {
+                Some(ScalarRef::Boolean(v)) => v,
+                other => panic!("{other:?}"),
+            };
+            let output_rows = match block.get_by_offset(2).value.index(row) {
+                Some(ScalarRef::Number(NumberScalar::UInt64(v))) => v,
+                other => panic!("{other:?}"),
+            };
+            (name, is_root, output_rows)
+        })
+        .collect::<Vec<_>>();
+
+    // The source reads the numbers, the aggregate groups them and the root has no parent.
+    assert!(
+        rows.iter()
+            .any(|(name, _, output_rows)| name == "TableScan" && *output_rows == 1000),
+        "{rows:?}"
+    );
+    assert!(
+        rows.iter()
+            .any(|(name, _, output_rows)| name == "AggregateFinal" && *output_rows == 3),
+        "{rows:?}"
+    );
+    assert_eq!(rows.iter().filter(|(_, is_root, _)| *is_root).count(), 1);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_truncate_query_log() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.execute_command("select 1").await?;
+    fixture
+        .execute_command("truncate table system.query_log")
+        .await?;
+
+    Ok(())
+}
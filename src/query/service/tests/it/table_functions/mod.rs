@@ -14,3 +14,4 @@
 
 mod ai_to_sql;
 mod numbers_table;
+mod range_table;
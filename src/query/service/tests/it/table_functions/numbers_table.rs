@@ -75,6 +75,50 @@ async fn test_number_table() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_number_table_scan_progress() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+    ctx.get_settings()
+        .set_setting("max_block_size".to_string(), "1000".to_string())?;
+
+    let tbl_args = TableArgs::new_positioned(vec![Scalar::from(10_000u64)]);
+    let table = NumbersTable::create("system", "numbers_mt", 1, tbl_args)?;
+    let source_plan = table
+        .clone()
+        .as_table()
+        .read_plan(
+            ctx.clone(),
+            Some(PushDownInfo::default()),
+            None,
+            false,
+            true,
+        )
+        .await?;
+    ctx.set_partitions(source_plan.parts.clone())?;
+
+    let stream = table
+        .as_table()
+        .read_data_block_stream(ctx.clone(), &source_plan)
+        .await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    assert!(result.len() > 1);
+
+    // Each generated block is recorded once.
+    let progress = ctx.get_scan_progress_value();
+    assert_eq!(progress.rows, 10_000);
+    assert_eq!(
+        progress.rows,
+        result.iter().map(|b| b.num_rows()).sum::<usize>()
+    );
+    assert_eq!(
+        progress.bytes,
+        result.iter().map(|b| b.memory_size()).sum::<usize>()
+    );
+
+    Ok(())
+}
+
 #[test]
 fn test_util_generate_parts() -> Result<()> {
     {
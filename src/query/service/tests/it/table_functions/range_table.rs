@@ -0,0 +1,52 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_query::sessions::TableContext;
+use databend_query::test_kits::execute_query;
+use databend_query::test_kits::TestFixture;
+use futures::StreamExt;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_generate_series_killed() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+    ctx.get_settings().set_max_threads(1)?;
+
+    let mut stream = execute_query(
+        ctx.clone(),
+        "SELECT * FROM generate_series(0, 1000000000000)",
+    )
+    .await?;
+    assert!(stream.next().await.unwrap().is_ok());
+
+    ctx.get_current_session()
+        .force_kill_query(ErrorCode::AbortedQuery("killed by test"));
+
+    // The blocks already generated are still received, the source doesn't generate any more.
+    let mut blocks = 0;
+    let error = loop {
+        match stream.next().await {
+            Some(Ok(_)) => blocks += 1,
+            Some(Err(error)) => break error,
+            None => panic!("the killed query finished without an error"),
+        }
+        assert!(blocks < 64, "the killed query is still running");
+    };
+    assert_eq!(error.code(), ErrorCode::ABORTED_QUERY);
+
+    Ok(())
+}
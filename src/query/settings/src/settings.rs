@@ -31,10 +31,13 @@ use crate::settings_default::DefaultSettings;
 use crate::settings_default::SettingRange;
 use crate::SettingMode;
 
+/// The source of the effective value of a setting.
 #[derive(serde::Serialize, serde::Deserialize, Clone)]
 pub enum ScopeLevel {
     Default,
+    // Set by the config of the node.
     Local,
+    // Set by `SET GLOBAL` and persisted in the meta service for the tenant.
     Global,
     Session,
 }
@@ -46,7 +49,7 @@ impl Debug for ScopeLevel {
                 write!(f, "DEFAULT")
             }
             ScopeLevel::Local => {
-                write!(f, "LOCAL")
+                write!(f, "CONFIG")
             }
             ScopeLevel::Global => {
                 write!(f, "GLOBAL")
@@ -142,6 +145,12 @@ impl Settings {
         self.changes.remove(k);
     }
 
+    /// Drops the changes of the session, the global and the config changes are kept.
+    pub fn unset_session_settings(&self) {
+        self.changes
+            .retain(|_, v| !matches!(v.level, ScopeLevel::Session));
+    }
+
     pub fn set_batch_settings(&self, settings: &HashMap<String, String>) -> Result<()> {
         for (k, v) in settings.iter() {
             if self.has_setting(k.as_str())? {
@@ -174,6 +183,8 @@ impl Settings {
 pub struct SettingsItem {
     pub name: String,
     pub level: ScopeLevel,
+    // Whether the value comes from a `SET` or `SET GLOBAL` change, decides `level` too.
+    pub changed: bool,
     pub desc: &'static str,
     pub user_value: UserSettingValue,
     pub default_value: UserSettingValue,
@@ -215,6 +226,7 @@ impl<'a> Iterator for SettingsIter<'a> {
                     Some(change_value) => SettingsItem {
                         name: key,
                         level: change_value.level.clone(),
+                        changed: true,
                         desc: default_value.desc,
                         user_value: change_value.value.clone(),
                         default_value: default_value.value,
@@ -224,6 +236,7 @@ impl<'a> Iterator for SettingsIter<'a> {
                         Some(local_value) => SettingsItem {
                             name: key,
                             level: ScopeLevel::Local,
+                            changed: false,
                             desc: default_value.desc,
                             user_value: local_value.clone(),
                             default_value: default_value.value,
@@ -232,6 +245,7 @@ impl<'a> Iterator for SettingsIter<'a> {
                         None => SettingsItem {
                             name: key,
                             level: ScopeLevel::Default,
+                            changed: false,
                             desc: default_value.desc,
                             user_value: default_value.value.clone(),
                             default_value: default_value.value,
@@ -32,6 +32,9 @@ static COST_FACTOR_HASH_TABLE_PER_ROW: u64 = 10;
 static COST_FACTOR_AGGREGATE_PER_ROW: u64 = 5;
 static COST_FACTOR_NETWORK_PER_ROW: u64 = 50;
 
+// Settings bound to a session or a statement, they are rejected by `SET GLOBAL`.
+static SESSION_ONLY_SETTINGS: &[&str] = &["sandbox_tenant", "deduplicate_label"];
+
 // Settings for readability and writability of tags.
 // we will not be able to safely get its value when set to only write.
 // we will not be able to safely set its value when set to only read.
@@ -152,6 +155,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=u64::MAX)),
                 }),
+                ("max_pipeline_memory_usage", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum memory in bytes of the blocks buffered by the processors of a query pipeline, 0 is the same as max_memory_usage.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("data_retention_time_in_days", DefaultSettingValue {
                     // unit of retention_period is day
                     value: UserSettingValue::UInt64(1),
@@ -194,6 +203,36 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=u64::MAX)),
                 }),
+                ("flight_sql_statements_per_second", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(global_conf.map(|conf| conf.query.flight_sql_statements_per_second).unwrap_or(0)),
+                    desc: "Sets the statements a user can run through FlightSQL per second, 0 means no limit.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("flight_sql_statements_burst", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(global_conf.map(|conf| conf.query.flight_sql_statements_burst).unwrap_or(200)),
+                    desc: "Sets the statements a user can run through FlightSQL at once, before the rate applies.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("flight_sql_metadata_per_second", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(global_conf.map(|conf| conf.query.flight_sql_metadata_per_second).unwrap_or(0)),
+                    desc: "Sets the FlightSQL metadata commands a user can run per second, 0 means no limit.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("flight_sql_metadata_burst", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(global_conf.map(|conf| conf.query.flight_sql_metadata_burst).unwrap_or(1000)),
+                    desc: "Sets the FlightSQL metadata commands a user can run at once, before the rate applies.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("prepared_statements_sql_max_length", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1024),
+                    desc: "Sets the chars of the SQL text shown by system.prepared_statements.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("storage_read_buffer_size", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1024 * 1024),
                     desc: "Sets the byte size of the buffer used for reading data into memory.",
@@ -739,6 +778,24 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(1..=u64::MAX)),
                 }),
+                ("idle_session_timeout_secs", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the seconds a session without any query stays open before the server closes it, 0 means never. The pings of the clients don't count as queries.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("pooled_idle_session_timeout_secs", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the seconds a pooled session without any query stays open before the server closes it, 0 means never.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("pooled_session", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Set by the connection pools, the session is closed after pooled_idle_session_timeout_secs instead of idle_session_timeout_secs.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("enable_experimental_queries_executor", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Enables experimental new executor",
@@ -787,6 +844,18 @@ impl DefaultSettings {
                     desc: "Enables loser tree merge sort",
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("autocommit", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Set to 0 to run the statements of the MySQL handler in a transaction until COMMIT or ROLLBACK, as MySQL autocommit.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("strict_transaction_emulation", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Set to 1 to reject the statements which commit implicitly in a transaction, like DDL, or which write outside of it, instead of running them with a warning.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
                 })
             ]);
 
@@ -952,6 +1021,31 @@ impl DefaultSettings {
         }
     }
 
+    /// Checks the setting can be changed for the whole tenant, the read only settings come from
+    /// the config and are immutable at runtime.
+    pub fn check_global_setting(key: &str) -> Result<()> {
+        let default_settings = DefaultSettings::instance()?;
+        let setting_mode = default_settings
+            .settings
+            .get(key)
+            .map(|x| x.mode)
+            .ok_or_else(|| ErrorCode::UnknownVariable(format!("Unknown variable: {:?}", key)))?;
+
+        if matches!(setting_mode, SettingMode::Read) {
+            return Err(ErrorCode::ImmutableVariable(format!(
+                "Variable {:?} is immutable at runtime",
+                key
+            )));
+        }
+        if SESSION_ONLY_SETTINGS.contains(&key) {
+            return Err(ErrorCode::ImmutableVariable(format!(
+                "Variable {:?} can only be set for a session",
+                key
+            )));
+        }
+        Ok(())
+    }
+
     pub fn check_setting_mode(key: &str, expect: SettingMode) -> Result<()> {
         let default_settings = DefaultSettings::instance()?;
         let setting_mode = default_settings
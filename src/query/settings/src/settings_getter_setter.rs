@@ -171,6 +171,13 @@ impl Settings {
         self.try_get_u64("max_memory_usage")
     }
 
+    pub fn get_max_pipeline_memory_usage(&self) -> Result<u64> {
+        match self.try_get_u64("max_pipeline_memory_usage")? {
+            0 => self.get_max_memory_usage(),
+            max_pipeline_memory_usage => Ok(max_pipeline_memory_usage),
+        }
+    }
+
     pub fn set_max_memory_usage(&self, val: u64) -> Result<()> {
         self.try_set_u64("max_memory_usage", val)
     }
@@ -236,6 +243,10 @@ impl Settings {
         self.try_get_string("timezone")
     }
 
+    pub fn set_timezone(&self, val: String) -> Result<()> {
+        self.set_setting("timezone".to_string(), val)
+    }
+
     // Get group by two level threshold
     pub fn get_group_by_two_level_threshold(&self) -> Result<u64> {
         self.try_get_u64("group_by_two_level_threshold")
@@ -257,6 +268,10 @@ impl Settings {
         self.try_get_u64("max_result_rows")
     }
 
+    pub fn set_max_result_rows(&self, val: u64) -> Result<()> {
+        self.try_set_u64("max_result_rows", val)
+    }
+
     pub fn get_enable_dphyp(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_dphyp")? != 0)
     }
@@ -355,6 +370,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_query_result_cache")? != 0)
     }
 
+    pub fn set_enable_query_result_cache(&self, val: bool) -> Result<()> {
+        self.try_set_u64("enable_query_result_cache", u64::from(val))
+    }
+
     pub fn get_query_result_cache_max_bytes(&self) -> Result<usize> {
         Ok(self.try_get_u64("query_result_cache_max_bytes")? as usize)
     }
@@ -367,6 +386,26 @@ impl Settings {
         self.try_get_u64("http_handler_result_timeout_secs")
     }
 
+    pub fn get_flight_sql_statements_per_second(&self) -> Result<u64> {
+        self.try_get_u64("flight_sql_statements_per_second")
+    }
+
+    pub fn get_flight_sql_statements_burst(&self) -> Result<u64> {
+        self.try_get_u64("flight_sql_statements_burst")
+    }
+
+    pub fn get_flight_sql_metadata_per_second(&self) -> Result<u64> {
+        self.try_get_u64("flight_sql_metadata_per_second")
+    }
+
+    pub fn get_flight_sql_metadata_burst(&self) -> Result<u64> {
+        self.try_get_u64("flight_sql_metadata_burst")
+    }
+
+    pub fn get_prepared_statements_sql_max_length(&self) -> Result<usize> {
+        Ok(self.try_get_u64("prepared_statements_sql_max_length")? as usize)
+    }
+
     pub fn get_query_result_cache_ttl_secs(&self) -> Result<u64> {
         self.try_get_u64("query_result_cache_ttl_secs")
     }
@@ -629,6 +668,18 @@ impl Settings {
         self.try_get_u64("idle_transaction_timeout_secs")
     }
 
+    pub fn get_idle_session_timeout_secs(&self) -> Result<u64> {
+        self.try_get_u64("idle_session_timeout_secs")
+    }
+
+    pub fn get_pooled_idle_session_timeout_secs(&self) -> Result<u64> {
+        self.try_get_u64("pooled_idle_session_timeout_secs")
+    }
+
+    pub fn get_pooled_session(&self) -> Result<bool> {
+        Ok(self.try_get_u64("pooled_session")? == 1)
+    }
+
     pub fn get_enable_experimental_queries_executor(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_experimental_queries_executor")? == 1)
     }
@@ -657,4 +708,12 @@ impl Settings {
     pub fn get_enable_loser_tree_merge_sort(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_loser_tree_merge_sort")? == 1)
     }
+
+    pub fn get_autocommit(&self) -> Result<bool> {
+        Ok(self.try_get_u64("autocommit")? != 0)
+    }
+
+    pub fn get_strict_transaction_emulation(&self) -> Result<bool> {
+        Ok(self.try_get_u64("strict_transaction_emulation")? != 0)
+    }
 }
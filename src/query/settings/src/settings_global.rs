@@ -50,6 +50,7 @@ impl Settings {
 
     #[async_backtrace::framed]
     pub async fn set_global_setting(&self, k: String, v: String) -> Result<()> {
+        DefaultSettings::check_global_setting(&k)?;
         let (key, value) = DefaultSettings::convert_value(k.clone(), v)?;
         self.changes.insert(key.clone(), ChangeValue {
             value: value.clone(),
@@ -72,20 +73,31 @@ impl Settings {
         let query_config = &GlobalConfig::instance().query;
         if let Some(parquet_fast_read_bytes) = query_config.parquet_fast_read_bytes {
             self.set_parquet_fast_read_bytes(parquet_fast_read_bytes)?;
+            self.mark_config_change("parquet_fast_read_bytes");
         }
 
         if let Some(max_storage_io_requests) = query_config.max_storage_io_requests {
             self.set_max_storage_io_requests(max_storage_io_requests)?;
+            self.mark_config_change("max_storage_io_requests");
         }
 
         if let Some(enterprise_license_key) = query_config.databend_enterprise_license.clone() {
             unsafe {
                 self.set_enterprise_license(enterprise_license_key)?;
             }
+            self.mark_config_change("enterprise_license");
         }
         Ok(())
     }
 
+    // The setters record a session change, while the value comes from the config and outlives
+    // the session changes.
+    fn mark_config_change(&self, key: &str) {
+        if let Some(mut change) = self.changes.get_mut(key) {
+            change.level = ScopeLevel::Local;
+        }
+    }
+
     async fn load_global_changes(&self) -> Result<(), ErrorCode> {
         let default_settings = DefaultSettings::instance()?;
 
@@ -221,7 +221,7 @@ impl<'a> Binder {
             }
 
             Statement::ShowMetrics { show_options } => self.bind_show_metrics(bind_context, show_options).await?,
-            Statement::ShowProcessList { show_options } => self.bind_show_process_list(bind_context, show_options).await?,
+            Statement::ShowProcessList { full, show_options } => self.bind_show_process_list(bind_context, *full, show_options).await?,
             Statement::ShowEngines { show_options } => self.bind_show_engines(bind_context, show_options).await?,
             Statement::ShowSettings { show_options } => self.bind_show_settings(bind_context, show_options).await?,
             Statement::ShowIndexes { show_options } => self.bind_show_indexes(bind_context, show_options).await?,
@@ -247,6 +247,7 @@ impl<'a> Binder {
             }
             // Columns
             Statement::ShowColumns(stmt) => self.bind_show_columns(bind_context, stmt).await?,
+            Statement::ShowKeys(stmt) => self.bind_show_keys(bind_context, stmt).await?,
             // Tables
             Statement::ShowTables(stmt) => self.bind_show_tables(bind_context, stmt).await?,
             Statement::ShowCreateTable(stmt) => self.bind_show_create_table(stmt).await?,
@@ -276,6 +277,7 @@ impl<'a> Binder {
             Statement::AlterView(stmt) => self.bind_alter_view(stmt).await?,
             Statement::DropView(stmt) => self.bind_drop_view(stmt).await?,
             Statement::ShowViews(stmt) => self.bind_show_views(bind_context, stmt).await?,
+            Statement::ShowCreateView(stmt) => self.bind_show_create_view(stmt).await?,
             Statement::DescribeView(stmt) => self.bind_describe_view(stmt).await?,
 
             // Indexes
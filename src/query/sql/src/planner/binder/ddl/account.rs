@@ -23,6 +23,7 @@ use databend_common_ast::ast::PrincipalIdentity as AstPrincipalIdentity;
 use databend_common_ast::ast::RevokeStmt;
 use databend_common_ast::ast::ShowObjectPrivilegesStmt;
 use databend_common_ast::ast::ShowOptions;
+use databend_common_ast::ast::UserOptionItem;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::principal::AuthInfo;
@@ -30,6 +31,7 @@ use databend_common_meta_app::principal::GrantObject;
 use databend_common_meta_app::principal::PrincipalIdentity;
 use databend_common_meta_app::principal::UserOption;
 use databend_common_meta_app::principal::UserPrivilegeSet;
+use databend_common_meta_app::principal::UserQuota;
 use databend_common_users::UserApiProvider;
 
 use crate::binder::show::get_show_options;
@@ -250,8 +252,12 @@ impl Binder {
             )));
         }
         let mut user_option = UserOption::default();
+        let mut quota = UserQuota::no_limit();
         for option in user_options {
             user_option.apply(option);
+            if let UserOptionItem::SetQuota(item) = option {
+                quota.apply(item);
+            }
         }
         UserApiProvider::instance()
             .verify_password(
@@ -271,6 +277,7 @@ impl Binder {
                 &auth_option.password,
             )?,
             user_option,
+            quota,
             password_update_on: Some(Utc::now()),
         };
         Ok(Plan::CreateUser(Box::new(plan)))
@@ -296,8 +303,12 @@ impl Binder {
         };
 
         let mut user_option = user_info.option.clone();
+        let mut quota = user_info.quota.clone();
         for option in user_options {
             user_option.apply(option);
+            if let UserOptionItem::SetQuota(item) = option {
+                quota.apply(item);
+            }
         }
 
         // None means no change to make
@@ -330,10 +341,16 @@ impl Binder {
         } else {
             Some(user_option)
         };
+        let new_quota = if quota == user_info.quota {
+            None
+        } else {
+            Some(quota)
+        };
         let plan = AlterUserPlan {
             user: user_info.identity(),
             auth_info: new_auth_info,
             user_option: new_user_option,
+            quota: new_quota,
         };
 
         Ok(Plan::AlterUser(Box::new(plan)))
@@ -40,6 +40,7 @@ use databend_common_meta_app::schema::IcebergRestCatalogOption;
 use databend_common_meta_app::storage::StorageParams;
 
 use crate::binder::parse_storage_params_from_uri;
+use crate::binder::show::like_filter;
 use crate::normalize_identifier;
 use crate::plans::CreateCatalogPlan;
 use crate::plans::DropCatalogPlan;
@@ -61,7 +62,7 @@ impl Binder {
         write!(query, "SELECT name AS Catalogs FROM system.catalogs").unwrap();
         match limit {
             Some(ShowLimit::Like { pattern }) => {
-                write!(query, " WHERE name LIKE '{pattern}'").unwrap();
+                write!(query, " WHERE {}", like_filter("name", pattern)).unwrap();
             }
             Some(ShowLimit::Where { selection }) => {
                 write!(query, " WHERE {selection}").unwrap();
@@ -12,11 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_ast::ast::ColumnRef;
+use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::ShowColumnsStmt;
+use databend_common_ast::ast::ShowKeysStmt;
 use databend_common_ast::ast::ShowLimit;
+use databend_common_ast::parser::parse_comma_separated_exprs;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use derive_visitor::Drive;
+use derive_visitor::Visitor;
 use log::debug;
 
+use crate::binder::show::like_filter;
 use crate::normalize_identifier;
 use crate::plans::Plan;
 use crate::plans::RewriteKind;
@@ -96,7 +105,7 @@ impl Binder {
         let query = match limit {
             None => select_builder.build(),
             Some(ShowLimit::Like { pattern }) => {
-                select_builder.with_filter(format!("column_name LIKE '{pattern}'"));
+                select_builder.with_filter(like_filter("column_name", pattern));
                 select_builder.build()
             }
             Some(ShowLimit::Where { selection }) => {
@@ -112,4 +121,146 @@ impl Binder {
         )
         .await
     }
+
+    /// SHOW INDEX in the MySQL 8 layout, the cluster key is the only key of a table.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_show_keys(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &ShowKeysStmt,
+    ) -> Result<Plan> {
+        let ShowKeysStmt {
+            catalog,
+            database,
+            table,
+            limit,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let table_meta = self
+            .ctx
+            .get_table(&catalog, &database, &table)
+            .await
+            .map_err(|err| match err.code() {
+                // The MySQL clients expect ER_NO_SUCH_TABLE.
+                ErrorCode::UNKNOWN_TABLE => {
+                    ErrorCode::UnknownTable(format!("Table '{}.{}' doesn't exist", database, table))
+                }
+                _ => err,
+            })?;
+
+        let mut key_parts = vec![];
+        if let Some((_, cluster_key)) = table_meta.get_table_info().meta.cluster_key() {
+            let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+            let tokens = tokenize_sql(&cluster_key)?;
+            let mut exprs = parse_comma_separated_exprs(&tokens, sql_dialect)?;
+            if let [Expr::Tuple { exprs: tuple, .. }] = exprs.as_slice() {
+                exprs = tuple.clone();
+            }
+
+            let schema = table_meta.schema();
+            for (index, expr) in exprs.iter().enumerate() {
+                let mut visitor = KeyColumnVisitor::default();
+                expr.drive(&mut visitor);
+                let Some(column) = visitor.column else {
+                    continue;
+                };
+                let nullable = schema
+                    .field_with_name(&column)
+                    .map(|field| field.is_nullable())
+                    .unwrap_or(false);
+                let expression = match expr {
+                    Expr::ColumnRef { .. } => "NULL".to_string(),
+                    expr => format!("'{}'", escape_literal(&expr.to_string())),
+                };
+                key_parts.push(format!(
+                    "SELECT '{}' AS `table`, 1 AS `non_unique`, 'CLUSTER' AS `key_name`, {} AS `seq_in_index`, \
+                     '{}' AS `column_name`, 'A' AS `collation`, NULL::UInt64 AS `cardinality`, \
+                     NULL::UInt64 AS `sub_part`, NULL::String AS `packed`, '{}' AS `null`, \
+                     'CLUSTER' AS `index_type`, '' AS `comment`, '' AS `index_comment`, \
+                     'YES' AS `visible`, {} AS `expression`",
+                    escape_literal(&table),
+                    index + 1,
+                    escape_literal(&column),
+                    if nullable { "YES" } else { "" },
+                    expression,
+                ));
+            }
+        }
+
+        // A table without a cluster key still has the columns.
+        let empty = key_parts.is_empty();
+        if empty {
+            key_parts.push(
+                "SELECT '' AS `table`, 1 AS `non_unique`, '' AS `key_name`, 1 AS `seq_in_index`, \
+                 '' AS `column_name`, '' AS `collation`, NULL::UInt64 AS `cardinality`, \
+                 NULL::UInt64 AS `sub_part`, NULL::String AS `packed`, '' AS `null`, \
+                 '' AS `index_type`, '' AS `comment`, '' AS `index_comment`, \
+                 '' AS `visible`, NULL::String AS `expression`"
+                    .to_string(),
+            );
+        }
+
+        let mut select_builder =
+            SelectBuilder::from(&format!("({}) AS t", key_parts.join(" UNION ALL ")));
+        select_builder
+            .with_column("`table` AS `Table`")
+            .with_column("non_unique AS `Non_unique`")
+            .with_column("key_name AS `Key_name`")
+            .with_column("seq_in_index AS `Seq_in_index`")
+            .with_column("column_name AS `Column_name`")
+            .with_column("collation AS `Collation`")
+            .with_column("cardinality AS `Cardinality`")
+            .with_column("sub_part AS `Sub_part`")
+            .with_column("packed AS `Packed`")
+            .with_column("`null` AS `Null`")
+            .with_column("index_type AS `Index_type`")
+            .with_column("comment AS `Comment`")
+            .with_column("index_comment AS `Index_comment`")
+            .with_column("visible AS `Visible`")
+            .with_column("expression AS `Expression`");
+        select_builder.with_order_by("seq_in_index");
+        if empty {
+            select_builder.with_filter("false");
+        }
+
+        let query = match limit {
+            None => select_builder.build(),
+            Some(ShowLimit::Like { pattern }) => {
+                select_builder.with_filter(like_filter("column_name", pattern));
+                select_builder.build()
+            }
+            Some(ShowLimit::Where { selection }) => {
+                select_builder.with_filter(format!("({selection})"));
+                select_builder.build()
+            }
+        };
+        debug!("show keys rewrite to: {:?}", query);
+        self.bind_rewrite_to_query(
+            bind_context,
+            query.as_str(),
+            RewriteKind::ShowKeys(catalog, database, table),
+        )
+        .await
+    }
+}
+
+/// The column a cluster key expression is on, the keys are on exactly one column.
+#[derive(Default, Visitor)]
+#[visitor(ColumnRef(enter))]
+struct KeyColumnVisitor {
+    column: Option<String>,
+}
+
+impl KeyColumnVisitor {
+    fn enter_column_ref(&mut self, column: &ColumnRef) {
+        if self.column.is_none() {
+            self.column = Some(column.column.name().to_string());
+        }
+    }
+}
+
+fn escape_literal(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('\'', "\\'")
 }
@@ -35,6 +35,7 @@ use databend_common_meta_app::share::share_name_ident::ShareNameIdent;
 use databend_common_meta_app::share::share_name_ident::ShareNameIdentRaw;
 use log::debug;
 
+use crate::binder::show::like_filter;
 use crate::binder::Binder;
 use crate::planner::semantic::normalize_identifier;
 use crate::plans::CreateDatabasePlan;
@@ -81,7 +82,7 @@ impl Binder {
 
         match limit {
             Some(ShowLimit::Like { pattern }) => {
-                select_builder.with_filter(format!("name LIKE '{pattern}'"));
+                select_builder.with_filter(like_filter("name", pattern));
             }
             Some(ShowLimit::Where { selection }) => {
                 select_builder.with_filter(format!("({selection})"));
@@ -101,7 +102,11 @@ impl Binder {
         &self,
         stmt: &ShowCreateDatabaseStmt,
     ) -> Result<Plan> {
-        let ShowCreateDatabaseStmt { catalog, database } = stmt;
+        let ShowCreateDatabaseStmt {
+            catalog,
+            database,
+            if_not_exists,
+        } = stmt;
 
         let catalog = catalog
             .as_ref()
@@ -116,6 +121,7 @@ impl Binder {
         Ok(Plan::ShowCreateDatabase(Box::new(ShowCreateDatabasePlan {
             catalog,
             database,
+            if_not_exists: *if_not_exists,
             schema,
         })))
     }
@@ -22,6 +22,7 @@ use databend_common_license::license::Feature;
 use databend_common_license::license_manager::get_license_manager;
 use log::debug;
 
+use crate::binder::show::like_filter;
 use crate::binder::Binder;
 use crate::normalize_identifier;
 use crate::plans::CreateStreamPlan;
@@ -165,7 +166,7 @@ impl Binder {
         if let Some(limit) = limit {
             match limit {
                 ShowLimit::Like { pattern } => {
-                    select_builder.with_filter(format!("name LIKE '{pattern}'"));
+                    select_builder.with_filter(like_filter("name", pattern));
                 }
                 ShowLimit::Where { selection } => {
                     select_builder.with_filter(format!("({selection})"));
@@ -88,6 +88,7 @@ use log::debug;
 use crate::binder::get_storage_params_from_options;
 use crate::binder::parse_storage_params_from_uri;
 use crate::binder::scalar::ScalarBinder;
+use crate::binder::show::like_filter;
 use crate::binder::Binder;
 use crate::binder::ColumnBindingBuilder;
 use crate::binder::Visibility;
@@ -199,7 +200,7 @@ impl Binder {
         let query = match limit {
             None => select_builder.build(),
             Some(ShowLimit::Like { pattern }) => {
-                select_builder.with_filter(format!("name LIKE '{pattern}'"));
+                select_builder.with_filter(like_filter("name", pattern));
                 select_builder.build()
             }
             Some(ShowLimit::Where { selection }) => {
@@ -281,11 +282,16 @@ impl Binder {
 
         let database = self.check_database_exist(&None, database).await?;
 
+        // The sizes are the snapshot statistics of `system.tables`, NULL if the engine has none.
+        // There are no collations in Databend, the strings are compared as the UTF-8 bytes.
         let select_cols = "name AS Name, engine AS Engine, 0 AS Version, \
-        NULL AS Row_format, num_rows AS Rows, NULL AS Avg_row_length, data_size AS Data_length, \
-        NULL AS Max_data_length, index_size AS Index_length, NULL AS Data_free, NULL AS Auto_increment, \
-        created_on AS Create_time, NULL AS Update_time, NULL AS Check_time, NULL AS Collation, \
-        NULL AS Checksum, '' AS Comment, cluster_by as Cluster_by"
+        NULL AS Row_format, num_rows AS Rows, \
+        CASE WHEN num_rows = 0 THEN 0 ELSE data_size // num_rows END AS Avg_row_length, \
+        data_size AS Data_length, NULL AS Max_data_length, index_size AS Index_length, \
+        NULL AS Data_free, NULL AS Auto_increment, created_on AS Create_time, \
+        updated_on AS Update_time, NULL AS Check_time, \
+        CASE WHEN engine = 'VIEW' THEN NULL ELSE 'utf8mb4_bin' END AS Collation, \
+        NULL AS Checksum, comment AS Comment, cluster_by as Cluster_by"
             .to_string();
 
         // Use `system.tables` AS the "base" table to construct the result-set of `SHOW TABLE STATUS ..`
@@ -301,8 +307,10 @@ impl Binder {
             ),
             Some(ShowLimit::Like { pattern }) => format!(
                 "SELECT * from (SELECT {} FROM system.tables WHERE database = '{}') \
-            WHERE Name LIKE '{}' ORDER BY Name",
-                select_cols, database, pattern
+            WHERE {} ORDER BY Name",
+                select_cols,
+                database,
+                like_filter("Name", pattern)
             ),
             Some(ShowLimit::Where { selection }) => format!(
                 "SELECT * from (SELECT {} FROM system.tables WHERE database = '{}') \
@@ -354,7 +362,7 @@ impl Binder {
         let query = match limit {
             None => select_builder.build(),
             Some(ShowLimit::Like { pattern }) => {
-                select_builder.with_filter(format!("name LIKE '{pattern}'"));
+                select_builder.with_filter(like_filter("name", pattern));
                 select_builder.build()
             }
             Some(ShowLimit::Where { selection }) => {
@@ -16,6 +16,7 @@ use databend_common_ast::ast::AlterViewStmt;
 use databend_common_ast::ast::CreateViewStmt;
 use databend_common_ast::ast::DescribeViewStmt;
 use databend_common_ast::ast::DropViewStmt;
+use databend_common_ast::ast::ShowCreateViewStmt;
 use databend_common_ast::ast::ShowLimit;
 use databend_common_ast::ast::ShowViewsStmt;
 use databend_common_exception::Result;
@@ -25,6 +26,7 @@ use databend_common_expression::DataSchemaRefExt;
 use derive_visitor::DriveMut;
 use log::debug;
 
+use crate::binder::show::like_filter;
 use crate::binder::Binder;
 use crate::planner::semantic::normalize_identifier;
 use crate::plans::AlterViewPlan;
@@ -33,6 +35,7 @@ use crate::plans::DescribeViewPlan;
 use crate::plans::DropViewPlan;
 use crate::plans::Plan;
 use crate::plans::RewriteKind;
+use crate::plans::ShowCreateViewPlan;
 use crate::BindContext;
 use crate::SelectBuilder;
 use crate::ViewRewriter;
@@ -199,7 +202,7 @@ impl Binder {
         let query = match limit {
             None => select_builder.build(),
             Some(ShowLimit::Like { pattern }) => {
-                select_builder.with_filter(format!("name LIKE '{pattern}'"));
+                select_builder.with_filter(like_filter("name", pattern));
                 select_builder.build()
             }
             Some(ShowLimit::Where { selection }) => {
@@ -216,6 +219,34 @@ impl Binder {
         .await
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_show_create_view(
+        &mut self,
+        stmt: &ShowCreateViewStmt,
+    ) -> Result<Plan> {
+        let ShowCreateViewStmt {
+            catalog,
+            database,
+            view,
+        } = stmt;
+
+        let (catalog, database, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("View", DataType::String),
+            DataField::new("Create View", DataType::String),
+            DataField::new("character_set_client", DataType::String),
+            DataField::new("collation_connection", DataType::String),
+        ]);
+
+        Ok(Plan::ShowCreateView(Box::new(ShowCreateViewPlan {
+            catalog,
+            database,
+            view_name,
+            schema,
+        })))
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_describe_view(
         &mut self,
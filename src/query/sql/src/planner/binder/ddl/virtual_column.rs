@@ -31,6 +31,7 @@ use databend_common_expression::TableSchemaRef;
 use databend_common_meta_app::schema::ListVirtualColumnsReq;
 use log::debug;
 
+use crate::binder::show::like_filter;
 use crate::binder::Binder;
 use crate::normalize_identifier;
 use crate::plans::AlterVirtualColumnPlan;
@@ -315,7 +316,7 @@ impl Binder {
         let query = match limit {
             None => select_builder.build(),
             Some(ShowLimit::Like { pattern }) => {
-                select_builder.with_filter(format!("virtual_columns LIKE '{pattern}'"));
+                select_builder.with_filter(like_filter("virtual_columns", pattern));
                 select_builder.build()
             }
             Some(ShowLimit::Where { selection }) => {
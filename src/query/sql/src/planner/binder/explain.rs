@@ -28,12 +28,14 @@ pub struct ExplainConfig {
     pub verbose: bool,
     pub logical: bool,
     pub optimized: bool,
+    pub graphviz: bool,
 }
 
 struct ExplainConfigBuilder {
     verbose: bool,
     logical: bool,
     optimized: bool,
+    graphviz: bool,
 }
 
 impl ExplainConfigBuilder {
@@ -42,6 +44,7 @@ impl ExplainConfigBuilder {
             verbose: false,
             logical: false,
             optimized: false,
+            graphviz: false,
         }
     }
 
@@ -53,6 +56,7 @@ impl ExplainConfigBuilder {
                 self.logical = true;
                 self.optimized = true;
             }
+            ExplainOption::Graphviz => self.graphviz = true,
         }
 
         self
@@ -63,6 +67,7 @@ impl ExplainConfigBuilder {
             verbose: self.verbose,
             logical: self.logical,
             optimized: self.optimized,
+            graphviz: self.graphviz,
         }
     }
 }
@@ -146,5 +151,11 @@ fn validate_explain_config(kind: &ExplainKind, config: &ExplainConfig) -> Result
         ));
     }
 
+    if !matches!(kind, ExplainKind::Pipeline) && config.graphviz {
+        return Err(ErrorCode::SyntaxException(
+            "GRAPHVIZ option is only supported for EXPLAIN PIPELINE statement".to_string(),
+        ));
+    }
+
     Ok(())
 }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_ast::ast::quote::QuotedString;
 use databend_common_ast::ast::ShowLimit;
 use databend_common_ast::ast::ShowLocksStmt;
 use databend_common_ast::ast::ShowOptions;
@@ -109,13 +110,24 @@ impl Binder {
     pub(in crate::planner::binder) async fn bind_show_process_list(
         &mut self,
         bind_context: &mut BindContext,
+        full: bool,
         show_options: &Option<ShowOptions>,
     ) -> Result<Plan> {
         let (show_limit, limit_str) =
             get_show_options(show_options, Some("extra_info".to_string()));
+        // The columns of MySQL, `Id` is the numeric connection id KILL accepts.
+        // The statement is cut to 100 characters unless FULL.
+        let info = match full {
+            true => "extra_info",
+            false => "substr(extra_info, 1, 100)",
+        };
         let query = format!(
-            "SELECT * FROM system.processes {} {}",
-            show_limit, limit_str,
+            "SELECT mysql_connection_id AS \"Id\", user AS \"User\", host AS \"Host\", \
+            database AS \"db\", \
+            CASE command WHEN 'Idle' THEN 'Sleep' WHEN 'Aborting' THEN 'Killed' ELSE command END AS \"Command\", \
+            time AS \"Time\", status AS \"State\", NULLIF({}, '') AS \"Info\" \
+            FROM system.processes {} ORDER BY mysql_connection_id {}",
+            info, show_limit, limit_str,
         );
 
         self.bind_rewrite_to_query(bind_context, &query, RewriteKind::ShowProcessList)
@@ -130,8 +142,13 @@ impl Binder {
     ) -> Result<Plan> {
         let (show_limit, limit_str) =
             get_show_options(show_options, Some("\"Engine\"".to_string()));
+        // The columns of MySQL, the clients match on them.
         let query = format!(
-            "SELECT \"Engine\", \"Comment\" FROM system.engines {} ORDER BY \"Engine\" ASC {}",
+            "SELECT * FROM (SELECT \"Engine\", \
+            IF(\"Engine\" = 'FUSE', 'DEFAULT', 'YES') AS \"Support\", \"Comment\", \
+            IF(\"Engine\" = 'FUSE', 'YES', 'NO') AS \"Transactions\", \
+            'NO' AS \"XA\", 'NO' AS \"Savepoints\" FROM system.engines) \
+            {} ORDER BY \"Engine\" ASC {}",
             show_limit, limit_str,
         );
 
@@ -194,12 +211,8 @@ pub(crate) fn get_show_options(
     if let Some(show_option) = show_options {
         match &show_option.show_limit {
             Some(ShowLimit::Like { pattern }) => {
-                // convert like pattern to lowercase to uses case-insensitive pattern matching
-                if let Some(col) = &col {
-                    show_limit = format!("WHERE LOWER({}) LIKE '{}'", col, pattern.to_lowercase());
-                } else {
-                    show_limit = format!("WHERE LOWER(name) LIKE '{}'", pattern.to_lowercase());
-                }
+                let col = col.as_deref().unwrap_or("name");
+                show_limit = format!("WHERE {}", like_filter(col, pattern));
             }
             Some(ShowLimit::Where { selection }) => {
                 show_limit = format!("WHERE {}", selection);
@@ -214,3 +227,12 @@ pub(crate) fn get_show_options(
 
     (show_limit, limit_str)
 }
+
+/// The filter of `SHOW ... LIKE 'pattern'` on a name column. The same as MySQL and
+/// `like_pattern_to_matcher` of the federated and FlightSQL metadata commands, the names
+/// compare case-insensitively and `\` escapes the wildcards.
+pub(crate) fn like_filter(col: &str, pattern: &str) -> String {
+    // The pattern is the unescaped string literal, so it's quoted again.
+    let pattern = QuotedString(pattern.to_lowercase(), '\'');
+    format!("LOWER({col}) LIKE {pattern}")
+}
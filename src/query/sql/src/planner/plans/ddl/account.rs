@@ -27,6 +27,7 @@ use databend_common_meta_app::principal::PrincipalIdentity;
 use databend_common_meta_app::principal::UserIdentity;
 use databend_common_meta_app::principal::UserOption;
 use databend_common_meta_app::principal::UserPrivilegeSet;
+use databend_common_meta_app::principal::UserQuota;
 use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::tenant::Tenant;
 
@@ -36,6 +37,7 @@ pub struct CreateUserPlan {
     pub user: UserIdentity,
     pub auth_info: AuthInfo,
     pub user_option: UserOption,
+    pub quota: UserQuota,
     pub password_update_on: Option<DateTime<Utc>>,
 }
 
@@ -45,6 +47,7 @@ pub struct AlterUserPlan {
     // None means no change to make
     pub auth_info: Option<AuthInfo>,
     pub user_option: Option<UserOption>,
+    pub quota: Option<UserQuota>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
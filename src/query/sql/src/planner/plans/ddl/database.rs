@@ -120,6 +120,7 @@ pub struct UseDatabasePlan {
 pub struct ShowCreateDatabasePlan {
     pub catalog: String,
     pub database: String,
+    pub if_not_exists: bool,
     pub schema: DataSchemaRef,
 }
 
@@ -46,6 +46,20 @@ pub struct DropViewPlan {
     pub view_name: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShowCreateViewPlan {
+    pub catalog: String,
+    pub database: String,
+    pub view_name: String,
+    pub schema: DataSchemaRef,
+}
+
+impl ShowCreateViewPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        self.schema.clone()
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DescribeViewPlan {
     pub catalog: String,
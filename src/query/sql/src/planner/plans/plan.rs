@@ -135,6 +135,7 @@ use crate::plans::ShowConnectionsPlan;
 use crate::plans::ShowCreateCatalogPlan;
 use crate::plans::ShowCreateDatabasePlan;
 use crate::plans::ShowCreateTablePlan;
+use crate::plans::ShowCreateViewPlan;
 use crate::plans::ShowFileFormatsPlan;
 use crate::plans::ShowGrantTenantsOfSharePlan;
 use crate::plans::ShowNetworkPoliciesPlan;
@@ -244,6 +245,7 @@ pub enum Plan {
     CreateView(Box<CreateViewPlan>),
     AlterView(Box<AlterViewPlan>),
     DropView(Box<DropViewPlan>),
+    ShowCreateView(Box<ShowCreateViewPlan>),
     DescribeView(Box<DescribeViewPlan>),
 
     // Streams
@@ -386,6 +388,7 @@ pub enum RewriteKind {
     ShowDatabases,
     ShowTables(String, String),
     ShowColumns(String, String, String),
+    ShowKeys(String, String, String),
     ShowTablesStatus,
     ShowVirtualColumns,
 
@@ -459,6 +462,7 @@ impl Plan {
             Plan::VacuumDropTable(plan) => plan.schema(),
             Plan::VacuumTemporaryFiles(plan) => plan.schema(),
             Plan::ExistsTable(plan) => plan.schema(),
+            Plan::ShowCreateView(plan) => plan.schema(),
             Plan::DescribeView(plan) => plan.schema(),
             Plan::ShowRoles(plan) => plan.schema(),
             Plan::ShowFileFormats(plan) => plan.schema(),
@@ -2866,7 +2866,7 @@ impl<'a> TypeChecker<'a> {
             ),
             ("connection_id", &[]) => Some(self.resolve(&Expr::Literal {
                 span,
-                value: Literal::String(self.ctx.get_connection_id()),
+                value: Literal::UInt64(self.ctx.get_connection_id()),
             })),
             ("timezone", &[]) => {
                 let tz = self.ctx.get_settings().get_timezone().unwrap();
@@ -14,13 +14,18 @@
 
 use std::hash::BuildHasher;
 use std::hash::Hash;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
+use databend_common_cache::Cache;
 use databend_common_cache::Count;
 use databend_common_cache::CountableMeter;
 use databend_common_cache::DefaultHashBuilder;
 use databend_common_metrics::cache::*;
 
+use crate::InMemoryItemCacheHolder;
+
 // The cache accessor, crate users usually working on this interface while manipulating caches
 pub trait CacheAccessor<K, V, S = DefaultHashBuilder, M = Count>
 where
@@ -47,6 +52,7 @@ where Self: Sized
         NamedCache {
             name: name.into(),
             cache: self,
+            counters: Arc::new(CacheCounters::default()),
         }
     }
 }
@@ -58,6 +64,7 @@ impl<T> Named for T where T: Sized + Clone {}
 pub struct NamedCache<C> {
     name: String,
     cache: C,
+    counters: Arc<CacheCounters>,
 }
 
 impl<C> NamedCache<C> {
@@ -67,6 +74,69 @@ impl<C> NamedCache<C> {
     }
 }
 
+/// Statistics of a cache on this node, listed by `system.caches`.
+#[derive(Clone, Debug, Default)]
+pub struct CacheStatistics {
+    pub name: String,
+    /// The capacity and size are measured by the meter of the cache, in items or in bytes.
+    pub capacity: u64,
+    pub size: u64,
+    pub num_items: u64,
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+}
+
+/// Implemented by the caches which report their statistics to `system.caches`.
+pub trait CacheStatisticsProvider: Send + Sync {
+    fn statistics(&self) -> CacheStatistics;
+}
+
+/// Hits and misses of a cache since it is created, shared by the clones of the cache.
+#[derive(Debug, Default)]
+pub struct CacheCounters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl CacheCounters {
+    pub fn hit(&self) {
+        self.hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn miss(&self) {
+        self.misses.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+}
+
+impl<V, S, M> CacheStatisticsProvider for NamedCache<InMemoryItemCacheHolder<V, S, M>>
+where
+    S: BuildHasher,
+    M: CountableMeter<String, Arc<V>>,
+    Self: Send + Sync,
+{
+    fn statistics(&self) -> CacheStatistics {
+        let cache = self.cache.read();
+        CacheStatistics {
+            name: self.name.clone(),
+            capacity: cache.capacity(),
+            size: cache.size(),
+            num_items: cache.len() as u64,
+            hits: self.counters.hits(),
+            misses: self.counters.misses(),
+            evictions: cache.evictions(),
+        }
+    }
+}
+
 pub trait CacheAccessorExt<K, V, S, M> {
     fn get_with_len<Q: AsRef<str>>(&self, k: Q, len: u64) -> Option<Arc<V>>;
 }
@@ -111,10 +181,12 @@ where
         match self.cache.get(k) {
             None => {
                 metrics_inc_cache_miss_count(1, &self.name);
+                self.counters.miss();
                 None
             }
             v @ Some(_) => {
                 metrics_inc_cache_hit_count(1, &self.name);
+                self.counters.hit();
                 v
             }
         }
@@ -20,6 +20,9 @@ mod read;
 
 pub use cache::CacheAccessor;
 pub use cache::CacheAccessorExt;
+pub use cache::CacheCounters;
+pub use cache::CacheStatistics;
+pub use cache::CacheStatisticsProvider;
 pub use cache::Named;
 pub use cache::NamedCache;
 pub use databend_common_cache::CountableMeter;
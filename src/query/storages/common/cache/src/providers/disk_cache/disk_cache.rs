@@ -23,6 +23,7 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::time::Instant;
 
+use databend_common_base::base::ServerWarnings;
 use databend_common_cache::Cache;
 use databend_common_cache::DefaultHashBuilder;
 use databend_common_cache::FileSize;
@@ -97,6 +98,11 @@ where C: Cache<String, u64, DefaultHashBuilder, FileSize> + Send + Sync + 'stati
         self.cache.capacity()
     }
 
+    /// Return the count of files removed to make room for new ones.
+    pub fn evictions(&self) -> u64 {
+        self.cache.evictions()
+    }
+
     /// Return the path in which the cache is stored.
     pub fn path(&self) -> &Path {
         self.root.as_path()
@@ -219,6 +225,13 @@ where C: Cache<String, u64, DefaultHashBuilder, FileSize> + Send + Sync + 'stati
                     "failed to detect the number of parallelism: {}, fallback to 8",
                     e
                 );
+                ServerWarnings::instance().warn(
+                    "disk_cache",
+                    format!(
+                        "failed to detect the number of parallelism: {}, fallback to 8",
+                        e
+                    ),
+                );
                 8
             }
         };
@@ -32,6 +32,9 @@ use log::info;
 use crate::providers::LruDiskCacheHolder;
 use crate::CacheAccessor;
 use crate::CacheAccessorExt;
+use crate::CacheCounters;
+use crate::CacheStatistics;
+use crate::CacheStatisticsProvider;
 use crate::LruDiskCacheBuilder;
 
 struct CacheItem {
@@ -69,6 +72,7 @@ pub struct TableDataCache<T = LruDiskCacheHolder> {
     external_cache: T,
     population_queue: crossbeam_channel::Sender<CacheItem>,
     _cache_populator: DiskCachePopulator,
+    counters: Arc<CacheCounters>,
 }
 
 pub const DISK_TABLE_DATA_CACHE_NAME: &str = "disk_cache_table_data";
@@ -95,6 +99,7 @@ impl TableDataCacheBuilder {
             external_cache: disk_cache.clone(),
             population_queue: tx,
             _cache_populator: DiskCachePopulator::new(rx, disk_cache, num_population_thread)?,
+            counters: Arc::new(CacheCounters::default()),
         })
     }
 }
@@ -122,9 +127,11 @@ impl CacheAccessor<String, Bytes, DefaultHashBuilder, Count> for TableDataCache
         if let Some(item) = self.external_cache.get(k) {
             Profile::record_usize_profile(ProfileStatisticsName::ScanCacheBytes, item.len());
             metrics_inc_cache_hit_count(1, DISK_TABLE_DATA_CACHE_NAME);
+            self.counters.hit();
             Some(item)
         } else {
             metrics_inc_cache_miss_count(1, DISK_TABLE_DATA_CACHE_NAME);
+            self.counters.miss();
             None
         }
     }
@@ -166,6 +173,21 @@ impl CacheAccessor<String, Bytes, DefaultHashBuilder, Count> for TableDataCache
     }
 }
 
+impl CacheStatisticsProvider for TableDataCache {
+    fn statistics(&self) -> CacheStatistics {
+        let cache = self.external_cache.read();
+        CacheStatistics {
+            name: DISK_TABLE_DATA_CACHE_NAME.to_string(),
+            capacity: cache.capacity(),
+            size: cache.size(),
+            num_items: cache.len() as u64,
+            hits: self.counters.hits(),
+            misses: self.counters.misses(),
+            evictions: cache.evictions(),
+        }
+    }
+}
+
 struct CachePopulationWorker<T> {
     cache: T,
     population_queue: crossbeam_channel::Receiver<CacheItem>,
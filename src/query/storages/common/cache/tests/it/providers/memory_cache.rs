@@ -0,0 +1,41 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_storages_common_cache::CacheAccessor;
+use databend_storages_common_cache::CacheStatisticsProvider;
+use databend_storages_common_cache::InMemoryCacheBuilder;
+use databend_storages_common_cache::Named;
+
+#[test]
+fn test_named_cache_statistics() {
+    let cache = InMemoryCacheBuilder::new_item_cache::<u64>(2).name_with("test_cache");
+    cache.put("a".to_string(), Arc::new(1));
+    cache.put("b".to_string(), Arc::new(2));
+    assert!(cache.get("a").is_some());
+    assert!(cache.get("c").is_none());
+    // Evicts "b", the least recently used one.
+    cache.put("c".to_string(), Arc::new(3));
+
+    // The clones share the counters.
+    let statistics = cache.clone().statistics();
+    assert_eq!(statistics.name, "test_cache");
+    assert_eq!(statistics.capacity, 2);
+    assert_eq!(statistics.num_items, 2);
+    assert_eq!(statistics.size, 2);
+    assert_eq!(statistics.hits, 1);
+    assert_eq!(statistics.misses, 1);
+    assert_eq!(statistics.evictions, 1);
+}
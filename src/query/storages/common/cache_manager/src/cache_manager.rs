@@ -22,6 +22,8 @@ use databend_common_config::CacheConfig;
 use databend_common_config::CacheStorageTypeInnerConfig;
 use databend_common_config::DiskCacheKeyReloadPolicy;
 use databend_common_exception::Result;
+use databend_storages_common_cache::CacheStatistics;
+use databend_storages_common_cache::CacheStatisticsProvider;
 use databend_storages_common_cache::InMemoryCacheBuilder;
 use databend_storages_common_cache::InMemoryItemCacheHolder;
 use databend_storages_common_cache::Named;
@@ -29,6 +31,7 @@ use databend_storages_common_cache::NamedCache;
 use databend_storages_common_cache::TableDataCache;
 use databend_storages_common_cache::TableDataCacheBuilder;
 use log::info;
+use parking_lot::RwLock;
 
 use crate::caches::BloomIndexFilterCache;
 use crate::caches::BloomIndexMetaCache;
@@ -60,6 +63,8 @@ pub struct CacheManager {
     file_meta_data_cache: Option<FileMetaDataCache>,
     table_data_cache: Option<TableDataCache>,
     table_column_array_cache: Option<ColumnArrayCache>,
+    /// The caches listed by `system.caches`, in the order of registration.
+    registered_caches: RwLock<Vec<Arc<dyn CacheStatisticsProvider>>>,
 }
 
 impl CacheManager {
@@ -120,8 +125,8 @@ impl CacheManager {
         );
 
         // setup in-memory table meta cache
-        if !config.enable_table_meta_cache {
-            GlobalInstance::set(Arc::new(Self {
+        let cache_manager = if !config.enable_table_meta_cache {
+            Self {
                 table_snapshot_cache: None,
                 segment_info_cache: None,
                 bloom_index_filter_cache: None,
@@ -133,7 +138,8 @@ impl CacheManager {
                 table_statistic_cache: None,
                 table_data_cache,
                 table_column_array_cache: in_memory_table_data_cache,
-            }));
+                registered_caches: RwLock::new(vec![]),
+            }
         } else {
             let table_snapshot_cache = Self::new_named_cache(
                 config.table_meta_snapshot_count,
@@ -182,7 +188,7 @@ impl CacheManager {
                 DEFAULT_FILE_META_DATA_CACHE_ITEMS,
                 "memory_cache_parquet_file_meta",
             );
-            GlobalInstance::set(Arc::new(Self {
+            Self {
                 table_snapshot_cache,
                 segment_info_cache,
                 bloom_index_filter_cache,
@@ -194,9 +200,12 @@ impl CacheManager {
                 table_statistic_cache,
                 table_data_cache,
                 table_column_array_cache: in_memory_table_data_cache,
-            }));
-        }
+                registered_caches: RwLock::new(vec![]),
+            }
+        };
 
+        cache_manager.register_own_caches();
+        GlobalInstance::set(Arc::new(cache_manager));
         Ok(())
     }
 
@@ -248,6 +257,51 @@ impl CacheManager {
         self.table_column_array_cache.clone()
     }
 
+    /// Adds a cache to `system.caches`.
+    pub fn register_cache(&self, cache: Arc<dyn CacheStatisticsProvider>) {
+        self.registered_caches.write().push(cache);
+    }
+
+    /// Statistics of the registered caches.
+    pub fn cache_statistics(&self) -> Vec<CacheStatistics> {
+        self.registered_caches
+            .read()
+            .iter()
+            .map(|cache| cache.statistics())
+            .collect()
+    }
+
+    fn register_own_caches(&self) {
+        let caches: [Option<Arc<dyn CacheStatisticsProvider>>; 11] = [
+            self.table_snapshot_cache.clone().map(|c| Arc::new(c) as _),
+            self.table_statistic_cache.clone().map(|c| Arc::new(c) as _),
+            self.segment_info_cache.clone().map(|c| Arc::new(c) as _),
+            self.bloom_index_filter_cache
+                .clone()
+                .map(|c| Arc::new(c) as _),
+            self.bloom_index_meta_cache
+                .clone()
+                .map(|c| Arc::new(c) as _),
+            self.inverted_index_meta_cache
+                .clone()
+                .map(|c| Arc::new(c) as _),
+            self.inverted_index_file_cache
+                .clone()
+                .map(|c| Arc::new(c) as _),
+            self.prune_partitions_cache
+                .clone()
+                .map(|c| Arc::new(c) as _),
+            self.file_meta_data_cache.clone().map(|c| Arc::new(c) as _),
+            self.table_data_cache.clone().map(|c| Arc::new(c) as _),
+            self.table_column_array_cache
+                .clone()
+                .map(|c| Arc::new(c) as _),
+        ];
+        for cache in caches.into_iter().flatten() {
+            self.register_cache(cache);
+        }
+    }
+
     // create cache that meters size by `Count`
     fn new_named_cache<V>(
         capacity: u64,
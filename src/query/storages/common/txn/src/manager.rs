@@ -58,6 +58,10 @@ pub struct TxnBuffer {
     stream_tables: HashMap<u64, StreamSnapshot>,
 
     need_purge_files: Vec<(StageInfo, Vec<String>)>,
+
+    // The statements of the transaction which wrote outside of it, and
+    // which a rollback can not undo.
+    non_transactional_writes: usize,
 }
 
 #[derive(Debug, Clone)]
@@ -74,6 +78,7 @@ impl TxnBuffer {
         self.update_stream_meta.clear();
         self.deduplicated_labels.clear();
         self.stream_tables.clear();
+        self.non_transactional_writes = 0;
     }
 
     fn update_multi_table_meta(&mut self, mut req: UpdateMultiTableMetaReq) {
@@ -281,4 +286,12 @@ impl TxnManager {
     pub fn need_purge_files(&mut self) -> Vec<(StageInfo, Vec<String>)> {
         std::mem::take(&mut self.txn_buffer.need_purge_files)
     }
+
+    pub fn add_non_transactional_write(&mut self) {
+        self.txn_buffer.non_transactional_writes += 1;
+    }
+
+    pub fn non_transactional_writes(&self) -> usize {
+        self.txn_buffer.non_transactional_writes
+    }
 }
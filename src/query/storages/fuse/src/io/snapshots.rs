@@ -152,6 +152,40 @@ impl SnapshotsIO {
         .await
     }
 
+    // Read the snapshot files in parallel, without putting them into the cache.
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    pub async fn read_snapshots(
+        &self,
+        snapshot_files: &[String],
+    ) -> Result<Vec<Result<Arc<TableSnapshot>>>> {
+        // combine all the tasks.
+        let mut iter = snapshot_files.iter();
+        let tasks = std::iter::from_fn(move || {
+            iter.next().map(|location| {
+                let reader = MetaReaders::table_snapshot_reader(self.operator.clone());
+                let load_params = LoadParams {
+                    location: location.clone(),
+                    len_hint: None,
+                    ver: TableMetaLocationGenerator::snapshot_version(location),
+                    put_cache: false,
+                };
+                async move { reader.read(&load_params).await }
+                    .in_span(Span::enter_with_local_parent(full_name!()))
+            })
+        });
+
+        let threads_nums = self.ctx.get_settings().get_max_threads()? as usize;
+
+        execute_futures_in_parallel(
+            tasks,
+            threads_nums,
+            threads_nums * 2,
+            "fuse-req-snapshots-worker".to_owned(),
+        )
+        .await
+    }
+
     // Read all the snapshots by the root file.
     #[async_backtrace::framed]
     pub async fn read_snapshot_lites_ext<T>(
@@ -125,6 +125,8 @@ impl ReadNativeDataSource<false> {
 
 impl SyncSource for ReadNativeDataSource<true> {
     const NAME: &'static str = "SyncReadNativeDataSource";
+    // The deserializer records the blocks, these are the meta of the parts.
+    const RECORD_SCAN_PROGRESS: bool = false;
 
     fn generate(&mut self) -> Result<Option<DataBlock>> {
         match self.partitions.steal_one(self.id) {
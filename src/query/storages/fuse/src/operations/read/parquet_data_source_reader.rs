@@ -113,6 +113,8 @@ impl<const BLOCKING_IO: bool> ReadParquetDataSource<BLOCKING_IO> {
 
 impl SyncSource for ReadParquetDataSource<true> {
     const NAME: &'static str = "SyncReadParquetDataSource";
+    // The deserializer records the blocks, these are the meta of the parts.
+    const RECORD_SCAN_PROGRESS: bool = false;
 
     fn generate(&mut self) -> Result<Option<DataBlock>> {
         match self.partitions.steal_one(self.id) {
@@ -20,6 +20,7 @@ use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table_function::TableFunctionDescription;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
 use databend_common_meta_app::schema::TableIdent;
@@ -201,6 +202,15 @@ impl TableFunction for ClusteringInformationTable {
         self.name()
     }
 
+    fn describe(name: &str) -> Option<TableFunctionDescription> {
+        Some(TableFunctionDescription::create(
+            name,
+            "Shows the clustering information of a fuse table, by its own or the given cluster keys",
+            "clustering_information(database, table [, cluster_keys])",
+            "SELECT * FROM clustering_information('default', 't')",
+        ))
+    }
+
     fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
     where Self: 'a {
         self
@@ -21,6 +21,7 @@ use databend_common_catalog::plan::Filters;
 use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table_function::TableFunctionDescription;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
 use databend_common_meta_app::schema::TableIdent;
@@ -200,6 +201,15 @@ impl TableFunction for FuseEncodingTable {
         self.name()
     }
 
+    fn describe(name: &str) -> Option<TableFunctionDescription> {
+        Some(TableFunctionDescription::create(
+            name,
+            "Shows the encodings of the columns of the fuse tables in a database",
+            "fuse_encoding(database)",
+            "SELECT * FROM fuse_encoding('default')",
+        ))
+    }
+
     fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
     where Self: 'a {
         self
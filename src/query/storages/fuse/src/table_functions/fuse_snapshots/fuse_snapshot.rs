@@ -12,8 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 
+use databend_common_catalog::table::Table;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::number::UInt64Type;
@@ -26,11 +29,17 @@ use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRefExt;
-use databend_storages_common_table_meta::meta::TableSnapshotLite;
+use databend_storages_common_cache::LoadParams;
+use databend_storages_common_table_meta::meta::CompactSegmentInfo;
+use databend_storages_common_table_meta::meta::Location;
+use databend_storages_common_table_meta::meta::TableSnapshot;
 use log::info;
 
+use crate::io::MetaReaders;
+use crate::io::SegmentsIO;
 use crate::io::SnapshotsIO;
 use crate::io::TableMetaLocationGenerator;
+use crate::io::TableSnapshotReader;
 use crate::sessions::TableContext;
 use crate::FuseTable;
 
@@ -39,117 +48,247 @@ pub struct FuseSnapshot<'a> {
     pub table: &'a FuseTable,
 }
 
+/// A snapshot of the chain, as far as it could be read.
+enum ChainedSnapshot {
+    Loaded(Arc<TableSnapshot>),
+    Broken(String),
+    /// The history stops here, the older snapshots have been purged.
+    Purged,
+}
+
+/// How a snapshot of the chain differs from its parent.
+enum Delta {
+    /// The segments added to and removed from the parent.
+    Segments(Vec<Location>, Vec<Location>),
+    /// The first snapshot of the table, it added all its bytes.
+    First,
+    /// The parent could not be read.
+    Unknown,
+}
+
+impl Delta {
+    fn segments(segments: &[Location], parent_segments: &[Location]) -> Self {
+        let current = segments.iter().collect::<HashSet<_>>();
+        let parent = parent_segments.iter().collect::<HashSet<_>>();
+        let added = segments
+            .iter()
+            .filter(|s| !parent.contains(s))
+            .cloned()
+            .collect();
+        let removed = parent_segments
+            .iter()
+            .filter(|s| !current.contains(s))
+            .cloned()
+            .collect();
+        Delta::Segments(added, removed)
+    }
+
+    /// The compressed bytes added and removed.
+    fn bytes(
+        &self,
+        snapshot: &TableSnapshot,
+        segment_sizes: &HashMap<Location, Result<u64>>,
+    ) -> Result<Option<(u64, u64)>> {
+        let sum = |locations: &[Location]| {
+            locations
+                .iter()
+                .try_fold(0, |sum, location| match &segment_sizes[location] {
+                    Ok(size) => Ok(sum + size),
+                    Err(e) => Err(e.clone()),
+                })
+        };
+        match self {
+            Delta::Segments(added, removed) => Ok(Some((sum(added)?, sum(removed)?))),
+            Delta::First => Ok(Some((snapshot.summary.compressed_byte_size, 0))),
+            Delta::Unknown => Ok(None),
+        }
+    }
+}
+
 impl<'a> FuseSnapshot<'a> {
     pub fn new(ctx: Arc<dyn TableContext>, table: &'a FuseTable) -> Self {
         Self { ctx, table }
     }
 
+    /// Walks the snapshot chain from the current snapshot back, until the history ends or `limit`
+    /// snapshots are listed.
+    ///
+    /// A snapshot that can not be read is listed with the error and ends the listing, since its
+    /// parent is unknown. A parent that is not found is not an error, it was purged.
     #[async_backtrace::framed]
     pub async fn get_snapshots(self, limit: Option<usize>) -> Result<DataBlock> {
-        let meta_location_generator = self.table.meta_location_generator.clone();
-        let snapshot_location = self.table.snapshot_loc().await?;
-        let snapshot = self.table.read_table_snapshot().await?;
-        if let Some(snapshot_location) = snapshot_location {
-            let snapshot_version =
-                TableMetaLocationGenerator::snapshot_version(snapshot_location.as_str());
-            let snapshots_io = SnapshotsIO::create(self.ctx.clone(), self.table.operator.clone());
-            let snapshot_lite = if limit.is_none() {
+        let Some(snapshot_location) = self.table.snapshot_loc().await? else {
+            return Ok(DataBlock::empty_with_schema(Arc::new(
+                FuseSnapshot::schema().into(),
+            )));
+        };
+
+        let location_generator = self.table.meta_location_generator.clone();
+        let reader = MetaReaders::table_snapshot_reader(self.table.get_operator());
+
+        // Without a limit, all the snapshot files are read in parallel and chained afterwards.
+        // With a limit, the chain is followed a snapshot at a time, to stop once enough are read.
+        let mut snapshots = match limit {
+            None => {
                 info!("getting snapshots, using parallel strategy");
-                // Use SnapshotsIO::read_snapshot_lites only if limit is None
-                //
-                // SnapshotsIO::read_snapshot lists snapshots from object storage, taking limit into
-                // account, BEFORE the snapshots are chained, so there might be the case that although
-                // there are more than limited number of snapshots could be chained, the number of
-                // snapshots returned is lesser.
-                let (chained_snapshot_lites, _) = snapshots_io
-                    .read_snapshot_lites_ext(
-                        snapshot_location,
-                        snapshot.and_then(|s| s.timestamp),
-                        &|_| {},
-                    )
-                    .await?;
-                Ok::<_, ErrorCode>(chained_snapshot_lites)
-            } else {
-                // SnapshotsIO::read_chained_snapshot_lists traverses the history of snapshot sequentially, by using the
-                // TableSnapshot::prev_snapshot_id, which guarantees that the number of snapshot
-                // returned is as expected
-                let snapshot_lites = snapshots_io
-                    .read_chained_snapshot_lites(
-                        self.table.operator.clone(),
-                        meta_location_generator.clone(),
-                        snapshot_location,
-                        limit,
-                    )
-                    .await?;
-                info!(
-                    "getting snapshots in chained mod, limit: {:?}, number of snapshot_lite found: {}",
-                    limit,
-                    snapshot_lites.len()
-                );
-                Ok(snapshot_lites)
-            }?;
-
-            info!("got {} snapshots", snapshot_lite.len());
-            return self.to_block(&meta_location_generator, &snapshot_lite, snapshot_version);
+                Some(self.read_all_snapshots(&snapshot_location).await?)
+            }
+            Some(_) => None,
+        };
+        let limit = limit.unwrap_or(usize::MAX);
+
+        let mut chain = vec![];
+        let mut location = snapshot_location;
+        let mut current = Self::read_snapshot(&reader, &location, true).await;
+        while chain.len() < limit {
+            let snapshot = match current {
+                ChainedSnapshot::Loaded(snapshot) => snapshot,
+                ChainedSnapshot::Broken(error) => {
+                    chain.push((location, ChainedSnapshot::Broken(error), Delta::Unknown));
+                    break;
+                }
+                ChainedSnapshot::Purged => break,
+            };
+
+            let (parent, parent_location) = match snapshot.prev_snapshot_id {
+                Some((id, version)) => {
+                    let parent_location =
+                        location_generator.snapshot_location_from_uuid(&id, version)?;
+                    let parent = match snapshots.as_mut() {
+                        Some(snapshots) => snapshots
+                            .remove(&parent_location)
+                            .unwrap_or(ChainedSnapshot::Purged),
+                        None => Self::read_snapshot(&reader, &parent_location, false).await,
+                    };
+                    (parent, parent_location)
+                }
+                None => (ChainedSnapshot::Purged, String::new()),
+            };
+
+            let delta = match (&parent, snapshot.prev_snapshot_id) {
+                (ChainedSnapshot::Loaded(parent), _) => {
+                    Delta::segments(&snapshot.segments, &parent.segments)
+                }
+                (_, None) => Delta::First,
+                (_, Some(_)) => Delta::Unknown,
+            };
+            chain.push((location, ChainedSnapshot::Loaded(snapshot), delta));
+
+            location = parent_location;
+            current = parent;
         }
-        Ok(DataBlock::empty_with_schema(Arc::new(
-            FuseSnapshot::schema().into(),
-        )))
+
+        let segment_sizes = self.read_segment_sizes(&chain).await?;
+        let mut rows = SnapshotRows::default();
+        for (location, snapshot, delta) in chain {
+            match snapshot {
+                ChainedSnapshot::Loaded(snapshot) => {
+                    let delta = delta.bytes(&snapshot, &segment_sizes);
+                    rows.push(&location, &snapshot, delta);
+                }
+                ChainedSnapshot::Broken(error) => rows.push_broken(&location, error),
+                ChainedSnapshot::Purged => {}
+            }
+        }
+
+        info!("got {} snapshots", rows.len());
+        rows.build()
     }
 
-    fn to_block(
+    /// Reads all the snapshot files of the table but the current one, by location.
+    ///
+    /// The files of ongoing or aborted transactions are read too, they are never reached from the
+    /// current snapshot.
+    #[async_backtrace::framed]
+    async fn read_all_snapshots(
         &self,
-        location_generator: &TableMetaLocationGenerator,
-        snapshots: &[TableSnapshotLite],
-        latest_snapshot_version: u64,
-    ) -> Result<DataBlock> {
-        let len = snapshots.len();
-        let mut snapshot_ids: Vec<String> = Vec::with_capacity(len);
-        let mut snapshot_locations: Vec<String> = Vec::with_capacity(len);
-        let mut prev_snapshot_ids: Vec<Option<String>> = Vec::with_capacity(len);
-        let mut format_versions: Vec<u64> = Vec::with_capacity(len);
-        let mut segment_count: Vec<u64> = Vec::with_capacity(len);
-        let mut block_count: Vec<u64> = Vec::with_capacity(len);
-        let mut row_count: Vec<u64> = Vec::with_capacity(len);
-        let mut compressed: Vec<u64> = Vec::with_capacity(len);
-        let mut uncompressed: Vec<u64> = Vec::with_capacity(len);
-        let mut index_size: Vec<u64> = Vec::with_capacity(len);
-        let mut timestamps: Vec<Option<i64>> = Vec::with_capacity(len);
-        let mut current_snapshot_version = latest_snapshot_version;
-        for s in snapshots {
-            snapshot_ids.push(s.snapshot_id.simple().to_string());
-            snapshot_locations.push(
-                location_generator
-                    .snapshot_location_from_uuid(&s.snapshot_id, current_snapshot_version)?,
-            );
-            let (id, ver) = s
-                .prev_snapshot_id
-                .map_or((None, 0), |(id, v)| (Some(id.simple().to_string()), v));
-            prev_snapshot_ids.push(id);
-            format_versions.push(s.format_version);
-            segment_count.push(s.segment_count);
-            block_count.push(s.block_count);
-            row_count.push(s.row_count);
-            compressed.push(s.compressed_byte_size);
-            uncompressed.push(s.uncompressed_byte_size);
-            index_size.push(s.index_size);
-            timestamps.push(s.timestamp.map(|dt| (dt.timestamp_micros())));
-            current_snapshot_version = ver;
+        snapshot_location: &str,
+    ) -> Result<HashMap<String, ChainedSnapshot>> {
+        let mut snapshot_files = vec![];
+        if let Some(prefix) = SnapshotsIO::get_s3_prefix_from_file(snapshot_location) {
+            snapshot_files = SnapshotsIO::list_files(
+                self.table.get_operator(),
+                &prefix,
+                Some(snapshot_location),
+            )
+            .await?;
         }
 
-        Ok(DataBlock::new_from_columns(vec![
-            StringType::from_data(snapshot_ids),
-            StringType::from_data(snapshot_locations),
-            UInt64Type::from_data(format_versions),
-            StringType::from_opt_data(prev_snapshot_ids),
-            UInt64Type::from_data(segment_count),
-            UInt64Type::from_data(block_count),
-            UInt64Type::from_data(row_count),
-            UInt64Type::from_data(uncompressed),
-            UInt64Type::from_data(compressed),
-            UInt64Type::from_data(index_size),
-            TimestampType::from_opt_data(timestamps),
-        ]))
+        let snapshots_io = SnapshotsIO::create(self.ctx.clone(), self.table.get_operator());
+        let snapshots = snapshots_io.read_snapshots(&snapshot_files).await?;
+        Ok(snapshot_files
+            .into_iter()
+            .zip(snapshots)
+            .map(|(location, snapshot)| {
+                let snapshot = match snapshot {
+                    Ok(snapshot) => ChainedSnapshot::Loaded(snapshot),
+                    // Purged since it was listed.
+                    Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => ChainedSnapshot::Purged,
+                    Err(e) => ChainedSnapshot::Broken(e.message()),
+                };
+                (location, snapshot)
+            })
+            .collect())
+    }
+
+    /// Only the current snapshot is put into the cache, like any read of the table does, the
+    /// historical ones would just evict the snapshots of the other tables.
+    #[async_backtrace::framed]
+    async fn read_snapshot(
+        reader: &TableSnapshotReader,
+        location: &str,
+        is_current: bool,
+    ) -> ChainedSnapshot {
+        let load_params = LoadParams {
+            location: location.to_string(),
+            len_hint: None,
+            ver: TableMetaLocationGenerator::snapshot_version(location),
+            put_cache: is_current,
+        };
+        match reader.read(&load_params).await {
+            Ok(snapshot) => ChainedSnapshot::Loaded(snapshot),
+            Err(e) if !is_current && e.code() == ErrorCode::STORAGE_NOT_FOUND => {
+                ChainedSnapshot::Purged
+            }
+            Err(e) => ChainedSnapshot::Broken(e.message()),
+        }
+    }
+
+    /// The compressed bytes of the segments added or removed along the chain, all read at once.
+    #[async_backtrace::framed]
+    async fn read_segment_sizes(
+        &self,
+        chain: &[(String, ChainedSnapshot, Delta)],
+    ) -> Result<HashMap<Location, Result<u64>>> {
+        let mut locations = HashSet::new();
+        for (_, _, delta) in chain {
+            if let Delta::Segments(added, removed) = delta {
+                locations.extend(added.iter().chain(removed).cloned());
+            }
+        }
+        let locations = locations.into_iter().collect::<Vec<_>>();
+
+        let segments_io = SegmentsIO::create(
+            self.ctx.clone(),
+            self.table.operator.clone(),
+            self.table.schema(),
+        );
+        let mut sizes = HashMap::with_capacity(locations.len());
+        let chunk_size = std::cmp::min(
+            self.ctx.get_settings().get_max_threads()? as usize * 4,
+            locations.len(),
+        )
+        .max(1);
+        for chunk in locations.chunks(chunk_size) {
+            let segments = segments_io
+                .read_segments::<Arc<CompactSegmentInfo>>(chunk, false)
+                .await?;
+            for (location, segment) in chunk.iter().zip(segments) {
+                let size = segment.map(|segment| segment.summary.compressed_byte_size);
+                sizes.insert(location.clone(), size);
+            }
+        }
+        Ok(sizes)
     }
 
     pub fn schema() -> Arc<TableSchema> {
@@ -180,6 +319,118 @@ impl<'a> FuseSnapshot<'a> {
             ),
             TableField::new("index_size", TableDataType::Number(NumberDataType::UInt64)),
             TableField::new("timestamp", TableDataType::Timestamp.wrap_nullable()),
+            TableField::new(
+                "bytes_added",
+                TableDataType::Number(NumberDataType::UInt64).wrap_nullable(),
+            ),
+            TableField::new(
+                "bytes_removed",
+                TableDataType::Number(NumberDataType::UInt64).wrap_nullable(),
+            ),
+            TableField::new("error", TableDataType::String.wrap_nullable()),
         ])
     }
 }
+
+#[derive(Default)]
+struct SnapshotRows {
+    snapshot_ids: Vec<String>,
+    snapshot_locations: Vec<String>,
+    format_versions: Vec<u64>,
+    prev_snapshot_ids: Vec<Option<String>>,
+    segment_count: Vec<u64>,
+    block_count: Vec<u64>,
+    row_count: Vec<u64>,
+    uncompressed: Vec<u64>,
+    compressed: Vec<u64>,
+    index_size: Vec<u64>,
+    timestamps: Vec<Option<i64>>,
+    bytes_added: Vec<Option<u64>>,
+    bytes_removed: Vec<Option<u64>>,
+    errors: Vec<Option<String>>,
+}
+
+impl SnapshotRows {
+    fn len(&self) -> usize {
+        self.snapshot_ids.len()
+    }
+
+    fn push(
+        &mut self,
+        location: &str,
+        snapshot: &TableSnapshot,
+        delta: Result<Option<(u64, u64)>>,
+    ) {
+        self.snapshot_ids
+            .push(snapshot.snapshot_id.simple().to_string());
+        self.snapshot_locations.push(location.to_string());
+        self.format_versions
+            .push(TableMetaLocationGenerator::snapshot_version(location));
+        self.prev_snapshot_ids.push(
+            snapshot
+                .prev_snapshot_id
+                .map(|(id, _)| id.simple().to_string()),
+        );
+        self.segment_count.push(snapshot.segments.len() as u64);
+        self.block_count.push(snapshot.summary.block_count);
+        self.row_count.push(snapshot.summary.row_count);
+        self.uncompressed
+            .push(snapshot.summary.uncompressed_byte_size);
+        self.compressed.push(snapshot.summary.compressed_byte_size);
+        self.index_size.push(snapshot.summary.index_size);
+        self.timestamps
+            .push(snapshot.timestamp.map(|dt| dt.timestamp_micros()));
+        match delta {
+            Ok(delta) => {
+                self.bytes_added.push(delta.map(|(added, _)| added));
+                self.bytes_removed.push(delta.map(|(_, removed)| removed));
+                self.errors.push(None);
+            }
+            Err(e) => {
+                self.bytes_added.push(None);
+                self.bytes_removed.push(None);
+                self.errors.push(Some(e.message()));
+            }
+        }
+    }
+
+    /// A snapshot that could not be read, known by its location only.
+    fn push_broken(&mut self, location: &str, error: String) {
+        let file_name = location.rsplit('/').next().unwrap_or(location);
+        let snapshot_id = file_name.split('_').next().unwrap_or(file_name);
+        self.snapshot_ids.push(snapshot_id.to_string());
+        self.snapshot_locations.push(location.to_string());
+        self.format_versions
+            .push(TableMetaLocationGenerator::snapshot_version(location));
+        self.prev_snapshot_ids.push(None);
+        self.segment_count.push(0);
+        self.block_count.push(0);
+        self.row_count.push(0);
+        self.uncompressed.push(0);
+        self.compressed.push(0);
+        self.index_size.push(0);
+        self.timestamps.push(None);
+        self.bytes_added.push(None);
+        self.bytes_removed.push(None);
+        self.errors.push(Some(error));
+    }
+
+    fn build(self) -> Result<DataBlock> {
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(self.snapshot_ids),
+            StringType::from_data(self.snapshot_locations),
+            UInt64Type::from_data(self.format_versions),
+            StringType::from_opt_data(self.prev_snapshot_ids),
+            UInt64Type::from_data(self.segment_count),
+            UInt64Type::from_data(self.block_count),
+            UInt64Type::from_data(self.row_count),
+            UInt64Type::from_data(self.uncompressed),
+            UInt64Type::from_data(self.compressed),
+            UInt64Type::from_data(self.index_size),
+            TimestampType::from_opt_data(self.timestamps),
+            UInt64Type::from_opt_data(self.bytes_added),
+            UInt64Type::from_opt_data(self.bytes_removed),
+            StringType::from_opt_data(self.errors),
+        ]))
+    }
+}
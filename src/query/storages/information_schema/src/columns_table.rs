@@ -27,19 +27,19 @@ pub struct ColumnsTable {}
 impl ColumnsTable {
     pub fn create(table_id: u64) -> Arc<dyn Table> {
         let query = "SELECT
-            database AS table_catalog,
+            catalog AS table_catalog,
             database AS table_schema,
             table AS table_name,
             name AS column_name,
-            1 AS ordinal_position,
-            NULL AS column_default,
+            ordinal_position AS ordinal_position,
+            CASE WHEN default_kind = 'DEFAULT' THEN default_expression END AS column_default,
             comment AS column_comment,
             NULL AS column_key,
             case when is_nullable='NO' then 0
             when is_nullable='YES' then 1
             end as nullable,
             is_nullable AS is_nullable,
-            type AS data_type,
+            lower(split_part(data_type, '(', 1)) AS data_type,
             data_type AS column_type,
             NULL AS character_maximum_length,
             NULL AS character_octet_length,
@@ -58,7 +58,10 @@ impl ColumnsTable {
             NULL AS domain_name,
             NULL AS privileges,
             default_expression as default,
-            NULL AS extra
+            CASE default_kind
+                WHEN 'VIRTUAL' THEN 'VIRTUAL GENERATED'
+                WHEN 'STORED' THEN 'STORED GENERATED'
+            END AS extra
         FROM system.columns;";
 
         let mut options = BTreeMap::new();
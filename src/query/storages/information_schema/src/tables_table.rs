@@ -53,12 +53,17 @@ impl TablesTable {
     // +-----------------+--------------------------------------------------------------------+------+-----+---------+-------+
     pub fn create(table_id: u64) -> Arc<dyn Table> {
         let query = "SELECT
-            database AS table_catalog,
+            catalog AS table_catalog,
             database AS table_schema,
             name AS table_name,
-            'BASE TABLE' AS table_type,
+            CASE
+                WHEN database IN ('system', 'information_schema') THEN 'SYSTEM VIEW'
+                WHEN engine = 'VIEW' THEN 'VIEW'
+                ELSE 'BASE TABLE'
+            END AS table_type,
             engine AS engine,
             created_on AS create_time,
+            updated_on AS update_time,
             dropped_on AS drop_time,
             data_size AS data_length,
             index_size AS index_length,
@@ -30,13 +30,13 @@ impl ViewsTable {
             catalog AS table_catalog,
             database AS table_schema,
             name AS table_name,
-            NULL AS view_definition,
+            view_query AS view_definition,
             'NONE' AS check_option,
-            0 AS is_updatable,
-            engine = 'MaterializedView' AS is_insertable_into,
-            0 AS is_trigger_updatable,
-            0 AS is_trigger_deletable,
-            0 AS is_trigger_insertable_into
+            'NO' AS is_updatable,
+            if(engine = 'MaterializedView', 'YES', 'NO') AS is_insertable_into,
+            'NO' AS is_trigger_updatable,
+            'NO' AS is_trigger_deletable,
+            'NO' AS is_trigger_insertable_into
         FROM system.views";
 
         let mut options = BTreeMap::new();
@@ -18,13 +18,17 @@
 mod common;
 mod meta_manager;
 mod read;
+mod statistics;
 mod table_function;
 mod write;
 
 pub use common::gen_result_cache_key;
 pub use common::gen_result_cache_meta_key;
 pub use common::gen_result_cache_prefix;
+pub use common::ResultCacheValue;
 pub use meta_manager::ResultCacheMetaManager;
 pub use read::ResultCacheReader;
+pub use statistics::ResultCacheStatistics;
+pub use statistics::RESULT_CACHE_NAME;
 pub use table_function::ResultScan;
 pub use write::WriteResultCacheSink;
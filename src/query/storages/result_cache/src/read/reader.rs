@@ -29,6 +29,7 @@ use parquet::file::footer::parse_metadata;
 use crate::common::gen_result_cache_meta_key;
 use crate::common::ResultCacheValue;
 use crate::meta_manager::ResultCacheMetaManager;
+use crate::ResultCacheStatistics;
 
 pub struct ResultCacheReader {
     meta_mgr: ResultCacheMetaManager,
@@ -88,20 +89,47 @@ impl ResultCacheReader {
         &self,
         meta_key: String,
     ) -> Result<Option<Vec<DataBlock>>> {
+        Ok(self
+            .try_read_cached_entry_with_meta_key(meta_key)
+            .await?
+            .map(|(_, blocks)| blocks))
+    }
+
+    /// Like `try_read_cached_result`, also returns the meta of the cache entry.
+    #[async_backtrace::framed]
+    pub async fn try_read_cached_entry(
+        &self,
+    ) -> Result<Option<(ResultCacheValue, Vec<DataBlock>)>> {
+        self.try_read_cached_entry_with_meta_key(self.meta_key.clone())
+            .await
+    }
+
+    #[async_backtrace::framed]
+    async fn try_read_cached_entry_with_meta_key(
+        &self,
+        meta_key: String,
+    ) -> Result<Option<(ResultCacheValue, Vec<DataBlock>)>> {
+        let statistics = ResultCacheStatistics::instance();
         match self.meta_mgr.get(meta_key).await? {
             Some(value) => {
                 if self.tolerate_inconsistent || value.partitions_shas == self.partitions_shas {
-                    if value.num_rows == 0 {
-                        Ok(Some(vec![DataBlock::empty()]))
+                    statistics.hit();
+                    let blocks = if value.num_rows == 0 {
+                        vec![DataBlock::empty()]
                     } else {
-                        Ok(Some(self.read_result_from_cache(&value.location).await?))
-                    }
+                        self.read_result_from_cache(&value.location).await?
+                    };
+                    Ok(Some((value, blocks)))
                 } else {
                     // The cache is invalid (due to data update or other reasons).
+                    statistics.miss();
                     Ok(None)
                 }
             }
-            None => Ok(None),
+            None => {
+                statistics.miss();
+                Ok(None)
+            }
         }
     }
 
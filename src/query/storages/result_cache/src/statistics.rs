@@ -0,0 +1,95 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use databend_common_base::base::GlobalInstance;
+use databend_common_exception::Result;
+use databend_storages_common_cache::CacheCounters;
+use databend_storages_common_cache::CacheStatistics;
+use databend_storages_common_cache::CacheStatisticsProvider;
+use parking_lot::Mutex;
+
+pub const RESULT_CACHE_NAME: &str = "query_result_cache";
+
+/// The query result cache as seen by this node, listed by `system.caches`.
+///
+/// The results are kept by the meta service and the storage until their TTL, so the entries
+/// and bytes are the ones written by this node and not expired yet, an expired entry counts as
+/// an eviction. The cache has no capacity, each entry is bounded by
+/// `query_result_cache_max_bytes` instead.
+pub struct ResultCacheStatistics {
+    counters: CacheCounters,
+    // Meta key -> (expire time, bytes of the result).
+    entries: Mutex<HashMap<String, (Instant, u64)>>,
+    evictions: AtomicU64,
+}
+
+impl ResultCacheStatistics {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Arc::new(ResultCacheStatistics {
+            counters: CacheCounters::default(),
+            entries: Mutex::new(HashMap::new()),
+            evictions: AtomicU64::new(0),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<ResultCacheStatistics> {
+        GlobalInstance::get()
+    }
+
+    pub(crate) fn hit(&self) {
+        self.counters.hit();
+    }
+
+    pub(crate) fn miss(&self) {
+        self.counters.miss();
+    }
+
+    pub(crate) fn written(&self, meta_key: String, bytes: u64, ttl: Duration) {
+        let now = Instant::now();
+        let mut entries = self.entries.lock();
+        self.evict_expired(&mut entries, now);
+        entries.insert(meta_key, (now + ttl, bytes));
+    }
+
+    fn evict_expired(&self, entries: &mut HashMap<String, (Instant, u64)>, now: Instant) {
+        let num_entries = entries.len();
+        entries.retain(|_, (expire_at, _)| *expire_at > now);
+        let evicted = num_entries - entries.len();
+        self.evictions.fetch_add(evicted as u64, Ordering::Relaxed);
+    }
+}
+
+impl CacheStatisticsProvider for ResultCacheStatistics {
+    fn statistics(&self) -> CacheStatistics {
+        let mut entries = self.entries.lock();
+        self.evict_expired(&mut entries, Instant::now());
+        CacheStatistics {
+            name: RESULT_CACHE_NAME.to_string(),
+            capacity: 0,
+            size: entries.values().map(|(_, bytes)| bytes).sum(),
+            num_items: entries.len() as u64,
+            hits: self.counters.hits(),
+            misses: self.counters.misses(),
+            evictions: self.evictions.load(Ordering::Relaxed),
+        }
+    }
+}
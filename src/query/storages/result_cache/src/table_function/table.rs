@@ -37,8 +37,8 @@ use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sources::BlocksSource;
 use databend_common_pipeline_sources::EmptySource;
-use databend_common_pipeline_sources::OneBlockSource;
 
 const RESULT_SCAN: &str = "result_scan";
 
@@ -122,7 +122,7 @@ impl Table for ResultScan {
 
     fn read_data(
         &self,
-        _ctx: Arc<dyn TableContext>,
+        ctx: Arc<dyn TableContext>,
         _plan: &DataSourcePlan,
         pipeline: &mut Pipeline,
         _put_cache: bool,
@@ -135,14 +135,17 @@ impl Table for ResultScan {
             let arrow_schema = infer_schema(&meta)?;
             let schema = DataSchema::try_from(&arrow_schema).unwrap();
 
-            // Read the parquet file into one block.
+            // Read the row groups of the parquet file into blocks.
             let chunks_iter =
                 pread::FileReader::new(reader, meta.row_groups, arrow_schema, None, None, None);
 
-            for chunk in chunks_iter {
-                let block = DataBlock::from_arrow_chunk(&chunk?, &schema)?;
-                pipeline.add_source(|output| OneBlockSource::create(output, block.clone()), 1)?;
-            }
+            let blocks = chunks_iter
+                .map(|chunk| DataBlock::from_arrow_chunk(&chunk?, &schema))
+                .collect::<Result<Vec<_>>>()?;
+            pipeline.add_source(
+                |output| BlocksSource::create_with_blocks(ctx.clone(), output, blocks.clone()),
+                1,
+            )?;
         }
         Ok(())
     }
@@ -34,6 +34,7 @@ use crate::common::gen_result_cache_dir;
 use crate::common::gen_result_cache_meta_key;
 use crate::common::ResultCacheValue;
 use crate::meta_manager::ResultCacheMetaManager;
+use crate::ResultCacheStatistics;
 
 pub struct WriteResultCacheSink {
     ctx: Arc<dyn TableContext>,
@@ -96,19 +97,25 @@ impl AsyncMpscSink for WriteResultCacheSink {
         let ttl_sec = self.meta_mgr.get_ttl();
         let ttl_interval = Duration::from_secs(ttl_sec);
 
+        let result_size = self.cache_writer.current_bytes();
         let value = ResultCacheValue {
             sql: self.sql.clone(),
             query_id: self.ctx.get_id(),
             query_time: now,
             ttl: ttl_sec,
             partitions_shas: self.partitions_shas.clone(),
-            result_size: self.cache_writer.current_bytes(),
+            result_size,
             num_rows: self.cache_writer.num_rows(),
             location,
         };
         self.meta_mgr
             .set(self.meta_key.clone(), value, MatchSeq::GE(0), ttl_interval)
             .await?;
+        ResultCacheStatistics::instance().written(
+            self.meta_key.clone(),
+            result_size as u64,
+            ttl_interval,
+        );
         self.ctx
             .set_query_id_result_cache(self.ctx.get_id(), self.meta_key.clone());
         Ok(())
@@ -14,14 +14,19 @@
 
 use std::any::type_name;
 use std::fmt::Write;
+use std::sync::mpsc;
 use std::sync::Arc;
+use std::time::Duration;
 
 use databend_common_base::get_all_tasks;
 use databend_common_base::runtime::Runtime;
+use databend_common_base::AsyncTaskItem;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
 use databend_common_expression::TableDataType;
@@ -35,6 +40,15 @@ use regex::Captures;
 use crate::SyncOneBlockSystemTable;
 use crate::SyncSystemTable;
 
+/// The stacks of the async tasks of the node, one row per task.
+///
+/// The dump does not wait for the tasks being polled, and it is given up after
+/// [`Self::DUMP_TIMEOUT`], leaving a single `UNAVAILABLE` row telling why in place of the
+/// tasks. Reading it requires the SUPER privilege.
+///
+/// At most [`Self::MAX_TASKS`] tasks are listed, the deepest first, and each stack is cut after
+/// [`Self::MAX_STACK_BYTES`] bytes with a trailing `...`. The `task_id` numbers the tasks of a
+/// dump, the traced tasks have no id kept from one dump to the next.
 pub struct BacktraceTable {
     table_info: TableInfo,
 }
@@ -52,11 +66,26 @@ impl SyncSystemTable for BacktraceTable {
 
     fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
         let local_node = ctx.get_cluster().local_id.clone();
-        let (tasks, polling_tasks) = get_all_tasks(false);
-        let tasks_size = tasks.len() + polling_tasks.len();
+        let (tasks, polling_tasks) = match Self::dump_tasks() {
+            Ok(tasks) => tasks,
+            Err(reason) => {
+                return Ok(DataBlock::new_from_columns(vec![
+                    StringType::from_data(vec![local_node]),
+                    StringType::from_data(vec!["Global".to_string()]),
+                    UInt64Type::from_data(vec![0]),
+                    StringType::from_data(vec![String::new()]),
+                    StringType::from_data(vec!["UNAVAILABLE".to_string()]),
+                    StringType::from_data(vec![reason]),
+                ]));
+            }
+        };
+        let tasks_size = std::cmp::min(tasks.len() + polling_tasks.len(), Self::MAX_TASKS);
+        let regex = regex::Regex::new("<(.+) as .+>").unwrap();
 
         let mut nodes: Vec<String> = Vec::with_capacity(tasks_size);
         let mut queries_id: Vec<String> = Vec::with_capacity(tasks_size);
+        let mut task_ids: Vec<u64> = Vec::with_capacity(tasks_size);
+        let mut spawn_locations: Vec<String> = Vec::with_capacity(tasks_size);
         let mut queries_status: Vec<String> = Vec::with_capacity(tasks_size);
         let mut stacks: Vec<String> = Vec::with_capacity(tasks_size);
 
@@ -67,12 +96,23 @@ impl SyncSystemTable for BacktraceTable {
             tasks.sort_by(|l, r| Ord::cmp(&l.stack_frames.len(), &r.stack_frames.len()));
 
             for item in tasks.into_iter().rev() {
+                if stacks.len() >= Self::MAX_TASKS {
+                    break;
+                }
+
                 let mut query_id = String::from("Global");
+                let mut spawn_location = String::new();
 
                 let mut stack_frames = String::new();
                 let mut frames_iter = item.stack_frames.into_iter();
 
                 if let Some(mut frame) = frames_iter.next() {
+                    // The root frame is the spawned future, `╼ <name> at <file>:<line>:<col>`.
+                    if let Some((_, location)) = frame.rsplit_once(" at ") {
+                        let location = location.trim_end_matches(" [POLLING]");
+                        spawn_location = location.to_string();
+                    }
+
                     let matcher = "╼ Running query ";
                     if let Some(_pos) = frame.find(matcher) {
                         let task_matcher = " spawn task";
@@ -90,23 +130,26 @@ impl SyncSystemTable for BacktraceTable {
                         frame = frame.replace("╼ Global spawn task", &replaced)
                     }
 
-                    writeln!(stack_frames, "{}", frame).unwrap();
+                    push_frame(&mut stack_frames, &frame);
                 }
 
                 for mut frame in frames_iter {
                     frame = frame.replace("::{{closure}}", "");
 
-                    let regex = regex::Regex::new("<(.+) as .+>").unwrap();
                     let frame = regex
                         .replace(&frame, |caps: &Captures| caps[1].to_string())
                         .to_string();
 
-                    writeln!(stack_frames, "{}", frame).unwrap();
+                    if !push_frame(&mut stack_frames, &frame) {
+                        break;
+                    }
                 }
 
                 nodes.push(local_node.clone());
                 stacks.push(stack_frames);
                 queries_id.push(query_id);
+                task_ids.push(task_ids.len() as u64 + 1);
+                spawn_locations.push(spawn_location);
                 queries_status.push(status.clone());
             }
         }
@@ -114,17 +157,55 @@ impl SyncSystemTable for BacktraceTable {
         Ok(DataBlock::new_from_columns(vec![
             StringType::from_data(nodes),
             StringType::from_data(queries_id),
+            UInt64Type::from_data(task_ids),
+            StringType::from_data(spawn_locations),
             StringType::from_data(queries_status),
             StringType::from_data(stacks),
         ]))
     }
 }
 
+// Appends the frame as a line, or `...` and returns false if it would exceed the stack bound.
+fn push_frame(stack_frames: &mut String, frame: &str) -> bool {
+    if stack_frames.len() + frame.len() + 1 > BacktraceTable::MAX_STACK_BYTES {
+        stack_frames.push_str("...");
+        return false;
+    }
+
+    writeln!(stack_frames, "{}", frame).unwrap();
+    true
+}
+
 impl BacktraceTable {
+    pub const MAX_TASKS: usize = 10_000;
+    pub const MAX_STACK_BYTES: usize = 64 * 1024;
+    pub const DUMP_TIMEOUT: Duration = Duration::from_secs(2);
+
+    /// The pending and the polled tasks, or why they could not be dumped in time. The dump runs
+    /// on its own thread, which is left behind if it doesn't finish before the timeout.
+    fn dump_tasks() -> std::result::Result<(Vec<AsyncTaskItem>, Vec<AsyncTaskItem>), String> {
+        let (tx, rx) = mpsc::channel();
+        std::thread::Builder::new()
+            .name("backtrace-dump".to_string())
+            .spawn(move || {
+                let _ = tx.send(get_all_tasks(false));
+            })
+            .map_err(|e| format!("the task dump could not be started: {e}"))?;
+
+        rx.recv_timeout(Self::DUMP_TIMEOUT).map_err(|_| {
+            format!(
+                "the task dump did not finish in {}s",
+                Self::DUMP_TIMEOUT.as_secs()
+            )
+        })
+    }
+
     pub fn create(table_id: u64) -> Arc<dyn Table> {
         let schema = TableSchemaRefExt::create(vec![
             TableField::new("node", TableDataType::String),
             TableField::new("query_id", TableDataType::String),
+            TableField::new("task_id", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("spawn_location", TableDataType::String),
             TableField::new("status", TableDataType::String),
             TableField::new("stack", TableDataType::String),
         ]);
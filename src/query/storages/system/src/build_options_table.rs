@@ -12,9 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::cmp::max;
 use std::sync::Arc;
 
+use databend_common_base::mem_allocator::DEFAULT_ALLOCATOR_NAME;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
@@ -31,8 +31,23 @@ use databend_common_meta_app::schema::TableMeta;
 use crate::SyncOneBlockSystemTable;
 use crate::SyncSystemTable;
 
+/// The dependencies whose versions are reported, as named in `Cargo.lock`.
+const KEY_DEPENDENCIES: &[&str] = &[
+    "arrow-array",
+    "arrow-flight",
+    "opendal",
+    "parquet",
+    "tokio",
+    "tonic",
+];
+
+/// The options the binary is built with, one row per option.
+///
+/// All the values are captured by the build scripts, the cargo features are those of the
+/// crate registering the table, the features of this crate tell nothing about the binary.
 pub struct BuildOptionsTable {
     table_info: TableInfo,
+    cargo_features: Vec<String>,
 }
 
 impl SyncSystemTable for BuildOptionsTable {
@@ -43,39 +58,65 @@ impl SyncSystemTable for BuildOptionsTable {
     }
 
     fn get_full_data(&self, _: Arc<dyn TableContext>) -> Result<DataBlock> {
-        let mut cargo_features: Vec<String>;
-
-        if let Some(features) = option_env!("VERGEN_CARGO_FEATURES") {
-            cargo_features = features
-                .split_terminator(',')
-                .map(|x| x.trim().to_string())
-                .collect();
-        } else {
-            cargo_features = vec!["not available".to_string()];
-        }
+        let env = |value: Option<&str>| value.unwrap_or("unknown").to_string();
 
-        let mut target_features: Vec<String> = env!("DATABEND_CARGO_CFG_TARGET_FEATURE")
-            .split_terminator(',')
-            .map(|x| x.trim().to_string())
-            .collect();
+        let mut options = vec![
+            ("version", env(option_env!("DATABEND_GIT_SEMVER"))),
+            ("git_sha", env(option_env!("VERGEN_GIT_SHA"))),
+            (
+                "build_timestamp",
+                env(option_env!("VERGEN_BUILD_TIMESTAMP")),
+            ),
+            ("rustc_version", env(option_env!("VERGEN_RUSTC_SEMVER"))),
+            (
+                "target_triple",
+                env(option_env!("VERGEN_CARGO_TARGET_TRIPLE")),
+            ),
+            ("opt_level", env(option_env!("VERGEN_CARGO_OPT_LEVEL"))),
+            ("debug", env(option_env!("VERGEN_CARGO_DEBUG"))),
+            ("allocator", DEFAULT_ALLOCATOR_NAME.to_string()),
+            ("cargo_features", self.cargo_features.join(",")),
+            (
+                "target_features",
+                env!("DATABEND_CARGO_CFG_TARGET_FEATURE").to_string(),
+            ),
+        ];
 
-        let length = max(cargo_features.len(), target_features.len());
+        let names = env!("DATABEND_CREDITS_NAMES").split(',').map(str::trim);
+        let versions = env!("DATABEND_CREDITS_VERSIONS").split(',').map(str::trim);
+        let mut dependencies = names.zip(versions).collect::<Vec<_>>();
+        dependencies.sort();
+        for dependency in KEY_DEPENDENCIES {
+            // Several versions of a crate may be linked.
+            let versions = dependencies
+                .iter()
+                .filter(|(name, _)| name == dependency)
+                .map(|(_, version)| *version)
+                .collect::<Vec<_>>();
+            if !versions.is_empty() {
+                options.push((*dependency, versions.join(",")));
+            }
+        }
 
-        cargo_features.resize(length, "".to_string());
-        target_features.resize(length, "".to_string());
+        let (names, values): (Vec<String>, Vec<String>) = options
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .unzip();
 
         Ok(DataBlock::new_from_columns(vec![
-            StringType::from_data(cargo_features),
-            StringType::from_data(target_features),
+            StringType::from_data(names),
+            StringType::from_data(values),
         ]))
     }
 }
 
 impl BuildOptionsTable {
-    pub fn create(table_id: u64) -> Arc<dyn Table> {
+    /// `cargo_features` are the comma separated features of the binary, e.g. the
+    /// `VERGEN_CARGO_FEATURES` of the crate registering the table.
+    pub fn create(table_id: u64, cargo_features: Option<&str>) -> Arc<dyn Table> {
         let schema = TableSchemaRefExt::create(vec![
-            TableField::new("cargo_features", TableDataType::String),
-            TableField::new("target_features", TableDataType::String),
+            TableField::new("name", TableDataType::String),
+            TableField::new("value", TableDataType::String),
         ]);
 
         let table_info = TableInfo {
@@ -90,6 +131,17 @@ impl BuildOptionsTable {
             ..Default::default()
         };
 
-        SyncOneBlockSystemTable::create(BuildOptionsTable { table_info })
+        let mut cargo_features = cargo_features
+            .unwrap_or_default()
+            .split_terminator(',')
+            .map(|x| x.trim().to_string())
+            .filter(|x| !x.is_empty() && x != "default")
+            .collect::<Vec<_>>();
+        cargo_features.sort();
+
+        SyncOneBlockSystemTable::create(BuildOptionsTable {
+            table_info,
+            cargo_features,
+        })
     }
 }
@@ -12,8 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::hash::BuildHasher;
-use std::hash::Hash;
 use std::sync::Arc;
 
 use databend_common_catalog::table::Table;
@@ -30,27 +28,16 @@ use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_storages_fuse::TableContext;
-use databend_storages_common_cache::CacheAccessor;
-use databend_storages_common_cache::CountableMeter;
-use databend_storages_common_cache::NamedCache;
-use databend_storages_common_cache::DISK_TABLE_DATA_CACHE_NAME;
 use databend_storages_common_cache_manager::CacheManager;
 
 use crate::SyncOneBlockSystemTable;
 use crate::SyncSystemTable;
 
+/// The caches registered to the CacheManager, with their sizes and counters since startup.
 pub struct CachesTable {
     table_info: TableInfo,
 }
 
-#[derive(Default)]
-struct CachesTableColumns {
-    nodes: Vec<String>,
-    names: Vec<String>,
-    num_items: Vec<u64>,
-    size: Vec<u64>,
-}
-
 impl SyncSystemTable for CachesTable {
     const NAME: &'static str = "system.caches";
 
@@ -63,74 +50,36 @@ impl SyncSystemTable for CachesTable {
 
     fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
         let local_node = ctx.get_cluster().local_id.clone();
-        let cache_manager = CacheManager::instance();
-
-        let table_snapshot_cache = cache_manager.get_table_snapshot_cache();
-        let table_snapshot_statistic_cache = cache_manager.get_table_snapshot_statistics_cache();
-        let segment_info_cache = cache_manager.get_table_segment_cache();
-        let bloom_index_filter_cache = cache_manager.get_bloom_index_filter_cache();
-        let bloom_index_meta_cache = cache_manager.get_bloom_index_meta_cache();
-        let inverted_index_meta_cache = cache_manager.get_inverted_index_meta_cache();
-        let inverted_index_file_cache = cache_manager.get_inverted_index_file_cache();
-        let prune_partitions_cache = cache_manager.get_prune_partitions_cache();
-        let file_meta_data_cache = cache_manager.get_file_meta_data_cache();
-        let table_data_cache = cache_manager.get_table_data_cache();
-        let table_column_array_cache = cache_manager.get_table_data_array_cache();
-
-        let mut columns = CachesTableColumns::default();
-
-        if let Some(table_snapshot_cache) = table_snapshot_cache {
-            Self::append_row(&table_snapshot_cache, &local_node, &mut columns);
-        }
-        if let Some(table_snapshot_statistic_cache) = table_snapshot_statistic_cache {
-            Self::append_row(&table_snapshot_statistic_cache, &local_node, &mut columns);
-        }
-
-        if let Some(segment_info_cache) = segment_info_cache {
-            Self::append_row(&segment_info_cache, &local_node, &mut columns);
-        }
-
-        if let Some(bloom_index_filter_cache) = bloom_index_filter_cache {
-            Self::append_row(&bloom_index_filter_cache, &local_node, &mut columns);
-        }
-
-        if let Some(bloom_index_meta_cache) = bloom_index_meta_cache {
-            Self::append_row(&bloom_index_meta_cache, &local_node, &mut columns);
-        }
-
-        if let Some(inverted_index_meta_cache) = inverted_index_meta_cache {
-            Self::append_row(&inverted_index_meta_cache, &local_node, &mut columns);
-        }
-
-        if let Some(inverted_index_file_cache) = inverted_index_file_cache {
-            Self::append_row(&inverted_index_file_cache, &local_node, &mut columns);
-        }
-
-        if let Some(prune_partitions_cache) = prune_partitions_cache {
-            Self::append_row(&prune_partitions_cache, &local_node, &mut columns);
-        }
-
-        if let Some(file_meta_data_cache) = file_meta_data_cache {
-            Self::append_row(&file_meta_data_cache, &local_node, &mut columns);
-        }
-
-        if let Some(table_data_cache) = table_data_cache {
-            // table data cache is not a named cache yet
-            columns.nodes.push(local_node.clone());
-            columns.names.push(DISK_TABLE_DATA_CACHE_NAME.to_string());
-            columns.num_items.push(table_data_cache.len() as u64);
-            columns.size.push(table_data_cache.size());
-        }
-
-        if let Some(table_column_array_cache) = table_column_array_cache {
-            Self::append_row(&table_column_array_cache, &local_node, &mut columns);
+        let statistics = CacheManager::instance().cache_statistics();
+
+        let mut nodes = Vec::with_capacity(statistics.len());
+        let mut names = Vec::with_capacity(statistics.len());
+        let mut num_items = Vec::with_capacity(statistics.len());
+        let mut sizes = Vec::with_capacity(statistics.len());
+        let mut capacities = Vec::with_capacity(statistics.len());
+        let mut hits = Vec::with_capacity(statistics.len());
+        let mut misses = Vec::with_capacity(statistics.len());
+        let mut evictions = Vec::with_capacity(statistics.len());
+        for cache in statistics {
+            nodes.push(local_node.clone());
+            names.push(cache.name);
+            num_items.push(cache.num_items);
+            sizes.push(cache.size);
+            capacities.push(cache.capacity);
+            hits.push(cache.hits);
+            misses.push(cache.misses);
+            evictions.push(cache.evictions);
         }
 
         Ok(DataBlock::new_from_columns(vec![
-            StringType::from_data(columns.nodes),
-            StringType::from_data(columns.names),
-            UInt64Type::from_data(columns.num_items),
-            UInt64Type::from_data(columns.size),
+            StringType::from_data(nodes),
+            StringType::from_data(names),
+            UInt64Type::from_data(num_items),
+            UInt64Type::from_data(sizes),
+            UInt64Type::from_data(capacities),
+            UInt64Type::from_data(hits),
+            UInt64Type::from_data(misses),
+            UInt64Type::from_data(evictions),
         ]))
     }
 }
@@ -142,6 +91,10 @@ impl CachesTable {
             TableField::new("name", TableDataType::String),
             TableField::new("num_items", TableDataType::Number(NumberDataType::UInt64)),
             TableField::new("size", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("capacity", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("hits", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("misses", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("evictions", TableDataType::Number(NumberDataType::UInt64)),
         ]);
 
         let table_info = TableInfo {
@@ -158,20 +111,4 @@ impl CachesTable {
         };
         SyncOneBlockSystemTable::create(Self { table_info })
     }
-
-    fn append_row<K, V, S, M, C>(
-        cache: &NamedCache<C>,
-        local_node: &str,
-        row: &mut CachesTableColumns,
-    ) where
-        C: CacheAccessor<K, V, S, M>,
-        K: Eq + Hash,
-        S: BuildHasher,
-        M: CountableMeter<K, Arc<V>>,
-    {
-        row.nodes.push(local_node.to_string());
-        row.names.push(cache.name().to_string());
-        row.num_items.push(cache.len() as u64);
-        row.size.push(cache.size());
-    }
 }
@@ -13,7 +13,9 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
+use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_config::GlobalConfig;
@@ -31,22 +33,32 @@ use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 
-use crate::SyncOneBlockSystemTable;
-use crate::SyncSystemTable;
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+/// How long a node is waited for before it is listed as unreachable.
+const PING_TIMEOUT: Duration = Duration::from_secs(3);
 
 pub struct ClustersTable {
     table_info: TableInfo,
 }
 
-impl SyncSystemTable for ClustersTable {
+#[async_trait::async_trait]
+impl AsyncSystemTable for ClustersTable {
     const NAME: &'static str = "system.cluster";
 
     fn get_table_info(&self) -> &TableInfo {
         &self.table_info
     }
 
-    fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
         let cluster_nodes = ctx.get_cluster().nodes.clone();
+        let mut nodes_status = ctx.ping_cluster_nodes(PING_TIMEOUT).await;
 
         let mut names = ColumnBuilder::with_capacity(&DataType::String, cluster_nodes.len());
         let mut clusters = ColumnBuilder::with_capacity(&DataType::String, cluster_nodes.len());
@@ -56,6 +68,15 @@ impl SyncSystemTable for ClustersTable {
             cluster_nodes.len(),
         );
         let mut versions = ColumnBuilder::with_capacity(&DataType::String, cluster_nodes.len());
+        let mut uptimes = ColumnBuilder::with_capacity(
+            &DataType::Nullable(Box::new(DataType::Number(NumberDataType::UInt64))),
+            cluster_nodes.len(),
+        );
+        let mut statuses = ColumnBuilder::with_capacity(&DataType::String, cluster_nodes.len());
+        let mut errors = ColumnBuilder::with_capacity(
+            &DataType::Nullable(Box::new(DataType::String)),
+            cluster_nodes.len(),
+        );
 
         let cluster_id = GlobalConfig::instance().query.cluster_id.clone();
         for cluster_node in &cluster_nodes {
@@ -65,7 +86,29 @@ impl SyncSystemTable for ClustersTable {
             clusters.push(Scalar::String(cluster_id.clone()).as_ref());
             addresses.push(Scalar::String(ip).as_ref());
             addresses_port.push(Scalar::Number(NumberScalar::UInt16(port)).as_ref());
-            versions.push(Scalar::String(cluster_node.binary_version.clone()).as_ref());
+
+            // An unreachable node is listed with the version it registered with.
+            match nodes_status.remove(&cluster_node.id) {
+                Some(Ok(status)) => {
+                    versions.push(Scalar::String(status.version).as_ref());
+                    uptimes
+                        .push(Scalar::Number(NumberScalar::UInt64(status.uptime_seconds)).as_ref());
+                    statuses.push(Scalar::String("online".to_string()).as_ref());
+                    errors.push(Scalar::Null.as_ref());
+                }
+                Some(Err(error)) => {
+                    versions.push(Scalar::String(cluster_node.binary_version.clone()).as_ref());
+                    uptimes.push(Scalar::Null.as_ref());
+                    statuses.push(Scalar::String("unreachable".to_string()).as_ref());
+                    errors.push(Scalar::String(error).as_ref());
+                }
+                None => {
+                    versions.push(Scalar::String(cluster_node.binary_version.clone()).as_ref());
+                    uptimes.push(Scalar::Null.as_ref());
+                    statuses.push(Scalar::String("unreachable".to_string()).as_ref());
+                    errors.push(Scalar::Null.as_ref());
+                }
+            }
         }
 
         Ok(DataBlock::new_from_columns(vec![
@@ -74,6 +117,9 @@ impl SyncSystemTable for ClustersTable {
             addresses.build(),
             addresses_port.build(),
             versions.build(),
+            uptimes.build(),
+            statuses.build(),
+            errors.build(),
         ]))
     }
 }
@@ -86,6 +132,15 @@ impl ClustersTable {
             TableField::new("host", TableDataType::String),
             TableField::new("port", TableDataType::Number(NumberDataType::UInt16)),
             TableField::new("version", TableDataType::String),
+            TableField::new(
+                "uptime_seconds",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64))),
+            ),
+            TableField::new("status", TableDataType::String),
+            TableField::new(
+                "last_error",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
         ]);
 
         let table_info = TableInfo {
@@ -100,6 +155,6 @@ impl ClustersTable {
             ..Default::default()
         };
 
-        SyncOneBlockSystemTable::create(ClustersTable { table_info })
+        AsyncOneBlockSystemTable::create(ClustersTable { table_info })
     }
 }
@@ -18,16 +18,18 @@ use databend_common_catalog::catalog_kind::CATALOG_DEFAULT;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::infer_table_schema;
+use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
 use databend_common_expression::utils::FromData;
+use databend_common_expression::ComputedExpr;
 use databend_common_expression::DataBlock;
-use databend_common_expression::Scalar;
 use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchemaRefExt;
-use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
@@ -38,10 +40,13 @@ use databend_common_storages_view::view_table::QUERY;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
 use log::warn;
 
+use crate::table::pushed_down_limit;
 use crate::table::AsyncOneBlockSystemTable;
 use crate::table::AsyncSystemTable;
-use crate::util::find_eq_filter;
+use crate::util::CatalogScope;
 
+/// The rows are always ordered by database and table name, the columns of a table in the order
+/// of its schema.
 pub struct ColumnsTable {
     table_info: TableInfo,
 }
@@ -61,6 +66,7 @@ impl AsyncSystemTable for ColumnsTable {
         push_downs: Option<PushDownInfo>,
     ) -> Result<DataBlock> {
         let rows = self.dump_table_columns(ctx, push_downs).await?;
+        let rows_len = rows.len();
         let mut names: Vec<String> = Vec::with_capacity(rows.len());
         let mut tables: Vec<String> = Vec::with_capacity(rows.len());
         let mut databases: Vec<String> = Vec::with_capacity(rows.len());
@@ -70,7 +76,19 @@ impl AsyncSystemTable for ColumnsTable {
         let mut default_exprs: Vec<String> = Vec::with_capacity(rows.len());
         let mut is_nullables: Vec<String> = Vec::with_capacity(rows.len());
         let mut comments: Vec<String> = Vec::with_capacity(rows.len());
+        let mut ordinal_positions: Vec<u64> = Vec::with_capacity(rows.len());
         for (database_name, table_name, comment, field) in rows.into_iter() {
+            // The columns of a table come one after another, in the order of its schema.
+            let ordinal_position = match ordinal_positions.last() {
+                Some(position)
+                    if databases.last() == Some(&database_name)
+                        && tables.last() == Some(&table_name) =>
+                {
+                    position + 1
+                }
+                _ => 1,
+            };
+            ordinal_positions.push(ordinal_position);
             names.push(field.name().clone());
             tables.push(table_name);
             databases.push(database_name);
@@ -78,15 +96,16 @@ impl AsyncSystemTable for ColumnsTable {
             let data_type = field.data_type().remove_recursive_nullable().sql_name();
             data_types.push(data_type);
 
-            let mut default_kind = "".to_string();
-            let mut default_expr = "".to_string();
-            if let Some(expr) = field.default_expr() {
-                default_kind = "DEFAULT".to_string();
-                default_expr = expr.to_string();
-            }
-            default_kinds.push(default_kind);
+            // The kinds and expressions are those of `SHOW CREATE TABLE`.
+            let (default_kind, default_expr) = match (field.default_expr(), field.computed_expr()) {
+                (Some(expr), _) => ("DEFAULT", expr.clone()),
+                (None, Some(ComputedExpr::Virtual(expr))) => ("VIRTUAL", expr.clone()),
+                (None, Some(ComputedExpr::Stored(expr))) => ("STORED", expr.clone()),
+                (None, None) => ("", "".to_string()),
+            };
+            default_kinds.push(default_kind.to_string());
             default_exprs.push(default_expr);
-            if field.is_nullable() {
+            if field.is_nullable_or_null() {
                 is_nullables.push("YES".to_string());
             } else {
                 is_nullables.push("NO".to_string());
@@ -105,6 +124,9 @@ impl AsyncSystemTable for ColumnsTable {
             StringType::from_data(default_exprs),
             StringType::from_data(is_nullables),
             StringType::from_data(comments),
+            UInt64Type::from_data(ordinal_positions),
+            // The columns are those of the tables in the default catalog only.
+            StringType::from_data(vec![CATALOG_DEFAULT.to_string(); rows_len]),
         ]))
     }
 }
@@ -123,6 +145,12 @@ impl ColumnsTable {
             TableField::new("default_expression", TableDataType::String),
             TableField::new("is_nullable", TableDataType::String),
             TableField::new("comment", TableDataType::String),
+            // 1-based position of the column in its table
+            TableField::new(
+                "ordinal_position",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("catalog", TableDataType::String),
         ]);
 
         let table_info = TableInfo {
@@ -146,11 +174,16 @@ impl ColumnsTable {
         ctx: Arc<dyn TableContext>,
         push_downs: Option<PushDownInfo>,
     ) -> Result<Vec<(String, String, String, TableField)>> {
+        // The views are planned and the streams resolve their source tables, stop at the limit.
+        let limit = pushed_down_limit(&push_downs).unwrap_or(usize::MAX);
         let database_and_tables = dump_tables(&ctx, push_downs).await?;
 
         let mut rows: Vec<(String, String, String, TableField)> = vec![];
-        for (database, tables) in database_and_tables {
+        'tables: for (database, tables) in database_and_tables {
             for table in tables {
+                if rows.len() >= limit {
+                    break 'tables;
+                }
                 match table.engine() {
                     VIEW_ENGINE => {
                         let fields = if let Some(query) = table.options().get(QUERY) {
@@ -185,11 +218,13 @@ impl ColumnsTable {
                         let stream = StreamTable::try_from_table(table.as_ref())?;
                         match stream.source_table(ctx.clone()).await {
                             Ok(source_table) => {
-                                for field in source_table.schema().fields() {
+                                let schema = source_table.schema();
+                                let comments = field_comments(source_table.as_ref());
+                                for (field, comment) in schema.fields().iter().zip(comments) {
                                     rows.push((
                                         database.clone(),
                                         table.name().into(),
-                                        "".to_string(),
+                                        comment,
                                         field.clone(),
                                     ))
                                 }
@@ -205,18 +240,8 @@ impl ColumnsTable {
                     }
                     _ => {
                         let schema = table.schema();
-                        let field_comments = table.field_comments();
-                        let n_fields = schema.fields().len();
-                        for (idx, field) in schema.fields().iter().enumerate() {
-                            // compatibility: creating table in the old planner will not have `fields_comments`
-                            let comment = if field_comments.len() == n_fields
-                                && !field_comments[idx].is_empty()
-                            {
-                                // can not use debug print, will add double quote
-                                format!("'{}'", &field_comments[idx].as_str().replace('\'', "\\'"))
-                            } else {
-                                "".to_string()
-                            };
+                        let comments = field_comments(table.as_ref());
+                        for (field, comment) in schema.fields().iter().zip(comments) {
                             rows.push((
                                 database.clone(),
                                 table.name().into(),
@@ -233,6 +258,18 @@ impl ColumnsTable {
     }
 }
 
+/// The comment of each field of the table, as it was written.
+fn field_comments(table: &dyn Table) -> Vec<String> {
+    let n_fields = table.schema().num_fields();
+    let field_comments = table.field_comments();
+    // compatibility: creating table in the old planner will not have `fields_comments`
+    if field_comments.len() == n_fields {
+        field_comments.clone()
+    } else {
+        vec!["".to_string(); n_fields]
+    }
+}
+
 pub(crate) async fn dump_tables(
     ctx: &Arc<dyn TableContext>,
     push_downs: Option<PushDownInfo>,
@@ -240,73 +277,56 @@ pub(crate) async fn dump_tables(
     let tenant = ctx.get_tenant();
     let catalog = ctx.get_catalog(CATALOG_DEFAULT).await?;
 
-    let mut tables: Vec<String> = Vec::new();
-    let mut databases: Vec<String> = Vec::new();
-
-    if let Some(push_downs) = push_downs {
-        if let Some(filter) = push_downs.filters.as_ref().map(|f| &f.filter) {
-            let expr = filter.as_expr(&BUILTIN_FUNCTIONS);
-            find_eq_filter(&expr, &mut |col_name, scalar| {
-                if col_name == "database" {
-                    if let Scalar::String(database) = scalar {
-                        if !databases.contains(database) {
-                            databases.push(database.clone());
-                        }
-                    }
-                } else if col_name == "table" {
-                    if let Scalar::String(table) = scalar {
-                        if !tables.contains(table) {
-                            tables.push(table.clone());
-                        }
-                    }
-                }
-            });
-        }
-    }
+    // Only the databases and tables named by the filters are read from the meta service.
+    let scope = CatalogScope::from_push_downs(&push_downs, "database", "table");
 
     let visibility_checker = ctx.get_visibility_checker().await?;
 
     let mut final_dbs: Vec<(String, u64)> = Vec::new();
 
-    if databases.is_empty() {
-        let all_databases = catalog.list_databases(&tenant).await?;
-        for db in all_databases {
-            let db_id = db.get_db_info().ident.db_id;
-            let db_name = db.name();
-            if visibility_checker.check_database_visibility(CATALOG_DEFAULT, db_name, db_id) {
-                final_dbs.push((db_name.to_string(), db_id));
+    match scope.databases {
+        None => {
+            let all_databases = catalog.list_databases(&tenant).await?;
+            for db in all_databases {
+                let db_id = db.get_db_info().ident.db_id;
+                let db_name = db.name();
+                if visibility_checker.check_database_visibility(CATALOG_DEFAULT, db_name, db_id) {
+                    final_dbs.push((db_name.to_string(), db_id));
+                }
             }
         }
-    } else {
-        for db in databases {
-            let db_id = catalog
-                .get_database(&tenant, &db)
-                .await?
-                .get_db_info()
-                .ident
-                .db_id;
-            if visibility_checker.check_database_visibility(CATALOG_DEFAULT, &db, db_id) {
-                final_dbs.push((db.to_string(), db_id));
+        Some(databases) => {
+            for db in databases {
+                let db_id = match catalog.get_database(&tenant, &db).await {
+                    Ok(database) => database.get_db_info().ident.db_id,
+                    Err(e) if e.code() == ErrorCode::UNKNOWN_DATABASE => continue,
+                    Err(e) => return Err(e),
+                };
+                if visibility_checker.check_database_visibility(CATALOG_DEFAULT, &db, db_id) {
+                    final_dbs.push((db.to_string(), db_id));
+                }
             }
         }
     }
 
+    // The tables are ordered by database and table name, the columns of a table keep their order.
+    final_dbs.sort_by(|(l, _), (r, _)| l.cmp(r));
     let mut final_tables: Vec<(String, Vec<Arc<dyn Table>>)> = Vec::with_capacity(final_dbs.len());
     for (database, db_id) in final_dbs {
-        let tables = if tables.is_empty() {
-            if let Ok(table) = catalog.list_tables(&tenant, &database).await {
-                table
-            } else {
-                vec![]
-            }
-        } else {
-            let mut res = Vec::new();
-            for table in &tables {
-                if let Ok(table) = catalog.get_table(&tenant, &database, table).await {
-                    res.push(table);
+        let tables = match &scope.tables {
+            None => catalog
+                .list_tables(&tenant, &database)
+                .await
+                .unwrap_or_default(),
+            Some(tables) => {
+                let mut res = Vec::new();
+                for table in tables {
+                    if let Ok(table) = catalog.get_table(&tenant, &database, table).await {
+                        res.push(table);
+                    }
                 }
+                res
             }
-            res
         };
         let mut filtered_tables = Vec::with_capacity(tables.len());
         for table in tables {
@@ -320,6 +340,7 @@ pub(crate) async fn dump_tables(
                 filtered_tables.push(table);
             }
         }
+        filtered_tables.sort_by(|l, r| l.name().cmp(r.name()));
         final_tables.push((database, filtered_tables));
     }
     Ok(final_tables)
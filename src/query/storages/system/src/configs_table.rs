@@ -14,17 +14,21 @@
 
 use std::sync::Arc;
 
+use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_config::Config;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::Result;
+use databend_common_expression::types::BooleanType;
 use databend_common_expression::types::StringType;
 use databend_common_expression::utils::FromData;
 use databend_common_expression::DataBlock;
 use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::principal::GrantObject;
+use databend_common_meta_app::principal::UserPrivilegeType;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
@@ -32,26 +36,40 @@ use itertools::Itertools;
 use serde_json::Value as JsonValue;
 use serde_json::Value;
 
-use crate::SyncOneBlockSystemTable;
-use crate::SyncSystemTable;
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+/// The patterns of the config names whose values are secrets, matched against the last part of
+/// a name, like `secret_access_key` of `s3.secret_access_key`.
+const SECRET_CONFIG_SUFFIXES: &[&str] = &["_key"];
+const SECRET_CONFIG_PARTS: &[&str] = &["secret", "password", "token"];
+
+const MASKED_CONFIG_VALUE: &str = "******";
 
 pub struct ConfigsTable {
     table_info: TableInfo,
 }
 
-impl SyncSystemTable for ConfigsTable {
+#[async_trait::async_trait]
+impl AsyncSystemTable for ConfigsTable {
     const NAME: &'static str = "system.config";
 
     fn get_table_info(&self) -> &TableInfo {
         &self.table_info
     }
 
-    fn get_full_data(&self, _ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
-        let config = GlobalConfig::instance()
-            .as_ref()
-            .clone()
-            .into_config()
-            .with_mask();
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let show_secrets = Self::can_show_secrets(&ctx).await;
+        let config = GlobalConfig::instance().as_ref().clone().into_config();
+        let config = match show_secrets {
+            true => config,
+            false => config.with_mask(),
+        };
         let mut names: Vec<String> = vec![];
         let mut values: Vec<String> = vec![];
         let mut groups: Vec<String> = vec![];
@@ -61,6 +79,7 @@ impl SyncSystemTable for ConfigsTable {
 
         // Obsolete.
         let query_config_value = Self::remove_obsolete_configs(serde_json::to_value(query_config)?);
+        let query_config_value = Self::count_mysql_federated_rules(query_config_value);
 
         ConfigsTable::extract_config(
             &mut names,
@@ -115,15 +134,35 @@ impl SyncSystemTable for ConfigsTable {
             storage_config_value,
         );
 
+        let is_secrets: Vec<bool> = names.iter().map(|name| is_secret_config(name)).collect();
+        if !show_secrets {
+            for (value, is_secret) in values.iter_mut().zip(is_secrets.iter()) {
+                // An unset secret is shown as is, there is nothing to hide.
+                if *is_secret && !value.is_empty() && value != "null" {
+                    *value = MASKED_CONFIG_VALUE.to_string();
+                }
+            }
+        }
+
         Ok(DataBlock::new_from_columns(vec![
             StringType::from_data(groups),
             StringType::from_data(names),
             StringType::from_data(values),
             StringType::from_data(descs),
+            BooleanType::from_data(is_secrets),
         ]))
     }
 }
 
+/// Returns true if the value of the config is a secret, like an access key or a password.
+fn is_secret_config(name: &str) -> bool {
+    let name = name.rsplit('.').next().unwrap_or(name).to_lowercase();
+    SECRET_CONFIG_SUFFIXES
+        .iter()
+        .any(|suffix| name.ends_with(suffix))
+        || SECRET_CONFIG_PARTS.iter().any(|part| name.contains(part))
+}
+
 impl ConfigsTable {
     pub fn create(table_id: u64) -> Arc<dyn Table> {
         let schema = TableSchemaRefExt::create(vec![
@@ -131,6 +170,7 @@ impl ConfigsTable {
             TableField::new("name", TableDataType::String),
             TableField::new("value", TableDataType::String),
             TableField::new("description", TableDataType::String),
+            TableField::new("is_secret", TableDataType::Boolean),
         ]);
 
         let table_info = TableInfo {
@@ -145,7 +185,14 @@ impl ConfigsTable {
             ..Default::default()
         };
 
-        SyncOneBlockSystemTable::create(ConfigsTable { table_info })
+        AsyncOneBlockSystemTable::create(ConfigsTable { table_info })
+    }
+
+    /// The secrets are shown to the users holding the SUPER privilege.
+    async fn can_show_secrets(ctx: &Arc<dyn TableContext>) -> bool {
+        ctx.validate_privilege(&GrantObject::Global, UserPrivilegeType::Super, false)
+            .await
+            .is_ok()
     }
 
     fn extract_config(
@@ -272,6 +319,20 @@ impl ConfigsTable {
         descs.push(desc);
     }
 
+    /// The MySQL federated rules are listed as the count of the loaded rules.
+    fn count_mysql_federated_rules(config_json: JsonValue) -> JsonValue {
+        match config_json {
+            Value::Object(mut config_json_obj) => {
+                if let Some(Value::Array(rules)) = config_json_obj.get("mysql_federated_rules") {
+                    let count = JsonValue::from(rules.len());
+                    config_json_obj.insert("mysql_federated_rules".to_string(), count);
+                }
+                JsonValue::Object(config_json_obj)
+            }
+            _ => config_json,
+        }
+    }
+
     fn remove_obsolete_configs(config_json: JsonValue) -> JsonValue {
         match config_json {
             Value::Object(mut config_json_obj) => {
@@ -16,9 +16,11 @@ use std::sync::Arc;
 
 use databend_common_catalog::catalog::Catalog;
 use databend_common_catalog::catalog::CatalogManager;
+use databend_common_catalog::catalog_kind::CATALOG_DEFAULT;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::StringType;
@@ -28,6 +30,7 @@ use databend_common_expression::DataBlock;
 use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchemaRefExt;
+use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_meta_app::principal::OwnershipObject;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
@@ -36,7 +39,9 @@ use databend_common_users::UserApiProvider;
 
 use crate::table::AsyncOneBlockSystemTable;
 use crate::table::AsyncSystemTable;
+use crate::util::find_string_values;
 
+/// The rows are always ordered by catalog and database name.
 pub struct DatabasesTable {
     table_info: TableInfo,
 }
@@ -53,17 +58,25 @@ impl AsyncSystemTable for DatabasesTable {
     async fn get_full_data(
         &self,
         ctx: Arc<dyn TableContext>,
-        _push_downs: Option<PushDownInfo>,
+        push_downs: Option<PushDownInfo>,
     ) -> Result<DataBlock> {
         let tenant = ctx.get_tenant();
 
+        // Only the databases named by the filters are read from the catalogs.
+        let names = push_downs
+            .as_ref()
+            .and_then(|p| p.filters.as_ref())
+            .and_then(|f| find_string_values(&f.filter.as_expr(&BUILTIN_FUNCTIONS), "name"));
+
         let catalogs = CatalogManager::instance();
-        let catalogs: Vec<(String, Arc<dyn Catalog>)> = catalogs
+        // The rows are ordered by catalog and database name.
+        let mut catalogs: Vec<(String, Arc<dyn Catalog>)> = catalogs
             .list_catalogs(&tenant, ctx.txn_mgr())
             .await?
             .iter()
             .map(|e| (e.name(), e.clone()))
             .collect();
+        catalogs.sort_by(|(l, _), (r, _)| l.cmp(r));
 
         let user_api = UserApiProvider::instance();
         let mut catalog_names = vec![];
@@ -74,8 +87,21 @@ impl AsyncSystemTable for DatabasesTable {
         let visibility_checker = ctx.get_visibility_checker().await?;
 
         for (ctl_name, catalog) in catalogs.into_iter() {
-            let databases = catalog.list_databases(&tenant).await?;
-            let final_dbs = databases
+            let databases = match &names {
+                Some(names) => {
+                    let mut databases = Vec::with_capacity(names.len());
+                    for name in names {
+                        match catalog.get_database(&tenant, name).await {
+                            Ok(database) => databases.push(database),
+                            Err(e) if e.code() == ErrorCode::UNKNOWN_DATABASE => {}
+                            Err(e) => return Err(e),
+                        }
+                    }
+                    databases
+                }
+                None => catalog.list_databases(&tenant).await?,
+            };
+            let mut final_dbs = databases
                 .into_iter()
                 .filter(|db| {
                     visibility_checker.check_database_visibility(
@@ -85,6 +111,7 @@ impl AsyncSystemTable for DatabasesTable {
                     )
                 })
                 .collect::<Vec<_>>();
+            final_dbs.sort_by(|l, r| l.name().cmp(r.name()));
 
             for db in final_dbs {
                 catalog_names.push(ctl_name.clone());
@@ -112,6 +139,14 @@ impl AsyncSystemTable for DatabasesTable {
             StringType::from_opt_data(owners),
         ]))
     }
+
+    #[async_backtrace::framed]
+    async fn estimated_rows(&self, ctx: Arc<dyn TableContext>) -> Result<Option<u64>> {
+        // Only the default catalog is counted, listing the others may reach external services.
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT).await?;
+        let databases = catalog.list_databases(&ctx.get_tenant()).await?;
+        Ok(Some(databases.len() as u64))
+    }
 }
 
 impl DatabasesTable {
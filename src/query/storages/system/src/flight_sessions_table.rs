@@ -0,0 +1,167 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::sync::Weak;
+
+use databend_common_base::base::GlobalInstance;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::number::UInt64Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use parking_lot::Mutex;
+
+use crate::SyncOneBlockSystemTable;
+use crate::SyncSystemTable;
+
+/// A session of a FlightSQL client, the times are in unix microseconds.
+pub struct FlightSessionInfo {
+    /// Hash of the bearer token, so that the token itself is never shown.
+    pub token_hash: String,
+    pub user: String,
+    pub client_address: Option<String>,
+    pub created_at: i64,
+    pub last_active_at: i64,
+    pub prepared_statement_count: u64,
+    pub database: String,
+}
+
+pub trait FlightSessionsProvider: Send + Sync {
+    fn list_sessions(&self) -> Vec<FlightSessionInfo>;
+}
+
+/// The FlightSQL services of this node, read by `system.flight_sessions`.
+///
+/// A service registers itself when it is created, and is dropped from the registry along with
+/// the service, the registry only holds weak references.
+#[derive(Default)]
+pub struct FlightSessionsRegistry {
+    providers: Mutex<Vec<Weak<dyn FlightSessionsProvider>>>,
+}
+
+impl FlightSessionsRegistry {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Arc::new(FlightSessionsRegistry::default()));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<FlightSessionsRegistry> {
+        GlobalInstance::get()
+    }
+
+    pub fn register(&self, provider: Weak<dyn FlightSessionsProvider>) {
+        let mut providers = self.providers.lock();
+        providers.retain(|p| p.strong_count() > 0);
+        providers.push(provider);
+    }
+
+    pub fn list_sessions(&self) -> Vec<FlightSessionInfo> {
+        let providers = self
+            .providers
+            .lock()
+            .iter()
+            .filter_map(|p| p.upgrade())
+            .collect::<Vec<_>>();
+        providers.iter().flat_map(|p| p.list_sessions()).collect()
+    }
+}
+
+pub struct FlightSessionsTable {
+    table_info: TableInfo,
+}
+
+impl SyncSystemTable for FlightSessionsTable {
+    const NAME: &'static str = "system.flight_sessions";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, _ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let sessions = FlightSessionsRegistry::instance().list_sessions();
+
+        let mut token_hashes = Vec::with_capacity(sessions.len());
+        let mut users = Vec::with_capacity(sessions.len());
+        let mut client_addresses = Vec::with_capacity(sessions.len());
+        let mut created_ats = Vec::with_capacity(sessions.len());
+        let mut last_active_ats = Vec::with_capacity(sessions.len());
+        let mut prepared_statement_counts = Vec::with_capacity(sessions.len());
+        let mut databases = Vec::with_capacity(sessions.len());
+        for session in sessions {
+            token_hashes.push(session.token_hash);
+            users.push(session.user);
+            client_addresses.push(session.client_address);
+            created_ats.push(session.created_at);
+            last_active_ats.push(session.last_active_at);
+            prepared_statement_counts.push(session.prepared_statement_count);
+            databases.push(session.database);
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(token_hashes),
+            StringType::from_data(users),
+            StringType::from_opt_data(client_addresses),
+            TimestampType::from_data(created_ats),
+            TimestampType::from_data(last_active_ats),
+            UInt64Type::from_data(prepared_statement_counts),
+            StringType::from_data(databases),
+        ]))
+    }
+}
+
+impl FlightSessionsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("token_hash", TableDataType::String),
+            TableField::new("user", TableDataType::String),
+            TableField::new(
+                "client_address",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new("created_at", TableDataType::Timestamp),
+            TableField::new("last_active_at", TableDataType::Timestamp),
+            TableField::new(
+                "prepared_statement_count",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("database", TableDataType::String),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'flight_sessions'".to_string(),
+            name: "flight_sessions".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemFlightSessions".to_string(),
+
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        SyncOneBlockSystemTable::create(FlightSessionsTable { table_info })
+    }
+}
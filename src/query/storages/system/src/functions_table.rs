@@ -27,14 +27,17 @@ use databend_common_expression::TableField;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_functions::aggregates::AggregateFunctionFactory;
 use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_functions::GENERAL_WINDOW_FUNCTIONS;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_sql::TypeChecker;
 
+use crate::table::pushed_down_limit;
 use crate::table::AsyncOneBlockSystemTable;
 use crate::table::AsyncSystemTable;
 
+/// The rows are always ordered by function name.
 pub struct FunctionsTable {
     table_info: TableInfo,
 }
@@ -51,7 +54,7 @@ impl AsyncSystemTable for FunctionsTable {
     async fn get_full_data(
         &self,
         _: Arc<dyn TableContext>,
-        _push_downs: Option<PushDownInfo>,
+        push_downs: Option<PushDownInfo>,
     ) -> Result<DataBlock> {
         let mut scalar_func_names: Vec<String> = BUILTIN_FUNCTIONS.registered_names();
         scalar_func_names.extend(
@@ -64,25 +67,63 @@ impl AsyncSystemTable for FunctionsTable {
                 .iter()
                 .map(|name| name.to_lowercase()),
         );
-        scalar_func_names.sort();
         let aggregate_function_factory = AggregateFunctionFactory::instance();
         let aggr_func_names = aggregate_function_factory.registered_names();
+        // The window functions that are not aggregate functions too.
+        let window_func_names = GENERAL_WINDOW_FUNCTIONS
+            .iter()
+            .filter(|name| !aggregate_function_factory.contains(name))
+            .map(|name| name.to_string())
+            .collect::<Vec<_>>();
 
-        let names: Vec<&str> = scalar_func_names
+        // The rows are ordered by name, a name of both kinds is listed as a scalar function first.
+        let mut functions: Vec<(&str, bool)> = scalar_func_names
             .iter()
-            .chain(&aggr_func_names)
-            .map(|x| x.as_str())
+            .map(|name| (name.as_str(), false))
+            .chain(aggr_func_names.iter().map(|name| (name.as_str(), true)))
+            .chain(window_func_names.iter().map(|name| (name.as_str(), false)))
             .collect();
-
-        let is_aggregate = (0..names.len())
-            .map(|i| i >= scalar_func_names.len())
+        functions.sort();
+        // The docs are only looked up for the rows within the limit.
+        if let Some(limit) = pushed_down_limit(&push_downs) {
+            functions.truncate(limit);
+        }
+        let (names, is_aggregate): (Vec<&str>, Vec<bool>) = functions.into_iter().unzip();
+        let is_window = names
+            .iter()
+            .map(|name| GENERAL_WINDOW_FUNCTIONS.contains(name))
             .collect::<Vec<bool>>();
 
-        let descriptions = (0..names.len()).map(|_| "").collect::<Vec<&str>>();
-
-        let syntaxes = (0..names.len()).map(|_| "").collect::<Vec<&str>>();
-
-        let examples = (0..names.len()).map(|_| "").collect::<Vec<&str>>();
+        let docs = names
+            .iter()
+            .zip(&is_aggregate)
+            .map(|(name, is_aggregate)| match is_aggregate {
+                true => aggregate_function_factory.get_doc(name),
+                false => BUILTIN_FUNCTIONS.get_doc(name).copied(),
+            })
+            .collect::<Vec<_>>();
+        let categories = docs
+            .iter()
+            .zip(is_aggregate.iter().zip(&is_window))
+            .map(|(doc, kind)| match (doc, kind) {
+                (Some(doc), _) => doc.category,
+                (None, (true, _)) => "aggregate",
+                (None, (_, true)) => "window",
+                (None, _) => "",
+            })
+            .collect::<Vec<&str>>();
+        let descriptions = docs
+            .iter()
+            .map(|doc| doc.map_or("", |doc| doc.description))
+            .collect::<Vec<&str>>();
+        let syntaxes = docs
+            .iter()
+            .map(|doc| doc.map_or("", |doc| doc.syntax))
+            .collect::<Vec<&str>>();
+        let examples = docs
+            .iter()
+            .map(|doc| doc.map_or("", |doc| doc.example))
+            .collect::<Vec<&str>>();
 
         Ok(DataBlock::new_from_columns(vec![
             StringType::from_data(names),
@@ -90,6 +131,8 @@ impl AsyncSystemTable for FunctionsTable {
             StringType::from_data(descriptions),
             StringType::from_data(syntaxes),
             StringType::from_data(examples),
+            BooleanType::from_data(is_window),
+            StringType::from_data(categories),
         ]))
     }
 }
@@ -102,6 +145,8 @@ impl FunctionsTable {
             TableField::new("description", TableDataType::String),
             TableField::new("syntax", TableDataType::String),
             TableField::new("example", TableDataType::String),
+            TableField::new("is_window", TableDataType::Boolean),
+            TableField::new("category", TableDataType::String),
         ]);
 
         let table_info = TableInfo {
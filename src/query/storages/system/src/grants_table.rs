@@ -0,0 +1,204 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::principal::GrantEntry;
+use databend_common_meta_app::principal::GrantObject;
+use databend_common_meta_app::principal::UserPrivilegeSet;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+/// The privileges granted directly to each user and role, a row per object.
+///
+/// The privileges inherited through the granted roles are not repeated, they are the rows of
+/// those roles.
+pub struct GrantsTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for GrantsTable {
+    const NAME: &'static str = "system.grants";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let user_api = UserApiProvider::instance();
+
+        let mut grants: Vec<(&str, String, GrantEntry)> = vec![];
+        for user in user_api.get_users(&tenant).await? {
+            for entry in user.grants.entries() {
+                grants.push(("USER", user.name.clone(), entry));
+            }
+        }
+        for role in user_api.get_roles(&tenant).await? {
+            for entry in role.grants.entries() {
+                grants.push(("ROLE", role.name.clone(), entry));
+            }
+        }
+
+        // The objects granted by id are named as they are now, the dropped ones are left out.
+        let mut db_ids: HashMap<String, Vec<u64>> = HashMap::new();
+        let mut table_ids: HashMap<String, Vec<u64>> = HashMap::new();
+        for (_, _, entry) in &grants {
+            match entry.object() {
+                GrantObject::DatabaseById(catalog, db_id) => {
+                    db_ids.entry(catalog.clone()).or_default().push(*db_id);
+                }
+                GrantObject::TableById(catalog, db_id, table_id) => {
+                    db_ids.entry(catalog.clone()).or_default().push(*db_id);
+                    table_ids
+                        .entry(catalog.clone())
+                        .or_default()
+                        .push(*table_id);
+                }
+                _ => {}
+            }
+        }
+        let mut db_names: HashMap<(String, u64), String> = HashMap::new();
+        for (catalog_name, ids) in db_ids {
+            let catalog = ctx.get_catalog(&catalog_name).await?;
+            let names = catalog.mget_database_names_by_ids(&tenant, &ids).await?;
+            for (id, name) in ids.into_iter().zip(names) {
+                if let Some(name) = name {
+                    db_names.insert((catalog_name.clone(), id), name);
+                }
+            }
+        }
+        let mut table_names: HashMap<(String, u64), String> = HashMap::new();
+        for (catalog_name, ids) in table_ids {
+            let catalog = ctx.get_catalog(&catalog_name).await?;
+            let names = catalog.mget_table_names_by_ids(&tenant, &ids).await?;
+            for (id, name) in ids.into_iter().zip(names) {
+                if let Some(name) = name {
+                    table_names.insert((catalog_name.clone(), id), name);
+                }
+            }
+        }
+
+        let mut grantee_types = vec![];
+        let mut grantee_names = vec![];
+        let mut object_types = vec![];
+        let mut object_names = vec![];
+        let mut object_ids = vec![];
+        let mut privileges = vec![];
+        for (grantee_type, grantee_name, entry) in grants {
+            let (object_type, object_name, object_id) = match entry.object() {
+                GrantObject::Global => ("GLOBAL", "*.*".to_string(), None),
+                GrantObject::Database(catalog, db) => {
+                    ("DATABASE", format!("{catalog}.{db}.*"), None)
+                }
+                GrantObject::DatabaseById(catalog, db_id) => {
+                    match db_names.get(&(catalog.clone(), *db_id)) {
+                        Some(db) => ("DATABASE", format!("{catalog}.{db}.*"), Some(*db_id)),
+                        None => continue,
+                    }
+                }
+                GrantObject::Table(catalog, db, table) => {
+                    ("TABLE", format!("{catalog}.{db}.{table}"), None)
+                }
+                GrantObject::TableById(catalog, db_id, table_id) => {
+                    match (
+                        db_names.get(&(catalog.clone(), *db_id)),
+                        table_names.get(&(catalog.clone(), *table_id)),
+                    ) {
+                        (Some(db), Some(table)) => {
+                            ("TABLE", format!("{catalog}.{db}.{table}"), Some(*table_id))
+                        }
+                        _ => continue,
+                    }
+                }
+                GrantObject::Stage(stage) => ("STAGE", stage.clone(), None),
+                GrantObject::UDF(udf) => ("UDF", udf.clone(), None),
+            };
+
+            grantee_types.push(grantee_type.to_string());
+            grantee_names.push(grantee_name);
+            object_types.push(object_type.to_string());
+            object_names.push(object_name);
+            object_ids.push(object_id);
+            privileges.push(if entry.has_all_available_privileges() {
+                "ALL".to_string()
+            } else {
+                UserPrivilegeSet::from(*entry.privileges()).to_string()
+            });
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(grantee_types),
+            StringType::from_data(grantee_names),
+            StringType::from_data(object_types),
+            StringType::from_data(object_names),
+            UInt64Type::from_opt_data(object_ids),
+            StringType::from_data(privileges),
+        ]))
+    }
+}
+
+impl GrantsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("grantee_type", TableDataType::String),
+            TableField::new("grantee_name", TableDataType::String),
+            TableField::new("object_type", TableDataType::String),
+            TableField::new("object_name", TableDataType::String),
+            TableField::new(
+                "object_id",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64))),
+            ),
+            TableField::new("privileges", TableDataType::String),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'grants'".to_string(),
+            name: "grants".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemGrants".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(GrantsTable { table_info })
+    }
+}
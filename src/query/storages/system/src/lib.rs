@@ -34,7 +34,10 @@ mod contributors_table;
 mod credits_table;
 mod databases_table;
 mod engines_table;
+mod flight_sessions_table;
 mod functions_table;
+mod global_status_table;
+mod grants_table;
 mod indexes_table;
 mod locks_table;
 mod log_queue;
@@ -45,6 +48,7 @@ mod notification_history_table;
 mod notifications_table;
 mod one_table;
 mod password_policies_table;
+mod prepared_statements_table;
 mod processes_table;
 mod queries_profiling;
 mod query_cache_table;
@@ -63,6 +67,7 @@ mod user_functions_table;
 mod users_table;
 mod util;
 mod virtual_columns_table;
+mod warnings_table;
 
 pub use background_jobs_table::BackgroundJobTable;
 pub use background_tasks_table::BackgroundTaskTable;
@@ -80,9 +85,16 @@ pub use contributors_table::ContributorsTable;
 pub use credits_table::CreditsTable;
 pub use databases_table::DatabasesTable;
 pub use engines_table::EnginesTable;
+pub use flight_sessions_table::FlightSessionInfo;
+pub use flight_sessions_table::FlightSessionsProvider;
+pub use flight_sessions_table::FlightSessionsRegistry;
+pub use flight_sessions_table::FlightSessionsTable;
 pub use functions_table::FunctionsTable;
+pub use global_status_table::GlobalStatusTable;
+pub use grants_table::GrantsTable;
 pub use indexes_table::IndexesTable;
 pub use locks_table::LocksTable;
+pub use log_queue::LogRetention;
 pub use log_queue::SystemLogElement;
 pub use log_queue::SystemLogQueue;
 pub use log_queue::SystemLogTable;
@@ -94,10 +106,15 @@ pub use notifications_table::parse_notifications_to_datablock;
 pub use notifications_table::NotificationsTable;
 pub use one_table::OneTable;
 pub use password_policies_table::PasswordPoliciesTable;
+pub use prepared_statements_table::PreparedStatementInfo;
+pub use prepared_statements_table::PreparedStatementsProvider;
+pub use prepared_statements_table::PreparedStatementsRegistry;
+pub use prepared_statements_table::PreparedStatementsTable;
 pub use processes_table::ProcessesTable;
 pub use queries_profiling::ProfilesLogElement;
 pub use queries_profiling::ProfilesLogQueue;
 pub use queries_profiling::QueriesProfilingTable;
+pub use queries_profiling::QueryProfileTable;
 pub use query_cache_table::QueryCacheTable;
 pub use query_log_table::LogType;
 pub use query_log_table::QueryLogElement;
@@ -123,4 +140,7 @@ pub use tasks_table::TasksTable;
 pub use temp_files_table::TempFilesTable;
 pub use user_functions_table::UserFunctionsTable;
 pub use users_table::UsersTable;
+pub use util::visible_user;
+pub use util::CatalogScope;
 pub use virtual_columns_table::VirtualColumnsTable;
+pub use warnings_table::WarningsTable;
@@ -12,9 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
-use databend_common_catalog::catalog::CatalogManager;
+use databend_common_catalog::lock::LockSnapshot;
+use databend_common_catalog::lock::LOCK_OBJECT;
+use databend_common_catalog::lock::LOCK_SESSION_ID;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
@@ -40,6 +43,10 @@ use crate::table::AsyncOneBlockSystemTable;
 use crate::table::AsyncSystemTable;
 use crate::util::find_eq_filter;
 
+/// The table locks held or waited for, one row per lock revision.
+///
+/// `status` is `HOLDING` for at most one revision per table, whose `acquired_on` is set;
+/// a `WAITING` revision has been queued since `created_on`.
 pub struct LocksTable {
     table_info: TableInfo,
 }
@@ -59,71 +66,81 @@ impl AsyncSystemTable for LocksTable {
         push_downs: Option<PushDownInfo>,
     ) -> Result<DataBlock> {
         let tenant = ctx.get_tenant();
-        let catalog_mgr = CatalogManager::instance();
-        let ctls = catalog_mgr.list_catalogs(&tenant, ctx.txn_mgr()).await?;
+        // Locks are kept per tenant in the meta service, which only the default catalog
+        // reads. Listing them once also keeps a lock from being reported by several catalogs.
+        let catalog = ctx.get_default_catalog()?;
 
         let mut lock_table_id = Vec::new();
         let mut lock_revision = Vec::new();
         let mut lock_type = Vec::new();
+        let mut lock_object = Vec::new();
         let mut lock_status = Vec::new();
         let mut lock_user = Vec::new();
+        let mut lock_session_id = Vec::new();
         let mut lock_node = Vec::new();
         let mut lock_query_id = Vec::new();
         let mut lock_created_on = Vec::new();
         let mut lock_acquired_on = Vec::new();
         let mut lock_extra_info = Vec::new();
-        for ctl in ctls.into_iter() {
-            let mut table_ids = Vec::new();
-            if let Some(push_downs) = &push_downs {
-                if let Some(filter) = push_downs.filters.as_ref().map(|f| &f.filter) {
-                    let expr = filter.as_expr(&BUILTIN_FUNCTIONS);
-                    find_eq_filter(&expr, &mut |col_name, scalar| {
-                        if col_name == "table_id" {
-                            if let Scalar::Number(s) = scalar {
-                                if let Some(v) = s.as_u_int64() {
-                                    if !table_ids.contains(v) {
-                                        table_ids.push(*v);
-                                    }
+
+        let mut table_ids = Vec::new();
+        if let Some(push_downs) = &push_downs {
+            if let Some(filter) = push_downs.filters.as_ref().map(|f| &f.filter) {
+                let expr = filter.as_expr(&BUILTIN_FUNCTIONS);
+                find_eq_filter(&expr, &mut |col_name, scalar| {
+                    if col_name == "table_id" {
+                        if let Scalar::Number(s) = scalar {
+                            if let Some(v) = s.as_u_int64() {
+                                if !table_ids.contains(v) {
+                                    table_ids.push(*v);
                                 }
                             }
                         }
-                    });
-                }
+                    }
+                });
             }
+        }
+
+        let req = if table_ids.is_empty() {
+            ListLocksReq::create(&tenant)
+        } else {
+            ListLocksReq::create_with_table_ids(&tenant, table_ids)
+        };
+        let snapshot = LockSnapshot::load(catalog.as_ref(), req).await?;
+        for entry in snapshot.entries {
+            lock_object.push(entry.object().map(|v| v.to_string()));
+            lock_session_id.push(entry.session_id().map(|v| v.to_string()));
+            lock_status.push(entry.status.to_string());
 
-            let req = if table_ids.is_empty() {
-                ListLocksReq::create(&tenant)
+            let info = entry.info;
+            lock_table_id.push(info.table_id);
+            lock_revision.push(info.revision);
+            lock_type.push(info.meta.lock_type.to_string());
+            lock_user.push(info.meta.user);
+            lock_node.push(info.meta.node);
+            lock_query_id.push(info.meta.query_id);
+            lock_created_on.push(info.meta.created_on.timestamp_micros());
+            lock_acquired_on.push(info.meta.acquired_on.map(|v| v.timestamp_micros()));
+            let extra_info = info
+                .meta
+                .extra_info
+                .into_iter()
+                .filter(|(k, _)| k != LOCK_SESSION_ID && k != LOCK_OBJECT)
+                .collect::<BTreeMap<_, _>>();
+            if extra_info.is_empty() {
+                lock_extra_info.push("".to_string());
             } else {
-                ListLocksReq::create_with_table_ids(&tenant, table_ids)
-            };
-            let lock_infos = ctl.list_locks(req).await?;
-            for info in lock_infos {
-                lock_table_id.push(info.table_id);
-                lock_revision.push(info.revision);
-                lock_type.push(info.meta.lock_type.to_string().clone());
-                if info.meta.acquired_on.is_some() {
-                    lock_status.push("HOLDING");
-                } else {
-                    lock_status.push("WAITING");
-                }
-                lock_user.push(info.meta.user.clone());
-                lock_node.push(info.meta.node.clone());
-                lock_query_id.push(info.meta.query_id.clone());
-                lock_created_on.push(info.meta.created_on.timestamp_micros());
-                lock_acquired_on.push(info.meta.acquired_on.map(|v| v.timestamp_micros()));
-                if info.meta.extra_info.is_empty() {
-                    lock_extra_info.push("".to_string());
-                } else {
-                    lock_extra_info.push(format!("{:?}", info.meta.extra_info));
-                }
+                lock_extra_info.push(format!("{:?}", extra_info));
             }
         }
         Ok(DataBlock::new_from_columns(vec![
             UInt64Type::from_data(lock_table_id),
             UInt64Type::from_data(lock_revision),
             StringType::from_data(lock_type),
+            StringType::from_opt_data(lock_object),
             StringType::from_data(lock_status),
             StringType::from_data(lock_user),
+            StringType::from_opt_data(lock_session_id),
             StringType::from_data(lock_node),
             StringType::from_data(lock_query_id),
             TimestampType::from_data(lock_created_on),
@@ -139,8 +156,16 @@ impl LocksTable {
             TableField::new("table_id", TableDataType::Number(NumberDataType::UInt64)),
             TableField::new("revision", TableDataType::Number(NumberDataType::UInt64)),
             TableField::new("type", TableDataType::String),
+            TableField::new(
+                "object",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
             TableField::new("status", TableDataType::String),
             TableField::new("user", TableDataType::String),
+            TableField::new(
+                "session_id",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
             TableField::new("node", TableDataType::String),
             TableField::new("query_id", TableDataType::String),
             TableField::new("created_on", TableDataType::Timestamp),
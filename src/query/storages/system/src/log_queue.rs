@@ -16,9 +16,14 @@ use std::any::Any;
 use std::any::TypeId;
 use std::collections::HashMap;
 use std::marker::PhantomData;
+use std::ops::Range;
 use std::sync::Arc;
+use std::time::Duration;
 
+use chrono::Utc;
 use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::plan::PartInfo;
+use databend_common_catalog::plan::PartInfoPtr;
 use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
@@ -31,18 +36,22 @@ use databend_common_expression::types::DataType;
 use databend_common_expression::ColumnBuilder;
 use databend_common_expression::DataBlock;
 use databend_common_expression::TableSchemaRef;
+use databend_common_meta_app::principal::UserIdentity;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_core::SourcePipeBuilder;
+use databend_common_pipeline_sources::EmptySource;
 use databend_common_pipeline_sources::SyncSource;
 use databend_common_pipeline_sources::SyncSourcer;
 use once_cell::sync::OnceCell;
 use parking_lot::RwLock;
 
-use crate::table::SystemTablePart;
+use crate::table::pushed_down_limit;
+use crate::util::visible_user;
 
 pub trait SystemLogElement: Send + Sync + Clone {
     const TABLE_NAME: &'static str;
@@ -50,10 +59,31 @@ pub trait SystemLogElement: Send + Sync + Clone {
     fn schema() -> TableSchemaRef;
 
     fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()>;
+
+    /// Unix microseconds of the event, the events without a time are not evicted by age.
+    fn event_time(&self) -> Option<i64> {
+        None
+    }
+
+    /// The user the event belongs to, the events of a user are hidden from the other
+    /// users without the SUPER privilege.
+    fn user(&self) -> Option<UserIdentity> {
+        None
+    }
+}
+
+/// Limits on the events kept by a queue, below its capacity.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LogRetention {
+    /// Keeps the newest rows only, 0 means up to the capacity of the queue.
+    pub max_rows: usize,
+    /// Drops the events older than it.
+    pub max_age: Option<Duration>,
 }
 
 pub struct Data<Event: SystemLogElement> {
     index: usize,
+    len: usize,
     pub event_queue: Vec<Option<Event>>,
 }
 
@@ -61,9 +91,29 @@ impl<Event: SystemLogElement> Data<Event> {
     pub fn new(size: usize) -> Data<Event> {
         Data::<Event> {
             index: 0,
+            len: 0,
             event_queue: vec![None; size],
         }
     }
+
+    fn clear(&mut self) {
+        self.index = 0;
+        self.len = 0;
+        self.event_queue.iter_mut().for_each(|event| *event = None);
+    }
+
+    /// Drops the oldest events, which are the `len` ones before `index`, while `evict` is true.
+    fn evict_oldest(&mut self, mut evict: impl FnMut(usize, &Event) -> bool) {
+        let capacity = self.event_queue.len();
+        while self.len > 0 {
+            let oldest = (self.index + capacity - self.len) % capacity;
+            if !matches!(&self.event_queue[oldest], Some(event) if evict(self.len, event)) {
+                break;
+            }
+            self.event_queue[oldest] = None;
+            self.len -= 1;
+        }
+    }
 }
 
 pub struct SystemLogQueue<Event: SystemLogElement> {
@@ -107,17 +157,44 @@ impl<Event: SystemLogElement + 'static> SystemLogQueue<Event> {
     }
 
     pub fn append_data(&self, event: Event) -> Result<()> {
+        self.append_data_with_retention(event, LogRetention::default())
+    }
+
+    /// Appends the event, then evicts the oldest events beyond the retention.
+    pub fn append_data_with_retention(&self, event: Event, retention: LogRetention) -> Result<()> {
         let mut write_guard = self.data.write();
         let cur_index = write_guard.index;
-        write_guard.event_queue[cur_index] = Some(event);
+        if write_guard.event_queue[cur_index].replace(event).is_none() {
+            write_guard.len += 1;
+        }
         write_guard.index += 1;
 
         if write_guard.index == self.max_rows {
             write_guard.index = 0;
         }
 
+        let max_rows = match retention.max_rows {
+            0 => self.max_rows,
+            max_rows => max_rows,
+        };
+        let min_event_time = retention
+            .max_age
+            .map(|age| Utc::now().timestamp_micros() - age.as_micros() as i64);
+        write_guard.evict_oldest(|len, event| {
+            len > max_rows
+                || matches!(
+                    (min_event_time, event.event_time()),
+                    (Some(min), Some(time)) if time < min
+                )
+        });
+
         Ok(())
     }
+
+    /// Drops all the events at once, a concurrent reader sees either all or none of them.
+    pub fn clear(&self) {
+        self.data.write().clear();
+    }
 }
 
 pub struct SystemLogTable<Event: SystemLogElement> {
@@ -161,96 +238,188 @@ impl<Event: SystemLogElement + 'static> Table for SystemLogTable<Event> {
     #[async_backtrace::framed]
     async fn read_partitions(
         &self,
-        _ctx: Arc<dyn TableContext>,
-        _push_downs: Option<PushDownInfo>,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
         _dry_run: bool,
     ) -> Result<(PartStatistics, Partitions)> {
+        let log_queue = SystemLogQueue::<Event>::instance()?;
+        let num_rows = log_queue.data.read().event_queue.iter().flatten().count();
+        let read_rows = pushed_down_limit(&push_downs).map_or(num_rows, |l| num_rows.min(l));
+
+        // A part per block of rows, up to a part per thread.
+        let settings = ctx.get_settings();
+        let max_threads = settings.get_max_threads()?.max(1) as usize;
+        let max_block_size = settings.get_max_block_size()?.max(1) as usize;
+        let num_parts = read_rows.div_ceil(max_block_size).clamp(1, max_threads);
+        let visible_user = visible_user(&ctx).await?;
+        let parts = (0..num_parts)
+            .map(|index| SystemLogPart::create(index, num_parts, visible_user.clone()))
+            .collect();
         Ok((
-            PartStatistics::default(),
+            // Rows of this node only, and the queue keeps growing while being read.
+            PartStatistics::new_estimated(None, read_rows, 0, num_parts, num_parts),
             // Make the table in distributed.
-            Partitions::create(PartitionsShuffleKind::Broadcast, vec![Arc::new(Box::new(
-                SystemTablePart,
-            ))]),
+            Partitions::create(PartitionsShuffleKind::Broadcast, parts),
         ))
     }
 
     fn read_data(
         &self,
         ctx: Arc<dyn TableContext>,
-        _: &DataSourcePlan,
+        plan: &DataSourcePlan,
         pipeline: &mut Pipeline,
         _put_cache: bool,
     ) -> Result<()> {
-        let schema = Event::schema();
-        let mut mutable_columns: Vec<ColumnBuilder> = Vec::with_capacity(schema.num_fields());
-        let mut data_types: Vec<DataType> = Vec::with_capacity(schema.num_fields());
-
-        for column_field in schema.fields() {
-            let data_type: DataType = column_field.data_type().into();
-            let mutable_column = ColumnBuilder::with_capacity(&data_type, 0);
-            mutable_columns.push(mutable_column);
-            data_types.push(data_type);
-        }
-
+        // The parts split a single snapshot of the queue, so they read each event exactly once
+        // however the queue changes meanwhile.
+        let limit = pushed_down_limit(&plan.push_downs).unwrap_or(usize::MAX);
+        let parts = plan
+            .parts
+            .partitions
+            .iter()
+            .filter_map(|part| part.as_any().downcast_ref::<SystemLogPart>())
+            .collect::<Vec<_>>();
         let log_queue = SystemLogQueue::<Event>::instance()?;
-        for event in log_queue.data.read().event_queue.iter().flatten() {
-            event.fill_to_data_block(&mut mutable_columns)?;
-        }
-
-        let mut columns = Vec::with_capacity(mutable_columns.len());
-        for mutable_column in mutable_columns.into_iter() {
-            columns.push(mutable_column.build());
-        }
-
-        // Add source pipe.
-        pipeline.add_source(
-            move |output| {
+        let events: Arc<Vec<Event>> = Arc::new(
+            log_queue
+                .data
+                .read()
+                .event_queue
+                .iter()
+                .flatten()
+                .cloned()
+                .collect(),
+        );
+
+        // Each source reads the events of its part as visible to the user of the part, without
+        // a part nothing is read.
+        let mut source_builder = SourcePipeBuilder::create();
+        for part in &parts {
+            let output = OutputPort::create();
+            source_builder.add_source(
+                output.clone(),
                 SystemLogSource::<Event>::create(
                     ctx.clone(),
                     output,
-                    DataBlock::new_from_columns(columns.clone()),
-                )
-            },
-            1,
-        )
+                    events.clone(),
+                    part.range(events.len()),
+                    part.user.clone(),
+                    limit,
+                )?,
+            );
+        }
+        if parts.is_empty() {
+            let output = OutputPort::create();
+            source_builder.add_source(output.clone(), EmptySource::create(output)?);
+        }
+        pipeline.add_pipe(source_builder.finalize());
+        Ok(())
     }
 
     #[async_backtrace::framed]
     async fn truncate(&self, _ctx: Arc<dyn TableContext>, _pipeline: &mut Pipeline) -> Result<()> {
-        let log_queue = SystemLogQueue::<Event>::instance()?;
-        let mut write_guard = log_queue.data.write();
+        SystemLogQueue::<Event>::instance()?.clear();
+        Ok(())
+    }
+}
 
-        write_guard.index = 0;
-        for index in 0..write_guard.event_queue.len() {
-            write_guard.event_queue[index] = None;
-        }
+/// A slice of the events of a log table, read by its own source.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+pub struct SystemLogPart {
+    index: usize,
+    num_parts: usize,
+    // Only the events of the user are read if set.
+    user: Option<UserIdentity>,
+}
 
-        Ok(())
+#[typetag::serde(name = "system_log")]
+impl PartInfo for SystemLogPart {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn PartInfo>) -> bool {
+        info.as_any()
+            .downcast_ref::<SystemLogPart>()
+            .is_some_and(|other| self == other)
+    }
+
+    fn hash(&self) -> u64 {
+        self.index as u64
+    }
+}
+
+impl SystemLogPart {
+    pub fn create(index: usize, num_parts: usize, user: Option<UserIdentity>) -> PartInfoPtr {
+        Arc::new(Box::new(SystemLogPart {
+            index,
+            num_parts,
+            user,
+        }))
+    }
+
+    /// The events of the part, out of `num_events`.
+    pub fn range(&self, num_events: usize) -> Range<usize> {
+        num_events * self.index / self.num_parts..num_events * (self.index + 1) / self.num_parts
     }
 }
 
 struct SystemLogSource<Event: SystemLogElement> {
-    data: Option<DataBlock>,
-    _phantom: PhantomData<Event>,
+    events: Arc<Vec<Event>>,
+    range: Option<Range<usize>>,
+    // Only the events of the user are read if set.
+    user: Option<UserIdentity>,
+    limit: usize,
 }
 
 impl<Event: SystemLogElement + 'static> SystemLogSource<Event> {
     pub fn create(
         ctx: Arc<dyn TableContext>,
         output: Arc<OutputPort>,
-        data: DataBlock,
+        events: Arc<Vec<Event>>,
+        range: Range<usize>,
+        user: Option<UserIdentity>,
+        limit: usize,
     ) -> Result<ProcessorPtr> {
         SyncSourcer::create(ctx, output, Self {
-            data: Some(data),
-            _phantom: Default::default(),
+            events,
+            range: Some(range),
+            user,
+            limit,
         })
     }
+
+    fn is_visible(&self, event: &Event) -> bool {
+        match (&self.user, event.user()) {
+            (Some(visible_user), Some(user)) => visible_user == &user,
+            _ => true,
+        }
+    }
 }
 
 impl<Event: SystemLogElement + 'static> SyncSource for SystemLogSource<Event> {
     const NAME: &'static str = Event::TABLE_NAME;
 
     fn generate(&mut self) -> Result<Option<DataBlock>> {
-        Ok(self.data.take())
+        let Some(range) = self.range.take() else {
+            return Ok(None);
+        };
+
+        let schema = Event::schema();
+        let mut columns = Vec::with_capacity(schema.num_fields());
+        for field in schema.fields() {
+            let data_type: DataType = field.data_type().into();
+            columns.push(ColumnBuilder::with_capacity(&data_type, range.len()));
+        }
+        let events = self.events[range]
+            .iter()
+            .filter(|event| self.is_visible(event))
+            .take(self.limit);
+        for event in events {
+            event.fill_to_data_block(&mut columns)?;
+        }
+
+        let columns = columns.into_iter().map(|c| c.build()).collect();
+        Ok(Some(DataBlock::new_from_columns(columns)))
     }
 }
@@ -17,7 +17,6 @@ use std::sync::Arc;
 
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
-use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
 use databend_common_expression::BlockEntry;
@@ -46,7 +45,12 @@ impl SyncSystemTable for MallocStatsTable {
     }
 
     fn get_full_data(&self, _ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
-        let values = Self::build_columns().map_err(convert_je_err)?;
+        // The stats are refreshed by jemalloc on each print, a build without the allocator stats
+        // gets a row telling why instead of failing the query.
+        let values = match Self::build_columns() {
+            Ok(values) => values,
+            Err(cause) => Self::unavailable_columns(cause),
+        };
         Ok(DataBlock::new(values, 1))
     }
 }
@@ -80,7 +84,6 @@ impl MallocStatsTable {
         options.json_format = true;
         options.skip_constants = true;
         options.skip_mutex_statistics = true;
-        options.skip_per_arena = true;
 
         tikv_jemalloc_ctl::stats_print::stats_print(&mut buf, options)?;
         let json_value: serde_json::Value = serde_json::from_slice(&buf)?;
@@ -90,8 +93,15 @@ impl MallocStatsTable {
             Value::Scalar(Scalar::Variant(jsonb_value.to_vec())),
         )])
     }
-}
 
-fn convert_je_err(je_err: Box<dyn std::error::Error>) -> ErrorCode {
-    ErrorCode::Internal(format!("{}", je_err))
+    fn unavailable_columns(cause: Box<dyn std::error::Error>) -> Vec<BlockEntry> {
+        let json_value = serde_json::json!({
+            "error": format!("allocator statistics are unavailable: {}", cause),
+        });
+        let jsonb_value: jsonb::Value = (&json_value).into();
+        vec![BlockEntry::new(
+            DataType::Variant,
+            Value::Scalar(Scalar::Variant(jsonb_value.to_vec())),
+        )]
+    }
 }
@@ -17,13 +17,14 @@ use std::sync::Arc;
 
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
-use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::string::StringColumnBuilder;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::NumberType;
 use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
 use databend_common_expression::types::ValueType;
+use databend_common_expression::utils::FromData;
 use databend_common_expression::Column;
 use databend_common_expression::DataBlock;
 use databend_common_expression::TableDataType;
@@ -59,7 +60,17 @@ impl SyncSystemTable for MallocStatsTotalsTable {
     }
 
     fn get_full_data(&self, _ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
-        let values = Self::build_columns().map_err(convert_je_err)?;
+        // Like system.malloc_stats, a build without the allocator stats gets a row telling why.
+        let values = match Self::build_columns() {
+            Ok(values) => values,
+            Err(cause) => vec![
+                StringType::from_data(vec![format!(
+                    "allocator statistics are unavailable: {}",
+                    cause
+                )]),
+                UInt64Type::from_data(vec![0]),
+            ],
+        };
         Ok(DataBlock::new_from_columns(values))
     }
 }
@@ -115,7 +126,3 @@ impl MallocStatsTotalsTable {
         Ok(vec![names, values])
     }
 }
-
-fn convert_je_err(je_err: Box<dyn std::error::Error>) -> ErrorCode {
-    ErrorCode::Internal(format!("{}", je_err))
-}
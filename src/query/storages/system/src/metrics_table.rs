@@ -12,12 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::sync::Arc;
 
 use databend_common_base::runtime::metrics::MetricSample;
 use databend_common_base::runtime::metrics::MetricValue;
 use databend_common_base::runtime::metrics::GLOBAL_METRICS_REGISTRY;
+use databend_common_base::runtime::metrics::MAX_HISTOGRAM_BOUND;
 use databend_common_base::runtime::GLOBAL_MEM_STAT;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
@@ -41,6 +43,13 @@ pub struct MetricsTable {
     table_info: TableInfo,
 }
 
+struct MetricRow {
+    metric: String,
+    kind: String,
+    labels: BTreeMap<String, String>,
+    value: f64,
+}
+
 impl SyncSystemTable for MetricsTable {
     const NAME: &'static str = "system.metrics";
     // Allow distributed query.
@@ -56,18 +65,19 @@ impl SyncSystemTable for MetricsTable {
 
         let mut samples = GLOBAL_METRICS_REGISTRY.dump_sample()?;
         samples.extend(self.custom_metric_samples()?);
-
-        let mut nodes: Vec<String> = Vec::with_capacity(samples.len());
-        let mut metrics: Vec<String> = Vec::with_capacity(samples.len());
-        let mut labels: Vec<String> = Vec::with_capacity(samples.len());
-        let mut kinds: Vec<String> = Vec::with_capacity(samples.len());
-        let mut values: Vec<String> = Vec::with_capacity(samples.len());
-        for sample in samples.into_iter() {
+        let rows = Self::expand_samples(samples);
+
+        let mut nodes: Vec<String> = Vec::with_capacity(rows.len());
+        let mut metrics: Vec<String> = Vec::with_capacity(rows.len());
+        let mut labels: Vec<String> = Vec::with_capacity(rows.len());
+        let mut kinds: Vec<String> = Vec::with_capacity(rows.len());
+        let mut values: Vec<String> = Vec::with_capacity(rows.len());
+        for row in rows.into_iter() {
             nodes.push(local_id.clone());
-            metrics.push(sample.name.clone());
-            kinds.push(sample.value.kind());
-            labels.push(self.display_sample_labels(&sample.labels)?);
-            values.push(self.display_sample_value(&sample.value)?);
+            labels.push(self.display_sample_labels(&row.labels)?);
+            values.push(self.display_sample_value(row.value)?);
+            metrics.push(row.metric);
+            kinds.push(row.kind);
         }
 
         Ok(DataBlock::new_from_columns(vec![
@@ -110,7 +120,80 @@ impl MetricsTable {
         SyncOneBlockSystemTable::create(MetricsTable { table_info })
     }
 
-    fn display_sample_labels(&self, labels: &HashMap<String, String>) -> Result<String> {
+    /// Turns the samples into the rows of the prometheus exposition: a histogram has one
+    /// `_bucket` row per bucket with the `le` label, a summary has one row per quantile with the
+    /// `quantile` label, and their `_sum` and `_count` rows take the kind of the metric.
+    fn expand_samples(samples: Vec<MetricSample>) -> Vec<MetricRow> {
+        // The name of a histogram sample is either `name` or `name_bucket`.
+        let mut distributions: HashMap<String, String> = HashMap::new();
+        for sample in samples.iter() {
+            match &sample.value {
+                MetricValue::Histogram(_) => {
+                    let name = sample.name.strip_suffix("_bucket").unwrap_or(&sample.name);
+                    distributions.insert(name.to_string(), sample.value.kind());
+                }
+                MetricValue::Summary(_) => {
+                    distributions.insert(sample.name.clone(), sample.value.kind());
+                }
+                _ => {}
+            }
+        }
+
+        let mut rows = Vec::with_capacity(samples.len());
+        for sample in samples.into_iter() {
+            let kind = sample.value.kind();
+            match sample.value {
+                MetricValue::Histogram(buckets) => {
+                    let name = sample.name.strip_suffix("_bucket").unwrap_or(&sample.name);
+                    for bucket in buckets {
+                        let mut labels = sample.labels.clone();
+                        labels.insert("le".to_string(), Self::display_bound(bucket.less_than));
+                        rows.push(MetricRow {
+                            metric: format!("{}_bucket", name),
+                            kind: kind.clone(),
+                            labels,
+                            value: bucket.count,
+                        });
+                    }
+                }
+                MetricValue::Summary(quantiles) => {
+                    for quantile in quantiles {
+                        let mut labels = sample.labels.clone();
+                        labels.insert("quantile".to_string(), format!("{:?}", quantile.quantile));
+                        rows.push(MetricRow {
+                            metric: sample.name.clone(),
+                            kind: kind.clone(),
+                            labels,
+                            value: quantile.count,
+                        });
+                    }
+                }
+                MetricValue::Counter(v) | MetricValue::Gauge(v) | MetricValue::Untyped(v) => {
+                    let distribution = ["_sum", "_count"]
+                        .iter()
+                        .filter_map(|suffix| sample.name.strip_suffix(suffix))
+                        .find_map(|name| distributions.get(name));
+                    rows.push(MetricRow {
+                        kind: distribution.cloned().unwrap_or(kind),
+                        metric: sample.name,
+                        labels: sample.labels,
+                        value: v,
+                    });
+                }
+            }
+        }
+        rows
+    }
+
+    fn display_bound(bound: f64) -> String {
+        if bound >= MAX_HISTOGRAM_BOUND {
+            "+Inf".to_string()
+        } else {
+            format!("{:?}", bound)
+        }
+    }
+
+    fn display_sample_labels(&self, labels: &BTreeMap<String, String>) -> Result<String> {
         serde_json::to_string(labels).map_err(|err| {
             ErrorCode::Internal(format!(
                 "Dump prometheus metrics on display labels: {}",
@@ -119,15 +202,8 @@ impl MetricsTable {
         })
     }
 
-    fn display_sample_value(&self, value: &MetricValue) -> Result<String> {
-        match value {
-            MetricValue::Counter(v) => serde_json::to_string(v),
-            MetricValue::Gauge(v) => serde_json::to_string(v),
-            MetricValue::Untyped(v) => serde_json::to_string(v),
-            MetricValue::Histogram(v) => serde_json::to_string(v),
-            MetricValue::Summary(v) => serde_json::to_string(v),
-        }
-        .map_err(|err| {
+    fn display_sample_value(&self, value: f64) -> Result<String> {
+        serde_json::to_string(&value).map_err(|err| {
             ErrorCode::Internal(format!(
                 "Dump prometheus metrics failed on display values: {}",
                 err
@@ -141,12 +217,12 @@ impl MetricsTable {
             MetricSample {
                 name: "query_memory_usage_bytes".to_string(),
                 value: MetricValue::Counter(GLOBAL_MEM_STAT.get_memory_usage() as f64),
-                labels: HashMap::new(),
+                labels: BTreeMap::new(),
             },
             MetricSample {
                 name: "query_memory_peak_usage_bytes".to_string(),
                 value: MetricValue::Counter(GLOBAL_MEM_STAT.get_peak_memory_usage() as f64),
-                labels: HashMap::new(),
+                labels: BTreeMap::new(),
             },
         ];
 
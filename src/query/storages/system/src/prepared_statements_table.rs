@@ -0,0 +1,192 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::sync::Weak;
+
+use databend_common_base::base::GlobalInstance;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::number::UInt64Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use parking_lot::Mutex;
+
+use crate::SyncOneBlockSystemTable;
+use crate::SyncSystemTable;
+
+/// A statement prepared by a client and not closed yet, the times are in unix microseconds.
+pub struct PreparedStatementInfo {
+    pub handle: String,
+    /// The handler keeping the statement, e.g. `FlightSQL`.
+    pub handler: String,
+    pub user: String,
+    pub session_id: String,
+    pub sql: String,
+    pub created_at: i64,
+    pub last_executed_at: Option<i64>,
+    pub execution_count: u64,
+    pub estimated_plan_memory: u64,
+}
+
+pub trait PreparedStatementsProvider: Send + Sync {
+    fn list_prepared_statements(&self) -> Vec<PreparedStatementInfo>;
+}
+
+/// The handlers keeping prepared statements on this node, read by `system.prepared_statements`.
+///
+/// The registry only holds weak references, a handler is dropped from it along with itself.
+#[derive(Default)]
+pub struct PreparedStatementsRegistry {
+    providers: Mutex<Vec<Weak<dyn PreparedStatementsProvider>>>,
+}
+
+impl PreparedStatementsRegistry {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Arc::new(PreparedStatementsRegistry::default()));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<PreparedStatementsRegistry> {
+        GlobalInstance::get()
+    }
+
+    pub fn register(&self, provider: Weak<dyn PreparedStatementsProvider>) {
+        let mut providers = self.providers.lock();
+        providers.retain(|p| p.strong_count() > 0);
+        providers.push(provider);
+    }
+
+    pub fn list_prepared_statements(&self) -> Vec<PreparedStatementInfo> {
+        let providers = self
+            .providers
+            .lock()
+            .iter()
+            .filter_map(|p| p.upgrade())
+            .collect::<Vec<_>>();
+        providers
+            .iter()
+            .flat_map(|p| p.list_prepared_statements())
+            .collect()
+    }
+}
+
+/// The SQL text may carry sensitive data, reading the table needs the SUPER privilege.
+pub struct PreparedStatementsTable {
+    table_info: TableInfo,
+}
+
+impl SyncSystemTable for PreparedStatementsTable {
+    const NAME: &'static str = "system.prepared_statements";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let sql_max_length = ctx
+            .get_settings()
+            .get_prepared_statements_sql_max_length()?;
+        let statements = PreparedStatementsRegistry::instance().list_prepared_statements();
+
+        let mut handles = Vec::with_capacity(statements.len());
+        let mut handlers = Vec::with_capacity(statements.len());
+        let mut users = Vec::with_capacity(statements.len());
+        let mut session_ids = Vec::with_capacity(statements.len());
+        let mut sqls = Vec::with_capacity(statements.len());
+        let mut created_ats = Vec::with_capacity(statements.len());
+        let mut last_executed_ats = Vec::with_capacity(statements.len());
+        let mut execution_counts = Vec::with_capacity(statements.len());
+        let mut estimated_plan_memories = Vec::with_capacity(statements.len());
+        for statement in statements {
+            handles.push(statement.handle);
+            handlers.push(statement.handler);
+            users.push(statement.user);
+            session_ids.push(statement.session_id);
+            sqls.push(
+                statement
+                    .sql
+                    .chars()
+                    .take(sql_max_length)
+                    .collect::<String>(),
+            );
+            created_ats.push(statement.created_at);
+            last_executed_ats.push(statement.last_executed_at);
+            execution_counts.push(statement.execution_count);
+            estimated_plan_memories.push(statement.estimated_plan_memory);
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(handles),
+            StringType::from_data(handlers),
+            StringType::from_data(users),
+            StringType::from_data(session_ids),
+            StringType::from_data(sqls),
+            TimestampType::from_data(created_ats),
+            TimestampType::from_opt_data(last_executed_ats),
+            UInt64Type::from_data(execution_counts),
+            UInt64Type::from_data(estimated_plan_memories),
+        ]))
+    }
+}
+
+impl PreparedStatementsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("handle", TableDataType::String),
+            TableField::new("handler", TableDataType::String),
+            TableField::new("user", TableDataType::String),
+            TableField::new("session_id", TableDataType::String),
+            TableField::new("sql", TableDataType::String),
+            TableField::new("created_at", TableDataType::Timestamp),
+            TableField::new(
+                "last_executed_at",
+                TableDataType::Nullable(Box::new(TableDataType::Timestamp)),
+            ),
+            TableField::new(
+                "execution_count",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "estimated_plan_memory",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'prepared_statements'".to_string(),
+            name: "prepared_statements".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemPreparedStatements".to_string(),
+
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        SyncOneBlockSystemTable::create(PreparedStatementsTable { table_info })
+    }
+}
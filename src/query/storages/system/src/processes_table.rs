@@ -17,16 +17,19 @@ use std::time::Duration;
 
 use chrono::DateTime;
 use chrono::Utc;
+use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_expression::types::number::Int64Type;
 use databend_common_expression::types::number::UInt32Type;
 use databend_common_expression::types::number::UInt64Type;
+use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::StringType;
 use databend_common_expression::types::TimestampType;
 use databend_common_expression::utils::FromData;
+use databend_common_expression::ColumnBuilder;
 use databend_common_expression::DataBlock;
 use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
@@ -35,15 +38,18 @@ use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 
-use crate::SyncOneBlockSystemTable;
-use crate::SyncSystemTable;
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+use crate::util::labels_data_type;
+use crate::util::labels_scalar;
+use crate::util::visible_user;
 
 pub struct ProcessesTable {
     table_info: TableInfo,
 }
 
 #[async_trait::async_trait]
-impl SyncSystemTable for ProcessesTable {
+impl AsyncSystemTable for ProcessesTable {
     const NAME: &'static str = "system.processes";
 
     const IS_LOCAL: bool = false;
@@ -52,8 +58,25 @@ impl SyncSystemTable for ProcessesTable {
         &self.table_info
     }
 
-    fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
-        let processes_info = ctx.get_processes_info();
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        // The sessions of the other users are only shown to the users with the SUPER privilege.
+        let visible_user = visible_user(&ctx).await?;
+        let processes_info = ctx
+            .get_processes_info()
+            .into_iter()
+            .filter(|process| match &visible_user {
+                Some(identity) => process
+                    .user
+                    .as_ref()
+                    .is_some_and(|user| &user.identity() == identity),
+                None => true,
+            })
+            .collect::<Vec<_>>();
 
         let local_node = ctx.get_cluster().local_id.clone();
 
@@ -74,6 +97,11 @@ impl SyncSystemTable for ProcessesTable {
         let mut processes_time = Vec::with_capacity(processes_info.len());
         let mut processes_created_time = Vec::with_capacity(processes_info.len());
         let mut processes_status = Vec::with_capacity(processes_info.len());
+        let mut processes_query_tag = Vec::with_capacity(processes_info.len());
+        let mut processes_labels = ColumnBuilder::with_capacity(
+            &DataType::from(&labels_data_type()),
+            processes_info.len(),
+        );
 
         for process_info in &processes_info {
             let data_metrics = &process_info.data_metrics;
@@ -115,6 +143,10 @@ impl SyncSystemTable for ProcessesTable {
 
             // Status info.
             processes_status.push(process_info.status_info.clone().unwrap_or("".to_owned()));
+            processes_query_tag.push(ProcessesTable::process_option_value(
+                process_info.query_tag.clone(),
+            ));
+            processes_labels.push(labels_scalar(&process_info.labels).as_ref());
         }
 
         Ok(DataBlock::new_from_columns(vec![
@@ -135,6 +167,8 @@ impl SyncSystemTable for ProcessesTable {
             UInt64Type::from_data(processes_time),
             TimestampType::from_data(processes_created_time),
             StringType::from_data(processes_status),
+            StringType::from_data(processes_query_tag),
+            processes_labels.build(),
         ]))
     }
 }
@@ -177,6 +211,8 @@ impl ProcessesTable {
             TableField::new("time", TableDataType::Number(NumberDataType::UInt64)),
             TableField::new("created_time", TableDataType::Timestamp),
             TableField::new("status", TableDataType::String),
+            TableField::new("query_tag", TableDataType::String),
+            TableField::new("labels", labels_data_type()),
         ]);
 
         let table_info = TableInfo {
@@ -192,7 +228,7 @@ impl ProcessesTable {
             ..Default::default()
         };
 
-        SyncOneBlockSystemTable::create(ProcessesTable { table_info })
+        AsyncOneBlockSystemTable::create(ProcessesTable { table_info })
     }
 
     fn process_option_value<T>(opt: Option<T>) -> T
@@ -22,6 +22,7 @@ use databend_common_exception::Result;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::StringType;
 use databend_common_expression::types::UInt32Type;
+use databend_common_expression::types::UInt64Type;
 use databend_common_expression::types::VariantType;
 use databend_common_expression::ColumnBuilder;
 use databend_common_expression::DataBlock;
@@ -54,21 +55,10 @@ impl SyncSystemTable for QueriesProfilingTable {
     }
 
     fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
-        let queries_profiles = ctx.get_queries_profile();
-
-        let cache_queue = ProfilesLogQueue::instance()?;
-        let cache_profiles: Vec<ProfilesLogElement> = cache_queue
-            .data
-            .read()
-            .event_queue
-            .iter()
-            .flatten()
-            .cloned()
-            .collect();
+        let queries_profiles = collect_queries_profiles(ctx.as_ref())?;
 
         let local_id = ctx.get_cluster().local_id.clone();
-        let total_size =
-            queries_profiles.values().map(Vec::len).sum::<usize>() + cache_profiles.len();
+        let total_size = queries_profiles.iter().map(|(_, p)| p.len()).sum::<usize>();
 
         let mut node: Vec<String> = Vec::with_capacity(total_size);
         let mut queries_id: Vec<String> = Vec::with_capacity(total_size);
@@ -98,27 +88,6 @@ impl SyncSystemTable for QueriesProfilingTable {
             }
         }
 
-        for cache_element in cache_profiles {
-            let query_id = cache_element.query_id;
-            for query_plan_profile in cache_element.profiles {
-                node.push(local_id.clone());
-                queries_id.push(query_id.clone());
-                plan_id.push(query_plan_profile.id);
-                parent_id.push(query_plan_profile.parent_id);
-                plan_name.push(query_plan_profile.name.clone());
-                errors.push(serde_json::to_vec(&query_plan_profile.errors).unwrap());
-
-                let mut statistics_map =
-                    HashMap::with_capacity(query_plan_profile.statistics.len());
-                for (idx, item_value) in query_plan_profile.statistics.iter().enumerate() {
-                    statistics_map
-                        .insert(ProfileStatisticsName::from(idx).to_string(), *item_value);
-                }
-
-                statistics.push(serde_json::to_vec(&statistics_map).unwrap());
-            }
-        }
-
         Ok(DataBlock::new_from_columns(vec![
             StringType::from_data(node),
             StringType::from_data(queries_id),
@@ -168,6 +137,154 @@ impl QueriesProfilingTable {
     }
 }
 
+/// The profiles of the running queries followed by those of the recently finished ones.
+fn collect_queries_profiles(ctx: &dyn TableContext) -> Result<Vec<(String, Vec<PlanProfile>)>> {
+    let mut queries_profiles: Vec<(String, Vec<PlanProfile>)> =
+        ctx.get_queries_profile().into_iter().collect();
+
+    let cache_queue = ProfilesLogQueue::instance()?;
+    let cache_profiles = cache_queue.data.read();
+    queries_profiles.extend(
+        cache_profiles
+            .event_queue
+            .iter()
+            .flatten()
+            .map(|element| (element.query_id.clone(), element.profiles.clone())),
+    );
+    Ok(queries_profiles)
+}
+
+/// The metrics of `system.query_profile`, a column each.
+const QUERY_PROFILE_METRICS: &[(&str, ProfileStatisticsName)] = &[
+    ("cpu_time", ProfileStatisticsName::CpuTime),
+    ("wait_time", ProfileStatisticsName::WaitTime),
+    ("output_rows", ProfileStatisticsName::OutputRows),
+    ("output_bytes", ProfileStatisticsName::OutputBytes),
+    ("scan_bytes", ProfileStatisticsName::ScanBytes),
+    ("exchange_rows", ProfileStatisticsName::ExchangeRows),
+    ("exchange_bytes", ProfileStatisticsName::ExchangeBytes),
+    ("spill_write_bytes", ProfileStatisticsName::SpillWriteBytes),
+    ("spill_read_bytes", ProfileStatisticsName::SpillReadBytes),
+    ("memory_usage", ProfileStatisticsName::MemoryUsage),
+];
+
+/// The per-operator statistics of the running and recently finished queries, a row per plan
+/// node. The tree of a query is rebuilt by joining `parent_id` to `plan_node_id`, the root
+/// operator has no parent.
+///
+/// The number of finished queries kept is `max_cached_queries_profiles`.
+pub struct QueryProfileTable {
+    table_info: TableInfo,
+}
+
+impl SyncSystemTable for QueryProfileTable {
+    const NAME: &'static str = "system.query_profile";
+
+    const IS_LOCAL: bool = false;
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let queries_profiles = collect_queries_profiles(ctx.as_ref())?;
+
+        let local_id = ctx.get_cluster().local_id.clone();
+        let total_size = queries_profiles.iter().map(|(_, p)| p.len()).sum::<usize>();
+
+        let mut node: Vec<String> = Vec::with_capacity(total_size);
+        let mut queries_id: Vec<String> = Vec::with_capacity(total_size);
+        let mut plan_node_id: Vec<Option<u32>> = Vec::with_capacity(total_size);
+        let mut operator_name: Vec<Option<String>> = Vec::with_capacity(total_size);
+        let mut parent_id: Vec<Option<u32>> = Vec::with_capacity(total_size);
+        let mut input_rows: Vec<u64> = Vec::with_capacity(total_size);
+        let mut metrics: Vec<Vec<u64>> = QUERY_PROFILE_METRICS
+            .iter()
+            .map(|_| Vec::with_capacity(total_size))
+            .collect();
+
+        for (query_id, query_profiles) in queries_profiles {
+            // The rows an operator reads are the rows its children output.
+            let mut children_rows: HashMap<u32, u64> = HashMap::new();
+            for profile in &query_profiles {
+                if let Some(parent) = profile.parent_id {
+                    *children_rows.entry(parent).or_default() +=
+                        profile.statistics[ProfileStatisticsName::OutputRows as usize] as u64;
+                }
+            }
+
+            for profile in query_profiles {
+                node.push(local_id.clone());
+                queries_id.push(query_id.clone());
+                plan_node_id.push(profile.id);
+                operator_name.push(profile.name);
+                parent_id.push(profile.parent_id);
+                input_rows.push(
+                    profile
+                        .id
+                        .and_then(|id| children_rows.get(&id).copied())
+                        .unwrap_or_default(),
+                );
+                for ((_, name), values) in QUERY_PROFILE_METRICS.iter().zip(metrics.iter_mut()) {
+                    values.push(profile.statistics[name.clone() as usize] as u64);
+                }
+            }
+        }
+
+        let mut columns = vec![
+            StringType::from_data(node),
+            StringType::from_data(queries_id),
+            UInt32Type::from_opt_data(plan_node_id),
+            StringType::from_opt_data(operator_name),
+            UInt32Type::from_opt_data(parent_id),
+            UInt64Type::from_data(input_rows),
+        ];
+        columns.extend(metrics.into_iter().map(UInt64Type::from_data));
+        Ok(DataBlock::new_from_columns(columns))
+    }
+}
+
+impl QueryProfileTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let mut fields = vec![
+            TableField::new("node", TableDataType::String),
+            TableField::new("query_id", TableDataType::String),
+            TableField::new(
+                "plan_node_id",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt32))),
+            ),
+            TableField::new(
+                "operator_name",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new(
+                "parent_id",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt32))),
+            ),
+            TableField::new("input_rows", TableDataType::Number(NumberDataType::UInt64)),
+        ];
+        fields.extend(
+            QUERY_PROFILE_METRICS.iter().map(|(name, _)| {
+                TableField::new(name, TableDataType::Number(NumberDataType::UInt64))
+            }),
+        );
+
+        let table_info = TableInfo {
+            desc: "'system'.'query_profile'".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            name: "query_profile".to_string(),
+            meta: TableMeta {
+                schema: TableSchemaRefExt::create(fields),
+                engine: "QueryProfileTable".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        SyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
+
 #[derive(Clone)]
 pub struct ProfilesLogElement {
     pub query_id: String,
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use chrono::DateTime;
 use databend_common_exception::Result;
 use databend_common_expression::types::number::NumberScalar;
@@ -22,10 +24,13 @@ use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchemaRef;
 use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::principal::UserIdentity;
 use serde::Serialize;
 use serde::Serializer;
 use serde_repr::Serialize_repr;
 
+use crate::util::labels_data_type;
+use crate::util::labels_scalar;
 use crate::SystemLogElement;
 use crate::SystemLogQueue;
 use crate::SystemLogTable;
@@ -98,6 +103,8 @@ pub struct QueryLogElement {
     pub node_id: String,
     pub sql_user: String,
 
+    #[serde(skip_serializing)]
+    pub sql_user_host: String,
     #[serde(skip_serializing)]
     pub sql_user_quota: String,
     #[serde(skip_serializing)]
@@ -155,6 +162,8 @@ pub struct QueryLogElement {
     pub client_info: String,
     pub client_address: String,
     pub user_agent: String,
+    pub query_tag: String,
+    pub labels: BTreeMap<String, String>,
 
     // Exception.
     pub exception_code: i32,
@@ -172,6 +181,7 @@ pub struct QueryLogElement {
     pub extra: String,
 
     pub has_profiles: bool,
+    pub result_cache_hit: bool,
 
     // Transaction
     pub txn_state: String,
@@ -302,6 +312,8 @@ impl SystemLogElement for QueryLogElement {
             TableField::new("client_info", TableDataType::String),
             TableField::new("client_address", TableDataType::String),
             TableField::new("user_agent", TableDataType::String),
+            TableField::new("query_tag", TableDataType::String),
+            TableField::new("labels", labels_data_type()),
             // Exception.
             TableField::new(
                 "exception_code",
@@ -316,9 +328,18 @@ impl SystemLogElement for QueryLogElement {
             // Extra.
             TableField::new("extra", TableDataType::String),
             TableField::new("has_profile", TableDataType::Boolean),
+            TableField::new("result_cache_hit", TableDataType::Boolean),
         ])
     }
 
+    fn event_time(&self) -> Option<i64> {
+        Some(self.event_time)
+    }
+
+    fn user(&self) -> Option<UserIdentity> {
+        Some(UserIdentity::new(&self.sql_user, &self.sql_user_host))
+    }
+
     fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
         let mut columns = columns.iter_mut();
         columns
@@ -530,6 +551,14 @@ impl SystemLogElement for QueryLogElement {
             .next()
             .unwrap()
             .push(Scalar::String(self.user_agent.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.query_tag.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(labels_scalar(&self.labels).as_ref());
         // Exception.
         columns
             .next()
@@ -562,6 +591,10 @@ impl SystemLogElement for QueryLogElement {
             .next()
             .unwrap()
             .push(Scalar::Boolean(self.has_profiles).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Boolean(self.result_cache_hit).as_ref());
         Ok(())
     }
 }
@@ -17,6 +17,7 @@ use std::sync::Arc;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
+use databend_common_expression::types::BooleanType;
 use databend_common_expression::types::StringType;
 use databend_common_expression::utils::FromData;
 use databend_common_expression::DataBlock;
@@ -32,6 +33,7 @@ use snailquote::escape;
 use crate::SyncOneBlockSystemTable;
 use crate::SyncSystemTable;
 
+/// The rows are always ordered by setting name.
 pub struct SettingsTable {
     table_info: TableInfo,
 }
@@ -53,6 +55,7 @@ impl SyncSystemTable for SettingsTable {
         let mut levels: Vec<String> = vec![];
         let mut descs: Vec<String> = vec![];
         let mut types: Vec<String> = vec![];
+        let mut changes: Vec<bool> = vec![];
         for item in settings.into_iter() {
             // Name.
             names.push(item.name);
@@ -60,6 +63,9 @@ impl SyncSystemTable for SettingsTable {
             // Value.
             values.push(escape(format!("{:?}", item.user_value).as_str()).to_string());
 
+            // Changed, by the same change that decides the level.
+            changes.push(item.changed);
+
             // Default Value.
             defaults.push(escape(format!("{:?}", item.default_value).as_str()).to_string());
 
@@ -100,6 +106,7 @@ impl SyncSystemTable for SettingsTable {
             StringType::from_data(levels),
             StringType::from_data(descs),
             StringType::from_data(types),
+            BooleanType::from_data(changes),
         ]))
     }
 }
@@ -114,6 +121,7 @@ impl SettingsTable {
             TableField::new("level", TableDataType::String),
             TableField::new("description", TableDataType::String),
             TableField::new("type", TableDataType::String),
+            TableField::new("changed", TableDataType::Boolean),
         ]);
 
         let table_info = TableInfo {
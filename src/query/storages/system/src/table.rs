@@ -15,16 +15,22 @@
 use std::any::Any;
 use std::sync::Arc;
 
+use databend_common_base::base::ProgressValues;
 use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::PartInfo;
+use databend_common_catalog::plan::PartInfoPtr;
 use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
+use databend_common_catalog::plan::Projection;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
+use databend_common_expression::TableSchema;
+use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::ProcessorPtr;
@@ -32,8 +38,9 @@ use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_sources::AsyncSource;
 use databend_common_pipeline_sources::AsyncSourcer;
 use databend_common_pipeline_sources::EmptySource;
-use databend_common_pipeline_sources::SyncSource;
-use databend_common_pipeline_sources::SyncSourcer;
+use databend_common_pipeline_sources::OneBlockSource;
+
+use crate::util::find_string_values;
 
 #[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq)]
 pub struct SystemTablePart;
@@ -55,6 +62,91 @@ impl PartInfo for SystemTablePart {
     }
 }
 
+/// The LIMIT a system table can stop reading at.
+///
+/// A LIMIT above a sort is pushed down along with the ORDER BY, and the filters are still
+/// evaluated above the scan, while a system table returns its rows in no particular order, so the
+/// limit is only taken if neither of them is pushed down.
+pub fn pushed_down_limit(push_downs: &Option<PushDownInfo>) -> Option<usize> {
+    push_downs
+        .as_ref()
+        .filter(|p| p.order_by.is_empty() && p.filters.is_none())
+        .and_then(|p| p.limit)
+}
+
+/// The partitions of a table that is read on every node of the cluster, each node returns its own
+/// rows tagged with a `node` column.
+///
+/// A `node = ...` or `node IN (...)` predicate limits the read to the listed nodes, the others
+/// get no partition and return nothing. In a single node deployment the partitions are not
+/// reshuffled, so the table is read as before and the predicate is evaluated on its rows.
+pub fn broadcast_partitions(push_downs: &Option<PushDownInfo>) -> Partitions {
+    let parts: Vec<PartInfoPtr> = vec![Arc::new(Box::new(SystemTablePart))];
+    let nodes = push_downs
+        .as_ref()
+        .and_then(|p| p.filters.as_ref())
+        .and_then(|f| find_string_values(&f.filter.as_expr(&BUILTIN_FUNCTIONS), "node"));
+    match nodes {
+        Some(nodes) => Partitions::create(PartitionsShuffleKind::BroadcastTo(nodes), parts),
+        None => Partitions::create(PartitionsShuffleKind::Broadcast, parts),
+    }
+}
+
+/// The statistics of a system table read, out of the rows it is estimated to hold.
+///
+/// System tables are built at read time, so the estimate is never marked exact. It is kept out of
+/// the table statistics, the optimizer takes those as the precise row count, e.g. to fold
+/// `count(*)`.
+pub fn estimated_part_statistics(estimated_rows: Option<u64>) -> PartStatistics {
+    match estimated_rows {
+        Some(rows) => PartStatistics::new_estimated(None, rows as usize, 0, 1, 1),
+        None => PartStatistics::default(),
+    }
+}
+
+fn limit_block(block: DataBlock, limit: Option<usize>) -> DataBlock {
+    match limit {
+        Some(limit) if block.num_rows() > limit => block.slice(0..limit),
+        _ => block,
+    }
+}
+
+/// Keeps the columns of the pushed-down projection in its order, which is the schema the scan
+/// of a table supporting column projection expects.
+///
+/// System tables have no tuple columns, each path of an inner projection is a single column.
+pub fn project_block(block: DataBlock, push_downs: &Option<PushDownInfo>) -> DataBlock {
+    let Some(indices) = projected_columns(push_downs) else {
+        return block;
+    };
+
+    let num_rows = block.num_rows();
+    let columns = indices
+        .iter()
+        .map(|index| block.get_by_offset(*index).clone())
+        .collect();
+    DataBlock::new(columns, num_rows)
+}
+
+/// The schema of the blocks [`project_block`] returns.
+pub fn project_schema(schema: &TableSchema, push_downs: &Option<PushDownInfo>) -> DataSchema {
+    match projected_columns(push_downs) {
+        Some(indices) => DataSchema::from(schema.project(&indices)),
+        None => DataSchema::from(schema),
+    }
+}
+
+fn projected_columns(push_downs: &Option<PushDownInfo>) -> Option<Vec<usize>> {
+    let projection = push_downs
+        .as_ref()
+        .and_then(|p| p.output_columns.as_ref().or(p.projection.as_ref()));
+    match projection {
+        Some(Projection::Columns(indices)) => Some(indices.clone()),
+        Some(Projection::InnerColumns(paths)) => Some(paths.values().map(|path| path[0]).collect()),
+        None => None,
+    }
+}
+
 pub trait SyncSystemTable: Send + Sync {
     const NAME: &'static str;
     const IS_LOCAL: bool = true;
@@ -63,24 +155,32 @@ pub trait SyncSystemTable: Send + Sync {
     fn get_table_info(&self) -> &TableInfo;
     fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock>;
 
+    /// Returns the first `limit` rows, a table holding many rows may override it to stop
+    /// building the block early.
+    fn get_data(&self, ctx: Arc<dyn TableContext>, limit: Option<usize>) -> Result<DataBlock> {
+        Ok(limit_block(self.get_full_data(ctx)?, limit))
+    }
+
+    /// The number of rows the table holds, for the read statistics. It is only worth
+    /// overriding if the count is cheaper than building the rows.
+    fn estimated_rows(&self, _ctx: Arc<dyn TableContext>) -> Result<Option<u64>> {
+        Ok(None)
+    }
+
     fn get_partitions(
         &self,
-        _ctx: Arc<dyn TableContext>,
-        _push_downs: Option<PushDownInfo>,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
     ) -> Result<(PartStatistics, Partitions)> {
+        let statistics = estimated_part_statistics(self.estimated_rows(ctx)?);
         match Self::IS_LOCAL {
             true => Ok((
-                PartStatistics::default(),
+                statistics,
                 Partitions::create(PartitionsShuffleKind::Seq, vec![Arc::new(Box::new(
                     SystemTablePart,
                 ))]),
             )),
-            false => Ok((
-                PartStatistics::default(),
-                Partitions::create(PartitionsShuffleKind::Broadcast, vec![Arc::new(Box::new(
-                    SystemTablePart,
-                ))]),
-            )),
+            false => Ok((statistics, broadcast_partitions(&push_downs))),
         }
     }
 
@@ -89,6 +189,8 @@ pub trait SyncSystemTable: Send + Sync {
     }
 }
 
+/// A system table read as a single block, the block is built with the pipeline and checked
+/// against the schema of the table by [`OneBlockSource`].
 pub struct SyncOneBlockSystemTable<TTable: SyncSystemTable> {
     inner_table: Arc<TTable>,
 }
@@ -117,6 +219,10 @@ impl<TTable: 'static + SyncSystemTable> Table for SyncOneBlockSystemTable<TTable
         self.inner_table.get_table_info()
     }
 
+    fn support_column_projection(&self) -> bool {
+        true
+    }
+
     #[async_backtrace::framed]
     async fn read_partitions(
         &self,
@@ -140,9 +246,21 @@ impl<TTable: 'static + SyncSystemTable> Table for SyncOneBlockSystemTable<TTable
             return Ok(());
         }
 
-        let inner_table = self.inner_table.clone();
+        // The block is built here, so that one not matching the schema of the table fails the
+        // query before it runs.
+        let push_downs = &plan.push_downs;
+        let limit = pushed_down_limit(push_downs);
+        let block = self.inner_table.get_data(ctx.clone(), limit)?;
+        let block = project_block(block, push_downs);
+        ctx.get_scan_progress().incr(&ProgressValues {
+            rows: block.num_rows(),
+            bytes: block.memory_size(),
+        });
+        let schema = project_schema(&self.get_table_info().schema(), push_downs);
         pipeline.add_source(
-            |output| SystemTableSyncSource::create(ctx.clone(), output, inner_table.clone()),
+            |output| {
+                OneBlockSource::create_with_schema(output, TTable::NAME, &schema, block.clone())
+            },
             1,
         )?;
 
@@ -159,41 +277,6 @@ impl<TTable: 'static + SyncSystemTable> Table for SyncOneBlockSystemTable<TTable
     }
 }
 
-struct SystemTableSyncSource<TTable: 'static + SyncSystemTable> {
-    finished: bool,
-    inner: Arc<TTable>,
-    context: Arc<dyn TableContext>,
-}
-
-impl<TTable: 'static + SyncSystemTable> SystemTableSyncSource<TTable>
-where Self: SyncSource
-{
-    pub fn create(
-        ctx: Arc<dyn TableContext>,
-        output: Arc<OutputPort>,
-        inner: Arc<TTable>,
-    ) -> Result<ProcessorPtr> {
-        SyncSourcer::create(ctx.clone(), output, SystemTableSyncSource::<TTable> {
-            inner,
-            context: ctx,
-            finished: false,
-        })
-    }
-}
-
-impl<TTable: 'static + SyncSystemTable> SyncSource for SystemTableSyncSource<TTable> {
-    const NAME: &'static str = TTable::NAME;
-
-    fn generate(&mut self) -> Result<Option<DataBlock>> {
-        if self.finished {
-            return Ok(None);
-        }
-
-        self.finished = true;
-        Ok(Some(self.inner.get_full_data(self.context.clone())?))
-    }
-}
-
 #[async_trait::async_trait]
 pub trait AsyncSystemTable: Send + Sync {
     const NAME: &'static str;
@@ -206,25 +289,28 @@ pub trait AsyncSystemTable: Send + Sync {
         _push_downs: Option<PushDownInfo>,
     ) -> Result<DataBlock>;
 
+    /// The number of rows the table holds, for the read statistics. It is only worth
+    /// overriding if the count is cheaper than building the rows.
+    #[async_backtrace::framed]
+    async fn estimated_rows(&self, _ctx: Arc<dyn TableContext>) -> Result<Option<u64>> {
+        Ok(None)
+    }
+
     #[async_backtrace::framed]
     async fn get_partitions(
         &self,
-        _ctx: Arc<dyn TableContext>,
-        _push_downs: Option<PushDownInfo>,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
     ) -> Result<(PartStatistics, Partitions)> {
+        let statistics = estimated_part_statistics(self.estimated_rows(ctx).await?);
         match Self::IS_LOCAL {
             true => Ok((
-                PartStatistics::default(),
+                statistics,
                 Partitions::create(PartitionsShuffleKind::Seq, vec![Arc::new(Box::new(
                     SystemTablePart,
                 ))]),
             )),
-            false => Ok((
-                PartStatistics::default(),
-                Partitions::create(PartitionsShuffleKind::Broadcast, vec![Arc::new(Box::new(
-                    SystemTablePart,
-                ))]),
-            )),
+            false => Ok((statistics, broadcast_partitions(&push_downs))),
         }
     }
 }
@@ -257,6 +343,10 @@ impl<TTable: 'static + AsyncSystemTable> Table for AsyncOneBlockSystemTable<TTab
         self.inner_table.get_table_info()
     }
 
+    fn support_column_projection(&self) -> bool {
+        true
+    }
+
     #[async_backtrace::framed]
     async fn read_partitions(
         &self,
@@ -333,6 +423,7 @@ impl<TTable: 'static + AsyncSystemTable> AsyncSource for SystemTableAsyncSource<
             .inner
             .get_full_data(self.context.clone(), self.push_downs.clone())
             .await?;
+        let block = limit_block(block, pushed_down_limit(&self.push_downs));
 
         #[cfg(debug_assertions)]
         {
@@ -359,6 +450,6 @@ impl<TTable: 'static + AsyncSystemTable> AsyncSource for SystemTableAsyncSource<
             )
         }
 
-        Ok(Some(block))
+        Ok(Some(project_block(block, &self.push_downs)))
     }
 }
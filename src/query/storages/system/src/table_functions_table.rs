@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_catalog::table_function::TableFunctionDescription;
 use databend_common_exception::Result;
 use databend_common_expression::types::StringType;
 use databend_common_expression::utils::FromData;
@@ -44,27 +45,58 @@ impl SyncSystemTable for TableFunctionsTable {
     }
 
     fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
-        let func_names = ctx.get_default_catalog()?.list_table_functions();
-        let mut names = func_names.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+        let mut functions = ctx
+            .get_default_catalog()?
+            .list_table_function_descriptions();
         // srf functions can also used as table functions
-        let mut srf_func_names = BUILTIN_FUNCTIONS
-            .properties
-            .iter()
-            .filter(|(_, property)| property.kind == FunctionKind::SRF)
-            .map(|(name, _)| name.as_str())
-            .collect::<Vec<_>>();
-        names.append(&mut srf_func_names);
+        functions.extend(
+            BUILTIN_FUNCTIONS
+                .properties
+                .iter()
+                .filter(|(_, property)| property.kind == FunctionKind::SRF)
+                .map(|(name, _)| TableFunctionDescription::new(name.as_str())),
+        );
+        // Keep the first of the same name, a registered table function comes before the srf.
+        functions.sort_by(|a, b| a.name.cmp(&b.name));
+        functions.dedup_by(|a, b| a.name == b.name);
 
-        Ok(DataBlock::new_from_columns(vec![StringType::from_data(
-            names,
-        )]))
+        let mut names = Vec::with_capacity(functions.len());
+        let mut descriptions = Vec::with_capacity(functions.len());
+        let mut signatures = Vec::with_capacity(functions.len());
+        let mut examples = Vec::with_capacity(functions.len());
+        for function in functions {
+            names.push(function.name);
+            descriptions.push(function.description);
+            signatures.push(function.signature);
+            examples.push(function.example);
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_opt_data(descriptions),
+            StringType::from_opt_data(signatures),
+            StringType::from_opt_data(examples),
+        ]))
     }
 }
 
 impl TableFunctionsTable {
     pub fn create(table_id: u64) -> Arc<dyn Table> {
-        let schema =
-            TableSchemaRefExt::create(vec![TableField::new("name", TableDataType::String)]);
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new(
+                "description",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new(
+                "signature",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new(
+                "example",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+        ]);
 
         let table_info = TableInfo {
             desc: "'system'.'table_functions'".to_string(),
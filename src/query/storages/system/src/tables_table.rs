@@ -19,6 +19,7 @@ use databend_common_catalog::catalog::CatalogManager;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::number::UInt64Type;
 use databend_common_expression::types::NumberDataType;
@@ -26,12 +27,10 @@ use databend_common_expression::types::StringType;
 use databend_common_expression::types::TimestampType;
 use databend_common_expression::utils::FromData;
 use databend_common_expression::DataBlock;
-use databend_common_expression::Scalar;
 use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchemaRef;
 use databend_common_expression::TableSchemaRefExt;
-use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_meta_app::principal::OwnershipObject;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
@@ -42,10 +41,13 @@ use databend_common_users::GrantObjectVisibilityChecker;
 use databend_common_users::UserApiProvider;
 use log::warn;
 
+use crate::table::pushed_down_limit;
 use crate::table::AsyncOneBlockSystemTable;
 use crate::table::AsyncSystemTable;
-use crate::util::find_eq_filter;
+use crate::util::CatalogScope;
 
+/// The rows are always ordered by catalog, database and table name, then by table id for the
+/// dropped tables of the history.
 pub struct TablesTable<const WITH_HISTORY: bool, const WITHOUT_VIEW: bool> {
     table_info: TableInfo,
 }
@@ -209,6 +211,32 @@ where TablesTable<T, U>: HistoryAware
         }
     }
 
+    /// Gets the tables named by the filters one by one, instead of listing the whole database.
+    ///
+    /// The dropped tables are only found by listing, so the tables with history are always listed.
+    #[async_backtrace::framed]
+    async fn get_tables(
+        catalog: &Arc<dyn Catalog>,
+        tenant: &Tenant,
+        database_name: &str,
+        scope: &CatalogScope,
+    ) -> Result<Vec<Arc<dyn Table>>> {
+        match &scope.tables {
+            Some(table_names) if !T => {
+                let mut tables = Vec::with_capacity(table_names.len());
+                for table_name in table_names {
+                    match catalog.get_table(tenant, database_name, table_name).await {
+                        Ok(table) => tables.push(table),
+                        Err(err) if err.code() == ErrorCode::UNKNOWN_TABLE => {}
+                        Err(err) => return Err(err),
+                    }
+                }
+                Ok(tables)
+            }
+            _ => Self::list_tables(catalog, tenant, database_name, T, U).await,
+        }
+    }
+
     /// dump all the tables from all the catalogs with pushdown, this is used for `SHOW TABLES` command.
     /// please note that this function is intended to not wrapped with Result<>, because we do not want to
     /// break ALL the output on reading ANY of the catalog, database or table failed.
@@ -222,8 +250,10 @@ where TablesTable<T, U>: HistoryAware
     ) -> DataBlock {
         let tenant = ctx.get_tenant();
 
-        let ctls: Vec<(String, Arc<dyn Catalog>)> =
+        // The rows are ordered by catalog, database and table name, whatever the catalogs list.
+        let mut ctls: Vec<(String, Arc<dyn Catalog>)> =
             catalogs.iter().map(|e| (e.name(), e.clone())).collect();
+        ctls.sort_by(|(l, _), (r, _)| l.cmp(r));
 
         let mut catalogs = vec![];
         let mut databases = vec![];
@@ -232,22 +262,14 @@ where TablesTable<T, U>: HistoryAware
         let mut owner: Vec<Option<String>> = Vec::new();
         let user_api = UserApiProvider::instance();
 
+        // Only the databases and tables named by the filters are read from the catalogs.
+        let scope = CatalogScope::from_push_downs(&push_downs, "database", "name");
+
         for (ctl_name, ctl) in ctls.iter() {
-            let mut dbs = Vec::new();
-            if let Some(push_downs) = &push_downs {
-                let mut db_name: Vec<String> = Vec::new();
-                if let Some(filter) = push_downs.filters.as_ref().map(|f| &f.filter) {
-                    let expr = filter.as_expr(&BUILTIN_FUNCTIONS);
-                    find_eq_filter(&expr, &mut |col_name, scalar| {
-                        if col_name == "database" {
-                            if let Scalar::String(database) = scalar {
-                                if !db_name.contains(database) {
-                                    db_name.push(database.clone());
-                                }
-                            }
-                        }
-                    });
-                    for db in db_name {
+            let dbs = match &scope.databases {
+                Some(db_names) => {
+                    let mut dbs = Vec::with_capacity(db_names.len());
+                    for db in db_names {
                         match ctl.get_database(&tenant, db.as_str()).await {
                             Ok(database) => dbs.push(database),
                             Err(err) => {
@@ -257,11 +279,9 @@ where TablesTable<T, U>: HistoryAware
                             }
                         }
                     }
+                    dbs
                 }
-            }
-
-            if dbs.is_empty() {
-                dbs = match ctl.list_databases(&tenant).await {
+                None => match ctl.list_databases(&tenant).await {
                     Ok(dbs) => dbs,
                     Err(err) => {
                         let msg =
@@ -271,10 +291,10 @@ where TablesTable<T, U>: HistoryAware
 
                         vec![]
                     }
-                }
-            }
+                },
+            };
 
-            let final_dbs = dbs
+            let mut final_dbs = dbs
                 .into_iter()
                 .filter(|db| {
                     visibility_checker.check_database_visibility(
@@ -284,12 +304,13 @@ where TablesTable<T, U>: HistoryAware
                     )
                 })
                 .collect::<Vec<_>>();
+            final_dbs.sort_by(|l, r| l.name().cmp(r.name()));
 
             let ownership = user_api.get_ownerships(&tenant).await.unwrap_or_default();
             for db in final_dbs {
                 let db_id = db.get_db_info().ident.db_id;
                 let db_name = db.name();
-                let tables = match Self::list_tables(ctl, &tenant, db_name, T, U).await {
+                let mut tables = match Self::get_tables(ctl, &tenant, db_name, &scope).await {
                     Ok(tables) => tables,
                     Err(err) => {
                         // swallow the errors related with remote database or tables, avoid ANY of bad table config corrupt ALL of the results.
@@ -307,6 +328,8 @@ where TablesTable<T, U>: HistoryAware
                         continue;
                     }
                 };
+                // The dropped tables of the history may share a name, they follow their ids.
+                tables.sort_by(|l, r| (l.name(), l.get_id()).cmp(&(r.name(), r.get_id())));
 
                 for table in tables {
                     let table_id = table.get_id();
@@ -360,6 +383,14 @@ where TablesTable<T, U>: HistoryAware
             }
         }
 
+        // The statistics are only read for the tables within the limit.
+        if let Some(limit) = pushed_down_limit(&push_downs) {
+            catalogs.truncate(limit);
+            databases.truncate(limit);
+            database_tables.truncate(limit);
+            owner.truncate(limit);
+        }
+
         let mut number_of_blocks: Vec<Option<u64>> = Vec::new();
         let mut number_of_segments: Vec<Option<u64>> = Vec::new();
         let mut num_rows: Vec<Option<u64>> = Vec::new();
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use databend_common_catalog::plan::PushDownInfo;
@@ -36,13 +37,18 @@ use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_pipeline_core::query_spill_prefix;
 use databend_common_storage::DataOperator;
+use databend_common_storage::SpillFiles;
 use futures::StreamExt;
 use futures::TryStreamExt;
 use opendal::Metakey;
 
+use crate::table::pushed_down_limit;
 use crate::table::AsyncOneBlockSystemTable;
 use crate::table::AsyncSystemTable;
 
+/// The spill files of the tenant in the storage.
+///
+/// `operator` and `created_at` are only known for the files spilled by this node.
 pub struct TempFilesTable {
     table_info: TableInfo,
 }
@@ -67,6 +73,16 @@ impl AsyncSystemTable for TempFilesTable {
         let mut temp_files_name: Vec<String> = vec![];
         let mut temp_files_content_length = vec![];
         let mut temp_files_last_modified = vec![];
+        let mut temp_files_query_id = vec![];
+        let mut temp_files_operator = vec![];
+        let mut temp_files_created_at = vec![];
+
+        // The files spilled by the queries of this node, others are listed without them.
+        let spilled = SpillFiles::instance()
+            .list()
+            .into_iter()
+            .map(|file| (file.location.clone(), file))
+            .collect::<HashMap<_, _>>();
 
         let location_prefix = format!("{}/", query_spill_prefix(tenant.tenant_name(), ""));
         if let Ok(lister) = operator
@@ -75,19 +91,25 @@ impl AsyncSystemTable for TempFilesTable {
             .metakey(Metakey::LastModified | Metakey::ContentLength)
             .await
         {
-            let limit = push_downs.and_then(|x| x.limit).unwrap_or(usize::MAX);
+            let limit = pushed_down_limit(&push_downs).unwrap_or(usize::MAX);
             let mut lister = lister.take(limit);
 
             while let Some(entry) = lister.try_next().await? {
                 let metadata = entry.metadata();
 
                 if metadata.is_file() {
-                    temp_files_name.push(
-                        entry
-                            .path()
-                            .trim_start_matches(&location_prefix)
-                            .to_string(),
-                    );
+                    let name = entry.path().trim_start_matches(&location_prefix);
+                    let spilled = spilled.get(entry.path());
+                    temp_files_query_id.push(match spilled {
+                        Some(file) => Some(file.query_id.clone()),
+                        None => name
+                            .split_once('/')
+                            .map(|(query_id, _)| query_id.to_string()),
+                    });
+                    temp_files_operator.push(spilled.map(|file| file.operator.clone()));
+                    temp_files_created_at
+                        .push(spilled.map(|file| file.created_at.timestamp_micros()));
+                    temp_files_name.push(name.to_string());
 
                     temp_files_last_modified
                         .push(metadata.last_modified().map(|x| x.timestamp_micros()));
@@ -115,6 +137,18 @@ impl AsyncSystemTable for TempFilesTable {
                     DataType::Timestamp.wrap_nullable(),
                     Value::Column(TimestampType::from_opt_data(temp_files_last_modified)),
                 ),
+                BlockEntry::new(
+                    DataType::String.wrap_nullable(),
+                    Value::Column(StringType::from_opt_data(temp_files_query_id)),
+                ),
+                BlockEntry::new(
+                    DataType::String.wrap_nullable(),
+                    Value::Column(StringType::from_opt_data(temp_files_operator)),
+                ),
+                BlockEntry::new(
+                    DataType::Timestamp.wrap_nullable(),
+                    Value::Column(TimestampType::from_opt_data(temp_files_created_at)),
+                ),
             ],
             num_rows,
         );
@@ -136,6 +170,9 @@ impl TempFilesTable {
                 "file_last_modified_time",
                 TableDataType::Timestamp.wrap_nullable(),
             ),
+            TableField::new("query_id", TableDataType::String.wrap_nullable()),
+            TableField::new("operator", TableDataType::String.wrap_nullable()),
+            TableField::new("created_at", TableDataType::Timestamp.wrap_nullable()),
         ]);
 
         let table_info = TableInfo {
@@ -19,8 +19,10 @@ use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::StringType;
 use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::UInt64Type;
 use databend_common_expression::utils::FromData;
 use databend_common_expression::DataBlock;
 use databend_common_expression::TableDataType;
@@ -85,6 +87,18 @@ impl AsyncSystemTable for UsersTable {
             .iter()
             .map(|user| Some(user.update_on.timestamp_micros()))
             .collect();
+        let mut max_concurrent_queries: Vec<u64> = users
+            .iter()
+            .map(|user| user.quota.max_concurrent_queries)
+            .collect();
+        let mut max_memory_per_query: Vec<u64> = users
+            .iter()
+            .map(|user| user.quota.max_memory_per_query)
+            .collect();
+        let mut max_result_rows: Vec<u64> = users
+            .iter()
+            .map(|user| user.quota.max_result_rows)
+            .collect();
 
         let configured_users = UserApiProvider::instance().get_configured_users();
         for (name, auth_info) in configured_users {
@@ -97,6 +111,9 @@ impl AsyncSystemTable for UsersTable {
             roles.push(BUILTIN_ROLE_ACCOUNT_ADMIN.to_string());
             created_on.push(None);
             update_on.push(None);
+            max_concurrent_queries.push(0);
+            max_memory_per_query.push(0);
+            max_result_rows.push(0);
         }
 
         // please note that do NOT display the auth_string field in the result, because there're risks of
@@ -111,6 +128,9 @@ impl AsyncSystemTable for UsersTable {
             StringType::from_data(roles),
             TimestampType::from_opt_data(created_on),
             TimestampType::from_opt_data(update_on),
+            UInt64Type::from_data(max_concurrent_queries),
+            UInt64Type::from_data(max_memory_per_query),
+            UInt64Type::from_data(max_result_rows),
         ]))
     }
 }
@@ -135,6 +155,18 @@ impl UsersTable {
                 "update_on",
                 TableDataType::Nullable(Box::new(TableDataType::Timestamp)),
             ),
+            TableField::new(
+                "max_concurrent_queries",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "max_memory_per_query",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "max_result_rows",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
         ]);
 
         let table_info = TableInfo {
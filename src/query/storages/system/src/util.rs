@@ -12,8 +12,131 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::Column;
 use databend_common_expression::Expr;
+use databend_common_expression::FromData;
 use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::principal::GrantObject;
+use databend_common_meta_app::principal::UserIdentity;
+use databend_common_meta_app::principal::UserPrivilegeType;
+
+/// The user the rows of a table showing the queries or sessions of all the users are limited
+/// to, None if the current user may see everyone's, which needs the SUPER privilege.
+///
+/// The users are told apart by their name and host, `'u'@'%'` doesn't see the rows of `'u'@'h'`.
+pub async fn visible_user(ctx: &Arc<dyn TableContext>) -> Result<Option<UserIdentity>> {
+    match ctx
+        .has_privilege(&GrantObject::Global, UserPrivilegeType::Super)
+        .await?
+    {
+        true => Ok(None),
+        false => Ok(Some(ctx.get_current_user()?.identity())),
+    }
+}
+
+/// The type of the `labels` columns, the labels set by the client of a session, queried like
+/// `labels['app']`.
+pub fn labels_data_type() -> TableDataType {
+    TableDataType::Map(Box::new(TableDataType::Tuple {
+        fields_name: vec!["key".to_string(), "value".to_string()],
+        fields_type: vec![TableDataType::String, TableDataType::String],
+    }))
+}
+
+pub fn labels_scalar(labels: &BTreeMap<String, String>) -> Scalar {
+    Scalar::Map(Column::Tuple(vec![
+        StringType::from_data(labels.keys().cloned().collect::<Vec<_>>()),
+        StringType::from_data(labels.values().cloned().collect::<Vec<_>>()),
+    ]))
+}
+
+/// The databases and tables a read of a catalog system table is restricted to, by the
+/// `=` and `IN` predicates on its database and table columns.
+///
+/// `None` means the predicates are not understood, then all of them have to be listed.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CatalogScope {
+    pub databases: Option<Vec<String>>,
+    pub tables: Option<Vec<String>>,
+}
+
+impl CatalogScope {
+    pub fn from_push_downs(
+        push_downs: &Option<PushDownInfo>,
+        database_column: &str,
+        table_column: &str,
+    ) -> CatalogScope {
+        match push_downs.as_ref().and_then(|p| p.filters.as_ref()) {
+            Some(filters) => {
+                let expr = filters.filter.as_expr(&BUILTIN_FUNCTIONS);
+                CatalogScope {
+                    databases: find_string_values(&expr, database_column),
+                    tables: find_string_values(&expr, table_column),
+                }
+            }
+            None => CatalogScope::default(),
+        }
+    }
+}
+
+/// Finds the values a string column is limited to by a filter, like `col = 'a'`, `col IN ('a',
+/// 'b')` and their conjunctions, returns None if the column may take any value.
+pub fn find_string_values(expr: &Expr<String>, column: &str) -> Option<Vec<String>> {
+    let Expr::FunctionCall { function, args, .. } = expr else {
+        return None;
+    };
+    match (function.signature.name.as_str(), args.as_slice()) {
+        ("eq", [Expr::ColumnRef { id, .. }, Expr::Constant { scalar, .. }])
+        | ("eq", [Expr::Constant { scalar, .. }, Expr::ColumnRef { id, .. }])
+            if id == column =>
+        {
+            match scalar {
+                Scalar::String(value) => Some(vec![value.clone()]),
+                _ => None,
+            }
+        }
+        // A long IN list is bound to `contains(array_distinct([...]), col)`.
+        ("contains", [Expr::Constant { scalar, .. }, Expr::ColumnRef { id, .. }])
+            if id == column =>
+        {
+            match scalar {
+                Scalar::Array(Column::String(values)) => {
+                    Some(values.iter().map(|v| v.to_string()).collect())
+                }
+                _ => None,
+            }
+        }
+        // A short IN list is bound to `col = 'a' OR col = 'b'`.
+        ("or", args) => {
+            let mut values: Vec<String> = Vec::new();
+            for arg in args {
+                for value in find_string_values(arg, column)? {
+                    if !values.contains(&value) {
+                        values.push(value);
+                    }
+                }
+            }
+            Some(values)
+        }
+        ("and" | "and_filters", args) => args
+            .iter()
+            .filter_map(|arg| find_string_values(arg, column))
+            .reduce(|mut values, other| {
+                values.retain(|v| other.contains(v));
+                values
+            }),
+        _ => None,
+    }
+}
 
 pub fn find_eq_filter(expr: &Expr<String>, visitor: &mut impl FnMut(&str, &Scalar)) {
     match expr {
@@ -0,0 +1,117 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::ServerWarnings;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_pipeline_core::Pipeline;
+
+use crate::SyncOneBlockSystemTable;
+use crate::SyncSystemTable;
+
+/// The warnings buffered by each node, `TRUNCATE` acknowledges them on all the nodes.
+pub struct WarningsTable {
+    table_info: TableInfo,
+}
+
+impl SyncSystemTable for WarningsTable {
+    const NAME: &'static str = "system.warnings";
+    // Allow distributed query.
+    const IS_LOCAL: bool = false;
+    const BROADCAST_TRUNCATE: bool = true;
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let local_id = ctx.get_cluster().local_id.clone();
+        let warnings = ServerWarnings::instance().list();
+
+        let mut nodes = Vec::with_capacity(warnings.len());
+        let mut first_seen = Vec::with_capacity(warnings.len());
+        let mut last_seen = Vec::with_capacity(warnings.len());
+        let mut severities = Vec::with_capacity(warnings.len());
+        let mut components = Vec::with_capacity(warnings.len());
+        let mut messages = Vec::with_capacity(warnings.len());
+        let mut counts = Vec::with_capacity(warnings.len());
+        for warning in warnings {
+            nodes.push(local_id.clone());
+            first_seen.push(warning.first_seen);
+            last_seen.push(warning.last_seen);
+            severities.push(warning.severity.to_string());
+            components.push(warning.component);
+            messages.push(warning.message);
+            counts.push(warning.count);
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(nodes),
+            TimestampType::from_data(first_seen),
+            TimestampType::from_data(last_seen),
+            StringType::from_data(severities),
+            StringType::from_data(components),
+            StringType::from_data(messages),
+            UInt64Type::from_data(counts),
+        ]))
+    }
+
+    fn truncate(&self, _ctx: Arc<dyn TableContext>, _pipeline: &mut Pipeline) -> Result<()> {
+        ServerWarnings::instance().clear();
+        Ok(())
+    }
+}
+
+impl WarningsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("node", TableDataType::String),
+            TableField::new("first_seen", TableDataType::Timestamp),
+            TableField::new("timestamp", TableDataType::Timestamp),
+            TableField::new("severity", TableDataType::String),
+            TableField::new("component", TableDataType::String),
+            TableField::new("message", TableDataType::String),
+            TableField::new("count", TableDataType::Number(NumberDataType::UInt64)),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'warnings'".to_string(),
+            name: "warnings".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemWarnings".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        SyncOneBlockSystemTable::create(WarningsTable { table_info })
+    }
+}
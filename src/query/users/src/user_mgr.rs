@@ -26,6 +26,7 @@ use databend_common_meta_app::principal::UserIdentity;
 use databend_common_meta_app::principal::UserInfo;
 use databend_common_meta_app::principal::UserOption;
 use databend_common_meta_app::principal::UserPrivilegeSet;
+use databend_common_meta_app::principal::UserQuota;
 use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_meta_types::MatchSeq;
@@ -351,6 +352,34 @@ impl UserApiProvider {
             .await
     }
 
+    // Update an user's quota
+    #[async_backtrace::framed]
+    pub async fn update_user_quota(
+        &self,
+        tenant: &Tenant,
+        user: UserIdentity,
+        quota: UserQuota,
+    ) -> Result<Option<u64>> {
+        if self.get_configured_user(&user.username).is_some() {
+            return Err(ErrorCode::UserAlreadyExists(format!(
+                "Built-in user `{}` cannot be updated",
+                user.username
+            )));
+        }
+        let client = self.user_api(tenant);
+        let update_user = client
+            .update_user_with(user, MatchSeq::GE(1), |ui: &mut UserInfo| {
+                ui.quota = quota;
+                ui.update_user_time();
+            })
+            .await;
+
+        match update_user {
+            Ok(res) => Ok(res),
+            Err(e) => Err(e.add_message_back("(while alter user quota).")),
+        }
+    }
+
     #[async_backtrace::framed]
     pub async fn update_user_login_result(
         &self,
@@ -0,0 +1,55 @@
+#!/usr/bin/env python3
+
+import os
+
+import pyarrow.flight as flight
+
+flight_port = os.getenv("QUERY_FLIGHT_SQL_HANDLER_PORT")
+if flight_port is None:
+    port = "8900"
+else:
+    port = flight_port
+
+
+def encode_varint(value):
+    out = bytearray()
+    while True:
+        byte = value & 0x7F
+        value >>= 7
+        if value:
+            out.append(byte | 0x80)
+        else:
+            out.append(byte)
+            return bytes(out)
+
+
+def encode_bytes_field(number, value):
+    return bytes([number << 3 | 2]) + encode_varint(len(value)) + value
+
+
+# google.protobuf.Any of arrow.flight.protocol.sql.CommandStatementQuery { query }, pyarrow has
+# no FlightSQL client.
+def statement_query(sql):
+    command = encode_bytes_field(1, sql.encode())
+    type_url = b"type.googleapis.com/arrow.flight.protocol.sql.CommandStatementQuery"
+    return encode_bytes_field(1, type_url) + encode_bytes_field(2, command)
+
+
+# 2 is GRPC_COMPRESS_GZIP, the requests are sent compressed and gzip is accepted for the
+# responses, the server compresses them with flight_sql_grpc_compression = "gzip".
+client = flight.FlightClient(
+    "grpc://127.0.0.1:" + port,
+    generic_options=[("grpc.default_compression_algorithm", 2)],
+)
+token = client.authenticate_basic_token("root", "")
+options = flight.FlightCallOptions(headers=[token])
+
+descriptor = flight.FlightDescriptor.for_command(
+    statement_query("select number from numbers(100000)")
+)
+info = client.get_flight_info(descriptor, options)
+total = 0
+for endpoint in info.endpoints:
+    table = client.do_get(endpoint.ticket, options).read_all()
+    total += sum(table.column(0).to_pylist())
+print(total)
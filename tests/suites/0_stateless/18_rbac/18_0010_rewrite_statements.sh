@@ -17,7 +17,6 @@ echo "GRANT SELECT ON default.t20_0016_table1 TO testuser1" | $BENDSQL_CLIENT_CO
 echo '-- ensure the statements not break with PUBLIC role'
 echo "SHOW TABLES;" | $TEST_USER_CONNECT > /dev/null
 echo "SHOW DATABASES;" | $TEST_USER_CONNECT > /dev/null
-echo "SHOW USERS;" | $TEST_USER_CONNECT > /dev/null
 echo "SHOW ROLES;" | $TEST_USER_CONNECT > /dev/null
 echo "SHOW STAGES;" | $TEST_USER_CONNECT > /dev/null
 echo "SHOW PROCESSLIST;" | $TEST_USER_CONNECT > /dev/null
\ No newline at end of file
@@ -0,0 +1,34 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export TEST_USER_PASSWORD="password"
+export TEST_USER_CONNECT="bendsql --user=testuser1 --password=password --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+
+echo '-- reset users'
+echo "DROP USER IF EXISTS 'testuser1'" | $BENDSQL_CLIENT_CONNECT
+echo "DROP ROLE IF EXISTS grant_admin" | $BENDSQL_CLIENT_CONNECT
+
+echo '-- prepare user for tests'
+echo "CREATE USER 'testuser1' IDENTIFIED BY '$TEST_USER_PASSWORD'" | $BENDSQL_CLIENT_CONNECT
+
+echo '-- without the GRANT privilege'
+echo "SELECT count(*) > 0 FROM system.users" | $TEST_USER_CONNECT
+echo "SELECT count(*) > 0 FROM system.roles" | $TEST_USER_CONNECT
+echo "SELECT count(*) > 0 FROM system.grants" | $TEST_USER_CONNECT
+echo "SHOW USERS" | $TEST_USER_CONNECT > /dev/null
+
+echo '-- with the GRANT privilege'
+echo "CREATE ROLE grant_admin" | $BENDSQL_CLIENT_CONNECT
+echo "GRANT GRANT ON *.* TO ROLE grant_admin" | $BENDSQL_CLIENT_CONNECT
+echo "GRANT ROLE grant_admin TO testuser1" | $BENDSQL_CLIENT_CONNECT
+echo "ALTER USER 'testuser1' WITH DEFAULT_ROLE = 'grant_admin'" | $BENDSQL_CLIENT_CONNECT
+echo "SELECT count(*) > 0 FROM system.users" | $TEST_USER_CONNECT
+echo "SELECT count(*) > 0 FROM system.roles" | $TEST_USER_CONNECT
+echo "SHOW USERS" | $TEST_USER_CONNECT > /dev/null
+echo "SELECT grantee_type, object_type, privileges FROM system.grants WHERE grantee_name = 'grant_admin'" | $TEST_USER_CONNECT
+
+echo '-- reset users'
+echo "DROP USER IF EXISTS 'testuser1'" | $BENDSQL_CLIENT_CONNECT
+echo "DROP ROLE IF EXISTS grant_admin" | $BENDSQL_CLIENT_CONNECT
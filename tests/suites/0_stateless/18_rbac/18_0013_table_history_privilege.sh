@@ -0,0 +1,27 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export TEST_USER_PASSWORD="password"
+export TEST_USER_CONNECT="bendsql --user=testuser2 --password=password --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+
+echo '-- reset users'
+echo "DROP USER IF EXISTS 'testuser2'" | $BENDSQL_CLIENT_CONNECT
+echo "DROP DATABASE IF EXISTS db_history_priv" | $BENDSQL_CLIENT_CONNECT
+
+echo '-- prepare user and table for tests'
+echo "CREATE USER 'testuser2' IDENTIFIED BY '$TEST_USER_PASSWORD'" | $BENDSQL_CLIENT_CONNECT
+echo "CREATE DATABASE db_history_priv" | $BENDSQL_CLIENT_CONNECT
+echo "CREATE TABLE db_history_priv.t(a int)" | $BENDSQL_CLIENT_CONNECT
+
+echo '-- without a privilege on the table'
+echo "SELECT operation FROM table_history('db_history_priv', 't')" | $TEST_USER_CONNECT
+
+echo '-- with the SELECT privilege on the table'
+echo "GRANT SELECT ON db_history_priv.t TO 'testuser2'" | $BENDSQL_CLIENT_CONNECT
+echo "SELECT operation FROM table_history('db_history_priv', 't')" | $TEST_USER_CONNECT
+
+echo '-- reset users'
+echo "DROP USER IF EXISTS 'testuser2'" | $BENDSQL_CLIENT_CONNECT
+echo "DROP DATABASE IF EXISTS db_history_priv" | $BENDSQL_CLIENT_CONNECT
@@ -0,0 +1,28 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export TEST_USER_PASSWORD="password"
+export TEST_USER_CONNECT="bendsql --user=testuser1 --password=password --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+
+echo '-- reset users'
+echo "DROP USER IF EXISTS 'testuser1'" | $BENDSQL_CLIENT_CONNECT
+echo "DROP ROLE IF EXISTS super_admin" | $BENDSQL_CLIENT_CONNECT
+
+echo '-- prepare user for tests'
+echo "CREATE USER 'testuser1' IDENTIFIED BY '$TEST_USER_PASSWORD'" | $BENDSQL_CLIENT_CONNECT
+
+echo '-- without the SUPER privilege'
+echo "SELECT count(*) > 0 FROM system.backtrace" | $TEST_USER_CONNECT
+
+echo '-- with the SUPER privilege'
+echo "CREATE ROLE super_admin" | $BENDSQL_CLIENT_CONNECT
+echo "GRANT SUPER ON *.* TO ROLE super_admin" | $BENDSQL_CLIENT_CONNECT
+echo "GRANT ROLE super_admin TO testuser1" | $BENDSQL_CLIENT_CONNECT
+echo "ALTER USER 'testuser1' WITH DEFAULT_ROLE = 'super_admin'" | $BENDSQL_CLIENT_CONNECT
+echo "SELECT count(*) > 0 FROM system.backtrace" | $TEST_USER_CONNECT
+
+echo '-- reset users'
+echo "DROP USER IF EXISTS 'testuser1'" | $BENDSQL_CLIENT_CONNECT
+echo "DROP ROLE IF EXISTS super_admin" | $BENDSQL_CLIENT_CONNECT
@@ -0,0 +1,50 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export TEST_USER_PASSWORD="password"
+export TEST_USER_CONNECT="bendsql --user=testuser1 --password=password --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+
+echo '-- reset users'
+echo "DROP USER IF EXISTS 'testuser1'" | $BENDSQL_CLIENT_CONNECT
+echo "DROP DATABASE IF EXISTS db18_0015" | $BENDSQL_CLIENT_CONNECT
+echo "DROP DATABASE IF EXISTS db18_0015_hidden" | $BENDSQL_CLIENT_CONNECT
+
+echo '-- prepare user for tests'
+echo "CREATE USER 'testuser1' IDENTIFIED BY '$TEST_USER_PASSWORD'" | $BENDSQL_CLIENT_CONNECT
+echo "CREATE DATABASE db18_0015" | $BENDSQL_CLIENT_CONNECT
+echo "CREATE DATABASE db18_0015_hidden" | $BENDSQL_CLIENT_CONNECT
+echo "CREATE TABLE db18_0015.t1(a int)" | $BENDSQL_CLIENT_CONNECT
+echo "CREATE TABLE db18_0015.t2(a int)" | $BENDSQL_CLIENT_CONNECT
+echo "CREATE TABLE db18_0015_hidden.t3(a int)" | $BENDSQL_CLIENT_CONNECT
+echo "GRANT SELECT ON db18_0015.t1 TO 'testuser1'" | $BENDSQL_CLIENT_CONNECT
+
+echo '-- processes of the other users'
+echo "SELECT count(*) FROM system.processes WHERE user <> 'testuser1'" | $TEST_USER_CONNECT
+echo "SELECT count(*) > 0 FROM system.processes WHERE user = 'root'" | $BENDSQL_CLIENT_CONNECT
+
+echo '-- query log of the other users'
+echo "SELECT count(*) FROM system.query_log WHERE sql_user <> 'testuser1'" | $TEST_USER_CONNECT
+echo "SELECT count(*) > 0 FROM system.query_log WHERE sql_user = 'testuser1'" | $BENDSQL_CLIENT_CONNECT
+
+echo '-- configs'
+echo "SELECT count(*) > 0 FROM system.configs" | $TEST_USER_CONNECT
+echo "SELECT count(*) > 0 FROM system.configs" | $BENDSQL_CLIENT_CONNECT
+
+echo '-- users and roles'
+echo "SELECT count(*) > 0 FROM system.users" | $TEST_USER_CONNECT
+echo "SELECT count(*) > 0 FROM system.roles" | $TEST_USER_CONNECT
+echo "SELECT count(*) > 0 FROM system.users WHERE name = 'testuser1'" | $BENDSQL_CLIENT_CONNECT
+echo "SELECT count(*) > 0 FROM system.roles" | $BENDSQL_CLIENT_CONNECT
+
+echo '-- databases and tables'
+echo "SELECT name FROM system.databases WHERE name LIKE 'db18_0015%' ORDER BY name" | $TEST_USER_CONNECT
+echo "SELECT name FROM system.databases WHERE name LIKE 'db18_0015%' ORDER BY name" | $BENDSQL_CLIENT_CONNECT
+echo "SHOW TABLES FROM db18_0015" | $TEST_USER_CONNECT
+echo "SHOW TABLES FROM db18_0015" | $BENDSQL_CLIENT_CONNECT
+
+echo '-- reset users'
+echo "DROP USER IF EXISTS 'testuser1'" | $BENDSQL_CLIENT_CONNECT
+echo "DROP DATABASE IF EXISTS db18_0015" | $BENDSQL_CLIENT_CONNECT
+echo "DROP DATABASE IF EXISTS db18_0015_hidden" | $BENDSQL_CLIENT_CONNECT